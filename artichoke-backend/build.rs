@@ -54,12 +54,20 @@ mod buildpath {
             crate::enumerate_sources(mruby_sys_ext_source_dir(), paths).unwrap();
         }
 
+        pub fn mruby_vendored_dir() -> PathBuf {
+            super::crate_root().join("vendor").join("mruby")
+        }
+
+        pub fn mruby_vendored_version_header() -> PathBuf {
+            mruby_vendored_include_dir().join("mruby").join("version.h")
+        }
+
         fn mruby_vendored_include_dir() -> PathBuf {
-            super::crate_root().join("vendor").join("mruby").join("include")
+            mruby_vendored_dir().join("include")
         }
 
         fn mruby_vendored_source_dir() -> PathBuf {
-            super::crate_root().join("vendor").join("mruby").join("src")
+            mruby_vendored_dir().join("src")
         }
 
         pub fn mruby_build_config() -> PathBuf {
@@ -88,6 +96,328 @@ mod buildpath {
     }
 }
 
+/// Support for out-of-tree mruby sources and pregenerated minirake outputs.
+///
+/// Restricted build environments, like offline CI and distro packaging, can
+/// point the build at a pre-extracted mruby source tree and pre-generated
+/// build outputs so the build never touches the network or invokes a ruby
+/// toolchain:
+///
+/// - `MRUBY_SOURCE_DIR`: use this mruby source tree instead of copying the
+///   vendored tree into `OUT_DIR`. The tree's release version is validated
+///   against the vendored copy, or against `MRUBY_REVISION` when set.
+/// - `MRUBY_PREGENERATED_DIR`: directory containing the minirake outputs (a
+///   `sys/` directory of generated C sources). When set, the minirake
+///   invocation is skipped entirely and the pregenerated sources are compiled
+///   with `cc`.
+/// - `MRUBY_REVISION`: expected `major.minor.teeny` release version of the
+///   tree given in `MRUBY_SOURCE_DIR`.
+mod overrides {
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    /// Resolve the effective mruby source directory.
+    ///
+    /// Returns the `MRUBY_SOURCE_DIR` override when set, otherwise the default
+    /// copy destination in the build root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    /// use std::path::PathBuf;
+    ///
+    /// let default = PathBuf::from("/out/artichoke-mruby/mruby");
+    /// assert_eq!(resolve_source_dir(None, default.clone()), default);
+    /// assert_eq!(
+    ///     resolve_source_dir(Some(OsString::from("/src/mruby")), default),
+    ///     PathBuf::from("/src/mruby")
+    /// );
+    /// ```
+    pub fn resolve_source_dir(override_dir: Option<OsString>, default: PathBuf) -> PathBuf {
+        override_dir.map_or(default, PathBuf::from)
+    }
+
+    /// Resolve the effective directory of minirake-generated C sources.
+    ///
+    /// Returns the `sys` directory inside the `MRUBY_PREGENERATED_DIR`
+    /// override when set, otherwise the default location in the minirake
+    /// build directory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::OsString;
+    /// use std::path::PathBuf;
+    ///
+    /// let default = PathBuf::from("/out/artichoke-mruby/mruby-build/sys");
+    /// assert_eq!(resolve_generated_dir(None, default.clone()), default);
+    /// assert_eq!(
+    ///     resolve_generated_dir(Some(OsString::from("/src/pregenerated")), default),
+    ///     PathBuf::from("/src/pregenerated/sys")
+    /// );
+    /// ```
+    pub fn resolve_generated_dir(override_dir: Option<OsString>, default: PathBuf) -> PathBuf {
+        if let Some(dir) = override_dir {
+            PathBuf::from(dir).join("sys")
+        } else {
+            default
+        }
+    }
+
+    /// Extract the `major.minor.teeny` release version from the contents of
+    /// mruby's `include/mruby/version.h`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let version_h = r"
+    /// #define MRUBY_RELEASE_MAJOR 3
+    /// #define MRUBY_RELEASE_MINOR 0
+    /// #define MRUBY_RELEASE_TEENY 0
+    /// ";
+    /// assert_eq!(parse_mruby_version(version_h), Some(String::from("3.0.0")));
+    /// assert_eq!(parse_mruby_version("not a version header"), None);
+    /// ```
+    pub fn parse_mruby_version(version_h: &str) -> Option<String> {
+        let mut major = None;
+        let mut minor = None;
+        let mut teeny = None;
+        for line in version_h.lines() {
+            let mut tokens = line.split_whitespace();
+            if tokens.next() != Some("#define") {
+                continue;
+            }
+            let (name, value) = match (tokens.next(), tokens.next()) {
+                (Some(name), Some(value)) => (name, value),
+                _ => continue,
+            };
+            match name {
+                "MRUBY_RELEASE_MAJOR" => major = value.parse::<u32>().ok(),
+                "MRUBY_RELEASE_MINOR" => minor = value.parse::<u32>().ok(),
+                "MRUBY_RELEASE_TEENY" => teeny = value.parse::<u32>().ok(),
+                _ => {}
+            }
+        }
+        Some(format!("{}.{}.{}", major?, minor?, teeny?))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::ffi::OsString;
+        use std::path::PathBuf;
+
+        use super::{parse_mruby_version, resolve_generated_dir, resolve_source_dir};
+
+        #[test]
+        fn source_dir_defaults_to_build_root_copy() {
+            let default = PathBuf::from("/out/artichoke-mruby/mruby");
+            assert_eq!(resolve_source_dir(None, default.clone()), default);
+        }
+
+        #[test]
+        fn source_dir_override_wins() {
+            let default = PathBuf::from("/out/artichoke-mruby/mruby");
+            let resolved = resolve_source_dir(Some(OsString::from("/src/mruby")), default);
+            assert_eq!(resolved, PathBuf::from("/src/mruby"));
+        }
+
+        #[test]
+        fn generated_dir_defaults_to_minirake_build_dir() {
+            let default = PathBuf::from("/out/artichoke-mruby/mruby-build/sys");
+            assert_eq!(resolve_generated_dir(None, default.clone()), default);
+        }
+
+        #[test]
+        fn generated_dir_override_points_at_sys_subdir() {
+            let default = PathBuf::from("/out/artichoke-mruby/mruby-build/sys");
+            let resolved = resolve_generated_dir(Some(OsString::from("/src/pregenerated")), default);
+            assert_eq!(resolved, PathBuf::from("/src/pregenerated/sys"));
+        }
+
+        #[test]
+        fn parse_version_from_header() {
+            let version_h = r"
+#define MRUBY_RELEASE_MAJOR 3
+#define MRUBY_RELEASE_MINOR 0
+#define MRUBY_RELEASE_TEENY 0
+";
+            assert_eq!(parse_mruby_version(version_h), Some(String::from("3.0.0")));
+        }
+
+        #[test]
+        fn parse_version_requires_all_components() {
+            let version_h = "#define MRUBY_RELEASE_MAJOR 3";
+            assert_eq!(parse_mruby_version(version_h), None);
+            assert_eq!(parse_mruby_version(""), None);
+        }
+    }
+}
+
+/// Preflight checks for the host tools required to build mruby.
+///
+/// The mruby codegen shells out to a host `ruby` running minirake, which in
+/// turn requires `bison` and a working C compiler. When one of these is
+/// missing, minirake fails in ways that are hard to diagnose from the build
+/// output alone, so the tools are probed up front and missing ones are
+/// reported with per-platform install instructions.
+mod preflight {
+    use std::process::{Command, Stdio};
+
+    /// Number of trailing minirake log lines to include in build failures.
+    pub const MINIRAKE_LOG_LINES: usize = 50;
+
+    /// A host tool required to run the mruby codegen.
+    #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+    pub enum HostTool {
+        Ruby,
+        Bison,
+        CCompiler,
+    }
+
+    impl HostTool {
+        pub fn name(self) -> &'static str {
+            match self {
+                Self::Ruby => "ruby",
+                Self::Bison => "bison",
+                Self::CCompiler => "a C compiler",
+            }
+        }
+
+        fn install_hints(self) -> &'static [&'static str] {
+            match self {
+                Self::Ruby => &[
+                    "  macOS: brew install ruby",
+                    "  Debian/Ubuntu: apt-get install ruby",
+                    "  Windows: https://rubyinstaller.org/",
+                ],
+                Self::Bison => &[
+                    "  macOS: brew install bison",
+                    "  Debian/Ubuntu: apt-get install bison",
+                    "  Windows: choco install winflexbison3",
+                ],
+                Self::CCompiler => &[
+                    "  macOS: xcode-select --install",
+                    "  Debian/Ubuntu: apt-get install build-essential",
+                    "  Windows: install the Visual Studio C++ build tools",
+                ],
+            }
+        }
+
+        fn is_available(self) -> bool {
+            match self {
+                Self::Ruby => probe_command("ruby"),
+                Self::Bison => probe_command("bison"),
+                Self::CCompiler => cc::Build::new().try_get_compiler().is_ok(),
+            }
+        }
+    }
+
+    fn probe_command(program: &str) -> bool {
+        Command::new(program)
+            .arg("--version")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map_or(false, |status| status.success())
+    }
+
+    /// Probe for all host tools required to run the mruby codegen.
+    ///
+    /// # Errors
+    ///
+    /// If any tool is missing, the full set of missing tools is returned.
+    pub fn check() -> Result<(), Vec<HostTool>> {
+        check_with(HostTool::is_available)
+    }
+
+    fn check_with<F>(is_available: F) -> Result<(), Vec<HostTool>>
+    where
+        F: Fn(HostTool) -> bool,
+    {
+        let missing = [HostTool::Ruby, HostTool::Bison, HostTool::CCompiler]
+            .into_iter()
+            .filter(|&tool| !is_available(tool))
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
+    /// Format a diagnostic naming each missing tool and how to install it.
+    ///
+    /// The returned message is multi-line. Callers should emit each line with
+    /// a `cargo:warning=` prefix so the message survives cargo's build script
+    /// output capture.
+    pub fn diagnostic(missing: &[HostTool]) -> String {
+        let mut message = String::from("artichoke-backend requires host tools to generate and compile the mruby C sources.");
+        for tool in missing {
+            message.push('\n');
+            message.push_str(tool.name());
+            message.push_str(" was not found. To install it:");
+            for hint in tool.install_hints() {
+                message.push('\n');
+                message.push_str(hint);
+            }
+        }
+        message
+    }
+
+    /// Extract the last `limit` lines of a command's captured output.
+    pub fn tail(text: &str, limit: usize) -> String {
+        let lines = text.lines().collect::<Vec<_>>();
+        let skip = lines.len().saturating_sub(limit);
+        lines[skip..].join("\n")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{check_with, diagnostic, tail, HostTool};
+
+        #[test]
+        fn check_passes_when_all_tools_are_available() {
+            assert!(check_with(|_| true).is_ok());
+        }
+
+        #[test]
+        fn check_reports_each_missing_tool() {
+            let missing = check_with(|tool| tool != HostTool::Bison).unwrap_err();
+            assert_eq!(missing, [HostTool::Bison]);
+
+            let missing = check_with(|_| false).unwrap_err();
+            assert_eq!(missing, [HostTool::Ruby, HostTool::Bison, HostTool::CCompiler]);
+        }
+
+        #[test]
+        fn diagnostic_names_missing_tools_and_install_hints() {
+            let message = diagnostic(&[HostTool::Ruby, HostTool::Bison]);
+            assert!(message.contains("ruby was not found"));
+            assert!(message.contains("bison was not found"));
+            assert!(message.contains("brew install bison"));
+            assert!(message.contains("https://rubyinstaller.org/"));
+            assert!(!message.contains("C compiler"));
+        }
+
+        #[test]
+        fn tail_returns_short_input_unchanged() {
+            assert_eq!(tail("a\nb\nc", 50), "a\nb\nc");
+            assert_eq!(tail("", 50), "");
+        }
+
+        #[test]
+        fn tail_returns_last_lines_of_long_input() {
+            let text = (1..=60).map(|line| line.to_string()).collect::<Vec<_>>().join("\n");
+            let tail = tail(&text, 50);
+            assert!(tail.starts_with("11\n"));
+            assert!(tail.ends_with("\n60"));
+            assert_eq!(tail.lines().count(), 50);
+        }
+    }
+}
+
 mod libmruby {
     use std::collections::HashMap;
     use std::env;
@@ -99,7 +429,7 @@ mod libmruby {
 
     use target_lexicon::{Architecture, OperatingSystem, Triple};
 
-    use super::{buildpath, enumerate_sources};
+    use super::{buildpath, enumerate_sources, overrides, preflight};
 
     fn gems() -> impl Iterator<Item = &'static str> {
         [
@@ -165,7 +495,7 @@ mod libmruby {
     }
 
     pub fn mruby_source_dir() -> PathBuf {
-        buildpath::build_root().join("mruby")
+        overrides::resolve_source_dir(env::var_os("MRUBY_SOURCE_DIR"), buildpath::build_root().join("mruby"))
     }
 
     fn mruby_minirake() -> PathBuf {
@@ -181,7 +511,7 @@ mod libmruby {
     }
 
     fn mruby_generated_source_dir() -> PathBuf {
-        mruby_build_dir().join("sys")
+        overrides::resolve_generated_dir(env::var_os("MRUBY_PREGENERATED_DIR"), mruby_build_dir().join("sys"))
     }
 
     fn mruby_generated_gembox() -> PathBuf {
@@ -204,24 +534,53 @@ mod libmruby {
     }
 
     /// Build the mruby static library with its built in minirake build system.
-    fn staticlib(target: &Triple) {
-        // minirake dynamically generates some c source files so we can't build
-        // directly with the `cc` crate. We must first hijack the mruby build
-        // system to do the codegen for us.
-        generate_mrbgem_config();
-        let status = Command::new("ruby")
-            .stdin(Stdio::null())
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .arg(mruby_minirake())
-            .arg("--verbose")
-            .env("MRUBY_BUILD_DIR", mruby_build_dir())
-            .env("MRUBY_CONFIG", mruby_build_config())
-            .current_dir(mruby_source_dir())
-            .status()
-            .unwrap();
-        if !status.success() {
-            panic!("minirake failed");
+    fn staticlib(target: &Triple) -> Result<(), String> {
+        if env::var_os("MRUBY_PREGENERATED_DIR").is_some() {
+            // Offline build: the minirake outputs were supplied up front, so
+            // no codegen and no ruby toolchain invocation is necessary.
+            let generated = mruby_generated_source_dir();
+            if !generated.is_dir() {
+                return Err(format!(
+                    "MRUBY_PREGENERATED_DIR does not contain minirake outputs: missing {}",
+                    generated.display()
+                ));
+            }
+        } else {
+            if let Err(missing) = preflight::check() {
+                for line in preflight::diagnostic(&missing).lines() {
+                    println!("cargo:warning={}", line);
+                }
+                let missing = missing.iter().map(|tool| tool.name()).collect::<Vec<_>>().join(", ");
+                return Err(format!("missing required host tools: {}", missing));
+            }
+
+            // minirake dynamically generates some c source files so we can't
+            // build directly with the `cc` crate. We must first hijack the
+            // mruby build system to do the codegen for us.
+            generate_mrbgem_config();
+            let output = Command::new("ruby")
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .arg(mruby_minirake())
+                .arg("--verbose")
+                .env("MRUBY_BUILD_DIR", mruby_build_dir())
+                .env("MRUBY_CONFIG", mruby_build_config())
+                .current_dir(mruby_source_dir())
+                .output()
+                .map_err(|err| format!("failed to spawn ruby to run minirake: {}", err))?;
+            if !output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!(
+                    "minirake failed with {}.\n\nlast {} lines of minirake stdout:\n{}\n\nlast {} lines of minirake stderr:\n{}",
+                    output.status,
+                    preflight::MINIRAKE_LOG_LINES,
+                    preflight::tail(&stdout, preflight::MINIRAKE_LOG_LINES),
+                    preflight::MINIRAKE_LOG_LINES,
+                    preflight::tail(&stderr, preflight::MINIRAKE_LOG_LINES),
+                ));
+            }
         }
 
         let mut sources = HashMap::new();
@@ -285,6 +644,7 @@ mod libmruby {
             .define("MRB_NO_BOXING", None)
             .define("MRB_NO_PRESYM", None)
             .define("MRB_NO_STDIO", None)
+            .define("MRB_USE_DEBUG_HOOK", None)
             .define("MRB_UTF8_STRING", None);
 
         for gem in gems() {
@@ -310,6 +670,7 @@ mod libmruby {
         }
 
         build.compile("libartichokemruby.a");
+        Ok(())
     }
 
     fn bindgen(target: &Triple, out_dir: &OsStr) {
@@ -390,6 +751,7 @@ mod libmruby {
             .arg("-DMRB_NO_BOXING")
             .arg("-DMRB_NO_PRESYM")
             .arg("-DMRB_NO_STDIO")
+            .arg("-DMRB_USE_DEBUG_HOOK")
             .arg("-DMRB_UTF8_STRING");
 
         if let Architecture::Wasm32 = target.architecture {
@@ -402,42 +764,86 @@ mod libmruby {
         command.status().ok()
     }
 
-    pub fn build(target: &Triple, out_dir: &OsStr) {
+    pub fn build(target: &Triple, out_dir: &OsStr) -> Result<(), String> {
         fs::create_dir_all(mruby_build_dir()).unwrap();
-        staticlib(target);
+        staticlib(target)?;
         bindgen(target, out_dir);
+        Ok(())
     }
 }
 
 mod build {
+    use std::env;
     use std::fs;
     use std::io;
     use std::path::{Path, PathBuf};
 
-    use super::{buildpath, libmruby};
+    use super::{buildpath, libmruby, overrides};
 
     pub fn clean() {
         let _ignored = fs::remove_dir_all(buildpath::build_root());
     }
 
+    /// Ensure an out-of-tree mruby source matches the revision this crate was
+    /// written against.
+    ///
+    /// The expected revision is `MRUBY_REVISION` when set, otherwise the
+    /// release version of the vendored mruby sources.
+    fn validate_source_override(source_dir: &Path) {
+        let version_header = source_dir.join("include").join("mruby").join("version.h");
+        let version_h = fs::read_to_string(&version_header).unwrap_or_else(|_| {
+            panic!(
+                "MRUBY_SOURCE_DIR does not look like an mruby source tree: missing {}",
+                version_header.display()
+            )
+        });
+        let actual = overrides::parse_mruby_version(&version_h).unwrap_or_else(|| {
+            panic!(
+                "could not parse mruby release version from {}",
+                version_header.display()
+            )
+        });
+        let expected = if let Some(revision) = env::var_os("MRUBY_REVISION") {
+            revision
+                .into_string()
+                .expect("MRUBY_REVISION env variable was not valid UTF-8")
+        } else {
+            let vendored = buildpath::source::mruby_vendored_version_header();
+            let version_h = fs::read_to_string(&vendored).unwrap();
+            overrides::parse_mruby_version(&version_h)
+                .unwrap_or_else(|| panic!("could not parse mruby release version from {}", vendored.display()))
+        };
+        assert!(
+            actual == expected,
+            "MRUBY_SOURCE_DIR revision mismatch: expected mruby {}, found mruby {} in {}",
+            expected,
+            actual,
+            source_dir.display()
+        );
+    }
+
     pub fn setup_build_root() {
         fs::create_dir_all(buildpath::build_root()).unwrap();
 
-        copy_dir_recursive(
-            &buildpath::crate_root().join("vendor").join("mruby"),
-            &libmruby::mruby_source_dir(),
-        )
-        .unwrap();
+        if env::var_os("MRUBY_SOURCE_DIR").is_some() {
+            validate_source_override(&libmruby::mruby_source_dir());
+        } else {
+            copy_dir_recursive(&buildpath::source::mruby_vendored_dir(), &libmruby::mruby_source_dir()).unwrap();
+        }
 
-        let _ignored = fs::remove_file(libmruby::mruby_build_config());
         fs::create_dir_all(libmruby::mruby_build_dir()).unwrap();
-        fs::copy(buildpath::source::mruby_build_config(), libmruby::mruby_build_config()).unwrap();
-        fs::copy(
-            buildpath::source::mruby_bootstrap_gembox(),
-            libmruby::bootstrap_gembox(),
-        )
-        .unwrap();
-        fs::copy(buildpath::source::mruby_noop(), libmruby::builder_noop()).unwrap();
+        if env::var_os("MRUBY_PREGENERATED_DIR").is_none() {
+            // These support files are only consumed by the minirake codegen,
+            // which is skipped entirely for pregenerated builds.
+            let _ignored = fs::remove_file(libmruby::mruby_build_config());
+            fs::copy(buildpath::source::mruby_build_config(), libmruby::mruby_build_config()).unwrap();
+            fs::copy(
+                buildpath::source::mruby_bootstrap_gembox(),
+                libmruby::bootstrap_gembox(),
+            )
+            .unwrap();
+            fs::copy(buildpath::source::mruby_noop(), libmruby::builder_noop()).unwrap();
+        }
     }
 
     pub fn rerun_if_changed() {
@@ -447,6 +853,10 @@ mod build {
         for path in paths {
             println!("cargo:rerun-if-changed={}", path.to_str().unwrap());
         }
+
+        println!("cargo:rerun-if-env-changed=MRUBY_SOURCE_DIR");
+        println!("cargo:rerun-if-env-changed=MRUBY_PREGENERATED_DIR");
+        println!("cargo:rerun-if-env-changed=MRUBY_REVISION");
     }
 
     fn copy_dir_recursive(from: &Path, to: &Path) -> io::Result<()> {
@@ -491,5 +901,7 @@ fn main() {
     build::clean();
     build::rerun_if_changed();
     build::setup_build_root();
-    libmruby::build(&target, &out_dir);
+    if let Err(err) = libmruby::build(&target, &out_dir) {
+        panic!("{}", err);
+    }
 }
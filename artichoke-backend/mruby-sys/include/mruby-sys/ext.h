@@ -142,8 +142,60 @@ MRB_API _Bool mrb_sys_gc_disable(mrb_state *mrb);
  */
 MRB_API _Bool mrb_sys_gc_enable(mrb_state *mrb);
 
+/**
+ * Check whether GC is enabled without modifying the enabled state.
+ */
+MRB_API _Bool mrb_sys_gc_is_enabled(mrb_state *mrb);
+
 MRB_API _Bool mrb_sys_value_is_dead(mrb_state *_mrb, mrb_value value);
 
 MRB_API int mrb_sys_gc_live_objects(mrb_state *mrb);
 
+/**
+ * Count the heap pages allocated by the GC.
+ */
+MRB_API size_t mrb_sys_gc_heap_pages(mrb_state *mrb);
+
+/**
+ * Total object capacity of the heap pages allocated by the GC.
+ */
+MRB_API size_t mrb_sys_gc_heap_capacity(mrb_state *mrb);
+
+/**
+ * Current phase of the incremental GC: `MRB_GC_STATE_ROOT`,
+ * `MRB_GC_STATE_MARK`, or `MRB_GC_STATE_SWEEP`.
+ */
+MRB_API int mrb_sys_gc_phase(mrb_state *mrb);
+
 MRB_API void mrb_sys_safe_gc_mark(mrb_state *mrb, mrb_value value);
+
+/**
+ * A single frame captured from the interpreter call stack.
+ *
+ * `filename` and `method` point into interpreter-owned storage (the irep
+ * debug info and the symbol table) and are only valid until the VM resumes
+ * execution.
+ */
+typedef struct mrb_sys_stack_frame {
+  /** Source file of the frame. `NULL` for frames executing a C function. */
+  const char *filename;
+  /** Source line of the frame. `-1` for frames executing a C function. */
+  int32_t lineno;
+  /** Name of the method being invoked. `NULL` for top-level frames. */
+  const char *method;
+} mrb_sys_stack_frame;
+
+/**
+ * Upper bound on the number of frames on the current callinfo stack.
+ */
+MRB_API size_t mrb_sys_call_stack_depth(mrb_state *mrb);
+
+/**
+ * Capture the current call stack into `frames`, innermost frame first.
+ *
+ * Unlike exception backtraces, frames executing C functions are included so
+ * callers can attribute them to their registered method names. Returns the
+ * number of frames written, which is at most `capacity`.
+ */
+MRB_API size_t mrb_sys_call_stack(mrb_state *mrb, mrb_sys_stack_frame *frames,
+                                  size_t capacity);
@@ -17,6 +17,8 @@
 #include <mruby.h>
 #include <mruby/array.h>
 #include <mruby/class.h>
+#include <mruby/debug.h>
+#include <mruby/proc.h>
 #include <mruby/numeric.h>
 #include <mruby/presym.h>
 #include <mruby/range.h>
@@ -455,6 +457,13 @@ mrb_sys_gc_enable(mrb_state *mrb)
   return was_enabled;
 }
 
+MRB_API _Bool
+mrb_sys_gc_is_enabled(mrb_state *mrb)
+{
+  mrb_gc *gc = &mrb->gc;
+  return !gc->disabled;
+}
+
 MRB_API _Bool
 mrb_sys_value_is_dead(mrb_state *mrb, mrb_value value)
 {
@@ -480,6 +489,36 @@ mrb_sys_gc_live_objects(mrb_state *mrb)
   return gc->live;
 }
 
+// Heap pages are sized by `MRB_HEAP_PAGE_SIZE`, which is private to gc.c.
+// Mirror its default here so heap capacity can be computed.
+#ifndef MRB_HEAP_PAGE_SIZE
+#define MRB_HEAP_PAGE_SIZE 1024
+#endif
+
+MRB_API size_t
+mrb_sys_gc_heap_pages(mrb_state *mrb)
+{
+  mrb_gc *gc = &mrb->gc;
+  size_t pages = 0;
+  for (mrb_heap_page *page = gc->heaps; page != NULL; page = page->next) {
+    pages++;
+  }
+  return pages;
+}
+
+MRB_API size_t
+mrb_sys_gc_heap_capacity(mrb_state *mrb)
+{
+  return mrb_sys_gc_heap_pages(mrb) * MRB_HEAP_PAGE_SIZE;
+}
+
+MRB_API int
+mrb_sys_gc_phase(mrb_state *mrb)
+{
+  mrb_gc *gc = &mrb->gc;
+  return gc->state;
+}
+
 MRB_API void
 mrb_sys_safe_gc_mark(mrb_state *mrb, mrb_value value)
 {
@@ -487,3 +526,71 @@ mrb_sys_safe_gc_mark(mrb_state *mrb, mrb_value value)
     mrb_gc_mark(mrb, mrb_basic_ptr(value));
   }
 }
+
+MRB_API size_t
+mrb_sys_call_stack_depth(mrb_state *mrb)
+{
+  ptrdiff_t ciidx = mrb->c->ci - mrb->c->cibase;
+
+  if (ciidx >= mrb->c->ciend - mrb->c->cibase) {
+    ciidx = 10; /* ciidx is broken... */
+  }
+  return (size_t)ciidx + 1;
+}
+
+// This walk mirrors `each_backtrace` in mruby's backtrace.c with one
+// difference: frames executing C functions are reported instead of skipped so
+// that methods implemented in Rust show up in `Kernel#caller` output.
+MRB_API size_t
+mrb_sys_call_stack(mrb_state *mrb, mrb_sys_stack_frame *frames, size_t capacity)
+{
+  ptrdiff_t ciidx = mrb->c->ci - mrb->c->cibase;
+  ptrdiff_t i;
+  size_t count = 0;
+
+  if (ciidx >= mrb->c->ciend - mrb->c->cibase) {
+    ciidx = 10; /* ciidx is broken... */
+  }
+
+  for (i = ciidx; i >= 0 && count < capacity; i--) {
+    mrb_sys_stack_frame *frame = &frames[count];
+    mrb_callinfo *ci;
+    const mrb_irep *irep;
+    const mrb_code *pc;
+    uint32_t idx;
+
+    ci = &mrb->c->cibase[i];
+
+    if (!ci->proc) continue;
+
+    frame->method = ci->mid ? mrb_sym_name(mrb, ci->mid) : NULL;
+
+    if (MRB_PROC_CFUNC_P(ci->proc)) {
+      frame->filename = NULL;
+      frame->lineno = -1;
+      count++;
+      continue;
+    }
+
+    irep = ci->proc->body.irep;
+    if (!irep) continue;
+
+    if (ci->pc) {
+      pc = &ci->pc[-1];
+    }
+    else {
+      continue;
+    }
+
+    idx = (uint32_t)(pc - irep->iseq);
+    frame->lineno = mrb_debug_get_line(mrb, irep, idx);
+    if (frame->lineno == -1) continue;
+
+    frame->filename = mrb_debug_get_filename(mrb, irep, idx);
+    if (!frame->filename) {
+      frame->filename = "(unknown)";
+    }
+    count++;
+  }
+  return count;
+}
@@ -6,6 +6,7 @@ use crate::core::{ClassRegistry, TryConvertMut, Value as _};
 use crate::error::{Error, RubyException};
 use crate::exception_handler;
 use crate::extn::core::exception::{Fatal, TypeError};
+use crate::extn::core::symbol;
 use crate::sys::{self, protect};
 use crate::types::{self, Ruby};
 use crate::value::Value;
@@ -159,10 +160,41 @@ impl Block {
         self.0
     }
 
+    /// If this block is a proc created by `Symbol#to_proc`, return the method
+    /// symbol it dispatches to.
+    ///
+    /// Symbol procs are cfunc procs whose body is the symbol proc call shim
+    /// and whose cfunc environment holds the symbol.
+    fn symbol_proc_method(&self) -> Option<sys::mrb_sym> {
+        if !matches!(types::ruby_from_mrb_value(self.0), Ruby::Proc) {
+            return None;
+        }
+        unsafe {
+            let proc = sys::mrb_sys_proc_ptr(self.0);
+            if (*proc).flags & sys::MRB_PROC_CFUNC_FL == 0 || (*proc).flags & sys::MRB_PROC_ENVSET == 0 {
+                return None;
+            }
+            // Function pointer identity is sufficient here: a false negative
+            // only means falling back to the generic proc call path.
+            #[allow(clippy::fn_address_comparisons)]
+            if (*proc).body.func != Some(symbol::trampoline::symbol_proc_call as _) {
+                return None;
+            }
+            let env = (*proc).e.env;
+            let method = *(*env).stack;
+            Some(method.value.sym)
+        }
+    }
+
     pub fn yield_arg(&self, interp: &mut Artichoke, arg: &Value) -> Result<Value, Error> {
         if arg.is_dead(interp) {
             return Err(Fatal::from("Value yielded to block is dead. This indicates a bug in the mruby garbage collector. Please leave a comment at https://github.com/artichoke/artichoke/issues/1336.").into());
         }
+        // Symbol procs dispatch directly to the method named by the symbol,
+        // skipping proc frame setup.
+        if let Some(method) = self.symbol_proc_method() {
+            return symbol::trampoline::symbol_proc_dispatch(interp, method, *arg, &[], None);
+        }
         let result = unsafe { interp.with_ffi_boundary(|mrb| protect::block_yield(mrb, self.inner(), arg.inner()))? };
         match result {
             Ok(value) => {
@@ -0,0 +1,124 @@
+//! Capture the live call stack from the mruby VM.
+//!
+//! Exception backtraces are only materialized when an exception is raised.
+//! This module reads the mruby callinfo stack directly so APIs like
+//! [`Kernel#caller`] can report where execution currently is without raising.
+//!
+//! Frames are ordered innermost first. Frames executing methods implemented in
+//! Rust have no Ruby source location; they are attributed to the synthetic
+//! [`NATIVE_FRAME_FILENAME`] path with their registered method name.
+//!
+//! [`Kernel#caller`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-caller
+
+use std::ffi::CStr;
+use std::ptr;
+
+use crate::error::Error;
+use crate::sys;
+use crate::Artichoke;
+
+/// Synthetic path attributed to frames executing methods implemented in Rust
+/// or C, which have no Ruby source location.
+pub const NATIVE_FRAME_FILENAME: &[u8] = b"(native)";
+
+/// A single frame captured from the mruby callinfo stack.
+///
+/// `Frame`s own copies of the path and method name, so they remain valid after
+/// the VM resumes execution.
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Frame {
+    path: Vec<u8>,
+    lineno: i64,
+    label: Vec<u8>,
+}
+
+impl Frame {
+    /// Source file of the frame.
+    ///
+    /// Frames executing native methods are attributed to
+    /// [`NATIVE_FRAME_FILENAME`].
+    #[must_use]
+    pub fn path(&self) -> &[u8] {
+        &self.path
+    }
+
+    /// Source line of the frame, or `0` for frames executing native methods.
+    #[must_use]
+    pub fn lineno(&self) -> i64 {
+        self.lineno
+    }
+
+    /// Name of the method being invoked, or an empty slice for top-level
+    /// frames.
+    #[must_use]
+    pub fn label(&self) -> &[u8] {
+        &self.label
+    }
+
+    /// Render the frame in the format used by exception backtraces: `` file:line:in `method' ``.
+    ///
+    /// The line segment is omitted for native frames and the method segment is
+    /// omitted for top-level frames.
+    #[must_use]
+    pub fn to_display(&self) -> Vec<u8> {
+        let mut display = self.path.clone();
+        if self.lineno > 0 {
+            display.push(b':');
+            display.extend_from_slice(self.lineno.to_string().as_bytes());
+        }
+        if !self.label.is_empty() {
+            display.extend_from_slice(b":in `");
+            display.extend_from_slice(&self.label);
+            display.push(b'\'');
+        }
+        display
+    }
+}
+
+impl Artichoke {
+    /// Capture the current call stack, innermost frame first.
+    ///
+    /// The returned frames include frames executing native methods, which
+    /// means the frame for the native method that called `call_stack`, for
+    /// example the method implementing `Kernel#caller`, is the first entry.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be accessed, an error is returned.
+    pub fn call_stack(&mut self) -> Result<Vec<Frame>, Error> {
+        let frames = unsafe {
+            self.with_ffi_boundary(|mrb| {
+                let depth = sys::mrb_sys_call_stack_depth(mrb);
+                let empty = sys::mrb_sys_stack_frame {
+                    filename: ptr::null(),
+                    lineno: -1,
+                    method: ptr::null(),
+                };
+                let mut frames = vec![empty; depth];
+                let count = sys::mrb_sys_call_stack(mrb, frames.as_mut_ptr(), frames.len());
+                frames.truncate(count);
+                // The filename and method pointers point into the irep debug
+                // info and the symbol table. Copy them into owned buffers
+                // before yielding control back to the VM.
+                frames
+                    .iter()
+                    .map(|frame| {
+                        let path = if frame.filename.is_null() {
+                            NATIVE_FRAME_FILENAME.to_vec()
+                        } else {
+                            CStr::from_ptr(frame.filename).to_bytes().to_vec()
+                        };
+                        let lineno = if frame.lineno < 0 { 0 } else { i64::from(frame.lineno) };
+                        let label = if frame.method.is_null() {
+                            Vec::new()
+                        } else {
+                            CStr::from_ptr(frame.method).to_bytes().to_vec()
+                        };
+                        Frame { path, lineno, label }
+                    })
+                    .collect::<Vec<_>>()
+            })?
+        };
+        Ok(frames)
+    }
+}
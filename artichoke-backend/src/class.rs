@@ -23,6 +23,8 @@ pub struct Builder<'a> {
     is_mrb_tt_data: bool,
     super_class: Option<NonNull<sys::RClass>>,
     methods: HashSet<method::Spec>,
+    closure_methods: Vec<method::closure::ClosureMethod>,
+    method_signatures: Vec<method::signature::MethodSignature>,
 }
 
 impl<'a> Builder<'a> {
@@ -34,6 +36,8 @@ impl<'a> Builder<'a> {
             is_mrb_tt_data: false,
             super_class: None,
             methods: HashSet::default(),
+            closure_methods: Vec::new(),
+            method_signatures: Vec::new(),
         }
     }
 
@@ -72,6 +76,51 @@ impl<'a> Builder<'a> {
         Ok(self)
     }
 
+    /// Add an instance method backed by a Rust closure.
+    ///
+    /// Unlike [`add_method`], the method implementation may capture state at
+    /// definition time, e.g. configuration resolved during interpreter init.
+    /// See [`method::closure`] for the dispatch and panic-handling semantics.
+    ///
+    /// [`add_method`]: Self::add_method
+    pub fn add_closure_method<T>(
+        mut self,
+        name: T,
+        closure: method::closure::Closure,
+        args: sys::mrb_aspec,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let method = method::closure::ClosureMethod::new(name.into(), closure, args)?;
+        self.closure_methods.push(method);
+        Ok(self)
+    }
+
+    /// Declare the parameter specification of an instance method.
+    ///
+    /// The signature is recorded in the interpreter state and is consulted by
+    /// `Method#parameters` and `Method#arity` before falling back to the
+    /// proc-derived guess. See [`method::signature`] for details.
+    ///
+    /// Declaring a signature does not define a method: the method may be
+    /// implemented in Rust, e.g. with [`add_method`], or in Ruby sources
+    /// evaluated during init.
+    ///
+    /// [`add_method`]: Self::add_method
+    pub fn add_method_signature<T>(
+        mut self,
+        name: T,
+        parameters: Vec<method::signature::Parameter>,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let signature = method::signature::MethodSignature::new(name.into(), parameters.into())?;
+        self.method_signatures.push(signature);
+        Ok(self)
+    }
+
     pub fn add_self_method<T>(
         mut self,
         name: T,
@@ -140,6 +189,22 @@ impl<'a> Builder<'a> {
             }
         }
 
+        for method in self.closure_methods {
+            let name = method.name();
+            unsafe {
+                method
+                    .define(self.interp, rclass.as_mut())
+                    .map_err(|_| NotDefinedError::method(name))?;
+            }
+        }
+
+        for signature in self.method_signatures {
+            let name = signature.name();
+            signature
+                .declare(self.interp, rclass.as_ptr())
+                .map_err(|_| NotDefinedError::method(name))?;
+        }
+
         // If a `Spec` defines a `Class` whose instances own a pointer to a
         // Rust object, mark them as `MRB_TT_DATA`.
         if self.is_mrb_tt_data {
@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+
+use crate::core::{ClassRegistry, CompareError, TryConvertMut};
+use crate::error::{Error, RubyException};
+use crate::extn::core::exception::ArgumentError;
+use crate::sys;
+use crate::Artichoke;
+
+impl RubyException for CompareError {
+    fn message(&self) -> Cow<'_, [u8]> {
+        self.to_string().into_bytes().into()
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "ArgumentError".into()
+    }
+
+    fn vm_backtrace(&self, interp: &mut Artichoke) -> Option<Vec<Vec<u8>>> {
+        let _ = interp;
+        None
+    }
+
+    fn as_mrb_value(&self, interp: &mut Artichoke) -> Option<sys::mrb_value> {
+        let message = interp.try_convert_mut(self.message()).ok()?;
+        let value = interp.new_instance::<ArgumentError>(&[message]).ok().flatten()?;
+        Some(value.inner())
+    }
+}
+
+impl From<CompareError> for Error {
+    fn from(exception: CompareError) -> Self {
+        Self::from(Box::<dyn RubyException>::from(exception))
+    }
+}
+
+impl From<Box<CompareError>> for Error {
+    fn from(exception: Box<CompareError>) -> Self {
+        Self::from(Box::<dyn RubyException>::from(exception))
+    }
+}
+
+impl From<CompareError> for Box<dyn RubyException> {
+    fn from(exception: CompareError) -> Box<dyn RubyException> {
+        Box::new(exception)
+    }
+}
+
+impl From<Box<CompareError>> for Box<dyn RubyException> {
+    fn from(exception: Box<CompareError>) -> Box<dyn RubyException> {
+        exception
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use crate::test::prelude::*;
+
+    const DISTANCE: &[u8] = b"class Distance
+  attr_reader :meters
+
+  def initialize(meters)
+    @meters = meters
+  end
+
+  def <=>(other)
+    return nil unless other.is_a?(Distance)
+
+    meters <=> other.meters
+  end
+end";
+
+    #[test]
+    fn compare_dispatches_to_a_custom_spaceship() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(DISTANCE).unwrap();
+        let one = interp.eval(b"Distance.new(1)").unwrap();
+        let two = interp.eval(b"Distance.new(2)").unwrap();
+        assert_eq!(one.compare(&mut interp, &two).unwrap(), Some(Ordering::Less));
+        assert_eq!(two.compare(&mut interp, &one).unwrap(), Some(Ordering::Greater));
+        assert_eq!(one.compare(&mut interp, &one).unwrap(), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn compare_returns_none_for_incomparable_operands() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(DISTANCE).unwrap();
+        let distance = interp.eval(b"Distance.new(1)").unwrap();
+        let other = interp.eval(b"Object.new").unwrap();
+        assert_eq!(distance.compare(&mut interp, &other).unwrap(), None);
+    }
+
+    #[test]
+    fn between_is_inclusive_of_both_bounds() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(DISTANCE).unwrap();
+        let min = interp.eval(b"Distance.new(1)").unwrap();
+        let max = interp.eval(b"Distance.new(10)").unwrap();
+        let mid = interp.eval(b"Distance.new(5)").unwrap();
+        let low = interp.eval(b"Distance.new(0)").unwrap();
+        assert!(mid.between(&mut interp, &min, &max).unwrap());
+        assert!(min.between(&mut interp, &min, &max).unwrap());
+        assert!(max.between(&mut interp, &min, &max).unwrap());
+        assert!(!low.between(&mut interp, &min, &max).unwrap());
+    }
+
+    #[test]
+    fn clamp_clamps_to_the_bounds() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(DISTANCE).unwrap();
+        let min = interp.eval(b"Distance.new(1)").unwrap();
+        let max = interp.eval(b"Distance.new(10)").unwrap();
+        let mid = interp.eval(b"Distance.new(5)").unwrap();
+        let low = interp.eval(b"Distance.new(0)").unwrap();
+        let clamped = mid.clamp(&mut interp, &min, &max).unwrap();
+        let meters = clamped.funcall(&mut interp, "meters", &[], None).unwrap();
+        assert_eq!(meters.try_convert_into::<i64>(&interp).unwrap(), 5);
+        let clamped = low.clamp(&mut interp, &min, &max).unwrap();
+        let meters = clamped.funcall(&mut interp, "meters", &[], None).unwrap();
+        assert_eq!(meters.try_convert_into::<i64>(&interp).unwrap(), 1);
+    }
+
+    #[test]
+    fn incomparable_operands_raise_argument_error() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(DISTANCE).unwrap();
+        let distance = interp.eval(b"Distance.new(1)").unwrap();
+        let min = interp.eval(b"Object.new").unwrap();
+        let max = interp.eval(b"Distance.new(10)").unwrap();
+        let err = distance.between(&mut interp, &min, &max).unwrap_err();
+        assert_eq!(err.name().as_ref(), "ArgumentError");
+        assert_eq!(err.message().as_ref(), b"comparison of Distance with Object failed");
+    }
+
+    #[test]
+    fn clamp_with_min_greater_than_max_raises_argument_error() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(DISTANCE).unwrap();
+        let distance = interp.eval(b"Distance.new(5)").unwrap();
+        let min = interp.eval(b"Distance.new(10)").unwrap();
+        let max = interp.eval(b"Distance.new(1)").unwrap();
+        let err = distance.clamp(&mut interp, &min, &max).unwrap_err();
+        assert_eq!(err.name().as_ref(), "ArgumentError");
+        assert_eq!(err.message().as_ref(), b"min argument must be smaller than max argument");
+    }
+}
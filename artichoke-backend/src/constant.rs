@@ -1,13 +1,27 @@
-use std::ffi::CString;
+use std::ffi::{c_void, CString};
 
-use crate::core::DefineConstant;
+use spinoso_symbol::IdentifierType;
+
+use crate::core::{DefineConstant, Intern};
 use crate::def::{ConstantNameError, NotDefinedError};
 use crate::error::Error;
+use crate::extn::core::exception::NameError;
 use crate::ffi::InterpreterExtractError;
 use crate::sys;
 use crate::value::Value;
 use crate::Artichoke;
 
+// Validate a constant name with `spinoso-symbol`'s identifier classifier and
+// intern it so it can be used to index the mruby constant tables.
+fn constant_name_to_sym(interp: &mut Artichoke, constant: &str) -> Result<u32, Error> {
+    if !matches!(constant.parse::<IdentifierType>(), Ok(IdentifierType::Constant)) {
+        let mut message = String::from("wrong constant name ");
+        message.push_str(constant);
+        return Err(NameError::from(message).into());
+    }
+    interp.intern_string(String::from(constant))
+}
+
 impl DefineConstant for Artichoke {
     type Value = Value;
 
@@ -82,4 +96,201 @@ impl DefineConstant for Artichoke {
             Err(NotDefinedError::module_constant(String::from(constant)).into())
         }
     }
+
+    fn get_global_constant(&mut self, constant: &str) -> Result<Option<Self::Value>, Self::Error> {
+        let sym = constant_name_to_sym(self, constant)?;
+        let value = unsafe {
+            self.with_ffi_boundary(|mrb| {
+                let scope = sys::mrb_sys_obj_value((*mrb).object_class.cast::<c_void>());
+                // `mrb_const_get` raises if the constant is not defined, so
+                // probe the constant table first to keep this API infallible
+                // for missing constants.
+                if sys::mrb_const_defined(mrb, scope, sym) == 0 {
+                    None
+                } else {
+                    Some(sys::mrb_const_get(mrb, scope, sym))
+                }
+            })?
+        };
+        Ok(value.map(Value::from))
+    }
+
+    fn get_class_constant<T>(&mut self, constant: &str) -> Result<Option<Self::Value>, Self::Error>
+    where
+        T: 'static,
+    {
+        let sym = constant_name_to_sym(self, constant)?;
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let spec = state
+            .classes
+            .get::<T>()
+            .ok_or_else(|| NotDefinedError::class_constant(String::from(constant)))?;
+        let rclass = spec.rclass();
+
+        let rclass = unsafe { self.with_ffi_boundary(|mrb| rclass.resolve(mrb))? };
+        if let Some(mut rclass) = rclass {
+            let value = unsafe {
+                self.with_ffi_boundary(|mrb| {
+                    let scope = sys::mrb_sys_class_value(rclass.as_mut());
+                    if sys::mrb_const_defined(mrb, scope, sym) == 0 {
+                        None
+                    } else {
+                        Some(sys::mrb_const_get(mrb, scope, sym))
+                    }
+                })?
+            };
+            Ok(value.map(Value::from))
+        } else {
+            Err(NotDefinedError::class_constant(String::from(constant)).into())
+        }
+    }
+
+    fn get_module_constant<T>(&mut self, constant: &str) -> Result<Option<Self::Value>, Self::Error>
+    where
+        T: 'static,
+    {
+        let sym = constant_name_to_sym(self, constant)?;
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let spec = state
+            .modules
+            .get::<T>()
+            .ok_or_else(|| NotDefinedError::module_constant(String::from(constant)))?;
+        let rclass = spec.rclass();
+
+        let rclass = unsafe { self.with_ffi_boundary(|mrb| rclass.resolve(mrb))? };
+        if let Some(mut rclass) = rclass {
+            let value = unsafe {
+                self.with_ffi_boundary(|mrb| {
+                    let scope = sys::mrb_sys_module_value(rclass.as_mut());
+                    if sys::mrb_const_defined(mrb, scope, sym) == 0 {
+                        None
+                    } else {
+                        Some(sys::mrb_const_get(mrb, scope, sym))
+                    }
+                })?
+            };
+            Ok(value.map(Value::from))
+        } else {
+            Err(NotDefinedError::module_constant(String::from(constant)).into())
+        }
+    }
+
+    fn constant_defined<T>(&mut self, constant: &str) -> Result<bool, Self::Error>
+    where
+        T: 'static,
+    {
+        let is_class = {
+            let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+            state.classes.get::<T>().is_some()
+        };
+        if is_class {
+            Ok(self.get_class_constant::<T>(constant)?.is_some())
+        } else {
+            Ok(self.get_module_constant::<T>(constant)?.is_some())
+        }
+    }
+
+    fn remove_class_constant<T>(&mut self, constant: &str) -> Result<Self::Value, Self::Error>
+    where
+        T: 'static,
+    {
+        let sym = constant_name_to_sym(self, constant)?;
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let spec = state
+            .classes
+            .get::<T>()
+            .ok_or_else(|| NotDefinedError::class_constant(String::from(constant)))?;
+        let rclass = spec.rclass();
+
+        let rclass = unsafe { self.with_ffi_boundary(|mrb| rclass.resolve(mrb))? };
+        if let Some(mut rclass) = rclass {
+            let value = unsafe {
+                self.with_ffi_boundary(|mrb| {
+                    let scope = sys::mrb_sys_class_value(rclass.as_mut());
+                    // `mrb_const_remove` raises if the constant is not defined
+                    // directly on the class, so probe the constant table first.
+                    if sys::mrb_const_defined_at(mrb, scope, sym) == 0 {
+                        None
+                    } else {
+                        let value = sys::mrb_const_get(mrb, scope, sym);
+                        sys::mrb_const_remove(mrb, scope, sym);
+                        Some(value)
+                    }
+                })?
+            };
+            if let Some(value) = value {
+                Ok(Value::from(value))
+            } else {
+                let mut message = String::from("constant ");
+                message.push_str(constant);
+                message.push_str(" not defined");
+                Err(NameError::from(message).into())
+            }
+        } else {
+            Err(NotDefinedError::class_constant(String::from(constant)).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::extn::core::array::Array;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn global_constant_round_trip() {
+        let mut interp = interpreter().unwrap();
+        let value = interp.convert(42_i64);
+        interp.define_global_constant("GET_GLOBAL_CONSTANT", value).unwrap();
+
+        let value = interp.eval(b"GET_GLOBAL_CONSTANT").unwrap();
+        assert_eq!(value.try_convert_into::<i64>(&interp).unwrap(), 42);
+
+        let value = interp.get_global_constant("GET_GLOBAL_CONSTANT").unwrap().unwrap();
+        assert_eq!(value.try_convert_into::<i64>(&interp).unwrap(), 42);
+
+        assert!(interp.get_global_constant("GET_GLOBAL_CONSTANT_MISSING").unwrap().is_none());
+    }
+
+    #[test]
+    fn class_constant_round_trip() {
+        let mut interp = interpreter().unwrap();
+        let value = interp.convert(29_i64);
+        interp.define_class_constant::<Array>("ROUND_TRIP", value).unwrap();
+
+        let value = interp.eval(b"Array::ROUND_TRIP").unwrap();
+        assert_eq!(value.try_convert_into::<i64>(&interp).unwrap(), 29);
+
+        assert!(interp.constant_defined::<Array>("ROUND_TRIP").unwrap());
+        let value = interp.get_class_constant::<Array>("ROUND_TRIP").unwrap().unwrap();
+        assert_eq!(value.try_convert_into::<i64>(&interp).unwrap(), 29);
+    }
+
+    #[test]
+    fn remove_class_constant_returns_value_and_unsets_constant() {
+        let mut interp = interpreter().unwrap();
+        let value = interp.convert(99_i64);
+        interp.define_class_constant::<Array>("REMOVED", value).unwrap();
+
+        let removed = interp.remove_class_constant::<Array>("REMOVED").unwrap();
+        assert_eq!(removed.try_convert_into::<i64>(&interp).unwrap(), 99);
+        assert!(!interp.constant_defined::<Array>("REMOVED").unwrap());
+
+        // Referencing a removed constant raises `NameError`.
+        let err = interp.eval(b"Array::REMOVED").unwrap_err();
+        assert_eq!("NameError", err.name().as_ref());
+
+        // Removing a constant that is not defined raises `NameError`.
+        let err = interp.remove_class_constant::<Array>("REMOVED").unwrap_err();
+        assert_eq!("NameError", err.name().as_ref());
+    }
+
+    #[test]
+    fn invalid_constant_names_are_rejected() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.get_global_constant("lowercase").unwrap_err();
+        assert_eq!("NameError", err.name().as_ref());
+        let err = interp.constant_defined::<Array>("not a constant").unwrap_err();
+        assert_eq!("NameError", err.name().as_ref());
+    }
 }
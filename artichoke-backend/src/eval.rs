@@ -9,7 +9,7 @@ use crate::exception_handler;
 use crate::extn::core::exception::{ArgumentError, Fatal};
 use crate::ffi::InterpreterExtractError;
 use crate::platform_string::os_str_to_bytes;
-use crate::state::parser::Context;
+use crate::state::parser::{detect_frozen_string_literal, Context};
 use crate::sys;
 use crate::sys::protect;
 use crate::value::Value;
@@ -24,9 +24,25 @@ impl Eval for Artichoke {
         trace!("Attempting eval of Ruby source");
         let result = unsafe {
             let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+            let overridden = state.frozen_string_literal;
             let parser = state.parser.as_mut().ok_or_else(InterpreterExtractError::new)?;
+            let detected = detect_frozen_string_literal(code);
+            if let Some(context) = parser.peek_context_mut() {
+                context.set_frozen_string_literal(detected);
+            }
+            // The interpreter-wide override set from the CLI takes precedence
+            // over the per-file magic comment.
+            let frozen_string_literal = overridden.or(detected).unwrap_or(false);
             let context: *mut sys::mrbc_context = parser.context_mut();
-            self.with_ffi_boundary(|mrb| protect::eval(mrb, context, code))?
+            self.with_ffi_boundary(|mrb| {
+                // `eval` can nest, for example through `require`, so the
+                // previous flag is restored instead of cleared.
+                let prev = (*mrb).frozen_string_literal;
+                (*mrb).frozen_string_literal = sys::mrb_bool::from(frozen_string_literal);
+                let result = protect::eval(mrb, context, code);
+                (*mrb).frozen_string_literal = prev;
+                result
+            })?
         };
         match result {
             Ok(value) => {
@@ -226,4 +242,83 @@ mod tests {
         let err = interp.eval(b"require 'fail'").unwrap_err();
         assert_eq!("SyntaxError", err.name().as_ref());
     }
+
+    mod frozen_string_literal {
+        use crate::test::prelude::*;
+
+        fn eval_bool(interp: &mut Artichoke, code: &[u8]) -> bool {
+            let result = interp.eval(code).unwrap();
+            result.try_convert_into::<bool>(interp).unwrap()
+        }
+
+        #[test]
+        fn literals_are_not_frozen_by_default() {
+            let mut interp = interpreter().unwrap();
+            assert!(!eval_bool(&mut interp, b"'a'.frozen?"));
+            assert!(!eval_bool(&mut interp, b"'a'.equal?('a')"));
+        }
+
+        #[test]
+        fn magic_comment_freezes_and_dedups_literals() {
+            let mut interp = interpreter().unwrap();
+            assert!(eval_bool(&mut interp, b"# frozen_string_literal: true\n'a'.frozen?"));
+            assert!(eval_bool(&mut interp, b"# frozen_string_literal: true\n'a'.equal?('a')"));
+        }
+
+        #[test]
+        fn identical_literals_are_shared_across_evals() {
+            let mut interp = interpreter().unwrap();
+            interp
+                .eval(b"# frozen_string_literal: true\n$first = 'shared'")
+                .unwrap();
+            interp
+                .eval(b"# frozen_string_literal: true\n$second = 'shared'")
+                .unwrap();
+            assert!(eval_bool(&mut interp, b"$first.equal?($second)"));
+        }
+
+        #[test]
+        fn interpreter_flag_overrides_magic_comment() {
+            let mut interp = interpreter().unwrap();
+            interp.set_frozen_string_literal(Some(true)).unwrap();
+            assert!(eval_bool(&mut interp, b"'a'.frozen?"));
+            assert!(eval_bool(&mut interp, b"# frozen_string_literal: false\n'a'.frozen?"));
+            interp.set_frozen_string_literal(Some(false)).unwrap();
+            assert!(!eval_bool(&mut interp, b"# frozen_string_literal: true\n'a'.frozen?"));
+            interp.set_frozen_string_literal(None).unwrap();
+            assert!(!eval_bool(&mut interp, b"'a'.frozen?"));
+        }
+
+        #[test]
+        fn unary_plus_returns_an_unfrozen_dup() {
+            let mut interp = interpreter().unwrap();
+            let code = b"# frozen_string_literal: true\ns = +'a'\n!s.frozen? && !s.equal?('a')";
+            assert!(eval_bool(&mut interp, code));
+        }
+
+        #[test]
+        fn interpolated_strings_are_not_frozen() {
+            let mut interp = interpreter().unwrap();
+            let code = b"# frozen_string_literal: true\nx = 'b'\n!\"a#{x}\".frozen?";
+            assert!(eval_bool(&mut interp, code));
+        }
+
+        #[test]
+        fn mutating_a_frozen_literal_names_the_string() {
+            let mut interp = interpreter().unwrap();
+            let code = b"# frozen_string_literal: true\nbegin\n  'a' << 'b'\n  false\nrescue FrozenError => e\n  e.message == %q[can't modify frozen String: \"a\"]\nend";
+            assert!(eval_bool(&mut interp, code));
+        }
+
+        #[test]
+        fn magic_comment_is_recorded_on_the_context() {
+            let mut interp = interpreter().unwrap();
+            let context = Context::new(&b"frozen.rb"[..]).unwrap();
+            interp.push_context(context).unwrap();
+            interp.eval(b"# frozen_string_literal: true\n'a'").unwrap();
+            let context = interp.peek_context().unwrap().unwrap();
+            assert_eq!(context.frozen_string_literal(), Some(true));
+            interp.pop_context().unwrap();
+        }
+    }
 }
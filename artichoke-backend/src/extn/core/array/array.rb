@@ -777,40 +777,6 @@ class Array
     self[0, len] = ar if modified
   end
 
-  def include?(object)
-    idx = 0
-    len = length
-    while idx < len
-      return true if self[idx] == object
-
-      idx += 1
-    end
-    false
-  end
-
-  def index(val = (not_set = true), &block)
-    return to_enum(:index) if !block && not_set # rubocop:disable Lint/ToEnumArguments
-
-    idx = 0
-    if not_set
-      while idx < length
-        return idx if block.call(self[idx])
-
-        idx += 1
-      end
-    else
-      warn('warning: given block not used') if block
-
-      len = length
-      while idx < len
-        return idx if self[idx] == val
-
-        idx += 1
-      end
-    end
-    nil
-  end
-
   def insert(idx, *args)
     idx += size + 1 if idx.negative?
 
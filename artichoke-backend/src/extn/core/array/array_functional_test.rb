@@ -25,8 +25,20 @@ def spec
   inline_pop
   dynamic_pop
 
+  empty_shift
+  inline_shift
+  dynamic_shift
+  unshift_args
+
   reverse
 
+  include_and_index_immediates
+  include_and_index_heap_elements
+  include_and_index_custom_equality
+  index_with_block
+
+  deconstruct
+
   true
 end
 
@@ -405,6 +417,58 @@ def dynamic_pop
   raise unless a == [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
 end
 
+def empty_shift
+  a = []
+  raise unless a.shift.nil?
+  raise unless a.shift(3) == []
+  raise unless a == []
+end
+
+def inline_shift
+  a = [1, 2, 3]
+  r = a.shift
+  raise unless r == 1
+  raise unless a == [2, 3]
+
+  a = [1, 2, 3, 4, 5, 6, 7, 8]
+  r = a.shift(3)
+  raise unless r == [1, 2, 3]
+  raise unless a == [4, 5, 6, 7, 8]
+
+  r = a.shift(100)
+  raise unless r == [4, 5, 6, 7, 8]
+  raise unless a == []
+end
+
+def dynamic_shift
+  a = (1..25).map.to_a
+  r = a.shift
+  raise unless r == 1
+  raise unless a == (2..25).map.to_a
+
+  r = a.shift(10)
+  raise unless r == (2..11).map.to_a
+  raise unless a == (12..25).map.to_a
+end
+
+def unshift_args
+  a = [4, 5]
+  r = a.unshift(3)
+  raise unless r.equal?(a)
+  raise unless a == [3, 4, 5]
+
+  a.unshift(1, 2)
+  raise unless a == [1, 2, 3, 4, 5]
+
+  a = (10..25).map.to_a
+  a.unshift(1, 2, 3)
+  raise unless a == [1, 2, 3] + (10..25).map.to_a
+
+  a = []
+  a.unshift
+  raise unless a == []
+end
+
 def reverse
   a = []
   a.reverse!
@@ -423,4 +487,67 @@ def reverse
   raise unless a == [10, 9, 8, 7, 6, 5, 4, 3, 2, 1]
 end
 
+def include_and_index_immediates
+  a = [1, 2, :sym, nil, false, true, 3]
+  raise unless a.include?(2)
+  raise unless a.index(2) == 1
+  raise unless a.include?(:sym)
+  raise unless a.index(:sym) == 2
+  raise unless a.include?(nil)
+  raise unless a.index(nil) == 3
+  raise unless a.include?(false)
+  raise unless a.index(false) == 4
+  raise unless a.include?(true)
+  raise unless a.index(true) == 5
+  raise if a.include?(99)
+  raise unless a.index(99).nil?
+  raise if a.include?(:other)
+  raise unless a.index(:other).nil?
+end
+
+def include_and_index_heap_elements
+  a = [1.0, 2.0, 'str', 42]
+  raise unless a.include?(1)
+  raise unless a.index(2) == 1
+  raise unless a.include?('str')
+  raise unless a.index('str') == 2
+  raise unless a.include?(42.0)
+  raise unless a.index(42) == 3
+end
+
+def include_and_index_custom_equality
+  always42 = Class.new do
+    def ==(other)
+      other == 42
+    end
+  end
+  obj = always42.new
+  a = [1, obj, 3]
+  raise unless a.include?(42)
+  raise unless a.index(42) == 1
+  raise if a.include?(99)
+  raise unless a.index(99).nil?
+
+  truthy = Class.new do
+    def ==(_other)
+      :always
+    end
+  end
+  b = [0, truthy.new]
+  raise unless b.include?(:anything)
+  raise unless b.index(:anything) == 1
+end
+
+def index_with_block
+  a = [10, 20, 30]
+  raise unless a.index { |x| x > 15 } == 1
+  raise unless a.index { |x| x > 100 }.nil?
+end
+
+def deconstruct
+  ary = [1, 2, 3]
+  raise unless ary.deconstruct.equal?(ary)
+  raise unless [].deconstruct == []
+end
+
 spec if $PROGRAM_NAME == __FILE__
@@ -9,6 +9,7 @@ use crate::io::IoError;
 pub mod args;
 mod ffi;
 pub mod mruby;
+pub mod pack;
 pub mod trampoline;
 mod wrapper;
 
@@ -342,6 +343,7 @@ mod tests {
 
     const SUBJECT: &str = "Array";
     const FUNCTIONAL_TEST: &[u8] = include_bytes!("array_functional_test.rb");
+    const PACK_FUNCTIONAL_TEST: &[u8] = include_bytes!("pack_test.rb");
 
     #[test]
     fn functional() {
@@ -351,4 +353,93 @@ mod tests {
         let result = interp.eval(b"spec");
         unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
     }
+
+    #[test]
+    fn pack_functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(PACK_FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+    }
+
+    fn pack_result_encoding(interp: &mut Artichoke, code: &[u8]) -> spinoso_string::Encoding {
+        let mut result = interp.eval(code).unwrap();
+        let s = unsafe { spinoso_string::String::unbox_from_value(&mut result, interp) }.unwrap();
+        s.encoding()
+    }
+
+    // Ruby-level `String#encoding` is stubbed to always return UTF-8, so the
+    // result encoding is asserted by unboxing the packed string.
+    #[test]
+    fn pack_result_encoding_follows_mri_rules() {
+        let mut interp = interpreter().unwrap();
+        assert_eq!(
+            pack_result_encoding(&mut interp, b"[].pack('')"),
+            spinoso_string::Encoding::Ascii
+        );
+        assert_eq!(
+            pack_result_encoding(&mut interp, b"[0x20AC].pack('U')"),
+            spinoso_string::Encoding::Utf8
+        );
+        assert_eq!(
+            pack_result_encoding(&mut interp, b"[65, 0x20AC].pack('CU')"),
+            spinoso_string::Encoding::Binary
+        );
+        assert_eq!(
+            pack_result_encoding(&mut interp, b"['abc'].pack('a*')"),
+            spinoso_string::Encoding::Binary
+        );
+    }
+
+    /// Assert that every [`Value`] held by the given `Array` — recursing into
+    /// nested `Array`s — is still live on the interpreter heap.
+    #[cfg(debug_assertions)]
+    fn assert_children_live(interp: &mut Artichoke, value: &mut Value) {
+        let unboxed = unsafe { super::Array::unbox_from_value(value, interp) }.unwrap();
+        let children = unboxed.debug_children();
+        drop(unboxed);
+        for mut child in children {
+            assert!(
+                !child.is_dead(interp),
+                "child of Array was collected while still reachable"
+            );
+            if child.ruby_type() == Ruby::Array {
+                assert_children_live(interp, &mut child);
+            }
+        }
+    }
+
+    /// GC stress harness: evaluate each mutation with an incremental GC in
+    /// between and assert all `Array` children remain live, then do the same
+    /// after a final full GC.
+    #[cfg(debug_assertions)]
+    fn gc_stress_check(interp: &mut Artichoke, setup: &[u8], mutations: &[&[u8]]) {
+        let mut ary = interp.eval(setup).unwrap();
+        for &mutation in mutations {
+            interp.eval(mutation).unwrap();
+            interp.incremental_gc().unwrap();
+            assert_children_live(interp, &mut ary);
+        }
+        interp.full_gc().unwrap();
+        assert_children_live(interp, &mut ary);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn gc_stress_keeps_array_children_live() {
+        let mut interp = interpreter().unwrap();
+        gc_stress_check(
+            &mut interp,
+            b"$a = [[1, 'two', :three], ['nested', ['deeply', ['deeper']]]]",
+            &[
+                b"$a << 'pushed'".as_slice(),
+                b"$a[0] << ('concat' + 'enated')",
+                b"$a.unshift('shifted in')",
+                b"$a[1] = ['replacement', ['elements']]",
+                b"256.times { |i| $a << \"element #{i}\" }",
+                b"$a.shift",
+            ],
+        );
+    }
 }
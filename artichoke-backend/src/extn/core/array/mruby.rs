@@ -2,6 +2,7 @@ use std::ffi::CStr;
 
 use crate::extn::core::array::{trampoline, Array};
 use crate::extn::prelude::*;
+use crate::method::signature::Parameter;
 
 const ARRAY_CSTR: &CStr = cstr::cstr!("Array");
 
@@ -10,6 +11,11 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         return Ok(());
     }
     let spec = class::Spec::new("Array", ARRAY_CSTR, None, Some(def::box_unbox_free::<Array>))?;
+    // Preserve the `Array#pack` implementation from the vendored mruby-pack
+    // gem under a private name. The Rust implementation in the `pack` module
+    // delegates to it for directives outside the supported subset. The alias
+    // must be taken before the builder below replaces `pack`.
+    interp.eval(&b"class Array; alias_method :__pack_with_mruby_pack, :pack; private :__pack_with_mruby_pack; end"[..])?;
     class::Builder::for_spec(interp, &spec)
         .add_self_method("[]", ary_cls_constructor, sys::mrb_args_rest())?
         .add_method("+", ary_plus, sys::mrb_args_req(1))?
@@ -18,7 +24,10 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("[]=", ary_element_assignment, sys::mrb_args_req_and_opt(2, 1))?
         .add_method("clear", ary_clear, sys::mrb_args_none())?
         .add_method("concat", ary_concat, sys::mrb_args_rest())?
+        .add_method("deconstruct", ary_deconstruct, sys::mrb_args_none())?
         .add_method("first", ary_first, sys::mrb_args_opt(1))?
+        .add_method("include?", ary_include, sys::mrb_args_req(1))?
+        .add_method("index", ary_index, sys::mrb_args_opt(1) | sys::mrb_args_block())?
         .add_method(
             "initialize",
             ary_initialize,
@@ -27,7 +36,10 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("initialize_copy", ary_initialize_copy, sys::mrb_args_req(1))?
         .add_method("last", ary_last, sys::mrb_args_opt(1))?
         .add_method("length", ary_len, sys::mrb_args_none())?
+        .add_method("pack", ary_pack, sys::mrb_args_req(1))?
         .add_method("pop", ary_pop, sys::mrb_args_none())?
+        // `Array#push` is implemented in Ruby in `array.rb`.
+        .add_method_signature("push", vec![Parameter::Rest("args")])?
         .add_method("reverse", ary_reverse, sys::mrb_args_none())?
         .add_method("reverse!", ary_reverse_bang, sys::mrb_args_none())?
         .add_method("shift", ary_shift, sys::mrb_args_opt(1))?
@@ -153,6 +165,17 @@ unsafe extern "C" fn ary_concat(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -
     }
 }
 
+unsafe extern "C" fn ary_deconstruct(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let array = Value::from(ary);
+    let result = trampoline::deconstruct(&mut guard, array);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn ary_first(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
     let num = mrb_get_args!(mrb, optional = 1);
     unwrap_interpreter!(mrb, to => guard);
@@ -169,6 +192,30 @@ unsafe extern "C" fn ary_first(mrb: *mut sys::mrb_state, ary: sys::mrb_value) ->
     }
 }
 
+unsafe extern "C" fn ary_include(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let needle = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let array = Value::from(ary);
+    let needle = Value::from(needle);
+    let result = trampoline::include(&mut guard, array, needle);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn ary_index(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let (needle, block) = mrb_get_args!(mrb, optional = 1, &block);
+    unwrap_interpreter!(mrb, to => guard);
+    let array = Value::from(ary);
+    let needle = needle.map(Value::from);
+    let result = trampoline::index(&mut guard, array, needle, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn ary_initialize(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
     let (first, second, block) = mrb_get_args!(mrb, optional = 2, &block);
     unwrap_interpreter!(mrb, to => guard);
@@ -238,6 +285,18 @@ unsafe extern "C" fn ary_len(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> s
     }
 }
 
+unsafe extern "C" fn ary_pack(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
+    let template = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let array = Value::from(ary);
+    let template = Value::from(template);
+    let result = trampoline::pack(&mut guard, array, template);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn ary_pop(mrb: *mut sys::mrb_state, ary: sys::mrb_value) -> sys::mrb_value {
     mrb_get_args!(mrb, none);
     unwrap_interpreter!(mrb, to => guard);
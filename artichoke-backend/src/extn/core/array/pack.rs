@@ -0,0 +1,512 @@
+//! Rust implementation of `Array#pack` for a core subset of directives.
+//!
+//! The encoder supports the integer directives `C c S s L l Q q n N v V`
+//! (with `<`, `>`, and `!` modifiers where MRI permits them), the string
+//! directives `a A Z`, UTF-8 codepoints with `U`, and the positioning
+//! directives `x X @`. Templates containing any other directive are reported
+//! as [`TemplateError::Unsupported`] so the caller can fall back to the C
+//! implementation in the vendored mruby-pack gem.
+
+use std::mem::size_of;
+use std::os::raw::c_long;
+
+use spinoso_string::Encoding;
+
+use crate::convert::{implicitly_convert_to_int, implicitly_convert_to_string};
+use crate::extn::prelude::*;
+
+/// Byte order of an integer directive.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ByteOrder {
+    Little,
+    Big,
+}
+
+impl ByteOrder {
+    const NATIVE: Self = if cfg!(target_endian = "big") {
+        Self::Big
+    } else {
+        Self::Little
+    };
+}
+
+/// A single directive parsed from a pack template.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Directive {
+    /// `C c S s L l Q q n N v V`: an integer truncated to `bytes` bytes.
+    ///
+    /// Signed and unsigned variants produce the same two's complement bit
+    /// patterns, so the encoder does not distinguish them.
+    Integer { bytes: usize, order: ByteOrder },
+    /// `U`: a UTF-8 encoded codepoint.
+    Utf8,
+    /// `a A Z`: a byte string truncated or padded to the directive length.
+    String { padding: u8, append_nul: bool },
+    /// `x`: a NUL byte.
+    Null,
+    /// `X`: back up a byte.
+    Back,
+    /// `@`: move to an absolute offset.
+    Position,
+}
+
+/// Repeat count of a directive.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Count {
+    /// An explicit length, or 1 if the template gives no length.
+    Exact(usize),
+    /// `*`: all remaining elements, or the whole string for `a A Z`.
+    Star,
+}
+
+/// A directive together with its repeat count.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Entry {
+    pub directive: Directive,
+    pub count: Count,
+}
+
+/// Errors encountered while parsing a pack template.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum TemplateError {
+    /// The template contains a directive outside the supported subset.
+    ///
+    /// The caller should fall back to the C implementation in the vendored
+    /// mruby-pack gem.
+    Unsupported(u8),
+    /// A directive length does not fit in memory.
+    LengthTooBig,
+    /// A `<`, `>`, `!`, or `_` modifier follows a directive that does not
+    /// take one.
+    InvalidModifier(u8),
+    /// A directive has both `<` and `>` modifiers.
+    ConflictingByteOrder,
+}
+
+/// Parse a pack template into directives.
+///
+/// # Errors
+///
+/// If the template contains a directive outside the supported subset, has a
+/// length that does not fit in memory, or misuses a modifier, an error is
+/// returned.
+pub fn parse_template(template: &[u8]) -> Result<Vec<Entry>, TemplateError> {
+    let mut entries = Vec::new();
+    let mut idx = 0;
+    while let Some(&byte) = template.get(idx) {
+        idx += 1;
+        match byte {
+            // MRI ignores whitespace and NUL bytes between directives and
+            // skips comments through the end of the line.
+            b'\0' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r' | b' ' => continue,
+            b'#' => {
+                while let Some(&byte) = template.get(idx) {
+                    idx += 1;
+                    if byte == b'\n' {
+                        break;
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+        let mut directive = match byte {
+            b'C' | b'c' => Directive::Integer {
+                bytes: 1,
+                order: ByteOrder::NATIVE,
+            },
+            b'S' | b's' => Directive::Integer {
+                bytes: 2,
+                order: ByteOrder::NATIVE,
+            },
+            b'L' | b'l' => Directive::Integer {
+                bytes: 4,
+                order: ByteOrder::NATIVE,
+            },
+            b'Q' | b'q' => Directive::Integer {
+                bytes: 8,
+                order: ByteOrder::NATIVE,
+            },
+            b'n' => Directive::Integer {
+                bytes: 2,
+                order: ByteOrder::Big,
+            },
+            b'N' => Directive::Integer {
+                bytes: 4,
+                order: ByteOrder::Big,
+            },
+            b'v' => Directive::Integer {
+                bytes: 2,
+                order: ByteOrder::Little,
+            },
+            b'V' => Directive::Integer {
+                bytes: 4,
+                order: ByteOrder::Little,
+            },
+            b'U' => Directive::Utf8,
+            b'a' => Directive::String {
+                padding: b'\0',
+                append_nul: false,
+            },
+            b'A' => Directive::String {
+                padding: b' ',
+                append_nul: false,
+            },
+            b'Z' => Directive::String {
+                padding: b'\0',
+                append_nul: true,
+            },
+            b'x' => Directive::Null,
+            b'X' => Directive::Back,
+            b'@' => Directive::Position,
+            unsupported => return Err(TemplateError::Unsupported(unsupported)),
+        };
+        // `<`, `>`, `!`, and `_` modifiers are only permitted on the
+        // native-size integer directives, as in MRI.
+        let modifiable = matches!(byte, b'S' | b's' | b'L' | b'l' | b'Q' | b'q');
+        let mut explicit_order = None;
+        while let Some(&modifier) = template.get(idx) {
+            if !matches!(modifier, b'<' | b'>' | b'!' | b'_') {
+                break;
+            }
+            idx += 1;
+            if !modifiable {
+                return Err(TemplateError::InvalidModifier(modifier));
+            }
+            match modifier {
+                b'<' | b'>' => {
+                    let order = if modifier == b'<' {
+                        ByteOrder::Little
+                    } else {
+                        ByteOrder::Big
+                    };
+                    if matches!(explicit_order, Some(existing) if existing != order) {
+                        return Err(TemplateError::ConflictingByteOrder);
+                    }
+                    explicit_order = Some(order);
+                    if let Directive::Integer { order: dir_order, .. } = &mut directive {
+                        *dir_order = order;
+                    }
+                }
+                // `!` and `_` request native sizes. `s S q Q` already have
+                // native sizes; `l L` widen to the platform `long`.
+                _ => {
+                    if let (b'L' | b'l', Directive::Integer { bytes, .. }) = (byte, &mut directive) {
+                        *bytes = size_of::<c_long>();
+                    }
+                }
+            }
+        }
+        let count = match template.get(idx) {
+            Some(b'*') => {
+                idx += 1;
+                // As in MRI, `*` on the positioning directives means a zero
+                // length.
+                if matches!(directive, Directive::Null | Directive::Back | Directive::Position) {
+                    Count::Exact(0)
+                } else {
+                    Count::Star
+                }
+            }
+            Some(digit) if digit.is_ascii_digit() => {
+                let mut length = 0_usize;
+                while let Some(&digit) = template.get(idx) {
+                    if !digit.is_ascii_digit() {
+                        break;
+                    }
+                    idx += 1;
+                    length = length
+                        .checked_mul(10)
+                        .and_then(|length| length.checked_add(usize::from(digit - b'0')))
+                        .ok_or(TemplateError::LengthTooBig)?;
+                }
+                Count::Exact(length)
+            }
+            _ => Count::Exact(1),
+        };
+        entries.push(Entry { directive, count });
+    }
+    Ok(entries)
+}
+
+/// Determine the encoding of the packed string, following the rules MRI uses.
+///
+/// The result is US-ASCII for an empty template, UTF-8 if the template only
+/// contains `U` directives, and binary otherwise.
+#[must_use]
+pub fn result_encoding(entries: &[Entry]) -> Encoding {
+    let mut encoding = Encoding::Ascii;
+    for entry in entries {
+        match entry.directive {
+            Directive::Utf8 => {
+                if encoding == Encoding::Ascii {
+                    encoding = Encoding::Utf8;
+                }
+            }
+            _ => encoding = Encoding::Binary,
+        }
+    }
+    encoding
+}
+
+/// Encode the given items into a byte buffer according to the parsed
+/// template.
+///
+/// # Errors
+///
+/// If the template consumes more items than the array holds, an
+/// `ArgumentError` is returned.
+///
+/// If a `U` directive receives a value outside the Unicode codepoint range or
+/// an `X` directive backs up past the start of the buffer, errors are
+/// returned matching MRI's.
+pub fn pack(interp: &mut Artichoke, entries: &[Entry], items: &[Value]) -> Result<Vec<u8>, Error> {
+    let mut buf = Vec::new();
+    let mut idx = 0;
+    let mut next_item = |idx: &mut usize| -> Result<Value, Error> {
+        let item = items
+            .get(*idx)
+            .copied()
+            .ok_or_else(|| ArgumentError::with_message("too few arguments"))?;
+        *idx += 1;
+        Ok(item)
+    };
+    for entry in entries {
+        match entry.directive {
+            Directive::Integer { bytes, order } => {
+                let repeat = match entry.count {
+                    Count::Exact(repeat) => repeat,
+                    Count::Star => items.len().saturating_sub(idx),
+                };
+                for _ in 0..repeat {
+                    let item = next_item(&mut idx)?;
+                    let int = implicitly_convert_to_int(interp, item)?;
+                    match order {
+                        ByteOrder::Little => buf.extend_from_slice(&int.to_le_bytes()[..bytes]),
+                        ByteOrder::Big => buf.extend_from_slice(&int.to_be_bytes()[8 - bytes..]),
+                    }
+                }
+            }
+            Directive::Utf8 => {
+                let repeat = match entry.count {
+                    Count::Exact(repeat) => repeat,
+                    Count::Star => items.len().saturating_sub(idx),
+                };
+                for _ in 0..repeat {
+                    let item = next_item(&mut idx)?;
+                    let codepoint = implicitly_convert_to_int(interp, item)?;
+                    let codepoint = u32::try_from(codepoint)
+                        .ok()
+                        .filter(|&codepoint| codepoint <= 0x10_FFFF)
+                        .ok_or_else(|| RangeError::with_message("pack(U): value out of range"))?;
+                    encode_utf8_into(codepoint, &mut buf);
+                }
+            }
+            Directive::String { padding, append_nul } => {
+                let mut item = next_item(&mut idx)?;
+                // Safety:
+                //
+                // The bytes are copied out of the `RString` before any
+                // intervening interpreter operation can trigger a garbage
+                // collection.
+                let string = unsafe { implicitly_convert_to_string(interp, &mut item)? }.to_vec();
+                match entry.count {
+                    Count::Star => {
+                        buf.extend_from_slice(&string);
+                        if append_nul {
+                            buf.push(b'\0');
+                        }
+                    }
+                    Count::Exact(width) => {
+                        if let Some(prefix) = string.get(..width) {
+                            buf.extend_from_slice(prefix);
+                        } else {
+                            buf.extend_from_slice(&string);
+                            buf.resize(buf.len() + (width - string.len()), padding);
+                        }
+                    }
+                }
+            }
+            Directive::Null => {
+                if let Count::Exact(len) = entry.count {
+                    buf.resize(buf.len() + len, b'\0');
+                }
+            }
+            Directive::Back => {
+                if let Count::Exact(len) = entry.count {
+                    let truncated = buf
+                        .len()
+                        .checked_sub(len)
+                        .ok_or_else(|| ArgumentError::with_message("X outside of string"))?;
+                    buf.truncate(truncated);
+                }
+            }
+            Directive::Position => {
+                if let Count::Exact(position) = entry.count {
+                    if position <= buf.len() {
+                        buf.truncate(position);
+                    } else {
+                        buf.resize(position, b'\0');
+                    }
+                }
+            }
+        }
+    }
+    Ok(buf)
+}
+
+/// Encode a Unicode codepoint as UTF-8.
+///
+/// `char::encode_utf8` cannot be used because MRI permits packing surrogate
+/// codepoints.
+#[allow(clippy::cast_possible_truncation)]
+fn encode_utf8_into(codepoint: u32, buf: &mut Vec<u8>) {
+    match codepoint {
+        0..=0x7F => buf.push(codepoint as u8),
+        0x80..=0x7FF => {
+            buf.push(0xC0 | (codepoint >> 6) as u8);
+            buf.push(0x80 | (codepoint & 0x3F) as u8);
+        }
+        0x800..=0xFFFF => {
+            buf.push(0xE0 | (codepoint >> 12) as u8);
+            buf.push(0x80 | ((codepoint >> 6) & 0x3F) as u8);
+            buf.push(0x80 | (codepoint & 0x3F) as u8);
+        }
+        _ => {
+            buf.push(0xF0 | (codepoint >> 18) as u8);
+            buf.push(0x80 | ((codepoint >> 12) & 0x3F) as u8);
+            buf.push(0x80 | ((codepoint >> 6) & 0x3F) as u8);
+            buf.push(0x80 | (codepoint & 0x3F) as u8);
+        }
+    }
+}
+
+impl TemplateError {
+    /// Error message for raising this error as an `ArgumentError` or
+    /// `RangeError`.
+    #[must_use]
+    pub fn message(self) -> String {
+        match self {
+            Self::Unsupported(directive) => format!("unsupported pack directive '{}'", char::from(directive)),
+            Self::LengthTooBig => String::from("pack length too big"),
+            Self::InvalidModifier(modifier) => {
+                format!("'{}' allowed only after types sSiIlLqQjJ", char::from(modifier))
+            }
+            Self::ConflictingByteOrder => String::from("can't use both '<' and '>'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_template, result_encoding, ByteOrder, Count, Directive, Entry, TemplateError};
+    use spinoso_string::Encoding;
+
+    #[test]
+    fn directive_without_length_defaults_to_one() {
+        let entries = parse_template(b"C").unwrap();
+        assert_eq!(
+            entries,
+            [Entry {
+                directive: Directive::Integer {
+                    bytes: 1,
+                    order: ByteOrder::NATIVE
+                },
+                count: Count::Exact(1)
+            }]
+        );
+    }
+
+    #[test]
+    fn explicit_length_is_parsed() {
+        let entries = parse_template(b"a10C3").unwrap();
+        assert_eq!(entries[0].count, Count::Exact(10));
+        assert_eq!(entries[1].count, Count::Exact(3));
+    }
+
+    #[test]
+    fn star_length_is_parsed() {
+        let entries = parse_template(b"C*a*").unwrap();
+        assert_eq!(entries[0].count, Count::Star);
+        assert_eq!(entries[1].count, Count::Star);
+    }
+
+    #[test]
+    fn star_length_on_positioning_directives_is_zero() {
+        let entries = parse_template(b"x*X*@*").unwrap();
+        assert_eq!(entries[0].count, Count::Exact(0));
+        assert_eq!(entries[1].count, Count::Exact(0));
+        assert_eq!(entries[2].count, Count::Exact(0));
+    }
+
+    #[test]
+    fn whitespace_and_comments_are_skipped() {
+        let entries = parse_template(b"C # codepoint\n  N").unwrap();
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn overlong_length_is_an_error() {
+        let err = parse_template(b"a99999999999999999999999999").unwrap_err();
+        assert_eq!(err, TemplateError::LengthTooBig);
+    }
+
+    #[test]
+    fn endianness_modifiers_override_native_order() {
+        let entries = parse_template(b"S>L<").unwrap();
+        assert_eq!(
+            entries[0].directive,
+            Directive::Integer {
+                bytes: 2,
+                order: ByteOrder::Big
+            }
+        );
+        assert_eq!(
+            entries[1].directive,
+            Directive::Integer {
+                bytes: 4,
+                order: ByteOrder::Little
+            }
+        );
+    }
+
+    #[test]
+    fn bang_modifier_widens_long_to_native_size() {
+        let entries = parse_template(b"l!>").unwrap();
+        assert_eq!(
+            entries[0].directive,
+            Directive::Integer {
+                bytes: std::mem::size_of::<std::os::raw::c_long>(),
+                order: ByteOrder::Big
+            }
+        );
+    }
+
+    #[test]
+    fn modifiers_are_rejected_on_fixed_order_directives() {
+        let err = parse_template(b"n<").unwrap_err();
+        assert_eq!(err, TemplateError::InvalidModifier(b'<'));
+        let err = parse_template(b"a!").unwrap_err();
+        assert_eq!(err, TemplateError::InvalidModifier(b'!'));
+    }
+
+    #[test]
+    fn conflicting_byte_orders_are_rejected() {
+        let err = parse_template(b"s<>").unwrap_err();
+        assert_eq!(err, TemplateError::ConflictingByteOrder);
+    }
+
+    #[test]
+    fn unsupported_directives_are_reported_for_fallback() {
+        let err = parse_template(b"C3m").unwrap_err();
+        assert_eq!(err, TemplateError::Unsupported(b'm'));
+    }
+
+    #[test]
+    fn result_encoding_follows_mri_rules() {
+        assert_eq!(result_encoding(&parse_template(b"").unwrap()), Encoding::Ascii);
+        assert_eq!(result_encoding(&parse_template(b"U2").unwrap()), Encoding::Utf8);
+        assert_eq!(result_encoding(&parse_template(b"UC").unwrap()), Encoding::Binary);
+        assert_eq!(result_encoding(&parse_template(b"a*").unwrap()), Encoding::Binary);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::convert::{implicitly_convert_to_int, implicitly_convert_to_string};
-use crate::extn::core::array::Array;
+use crate::extn::core::array::{pack, Array};
+use crate::extn::core::symbol::Symbol;
 use crate::extn::prelude::*;
 
 pub fn plus(interp: &mut Artichoke, mut ary: Value, mut other: Value) -> Result<Value, Error> {
@@ -184,6 +185,12 @@ where
     Ok(ary)
 }
 
+pub fn deconstruct(_interp: &mut Artichoke, ary: Value) -> Result<Value, Error> {
+    // `Array#deconstruct` is part of the Ruby 3 pattern matching protocol and
+    // returns the receiver without copying.
+    Ok(ary)
+}
+
 pub fn first(interp: &mut Artichoke, mut ary: Value, num: Option<Value>) -> Result<Value, Error> {
     let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
     if let Some(num) = num {
@@ -205,6 +212,132 @@ pub fn first(interp: &mut Artichoke, mut ary: Value, num: Option<Value>) -> Resu
     }
 }
 
+pub fn find_first(interp: &mut Artichoke, mut ary: Value, needle: Value) -> Result<Option<usize>, Error> {
+    // `nil`, `false`, `true`, `Integer`, and `Symbol` needles are immediate
+    // values whose `==` on primitive receivers reduces to comparing the
+    // underlying `mrb_value` payloads. When both the needle and the element
+    // under test are immediates, equality can be decided without dispatching
+    // `==`, which avoids a funcall per element on large arrays of primitives.
+    //
+    // `Float` and heap object elements must still dispatch `==` -- `1 == 1.0`
+    // is true and arbitrary objects can redefine `==` -- so only elements
+    // which are themselves immediates take the fast path.
+    let needle_is_immediate = is_immediate(needle);
+    let mut idx = 0_usize;
+    loop {
+        // Re-unbox the array on each iteration because the `==` funcall below
+        // can run arbitrary Ruby code which may mutate or reallocate the
+        // receiver.
+        let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+        let element = if let Some(element) = array.get(idx) {
+            element
+        } else {
+            return Ok(None);
+        };
+        if needle_is_immediate && is_immediate(element) {
+            if immediate_eq(element, needle) {
+                return Ok(Some(idx));
+            }
+        } else {
+            let result = element.funcall(interp, "==", &[needle], None)?;
+            // any falsy returned value yields `false`, otherwise `true`.
+            let eql = if let Ok(result) = TryConvert::<_, Option<bool>>::try_convert(interp, result) {
+                result.unwrap_or_default()
+            } else {
+                true
+            };
+            if eql {
+                return Ok(Some(idx));
+            }
+        }
+        idx += 1;
+    }
+}
+
+fn is_immediate(value: Value) -> bool {
+    matches!(
+        value.ruby_type(),
+        Ruby::Nil | Ruby::Bool | Ruby::Fixnum | Ruby::Symbol
+    )
+}
+
+fn immediate_eq(left: Value, right: Value) -> bool {
+    match (left.ruby_type(), right.ruby_type()) {
+        (Ruby::Nil, Ruby::Nil) => true,
+        (Ruby::Bool, Ruby::Bool) => unsafe {
+            sys::mrb_sys_value_is_true(left.inner()) == sys::mrb_sys_value_is_true(right.inner())
+        },
+        (Ruby::Fixnum, Ruby::Fixnum) => unsafe {
+            sys::mrb_sys_fixnum_to_cint(left.inner()) == sys::mrb_sys_fixnum_to_cint(right.inner())
+        },
+        // Safety:
+        //
+        // The `Ruby::Symbol` type checks ensure both `value` unions hold a
+        // `u32` in the `sym` variant.
+        (Ruby::Symbol, Ruby::Symbol) => unsafe { left.inner().value.sym == right.inner().value.sym },
+        _ => false,
+    }
+}
+
+pub fn include(interp: &mut Artichoke, ary: Value, needle: Value) -> Result<Value, Error> {
+    let contains = find_first(interp, ary, needle)?.is_some();
+    Ok(interp.convert(contains))
+}
+
+pub fn index(
+    interp: &mut Artichoke,
+    mut ary: Value,
+    needle: Option<Value>,
+    block: Option<Block>,
+) -> Result<Value, Error> {
+    match (needle, block) {
+        (Some(needle), block) => {
+            if block.is_some() {
+                interp.warn(b"warning: given block not used")?;
+            }
+            if let Some(index) = find_first(interp, ary, needle)? {
+                let index = i64::try_from(index)
+                    .map_err(|_| Fatal::from("Array index does not fit in mruby Integer max"))?;
+                Ok(interp.convert(index))
+            } else {
+                Ok(Value::nil())
+            }
+        }
+        (None, Some(block)) => {
+            let mut idx = 0_usize;
+            loop {
+                // Re-unbox the array on each iteration because the block can
+                // run arbitrary Ruby code which may mutate or reallocate the
+                // receiver.
+                let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+                let element = if let Some(element) = array.get(idx) {
+                    element
+                } else {
+                    return Ok(Value::nil());
+                };
+                let result = block.yield_arg(interp, &element)?;
+                // any falsy returned value yields `false`, otherwise `true`.
+                let found = if let Ok(result) = TryConvert::<_, Option<bool>>::try_convert(interp, result) {
+                    result.unwrap_or_default()
+                } else {
+                    true
+                };
+                if found {
+                    let index = i64::try_from(idx)
+                        .map_err(|_| Fatal::from("Array index does not fit in mruby Integer max"))?;
+                    return Ok(interp.convert(index));
+                }
+                idx += 1;
+            }
+        }
+        (None, None) => {
+            let method = interp.intern_bytes(&b"index"[..])?;
+            let method = Symbol::alloc_value(method.into(), interp)?;
+            ary.funcall(interp, "to_enum", &[method], None)
+        }
+    }
+}
+
 pub fn initialize(
     interp: &mut Artichoke,
     into: Value,
@@ -264,6 +397,37 @@ pub fn len(interp: &mut Artichoke, mut ary: Value) -> Result<usize, Error> {
     Ok(array.len())
 }
 
+pub fn pack(interp: &mut Artichoke, mut ary: Value, mut template: Value) -> Result<Value, Error> {
+    // Safety:
+    //
+    // The bytes are copied out of the `RString` before any intervening
+    // interpreter operation can trigger a garbage collection.
+    let template_bytes = unsafe { implicitly_convert_to_string(interp, &mut template)? }.to_vec();
+    let entries = match pack::parse_template(&template_bytes) {
+        Ok(entries) => entries,
+        // Directives outside the supported subset are delegated to the C
+        // implementation from the vendored mruby-pack gem, which is preserved
+        // under a private alias during init.
+        Err(pack::TemplateError::Unsupported(_)) => {
+            return ary.funcall(interp, "__pack_with_mruby_pack", &[template], None);
+        }
+        Err(err @ pack::TemplateError::LengthTooBig) => return Err(RangeError::from(err.message()).into()),
+        Err(err) => return Err(ArgumentError::from(err.message()).into()),
+    };
+    let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+    // The encoder may call arbitrary Ruby code through implicit conversions,
+    // so copy the elements out of the array before releasing the guard.
+    let items = array.as_slice().to_vec();
+    drop(array);
+    let packed = pack::pack(interp, &entries, &items)?;
+    let result = match pack::result_encoding(&entries) {
+        spinoso_string::Encoding::Ascii => spinoso_string::String::ascii(packed),
+        spinoso_string::Encoding::Utf8 => spinoso_string::String::utf8(packed),
+        spinoso_string::Encoding::Binary => spinoso_string::String::binary(packed),
+    };
+    spinoso_string::String::alloc_value(result, interp)
+}
+
 pub fn pop(interp: &mut Artichoke, mut ary: Value) -> Result<Value, Error> {
     if ary.is_frozen(interp) {
         return Err(FrozenError::with_message("can't modify frozen Array").into());
@@ -202,6 +202,17 @@ impl Array {
         self.0.as_slice()
     }
 
+    /// Returns every [`Value`] this `Array` holds.
+    ///
+    /// This method is a garbage collection verification facility: GC stress
+    /// tests can assert that each child remains live after collections are
+    /// triggered between mutations. It is only available in debug builds.
+    #[cfg(debug_assertions)]
+    #[must_use]
+    pub fn debug_children(&self) -> Vec<Value> {
+        self.0.as_slice().iter().copied().map(Value::from).collect()
+    }
+
     /// Extracts a mutable slice containing the entire vector.
     ///
     /// Equivalent to `&mut ary[..]`.
@@ -18,5 +18,8 @@ pub fn init(interp: &mut crate::Artichoke) -> InitializeResult<()> {
 #[derive(Debug, Clone, Copy)]
 pub struct Artichoke;
 
+#[derive(Debug, Clone, Copy)]
+pub struct Enumerable;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Kernel;
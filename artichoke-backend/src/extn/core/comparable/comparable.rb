@@ -129,37 +129,42 @@ module Comparable
     true
   end
 
-  def clamp(min, max)
-    paramcmp = (min <=> max)
-    raise ArgumentError, 'min argument must be smaller than max argument' if paramcmp.nil? || paramcmp.positive?
+  def clamp(min, max = nil)
+    if max.nil?
+      raise TypeError, "wrong argument type #{min.class} (expected Range)" unless min.is_a?(Range)
 
-    cmp = (self <=> min)
-    if cmp.nil?
-      classname = other.class
-      classname = other.inspect if other.nil? || other.equal?(false) || other.equal?(true) || other.is_a?(Numeric)
-      raise ArgumentError, "Comparison of #{self.class} with #{classname} failed"
+      range = min
+      min = range.begin
+      max = range.end
+      raise ArgumentError, 'cannot clamp with an exclusive range' if range.exclude_end? && !max.nil?
     end
-    unless cmp.is_a?(Numeric)
-      classname = other.class
-      classname = other.inspect if other.nil? || other.equal?(false) || other.equal?(true) || other.is_a?(Numeric)
-      raise ArgumentError, "Comparison of #{self.class} with #{classname} failed"
+
+    unless min.nil? || max.nil?
+      paramcmp = (min <=> max)
+      raise ArgumentError, 'min argument must be smaller than max argument' if paramcmp.nil? || paramcmp.positive?
     end
 
-    return min if cmp < 0 # rubocop:disable Style/NumericPredicate
+    unless min.nil?
+      cmp = (self <=> min)
+      unless cmp.is_a?(Numeric)
+        classname = min.class
+        classname = min.inspect if min.equal?(false) || min.equal?(true) || min.is_a?(Numeric)
+        raise ArgumentError, "Comparison of #{self.class} with #{classname} failed"
+      end
 
-    cmp = (self <=> max)
-    if cmp.nil?
-      classname = other.class
-      classname = other.inspect if other.nil? || other.equal?(false) || other.equal?(true) || other.is_a?(Numeric)
-      raise ArgumentError, "Comparison of #{self.class} with #{classname} failed"
-    end
-    unless cmp.is_a?(Numeric)
-      classname = other.class
-      classname = other.inspect if other.nil? || other.equal?(false) || other.equal?(true) || other.is_a?(Numeric)
-      raise ArgumentError, "Comparison of #{self.class} with #{classname} failed"
+      return min if cmp < 0 # rubocop:disable Style/NumericPredicate
     end
 
-    return max if cmp > 0 # rubocop:disable Style/NumericPredicate
+    unless max.nil?
+      cmp = (self <=> max)
+      unless cmp.is_a?(Numeric)
+        classname = max.class
+        classname = max.inspect if max.equal?(false) || max.equal?(true) || max.is_a?(Numeric)
+        raise ArgumentError, "Comparison of #{self.class} with #{classname} failed"
+      end
+
+      return max if cmp > 0 # rubocop:disable Style/NumericPredicate
+    end
 
     self
   end
@@ -0,0 +1,81 @@
+# frozen_string_literal: true
+
+def spec
+  clamp_with_min_and_max
+  clamp_with_range
+  clamp_with_endless_range
+  clamp_with_beginless_range
+  clamp_with_exclusive_range
+  clamp_with_reversed_bounds
+  clamp_with_non_range_argument
+  clamp_custom_comparable
+
+  true
+end
+
+def clamp_with_min_and_max
+  raise unless 12.clamp(0, 5) == 5
+  raise unless (-1).clamp(0, 5) == 0
+  raise unless 3.clamp(0, 5) == 3
+end
+
+def clamp_with_range
+  raise unless 12.clamp(0..5) == 5
+  raise unless (-1).clamp(0..5) == 0
+  raise unless 3.clamp(0..5) == 3
+end
+
+def clamp_with_endless_range
+  raise unless 3.clamp(5..) == 5
+  raise unless 10.clamp(5..) == 10
+  raise unless 3.clamp(5...) == 5
+end
+
+def clamp_with_beginless_range
+  raise unless 10.clamp(..5) == 5
+  raise unless 3.clamp(..5) == 3
+end
+
+def clamp_with_exclusive_range
+  3.clamp(0...5)
+  raise
+rescue ArgumentError => e
+  raise unless e.message == 'cannot clamp with an exclusive range'
+end
+
+def clamp_with_reversed_bounds
+  3.clamp(5, 1)
+  raise
+rescue ArgumentError => e
+  raise unless e.message == 'min argument must be smaller than max argument'
+end
+
+def clamp_with_non_range_argument
+  3.clamp(5)
+  raise
+rescue TypeError => e
+  raise unless e.message == 'wrong argument type Integer (expected Range)'
+end
+
+def clamp_custom_comparable
+  temperature = Class.new do
+    include Comparable
+
+    attr_reader :degrees
+
+    def initialize(degrees)
+      @degrees = degrees
+    end
+
+    def <=>(other)
+      degrees <=> other.degrees
+    end
+  end
+  low = temperature.new(10)
+  high = temperature.new(30)
+  raise unless temperature.new(50).clamp(low, high).equal?(high)
+  raise unless temperature.new(0).clamp(low..high).equal?(low)
+  raise unless temperature.new(20).clamp(low..high).degrees == 20
+end
+
+spec if $PROGRAM_NAME == __FILE__
@@ -0,0 +1,20 @@
+# frozen_string_literal: true
+
+class Dir
+  class << self
+    def [](*patterns)
+      glob(patterns)
+    end
+
+    def glob(pattern, flags = 0, sort: true)
+      patterns = pattern.is_a?(Array) ? pattern : [pattern]
+      matches = __glob(patterns, flags, sort ? true : false)
+      if block_given?
+        matches.each { |match| yield match }
+        nil
+      else
+        matches
+      end
+    end
+  end
+end
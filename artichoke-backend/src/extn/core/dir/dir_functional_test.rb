@@ -0,0 +1,80 @@
+# frozen_string_literal: true
+
+def spec(root)
+  glob_with_star(root)
+  glob_recursive(root)
+  glob_dotmatch(root)
+  glob_casefold(root)
+  glob_directories(root)
+  glob_multiple_patterns(root)
+  glob_braces(root)
+  glob_unsorted(root)
+  glob_with_block(root)
+  glob_without_matches(root)
+
+  true
+end
+
+def glob_with_star(root)
+  raise unless Dir.glob("#{root}/*.rb") == ["#{root}/main.rb"]
+  raise unless Dir.glob("#{root}/*") == ["#{root}/README.md", "#{root}/lib", "#{root}/main.rb", "#{root}/spec"]
+  raise unless Dir.glob("#{root}/[a-m]*.rb") == ["#{root}/main.rb"]
+end
+
+def glob_recursive(root)
+  expected = [
+    "#{root}/lib/nested/deep.rb",
+    "#{root}/lib/song.rb",
+    "#{root}/main.rb",
+    "#{root}/spec/song_spec.rb"
+  ]
+  raise unless Dir.glob("#{root}/**/*.rb") == expected
+
+  expected = ["#{root}/lib/nested", "#{root}/lib/nested/deep.rb", "#{root}/lib/song.rb"]
+  raise unless Dir.glob("#{root}/lib/**") == expected
+end
+
+def glob_dotmatch(root)
+  raise unless Dir.glob("#{root}/.*.rb") == ["#{root}/.hidden.rb"]
+  raise if Dir.glob("#{root}/*.rb").include?("#{root}/.hidden.rb")
+  raise unless Dir.glob("#{root}/*.rb", File::FNM_DOTMATCH) == ["#{root}/.hidden.rb", "#{root}/main.rb"]
+
+  expected = ["#{root}/.hidden.rb", "#{root}/README.md", "#{root}/lib", "#{root}/main.rb", "#{root}/spec"]
+  raise unless Dir.glob("#{root}/*", File::FNM_DOTMATCH) == expected
+end
+
+def glob_casefold(root)
+  raise unless Dir.glob("#{root}/MAIN.RB").empty?
+  raise unless Dir.glob("#{root}/MAIN.RB", File::FNM_CASEFOLD) == ["#{root}/main.rb"]
+end
+
+def glob_directories(root)
+  raise unless Dir.glob("#{root}/*/") == ["#{root}/lib/", "#{root}/spec/"]
+  raise unless Dir.glob("#{root}/**/") == ["#{root}/", "#{root}/lib/", "#{root}/lib/nested/", "#{root}/spec/"]
+end
+
+def glob_multiple_patterns(root)
+  expected = ["#{root}/main.rb", "#{root}/README.md"]
+  raise unless Dir.glob(["#{root}/*.rb", "#{root}/*.md"]) == expected
+  raise unless Dir["#{root}/*.rb", "#{root}/*.md"] == expected
+end
+
+def glob_braces(root)
+  raise unless Dir.glob("#{root}/{main,lib/song}.rb") == ["#{root}/lib/song.rb", "#{root}/main.rb"]
+end
+
+def glob_unsorted(root)
+  sorted = Dir.glob("#{root}/**/*.rb")
+  raise unless Dir.glob("#{root}/**/*.rb", sort: false).sort == sorted
+end
+
+def glob_with_block(root)
+  yielded = []
+  result = Dir.glob("#{root}/*.rb") { |path| yielded << path }
+  raise unless result.nil?
+  raise unless yielded == ["#{root}/main.rb"]
+end
+
+def glob_without_matches(root)
+  raise unless Dir.glob("#{root}/nope/*.rb") == []
+end
@@ -0,0 +1,370 @@
+//! Pure-Rust implementation of the shell glob matching used by
+//! [`File.fnmatch`] and [`Dir.glob`].
+//!
+//! The matcher operates on bytes and supports `*`, `?`, `[a-z]` character
+//! classes with negation, `**/` directory recursion under
+//! [`PATHNAME`], and `{a,b}` alternation under [`EXTGLOB`].
+//!
+//! [`File.fnmatch`]: https://ruby-doc.org/core-2.6.3/File.html#method-c-fnmatch
+//! [`Dir.glob`]: https://ruby-doc.org/core-2.6.3/Dir.html#method-c-glob
+
+/// `File::FNM_NOESCAPE`: `\` does not escape the following character.
+pub const NOESCAPE: i64 = 0x01;
+
+/// `File::FNM_PATHNAME`: wildcards do not match `/`.
+pub const PATHNAME: i64 = 0x02;
+
+/// `File::FNM_DOTMATCH`: wildcards match filenames with a leading `.`.
+pub const DOTMATCH: i64 = 0x04;
+
+/// `File::FNM_CASEFOLD`: matching is case-insensitive.
+pub const CASEFOLD: i64 = 0x08;
+
+/// `File::FNM_EXTGLOB`: `{a,b}` alternatives are expanded.
+pub const EXTGLOB: i64 = 0x10;
+
+/// Match a path against an `fnmatch(3)`-style pattern.
+#[must_use]
+pub fn fnmatch(pattern: &[u8], path: &[u8], flags: i64) -> bool {
+    if flags & EXTGLOB != 0 && pattern.contains(&b'{') {
+        let mut patterns = Vec::new();
+        expand_braces(pattern, flags & NOESCAPE == 0, &mut patterns);
+        patterns.iter().any(|pattern| fnmatch_expanded(pattern, path, flags))
+    } else {
+        fnmatch_expanded(pattern, path, flags)
+    }
+}
+
+fn fnmatch_expanded(pattern: &[u8], path: &[u8], flags: i64) -> bool {
+    if flags & PATHNAME == 0 {
+        return match_region(pattern, path, flags);
+    }
+    let pattern = pattern.split(|&byte| byte == b'/').collect::<Vec<_>>();
+    let path = path.split(|&byte| byte == b'/').collect::<Vec<_>>();
+    match_pathname(&pattern, &path, flags)
+}
+
+/// Match a pattern split on `/` against a path split on `/`.
+fn match_pathname(pattern: &[&[u8]], path: &[&[u8]], flags: i64) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        // A `**/` segment matches zero or more directories. A trailing `**`
+        // is not recursive and behaves like `*`.
+        Some((&segment, rest)) if segment == b"**" && !rest.is_empty() => {
+            if match_pathname(rest, path, flags) {
+                return true;
+            }
+            if let Some((&head, tail)) = path.split_first() {
+                if flags & DOTMATCH != 0 || !head.starts_with(b".") {
+                    return match_pathname(pattern, tail, flags);
+                }
+            }
+            false
+        }
+        Some((&segment, rest)) => {
+            if let Some((&head, tail)) = path.split_first() {
+                match_region(segment, head, flags) && match_pathname(rest, tail, flags)
+            } else {
+                false
+            }
+        }
+    }
+}
+
+/// Match a pattern against a region of text in which `/` is not special.
+///
+/// Uses iterative backtracking: on a mismatch, the most recent `*` consumes
+/// one more byte and matching restarts after it.
+fn match_region(pattern: &[u8], text: &[u8], flags: i64) -> bool {
+    let escape = flags & NOESCAPE == 0;
+    let period = flags & DOTMATCH == 0;
+    let mut p = 0;
+    let mut t = 0;
+    let mut star: Option<(usize, usize)> = None;
+    loop {
+        if t == text.len() {
+            let mut rest = p;
+            while pattern.get(rest) == Some(&b'*') {
+                rest += 1;
+            }
+            if rest == pattern.len() {
+                return true;
+            }
+        } else {
+            let ch = text[t];
+            // A leading `.` must be matched literally.
+            let wildcard_ok = !(period && t == 0 && ch == b'.');
+            match pattern.get(p).copied() {
+                Some(b'*') => {
+                    p += 1;
+                    star = Some((p, t));
+                    continue;
+                }
+                Some(b'?') if wildcard_ok => {
+                    p += 1;
+                    t += 1;
+                    continue;
+                }
+                Some(b'[') if wildcard_ok => {
+                    if let Some((matched, next)) = match_bracket(pattern, p + 1, ch, flags) {
+                        if matched {
+                            p = next;
+                            t += 1;
+                            continue;
+                        }
+                    } else if chars_eq(b'[', ch, flags) {
+                        // An unterminated class is a literal `[`.
+                        p += 1;
+                        t += 1;
+                        continue;
+                    }
+                }
+                Some(b'\\') if escape => {
+                    if let Some(&literal) = pattern.get(p + 1) {
+                        if chars_eq(literal, ch, flags) {
+                            p += 2;
+                            t += 1;
+                            continue;
+                        }
+                    }
+                }
+                Some(literal) if chars_eq(literal, ch, flags) => {
+                    p += 1;
+                    t += 1;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        if let Some((star_p, star_t)) = star {
+            if star_t >= text.len() || (period && star_t == 0 && text[0] == b'.') {
+                return false;
+            }
+            star = Some((star_p, star_t + 1));
+            p = star_p;
+            t = star_t + 1;
+        } else {
+            return false;
+        }
+    }
+}
+
+/// Match a single byte against a `[...]` character class.
+///
+/// `start` is the index of the first byte after the `[`. On success, returns
+/// whether the byte matched and the index just past the closing `]`. Returns
+/// [`None`] if the class is not terminated.
+fn match_bracket(pattern: &[u8], start: usize, ch: u8, flags: i64) -> Option<(bool, usize)> {
+    let escape = flags & NOESCAPE == 0;
+    let mut idx = start;
+    let mut negated = false;
+    if let Some(b'!' | b'^') = pattern.get(idx).copied() {
+        negated = true;
+        idx += 1;
+    }
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let byte = *pattern.get(idx)?;
+        if byte == b']' && !first {
+            idx += 1;
+            break;
+        }
+        first = false;
+        let lower = if byte == b'\\' && escape {
+            idx += 1;
+            *pattern.get(idx)?
+        } else {
+            byte
+        };
+        idx += 1;
+        if pattern.get(idx) == Some(&b'-') && pattern.get(idx + 1).map_or(false, |&byte| byte != b']') {
+            idx += 1;
+            let mut upper = *pattern.get(idx)?;
+            if upper == b'\\' && escape {
+                idx += 1;
+                upper = *pattern.get(idx)?;
+            }
+            idx += 1;
+            let (lower, upper, ch) = if flags & CASEFOLD == 0 {
+                (lower, upper, ch)
+            } else {
+                (
+                    lower.to_ascii_lowercase(),
+                    upper.to_ascii_lowercase(),
+                    ch.to_ascii_lowercase(),
+                )
+            };
+            if (lower..=upper).contains(&ch) {
+                matched = true;
+            }
+        } else if chars_eq(lower, ch, flags) {
+            matched = true;
+        }
+    }
+    Some((matched != negated, idx))
+}
+
+fn chars_eq(left: u8, right: u8, flags: i64) -> bool {
+    if flags & CASEFOLD == 0 {
+        left == right
+    } else {
+        left.eq_ignore_ascii_case(&right)
+    }
+}
+
+/// Expand `{a,b}` alternation into one pattern per alternative.
+///
+/// Braces nest; expansion recurses until no top-level brace group remains.
+/// Patterns with unterminated groups are passed through unchanged.
+pub fn expand_braces(pattern: &[u8], escape: bool, into: &mut Vec<Vec<u8>>) {
+    let mut idx = 0;
+    let mut open = None;
+    while idx < pattern.len() {
+        match pattern[idx] {
+            b'\\' if escape => idx += 1,
+            b'{' => {
+                open = Some(idx);
+                break;
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    let start = if let Some(start) = open {
+        start
+    } else {
+        into.push(pattern.to_vec());
+        return;
+    };
+    let mut depth = 1_usize;
+    let mut alternatives = Vec::new();
+    let mut alternative_start = start + 1;
+    let mut close = None;
+    let mut idx = start + 1;
+    while idx < pattern.len() {
+        match pattern[idx] {
+            b'\\' if escape => idx += 1,
+            b'{' => depth += 1,
+            b'}' if depth == 1 => {
+                alternatives.push(&pattern[alternative_start..idx]);
+                close = Some(idx);
+                break;
+            }
+            b'}' => depth -= 1,
+            b',' if depth == 1 => {
+                alternatives.push(&pattern[alternative_start..idx]);
+                alternative_start = idx + 1;
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+    let close = if let Some(close) = close {
+        close
+    } else {
+        into.push(pattern.to_vec());
+        return;
+    };
+    for alternative in alternatives {
+        let mut candidate = pattern[..start].to_vec();
+        candidate.extend_from_slice(alternative);
+        candidate.extend_from_slice(&pattern[close + 1..]);
+        expand_braces(&candidate, escape, into);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fnmatch, CASEFOLD, DOTMATCH, EXTGLOB, NOESCAPE, PATHNAME};
+
+    #[test]
+    fn literal_patterns_match_exactly() {
+        assert!(fnmatch(b"cat", b"cat", 0));
+        assert!(!fnmatch(b"cat", b"category", 0));
+        assert!(!fnmatch(b"cat", b"wildcat", 0));
+    }
+
+    #[test]
+    fn question_mark_matches_one_character() {
+        assert!(fnmatch(b"c?t", b"cat", 0));
+        assert!(!fnmatch(b"c??t", b"cat", 0));
+        assert!(!fnmatch(b"c?t", b"ct", 0));
+    }
+
+    #[test]
+    fn star_matches_any_run() {
+        assert!(fnmatch(b"c*", b"cats", 0));
+        assert!(fnmatch(b"c*t", b"ct", 0));
+        assert!(fnmatch(b"*", b"cat", 0));
+        assert!(fnmatch(b"c*t*s", b"cats and dogs", 0));
+    }
+
+    #[test]
+    fn character_classes_with_ranges_and_negation() {
+        assert!(fnmatch(b"ca[a-z]", b"cat", 0));
+        assert!(!fnmatch(b"ca[b-c]", b"cat", 0));
+        assert!(!fnmatch(b"ca[!t]", b"cat", 0));
+        assert!(!fnmatch(b"ca[^t]", b"cat", 0));
+        assert!(fnmatch(b"ca[!t]", b"cas", 0));
+    }
+
+    #[test]
+    fn unterminated_class_is_a_literal_bracket() {
+        assert!(fnmatch(b"ca[t", b"ca[t", 0));
+        assert!(!fnmatch(b"ca[t", b"cat", 0));
+    }
+
+    #[test]
+    fn matching_is_case_sensitive_unless_casefold() {
+        assert!(!fnmatch(b"cat", b"CAT", 0));
+        assert!(fnmatch(b"cat", b"CAT", CASEFOLD));
+        assert!(fnmatch(b"ca[a-z]", b"CAT", CASEFOLD));
+    }
+
+    #[test]
+    fn escapes_are_literal_unless_noescape() {
+        assert!(fnmatch(br"\?", b"?", 0));
+        assert!(!fnmatch(br"\?", b"a", 0));
+        assert!(fnmatch(br"\*", b"*", 0));
+        assert!(fnmatch(br"\?", br"\a", NOESCAPE));
+        assert!(fnmatch(br"\\", br"\", 0));
+    }
+
+    #[test]
+    fn pathname_keeps_wildcards_out_of_slashes() {
+        assert!(fnmatch(b"c*t", b"c/a/b/t", 0));
+        assert!(!fnmatch(b"c*t", b"c/a/b/t", PATHNAME));
+        assert!(!fnmatch(b"?", b"/", PATHNAME));
+        assert!(!fnmatch(b"*", b"/", PATHNAME));
+        assert!(fnmatch(b"*/*", b"a/b", PATHNAME));
+    }
+
+    #[test]
+    fn double_star_recurses_under_pathname() {
+        assert!(fnmatch(b"**/foo", b"a/b/c/foo", PATHNAME));
+        assert!(fnmatch(b"**/foo", b"foo", PATHNAME));
+        assert!(fnmatch(b"**/*.rb", b"lib/song.rb", PATHNAME));
+        assert!(!fnmatch(b"**", b"a/b", PATHNAME));
+        assert!(!fnmatch(b"**/foo", b"a/.b/foo", PATHNAME));
+        assert!(fnmatch(b"**/foo", b"a/.b/foo", PATHNAME | DOTMATCH));
+    }
+
+    #[test]
+    fn leading_dots_are_not_matched_by_wildcards() {
+        assert!(!fnmatch(b"*", b".profile", 0));
+        assert!(fnmatch(b"*", b".profile", DOTMATCH));
+        assert!(fnmatch(b".*", b".profile", 0));
+        assert!(!fnmatch(b"?rofile", b".profile", 0));
+        assert!(!fnmatch(b"[.]profile", b".profile", 0));
+    }
+
+    #[test]
+    fn braces_require_extglob() {
+        assert!(!fnmatch(b"c{at,ub}s", b"cats", 0));
+        assert!(fnmatch(b"c{at,ub}s", b"cats", EXTGLOB));
+        assert!(fnmatch(b"c{at,ub}s", b"cubs", EXTGLOB));
+        assert!(!fnmatch(b"c{at,ub}s", b"cat", EXTGLOB));
+        assert!(fnmatch(b"{a,b{c,d}}e", b"bde", EXTGLOB));
+    }
+}
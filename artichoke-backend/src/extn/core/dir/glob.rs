@@ -0,0 +1,180 @@
+//! Filesystem walker behind [`Dir.glob`].
+//!
+//! Patterns are matched one `/`-separated segment at a time with the
+//! [`fnmatch`] matcher. Paths cross the FFI boundary as bytes and are
+//! converted with the [`platform_string`] helpers also used by the native
+//! load path loaders, so a virtual file system backend can slot in behind the
+//! same conversions later.
+//!
+//! [`Dir.glob`]: https://ruby-doc.org/core-2.6.3/Dir.html#method-c-glob
+//! [`fnmatch`]: super::fnmatch
+//! [`platform_string`]: crate::platform_string
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::extn::core::dir::fnmatch;
+use crate::platform_string::{bytes_to_os_str, os_str_to_bytes};
+
+/// Match a glob pattern against the host file system.
+///
+/// Returns matched paths in traversal order; the caller sorts them unless
+/// `sort: false` was given. Braces are always expanded, unlike in
+/// `File.fnmatch` where they are gated behind `File::FNM_EXTGLOB`.
+#[must_use]
+pub fn glob(pattern: &[u8], flags: i64) -> Vec<Vec<u8>> {
+    let mut results = Vec::new();
+    let mut patterns = Vec::new();
+    fnmatch::expand_braces(pattern, flags & fnmatch::NOESCAPE == 0, &mut patterns);
+    for pattern in &patterns {
+        if let Some(rest) = pattern.strip_prefix(b"/") {
+            let segments = rest.split(|&byte| byte == b'/').collect::<Vec<_>>();
+            walk(b"/", &segments, flags, &mut results);
+        } else {
+            let segments = pattern.split(|&byte| byte == b'/').collect::<Vec<_>>();
+            walk(b"", &segments, flags, &mut results);
+        }
+    }
+    results
+}
+
+fn join(prefix: &[u8], name: &[u8]) -> Vec<u8> {
+    let mut joined = prefix.to_vec();
+    if !joined.is_empty() && !joined.ends_with(b"/") {
+        joined.push(b'/');
+    }
+    joined.extend_from_slice(name);
+    joined
+}
+
+fn as_path(prefix: &[u8]) -> Option<PathBuf> {
+    let path = if prefix.is_empty() { b".".as_slice() } else { prefix };
+    bytes_to_os_str(path).ok().map(PathBuf::from)
+}
+
+fn is_dir(prefix: &[u8]) -> bool {
+    as_path(prefix).map_or(false, |path| path.is_dir())
+}
+
+fn exists(prefix: &[u8]) -> bool {
+    as_path(prefix).map_or(false, |path| fs::symlink_metadata(path).is_ok())
+}
+
+/// Read the entries of the directory named by `prefix` as byte strings.
+///
+/// `.` and `..` are never yielded, as with `Dir.glob` in MRI. Hidden entries
+/// are yielded; the matcher's leading-dot rule and the `**` traversal decide
+/// whether they participate in a match.
+fn entries(prefix: &[u8]) -> Vec<Vec<u8>> {
+    let mut names = Vec::new();
+    let path = if let Some(path) = as_path(prefix) {
+        path
+    } else {
+        return names;
+    };
+    if let Ok(read_dir) = fs::read_dir(path) {
+        for entry in read_dir.flatten() {
+            let name = entry.file_name();
+            if let Ok(name) = os_str_to_bytes(&name) {
+                names.push(name.to_vec());
+            }
+        }
+    }
+    names
+}
+
+/// Whether a `**` traversal may descend into or yield the named entry.
+fn visible_to_recursion(name: &[u8], flags: i64) -> bool {
+    flags & fnmatch::DOTMATCH != 0 || !name.starts_with(b".")
+}
+
+fn walk(prefix: &[u8], segments: &[&[u8]], flags: i64, results: &mut Vec<Vec<u8>>) {
+    let (&segment, rest) = if let Some(split) = segments.split_first() {
+        split
+    } else {
+        return;
+    };
+    if rest.is_empty() {
+        match segment {
+            // The pattern ends in `/` and only matches a directory.
+            b"" => {
+                if !prefix.is_empty() && is_dir(prefix) {
+                    results.push(join(prefix, b""));
+                }
+            }
+            // A trailing `**` matches every file and directory under the
+            // current prefix, recursively.
+            b"**" => recurse_all(prefix, flags, results),
+            segment if needs_enumeration(segment, flags) => {
+                for name in entries(prefix) {
+                    if fnmatch::fnmatch(segment, &name, flags) {
+                        results.push(join(prefix, &name));
+                    }
+                }
+            }
+            segment => {
+                let candidate = join(prefix, segment);
+                if exists(&candidate) {
+                    results.push(candidate);
+                }
+            }
+        }
+        return;
+    }
+    match segment {
+        // `**/` matches zero or more directories.
+        b"**" => {
+            walk(prefix, rest, flags, results);
+            for name in entries(prefix) {
+                let candidate = join(prefix, &name);
+                if visible_to_recursion(&name, flags) && is_dir(&candidate) {
+                    walk(&candidate, segments, flags, results);
+                }
+            }
+        }
+        // Collapse doubled slashes.
+        b"" => walk(prefix, rest, flags, results),
+        segment if needs_enumeration(segment, flags) => {
+            for name in entries(prefix) {
+                let candidate = join(prefix, &name);
+                if fnmatch::fnmatch(segment, &name, flags) && is_dir(&candidate) {
+                    walk(&candidate, rest, flags, results);
+                }
+            }
+        }
+        segment => {
+            let candidate = join(prefix, segment);
+            if is_dir(&candidate) {
+                walk(&candidate, rest, flags, results);
+            }
+        }
+    }
+}
+
+fn recurse_all(prefix: &[u8], flags: i64, results: &mut Vec<Vec<u8>>) {
+    for name in entries(prefix) {
+        if !visible_to_recursion(&name, flags) {
+            continue;
+        }
+        let candidate = join(prefix, &name);
+        if is_dir(&candidate) {
+            results.push(candidate.clone());
+            recurse_all(&candidate, flags, results);
+        } else {
+            results.push(candidate);
+        }
+    }
+}
+
+/// Whether a pattern segment must be matched against directory entries.
+///
+/// Segments with matcher syntax always are. Literal segments are probed
+/// directly, which lets `.` and `..` appear in patterns even though they are
+/// never yielded by directory enumeration — except under `File::FNM_CASEFOLD`,
+/// where a literal must be compared case-insensitively against each entry.
+fn needs_enumeration(segment: &[u8], flags: i64) -> bool {
+    if segment.iter().any(|&byte| matches!(byte, b'*' | b'?' | b'[' | b'\\')) {
+        return true;
+    }
+    flags & fnmatch::CASEFOLD != 0 && segment != b"." && segment != b".."
+}
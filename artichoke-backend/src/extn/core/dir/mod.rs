@@ -0,0 +1,65 @@
+//! Directory globbing with `Dir.glob`.
+//!
+//! This module implements a subset of the [`Dir`] class from Ruby Core:
+//! [`Dir.[]`] and [`Dir.glob`]. The pattern matcher in [`fnmatch`] also backs
+//! [`File.fnmatch`].
+//!
+//! You can use these APIs in your application:
+//!
+//! ```ruby
+//! Dir.glob('**/*.rb')
+//! Dir['lib/**/*.rb', File::FNM_DOTMATCH]
+//! ```
+//!
+//! [`Dir`]: https://ruby-doc.org/core-2.6.3/Dir.html
+//! [`Dir.[]`]: https://ruby-doc.org/core-2.6.3/Dir.html#method-c-5B-5D
+//! [`Dir.glob`]: https://ruby-doc.org/core-2.6.3/Dir.html#method-c-glob
+//! [`File.fnmatch`]: https://ruby-doc.org/core-2.6.3/File.html#method-c-fnmatch
+
+pub mod fnmatch;
+pub mod glob;
+pub mod mruby;
+pub mod trampoline;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Dir;
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::test::prelude::*;
+
+    const SUBJECT: &str = "Dir";
+    const FUNCTIONAL_TEST: &[u8] = include_bytes!("dir_functional_test.rb");
+
+    // Build a directory tree for the functional test to glob over. The tests
+    // use absolute patterns so that the interpreter does not need to change
+    // the process working directory.
+    fn fixture_tree() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("artichoke-dir-glob-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("lib").join("nested")).unwrap();
+        fs::create_dir_all(root.join("spec")).unwrap();
+        fs::write(root.join("main.rb"), b"").unwrap();
+        fs::write(root.join("README.md"), b"").unwrap();
+        fs::write(root.join(".hidden.rb"), b"").unwrap();
+        fs::write(root.join("lib").join("song.rb"), b"").unwrap();
+        fs::write(root.join("lib").join("nested").join("deep.rb"), b"").unwrap();
+        fs::write(root.join("spec").join("song_spec.rb"), b"").unwrap();
+        root
+    }
+
+    #[test]
+    fn functional() {
+        let root = fixture_tree();
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let spec = format!("spec({:?})", root.to_str().unwrap());
+        let result = interp.eval(spec.as_bytes());
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
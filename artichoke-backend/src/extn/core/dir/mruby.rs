@@ -0,0 +1,33 @@
+use std::ffi::CStr;
+
+use crate::extn::core::dir::{trampoline, Dir};
+use crate::extn::prelude::*;
+
+const DIR_CSTR: &CStr = cstr::cstr!("Dir");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<Dir>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Dir", DIR_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_self_method("__glob", dir_glob, sys::mrb_args_req(3))?
+        .define()?;
+    interp.def_class::<Dir>(spec)?;
+    interp.eval(&include_bytes!("dir.rb")[..])?;
+    trace!("Patched Dir onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn dir_glob(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (patterns, flags, sort) = mrb_get_args!(mrb, required = 3);
+    unwrap_interpreter!(mrb, to => guard);
+    let patterns = Value::from(patterns);
+    let flags = Value::from(flags);
+    let sort = Value::from(sort);
+    let result = trampoline::glob(&mut guard, patterns, flags, sort);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -0,0 +1,32 @@
+use crate::convert::{implicitly_convert_to_int, implicitly_convert_to_string};
+use crate::extn::core::array::Array;
+use crate::extn::prelude::*;
+
+pub fn glob(interp: &mut Artichoke, mut patterns: Value, flags: Value, sort: Value) -> Result<Value, Error> {
+    let flags = implicitly_convert_to_int(interp, flags)?;
+    let sort = sort.try_convert_into::<bool>(interp)?;
+    let patterns = if let Ok(array) = unsafe { Array::unbox_from_value(&mut patterns, interp) } {
+        array.as_slice().to_vec()
+    } else {
+        vec![patterns]
+    };
+    let mut matches = Vec::new();
+    for mut pattern in patterns {
+        // Safety:
+        //
+        // Convert the pattern to an owned byte vec to ensure the underlying
+        // `RString` is not garbage collected while walking the file system.
+        let pattern = unsafe { implicitly_convert_to_string(interp, &mut pattern)? }.to_vec();
+        let mut results = super::glob::glob(&pattern, flags);
+        if sort {
+            results.sort();
+        }
+        matches.append(&mut results);
+    }
+    let mut result = Vec::with_capacity(matches.len());
+    for path in matches {
+        let path = interp.try_convert_mut(path)?;
+        result.push(path);
+    }
+    Array::alloc_value(Array::from(result), interp)
+}
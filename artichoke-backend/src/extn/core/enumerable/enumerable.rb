@@ -338,21 +338,13 @@ module Enumerable
   def max_by(&block)
     return to_enum :max_by unless block
 
-    first = true
-    max = nil
-    max_cmp = nil
-
+    keys = []
+    values = []
     each do |val|
-      if first
-        max = val
-        max_cmp = block.call(val)
-        first = false
-      elsif (cmp = block.call(val)) > max_cmp
-        max = val
-        max_cmp = cmp
-      end
+      values.push(val)
+      keys.push(block.call(val))
     end
-    max
+    Artichoke::Enumerable.max_by_keys(keys, values)
   end
 
   def min(&block)
@@ -375,24 +367,18 @@ module Enumerable
   def min_by(&block)
     return to_enum :min_by unless block
 
-    first = true
-    min = nil
-    min_cmp = nil
-
+    keys = []
+    values = []
     each do |val|
-      if first
-        min = val
-        min_cmp = block.call(val)
-        first = false
-      elsif (cmp = block.call(val)) < min_cmp
-        min = val
-        min_cmp = cmp
-      end
+      values.push(val)
+      keys.push(block.call(val))
     end
-    min
+    Artichoke::Enumerable.min_by_keys(keys, values)
   end
 
   def minmax(&block)
+    return Artichoke::Enumerable.minmax(entries) unless block
+
     max = nil
     min = nil
     first = true
@@ -402,12 +388,9 @@ module Enumerable
         max = val
         min = val
         first = false
-      elsif block
+      else
         max = val if block.call(val, max).positive?
         min = val if block.call(val, min).negative?
-      else
-        max = val if (val <=> max).positive?
-        min = val if (val <=> min).negative?
       end
     end
     [min, max]
@@ -527,14 +510,23 @@ module Enumerable
   def sort_by(&block)
     return to_enum :sort_by unless block
 
-    ary = []
-    orig = []
-    each_with_index do |e, i|
-      orig.push(e)
-      ary.push([block.call(e), i])
+    keys = []
+    values = []
+    each do |val|
+      values.push(val)
+      keys.push(block.call(val))
+    end
+    Artichoke::Enumerable.sort_by_keys(keys, values)
+  end
+
+  def sum(init = 0, &block)
+    return Artichoke::Enumerable.sum(entries, init) unless block
+
+    result = init
+    each do |val|
+      result += block.call(val)
     end
-    ary.sort! if ary.size > 1
-    ary.collect { |_e, i| orig[i] }
+    result
   end
 
   def take(size)
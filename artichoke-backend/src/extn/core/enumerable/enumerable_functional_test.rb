@@ -0,0 +1,104 @@
+# frozen_string_literal: true
+
+def spec
+  sort_by_keys
+  sort_by_custom_comparable_keys
+  sort_by_without_block_returns_enumerator
+  sort_by_raises_on_incomparable_keys
+
+  min_by_and_max_by
+  min_by_empty_and_enumerator
+
+  minmax_mixed_numerics
+  minmax_raises_on_incomparable_elements
+
+  sum_integers
+  sum_mixed_numerics
+  sum_with_init_and_block
+  sum_concatenates_with_plus
+
+  true
+end
+
+def sort_by_keys
+  raise unless %w[aaa a aa].sort_by { |word| word.size } == %w[a aa aaa]
+  raise unless (1..5).sort_by { |i| -i } == [5, 4, 3, 2, 1]
+  raise unless [].sort_by { |x| x } == []
+end
+
+def sort_by_custom_comparable_keys
+  weight = Class.new do
+    include Comparable
+
+    attr_reader :grams
+
+    def initialize(grams)
+      @grams = grams
+    end
+
+    def <=>(other)
+      grams <=> other.grams
+    end
+  end
+  raise unless [3, 1, 2].sort_by { |i| weight.new(-i) } == [3, 2, 1]
+end
+
+def sort_by_without_block_returns_enumerator
+  raise unless [1, 2].sort_by.is_a?(Enumerator)
+end
+
+def sort_by_raises_on_incomparable_keys
+  [1, 'a'].sort_by { |x| x }
+  raise
+rescue ArgumentError => e
+  raise unless e.message == 'comparison of Integer with String failed'
+end
+
+def min_by_and_max_by
+  words = %w[albatross dog horse]
+  raise unless words.min_by { |word| word.size } == 'dog'
+  raise unless words.max_by { |word| word.size } == 'albatross'
+end
+
+def min_by_empty_and_enumerator
+  raise unless [].min_by { |x| x }.nil?
+  raise unless [].max_by { |x| x }.nil?
+  raise unless [1, 2].min_by.is_a?(Enumerator)
+  raise unless [1, 2].max_by.is_a?(Enumerator)
+end
+
+def minmax_mixed_numerics
+  raise unless [3, 1, 2].minmax == [1, 3]
+  raise unless [1, 2.5, -3].minmax == [-3, 2.5]
+  raise unless [].minmax == [nil, nil]
+  raise unless %w[albatross dog horse].minmax { |a, b| a.size <=> b.size } == %w[dog albatross]
+end
+
+def minmax_raises_on_incomparable_elements
+  [1, nil].minmax
+  raise
+rescue ArgumentError => e
+  raise unless e.message == 'comparison of NilClass with 1 failed'
+end
+
+def sum_integers
+  raise unless [1, 2, 3].sum == 6
+  raise unless [1, 2, 3].sum(10) == 16
+  raise unless [].sum == 0
+end
+
+def sum_mixed_numerics
+  raise unless [1, 2.5].sum == 3.5
+  raise unless (1..4).sum == 10
+end
+
+def sum_with_init_and_block
+  raise unless [1, 2, 3].sum(10) { |i| i * 2 } == 22
+end
+
+def sum_concatenates_with_plus
+  raise unless [[1], [2, 3]].sum([]) == [1, 2, 3]
+  raise unless %w[a b].sum('') == 'ab'
+end
+
+spec if $PROGRAM_NAME == __FILE__
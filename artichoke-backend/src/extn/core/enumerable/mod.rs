@@ -2,6 +2,9 @@ use std::ffi::CStr;
 
 use crate::extn::prelude::*;
 
+pub mod mruby;
+pub mod trampoline;
+
 const ENUMERABLE_CSTR: &CStr = cstr::cstr!("Enumerable");
 
 pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
@@ -18,3 +21,20 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Enumerable;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    const SUBJECT: &str = "Enumerable";
+    const FUNCTIONAL_TEST: &[u8] = include_bytes!("enumerable_functional_test.rb");
+
+    #[test]
+    fn functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+    }
+}
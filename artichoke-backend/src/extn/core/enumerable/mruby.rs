@@ -0,0 +1,91 @@
+use std::ffi::CStr;
+
+use crate::extn::core::artichoke;
+use crate::extn::core::enumerable::trampoline;
+use crate::extn::prelude::*;
+
+const ENUMERABLE_CSTR: &CStr = cstr::cstr!("Enumerable");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_module_defined::<artichoke::Enumerable>() {
+        return Ok(());
+    }
+    // The comparison-heavy parts of some `Enumerable` methods are implemented
+    // with methods in the `Artichoke::Enumerable` module. These functions are
+    // delegated to by Ruby implementations in `enumerable.rb` that run the
+    // given block to collect sort keys.
+    let scope = interp
+        .module_spec::<artichoke::Artichoke>()?
+        .map(EnclosingRubyScope::module)
+        .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
+    let spec = module::Spec::new(interp, "Enumerable", ENUMERABLE_CSTR, Some(scope))?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method("max_by_keys", enumerable_max_by_keys, sys::mrb_args_req(2))?
+        .add_self_method("min_by_keys", enumerable_min_by_keys, sys::mrb_args_req(2))?
+        .add_self_method("minmax", enumerable_minmax, sys::mrb_args_req(1))?
+        .add_self_method("sort_by_keys", enumerable_sort_by_keys, sys::mrb_args_req(2))?
+        .add_self_method("sum", enumerable_sum, sys::mrb_args_req(2))?
+        .define()?;
+    interp.def_module::<artichoke::Enumerable>(spec)?;
+    trace!("Patched Artichoke::Enumerable onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn enumerable_max_by_keys(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (keys, values) = mrb_get_args!(mrb, required = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let keys = Value::from(keys);
+    let values = Value::from(values);
+    let result = trampoline::max_by_keys(&mut guard, keys, values);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn enumerable_min_by_keys(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (keys, values) = mrb_get_args!(mrb, required = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let keys = Value::from(keys);
+    let values = Value::from(values);
+    let result = trampoline::min_by_keys(&mut guard, keys, values);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn enumerable_minmax(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let values = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let values = Value::from(values);
+    let result = trampoline::minmax(&mut guard, values);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn enumerable_sort_by_keys(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (keys, values) = mrb_get_args!(mrb, required = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let keys = Value::from(keys);
+    let values = Value::from(values);
+    let result = trampoline::sort_by_keys(&mut guard, keys, values);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn enumerable_sum(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (values, init) = mrb_get_args!(mrb, required = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let values = Value::from(values);
+    let init = Value::from(init);
+    let result = trampoline::sum(&mut guard, values, init);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -0,0 +1,160 @@
+use std::cmp::Ordering;
+
+use crate::extn::core::array::Array;
+use crate::extn::prelude::*;
+
+/// Compare two values with `<=>`, raising the canonical `ArgumentError` when
+/// the values are not comparable.
+fn compare(interp: &mut Artichoke, left: Value, right: Value) -> Result<Ordering, Error> {
+    let cmp = left.funcall(interp, "<=>", &[right], None)?;
+    if let Ok(cmp) = cmp.try_convert_into::<i64>(interp) {
+        Ok(cmp.cmp(&0))
+    } else {
+        // MRI names the class of the left operand and, as in `rb_cmperr`,
+        // inspects the right operand if it is an immediate or a `Float`, e.g.
+        // `comparison of NilClass with 1 failed`.
+        let mut message = String::from("comparison of ");
+        message.push_str(interp.class_name_for_value(left));
+        message.push_str(" with ");
+        match right.ruby_type() {
+            Ruby::Nil | Ruby::Bool | Ruby::Fixnum | Ruby::Float => {
+                let inspect = right.inspect(interp);
+                message.push_str(&String::from_utf8_lossy(&inspect));
+            }
+            _ => message.push_str(interp.class_name_for_value(right)),
+        }
+        message.push_str(" failed");
+        Err(ArgumentError::from(message).into())
+    }
+}
+
+fn elements(interp: &mut Artichoke, mut ary: Value) -> Result<Vec<Value>, Error> {
+    let array = unsafe { Array::unbox_from_value(&mut ary, interp)? };
+    // The comparisons below may call arbitrary Ruby code, so copy the
+    // elements out of the array before releasing the guard.
+    Ok(array.as_slice().to_vec())
+}
+
+/// Stable bottom-up merge sort over indexes into `keys`.
+///
+/// A handwritten sort is required because the comparator calls into the
+/// interpreter and is fallible.
+fn merge_sort_indexes(interp: &mut Artichoke, keys: &[Value]) -> Result<Vec<usize>, Error> {
+    let len = keys.len();
+    let mut sorted = (0..len).collect::<Vec<_>>();
+    let mut scratch = sorted.clone();
+    let mut width = 1;
+    while width < len {
+        let mut start = 0;
+        while start < len {
+            let mid = usize::min(start + width, len);
+            let end = usize::min(start + 2 * width, len);
+            let mut left = start;
+            let mut right = mid;
+            for slot in &mut scratch[start..end] {
+                // Take from the left run on ties to keep the sort stable.
+                if left < mid
+                    && (right >= end || compare(interp, keys[sorted[left]], keys[sorted[right]])? != Ordering::Greater)
+                {
+                    *slot = sorted[left];
+                    left += 1;
+                } else {
+                    *slot = sorted[right];
+                    right += 1;
+                }
+            }
+            start = end;
+        }
+        std::mem::swap(&mut sorted, &mut scratch);
+        width *= 2;
+    }
+    Ok(sorted)
+}
+
+pub fn sort_by_keys(interp: &mut Artichoke, keys: Value, values: Value) -> Result<Value, Error> {
+    let keys = elements(interp, keys)?;
+    let values = elements(interp, values)?;
+    let indexes = merge_sort_indexes(interp, &keys)?;
+    let sorted = indexes.into_iter().map(|index| values[index]).collect::<Array>();
+    Array::alloc_value(sorted, interp)
+}
+
+pub fn min_by_keys(interp: &mut Artichoke, keys: Value, values: Value) -> Result<Value, Error> {
+    let keys = elements(interp, keys)?;
+    let values = elements(interp, values)?;
+    let mut best = None;
+    for (index, &key) in keys.iter().enumerate() {
+        best = match best {
+            None => Some(index),
+            Some(found) if compare(interp, key, keys[found])? == Ordering::Less => Some(index),
+            best => best,
+        };
+    }
+    Ok(best.map(|index| values[index]).unwrap_or_else(Value::nil))
+}
+
+pub fn max_by_keys(interp: &mut Artichoke, keys: Value, values: Value) -> Result<Value, Error> {
+    let keys = elements(interp, keys)?;
+    let values = elements(interp, values)?;
+    let mut best = None;
+    for (index, &key) in keys.iter().enumerate() {
+        best = match best {
+            None => Some(index),
+            Some(found) if compare(interp, key, keys[found])? == Ordering::Greater => Some(index),
+            best => best,
+        };
+    }
+    Ok(best.map(|index| values[index]).unwrap_or_else(Value::nil))
+}
+
+pub fn minmax(interp: &mut Artichoke, values: Value) -> Result<Value, Error> {
+    let values = elements(interp, values)?;
+    let mut bounds = None;
+    for &value in &values {
+        bounds = if let Some((min, max)) = bounds {
+            let min = if compare(interp, value, min)? == Ordering::Less {
+                value
+            } else {
+                min
+            };
+            let max = if compare(interp, value, max)? == Ordering::Greater {
+                value
+            } else {
+                max
+            };
+            Some((min, max))
+        } else {
+            Some((value, value))
+        };
+    }
+    let (min, max) = bounds.unwrap_or_else(|| (Value::nil(), Value::nil()));
+    Array::alloc_value(Array::from(vec![min, max]), interp)
+}
+
+pub fn sum(interp: &mut Artichoke, values: Value, init: Value) -> Result<Value, Error> {
+    let values = elements(interp, values)?;
+    // Fast path: Integer-only collections are summed without method dispatch.
+    // On overflow, fall back to `Integer#+` so the VM's overflow behavior
+    // applies.
+    if init.ruby_type() == Ruby::Fixnum && values.iter().all(|value| value.ruby_type() == Ruby::Fixnum) {
+        let mut acc = init.try_convert_into::<i64>(interp)?;
+        let mut overflowed = false;
+        for &value in &values {
+            let value = value.try_convert_into::<i64>(interp)?;
+            if let Some(next) = acc.checked_add(value) {
+                acc = next;
+            } else {
+                overflowed = true;
+                break;
+            }
+        }
+        if !overflowed {
+            return Ok(interp.convert(acc));
+        }
+    }
+    let mut acc = init;
+    for &value in &values {
+        acc = acc.funcall(interp, "+", &[value], None)?;
+    }
+    Ok(acc)
+}
@@ -117,7 +117,12 @@ class Enumerator
   # Use of this form is discouraged.  Use Kernel#enum_for or Kernel#to_enum
   # instead.
   def initialize(obj = (not_set = true), meth = :each, *args, &block)
+    @size = nil
     if block
+      # In the block form, the optional leading argument is the size of the
+      # enumeration: an Integer, +Float::INFINITY+, +nil+, or a callable
+      # evaluated lazily by Enumerator#size.
+      @size = obj unless not_set
       obj = Generator.new(&block)
     elsif not_set
       raise ArgumentError, 'wrong number of arguments (given 0, expected 1+)'
@@ -142,12 +147,29 @@ class Enumerator
     @obj = obj.obj
     @meth = obj.meth
     @args = obj.args
+    @size = obj.instance_variable_get(:@size)
     @fib = nil
     @lookahead = nil
     @feedvalue = nil
     self
   end
 
+  ##
+  # call-seq:
+  #   e.size -> int, Float::INFINITY or nil
+  #
+  # Returns the size of the enumerator, or +nil+ if it can't be calculated
+  # lazily. Callable sizes are invoked with the enumerator's arguments each
+  # time +size+ is called.
+  #
+  def size
+    if @size.respond_to?(:call)
+      @size.call(*@args)
+    else
+      @size
+    end
+  end
+
   ##
   # call-seq:
   #   e.with_index(offset = 0) {|(*args), idx| ... }
@@ -649,8 +671,12 @@ module Kernel
   #       # => returns an Enumerator when called without a block
   #     enum.first(4) # => [1, 1, 1, 2]
   #
-  def to_enum(meth = :each, *args)
-    Enumerator.new self, meth, *args
+  def to_enum(meth = :each, *args, &size_block)
+    enum = Enumerator.new self, meth, *args
+    # An optional block is the size calculation for the enumerator, invoked
+    # lazily by Enumerator#size.
+    enum.instance_variable_set(:@size, size_block) unless size_block.nil?
+    enum
   end
   alias enum_for to_enum
 end
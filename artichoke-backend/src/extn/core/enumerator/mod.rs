@@ -19,3 +19,86 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Enumerator;
+
+/// Construct an `Enumerator` over `method` on `value` with a recorded size.
+///
+/// This is the Rust equivalent of `value.to_enum(method) { size }`. The size
+/// is attached to the enumerator directly rather than by evaluating a Ruby
+/// size-lambda snippet; `Enumerator#size` returns non-callable sizes as is.
+/// Pass [`None`] for enumerations whose size cannot be calculated lazily.
+pub fn obj_to_enum_with_size(
+    interp: &mut Artichoke,
+    value: &Value,
+    method: &str,
+    size: Option<i64>,
+) -> Result<Value, Error> {
+    let method = interp.try_convert_mut(method)?;
+    let enumerator = value.funcall(interp, "to_enum", &[method], None)?;
+    if let Some(size) = size {
+        let name = interp.try_convert_mut("@size")?;
+        let size = interp.convert(size);
+        enumerator.funcall(interp, "instance_variable_set", &[name, size], None)?;
+    }
+    Ok(enumerator)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    use super::obj_to_enum_with_size;
+
+    #[test]
+    fn string_iteration_enumerators_have_sizes() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval("'héllo'.each_char.size".as_bytes()).unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 5);
+        let result = interp.eval("'héllo'.each_byte.size".as_bytes()).unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 6);
+        let result = interp.eval("'héllo'.each_codepoint.size".as_bytes()).unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 5);
+        // The size of a line enumeration depends on the content.
+        let result = interp.eval(b"\"a\\nb\\n\".each_line.size.nil?").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn enumerator_size_is_computed_lazily() {
+        let mut interp = interpreter().unwrap();
+        let result = interp
+            .eval(b"s = 'ab'; enum = s.each_byte; s << 'c'; enum.size")
+            .unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 3);
+    }
+
+    #[test]
+    fn each_char_enumerator_round_trips() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval("'héllo'.each_char.to_a.join == 'héllo'".as_bytes()).unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn each_byte_enumerator_supports_lazy_chaining() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"'abc'.each_byte.lazy.map(&:succ).first(3)").unwrap();
+        let result = result.try_convert_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(result, [98, 99, 100]);
+    }
+
+    #[test]
+    fn sized_enumerator_from_rust_glue() {
+        let mut interp = interpreter().unwrap();
+        let ary = interp.eval(b"[1, 2, 3]").unwrap();
+        let enumerator = obj_to_enum_with_size(&mut interp, &ary, "each", Some(3)).unwrap();
+        let size = enumerator.funcall(&mut interp, "size", &[], None).unwrap();
+        assert_eq!(size.try_convert_into::<i64>(&interp).unwrap(), 3);
+        let elements = enumerator.funcall(&mut interp, "to_a", &[], None).unwrap();
+        let elements = elements.try_convert_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(elements, [1, 2, 3]);
+
+        let enumerator = obj_to_enum_with_size(&mut interp, &ary, "each", None).unwrap();
+        let size = enumerator.funcall(&mut interp, "size", &[], None).unwrap();
+        assert!(size.is_nil());
+    }
+}
@@ -0,0 +1,83 @@
+# frozen_string_literal: true
+
+def spec
+  env_element_reference_and_assignment
+  env_fetch
+  env_key_p
+  env_delete
+  env_to_h
+  env_each
+  env_name_with_equals_is_invalid
+  env_name_with_nul_is_invalid
+
+  true
+end
+
+def env_element_reference_and_assignment
+  raise unless ENV['ARTICHOKE_TEST_VAR'].nil?
+
+  ENV['ARTICHOKE_TEST_VAR'] = 'set'
+  raise unless ENV['ARTICHOKE_TEST_VAR'] == 'set'
+
+  ENV['ARTICHOKE_TEST_VAR'] = nil
+  raise unless ENV['ARTICHOKE_TEST_VAR'].nil?
+end
+
+def env_fetch
+  ENV['ARTICHOKE_FETCH_VAR'] = 'present'
+  raise unless ENV.fetch('ARTICHOKE_FETCH_VAR') == 'present'
+  raise unless ENV.fetch('ARTICHOKE_MISSING_VAR', 'default') == 'default'
+  raise unless ENV.fetch('ARTICHOKE_MISSING_VAR') { |name| name.length } == 21
+
+  begin
+    ENV.fetch('ARTICHOKE_MISSING_VAR')
+    raise 'expected KeyError'
+  rescue KeyError => e
+    raise unless e.message.include?('ARTICHOKE_MISSING_VAR')
+  end
+end
+
+def env_key_p
+  ENV['ARTICHOKE_KEY_VAR'] = 'here'
+  raise unless ENV.key?('ARTICHOKE_KEY_VAR')
+  raise if ENV.key?('ARTICHOKE_ABSENT_VAR')
+end
+
+def env_delete
+  ENV['ARTICHOKE_DELETE_VAR'] = 'doomed'
+  raise unless ENV.delete('ARTICHOKE_DELETE_VAR') == 'doomed'
+  raise unless ENV['ARTICHOKE_DELETE_VAR'].nil?
+  raise unless ENV.delete('ARTICHOKE_DELETE_VAR').nil?
+
+  yielded = nil
+  ENV.delete('ARTICHOKE_DELETE_VAR') { |name| yielded = name }
+  raise unless yielded == 'ARTICHOKE_DELETE_VAR'
+end
+
+def env_to_h
+  ENV['ARTICHOKE_TO_H_VAR'] = 'hashed'
+  pairs = ENV.to_h
+  raise unless pairs.is_a?(Hash)
+  raise unless pairs['ARTICHOKE_TO_H_VAR'] == 'hashed'
+end
+
+def env_each
+  ENV['ARTICHOKE_EACH_VAR'] = 'iterated'
+  seen = {}
+  ENV.each { |name, value| seen[name] = value }
+  raise unless seen['ARTICHOKE_EACH_VAR'] == 'iterated'
+end
+
+def env_name_with_equals_is_invalid
+  ENV['ARTICHOKE=INVALID'] = 'value'
+  raise 'expected SystemCallError'
+rescue SystemCallError => e
+  raise unless e.message.include?('setenv')
+end
+
+def env_name_with_nul_is_invalid
+  ENV["ARTICHOKE\0INVALID"] = 'value'
+  raise 'expected ArgumentError'
+rescue ArgumentError => e
+  raise unless e.message.include?('null byte')
+end
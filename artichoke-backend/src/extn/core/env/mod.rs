@@ -28,9 +28,12 @@ use crate::extn::prelude::*;
 pub mod mruby;
 pub mod trampoline;
 
-#[cfg(not(feature = "core-env-system"))]
+// Prefer the in-memory backend on WebAssembly targets even when the
+// **core-env-system** feature is enabled since wasm has no process
+// environment to proxy.
+#[cfg(any(not(feature = "core-env-system"), target_family = "wasm"))]
 type Backend = spinoso_env::Memory;
-#[cfg(feature = "core-env-system")]
+#[cfg(all(feature = "core-env-system", not(target_family = "wasm")))]
 type Backend = spinoso_env::System;
 
 #[derive(Default, Debug)]
@@ -59,6 +62,23 @@ impl Environ {
     }
 }
 
+impl crate::core::Environ for Environ {
+    type Error = Error;
+
+    fn get(&self, name: &[u8]) -> Result<Option<Cow<'_, [u8]>>, Self::Error> {
+        Self::get(self, name)
+    }
+
+    fn set(&mut self, name: &[u8], value: Option<&[u8]>) -> Result<(), Self::Error> {
+        self.put(name, value)
+    }
+
+    fn to_map(&self) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Self::Error> {
+        let map = Self::to_map(self)?;
+        Ok(map.into_iter().collect())
+    }
+}
+
 impl HeapAllocatedData for Environ {
     const RUBY_TYPE: &'static str = "Artichoke::Environ";
 }
@@ -84,3 +104,23 @@ impl From<EnvError> for Error {
         }
     }
 }
+
+// The functional test mutates the environment, so it only runs against the
+// deterministic in-memory backend to avoid leaking state into the host
+// process environment.
+#[cfg(all(test, not(feature = "core-env-system")))]
+mod tests {
+    use crate::test::prelude::*;
+
+    const SUBJECT: &str = "ENV";
+    const FUNCTIONAL_TEST: &[u8] = include_bytes!("env_functional_test.rb");
+
+    #[test]
+    fn functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+    }
+}
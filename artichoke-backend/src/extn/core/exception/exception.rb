@@ -1,5 +1,13 @@
 # frozen_string_literal: true
 
+class Exception
+  attr_reader :cause
+end
+
+class FrozenError
+  attr_reader :receiver
+end
+
 class KeyError
   attr_reader :key, :receiver
 
@@ -20,7 +28,7 @@ class NameError
 end
 
 class NoMethodError
-  attr_reader :args
+  attr_reader :args, :receiver
 
   def initialize(message = nil, name = nil, args = nil)
     @args = args
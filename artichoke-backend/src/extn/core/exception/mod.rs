@@ -21,9 +21,12 @@
 //! [`spinoso-exception`]: spinoso_exception
 
 use std::borrow::Cow;
+use std::io;
 
 #[doc(inline)]
 pub use spinoso_exception::core::*;
+#[doc(inline)]
+pub use spinoso_exception::Errno;
 
 use crate::extn::prelude::*;
 
@@ -40,6 +43,12 @@ pub mod mruby;
 /// [backtraces]: RubyException::vm_backtrace
 macro_rules! ruby_exception_impl {
     ($exc:ident) => {
+        ruby_exception_impl!(@impl $exc,);
+    };
+    ($exc:ident { $($ivar:literal => $accessor:ident),+ $(,)? }) => {
+        ruby_exception_impl!(@impl $exc, $($ivar => $accessor),+);
+    };
+    (@impl $exc:ident, $($ivar:literal => $accessor:ident),*) => {
         impl From<$exc> for Error {
             fn from(exception: $exc) -> Error {
                 Error::from(Box::<dyn RubyException>::from(exception))
@@ -81,6 +90,15 @@ macro_rules! ruby_exception_impl {
             fn as_mrb_value(&self, interp: &mut Artichoke) -> Option<sys::mrb_value> {
                 let message = interp.try_convert_mut(self.message()).ok()?;
                 let value = interp.new_instance::<Self>(&[message]).ok().flatten()?;
+                $(
+                    if let Some(field) = Self::$accessor(self) {
+                        let ivar = interp.try_convert_mut($ivar).ok()?;
+                        let field = interp.try_convert_mut(field.to_vec()).ok()?;
+                        value
+                            .funcall(interp, "instance_variable_set", &[ivar, field], None)
+                            .ok()?;
+                    }
+                )*
                 Some(value.inner())
             }
         }
@@ -105,19 +123,98 @@ ruby_exception_impl!(FiberError);
 ruby_exception_impl!(IOError);
 ruby_exception_impl!(EOFError);
 ruby_exception_impl!(IndexError);
-ruby_exception_impl!(KeyError);
+ruby_exception_impl!(KeyError {
+    "@key" => key,
+    "@receiver" => receiver,
+});
 ruby_exception_impl!(StopIteration);
 ruby_exception_impl!(LocalJumpError);
-ruby_exception_impl!(NameError);
-ruby_exception_impl!(NoMethodError);
+ruby_exception_impl!(NameError {
+    "@name" => unresolved_name,
+});
+ruby_exception_impl!(NoMethodError {
+    "@args" => args,
+    "@receiver" => receiver,
+});
 ruby_exception_impl!(RangeError);
 ruby_exception_impl!(FloatDomainError);
 ruby_exception_impl!(RegexpError);
 // Default `Exception` type for `raise`.
 ruby_exception_impl!(RuntimeError);
-ruby_exception_impl!(FrozenError);
+ruby_exception_impl!(FrozenError {
+    "@receiver" => receiver,
+});
 ruby_exception_impl!(SystemCallError);
-// ruby_exception_impl!(Errno::*);
+// `Errno` maps to one of many `Errno::*` classes depending on the errno
+// number it was constructed with. These classes are defined in generated Ruby
+// source rather than the class registry, so `Errno`'s glue is hand-written
+// instead of generated by `ruby_exception_impl!`.
+impl From<Errno> for Error {
+    fn from(exception: Errno) -> Error {
+        Error::from(Box::<dyn RubyException>::from(exception))
+    }
+}
+
+impl From<Box<Errno>> for Error {
+    fn from(exception: Box<Errno>) -> Error {
+        Error::from(Box::<dyn RubyException>::from(exception))
+    }
+}
+
+impl From<Errno> for Box<dyn RubyException> {
+    fn from(exception: Errno) -> Box<dyn RubyException> {
+        Box::new(exception)
+    }
+}
+
+impl From<Box<Errno>> for Box<dyn RubyException> {
+    fn from(exception: Box<Errno>) -> Box<dyn RubyException> {
+        exception
+    }
+}
+
+impl RubyException for Errno {
+    fn message(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(Self::message(self))
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(Self::name(self))
+    }
+
+    fn vm_backtrace(&self, interp: &mut Artichoke) -> Option<Vec<Vec<u8>>> {
+        let _ = interp;
+        None
+    }
+
+    fn as_mrb_value(&self, interp: &mut Artichoke) -> Option<sys::mrb_value> {
+        let message = interp.try_convert_mut(RubyException::message(self)).ok()?;
+        if let Some(constant) = self.constant() {
+            let class = interp.get_module_constant::<Self>(constant).ok()??;
+            let value = class.funcall(interp, "new", &[message], None).ok()?;
+            Some(value.inner())
+        } else {
+            // Errno numbers without a generated `Errno::*` class raise the
+            // `SystemCallError` base class.
+            let value = interp.new_instance::<SystemCallError>(&[message]).ok().flatten()?;
+            Some(value.inner())
+        }
+    }
+}
+
+impl Artichoke {
+    /// Convert an [`io::Error`] into an `Errno::*` exception with an optional
+    /// context, such as a path.
+    ///
+    /// The returned [`Error`] raises the `Errno::*` class matching the I/O
+    /// error's errno number, or `SystemCallError` if the errno number has no
+    /// corresponding class.
+    pub fn raise_io_error(&mut self, err: &io::Error, context: Option<&[u8]>) -> Error {
+        let _ = self;
+        Errno::from_io_error(err, context).into()
+    }
+}
+
 ruby_exception_impl!(ThreadError);
 ruby_exception_impl!(TypeError);
 ruby_exception_impl!(ZeroDivisionError);
@@ -128,6 +225,8 @@ ruby_exception_impl!(Fatal);
 
 #[cfg(test)]
 mod tests {
+    use std::io;
+
     use bstr::ByteSlice;
 
     use crate::test::prelude::*;
@@ -166,4 +265,160 @@ mod tests {
         let actual_backtrace = bstr::join("\n", err.vm_backtrace(&mut interp).unwrap());
         assert_eq!(expected_backtrace, actual_backtrace);
     }
+
+    #[test]
+    fn raise_in_rescue_sets_cause() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"raise 'a' rescue raise 'b'").unwrap_err();
+        assert_eq!(b"b".as_bstr(), err.message().as_ref().as_bstr());
+        let cause = interp.eval(b"$!.cause.message").unwrap();
+        let cause = cause.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(cause, "a");
+    }
+
+    struct RunKeyError;
+
+    unsafe extern "C" fn run_key_error(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        unwrap_interpreter!(mrb, to => guard);
+        let exc = KeyError::with_key_and_receiver(
+            b"key not found: :missing".to_vec(),
+            b":missing".to_vec(),
+            b"{}".to_vec(),
+        );
+        error::raise(guard, exc)
+    }
+
+    impl File for RunKeyError {
+        type Artichoke = Artichoke;
+
+        type Error = Error;
+
+        fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+            let spec = class::Spec::new("RunKeyError", cstr::cstr!("RunKeyError"), None, None).unwrap();
+            class::Builder::for_spec(interp, &spec)
+                .add_self_method("run", run_key_error, sys::mrb_args_none())?
+                .define()?;
+            interp.def_class::<Self>(spec)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn raise_key_error_populates_key_and_receiver() {
+        let mut interp = interpreter().unwrap();
+        RunKeyError::require(&mut interp).unwrap();
+        let key = interp
+            .eval(b"begin; RunKeyError.run; rescue KeyError => e; e.key; end")
+            .unwrap();
+        let key = key.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(key, ":missing");
+        let receiver = interp
+            .eval(b"begin; RunKeyError.run; rescue KeyError => e; e.receiver; end")
+            .unwrap();
+        let receiver = receiver.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(receiver, "{}");
+    }
+
+    struct RunNameError;
+
+    unsafe extern "C" fn run_name_error(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        unwrap_interpreter!(mrb, to => guard);
+        let exc = NameError::with_name(b"undefined local variable or method `garble'".to_vec(), b"garble".to_vec());
+        error::raise(guard, exc)
+    }
+
+    impl File for RunNameError {
+        type Artichoke = Artichoke;
+
+        type Error = Error;
+
+        fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+            let spec = class::Spec::new("RunNameError", cstr::cstr!("RunNameError"), None, None).unwrap();
+            class::Builder::for_spec(interp, &spec)
+                .add_self_method("run", run_name_error, sys::mrb_args_none())?
+                .define()?;
+            interp.def_class::<Self>(spec)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn raise_name_error_populates_name() {
+        let mut interp = interpreter().unwrap();
+        RunNameError::require(&mut interp).unwrap();
+        let name = interp
+            .eval(b"begin; RunNameError.run; rescue NameError => e; e.name; end")
+            .unwrap();
+        let name = name.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(name, "garble");
+    }
+
+    #[test]
+    fn errno_classes_subclass_system_call_error() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"Errno::ENOENT < SystemCallError").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let errno = interp.eval(b"Errno::EACCES::Errno").unwrap();
+        let errno = errno.try_convert_into::<i64>(&interp).unwrap();
+        assert_eq!(errno, 13);
+    }
+
+    #[test]
+    fn raise_io_error_maps_to_errno_class() {
+        let mut interp = interpreter().unwrap();
+        let err = io::Error::new(io::ErrorKind::NotFound, "not found");
+        let error = interp.raise_io_error(&err, Some(b"/nope"));
+        assert_eq!("Errno::ENOENT", error.name().as_ref());
+        assert_eq!(
+            b"No such file or directory - /nope".as_bstr(),
+            error.message().as_ref().as_bstr()
+        );
+        let err = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        let error = interp.raise_io_error(&err, None);
+        assert_eq!("Errno::EACCES", error.name().as_ref());
+        assert_eq!(b"Permission denied".as_bstr(), error.message().as_ref().as_bstr());
+    }
+
+    #[test]
+    fn unknown_errno_raises_system_call_error() {
+        let mut interp = interpreter().unwrap();
+        let error = Error::from(Errno::new(9999));
+        assert_eq!("SystemCallError", error.name().as_ref());
+        assert_eq!(b"Unknown error 9999".as_bstr(), error.message().as_ref().as_bstr());
+    }
+
+    struct RunErrno;
+
+    unsafe extern "C" fn run_errno(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+        unwrap_interpreter!(mrb, to => guard);
+        let err = io::Error::from_raw_os_error(2);
+        let exc = Errno::from_io_error(&err, Some(b"/missing/file"));
+        error::raise(guard, exc)
+    }
+
+    impl File for RunErrno {
+        type Artichoke = Artichoke;
+
+        type Error = Error;
+
+        fn require(interp: &mut Artichoke) -> Result<(), Self::Error> {
+            let spec = class::Spec::new("RunErrno", cstr::cstr!("RunErrno"), None, None).unwrap();
+            class::Builder::for_spec(interp, &spec)
+                .add_self_method("run", run_errno, sys::mrb_args_none())?
+                .define()?;
+            interp.def_class::<Self>(spec)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn raised_errno_is_rescuable_by_class() {
+        let mut interp = interpreter().unwrap();
+        RunErrno::require(&mut interp).unwrap();
+        let message = interp
+            .eval(b"begin; RunErrno.run; rescue Errno::ENOENT => e; e.message; end")
+            .unwrap();
+        let message = message.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(message, "No such file or directory - /missing/file");
+    }
 }
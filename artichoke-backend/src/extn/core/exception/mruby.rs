@@ -1,6 +1,9 @@
 //! FFI glue between the Rust trampolines and the mruby C interpreter.
 
 use std::ffi::CStr;
+use std::fmt::Write as _;
+
+use spinoso_exception::errno::ERRNO_TABLE;
 
 use crate::extn::prelude::*;
 
@@ -32,6 +35,7 @@ const REGEXP_CSTR: &CStr = cstr::cstr!("RegexpError");
 const RUNTIME_CSTR: &CStr = cstr::cstr!("RuntimeError");
 const FROZEN_CSTR: &CStr = cstr::cstr!("FrozenError");
 const SYSTEM_CALL_CSTR: &CStr = cstr::cstr!("SystemCallError");
+const ERRNO_CSTR: &CStr = cstr::cstr!("Errno");
 const THREAD_CSTR: &CStr = cstr::cstr!("ThreadError");
 const TYPE_CSTR: &CStr = cstr::cstr!("TypeError");
 const ZERO_DIVISION_CSTR: &CStr = cstr::cstr!("ZeroDivisionError");
@@ -208,6 +212,20 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .define()?;
     interp.def_class::<SystemCallError>(systemcall_spec)?;
 
+    // The `Errno::*` classes are generated from the errno table in
+    // `spinoso-exception`. Each class subclasses `SystemCallError` and exposes
+    // its errno number in an `Errno` constant, as in MRI.
+    let errno_spec = module::Spec::new(interp, "Errno", ERRNO_CSTR, None)?;
+    module::Builder::for_spec(interp, &errno_spec).define()?;
+    interp.def_module::<Errno>(errno_spec)?;
+    let mut errno_classes = String::from("module Errno\n");
+    for (errno, constant, _) in ERRNO_TABLE {
+        writeln!(errno_classes, "  class {} < SystemCallError; Errno = {}; end", constant, errno)
+            .map_err(WriteError::from)?;
+    }
+    errno_classes.push_str("end\n");
+    interp.eval(errno_classes.as_bytes())?;
+
     let thread_spec = class::Spec::new("ThreadError", THREAD_CSTR, None, None)?;
     class::Builder::for_spec(interp, &thread_spec)
         .with_super_class::<StandardError, _>("StandardError")?
@@ -0,0 +1,84 @@
+# frozen_string_literal: true
+
+def spec
+  fnmatch_literal_and_wildcards
+  fnmatch_leading_dot
+  fnmatch_character_classes
+  fnmatch_pathname
+  fnmatch_recursive_pathname
+  fnmatch_casefold
+  fnmatch_noescape
+  fnmatch_extglob
+  fnmatch_flag_constants
+
+  true
+end
+
+def fnmatch_literal_and_wildcards
+  raise unless File.fnmatch('cat', 'cat')
+  raise if File.fnmatch('cat', 'category')
+  raise unless File.fnmatch('c?t', 'cat')
+  raise if File.fnmatch('c??t', 'cat')
+  raise unless File.fnmatch('c*', 'cats')
+  raise unless File.fnmatch('*', 'cat')
+  raise unless File.fnmatch?('ca*t*', 'carrot')
+end
+
+def fnmatch_leading_dot
+  raise if File.fnmatch('*', '.profile')
+  raise unless File.fnmatch('*', '.profile', File::FNM_DOTMATCH)
+  raise unless File.fnmatch('.*', '.profile')
+end
+
+def fnmatch_character_classes
+  raise unless File.fnmatch('c[a-d]t', 'cat')
+  raise if File.fnmatch('c[e-z]t', 'cat')
+  raise unless File.fnmatch('c[!e-z]t', 'cat')
+  raise unless File.fnmatch('c[^e-z]t', 'cat')
+end
+
+def fnmatch_pathname
+  raise unless File.fnmatch('*', 'lib/song.rb')
+  raise if File.fnmatch('*', 'lib/song.rb', File::FNM_PATHNAME)
+  raise unless File.fnmatch('*/*', 'lib/song.rb', File::FNM_PATHNAME)
+end
+
+def fnmatch_recursive_pathname
+  flags = File::FNM_PATHNAME
+  raise unless File.fnmatch('**/*.rb', 'main.rb', flags)
+  raise unless File.fnmatch('**/*.rb', 'lib/song.rb', flags)
+  raise unless File.fnmatch('**/*.rb', 'lib/song/artist.rb', flags)
+  raise if File.fnmatch('**/*.rb', 'lib/.hidden/artist.rb', flags)
+  raise unless File.fnmatch('**/*.rb', 'lib/.hidden/artist.rb', flags | File::FNM_DOTMATCH)
+end
+
+def fnmatch_casefold
+  raise if File.fnmatch('cat', 'CAT')
+  raise unless File.fnmatch('cat', 'CAT', File::FNM_CASEFOLD)
+  raise unless File.fnmatch('c[a-d]t', 'CAT', File::FNM_CASEFOLD)
+end
+
+def fnmatch_noescape
+  raise unless File.fnmatch('\?', '?')
+  raise if File.fnmatch('\?', 'a')
+  raise unless File.fnmatch('\?', '\a', File::FNM_NOESCAPE)
+  raise if File.fnmatch('\?', '?', File::FNM_NOESCAPE)
+end
+
+def fnmatch_extglob
+  raise if File.fnmatch('c{at,ub}s', 'cats')
+  raise unless File.fnmatch('c{at,ub}s', 'cats', File::FNM_EXTGLOB)
+  raise unless File.fnmatch('c{at,ub}s', 'cubs', File::FNM_EXTGLOB)
+  raise if File.fnmatch('c{at,ub}s', 'cabs', File::FNM_EXTGLOB)
+end
+
+def fnmatch_flag_constants
+  raise unless File::FNM_NOESCAPE == 0x01
+  raise unless File::FNM_PATHNAME == 0x02
+  raise unless File::FNM_DOTMATCH == 0x04
+  raise unless File::FNM_CASEFOLD == 0x08
+  raise unless File::FNM_EXTGLOB == 0x10
+  raise unless File::FNM_SYSCASE.zero?
+end
+
+spec if $PROGRAM_NAME == __FILE__
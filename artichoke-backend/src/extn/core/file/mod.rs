@@ -0,0 +1,41 @@
+//! File name pattern matching with `File.fnmatch`.
+//!
+//! This module implements a subset of the [`File`] class from Ruby Core:
+//! [`File.fnmatch`], the `FNM_*` flag constants, and the [`File.fnmatch?`]
+//! alias. The matcher itself lives in [`fnmatch`] and is shared with
+//! [`Dir.glob`].
+//!
+//! You can use this API in your application:
+//!
+//! ```ruby
+//! File.fnmatch('lib/**/*.rb', 'lib/song/artist.rb', File::FNM_PATHNAME)
+//! ```
+//!
+//! [`File`]: https://ruby-doc.org/core-2.6.3/File.html
+//! [`File.fnmatch`]: https://ruby-doc.org/core-2.6.3/File.html#method-c-fnmatch
+//! [`File.fnmatch?`]: https://ruby-doc.org/core-2.6.3/File.html#method-c-fnmatch-3F
+//! [`fnmatch`]: crate::extn::core::dir::fnmatch
+//! [`Dir.glob`]: https://ruby-doc.org/core-2.6.3/Dir.html#method-c-glob
+
+pub mod mruby;
+pub mod trampoline;
+
+#[derive(Debug, Clone, Copy)]
+pub struct File;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    const SUBJECT: &str = "File";
+    const FUNCTIONAL_TEST: &[u8] = include_bytes!("file_functional_test.rb");
+
+    #[test]
+    fn functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+    }
+}
@@ -0,0 +1,49 @@
+use std::ffi::CStr;
+
+use crate::extn::core::dir::fnmatch;
+use crate::extn::core::file::{trampoline, File};
+use crate::extn::prelude::*;
+
+const FILE_CSTR: &CStr = cstr::cstr!("File");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<File>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("File", FILE_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_self_method("fnmatch", file_fnmatch, sys::mrb_args_req_and_opt(2, 1))?
+        .add_self_method("fnmatch?", file_fnmatch, sys::mrb_args_req_and_opt(2, 1))?
+        .define()?;
+    interp.def_class::<File>(spec)?;
+
+    let noescape = interp.convert(fnmatch::NOESCAPE);
+    interp.define_class_constant::<File>("FNM_NOESCAPE", noescape)?;
+    let pathname = interp.convert(fnmatch::PATHNAME);
+    interp.define_class_constant::<File>("FNM_PATHNAME", pathname)?;
+    let dotmatch = interp.convert(fnmatch::DOTMATCH);
+    interp.define_class_constant::<File>("FNM_DOTMATCH", dotmatch)?;
+    let casefold = interp.convert(fnmatch::CASEFOLD);
+    interp.define_class_constant::<File>("FNM_CASEFOLD", casefold)?;
+    let extglob = interp.convert(fnmatch::EXTGLOB);
+    interp.define_class_constant::<File>("FNM_EXTGLOB", extglob)?;
+    // Artichoke matches paths the same way on every platform.
+    let syscase = interp.convert(0_i64);
+    interp.define_class_constant::<File>("FNM_SYSCASE", syscase)?;
+
+    trace!("Patched File onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn file_fnmatch(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (pattern, path, flags) = mrb_get_args!(mrb, required = 2, optional = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let pattern = Value::from(pattern);
+    let path = Value::from(path);
+    let flags = flags.map(Value::from);
+    let result = trampoline::fnmatch(&mut guard, pattern, path, flags);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -0,0 +1,24 @@
+use crate::convert::{implicitly_convert_to_int, implicitly_convert_to_string};
+use crate::extn::core::dir::fnmatch;
+use crate::extn::prelude::*;
+
+pub fn fnmatch(
+    interp: &mut Artichoke,
+    mut pattern: Value,
+    mut path: Value,
+    flags: Option<Value>,
+) -> Result<Value, Error> {
+    // Safety:
+    //
+    // Convert the pattern and path to owned byte vecs to ensure the underlying
+    // `RString`s are not garbage collected while matching.
+    let pattern = unsafe { implicitly_convert_to_string(interp, &mut pattern)? }.to_vec();
+    let path = unsafe { implicitly_convert_to_string(interp, &mut path)? }.to_vec();
+    let flags = if let Some(flags) = flags {
+        implicitly_convert_to_int(interp, flags)?
+    } else {
+        0
+    };
+    let matched = fnmatch::fnmatch(&pattern, &path, flags);
+    Ok(interp.convert(matched))
+}
@@ -0,0 +1,113 @@
+//! The Ruby GC module.
+//!
+//! The GC module provides an interface to Ruby's mark and sweep garbage
+//! collection mechanism.
+//!
+//! You can use the `GC` module by accessing it in the interpreter. `GC` is
+//! globally available in the root namespace.
+//!
+//! ```ruby
+//! GC.start
+//! GC.stat[:count]
+//! ```
+//!
+//! This module is backed by the same [`MrbGarbageCollection`] primitives that
+//! are available to embedders from Rust, so Ruby code and Rust code observe
+//! consistent heap statistics.
+//!
+//! [`MrbGarbageCollection`]: crate::gc::MrbGarbageCollection
+
+pub mod mruby;
+pub mod trampoline;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Gc;
+
+#[cfg(test)]
+mod tests {
+    use crate::gc::State;
+    use crate::test::prelude::*;
+
+    #[test]
+    fn allocating_objects_increases_live_object_count() {
+        let mut interp = interpreter().unwrap();
+        let baseline = interp.heap_stats().unwrap();
+        interp.eval(b"$strings = Array.new(1000) { 'gc stat test' }").unwrap();
+        let stats = interp.heap_stats().unwrap();
+        assert!(
+            stats.live_objects > baseline.live_objects,
+            "Allocating 1000 strings should increase the live object count"
+        );
+        let live = interp
+            .eval(b"GC.stat[:live]")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        assert!(live > i64::from(baseline.live_objects));
+    }
+
+    #[test]
+    fn gc_start_increments_count() {
+        let mut interp = interpreter().unwrap();
+        let count = interp
+            .eval(b"GC.count")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        interp.eval(b"GC.start").unwrap();
+        let after = interp
+            .eval(b"GC.stat[:count]")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        assert_eq!(after, count + 1);
+    }
+
+    #[test]
+    fn rust_and_ruby_views_agree() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"GC.start").unwrap();
+        interp.incremental_gc().unwrap();
+        let full_count = interp
+            .eval(b"GC.stat[:full_count]")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        let incremental_count = interp
+            .eval(b"GC.stat[:incremental_count]")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        let stats = interp.heap_stats().unwrap();
+        assert_eq!(i64::try_from(stats.full_gc_runs).unwrap(), full_count);
+        assert_eq!(i64::try_from(stats.incremental_gc_runs).unwrap(), incremental_count);
+        assert!(stats.pages >= 1);
+        assert!(stats.capacity >= stats.pages);
+    }
+
+    #[test]
+    fn enable_and_disable_report_previous_state() {
+        let mut interp = interpreter().unwrap();
+        // GC starts out enabled, so the first `GC.disable` reports that GC was
+        // not already disabled.
+        let already_disabled = interp
+            .eval(b"GC.disable")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(!already_disabled);
+        assert_eq!(interp.heap_stats().unwrap().enabled, State::Disabled);
+        let was_disabled = interp
+            .eval(b"GC.enable")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(was_disabled);
+        assert_eq!(interp.heap_stats().unwrap().enabled, State::Enabled);
+        assert!(interp
+            .eval(b"GC.stat[:enabled]")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap());
+    }
+}
@@ -0,0 +1,77 @@
+//! FFI glue between the Rust trampolines and the mruby C interpreter.
+
+use std::ffi::CStr;
+
+use crate::extn::core::gc::{trampoline, Gc};
+use crate::extn::prelude::*;
+
+const GC_CSTR: &CStr = cstr::cstr!("GC");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_module_defined::<Gc>() {
+        return Ok(());
+    }
+    // mruby defines a `GC` module in `gc.c`. Reopen it and replace `start`,
+    // `enable`, and `disable` so collections triggered from Ruby are counted
+    // in the interpreter state and visible to `GC.count` and `GC.stat`.
+    let spec = module::Spec::new(interp, "GC", GC_CSTR, None)?;
+    module::Builder::for_spec(interp, &spec)
+        .add_self_method("start", gc_start, sys::mrb_args_none())?
+        .add_self_method("enable", gc_enable, sys::mrb_args_none())?
+        .add_self_method("disable", gc_disable, sys::mrb_args_none())?
+        .add_self_method("count", gc_count, sys::mrb_args_none())?
+        .add_self_method("stat", gc_stat, sys::mrb_args_none())?
+        .define()?;
+    interp.def_module::<Gc>(spec)?;
+    Ok(())
+}
+
+unsafe extern "C" fn gc_start(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let result = trampoline::start(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn gc_enable(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let result = trampoline::enable(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn gc_disable(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let result = trampoline::disable(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn gc_count(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let result = trampoline::count(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn gc_stat(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let result = trampoline::stat(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
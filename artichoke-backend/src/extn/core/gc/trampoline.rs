@@ -0,0 +1,63 @@
+use crate::extn::core::symbol::Symbol;
+use crate::extn::prelude::*;
+use crate::gc::{GcControl, HeapStats, MrbGarbageCollection, Phase, State};
+
+fn symbol_for(interp: &mut Artichoke, name: &'static str) -> Result<Value, Error> {
+    let mut bytes = name.as_bytes().to_vec();
+    bytes.push(b'\0');
+    let sym = interp.intern_bytes_with_trailing_nul(bytes)?;
+    Symbol::alloc_value(sym.into(), interp)
+}
+
+/// Total count of GC runs, full and incremental.
+fn total_gc_runs(stats: &HeapStats) -> i64 {
+    let runs = stats.full_gc_runs.saturating_add(stats.incremental_gc_runs);
+    i64::try_from(runs).unwrap_or(i64::MAX)
+}
+
+pub fn start(interp: &mut Artichoke) -> Result<Value, Error> {
+    interp.gc(GcControl::Full)?;
+    Ok(Value::nil())
+}
+
+pub fn enable(interp: &mut Artichoke) -> Result<Value, Error> {
+    let prior = interp.gc(GcControl::Enable)?;
+    Ok(interp.convert(prior == State::Disabled))
+}
+
+pub fn disable(interp: &mut Artichoke) -> Result<Value, Error> {
+    let prior = interp.gc(GcControl::Disable)?;
+    Ok(interp.convert(prior == State::Disabled))
+}
+
+pub fn count(interp: &mut Artichoke) -> Result<Value, Error> {
+    let stats = interp.heap_stats()?;
+    Ok(interp.convert(total_gc_runs(&stats)))
+}
+
+pub fn stat(interp: &mut Artichoke) -> Result<Value, Error> {
+    let stats = interp.heap_stats()?;
+    let count = interp.convert(total_gc_runs(&stats));
+    let full_count = interp.convert(i64::try_from(stats.full_gc_runs).unwrap_or(i64::MAX));
+    let incremental_count = interp.convert(i64::try_from(stats.incremental_gc_runs).unwrap_or(i64::MAX));
+    let live = interp.convert(i64::from(stats.live_objects));
+    let heap_pages = interp.convert(i64::try_from(stats.pages).unwrap_or(i64::MAX));
+    let heap_capacity = interp.convert(i64::try_from(stats.capacity).unwrap_or(i64::MAX));
+    let state = match stats.phase {
+        Phase::Root => symbol_for(interp, "root")?,
+        Phase::Mark => symbol_for(interp, "mark")?,
+        Phase::Sweep => symbol_for(interp, "sweep")?,
+    };
+    let enabled = interp.convert(stats.enabled == State::Enabled);
+    let pairs = vec![
+        (symbol_for(interp, "count")?, count),
+        (symbol_for(interp, "full_count")?, full_count),
+        (symbol_for(interp, "incremental_count")?, incremental_count),
+        (symbol_for(interp, "live")?, live),
+        (symbol_for(interp, "heap_pages")?, heap_pages),
+        (symbol_for(interp, "heap_capacity")?, heap_capacity),
+        (symbol_for(interp, "state")?, state),
+        (symbol_for(interp, "enabled")?, enabled),
+    ];
+    interp.try_convert_mut(pairs)
+}
@@ -1,19 +1,16 @@
-use std::ffi::CStr;
+//! The Ruby Hash core class.
+//!
+//! Most of `Hash` is implemented by the mruby VM. This module patches the
+//! mruby implementation with identity comparison support —
+//! [`Hash#compare_by_identity`] and [`Hash#compare_by_identity?`] — the
+//! pattern matching protocol method `Hash#deconstruct_keys`, and the Ruby
+//! sources in `hash.rb`.
+//!
+//! [`Hash#compare_by_identity`]: https://ruby-doc.org/core-2.6.3/Hash.html#method-i-compare_by_identity
+//! [`Hash#compare_by_identity?`]: https://ruby-doc.org/core-2.6.3/Hash.html#method-i-compare_by_identity-3F
 
-use crate::extn::prelude::*;
-
-const HASH_CSTR: &CStr = cstr::cstr!("Hash");
-
-pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
-    if interp.is_class_defined::<Hash>() {
-        return Ok(());
-    }
-    let spec = class::Spec::new("Hash", HASH_CSTR, None, None)?;
-    interp.def_class::<Hash>(spec)?;
-    interp.eval(&include_bytes!("hash.rb")[..])?;
-    trace!("Patched Hash onto interpreter");
-    Ok(())
-}
+pub mod mruby;
+pub mod trampoline;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Hash;
@@ -29,4 +26,145 @@ mod tests {
         let inspect = inspect.try_convert_into_mut::<&str>(&mut interp).unwrap();
         assert_eq!(inspect, r#"{:a=>"GH-1099"}"#);
     }
+
+    #[test]
+    fn compare_by_identity_returns_self_and_sets_the_flag() {
+        let mut interp = interpreter().unwrap();
+        let returns_self = interp
+            .eval(b"$h = {}; $h.compare_by_identity.equal?($h)")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(returns_self);
+        let is_ident = interp
+            .eval(b"$h.compare_by_identity?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(is_ident);
+        let default = interp
+            .eval(b"{}.compare_by_identity?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(!default);
+    }
+
+    #[test]
+    fn equal_but_distinct_strings_are_distinct_keys() {
+        let mut interp = interpreter().unwrap();
+        let size = interp
+            .eval(b"h = {}.compare_by_identity; h['a' + 'a'] = 1; h['a' + 'a'] = 2; h.size")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        assert_eq!(size, 2);
+    }
+
+    #[test]
+    fn existing_entries_are_rehashed_when_the_flag_is_flipped() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"$h = { 'a' => 1, :sym => 2, 42 => 3 }.compare_by_identity").unwrap();
+        // A newly allocated `"a"` is a distinct object from the stored key.
+        let lookup_misses = interp
+            .eval(b"$h['a'].nil?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(lookup_misses);
+        // Immediates are identical to themselves, so symbol and integer keys
+        // are still found after the rehash.
+        let immediates = interp
+            .eval(b"[$h[:sym], $h[42], $h.size]")
+            .unwrap()
+            .try_convert_into::<Vec<i64>>(&interp)
+            .unwrap();
+        assert_eq!(immediates, vec![2, 3, 3]);
+    }
+
+    #[test]
+    fn string_keys_are_not_duped_or_frozen() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(b"$h = {}.compare_by_identity; $k = 'mutable'; $h[$k] = 1")
+            .unwrap();
+        let key_is_stored_as_is = interp
+            .eval(b"$h.keys.first.equal?($k) && !$k.frozen?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(key_is_stored_as_is);
+        // Mutating the key does not break the identity lookup.
+        let found = interp
+            .eval(b"$k << ' key'; $h[$k]")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        assert_eq!(found, 1);
+        // Hashes comparing by `#eql?` dup and freeze mutable string keys on
+        // insertion.
+        let key_is_duped = interp
+            .eval(b"g = {}; k = 'x'; g[k] = 1; g.keys.first.frozen? && !g.keys.first.equal?(k)")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(key_is_duped);
+    }
+
+    #[test]
+    fn deconstruct_keys_with_nil_returns_self() {
+        let mut interp = interpreter().unwrap();
+        let returns_self = interp
+            .eval(b"$h = { a: 1, b: 2 }; $h.deconstruct_keys(nil).equal?($h)")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(returns_self);
+    }
+
+    #[test]
+    fn deconstruct_keys_slices_the_requested_keys() {
+        let mut interp = interpreter().unwrap();
+        let sliced = interp
+            .eval(b"{ a: 1, b: 2, c: 3 }.deconstruct_keys(%i[a c]) == { a: 1, c: 3 }")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(sliced);
+        // Keys which are not present in the receiver are skipped.
+        let skipped = interp
+            .eval(b"{ a: 1 }.deconstruct_keys(%i[a missing]) == { a: 1 }")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(skipped);
+        let empty = interp
+            .eval(b"{ a: 1 }.deconstruct_keys([]) == {}")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(empty);
+    }
+
+    #[test]
+    fn deconstruct_keys_requires_an_array_or_nil() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"{ a: 1 }.deconstruct_keys(:a)").unwrap_err();
+        assert_eq!(err.name().as_ref(), "TypeError");
+        assert_eq!(
+            err.message().as_ref(),
+            &b"wrong argument type Symbol (expected Array)"[..]
+        );
+    }
+
+    #[test]
+    fn dup_retains_identity_comparison() {
+        let mut interp = interpreter().unwrap();
+        let is_ident = interp
+            .eval(b"{}.compare_by_identity.dup.compare_by_identity?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(is_ident);
+    }
 }
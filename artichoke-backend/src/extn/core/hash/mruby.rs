@@ -0,0 +1,58 @@
+//! FFI glue between the Rust trampolines and the mruby C interpreter.
+
+use std::ffi::CStr;
+
+use crate::extn::core::hash::{trampoline, Hash};
+use crate::extn::prelude::*;
+
+const HASH_CSTR: &CStr = cstr::cstr!("Hash");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<Hash>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Hash", HASH_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_method("compare_by_identity", hash_compare_by_identity, sys::mrb_args_none())?
+        .add_method("compare_by_identity?", hash_is_compare_by_identity, sys::mrb_args_none())?
+        .add_method("deconstruct_keys", hash_deconstruct_keys, sys::mrb_args_req(1))?
+        .define()?;
+    interp.def_class::<Hash>(spec)?;
+    interp.eval(&include_bytes!("hash.rb")[..])?;
+    trace!("Patched Hash onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn hash_compare_by_identity(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let hash = Value::from(slf);
+    let result = trampoline::compare_by_identity(&mut guard, hash);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn hash_deconstruct_keys(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let keys = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let hash = Value::from(slf);
+    let keys = Value::from(keys);
+    let result = trampoline::deconstruct_keys(&mut guard, hash, keys);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn hash_is_compare_by_identity(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let hash = Value::from(slf);
+    let result = trampoline::is_compare_by_identity(&mut guard, hash);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -0,0 +1,49 @@
+use crate::extn::prelude::*;
+
+pub fn compare_by_identity(interp: &mut Artichoke, hash: Value) -> Result<Value, Error> {
+    // The frozen check is performed before crossing the FFI boundary so the
+    // error is raised through the trampoline instead of a C-level `longjmp`.
+    if hash.is_frozen(interp) {
+        return Err(FrozenError::with_message("can't modify frozen Hash").into());
+    }
+    let hash = unsafe {
+        interp.with_ffi_boundary(|mrb| sys::mrb_hash_compare_by_identity(mrb, hash.inner()))?
+    };
+    Ok(Value::from(hash))
+}
+
+pub fn deconstruct_keys(interp: &mut Artichoke, hash: Value, keys: Value) -> Result<Value, Error> {
+    // `nil` keys means the pattern matches against every key, so the receiver
+    // can be returned without slicing.
+    if keys.is_nil() {
+        return Ok(hash);
+    }
+    if !matches!(keys.ruby_type(), Ruby::Array) {
+        let mut message = String::from("wrong argument type ");
+        message.push_str(interp.inspect_type_name_for_value(keys));
+        message.push_str(" (expected Array)");
+        return Err(TypeError::from(message).into());
+    }
+    let keys: Vec<Value> = interp.try_convert_mut(keys)?;
+    let capa = sys::mrb_int::try_from(keys.len()).unwrap_or_default();
+    let sliced = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_hash_new_capa(mrb, capa))? };
+    let sliced = interp.protect(Value::from(sliced));
+    for key in keys {
+        let has_key = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_hash_key_p(mrb, hash.inner(), key.inner()))? };
+        if has_key == 0 {
+            continue;
+        }
+        let value = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_hash_get(mrb, hash.inner(), key.inner()))? };
+        unsafe {
+            interp.with_ffi_boundary(|mrb| sys::mrb_hash_set(mrb, sliced.inner(), key.inner(), value))?;
+        }
+    }
+    Ok(sliced)
+}
+
+pub fn is_compare_by_identity(interp: &mut Artichoke, hash: Value) -> Result<Value, Error> {
+    let is_ident = unsafe {
+        interp.with_ffi_boundary(|mrb| sys::mrb_hash_compare_by_identity_p(mrb, hash.inner()))?
+    };
+    Ok(interp.convert(is_ident != 0))
+}
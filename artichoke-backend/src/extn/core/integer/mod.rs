@@ -1,6 +1,7 @@
 use std::fmt;
 use std::mem;
 
+use crate::extn::core::math::DomainError;
 use crate::extn::core::numeric::{self, Coercion, Outcome};
 use crate::extn::prelude::*;
 
@@ -193,6 +194,132 @@ impl Integer {
         }
     }
 
+    pub fn digits(self, base: i64) -> Result<Vec<i64>, Error> {
+        let value = self.as_i64();
+        if value < 0 {
+            return Err(DomainError::with_message("out of domain").into());
+        }
+        match base {
+            base if base < 0 => Err(ArgumentError::with_message("negative radix").into()),
+            base @ (0 | 1) => {
+                let mut message = String::from("invalid radix ");
+                itoa::fmt(&mut message, base).map_err(WriteError::from)?;
+                Err(ArgumentError::from(message).into())
+            }
+            base => {
+                let mut digits = Vec::new();
+                let mut value = value;
+                // A zero receiver has a single zero digit in every radix.
+                loop {
+                    digits.push(value % base);
+                    value /= base;
+                    if value == 0 {
+                        break;
+                    }
+                }
+                Ok(digits)
+            }
+        }
+    }
+
+    pub fn pow(self, interp: &mut Artichoke, exponent: Value, modulus: Option<Value>) -> Result<Outcome, Error> {
+        if let Some(modulus) = modulus {
+            if exponent.ruby_type() != Ruby::Fixnum || modulus.ruby_type() != Ruby::Fixnum {
+                return Err(TypeError::with_message(
+                    "Integer#pow() 2nd argument not allowed unless all arguments are integers",
+                )
+                .into());
+            }
+            let exponent = exponent.try_convert_into::<i64>(interp)?;
+            let modulus = modulus.try_convert_into::<i64>(interp)?;
+            Ok(self.pow_mod(exponent, modulus)?.into())
+        } else {
+            match exponent.ruby_type() {
+                Ruby::Fixnum => {
+                    let exponent = exponent.try_convert_into::<i64>(interp)?;
+                    if exponent < 0 {
+                        // Match the behavior of the `**` operator, which
+                        // produces a `Float` for negative exponents.
+                        #[allow(clippy::cast_precision_loss)]
+                        let result = self.as_f64().powf(exponent as f64);
+                        return Ok(result.into());
+                    }
+                    // Exponents which do not fit in a `u32` can only avoid
+                    // overflowing an `i64` if the base is 0, 1, or -1.
+                    let result = match (self.as_i64(), u32::try_from(exponent)) {
+                        (base, Ok(exponent)) => base.checked_pow(exponent),
+                        (0, Err(_)) => Some(0),
+                        (1, Err(_)) => Some(1),
+                        (-1, Err(_)) if exponent % 2 == 0 => Some(1),
+                        (-1, Err(_)) => Some(-1),
+                        _ => None,
+                    };
+                    // Without Bignum support, raise instead of silently
+                    // wrapping on overflow.
+                    let result = result.ok_or_else(|| RangeError::with_message("integer overflow in pow"))?;
+                    Ok(result.into())
+                }
+                Ruby::Float => {
+                    let exponent = exponent.try_convert_into::<f64>(interp)?;
+                    Ok(self.as_f64().powf(exponent).into())
+                }
+                _ => {
+                    let x = interp.convert(self);
+                    let coerced = numeric::coerce(interp, x, exponent)?;
+                    match coerced {
+                        Coercion::Float(base, exponent) => Ok(base.powf(exponent).into()),
+                        Coercion::Integer(base, exponent) => {
+                            let exponent = interp.convert(exponent);
+                            Self::from(base).pow(interp, exponent, None)
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Modular exponentiation for `Integer#pow` with a modulus argument.
+    ///
+    /// Implemented with square-and-multiply over `u128` intermediates so the
+    /// multiplications cannot overflow. Like the `%` operator, the result
+    /// takes the sign of the modulus.
+    pub fn pow_mod(self, exponent: i64, modulus: i64) -> Result<Self, Error> {
+        if exponent < 0 {
+            return Err(RangeError::with_message(
+                "Integer#pow() 1st argument cannot be negative when 2nd argument specified",
+            )
+            .into());
+        }
+        if modulus == 0 {
+            return Err(ZeroDivisionError::with_message("divided by 0").into());
+        }
+        let modulo = u128::from(modulus.unsigned_abs());
+        // Reduce the base into `0..modulo` up front, mapping negative bases to
+        // their non-negative residue.
+        let mut base = u128::from(self.as_i64().unsigned_abs()) % modulo;
+        if self.as_i64() < 0 && base != 0 {
+            base = modulo - base;
+        }
+        let mut exponent = exponent;
+        let mut result = 1 % modulo;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base % modulo;
+            }
+            base = base * base % modulo;
+            exponent >>= 1;
+        }
+        // The residue is in `0..modulo`, so it always fits in an `i64` after
+        // adjusting for a negative modulus.
+        let result = if modulus < 0 && result != 0 {
+            let negated = modulo - result;
+            -i64::try_from(negated).map_err(|_| RangeError::with_message("integer overflow in pow"))?
+        } else {
+            i64::try_from(result).map_err(|_| RangeError::with_message("integer overflow in pow"))?
+        };
+        Ok(result.into())
+    }
+
     #[must_use]
     pub const fn is_allbits(self, mask: i64) -> bool {
         self.as_i64() & mask == mask
@@ -323,4 +450,118 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn pow_with_modulus_is_modular_exponentiation() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"2.pow(10, 1000)").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 24);
+        // Intermediate products overflow an `i64` without a widening mulmod:
+        // `0x7FFF_FFFF_FFFF_FFC5 ** 3` has nearly 189 bits.
+        let result = interp
+            .eval(b"0x7FFF_FFFF_FFFF_FFC5.pow(3, 0x7FFF_FFFF_FFFF_FFFF)")
+            .unwrap();
+        assert_eq!(
+            result.try_convert_into::<i64>(&interp).unwrap(),
+            9_223_372_036_854_580_695
+        );
+    }
+
+    #[test]
+    fn pow_with_modulus_result_takes_sign_of_modulus() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"(-2).pow(3, 5)").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 2);
+        let result = interp.eval(b"7.pow(2, -5)").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), -1);
+    }
+
+    #[test]
+    fn pow_with_modulus_rejects_negative_exponent() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"2.pow(-2, 5)").unwrap_err();
+        assert_eq!(err.name().as_ref(), "RangeError");
+        assert_eq!(
+            err.message().as_ref(),
+            &b"Integer#pow() 1st argument cannot be negative when 2nd argument specified"[..]
+        );
+    }
+
+    #[test]
+    fn pow_with_zero_modulus_raises_zero_division_error() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"2.pow(10, 0)").unwrap_err();
+        assert_eq!(err.name().as_ref(), "ZeroDivisionError");
+        assert_eq!(err.message().as_ref(), &b"divided by 0"[..]);
+    }
+
+    #[test]
+    fn pow_with_non_integer_modulus_raises_type_error() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"2.pow(10, 3.0)").unwrap_err();
+        assert_eq!(err.name().as_ref(), "TypeError");
+        assert_eq!(
+            err.message().as_ref(),
+            &b"Integer#pow() 2nd argument not allowed unless all arguments are integers"[..]
+        );
+    }
+
+    #[test]
+    fn pow_at_overflow_boundary() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"2.pow(62)").unwrap();
+        assert_eq!(
+            result.try_convert_into::<i64>(&interp).unwrap(),
+            4_611_686_018_427_387_904
+        );
+        let result = interp.eval(b"(-2).pow(63)").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), i64::MIN);
+
+        let err = interp.eval(b"2.pow(63)").unwrap_err();
+        assert_eq!(err.name().as_ref(), "RangeError");
+        assert_eq!(err.message().as_ref(), &b"integer overflow in pow"[..]);
+        let err = interp.eval(b"3.pow(0x1_0000_0000)").unwrap_err();
+        assert_eq!(err.name().as_ref(), "RangeError");
+    }
+
+    #[test]
+    fn pow_with_negative_exponent_returns_float() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"2.pow(-1)").unwrap();
+        let result = result.try_convert_into::<f64>(&interp).unwrap();
+        assert!((result - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn digits_are_least_significant_first() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"12345.digits").unwrap();
+        let digits = result.try_convert_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(digits, [5, 4, 3, 2, 1]);
+        let result = interp.eval(b"255.digits(16)").unwrap();
+        let digits = result.try_convert_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(digits, [15, 15]);
+        let result = interp.eval(b"0.digits").unwrap();
+        let digits = result.try_convert_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(digits, [0]);
+    }
+
+    #[test]
+    fn digits_rejects_invalid_radix() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"10.digits(1)").unwrap_err();
+        assert_eq!(err.name().as_ref(), "ArgumentError");
+        assert_eq!(err.message().as_ref(), &b"invalid radix 1"[..]);
+        let err = interp.eval(b"10.digits(0)").unwrap_err();
+        assert_eq!(err.message().as_ref(), &b"invalid radix 0"[..]);
+        let err = interp.eval(b"10.digits(-2)").unwrap_err();
+        assert_eq!(err.message().as_ref(), &b"negative radix"[..]);
+    }
+
+    #[test]
+    fn digits_rejects_negative_receiver() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"(-5).digits").unwrap_err();
+        assert_eq!(err.message().as_ref(), &b"out of domain"[..]);
+    }
 }
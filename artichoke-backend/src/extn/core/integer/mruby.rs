@@ -15,6 +15,8 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("chr", integer_chr, sys::mrb_args_opt(1))?
         .add_method("[]", integer_element_reference, sys::mrb_args_req(1))?
         .add_method("/", integer_div, sys::mrb_args_req(1))?
+        .add_method("digits", integer_digits, sys::mrb_args_opt(1))?
+        .add_method("pow", integer_pow, sys::mrb_args_req_and_opt(1, 1))?
         .add_method("allbits?", integer_is_allbits, sys::mrb_args_req(1))?
         .add_method("anybits?", integer_is_anybits, sys::mrb_args_req(1))?
         .add_method("nobits?", integer_is_nobits, sys::mrb_args_req(1))?
@@ -62,6 +64,31 @@ unsafe extern "C" fn integer_div(mrb: *mut sys::mrb_state, slf: sys::mrb_value)
     }
 }
 
+unsafe extern "C" fn integer_digits(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let base = mrb_get_args!(mrb, optional = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let base = base.map(Value::from);
+    let result = trampoline::digits(&mut guard, value, base);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn integer_pow(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let (exponent, modulus) = mrb_get_args!(mrb, required = 1, optional = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let exponent = Value::from(exponent);
+    let modulus = modulus.map(Value::from);
+    let result = trampoline::pow(&mut guard, value, exponent, modulus);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn integer_is_allbits(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
     let mask = mrb_get_args!(mrb, required = 1);
     unwrap_interpreter!(mrb, to => guard);
@@ -21,6 +21,23 @@ pub fn div(interp: &mut Artichoke, value: Value, denominator: Value) -> Result<V
     Ok(interp.convert_mut(quotient))
 }
 
+pub fn digits(interp: &mut Artichoke, value: Value, base: Option<Value>) -> Result<Value, Error> {
+    let value = value.try_convert_into::<Integer>(interp)?;
+    let base = if let Some(base) = base {
+        implicitly_convert_to_int(interp, base)?
+    } else {
+        10
+    };
+    let digits = value.digits(base)?;
+    interp.try_convert_mut(digits)
+}
+
+pub fn pow(interp: &mut Artichoke, value: Value, exponent: Value, modulus: Option<Value>) -> Result<Value, Error> {
+    let value = value.try_convert_into::<Integer>(interp)?;
+    let result = value.pow(interp, exponent, modulus)?;
+    Ok(interp.convert_mut(result))
+}
+
 pub fn is_allbits(interp: &mut Artichoke, value: Value, mask: Value) -> Result<Value, Error> {
     let value = value.try_convert_into::<Integer>(interp)?;
     let mask = implicitly_convert_to_int(interp, mask)?;
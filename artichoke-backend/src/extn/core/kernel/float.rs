@@ -0,0 +1,291 @@
+use std::str;
+
+use bstr::ByteSlice;
+
+use crate::convert::implicitly_convert_to_string;
+use crate::extn::prelude::*;
+
+/// Convert a Ruby `String` to a `Float` with the strict semantics of
+/// `Kernel#Float`.
+///
+/// Unlike `String#to_f`, the whole string must be a valid `Float` literal.
+/// Underscores are permitted between digits, leading and trailing whitespace
+/// is ignored, and hexadecimal literals with a binary exponent (`0x10p2`) are
+/// supported.
+pub fn method(interp: &mut Artichoke, arg: &mut Value) -> Result<f64, Error> {
+    let mut message = String::from("can't convert ");
+    message.push_str(interp.inspect_type_name_for_value(*arg));
+    message.push_str(" into Float");
+
+    // Safety:
+    //
+    // There is no use of an `Artichoke` in this module, which means a
+    // garbage collection of `arg` cannot be triggered.
+    if let Ok(bytes) = unsafe { implicitly_convert_to_string(interp, arg) } {
+        if let Some(float) = parse(bytes) {
+            Ok(float)
+        } else {
+            let mut message = String::from(r#"invalid value for Float(): ""#);
+            format_unicode_debug_into(&mut message, bytes)?;
+            message.push('"');
+            Err(ArgumentError::from(message).into())
+        }
+    } else {
+        Err(TypeError::from(message).into())
+    }
+}
+
+fn parse(arg: &[u8]) -> Option<f64> {
+    if arg.find_byte(b'\0').is_some() {
+        return None;
+    }
+    let arg = str::from_utf8(arg).ok()?;
+    let arg = arg.trim();
+    let (sign, rest) = match arg.as_bytes() {
+        [b'+', rest @ ..] => (1.0, rest),
+        [b'-', rest @ ..] => (-1.0, rest),
+        rest => (1.0, rest),
+    };
+    let magnitude = match rest {
+        [b'0', b'x' | b'X', rest @ ..] => parse_hexadecimal(rest)?,
+        rest => parse_decimal(rest)?,
+    };
+    Some(sign * magnitude)
+}
+
+fn parse_decimal(bytes: &[u8]) -> Option<f64> {
+    let mut src = String::with_capacity(bytes.len());
+    let mut pos = 0;
+    digit_run(bytes, &mut pos, &mut src, 10)?;
+    if let Some(b'.') = bytes.get(pos) {
+        pos += 1;
+        src.push('.');
+        digit_run(bytes, &mut pos, &mut src, 10)?;
+    }
+    if let Some(b'e' | b'E') = bytes.get(pos) {
+        pos += 1;
+        src.push('e');
+        if let Some(&byte @ (b'+' | b'-')) = bytes.get(pos) {
+            pos += 1;
+            src.push(char::from(byte));
+        }
+        digit_run(bytes, &mut pos, &mut src, 10)?;
+    }
+    if pos == bytes.len() {
+        // Out of range literals like `"2e1000"` saturate to `Infinity` and
+        // `"2e-1000"` saturates to `0.0`, matching MRI.
+        src.parse::<f64>().ok()
+    } else {
+        None
+    }
+}
+
+// Parse the remainder of a hexadecimal literal after the `0x` prefix, with an
+// optional fractional part and an optional `p` binary exponent, e.g. `1.8p2`.
+fn parse_hexadecimal(bytes: &[u8]) -> Option<f64> {
+    let mut digits = String::with_capacity(bytes.len());
+    let mut pos = 0;
+    digit_run(bytes, &mut pos, &mut digits, 16)?;
+    let mut fraction = String::new();
+    if let Some(b'.') = bytes.get(pos) {
+        pos += 1;
+        digit_run(bytes, &mut pos, &mut fraction, 16)?;
+    }
+    let mut exponent = 0_i32;
+    if let Some(b'p' | b'P') = bytes.get(pos) {
+        pos += 1;
+        let is_negative = match bytes.get(pos) {
+            Some(b'-') => {
+                pos += 1;
+                true
+            }
+            Some(b'+') => {
+                pos += 1;
+                false
+            }
+            _ => false,
+        };
+        let mut exponent_digits = String::new();
+        digit_run(bytes, &mut pos, &mut exponent_digits, 10)?;
+        // Exponents beyond `i32` saturate to `Infinity` or `0.0` below.
+        exponent = exponent_digits.parse::<i32>().unwrap_or(i32::MAX);
+        if is_negative {
+            exponent = -exponent;
+        }
+    }
+    if pos != bytes.len() {
+        return None;
+    }
+    let mut value = 0.0_f64;
+    for digit in digits.chars() {
+        value = value * 16.0 + f64::from(digit.to_digit(16)?);
+    }
+    let mut scale = 1.0 / 16.0;
+    for digit in fraction.chars() {
+        value += f64::from(digit.to_digit(16)?) * scale;
+        scale /= 16.0;
+    }
+    Some(value * 2.0_f64.powi(exponent))
+}
+
+// Consume a run of digits in the given radix where `_` may appear between two
+// digits. Returns [`None`] if the run is empty, begins or ends with `_`, or
+// contains consecutive underscores.
+fn digit_run(bytes: &[u8], pos: &mut usize, out: &mut String, radix: u32) -> Option<()> {
+    let mut last_was_underscore = false;
+    let mut any_digits = false;
+    while let Some(&byte) = bytes.get(*pos) {
+        let digit = char::from(byte);
+        if byte == b'_' {
+            if !any_digits || last_was_underscore {
+                return None;
+            }
+            last_was_underscore = true;
+            *pos += 1;
+        } else if digit.is_digit(radix) {
+            out.push(digit);
+            any_digits = true;
+            last_was_underscore = false;
+            *pos += 1;
+        } else {
+            break;
+        }
+    }
+    if any_digits && !last_was_underscore {
+        Some(())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bstr::ByteSlice;
+
+    use super::{method as float, parse};
+    use crate::test::prelude::*;
+
+    fn parses_to(arg: &str, expected: f64) {
+        let result = parse(arg.as_bytes());
+        let parsed = result.unwrap_or_else(|| panic!("expected {:?} to parse to {}", arg, expected));
+        assert!(
+            (parsed - expected).abs() < f64::EPSILON,
+            "expected {:?} to parse to {}, got {}",
+            arg,
+            expected,
+            parsed
+        );
+    }
+
+    #[test]
+    fn parses_decimal_literals() {
+        parses_to("10", 10.0);
+        parses_to("10.0", 10.0);
+        parses_to("+10", 10.0);
+        parses_to("-10", -10.0);
+        parses_to("2.0e2", 200.0);
+        parses_to("2.0E2", 200.0);
+        parses_to("2e-2", 0.02);
+        parses_to("2e+2", 200.0);
+    }
+
+    #[test]
+    fn parses_underscores_between_digits() {
+        parses_to("1_000", 1000.0);
+        parses_to("1_000.000_1", 1000.0001);
+        parses_to("2_0e1_0", 20e10);
+    }
+
+    #[test]
+    fn parses_surrounding_whitespace() {
+        parses_to(" 1", 1.0);
+        parses_to("1 ", 1.0);
+        parses_to("\t\n1", 1.0);
+        parses_to("1\t\n", 1.0);
+    }
+
+    #[test]
+    fn parses_hexadecimal_literals() {
+        parses_to("0x10", 16.0);
+        parses_to("0x10p0", 16.0);
+        parses_to("0x1p10", 1024.0);
+        parses_to("0x1.8p0", 1.5);
+        parses_to("-0x1p1", -2.0);
+        parses_to("0x1_0p1_0", 16384.0);
+    }
+
+    #[test]
+    fn out_of_range_exponents_saturate() {
+        assert!(parse(b"2e1000").unwrap().is_infinite());
+        parses_to("2e-1000", 0.0);
+        assert!(parse(b"0x1p10000").unwrap().is_infinite());
+        parses_to("0x1p-10000", 0.0);
+    }
+
+    #[test]
+    fn rejects_invalid_literals() {
+        let invalid = [
+            "",
+            " ",
+            "float",
+            "10.0.0",
+            "10D",
+            "D10",
+            "not a number\n10",
+            "10\nnot a number",
+            "1+1",
+            "1-1",
+            "11+",
+            "_1",
+            "10_",
+            "1__0",
+            "1 2",
+            "2e",
+            "e2",
+            "20e2.0",
+            "10.",
+            ".5",
+            "0x",
+            "0x1p",
+            "0xp1",
+            "0x1p1.0",
+            "\0",
+            "1\01",
+            "1\0",
+        ];
+        for arg in invalid {
+            assert!(parse(arg.as_bytes()).is_none(), "expected {:?} to fail to parse", arg);
+        }
+    }
+
+    #[test]
+    fn invalid_value_error_message_quotes_the_input() {
+        let mut interp = interpreter().unwrap();
+        let mut arg = interp.try_convert_mut("float").unwrap();
+        let result = float(&mut interp, &mut arg);
+        assert_eq!(
+            result.unwrap_err().message().as_bstr(),
+            r#"invalid value for Float(): "float""#.as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn invalid_value_error_message_escapes_invalid_utf8() {
+        let mut interp = interpreter().unwrap();
+        let mut arg = interp.try_convert_mut(&b"\xFF"[..]).unwrap();
+        let result = float(&mut interp, &mut arg);
+        assert_eq!(
+            result.unwrap_err().message().as_bstr(),
+            r#"invalid value for Float(): "\xFF""#.as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn non_string_arguments_are_a_type_error() {
+        let mut interp = interpreter().unwrap();
+        let mut arg = interp.convert(7);
+        let result = float(&mut interp, &mut arg);
+        let err = result.unwrap_err();
+        assert_eq!(err.name().as_ref(), "TypeError");
+    }
+}
@@ -42,6 +42,33 @@ module Kernel
     ret.nil? ? [arg] : ret
   end
 
+  def Float(arg, exception: true) # rubocop:disable Naming/MethodName
+    classname = arg.class
+    classname = arg.inspect if arg.nil? || arg.equal?(false) || arg.equal?(true)
+
+    raise TypeError, "can't convert #{classname} into Float" if arg.nil?
+
+    if arg.is_a?(Float)
+      arg
+    elsif arg.is_a?(Numeric)
+      arg.to_f
+    elsif arg.is_a?(String)
+      ::Artichoke::Kernel::Float(arg)
+    elsif arg.respond_to?(:to_f)
+      ret = arg.to_f
+
+      return ret if ret.is_a?(Float)
+
+      raise TypeError, "can't convert #{classname} to Float (#{arg.class}#to_f gives #{ret.class})"
+    else
+      raise TypeError, "can't convert #{classname} into Float"
+    end
+  rescue StandardError => e
+    return nil if exception.equal?(false)
+
+    raise e
+  end
+
   def Hash(arg) # rubocop:disable Naming/MethodName
     return arg if arg.is_a?(Hash)
     return {} if arg.nil? || arg == []
@@ -171,10 +198,7 @@ module Kernel
     msg.each do |warning|
       warning = warning.to_s
       warning << "\n" unless warning[-1] == "\n"
-      # TODO: This should call `Warning.warn` but due to method visibility
-      # limitations of the mruby VM, we cannot shadow the warn method there.
-      out = $stderr || $stdout || self
-      out.print(warning)
+      Warning.warn(warning)
     end
     nil
   end
@@ -1,3 +1,4 @@
+pub mod float;
 pub mod integer;
 pub mod mruby;
 pub mod require;
@@ -8,6 +9,7 @@ pub struct Kernel;
 
 #[cfg(test)]
 mod tests {
+    use crate::state::{input, output};
     use crate::test::prelude::*;
 
     const SUBJECT: &str = "Kernel";
@@ -21,4 +23,115 @@ mod tests {
         let result = interp.eval(b"spec");
         unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
     }
+
+    #[test]
+    fn caller_reports_nested_frames_with_call_site_line_numbers() {
+        let mut interp = interpreter().unwrap();
+        let code = b"def outer\n  # the call to `inner` is not on the `def` line\n  inner\nend\n\ndef inner\n  caller\nend\n\nbt = outer\nbt.length == 2 && bt[0].end_with?(\":3:in `outer'\") && bt[1].end_with?(':10')";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn caller_zero_includes_the_current_frame() {
+        let mut interp = interpreter().unwrap();
+        let code = b"def current\n  caller(0)\nend\n\ncurrent.first.end_with?(\":2:in `current'\")";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn caller_honors_start_and_length() {
+        let mut interp = interpreter().unwrap();
+        let code = b"def a\n  b\nend\n\ndef b\n  c\nend\n\ndef c\n  caller(1, 1)\nend\n\nbt = a\nbt.length == 1 && bt.first.end_with?(\":6:in `b'\")";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn caller_returns_an_empty_array_at_the_top_level() {
+        let mut interp = interpreter().unwrap();
+        let result = interp
+            .eval(b"caller == []")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn caller_returns_nil_when_start_exceeds_the_stack_depth() {
+        let mut interp = interpreter().unwrap();
+        let result = interp
+            .eval(b"caller(100).nil?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn caller_rejects_negative_start_and_length() {
+        let mut interp = interpreter().unwrap();
+        let code = b"begin\n  caller(-1)\n  false\nrescue ArgumentError => e\n  e.message == 'negative level (-1)'\nend";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+        let code = b"begin\n  caller(1, -1)\n  false\nrescue ArgumentError => e\n  e.message == 'negative size (-1)'\nend";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn caller_attributes_native_frames_to_a_synthetic_filename() {
+        let mut interp = interpreter().unwrap();
+        let code = b"bt = instance_eval { caller }\nbt.first == \"(native):in `instance_eval'\"";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn caller_locations_builds_thread_backtrace_location_frames() {
+        let mut interp = interpreter().unwrap();
+        let code = b"def locations\n  caller_locations\nend\n\nloc = locations.first\nloc.is_a?(Thread::Backtrace::Location) && loc.lineno == 5 && loc.label == '<main>' && loc.to_s.end_with?(\":5:in `<main>'\")";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn gets_reads_scripted_lines_from_the_interpreter_input() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_io(input::Strategy::scripted("first\nsecond\n"), output::Strategy::captured())
+            .unwrap();
+        let code = b"gets == \"first\\n\" && $_ == \"first\\n\" && gets == \"second\\n\" && gets.nil?";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn gets_delegates_to_a_reassigned_stdin() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_io(input::Strategy::scripted("from interpreter\n"), output::Strategy::captured())
+            .unwrap();
+        let code = b"class ScriptedStdin\n  def initialize(lines)\n    @lines = lines\n  end\n\n  def gets\n    @lines.shift\n  end\nend\n\n$stdin = ScriptedStdin.new([\"a\\n\"])\ngets == \"a\\n\" && gets.nil?";
+        let result = interp.eval(code).unwrap().try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn puts_and_print_delegate_to_a_reassigned_stdout() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_io(input::Strategy::new(), output::Strategy::captured())
+            .unwrap();
+        let code = b"class Sink\n  attr_reader :bytes\n\n  def initialize\n    @bytes = ''\n  end\n\n  def write(bytes)\n    @bytes += bytes.to_s\n    bytes.to_s.length\n  end\nend\n\n$stdout = Sink.new\nputs 'redirected'\nprint '!'\np :sym\n$stdout.bytes";
+        let result = interp.eval(code).unwrap();
+        let result = result.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(result, "redirected\n!:sym\n");
+        // nothing is written to the interpreter output stream while `$stdout`
+        // is reassigned.
+        let state = interp.state.as_deref().unwrap();
+        assert_eq!(state.output.as_captured().unwrap().stdout(), b"");
+    }
 }
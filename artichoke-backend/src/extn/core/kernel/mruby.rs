@@ -3,6 +3,7 @@ use std::ffi::CStr;
 use crate::extn::core::artichoke;
 use crate::extn::core::kernel::{self, trampoline};
 use crate::extn::prelude::*;
+use crate::method::signature::Parameter;
 
 const KERNEL_CSTR: &CStr = cstr::cstr!("Kernel");
 
@@ -15,9 +16,19 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("require", kernel_require, sys::mrb_args_rest())?
         .add_method("require_relative", kernel_require_relative, sys::mrb_args_rest())?
         .add_method("load", kernel_load, sys::mrb_args_rest())?
+        .add_method("caller", kernel_caller, sys::mrb_args_opt(2))?
+        .add_method("caller_locations", kernel_caller_locations, sys::mrb_args_opt(2))?
+        .add_method("gets", kernel_gets, sys::mrb_args_none())?
         .add_method("p", kernel_p, sys::mrb_args_rest())?
         .add_method("print", kernel_print, sys::mrb_args_rest())?
         .add_method("puts", kernel_puts, sys::mrb_args_rest())?
+        .add_method("system", kernel_system, sys::mrb_args_rest())?
+        .add_method("`", kernel_backtick, sys::mrb_args_req(1))?
+        // `Kernel#Integer` is implemented in Ruby in `kernel.rb`.
+        .add_method_signature(
+            "Integer",
+            vec![Parameter::Req("arg"), Parameter::Opt("base"), Parameter::Key("exception")],
+        )?
         .define()?;
     interp.def_module::<kernel::Kernel>(spec)?;
     interp.eval(&include_bytes!("kernel.rb")[..])?;
@@ -33,6 +44,8 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
     let spec = module::Spec::new(interp, "Kernel", KERNEL_CSTR, Some(scope))?;
     module::Builder::for_spec(interp, &spec)
+        .add_method("Float", kernel_float, sys::mrb_args_req(1))?
+        .add_self_method("Float", kernel_float, sys::mrb_args_req(1))?
         .add_method("Integer", kernel_integer, sys::mrb_args_req_and_opt(1, 1))?
         .add_self_method("Integer", kernel_integer, sys::mrb_args_req_and_opt(1, 1))?
         .define()?;
@@ -41,6 +54,17 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     Ok(())
 }
 
+unsafe extern "C" fn kernel_float(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let arg = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let arg = Value::from(arg);
+    let result = trampoline::float(&mut guard, arg);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn kernel_integer(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
     let (arg, base) = mrb_get_args!(mrb, required = 1, optional = 1);
     unwrap_interpreter!(mrb, to => guard);
@@ -53,6 +77,40 @@ unsafe extern "C" fn kernel_integer(mrb: *mut sys::mrb_state, _slf: sys::mrb_val
     }
 }
 
+unsafe extern "C" fn kernel_caller(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (start, length) = mrb_get_args!(mrb, optional = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let start = start.map(Value::from);
+    let length = length.map(Value::from);
+    let result = trampoline::caller(&mut guard, start, length);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn kernel_caller_locations(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (start, length) = mrb_get_args!(mrb, optional = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let start = start.map(Value::from);
+    let length = length.map(Value::from);
+    let result = trampoline::caller_locations(&mut guard, start, length);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn kernel_gets(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let result = trampoline::gets(&mut guard);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn kernel_load(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
     let file = mrb_get_args!(mrb, required = 1);
     unwrap_interpreter!(mrb, to => guard);
@@ -118,3 +176,25 @@ unsafe extern "C" fn kernel_require_relative(mrb: *mut sys::mrb_state, _slf: sys
         Err(exception) => error::raise(guard, exception),
     }
 }
+
+unsafe extern "C" fn kernel_backtick(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let command = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let command = Value::from(command);
+    let result = trampoline::backtick(&mut guard, command);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn kernel_system(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    unwrap_interpreter!(mrb, to => guard);
+    let args = args.iter().copied().map(Value::from);
+    let result = trampoline::system(&mut guard, args);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -145,12 +145,15 @@ impl RelativePath {
         let context = interp
             .peek_context()?
             .ok_or_else(|| Fatal::from("relative require with no context stack"))?;
-        let path = bytes_to_os_str(context.filename())?;
-        let path = Path::new(path);
-        if let Some(base) = path.parent() {
-            Ok(Self::from(base))
+        if let Some(base) = context.directory() {
+            let base = bytes_to_os_str(base)?;
+            Ok(Self::from(Path::new(base)))
         } else {
-            Ok(Self::from("/"))
+            // The `__FILE__` of the calling frame does not name a file on
+            // disk, for example the "(eval)" filename of the root context or
+            // inline code from the `-e` frontend switch. There is no directory
+            // to resolve relative requires against.
+            Err(LoadError::with_message("cannot infer basepath").into())
         }
     }
 }
@@ -286,6 +289,59 @@ mod test {
         };
     }
 
+    #[test]
+    fn relative_requires_resolve_against_requiring_file() {
+        let mut interp = interpreter().unwrap();
+        let lib = crate::load_path::RUBY_LOAD_PATH;
+        interp
+            .def_rb_source_file(format!("{}/a/b.rb", lib), &b"require_relative './c'"[..])
+            .unwrap();
+        interp
+            .def_rb_source_file(format!("{}/a/c.rb", lib), &b"module Relative; BASE = 10; end"[..])
+            .unwrap();
+
+        // Require via the load path.
+        let result = interp.eval(b"require 'a/b'").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"Relative::BASE").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 10);
+
+        // Require via absolute path dedups against the feature loaded through
+        // the relative require.
+        let require_code = format!("require '{}/a/c.rb'", lib);
+        let result = interp.eval(require_code.as_bytes()).unwrap();
+        assert!(!result.try_convert_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn relative_requires_resolve_from_absolute_require() {
+        let mut interp = interpreter().unwrap();
+        let lib = crate::load_path::RUBY_LOAD_PATH;
+        interp
+            .def_rb_source_file(format!("{}/a/b.rb", lib), &b"require_relative './c'"[..])
+            .unwrap();
+        interp
+            .def_rb_source_file(format!("{}/a/c.rb", lib), &b"module Relative; BASE = 10; end"[..])
+            .unwrap();
+
+        let require_code = format!("require '{}/a/b.rb'", lib);
+        let result = interp.eval(require_code.as_bytes()).unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"Relative::BASE").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 10);
+    }
+
+    #[test]
+    fn relative_require_from_eval_context_cannot_infer_basepath() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"require_relative './c'").unwrap_err();
+        assert_eq!("LoadError", err.name().as_ref());
+        assert_eq!(
+            b"cannot infer basepath".as_bstr(),
+            err.message().as_ref().as_bstr()
+        );
+    }
+
     #[test]
     fn directory_err() {
         let mut interp = interpreter().unwrap();
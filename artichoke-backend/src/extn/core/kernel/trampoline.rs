@@ -1,7 +1,43 @@
+use crate::call_stack::Frame;
+use crate::convert::implicitly_convert_to_int;
+use crate::convert::implicitly_convert_to_string;
 use crate::extn::core::kernel;
 use crate::extn::core::kernel::require::RelativePath;
+use crate::extn::core::process;
+use crate::extn::core::stdio;
+use crate::extn::core::thread::Thread;
 use crate::extn::prelude::*;
 
+/// Returns `$stdout` if the global has been reassigned to an object other than
+/// the default [`stdio::Stdout`] delegate.
+///
+/// The default `$stdout` routes through the [`Io`] trait natively, which
+/// avoids a Ruby method dispatch per write.
+///
+/// [`Io`]: crate::core::Io
+fn reassigned_stdout(interp: &mut Artichoke) -> Result<Option<Value>, Error> {
+    let stdout = if let Some(stdout) = interp.get_global_variable(&b"$stdout"[..])? {
+        stdout
+    } else {
+        return Ok(None);
+    };
+    if stdout.is_nil() {
+        return Ok(None);
+    }
+    if let Some(class) = interp.class_of::<stdio::Stdout>()? {
+        let is_default = stdout.funcall(interp, "instance_of?", &[class], None)?;
+        if is_default.try_convert_into::<bool>(interp)? {
+            return Ok(None);
+        }
+    }
+    Ok(Some(stdout))
+}
+
+pub fn float(interp: &mut Artichoke, mut arg: Value) -> Result<Value, Error> {
+    let float = kernel::float::method(interp, &mut arg)?;
+    Ok(interp.convert_mut(float))
+}
+
 pub fn integer(interp: &mut Artichoke, mut arg: Value, base: Option<Value>) -> Result<Value, Error> {
     let base = base.and_then(|base| interp.convert(base));
     // Safety:
@@ -14,18 +50,77 @@ pub fn integer(interp: &mut Artichoke, mut arg: Value, base: Option<Value>) -> R
     Ok(interp.convert(integer))
 }
 
+pub fn caller(interp: &mut Artichoke, start: Option<Value>, length: Option<Value>) -> Result<Value, Error> {
+    let (start, length) = backtrace_bounds(interp, start, length)?;
+    let frames = if let Some(frames) = backtrace_frames(interp, start, length)? {
+        frames
+    } else {
+        return Ok(Value::nil());
+    };
+    let entries = frames.iter().map(Frame::to_display).collect::<Vec<_>>();
+    interp.try_convert_mut(entries)
+}
+
+pub fn caller_locations(interp: &mut Artichoke, start: Option<Value>, length: Option<Value>) -> Result<Value, Error> {
+    let (start, length) = backtrace_bounds(interp, start, length)?;
+    let frames = if let Some(frames) = backtrace_frames(interp, start, length)? {
+        frames
+    } else {
+        return Ok(Value::nil());
+    };
+    let location_class = location_class(interp)?;
+    let mut locations = Vec::with_capacity(frames.len());
+    for frame in &frames {
+        let path = interp.try_convert_mut(frame.path().to_vec())?;
+        let lineno = interp.convert(frame.lineno());
+        // Top-level frames have no method name; MRI labels them `<main>`.
+        let label = if frame.label().is_empty() {
+            interp.try_convert_mut("<main>")?
+        } else {
+            interp.try_convert_mut(frame.label().to_vec())?
+        };
+        locations.push(location_class.funcall(interp, "new", &[path, lineno, label], None)?);
+    }
+    interp.try_convert_mut(locations)
+}
+
 pub fn load(interp: &mut Artichoke, path: Value) -> Result<Value, Error> {
     let success = kernel::require::load(interp, path)?;
     Ok(interp.convert(success))
 }
 
+pub fn gets(interp: &mut Artichoke) -> Result<Value, Error> {
+    // A reassigned `$stdin`, e.g. a `StringIO`, takes precedence over the
+    // interpreter input stream.
+    if let Some(stdin) = interp.get_global_variable(&b"$stdin"[..])? {
+        if !stdin.is_nil() {
+            let line = stdin.funcall(interp, "gets", &[], None)?;
+            interp.set_global_variable(&b"$_"[..], &line)?;
+            return Ok(line);
+        }
+    }
+    let line = if let Some(line) = interp.read_line()? {
+        interp.try_convert_mut(line)?
+    } else {
+        Value::nil()
+    };
+    interp.set_global_variable(&b"$_"[..], &line)?;
+    Ok(line)
+}
+
 pub fn print<T>(interp: &mut Artichoke, args: T) -> Result<Value, Error>
 where
     T: IntoIterator<Item = Value>,
 {
+    let stdout = reassigned_stdout(interp)?;
     for value in args {
         let display = value.to_s(interp);
-        interp.print(display)?;
+        if let Some(stdout) = stdout {
+            let display = interp.try_convert_mut(display)?;
+            stdout.funcall(interp, "write", &[display], None)?;
+        } else {
+            interp.print(display)?;
+        }
     }
     Ok(Value::nil())
 }
@@ -34,27 +129,41 @@ pub fn puts<T>(interp: &mut Artichoke, args: T) -> Result<Value, Error>
 where
     T: IntoIterator<Item = Value>,
 {
-    fn puts_foreach(interp: &mut Artichoke, value: &Value) -> Result<(), Error> {
+    fn puts_foreach(interp: &mut Artichoke, stdout: Option<Value>, value: &Value) -> Result<(), Error> {
         // TODO(GH-310): Use `Value::implicitly_convert_to_array` when
         // implemented so `Value`s that respond to `to_ary` are converted
         // and iterated over.
         if let Ok(array) = value.try_convert_into_mut::<Vec<_>>(interp) {
             for value in &array {
-                puts_foreach(interp, value)?;
+                puts_foreach(interp, stdout, value)?;
             }
         } else {
-            let display = value.to_s(interp);
-            interp.puts(display)?;
+            let mut display = value.to_s(interp);
+            if let Some(stdout) = stdout {
+                if !display.ends_with(b"\n") {
+                    display.push(b'\n');
+                }
+                let display = interp.try_convert_mut(display)?;
+                stdout.funcall(interp, "write", &[display], None)?;
+            } else if display.ends_with(b"\n") {
+                interp.print(display)?;
+            } else {
+                interp.puts(display)?;
+            }
         }
         Ok(())
     }
 
+    let stdout = reassigned_stdout(interp)?;
     let mut args = args.into_iter();
     if let Some(first) = args.next() {
-        puts_foreach(interp, &first)?;
+        puts_foreach(interp, stdout, &first)?;
         for value in args {
-            puts_foreach(interp, &value)?;
+            puts_foreach(interp, stdout, &value)?;
         }
+    } else if let Some(stdout) = stdout {
+        let newline = interp.try_convert_mut(&b"\n"[..])?;
+        stdout.funcall(interp, "write", &[newline], None)?;
     } else {
         interp.print(b"\n")?;
     }
@@ -65,17 +174,28 @@ pub fn p<T>(interp: &mut Artichoke, args: T) -> Result<Value, Error>
 where
     T: IntoIterator<Item = Value>,
 {
+    fn p_write(interp: &mut Artichoke, stdout: Option<Value>, value: &Value) -> Result<(), Error> {
+        let mut display = value.inspect(interp);
+        if let Some(stdout) = stdout {
+            display.push(b'\n');
+            let display = interp.try_convert_mut(display)?;
+            stdout.funcall(interp, "write", &[display], None)?;
+        } else {
+            interp.puts(display)?;
+        }
+        Ok(())
+    }
+
+    let stdout = reassigned_stdout(interp)?;
     let mut args = args.into_iter().peekable();
     if let Some(first) = args.next() {
-        let display = first.inspect(interp);
-        interp.puts(display)?;
+        p_write(interp, stdout, &first)?;
         if args.peek().is_none() {
             return Ok(first);
         }
         let mut result = vec![first];
         for value in args {
-            let display = value.inspect(interp);
-            interp.puts(display)?;
+            p_write(interp, stdout, &value)?;
             result.push(value);
         }
         interp.try_convert_mut(result)
@@ -94,3 +214,89 @@ pub fn require_relative(interp: &mut Artichoke, path: Value) -> Result<Value, Er
     let success = kernel::require::require_relative(interp, path, relative_base)?;
     Ok(interp.convert(success))
 }
+
+fn backtrace_bounds(
+    interp: &mut Artichoke,
+    start: Option<Value>,
+    length: Option<Value>,
+) -> Result<(usize, Option<usize>), Error> {
+    let start = if let Some(start) = start {
+        let start = implicitly_convert_to_int(interp, start)?;
+        usize::try_from(start).map_err(|_| ArgumentError::from(format!("negative level ({})", start)))?
+    } else {
+        1
+    };
+    let length = if let Some(length) = length {
+        let length = implicitly_convert_to_int(interp, length)?;
+        let length =
+            usize::try_from(length).map_err(|_| ArgumentError::from(format!("negative size ({})", length)))?;
+        Some(length)
+    } else {
+        None
+    };
+    Ok((start, length))
+}
+
+fn backtrace_frames(
+    interp: &mut Artichoke,
+    start: usize,
+    length: Option<usize>,
+) -> Result<Option<Vec<Frame>>, Error> {
+    let mut frames = interp.call_stack()?;
+    // Drop the native frame for the `caller`/`caller_locations` invocation
+    // itself so `start == 0` refers to the frame of the calling method.
+    if !frames.is_empty() {
+        frames.remove(0);
+    }
+    if start > frames.len() {
+        return Ok(None);
+    }
+    let mut frames = frames.split_off(start);
+    if let Some(length) = length {
+        frames.truncate(length);
+    }
+    Ok(Some(frames))
+}
+
+fn location_class(interp: &mut Artichoke) -> Result<Value, Error> {
+    let thread = interp
+        .class_of::<Thread>()?
+        .ok_or_else(|| NotDefinedError::class("Thread"))?;
+    let name = interp.try_convert_mut("Backtrace::Location")?;
+    thread.funcall(interp, "const_get", &[name], None)
+}
+
+pub fn backtick(interp: &mut Artichoke, mut command: Value) -> Result<Value, Error> {
+    // Safety:
+    //
+    // The byteslice is immediately copied to an owned buffer. There are no
+    // intervening interpreter accesses.
+    let command = unsafe { implicitly_convert_to_string(interp, &mut command)? }.to_vec();
+    let command = CommandSpec::parse(&command).capture_stdout();
+    let child = interp
+        .spawn(command.clone())?
+        .ok_or_else(|| process::no_such_command(&command))?;
+    let mut status = interp.wait(child)?;
+    let stdout = status.take_stdout().unwrap_or_default();
+    process::record_last_status(interp, status)?;
+    interp.try_convert_mut(stdout)
+}
+
+pub fn system<T>(interp: &mut Artichoke, args: T) -> Result<Value, Error>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let mut args = args.into_iter().collect::<Vec<_>>();
+    let command = process::extract_command(interp, &mut args)?;
+    let child = if let Some(child) = interp.spawn(command)? {
+        child
+    } else {
+        // Command execution failures are not errors: `Kernel#system` returns
+        // `nil` for them.
+        return Ok(Value::nil());
+    };
+    let status = interp.wait(child)?;
+    let success = matches!(status.is_success(), Some(true));
+    process::record_last_status(interp, status)?;
+    Ok(interp.convert(success))
+}
@@ -399,3 +399,66 @@ impl MatchData {
         self.regexp.inner().capture0(haystack)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn deconstruct_returns_captures() {
+        let mut interp = interpreter().unwrap();
+        let captures = interp
+            .eval(b"'hello'.match(/(?<head>h)(e)(?<tail>l+)/).deconstruct == %w[h e ll]")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(captures);
+    }
+
+    #[test]
+    fn deconstruct_keys_with_nil_returns_all_named_captures() {
+        let mut interp = interpreter().unwrap();
+        let named = interp
+            .eval(b"'hello'.match(/(?<head>h)(e)(?<tail>l+)/).deconstruct_keys(nil) == { head: 'h', tail: 'll' }")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(named);
+    }
+
+    #[test]
+    fn deconstruct_keys_slices_the_requested_names() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"$m = 'hello'.match(/(?<head>h)(?<tail>l+)?/)").unwrap();
+        let sliced = interp
+            .eval(b"$m.deconstruct_keys(%i[head]) == { head: 'h' }")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(sliced);
+        // Unmatched named captures deconstruct to `nil`.
+        let unmatched = interp
+            .eval(b"$m.deconstruct_keys(nil) == { head: 'h', tail: nil }")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(unmatched);
+        // More keys than named capture groups deconstructs to an empty `Hash`.
+        let empty = interp
+            .eval(b"$m.deconstruct_keys(%i[head tail other]) == {}")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(empty);
+    }
+
+    #[test]
+    fn deconstruct_keys_requires_symbol_keys() {
+        let mut interp = interpreter().unwrap();
+        let err = interp
+            .eval(b"'hello'.match(/(?<head>h)/).deconstruct_keys(['head'])")
+            .unwrap_err();
+        assert_eq!("TypeError", err.name().as_ref());
+        assert_eq!(&b"wrong argument type String (expected Symbol)"[..], err.message().as_ref());
+    }
+}
@@ -20,6 +20,8 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("begin", matchdata_begin, sys::mrb_args_req(1))?
         .add_method("captures", matchdata_captures, sys::mrb_args_none())?
         .add_method("[]", matchdata_element_reference, sys::mrb_args_req_and_opt(1, 1))?
+        .add_method("deconstruct", matchdata_captures, sys::mrb_args_none())?
+        .add_method("deconstruct_keys", matchdata_deconstruct_keys, sys::mrb_args_req(1))?
         .add_method("length", matchdata_length, sys::mrb_args_none())?
         .add_method("named_captures", matchdata_named_captures, sys::mrb_args_none())?
         .add_method("names", matchdata_names, sys::mrb_args_none())?
@@ -75,6 +77,18 @@ unsafe extern "C" fn matchdata_element_reference(mrb: *mut sys::mrb_state, slf:
     }
 }
 
+unsafe extern "C" fn matchdata_deconstruct_keys(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let keys = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let keys = Value::from(keys);
+    let result = trampoline::deconstruct_keys(&mut guard, value, keys);
+    match result {
+        Ok(result) => result.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn matchdata_end(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
     let end = mrb_get_args!(mrb, required = 1);
     unwrap_interpreter!(mrb, to => guard);
@@ -30,6 +30,65 @@ pub fn captures(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error
     }
 }
 
+pub fn deconstruct_keys(interp: &mut Artichoke, mut value: Value, keys: Value) -> Result<Value, Error> {
+    let pairs = if keys.is_nil() {
+        let data = unsafe { MatchData::unbox_from_value(&mut value, interp)? };
+        let mut named_captures = data.named_captures()?.unwrap_or_default();
+        // Yield the named captures in the order the groups are declared in the
+        // pattern instead of `HashMap` iteration order.
+        let mut pairs = Vec::with_capacity(named_captures.len());
+        for name in data.names() {
+            let capture = named_captures.remove(&name).flatten();
+            pairs.push((name, capture));
+        }
+        pairs
+    } else {
+        if !matches!(keys.ruby_type(), Ruby::Array) {
+            let mut message = String::from("wrong argument type ");
+            message.push_str(interp.inspect_type_name_for_value(keys));
+            message.push_str(" (expected Array)");
+            return Err(TypeError::from(message).into());
+        }
+        let keys: Vec<Value> = interp.try_convert_mut(keys)?;
+        let mut requested = Vec::with_capacity(keys.len());
+        for mut key in keys {
+            if let Ok(symbol) = unsafe { Symbol::unbox_from_value(&mut key, interp) } {
+                requested.push(symbol.bytes(interp).to_vec());
+            } else {
+                let mut message = String::from("wrong argument type ");
+                message.push_str(interp.inspect_type_name_for_value(key));
+                message.push_str(" (expected Symbol)");
+                return Err(TypeError::from(message).into());
+            }
+        }
+        let data = unsafe { MatchData::unbox_from_value(&mut value, interp)? };
+        let mut named_captures = data.named_captures()?.unwrap_or_default();
+        let mut pairs = Vec::with_capacity(requested.len());
+        // Patterns with more keys than named capture groups can never match,
+        // so the deconstructed `Hash` is empty.
+        if requested.len() <= named_captures.len() {
+            for name in requested {
+                if let Some(capture) = named_captures.remove(&name) {
+                    pairs.push((name, capture));
+                } else {
+                    // Stop at the first name which is not a capture group like
+                    // MRI does; the pattern cannot match anyway.
+                    break;
+                }
+            }
+        }
+        pairs
+    };
+    let mut deconstructed = Vec::with_capacity(pairs.len());
+    for (name, capture) in pairs {
+        let sym = interp.intern_bytes(name)?;
+        let key = Symbol::alloc_value(sym.into(), interp)?;
+        let capture = interp.try_convert_mut(capture)?;
+        deconstructed.push((key, capture));
+    }
+    interp.try_convert_mut(deconstructed)
+}
+
 pub fn element_reference(
     interp: &mut Artichoke,
     mut value: Value,
@@ -1,6 +1,14 @@
 # frozen_string_literal: true
 
 class Method
+  # `mruby-method` derives `arity` and `parameters` from the method's
+  # underlying proc. Preserve these implementations under aliases: the
+  # interpreter replaces the public methods with signature-aware Rust
+  # implementations which fall back to the aliases for methods without a
+  # declared signature.
+  alias __builtin_arity arity
+  alias __builtin_parameters parameters
+
   def <<(other)
     ->(*args, &block) { call(other.call(*args, &block)) }
   end
@@ -14,3 +22,8 @@ class Method
     ->(*args, &b) { m.call(*args, &b) }
   end
 end
+
+class UnboundMethod
+  alias __builtin_arity arity
+  alias __builtin_parameters parameters
+end
@@ -1,19 +1,71 @@
-use std::ffi::CStr;
+pub mod mruby;
+pub mod trampoline;
 
-use crate::extn::prelude::*;
+#[derive(Debug, Clone, Copy)]
+pub struct Method;
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnboundMethod;
 
-const METHOD_CSTR: &CStr = cstr::cstr!("Method");
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
 
-pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
-    if interp.is_class_defined::<Method>() {
-        return Ok(());
+    #[test]
+    fn declared_signatures_are_reported_by_parameters() {
+        let mut interp = interpreter().unwrap();
+        let result = interp
+            .eval(b"String.instance_method(:center).parameters == [[:req, :width], [:opt, :padstr]]")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"'x'.method(:center).parameters == [[:req, :width], [:opt, :padstr]]")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
     }
-    let spec = class::Spec::new("Method", METHOD_CSTR, None, None)?;
-    interp.def_class::<Method>(spec)?;
-    interp.eval(&include_bytes!("method.rb")[..])?;
-    trace!("Patched Method onto interpreter");
-    Ok(())
-}
 
-#[derive(Debug, Clone, Copy)]
-pub struct Method;
+    #[test]
+    fn declared_signatures_are_reported_by_arity() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"String.instance_method(:center).arity").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), -2);
+        let result = interp.eval(b"'x'.method(:center).arity").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), -2);
+    }
+
+    #[test]
+    fn ruby_implemented_methods_can_declare_signatures() {
+        let mut interp = interpreter().unwrap();
+        // `Array#push` and `Kernel#Integer` are implemented in Ruby but
+        // declare their signatures at registration time.
+        let result = interp.eval(b"[].method(:push).parameters == [[:rest, :args]]").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"[].method(:push).arity").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), -1);
+        let result = interp
+            .eval(b"method(:Integer).parameters == [[:req, :arg], [:opt, :base], [:key, :exception]]")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"method(:Integer).arity").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), -2);
+    }
+
+    #[test]
+    fn undeclared_rust_methods_fall_back_to_the_proc_derived_guess() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(b"'x'.method(:bytesize).arity").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), -1);
+        let result = interp.eval(b"'x'.method(:bytesize).parameters == [[:rest]]").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn undeclared_ruby_methods_fall_back_to_the_proc_derived_guess() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(b"class MethodArityFallbackTest; def m(a, b = 1); end; end")
+            .unwrap();
+        let result = interp.eval(b"MethodArityFallbackTest.new.method(:m).arity").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), -2);
+    }
+}
@@ -0,0 +1,55 @@
+use std::ffi::CStr;
+
+use crate::extn::core::method::{self, trampoline};
+use crate::extn::prelude::*;
+
+const METHOD_CSTR: &CStr = cstr::cstr!("Method");
+const UNBOUND_METHOD_CSTR: &CStr = cstr::cstr!("UnboundMethod");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<method::Method>() {
+        return Ok(());
+    }
+    // `method.rb` aliases the `mruby-method` implementations of `arity` and
+    // `parameters` before the Rust implementations below replace them. The
+    // Rust implementations fall back to these aliases for methods without a
+    // declared signature, so the aliases must be created first.
+    interp.eval(&include_bytes!("method.rb")[..])?;
+    let spec = class::Spec::new("Method", METHOD_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_method("arity", method_arity, sys::mrb_args_none())?
+        .add_method("parameters", method_parameters, sys::mrb_args_none())?
+        .define()?;
+    interp.def_class::<method::Method>(spec)?;
+    trace!("Patched Method onto interpreter");
+    let spec = class::Spec::new("UnboundMethod", UNBOUND_METHOD_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_method("arity", method_arity, sys::mrb_args_none())?
+        .add_method("parameters", method_parameters, sys::mrb_args_none())?
+        .define()?;
+    interp.def_class::<method::UnboundMethod>(spec)?;
+    trace!("Patched UnboundMethod onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn method_arity(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::arity(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn method_parameters(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::parameters(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
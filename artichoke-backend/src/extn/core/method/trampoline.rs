@@ -0,0 +1,65 @@
+use crate::extn::core::symbol::Symbol;
+use crate::extn::prelude::*;
+use crate::method::signature::Signature;
+
+/// Look up the parameter specification declared for the method wrapped by a
+/// `Method` or `UnboundMethod` object.
+///
+/// Signatures are declared at registration time with
+/// [`Builder::add_method_signature`] and are stored in the interpreter state
+/// keyed by the method's owner and name, both of which are recoverable from
+/// the method object.
+///
+/// [`Builder::add_method_signature`]: crate::class::Builder::add_method_signature
+fn declared_signature(interp: &mut Artichoke, value: &Value) -> Result<Option<Signature>, Error> {
+    let owner = value.funcall(interp, "owner", &[], None)?;
+    if !matches!(owner.ruby_type(), Ruby::Class | Ruby::Module) {
+        return Ok(None);
+    }
+    let mut name = value.funcall(interp, "name", &[], None)?;
+    if name.ruby_type() != Ruby::Symbol {
+        return Ok(None);
+    }
+    let method = unsafe { Symbol::unbox_from_value(&mut name, interp)? }.id();
+    // Safety:
+    //
+    // The type check above ensures `owner` is a Class or Module, so the value
+    // union holds an `RClass` pointer.
+    let owner = unsafe { sys::mrb_sys_class_ptr(owner.inner()) };
+    let state = interp.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+    Ok(state.method_signatures.get(owner, method).cloned())
+}
+
+fn symbol_for(interp: &mut Artichoke, name: &'static str) -> Result<Value, Error> {
+    let mut bytes = name.as_bytes().to_vec();
+    bytes.push(b'\0');
+    let sym = interp.intern_bytes_with_trailing_nul(bytes)?;
+    Symbol::alloc_value(sym.into(), interp)
+}
+
+pub fn arity(interp: &mut Artichoke, value: Value) -> Result<Value, Error> {
+    if let Some(signature) = declared_signature(interp, &value)? {
+        return Ok(interp.convert(signature.arity()));
+    }
+    // Fall back to mruby's guess derived from the underlying proc. The
+    // `mruby-method` implementation is preserved under this alias by
+    // `method.rb`.
+    value.funcall(interp, "__builtin_arity", &[], None)
+}
+
+pub fn parameters(interp: &mut Artichoke, value: Value) -> Result<Value, Error> {
+    if let Some(signature) = declared_signature(interp, &value)? {
+        let mut parameters = Vec::with_capacity(signature.parameters().len());
+        for parameter in signature.parameters() {
+            let kind = symbol_for(interp, parameter.kind())?;
+            let name = symbol_for(interp, parameter.name())?;
+            let parameter = interp.try_convert_mut(vec![kind, name])?;
+            parameters.push(parameter);
+        }
+        return interp.try_convert_mut(parameters);
+    }
+    // Fall back to mruby's guess derived from the underlying proc. The
+    // `mruby-method` implementation is preserved under this alias by
+    // `method.rb`.
+    value.funcall(interp, "__builtin_parameters", &[], None)
+}
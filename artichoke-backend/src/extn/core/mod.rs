@@ -6,13 +6,16 @@ pub mod array;
 pub mod artichoke;
 pub mod basicobject;
 pub mod comparable;
+pub mod dir;
 pub mod enumerable;
 pub mod enumerator;
 #[cfg(feature = "core-env")]
 pub mod env;
 pub mod exception;
 pub mod falseclass;
+pub mod file;
 pub mod float;
+pub mod gc;
 pub mod hash;
 pub mod integer;
 pub mod kernel;
@@ -25,13 +28,17 @@ pub mod module;
 pub mod nilclass;
 pub mod numeric;
 pub mod object;
+pub mod objectspace;
 pub mod proc;
+pub mod process;
 #[cfg(feature = "core-random")]
 pub mod random;
 pub mod range;
 #[cfg(feature = "core-regexp")]
 pub mod regexp;
+pub mod stdio;
 pub mod string;
+pub mod r#struct;
 pub mod symbol;
 pub mod thread;
 #[cfg(feature = "core-time")]
@@ -51,22 +58,30 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     comparable::init(interp)?;
     symbol::mruby::init(interp)?;
     artichoke::init(interp)?;
+    // `Artichoke::Enumerable` depends on: `Artichoke`
+    enumerable::mruby::init(interp)?;
     enumerator::init(interp)?;
     #[cfg(feature = "core-env")]
     env::mruby::init(interp)?;
-    hash::init(interp)?;
+    // `Dir.glob` depends on: `File` (`FNM_*` constants)
+    file::mruby::init(interp)?;
+    dir::mruby::init(interp)?;
+    hash::mruby::init(interp)?;
     numeric::init(interp)?;
     integer::mruby::init(interp)?;
     float::mruby::init(interp)?;
+    gc::mruby::init(interp)?;
     kernel::mruby::init(interp)?;
     #[cfg(feature = "core-regexp")]
     matchdata::mruby::init(interp)?;
     #[cfg(feature = "core-math")]
     math::mruby::init(interp)?;
-    method::init(interp)?;
+    method::mruby::init(interp)?;
     module::init(interp)?;
     object::init(interp)?;
+    objectspace::mruby::init(interp)?;
     proc::init(interp)?;
+    process::mruby::init(interp)?;
     trueclass::init(interp)?;
     falseclass::init(interp)?;
     nilclass::init(interp)?;
@@ -76,7 +91,10 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     range::init(interp)?;
     #[cfg(feature = "core-regexp")]
     regexp::mruby::init(interp)?;
+    stdio::mruby::init(interp)?;
     string::mruby::init(interp)?;
+    // `Struct` depends on: `Enumerable`
+    r#struct::mruby::init(interp)?;
     thread::init(interp)?;
     #[cfg(feature = "core-time")]
     time::mruby::init(interp)?;
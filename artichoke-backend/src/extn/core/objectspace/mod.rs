@@ -0,0 +1,194 @@
+//! ObjectSpace module, an interface to the mruby garbage collector.
+
+use crate::convert::HeapAllocatedData;
+use crate::extn::prelude::*;
+
+pub mod mruby;
+pub mod trampoline;
+
+/// Marker for the `ObjectSpace` module in the module registry.
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectSpace;
+
+/// `ObjectSpace::WeakMap`, an identity map that does not keep its keys and
+/// values alive.
+///
+/// Entries are raw [`sys::mrb_value`]s that are deliberately not registered
+/// with the garbage collector, so storing an object in a `WeakMap` does not
+/// prevent it from being collected. Entries whose key or value has been
+/// collected are purged lazily on every access.
+///
+/// Immediate values such as `Integer`s, `Symbol`s, `nil`, `true`, and `false`
+/// are never garbage collected, so they are effectively stored strongly.
+#[derive(Default, Debug)]
+pub struct WeakMap {
+    entries: Vec<(sys::mrb_value, sys::mrb_value)>,
+}
+
+impl WeakMap {
+    /// Construct a new, empty `WeakMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of live entries in the map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the map has no live entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Look up the value stored for `key`, comparing keys by object identity.
+    #[must_use]
+    pub fn get(&self, key: sys::mrb_value) -> Option<sys::mrb_value> {
+        let key = value_id(key);
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| value_id(*entry_key) == key)
+            .map(|(_, value)| *value)
+    }
+
+    /// Whether the map has an entry for `key`, comparing keys by object
+    /// identity.
+    #[must_use]
+    pub fn contains(&self, key: sys::mrb_value) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Store `value` for `key`, replacing any existing entry for `key`.
+    pub fn insert(&mut self, key: sys::mrb_value, value: sys::mrb_value) {
+        let id = value_id(key);
+        if let Some(entry) = self.entries.iter_mut().find(|(entry_key, _)| value_id(*entry_key) == id) {
+            entry.1 = value;
+        } else {
+            self.entries.push((key, value));
+        }
+    }
+
+    /// Snapshot of the entries in the map.
+    ///
+    /// Callers that yield entries to Ruby blocks must iterate over a snapshot
+    /// because the block may mutate the map.
+    #[must_use]
+    pub fn pairs(&self) -> Vec<(sys::mrb_value, sys::mrb_value)> {
+        self.entries.clone()
+    }
+
+    /// Remove all entries for which `f` returns `false`.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&(sys::mrb_value, sys::mrb_value)) -> bool,
+    {
+        self.entries.retain(f);
+    }
+}
+
+impl HeapAllocatedData for WeakMap {
+    const RUBY_TYPE: &'static str = "ObjectSpace::WeakMap";
+}
+
+/// Object id of an `mrb_value`, used to compare keys by identity.
+///
+/// `mrb_obj_id` derives the id from the inline value or heap pointer and does
+/// not dereference the value, so it is safe to call on dead values.
+#[must_use]
+fn value_id(value: sys::mrb_value) -> sys::mrb_int {
+    unsafe { sys::mrb_obj_id(value) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn weak_map_stores_and_retrieves_entries_by_identity() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(b"$map = ObjectSpace::WeakMap.new; $key = Object.new; $value = Object.new; $map[$key] = $value")
+            .unwrap();
+        let result = interp.eval(b"$map[$key].equal?($value)").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"$map.key?($key)").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"$map.key?(Object.new)").unwrap();
+        assert!(!result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"$map[Object.new].nil?").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"$map.length").unwrap();
+        assert_eq!(result.try_convert_into::<usize>(&interp).unwrap(), 1);
+    }
+
+    #[test]
+    fn weak_map_purges_entries_on_gc() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(b"$map = ObjectSpace::WeakMap.new; $key = Object.new; $value = Object.new; $map[$key] = $value")
+            .unwrap();
+        {
+            let mut arena = interp.create_arena_savepoint().unwrap();
+            for _ in 0..100 {
+                arena.eval(b"$map[Object.new] = Object.new").unwrap();
+            }
+            arena.restore();
+        }
+        let result = interp.eval(b"$map.length").unwrap();
+        assert_eq!(result.try_convert_into::<usize>(&interp).unwrap(), 101);
+        interp.full_gc().unwrap();
+        let result = interp.eval(b"$map.length").unwrap();
+        let length = result.try_convert_into::<usize>(&interp).unwrap();
+        assert!(
+            length < 101,
+            "Full GC should purge entries for collected objects, got {} live entries",
+            length
+        );
+        // Strongly referenced entries survive the GC and still resolve.
+        let result = interp.eval(b"$map[$key].equal?($value)").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn weak_map_each_pair_yields_live_entries() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(b"$map = ObjectSpace::WeakMap.new; $key = Object.new; $value = Object.new; $map[$key] = $value")
+            .unwrap();
+        let result = interp
+            .eval(b"pairs = []; $map.each_pair { |key, value| pairs << [key, value] }; pairs == [[$key, $value]]")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"begin; $map.each_pair; false; rescue LocalJumpError; true; end")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn id2ref_resolves_live_objects() {
+        let mut interp = interpreter().unwrap();
+        let result = interp
+            .eval(b"$obj = Object.new; ObjectSpace._id2ref($obj.object_id).equal?($obj)")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"ObjectSpace._id2ref(nil.object_id).nil?").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp
+            .eval(b"ObjectSpace._id2ref(true.object_id).equal?(true)")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+
+    #[test]
+    fn id2ref_raises_range_error_for_unresolvable_ids() {
+        let mut interp = interpreter().unwrap();
+        let result = interp
+            .eval(b"begin; ObjectSpace._id2ref(1.object_id); false; rescue RangeError; true; end")
+            .unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+}
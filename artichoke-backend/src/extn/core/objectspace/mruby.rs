@@ -0,0 +1,125 @@
+//! FFI glue between the Rust trampolines and the mruby C interpreter.
+
+use std::ffi::CStr;
+
+use crate::extn::core::objectspace::{self, trampoline};
+use crate::extn::prelude::*;
+
+const OBJECT_SPACE_CSTR: &CStr = cstr::cstr!("ObjectSpace");
+const WEAK_MAP_CSTR: &CStr = cstr::cstr!("WeakMap");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_module_defined::<objectspace::ObjectSpace>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "ObjectSpace", OBJECT_SPACE_CSTR, None)?;
+    module::Builder::for_spec(interp, &spec)
+        .add_module_method("_id2ref", object_space_id2ref, sys::mrb_args_req(1))?
+        .define()?;
+
+    let weak_map = class::Spec::new(
+        "WeakMap",
+        WEAK_MAP_CSTR,
+        Some(EnclosingRubyScope::module(&spec)),
+        Some(def::box_unbox_free::<objectspace::WeakMap>),
+    )?;
+    class::Builder::for_spec(interp, &weak_map)
+        .value_is_rust_object()
+        .add_method("[]", weak_map_element_reference, sys::mrb_args_req(1))?
+        .add_method("[]=", weak_map_element_assignment, sys::mrb_args_req(2))?
+        .add_method("each_pair", weak_map_each_pair, sys::mrb_args_none())?
+        .add_method("include?", weak_map_is_key, sys::mrb_args_req(1))?
+        .add_method("initialize", weak_map_initialize, sys::mrb_args_none())?
+        .add_method("key?", weak_map_is_key, sys::mrb_args_req(1))?
+        .add_method("length", weak_map_length, sys::mrb_args_none())?
+        .add_method("member?", weak_map_is_key, sys::mrb_args_req(1))?
+        .add_method("size", weak_map_length, sys::mrb_args_none())?
+        .define()?;
+    interp.def_class::<objectspace::WeakMap>(weak_map)?;
+
+    interp.def_module::<objectspace::ObjectSpace>(spec)?;
+    trace!("Patched ObjectSpace onto interpreter");
+    trace!("Patched ObjectSpace::WeakMap onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn object_space_id2ref(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let id = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let id = Value::from(id);
+    let result = trampoline::id2ref(&mut guard, id);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn weak_map_initialize(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let slf = Value::from(slf);
+    let result = trampoline::weak_map_initialize(&mut guard, slf);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn weak_map_element_reference(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let key = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let map = Value::from(slf);
+    let key = Value::from(key);
+    let result = trampoline::weak_map_element_reference(&mut guard, map, key);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn weak_map_element_assignment(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let (key, value) = mrb_get_args!(mrb, required = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let map = Value::from(slf);
+    let key = Value::from(key);
+    let value = Value::from(value);
+    let result = trampoline::weak_map_element_assignment(&mut guard, map, key, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn weak_map_each_pair(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let block = mrb_get_args!(mrb, &block);
+    unwrap_interpreter!(mrb, to => guard);
+    let map = Value::from(slf);
+    let result = trampoline::weak_map_each_pair(&mut guard, map, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn weak_map_is_key(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let key = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let map = Value::from(slf);
+    let key = Value::from(key);
+    let result = trampoline::weak_map_is_key(&mut guard, map, key);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn weak_map_length(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let map = Value::from(slf);
+    let result = trampoline::weak_map_length(&mut guard, map);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
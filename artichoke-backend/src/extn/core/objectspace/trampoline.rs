@@ -0,0 +1,141 @@
+//! Glue between mruby FFI and the `ObjectSpace` Rust implementation.
+
+use std::ffi::c_void;
+use std::os::raw::c_int;
+
+use crate::convert::implicitly_convert_to_int;
+use crate::extn::core::array::Array;
+use crate::extn::core::objectspace::WeakMap;
+use crate::extn::prelude::*;
+use crate::types;
+
+pub fn weak_map_initialize(interp: &mut Artichoke, into: Value) -> Result<Value, Error> {
+    let map = WeakMap::new();
+    WeakMap::box_into_value(map, into, interp)
+}
+
+pub fn weak_map_element_reference(interp: &mut Artichoke, mut map: Value, key: Value) -> Result<Value, Error> {
+    let mut map = unsafe { WeakMap::unbox_from_value(&mut map, interp)? };
+    purge_dead_entries(interp, &mut map);
+    if let Some(value) = map.get(key.inner()) {
+        Ok(interp.protect(Value::from(value)))
+    } else {
+        Ok(Value::nil())
+    }
+}
+
+pub fn weak_map_element_assignment(
+    interp: &mut Artichoke,
+    mut map: Value,
+    key: Value,
+    value: Value,
+) -> Result<Value, Error> {
+    let mut map = unsafe { WeakMap::unbox_from_value(&mut map, interp)? };
+    purge_dead_entries(interp, &mut map);
+    // The key and value are stored as raw `mrb_value`s without registering
+    // them with the garbage collector, which is what makes the map weak.
+    map.insert(key.inner(), value.inner());
+    Ok(value)
+}
+
+pub fn weak_map_is_key(interp: &mut Artichoke, mut map: Value, key: Value) -> Result<Value, Error> {
+    let mut map = unsafe { WeakMap::unbox_from_value(&mut map, interp)? };
+    purge_dead_entries(interp, &mut map);
+    let contains = map.contains(key.inner());
+    Ok(interp.convert(contains))
+}
+
+pub fn weak_map_length(interp: &mut Artichoke, mut map: Value) -> Result<Value, Error> {
+    let mut map = unsafe { WeakMap::unbox_from_value(&mut map, interp)? };
+    purge_dead_entries(interp, &mut map);
+    let length = map.len();
+    interp.try_convert(length)
+}
+
+pub fn weak_map_each_pair(interp: &mut Artichoke, map: Value, block: Option<Block>) -> Result<Value, Error> {
+    let block = block.ok_or_else(|| LocalJumpError::with_message("no block given"))?;
+    let pairs = {
+        let mut map_receiver = map;
+        let mut map = unsafe { WeakMap::unbox_from_value(&mut map_receiver, interp)? };
+        purge_dead_entries(interp, &mut map);
+        // Iterate over a snapshot of the entries because the block may mutate
+        // the map.
+        map.pairs()
+    };
+    for (key, value) in pairs {
+        let key = interp.protect(Value::from(key));
+        let value = interp.protect(Value::from(value));
+        let pair = Array::alloc_value(Array::from(vec![key, value]), interp)?;
+        block.yield_arg(interp, &pair)?;
+    }
+    Ok(map)
+}
+
+pub fn id2ref(interp: &mut Artichoke, id: Value) -> Result<Value, Error> {
+    let id = implicitly_convert_to_int(interp, id)?;
+    // `nil`, `true`, and `false` have well-known object ids but do not live on
+    // the garbage collector heap, so check them before walking the heap.
+    let immediates = [Value::nil(), interp.convert(true), interp.convert(false)];
+    for immediate in immediates {
+        if unsafe { sys::mrb_obj_id(immediate.inner()) } == id {
+            return Ok(immediate);
+        }
+    }
+    let mut search = Id2RefSearch { id, found: None };
+    unsafe {
+        interp.with_ffi_boundary(|mrb| {
+            sys::mrb_objspace_each_objects(
+                mrb,
+                Some(id2ref_each_object),
+                (&mut search as *mut Id2RefSearch).cast::<c_void>(),
+            );
+        })?;
+    }
+    if let Some(obj) = search.found {
+        Ok(interp.protect(Value::from(obj)))
+    } else {
+        // `Integer` and `Symbol` object ids are hashes of the inline value and
+        // cannot be mapped back to an object, so they raise `RangeError` like
+        // ids of collected objects.
+        Err(RangeError::from(format!("{} is not id value", id)).into())
+    }
+}
+
+/// Purge entries whose key or value has been garbage collected.
+///
+/// `WeakMap` stores raw `mrb_value`s that the garbage collector does not know
+/// about, so dead entries are swept lazily on every access instead of in a GC
+/// callback.
+fn purge_dead_entries(interp: &mut Artichoke, map: &mut WeakMap) {
+    map.retain(|&(key, value)| !Value::from(key).is_dead(interp) && !Value::from(value).is_dead(interp));
+}
+
+struct Id2RefSearch {
+    id: sys::mrb_int,
+    found: Option<sys::mrb_value>,
+}
+
+/// [`sys::mrb_objspace_each_objects`] callback which searches the heap for a
+/// live object with the given object id.
+unsafe extern "C" fn id2ref_each_object(
+    _mrb: *mut sys::mrb_state,
+    obj: *mut sys::RBasic,
+    data: *mut c_void,
+) -> c_int {
+    // From `mruby/gc.h`: return `MRB_EACH_OBJ_OK` to continue iterating and
+    // `MRB_EACH_OBJ_BREAK` to stop.
+    const EACH_OBJ_OK: c_int = sys::MRB_EACH_OBJ_OK as c_int;
+    const EACH_OBJ_BREAK: c_int = sys::MRB_EACH_OBJ_BREAK as c_int;
+
+    let search = &mut *data.cast::<Id2RefSearch>();
+    let value = sys::mrb_sys_obj_value(obj.cast::<c_void>());
+    // Skip free heap slots and internal-use objects like `MRB_TT_ICLASS`.
+    if let Ruby::Unreachable = types::ruby_from_mrb_value(value) {
+        return EACH_OBJ_OK;
+    }
+    if sys::mrb_obj_id(value) == search.id {
+        search.found = Some(value);
+        return EACH_OBJ_BREAK;
+    }
+    EACH_OBJ_OK
+}
@@ -0,0 +1,191 @@
+//! The Ruby Process module.
+//!
+//! This module implements a minimal subset of the [`Process`] module from Ruby
+//! Core: `Process.spawn`, `Process.wait`, and the [`Process::Status`] class.
+//! Together with `Kernel#system` and backticks, these APIs are backed by the
+//! [`SpawnProcess`] trait, which lets embedders deny or virtualize subprocess
+//! execution.
+//!
+//! Waiting on a child records its exit disposition in the `$?` global as a
+//! `Process::Status`.
+//!
+//! [`Process`]: https://ruby-doc.org/core-2.6.3/Process.html
+//! [`Process::Status`]: https://ruby-doc.org/core-2.6.3/Process/Status.html
+
+use crate::convert::implicitly_convert_to_string;
+use crate::extn::prelude::*;
+
+pub mod mruby;
+pub mod trampoline;
+
+/// The name of the global variable which holds the status of the most
+/// recently waited-on child process — `$?`.
+pub const LAST_STATUS: &[u8] = b"$?";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Process;
+
+/// A Ruby `Process::Status` instance — the exit disposition of a waited-on
+/// child process.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Status(ChildStatus);
+
+impl From<ChildStatus> for Status {
+    fn from(status: ChildStatus) -> Self {
+        Self(status)
+    }
+}
+
+impl Status {
+    /// Return the process ID of the child.
+    #[must_use]
+    pub fn pid(&self) -> i64 {
+        self.0.pid()
+    }
+
+    /// Return the exit code if the child exited normally.
+    #[must_use]
+    pub fn exitstatus(&self) -> Option<i64> {
+        self.0.exitstatus()
+    }
+
+    /// Whether the child was terminated by a signal.
+    #[must_use]
+    pub fn is_signaled(&self) -> bool {
+        self.0.is_signaled()
+    }
+
+    /// Whether the child exited normally with a zero exit code.
+    ///
+    /// Returns [`None`] for children which did not exit normally.
+    #[must_use]
+    pub fn is_success(&self) -> Option<bool> {
+        self.0.is_success()
+    }
+}
+
+impl HeapAllocatedData for Status {
+    const RUBY_TYPE: &'static str = "Process::Status";
+}
+
+/// Record `status` as the status of the most recently waited-on child by
+/// setting the `$?` global to a `Process::Status`.
+pub fn record_last_status(interp: &mut Artichoke, status: ChildStatus) -> Result<(), Error> {
+    let status = Status::alloc_value(Status::from(status), interp)?;
+    interp.set_global_variable(LAST_STATUS, &status)?;
+    Ok(())
+}
+
+/// Extract a [`CommandSpec`] from the arguments given to `Kernel#system` and
+/// `Process.spawn`.
+///
+/// A single command string is dispatched with MRI's shell rules — strings
+/// with shell metacharacters run through the shell, all others are split on
+/// whitespace and executed directly. Multiple arguments form an argument
+/// vector which is always executed directly.
+pub fn extract_command(interp: &mut Artichoke, args: &mut [Value]) -> Result<CommandSpec, Error> {
+    match args {
+        [] => Err(ArgumentError::with_message("wrong number of arguments (given 0, expected 1+)").into()),
+        [command] => {
+            // Safety:
+            //
+            // The byteslice is immediately copied to an owned buffer. There
+            // are no intervening interpreter accesses.
+            let command = unsafe { implicitly_convert_to_string(interp, command)? }.to_vec();
+            Ok(CommandSpec::parse(&command))
+        }
+        args => {
+            let mut argv = Vec::with_capacity(args.len());
+            for arg in args {
+                // Safety:
+                //
+                // The byteslice is immediately copied to an owned buffer.
+                // There are no intervening interpreter accesses.
+                let arg = unsafe { implicitly_convert_to_string(interp, arg)? }.to_vec();
+                argv.push(arg);
+            }
+            Ok(CommandSpec::argv(argv))
+        }
+    }
+}
+
+/// Construct the error raised when a command cannot be executed, with MRI's
+/// `Errno::ENOENT` message format.
+#[must_use]
+pub fn no_such_command(command: &CommandSpec) -> Error {
+    let program = match command.program() {
+        Program::Shell(line) => line.as_slice(),
+        Program::Argv(argv) => argv.first().map(Vec::as_slice).unwrap_or_default(),
+    };
+    let mut message = String::from("No such file or directory - ");
+    message.push_str(&String::from_utf8_lossy(program));
+    SystemCallError::from(message).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    const SUBJECT: &str = "Process";
+    const FUNCTIONAL_TEST: &[u8] = include_bytes!("process_functional_test.rb");
+
+    // The functional test spawns `echo`, `true`, and `false` through `sh`, so
+    // it only runs on hosts with a POSIX shell.
+    #[cfg(unix)]
+    #[test]
+    fn functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn backticks_capture_stdout_and_set_last_status() {
+        let mut interp = interpreter().unwrap();
+        let captured = interp
+            .eval(b"`echo artichoke` == \"artichoke\\n\" && $?.success? && $?.exitstatus.zero? && $?.pid.positive?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(captured);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn system_returns_true_false_and_nil() {
+        let mut interp = interpreter().unwrap();
+        let spec = interp
+            .eval(b"system('true') == true && system('false') == false && $?.exitstatus == 1")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(spec);
+        let not_executable = interp
+            .eval(b"system('artichoke-no-such-command-wibble').nil?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(not_executable);
+    }
+
+    #[test]
+    fn deny_all_strategy_raises_security_error() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_spawn_process(crate::state::process::Strategy::deny())
+            .unwrap();
+        let err = interp.eval(b"system('echo artichoke')").unwrap_err();
+        assert_eq!("SecurityError", err.name().as_ref());
+        assert_eq!(
+            &b"spawning child processes is disabled in this interpreter"[..],
+            err.message().as_ref()
+        );
+        let err = interp.eval(b"`echo artichoke`").unwrap_err();
+        assert_eq!("SecurityError", err.name().as_ref());
+        let err = interp.eval(b"Process.spawn('echo artichoke')").unwrap_err();
+        assert_eq!("SecurityError", err.name().as_ref());
+    }
+}
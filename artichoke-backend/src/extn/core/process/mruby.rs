@@ -0,0 +1,106 @@
+//! FFI glue between the Rust trampolines and the mruby C interpreter.
+
+use std::ffi::CStr;
+
+use crate::extn::core::process::{self, trampoline};
+use crate::extn::prelude::*;
+
+const PROCESS_CSTR: &CStr = cstr::cstr!("Process");
+const STATUS_CSTR: &CStr = cstr::cstr!("Status");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_module_defined::<process::Process>() {
+        return Ok(());
+    }
+    let spec = module::Spec::new(interp, "Process", PROCESS_CSTR, None)?;
+    module::Builder::for_spec(interp, &spec)
+        .add_module_method("spawn", process_spawn, sys::mrb_args_rest())?
+        .add_module_method("wait", process_wait, sys::mrb_args_req(1))?
+        .define()?;
+
+    let status = class::Spec::new(
+        "Status",
+        STATUS_CSTR,
+        Some(EnclosingRubyScope::module(&spec)),
+        Some(def::box_unbox_free::<process::Status>),
+    )?;
+    class::Builder::for_spec(interp, &status)
+        .value_is_rust_object()
+        .add_method("exitstatus", status_exitstatus, sys::mrb_args_none())?
+        .add_method("pid", status_pid, sys::mrb_args_none())?
+        .add_method("signaled?", status_is_signaled, sys::mrb_args_none())?
+        .add_method("success?", status_is_success, sys::mrb_args_none())?
+        .define()?;
+    interp.def_class::<process::Status>(status)?;
+
+    interp.def_module::<process::Process>(spec)?;
+    trace!("Patched Process onto interpreter");
+    trace!("Patched Process::Status onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn process_spawn(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    unwrap_interpreter!(mrb, to => guard);
+    let args = args.iter().copied().map(Value::from).collect::<Vec<_>>();
+    let result = trampoline::spawn(&mut guard, args);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn process_wait(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let pid = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let pid = Value::from(pid);
+    let result = trampoline::wait(&mut guard, pid);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn status_exitstatus(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::status_exitstatus(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn status_pid(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::status_pid(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn status_is_signaled(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::status_is_signaled(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn status_is_success(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::status_is_success(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -0,0 +1,49 @@
+# frozen_string_literal: true
+
+def spec
+  backticks_capture_stdout
+  system_exit_status
+  system_command_not_found
+  spawn_and_wait
+  shell_metacharacter_dispatch
+
+  true
+end
+
+def backticks_capture_stdout
+  out = `echo artichoke`
+  raise unless out == "artichoke\n"
+  raise unless $?.success?
+  raise unless $?.exitstatus.zero?
+  raise unless $?.pid.positive?
+  raise if $?.signaled?
+end
+
+def system_exit_status
+  raise unless system('true') == true
+  raise unless $?.success?
+  raise unless system('false') == false
+  raise if $?.success?
+  raise unless $?.exitstatus == 1
+end
+
+def system_command_not_found
+  raise unless system('artichoke-no-such-command-wibble').nil?
+end
+
+def spawn_and_wait
+  pid = Process.spawn('true')
+  raise unless pid.positive?
+  raise unless Process.wait(pid) == pid
+  raise unless $?.success?
+  raise unless $?.pid == pid
+end
+
+def shell_metacharacter_dispatch
+  # A command line with shell metacharacters runs through `sh`.
+  out = `echo artichoke | tr a-z A-Z`
+  raise unless out == "ARTICHOKE\n"
+  raise unless $?.success?
+end
+
+spec if $PROGRAM_NAME == __FILE__
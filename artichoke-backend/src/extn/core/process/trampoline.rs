@@ -0,0 +1,49 @@
+use crate::convert::implicitly_convert_to_int;
+use crate::extn::core::process::{self, Status};
+use crate::extn::prelude::*;
+
+pub fn spawn(interp: &mut Artichoke, mut args: Vec<Value>) -> Result<Value, Error> {
+    let command = process::extract_command(interp, &mut args)?;
+    let child = interp
+        .spawn(command.clone())?
+        .ok_or_else(|| process::no_such_command(&command))?;
+    Ok(interp.convert(child.pid()))
+}
+
+pub fn wait(interp: &mut Artichoke, pid: Value) -> Result<Value, Error> {
+    let pid = implicitly_convert_to_int(interp, pid)?;
+    let status = interp.wait(ChildHandle::new(pid))?;
+    let pid = status.pid();
+    process::record_last_status(interp, status)?;
+    Ok(interp.convert(pid))
+}
+
+pub fn status_exitstatus(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let status = unsafe { Status::unbox_from_value(&mut value, interp)? };
+    if let Some(exitstatus) = status.exitstatus() {
+        Ok(interp.convert(exitstatus))
+    } else {
+        Ok(Value::nil())
+    }
+}
+
+pub fn status_pid(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let status = unsafe { Status::unbox_from_value(&mut value, interp)? };
+    let pid = status.pid();
+    Ok(interp.convert(pid))
+}
+
+pub fn status_is_signaled(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let status = unsafe { Status::unbox_from_value(&mut value, interp)? };
+    let is_signaled = status.is_signaled();
+    Ok(interp.convert(is_signaled))
+}
+
+pub fn status_is_success(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let status = unsafe { Status::unbox_from_value(&mut value, interp)? };
+    if let Some(is_success) = status.is_success() {
+        Ok(interp.convert(is_success))
+    } else {
+        Ok(Value::nil())
+    }
+}
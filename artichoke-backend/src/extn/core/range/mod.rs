@@ -17,3 +17,20 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Range;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    const SUBJECT: &str = "Range";
+    const FUNCTIONAL_TEST: &[u8] = include_bytes!("range_functional_test.rb");
+
+    #[test]
+    fn functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+    }
+}
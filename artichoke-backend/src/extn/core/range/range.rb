@@ -218,6 +218,24 @@ class Range
   def to_a
     raise RangeError, 'cannot convert endless range to an array' if self.end.nil?
 
+    range_begin = self.begin
+    range_end = self.end
+
+    # Fast path for ranges of single-byte ASCII characters: the elements can
+    # be computed arithmetically from the endpoint bytes instead of iterating
+    # `String#succ`, which keeps `('a'..'z').to_a` cheap.
+    if range_begin.is_a?(String) && range_end.is_a?(String) &&
+       range_begin.bytesize == 1 && range_end.bytesize == 1
+      first_byte = range_begin.ord
+      last_byte = range_end.ord
+      if first_byte < 128 && last_byte < 128
+        last_byte -= 1 if exclude_end?
+        return [] if first_byte > last_byte
+
+        return (first_byte..last_byte).map(&:chr)
+      end
+    end
+
     super
   end
 end
@@ -0,0 +1,50 @@
+# frozen_string_literal: true
+
+def spec
+  char_range_to_a
+  char_range_to_a_exclusive
+  char_range_to_a_empty
+  char_range_to_a_single
+  char_range_to_a_mutation
+  multi_char_range_to_a
+
+  true
+end
+
+def char_range_to_a
+  chars = ('a'..'z').to_a
+  raise unless chars.length == 26
+  raise unless chars.first == 'a'
+  raise unless chars.last == 'z'
+  raise unless chars[1] == 'b'
+end
+
+def char_range_to_a_exclusive
+  raise unless ('a'...'e').to_a == %w[a b c d]
+end
+
+def char_range_to_a_empty
+  raise unless ('z'..'a').to_a == []
+  raise unless ('a'...'a').to_a == []
+end
+
+def char_range_to_a_single
+  raise unless ('a'..'a').to_a == %w[a]
+end
+
+def char_range_to_a_mutation
+  chars = ('a'..'e').to_a
+  chars[2] = 'X'
+  raise unless chars == %w[a b X d e]
+
+  chars << 'f'
+  raise unless chars == %w[a b X d e f]
+
+  chars.shift
+  raise unless chars == %w[b X d e f]
+end
+
+def multi_char_range_to_a
+  # Multi-character endpoints take the generic `String#succ` path.
+  raise unless ('aa'..'ac').to_a == %w[aa ab ac]
+end
@@ -9,7 +9,7 @@ use onig::{Regex, Syntax};
 
 use super::{NameToCaptureLocations, NilableString};
 use crate::extn::core::matchdata::MatchData;
-use crate::extn::core::regexp::{self, Config, Encoding, Regexp, RegexpType, Scan, Source};
+use crate::extn::core::regexp::{Config, Encoding, Regexp, RegexpType, Scan, Source};
 use crate::extn::prelude::*;
 
 // The Oniguruma `Regexp` backend requires that `u32` can be widened to `usize`
@@ -170,31 +170,16 @@ impl RegexpType for Onig {
     fn case_match(&self, interp: &mut Artichoke, haystack: &[u8]) -> Result<bool, Error> {
         let haystack = str::from_utf8(haystack)
             .map_err(|_| ArgumentError::with_message("Oniguruma backend for Regexp only supports UTF-8 haystacks"))?;
-        regexp::clear_capture_globals(interp)?;
         if let Some(captures) = self.regex.captures(haystack) {
-            interp.set_active_regexp_globals(captures.len())?;
-            let value = interp.try_convert_mut(captures.at(0))?;
-            interp.set_global_variable(regexp::LAST_MATCHED_STRING, &value)?;
-
-            for group in 0..captures.len() {
-                let value = interp.try_convert_mut(captures.at(group))?;
-                let group = unsafe { NonZeroUsize::new_unchecked(1 + group) };
-                interp.set_global_variable(regexp::nth_match_group(group), &value)?;
-            }
-
+            let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
             if let Some(match_pos) = captures.pos(0) {
-                let pre_match = interp.try_convert_mut(&haystack[..match_pos.0])?;
-                let post_match = interp.try_convert_mut(&haystack[match_pos.1..])?;
-                interp.set_global_variable(regexp::STRING_LEFT_OF_MATCH, &pre_match)?;
-                interp.set_global_variable(regexp::STRING_RIGHT_OF_MATCH, &post_match)?;
+                matchdata.set_region(match_pos.0..match_pos.1);
             }
-            let matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
-            let matchdata = MatchData::alloc_value(matchdata, interp)?;
-            interp.set_global_variable(regexp::LAST_MATCH, &matchdata)?;
+            let data = MatchData::alloc_value(matchdata, interp)?;
+            interp.set_last_match(Some(data))?;
             Ok(true)
         } else {
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             Ok(false)
         }
     }
@@ -234,7 +219,6 @@ impl RegexpType for Onig {
     ) -> Result<Value, Error> {
         let haystack = str::from_utf8(haystack)
             .map_err(|_| ArgumentError::with_message("Oniguruma backend for Regexp only supports UTF-8 haystacks"))?;
-        regexp::clear_capture_globals(interp)?;
         let haystack_char_len = haystack.chars().count();
         let pos = pos.unwrap_or_default();
         let pos = if let Ok(pos) = usize::try_from(pos) {
@@ -254,33 +238,17 @@ impl RegexpType for Onig {
         let target = if let Some(haystack) = haystack.get(offset..) {
             haystack
         } else {
-            interp.unset_global_variable(regexp::LAST_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             return Ok(Value::nil());
         };
 
         if let Some(captures) = self.regex.captures(target) {
-            interp.set_active_regexp_globals(captures.len())?;
-
-            let value = interp.try_convert_mut(captures.at(0))?;
-            interp.set_global_variable(regexp::LAST_MATCHED_STRING, &value)?;
-            for group in 0..captures.len() {
-                let value = interp.try_convert_mut(captures.at(group))?;
-                let group = unsafe { NonZeroUsize::new_unchecked(1 + group) };
-                interp.set_global_variable(regexp::nth_match_group(group), &value)?;
-            }
-
             let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
             if let Some(match_pos) = captures.pos(0) {
-                let pre_match = interp.try_convert_mut(&target[..match_pos.0])?;
-                let post_match = interp.try_convert_mut(&target[match_pos.1..])?;
-                interp.set_global_variable(regexp::STRING_LEFT_OF_MATCH, &pre_match)?;
-                interp.set_global_variable(regexp::STRING_RIGHT_OF_MATCH, &post_match)?;
                 matchdata.set_region(offset + match_pos.0..offset + match_pos.1);
             }
             let data = MatchData::alloc_value(matchdata, interp)?;
-            interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+            interp.set_last_match(Some(data))?;
             if let Some(block) = block {
                 let result = block.yield_arg(interp, &data)?;
                 Ok(result)
@@ -288,9 +256,7 @@ impl RegexpType for Onig {
                 Ok(data)
             }
         } else {
-            interp.unset_global_variable(regexp::LAST_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             Ok(Value::nil())
         }
     }
@@ -298,35 +264,17 @@ impl RegexpType for Onig {
     fn match_operator(&self, interp: &mut Artichoke, haystack: &[u8]) -> Result<Option<usize>, Error> {
         let haystack = str::from_utf8(haystack)
             .map_err(|_| ArgumentError::with_message("Oniguruma backend for Regexp only supports UTF-8 haystacks"))?;
-        regexp::clear_capture_globals(interp)?;
         if let Some(captures) = self.regex.captures(haystack) {
-            interp.set_active_regexp_globals(captures.len())?;
-
-            let value = interp.try_convert_mut(captures.at(0))?;
-            interp.set_global_variable(regexp::LAST_MATCHED_STRING, &value)?;
-            for group in 0..captures.len() {
-                let value = interp.try_convert_mut(captures.at(group))?;
-                let group = unsafe { NonZeroUsize::new_unchecked(1 + group) };
-                interp.set_global_variable(regexp::nth_match_group(group), &value)?;
-            }
-
-            let matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
+            let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
+            let pos = captures.pos(0).map(|match_pos| {
+                matchdata.set_region(match_pos.0..match_pos.1);
+                match_pos.0
+            });
             let data = MatchData::alloc_value(matchdata, interp)?;
-            interp.set_global_variable(regexp::LAST_MATCH, &data)?;
-            if let Some(match_pos) = captures.pos(0) {
-                let pre_match = interp.try_convert_mut(&haystack[..match_pos.0])?;
-                let post_match = interp.try_convert_mut(&haystack[match_pos.1..])?;
-                interp.set_global_variable(regexp::STRING_LEFT_OF_MATCH, &pre_match)?;
-                interp.set_global_variable(regexp::STRING_RIGHT_OF_MATCH, &post_match)?;
-                let pos = match_pos.0;
-                Ok(Some(pos))
-            } else {
-                Ok(Some(0))
-            }
+            interp.set_last_match(Some(data))?;
+            Ok(Some(pos.unwrap_or_default()))
         } else {
-            interp.unset_global_variable(regexp::LAST_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             Ok(None)
         }
     }
@@ -397,30 +345,20 @@ impl RegexpType for Onig {
     fn scan(&self, interp: &mut Artichoke, haystack: &[u8], block: Option<Block>) -> Result<Scan, Error> {
         let haystack = str::from_utf8(haystack)
             .map_err(|_| ArgumentError::with_message("Oniguruma backend for Regexp only supports UTF-8 haystacks"))?;
-        regexp::clear_capture_globals(interp)?;
         let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
 
         let len = NonZeroUsize::new(self.regex.captures_len());
         if let Some(block) = block {
             if let Some(len) = len {
-                interp.set_active_regexp_globals(len.get())?;
-
                 let mut iter = self.regex.captures_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Haystack);
                 }
                 for captures in iter {
-                    let fullcapture = interp.try_convert_mut(captures.at(0))?;
-                    interp.set_global_variable(regexp::LAST_MATCHED_STRING, &fullcapture)?;
-
                     let mut groups = Vec::with_capacity(len.get());
                     for group in 1..=len.get() {
-                        let capture = captures.at(group);
-                        groups.push(capture);
-                        let capture = interp.try_convert_mut(capture)?;
-                        let group = unsafe { NonZeroUsize::new_unchecked(group) };
-                        interp.set_global_variable(regexp::nth_match_group(group), &capture)?;
+                        groups.push(captures.at(group));
                     }
 
                     let matched = interp.try_convert_mut(groups)?;
@@ -428,14 +366,16 @@ impl RegexpType for Onig {
                         matchdata.set_region(pos.0..pos.1);
                     }
                     let data = MatchData::alloc_value(matchdata.clone(), interp)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    interp.set_last_match(Some(data))?;
                     block.yield_arg(interp, &matched)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    // The block may have executed matches of its own, so
+                    // restore `$~` to this scan's position.
+                    interp.set_last_match(Some(data))?;
                 }
             } else {
                 let mut iter = self.regex.find_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Haystack);
                 }
                 for pos in iter {
@@ -443,21 +383,21 @@ impl RegexpType for Onig {
                     let matched = interp.try_convert_mut(scanned)?;
                     matchdata.set_region(pos.0..pos.1);
                     let data = MatchData::alloc_value(matchdata.clone(), interp)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    interp.set_last_match(Some(data))?;
                     block.yield_arg(interp, &matched)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    // The block may have executed matches of its own, so
+                    // restore `$~` to this scan's position.
+                    interp.set_last_match(Some(data))?;
                 }
             }
             Ok(Scan::Haystack)
         } else {
             let mut last_pos = (0, 0);
             if let Some(len) = len {
-                interp.set_active_regexp_globals(len.get())?;
-
                 let mut collected = vec![];
                 let mut iter = self.regex.captures_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Collected(Vec::new()));
                 }
                 for captures in iter {
@@ -473,24 +413,13 @@ impl RegexpType for Onig {
                 }
                 matchdata.set_region(last_pos.0..last_pos.1);
                 let data = MatchData::alloc_value(matchdata, interp)?;
-                interp.set_global_variable(regexp::LAST_MATCH, &data)?;
-
-                let mut iter = collected.iter().enumerate();
-                if let Some((_, fullcapture)) = iter.next() {
-                    let fullcapture = interp.try_convert_mut(fullcapture.as_slice())?;
-                    interp.set_global_variable(regexp::LAST_MATCHED_STRING, &fullcapture)?;
-                }
-                for (group, capture) in iter {
-                    let capture = interp.try_convert_mut(capture.as_slice())?;
-                    let group = unsafe { NonZeroUsize::new_unchecked(group) };
-                    interp.set_global_variable(regexp::nth_match_group(group), &capture)?;
-                }
+                interp.set_last_match(Some(data))?;
                 Ok(Scan::Collected(collected))
             } else {
                 let mut collected = vec![];
                 let mut iter = self.regex.find_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Patterns(Vec::new()));
                 }
                 for pos in iter {
@@ -500,11 +429,7 @@ impl RegexpType for Onig {
                 }
                 matchdata.set_region(last_pos.0..last_pos.1);
                 let data = MatchData::alloc_value(matchdata, interp)?;
-                interp.set_global_variable(regexp::LAST_MATCH, &data)?;
-
-                let last_matched = collected.last().map(Vec::as_slice);
-                let last_matched = interp.try_convert_mut(last_matched)?;
-                interp.set_global_variable(regexp::LAST_MATCHED_STRING, &last_matched)?;
+                interp.set_last_match(Some(data))?;
                 Ok(Scan::Patterns(collected))
             }
         }
@@ -7,7 +7,7 @@ use regex::{Match, Regex, RegexBuilder};
 
 use super::super::{NameToCaptureLocations, NilableString};
 use crate::extn::core::matchdata::MatchData;
-use crate::extn::core::regexp::{self, Config, Encoding, Regexp, RegexpType, Scan, Source};
+use crate::extn::core::regexp::{Config, Encoding, Regexp, RegexpType, Scan, Source};
 use crate::extn::prelude::*;
 
 #[derive(Debug, Clone)]
@@ -172,39 +172,16 @@ impl RegexpType for Utf8 {
         let haystack = str::from_utf8(haystack).map_err(|_| {
             ArgumentError::with_message("regex crate utf8 backend for Regexp only supports UTF-8 haystack")
         })?;
-        regexp::clear_capture_globals(interp)?;
         if let Some(captures) = self.regex.captures(haystack) {
-            // per the [docs] for `captures.len()`:
-            //
-            // > This is always at least 1, since every regex has at least one
-            // > capture group that corresponds to the full match.
-            //
-            // [docs]: https://docs.rs/regex/1.3.4/regex/struct.Captures.html#method.len
-            interp.set_active_regexp_globals(captures.len().checked_sub(1).unwrap_or_default())?;
-
-            let fullmatch = captures.get(0).as_ref().map(Match::as_str).map(str::as_bytes);
-            let value = interp.try_convert_mut(fullmatch)?;
-            interp.set_global_variable(regexp::LAST_MATCHED_STRING, &value)?;
-            for group in 1..captures.len() {
-                let capture = captures.get(group).as_ref().map(Match::as_str).map(str::as_bytes);
-                let value = interp.try_convert_mut(capture)?;
-                let group = unsafe { NonZeroUsize::new_unchecked(group) };
-                interp.set_global_variable(regexp::nth_match_group(group), &value)?;
-            }
-
+            let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
             if let Some(match_pos) = captures.get(0) {
-                let pre_match = interp.try_convert_mut(&haystack[..match_pos.start()])?;
-                let post_match = interp.try_convert_mut(&haystack[match_pos.end()..])?;
-                interp.set_global_variable(regexp::STRING_LEFT_OF_MATCH, &pre_match)?;
-                interp.set_global_variable(regexp::STRING_RIGHT_OF_MATCH, &post_match)?;
+                matchdata.set_region(match_pos.start()..match_pos.end());
             }
-            let matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
-            let matchdata = MatchData::alloc_value(matchdata, interp)?;
-            interp.set_global_variable(regexp::LAST_MATCH, &matchdata)?;
+            let data = MatchData::alloc_value(matchdata, interp)?;
+            interp.set_last_match(Some(data))?;
             Ok(true)
         } else {
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             Ok(false)
         }
     }
@@ -246,7 +223,6 @@ impl RegexpType for Utf8 {
         let haystack = str::from_utf8(haystack).map_err(|_| {
             ArgumentError::with_message("regex crate utf8 backend for Regexp only supports UTF-8 haystacks")
         })?;
-        regexp::clear_capture_globals(interp)?;
         let haystack_char_len = haystack.chars().count();
         let pos = pos.unwrap_or_default();
         let pos = if let Ok(pos) = usize::try_from(pos) {
@@ -266,40 +242,16 @@ impl RegexpType for Utf8 {
         let target = if let Some(haystack) = haystack.get(offset..) {
             haystack
         } else {
-            interp.unset_global_variable(regexp::LAST_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             return Ok(Value::nil());
         };
         if let Some(captures) = self.regex.captures(target) {
-            // per the [docs] for `captures.len()`:
-            //
-            // > This is always at least 1, since every regex has at least one
-            // > capture group that corresponds to the full match.
-            //
-            // [docs]: https://docs.rs/regex/1.3.4/regex/struct.Captures.html#method.len
-            interp.set_active_regexp_globals(captures.len().checked_sub(1).unwrap_or_default())?;
-
-            let fullmatch = captures.get(0).as_ref().map(Match::as_str).map(str::as_bytes);
-            let value = interp.try_convert_mut(fullmatch)?;
-            interp.set_global_variable(regexp::LAST_MATCHED_STRING, &value)?;
-            for group in 1..captures.len() {
-                let capture = captures.get(group).as_ref().map(Match::as_str).map(str::as_bytes);
-                let value = interp.try_convert_mut(capture)?;
-                let group = unsafe { NonZeroUsize::new_unchecked(group) };
-                interp.set_global_variable(regexp::nth_match_group(group), &value)?;
-            }
-
             let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
             if let Some(match_pos) = captures.get(0) {
-                let pre_match = interp.try_convert_mut(&target[..match_pos.start()])?;
-                let post_match = interp.try_convert_mut(&target[match_pos.end()..])?;
-                interp.set_global_variable(regexp::STRING_LEFT_OF_MATCH, &pre_match)?;
-                interp.set_global_variable(regexp::STRING_RIGHT_OF_MATCH, &post_match)?;
                 matchdata.set_region(offset + match_pos.start()..offset + match_pos.end());
             }
             let data = MatchData::alloc_value(matchdata, interp)?;
-            interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+            interp.set_last_match(Some(data))?;
             if let Some(block) = block {
                 let result = block.yield_arg(interp, &data)?;
                 Ok(result)
@@ -307,9 +259,7 @@ impl RegexpType for Utf8 {
                 Ok(data)
             }
         } else {
-            interp.unset_global_variable(regexp::LAST_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             Ok(Value::nil())
         }
     }
@@ -318,43 +268,17 @@ impl RegexpType for Utf8 {
         let haystack = str::from_utf8(haystack).map_err(|_| {
             ArgumentError::with_message("regex crate utf8 backend for Regexp only supports UTF-8 haystacks")
         })?;
-        regexp::clear_capture_globals(interp)?;
         if let Some(captures) = self.regex.captures(haystack) {
-            // per the [docs] for `captures.len()`:
-            //
-            // > This is always at least 1, since every regex has at least one
-            // > capture group that corresponds to the full match.
-            //
-            // [docs]: https://docs.rs/regex/1.3.4/regex/struct.Captures.html#method.len
-            interp.set_active_regexp_globals(captures.len().checked_sub(1).unwrap_or_default())?;
-
-            let fullmatch = captures.get(0).as_ref().map(Match::as_str).map(str::as_bytes);
-            let value = interp.try_convert_mut(fullmatch)?;
-            interp.set_global_variable(regexp::LAST_MATCHED_STRING, &value)?;
-            for group in 1..captures.len() {
-                let capture = captures.get(group).as_ref().map(Match::as_str).map(str::as_bytes);
-                let value = interp.try_convert_mut(capture)?;
-                let group = unsafe { NonZeroUsize::new_unchecked(group) };
-                interp.set_global_variable(regexp::nth_match_group(group), &value)?;
-            }
-
-            let matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
+            let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
+            let pos = captures.get(0).map(|match_pos| {
+                matchdata.set_region(match_pos.start()..match_pos.end());
+                match_pos.start()
+            });
             let data = MatchData::alloc_value(matchdata, interp)?;
-            interp.set_global_variable(regexp::LAST_MATCH, &data)?;
-            if let Some(match_pos) = captures.get(0) {
-                let pre_match = interp.try_convert_mut(&haystack[..match_pos.start()])?;
-                let post_match = interp.try_convert_mut(&haystack[match_pos.end()..])?;
-                interp.set_global_variable(regexp::STRING_LEFT_OF_MATCH, &pre_match)?;
-                interp.set_global_variable(regexp::STRING_RIGHT_OF_MATCH, &post_match)?;
-                let pos = match_pos.start();
-                Ok(Some(pos))
-            } else {
-                Ok(Some(0))
-            }
+            interp.set_last_match(Some(data))?;
+            Ok(Some(pos.unwrap_or_default()))
         } else {
-            interp.unset_global_variable(regexp::LAST_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_LEFT_OF_MATCH)?;
-            interp.unset_global_variable(regexp::STRING_RIGHT_OF_MATCH)?;
+            interp.set_last_match(None)?;
             Ok(None)
         }
     }
@@ -427,31 +351,22 @@ impl RegexpType for Utf8 {
         let haystack = str::from_utf8(haystack).map_err(|_| {
             ArgumentError::with_message("regex crate utf8 backend for Regexp only supports UTF-8 haystacks")
         })?;
-        regexp::clear_capture_globals(interp)?;
         let mut matchdata = MatchData::new(haystack.into(), Regexp::from(self.box_clone()), ..);
 
         // regex crate always includes the zero group in the captures length.
         let len = self.regex.captures_len().checked_sub(1);
-        interp.set_active_regexp_globals(len.unwrap_or_default())?;
         let len = len.and_then(NonZeroUsize::new);
         if let Some(block) = block {
             if let Some(len) = len {
                 let mut iter = self.regex.captures_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Haystack);
                 }
                 for captures in iter {
-                    let matched = captures.get(0).as_ref().map(Match::as_str).map(str::as_bytes);
-                    let capture = interp.try_convert_mut(matched)?;
-                    interp.set_global_variable(regexp::LAST_MATCHED_STRING, &capture)?;
-
                     let mut groups = Vec::with_capacity(len.get() - 1);
                     for group in 1..=len.get() {
                         let matched = captures.get(group).as_ref().map(Match::as_str).map(str::as_bytes);
-                        let capture = interp.try_convert_mut(matched)?;
-                        let group = unsafe { NonZeroUsize::new_unchecked(group) };
-                        interp.set_global_variable(regexp::nth_match_group(group), &capture)?;
                         groups.push(matched);
                     }
 
@@ -460,14 +375,16 @@ impl RegexpType for Utf8 {
                         matchdata.set_region(pos.start()..pos.end());
                     }
                     let data = MatchData::alloc_value(matchdata.clone(), interp)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    interp.set_last_match(Some(data))?;
                     block.yield_arg(interp, &matched)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    // The block may have executed matches of its own, so
+                    // restore `$~` to this scan's position.
+                    interp.set_last_match(Some(data))?;
                 }
             } else {
                 let mut iter = self.regex.find_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Haystack);
                 }
                 for pos in iter {
@@ -475,9 +392,11 @@ impl RegexpType for Utf8 {
                     let matched = interp.try_convert_mut(scanned)?;
                     matchdata.set_region(pos.start()..pos.end());
                     let data = MatchData::alloc_value(matchdata.clone(), interp)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    interp.set_last_match(Some(data))?;
                     block.yield_arg(interp, &matched)?;
-                    interp.set_global_variable(regexp::LAST_MATCH, &data)?;
+                    // The block may have executed matches of its own, so
+                    // restore `$~` to this scan's position.
+                    interp.set_last_match(Some(data))?;
                 }
             }
             Ok(Scan::Haystack)
@@ -487,7 +406,7 @@ impl RegexpType for Utf8 {
                 let mut collected = vec![];
                 let mut iter = self.regex.captures_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Collected(Vec::new()));
                 }
                 for captures in iter {
@@ -509,23 +428,13 @@ impl RegexpType for Utf8 {
                 }
                 matchdata.set_region(last_pos.0..last_pos.1);
                 let data = MatchData::alloc_value(matchdata, interp)?;
-                interp.set_global_variable(regexp::LAST_MATCH, &data)?;
-                let mut iter = collected.iter().enumerate();
-                if let Some((_, fullcapture)) = iter.next() {
-                    let fullcapture = interp.try_convert_mut(fullcapture.as_slice())?;
-                    interp.set_global_variable(regexp::LAST_MATCHED_STRING, &fullcapture)?;
-                }
-                for (group, capture) in iter {
-                    let capture = interp.try_convert_mut(capture.as_slice())?;
-                    let group = unsafe { NonZeroUsize::new_unchecked(group) };
-                    interp.set_global_variable(regexp::nth_match_group(group), &capture)?;
-                }
+                interp.set_last_match(Some(data))?;
                 Ok(Scan::Collected(collected))
             } else {
                 let mut collected = vec![];
                 let mut iter = self.regex.find_iter(haystack).peekable();
                 if iter.peek().is_none() {
-                    interp.unset_global_variable(regexp::LAST_MATCH)?;
+                    interp.set_last_match(None)?;
                     return Ok(Scan::Patterns(Vec::new()));
                 }
                 for pos in iter {
@@ -535,10 +444,7 @@ impl RegexpType for Utf8 {
                 }
                 matchdata.set_region(last_pos.0..last_pos.1);
                 let data = MatchData::alloc_value(matchdata, interp)?;
-                interp.set_global_variable(regexp::LAST_MATCH, &data)?;
-                let last_matched = collected.last().map(Vec::as_slice);
-                let last_matched = interp.try_convert_mut(last_matched)?;
-                interp.set_global_variable(regexp::LAST_MATCHED_STRING, &last_matched)?;
+                interp.set_last_match(Some(data))?;
                 Ok(Scan::Patterns(collected))
             }
         }
@@ -20,6 +20,7 @@ pub use spinoso_regexp::{
 
 use crate::convert::implicitly_convert_to_string;
 use crate::extn::core::array::Array;
+use crate::extn::core::matchdata::MatchData;
 use crate::extn::core::symbol::Symbol;
 use crate::extn::prelude::*;
 
@@ -50,6 +51,64 @@ pub fn clear_capture_globals(interp: &mut Artichoke) -> Result<(), Error> {
     Ok(())
 }
 
+impl Artichoke {
+    /// Set `$~` and the `Regexp` special globals derived from it.
+    ///
+    /// `$~` holds the `MatchData` for the most recent match. `$&`, `` $` ``,
+    /// `$'`, and `$1` through `$9` are all views into `$~`, so every Rust
+    /// entry point which executes a `Regexp` match must route its result
+    /// through this single facility: `Some(matchdata)` after a successful
+    /// match and `None` after a failed one. The derived globals are computed
+    /// from the boxed [`MatchData`] so they can never disagree with `$~`.
+    ///
+    /// mruby compiles back references and nth references to plain global
+    /// variable reads, so unlike MRI these globals are not scoped to the
+    /// calling frame: a match executed inside a method is visible to its
+    /// caller.
+    pub fn set_last_match(&mut self, matchdata: Option<Value>) -> Result<(), Error> {
+        clear_capture_globals(self)?;
+        let mut data = if let Some(data) = matchdata {
+            data
+        } else {
+            self.unset_global_variable(LAST_MATCH)?;
+            self.unset_global_variable(LAST_MATCHED_STRING)?;
+            self.unset_global_variable(STRING_LEFT_OF_MATCH)?;
+            self.unset_global_variable(STRING_RIGHT_OF_MATCH)?;
+            return Ok(());
+        };
+        // Safety:
+        //
+        // The derived views are copied to owned buffers before any additional
+        // operations are run on the interpreter which might trigger a garbage
+        // collection of `data` and its backing `MatchData`.
+        let (matched, pre, post, captures) = {
+            let matchdata = unsafe { MatchData::unbox_from_value(&mut data, self)? };
+            let matched = matchdata.to_s()?.map(<[u8]>::to_vec);
+            let pre = matchdata.pre().to_vec();
+            let post = matchdata.post().to_vec();
+            let captures = matchdata.captures()?.unwrap_or_default();
+            (matched, pre, post, captures)
+        };
+        self.set_active_regexp_globals(captures.len())?;
+        let matched = self.try_convert_mut(matched)?;
+        self.set_global_variable(LAST_MATCHED_STRING, &matched)?;
+        let pre = self.try_convert_mut(pre)?;
+        self.set_global_variable(STRING_LEFT_OF_MATCH, &pre)?;
+        let post = self.try_convert_mut(post)?;
+        self.set_global_variable(STRING_RIGHT_OF_MATCH, &post)?;
+        for (group, capture) in captures.into_iter().enumerate() {
+            // `MatchData::captures` drops the implicit full-match group, so
+            // the first entry corresponds to `$1`.
+            if let Some(group) = NonZeroUsize::new(group + 1) {
+                let capture = self.try_convert_mut(capture)?;
+                self.set_global_variable(nth_match_group(group), &capture)?;
+            }
+        }
+        self.set_global_variable(LAST_MATCH, &data)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Regexp(Box<dyn RegexpType>);
 
@@ -204,7 +263,7 @@ impl Regexp {
             pattern_vec = pattern.to_vec();
             pattern_vec.as_slice()
         } else {
-            interp.unset_global_variable(LAST_MATCH)?;
+            interp.set_last_match(None)?;
             return Ok(false);
         };
         self.0.case_match(interp, pattern)
@@ -265,7 +324,7 @@ impl Regexp {
         if let Some(pattern) = pattern {
             self.0.match_(interp, pattern, pos, block)
         } else {
-            interp.unset_global_variable(LAST_MATCH)?;
+            interp.set_last_match(None)?;
             Ok(Value::nil())
         }
     }
@@ -364,3 +423,69 @@ impl TryConvertMut<(Option<Value>, Option<Value>), (Option<Options>, Option<Enco
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn match_operator_sets_last_match_and_capture_globals() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"'abc' =~ /b(c)/").unwrap();
+        let nth = interp
+            .eval(b"$1 == 'c'")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(nth);
+        let derived = interp
+            .eval(b"$~.is_a?(MatchData) && $& == 'bc' && $` == 'a' && $' == ''")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(derived);
+    }
+
+    #[test]
+    fn failed_match_clears_last_match_and_capture_globals() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"'abc' =~ /b(c)/").unwrap();
+        interp.eval(b"'abc' =~ /xyz/").unwrap();
+        let cleared = interp
+            .eval(b"$~.nil? && $1.nil? && $&.nil? && $`.nil? && $'.nil?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(cleared);
+    }
+
+    #[test]
+    fn last_match_is_derived_from_matchdata() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"/e(l+)(o)/.match('hello')").unwrap();
+        let derived = interp
+            .eval(b"$~[0] == 'ello' && $1 == 'll' && $2 == 'o' && $& == 'ello' && $` == 'h' && $' == ''")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(derived);
+    }
+
+    // In MRI, `$~` is scoped to the frame which ran the match, so a match
+    // inside a called method does not clobber the caller's `$~`. mruby
+    // compiles back references to plain global variable reads, so Artichoke's
+    // Regexp globals are process-global. This test documents the divergence.
+    #[test]
+    fn last_match_is_process_global_unlike_mri() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(b"def clobber; 'xyz' =~ /(y)/; end; 'abc' =~ /b(c)/; clobber")
+            .unwrap();
+        let clobbered = interp
+            .eval(b"$~[0] == 'y' && $1 == 'y'")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(clobbered);
+    }
+}
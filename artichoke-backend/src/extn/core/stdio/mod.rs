@@ -0,0 +1,108 @@
+//! `IO`-like objects for the interpreter standard streams.
+//!
+//! This module implements the default objects behind the `$stdout` and
+//! `$stderr` globals and the `STDOUT` and `STDERR` constants. These objects
+//! delegate writes to the interpreter's pluggable output stream via the
+//! [`Io`] trait, so embedders that capture interpreter output with
+//! [`Artichoke::set_io`] also capture writes made through the globals:
+//!
+//! ```ruby
+//! $stdout.puts 'hello'
+//! $stderr.write 'oh no'
+//! ```
+//!
+//! Reassigning `$stdout` or `$stderr` redirects `Kernel#puts` and friends to
+//! the replacement object.
+//!
+//! [`Io`]: crate::core::Io
+//! [`Artichoke::set_io`]: crate::Artichoke::set_io
+
+pub mod mruby;
+pub mod trampoline;
+
+/// The Ruby class behind the default `$stdout` global.
+#[derive(Debug, Clone, Copy)]
+pub struct Stdout;
+
+/// The Ruby class behind the default `$stderr` global.
+#[derive(Debug, Clone, Copy)]
+pub struct Stderr;
+
+#[cfg(test)]
+mod tests {
+    use crate::state::{input, output};
+    use crate::test::prelude::*;
+
+    fn captured_interp() -> AutoDropArtichoke {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_io(input::Strategy::new(), output::Strategy::captured())
+            .unwrap();
+        interp
+    }
+
+    fn captured_stdout(interp: &Artichoke) -> Vec<u8> {
+        let state = interp.state.as_deref().unwrap();
+        state.output.as_captured().unwrap().stdout().to_vec()
+    }
+
+    fn captured_stderr(interp: &Artichoke) -> Vec<u8> {
+        let state = interp.state.as_deref().unwrap();
+        state.output.as_captured().unwrap().stderr().to_vec()
+    }
+
+    #[test]
+    fn stdout_global_writes_to_the_interpreter_output_stream() {
+        let mut interp = captured_interp();
+        interp.eval(b"$stdout.write('a', 1, :b)").unwrap();
+        assert_eq!(captured_stdout(&interp), b"a1b");
+    }
+
+    #[test]
+    fn stdout_write_returns_the_number_of_bytes_written() {
+        let mut interp = captured_interp();
+        let result = interp.eval(b"$stdout.write('artichoke')").unwrap();
+        let result = result.try_convert_into::<i64>(&interp).unwrap();
+        assert_eq!(result, 9);
+    }
+
+    #[test]
+    fn stdout_puts_appends_a_newline_unless_present() {
+        let mut interp = captured_interp();
+        interp.eval(b"$stdout.puts('a', \"b\\n\", ['c', 'd'])").unwrap();
+        assert_eq!(captured_stdout(&interp), b"a\nb\nc\nd\n");
+    }
+
+    #[test]
+    fn stderr_global_writes_to_the_interpreter_error_stream() {
+        let mut interp = captured_interp();
+        interp.eval(b"$stderr.print('oh no')").unwrap();
+        assert_eq!(captured_stderr(&interp), b"oh no");
+        assert_eq!(captured_stdout(&interp), b"");
+    }
+
+    #[test]
+    fn stdout_shovel_returns_self_for_chaining() {
+        let mut interp = captured_interp();
+        interp.eval(b"$stdout << 'a' << 'b'").unwrap();
+        assert_eq!(captured_stdout(&interp), b"ab");
+    }
+
+    #[test]
+    fn constants_are_aliases_for_the_globals() {
+        let mut interp = captured_interp();
+        let result = interp
+            .eval(b"STDOUT.equal?($stdout) && STDERR.equal?($stderr)")
+            .unwrap();
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn kernel_warn_writes_to_the_stderr_global() {
+        let mut interp = captured_interp();
+        interp.eval(b"warn 'oh no'").unwrap();
+        assert_eq!(captured_stderr(&interp), b"oh no\n");
+        assert_eq!(captured_stdout(&interp), b"");
+    }
+}
@@ -0,0 +1,78 @@
+//! FFI glue between the Rust trampolines and the mruby C interpreter.
+
+use std::ffi::CStr;
+
+use crate::extn::core::artichoke;
+use crate::extn::core::stdio::{self, trampoline};
+use crate::extn::prelude::*;
+
+const STDOUT_CSTR: &CStr = cstr::cstr!("Stdout");
+const STDERR_CSTR: &CStr = cstr::cstr!("Stderr");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<stdio::Stdout>() {
+        return Ok(());
+    }
+    let scope = interp
+        .module_spec::<artichoke::Artichoke>()?
+        .map(EnclosingRubyScope::module)
+        .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
+    let spec = class::Spec::new("Stdout", STDOUT_CSTR, Some(scope), None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_method("write", stdout_write, sys::mrb_args_rest())?
+        .define()?;
+    interp.def_class::<stdio::Stdout>(spec)?;
+
+    // `Artichoke::Stderr` inherits the `IO`-like Ruby methods from
+    // `Artichoke::Stdout` and overrides the native `write` primitive to route
+    // bytes to the stderr stream.
+    let scope = interp
+        .module_spec::<artichoke::Artichoke>()?
+        .map(EnclosingRubyScope::module)
+        .ok_or_else(|| NotDefinedError::module("Artichoke"))?;
+    let spec = class::Spec::new("Stderr", STDERR_CSTR, Some(scope), None)?;
+    class::Builder::for_spec(interp, &spec)
+        .with_super_class::<stdio::Stdout, _>("Artichoke::Stdout")?
+        .add_method("write", stderr_write, sys::mrb_args_rest())?
+        .define()?;
+    interp.def_class::<stdio::Stderr>(spec)?;
+
+    interp.eval(&include_bytes!("stdio.rb")[..])?;
+
+    let stdout = interp
+        .new_instance::<stdio::Stdout>(&[])?
+        .ok_or_else(|| NotDefinedError::class("Artichoke::Stdout"))?;
+    interp.set_global_variable(&b"$stdout"[..], &stdout)?;
+    interp.define_global_constant("STDOUT", stdout)?;
+
+    let stderr = interp
+        .new_instance::<stdio::Stderr>(&[])?
+        .ok_or_else(|| NotDefinedError::class("Artichoke::Stderr"))?;
+    interp.set_global_variable(&b"$stderr"[..], &stderr)?;
+    interp.define_global_constant("STDERR", stderr)?;
+
+    trace!("Patched standard stream IO objects onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn stdout_write(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    unwrap_interpreter!(mrb, to => guard);
+    let args = args.iter().copied().map(Value::from);
+    let result = trampoline::stdout_write(&mut guard, args);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn stderr_write(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    unwrap_interpreter!(mrb, to => guard);
+    let args = args.iter().copied().map(Value::from);
+    let result = trampoline::stderr_write(&mut guard, args);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
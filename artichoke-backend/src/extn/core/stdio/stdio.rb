@@ -0,0 +1,52 @@
+# frozen_string_literal: true
+
+module Artichoke
+  # An `IO`-like object that delegates writes to the interpreter output
+  # stream.
+  #
+  # The `write` primitive is implemented in native code. `Stderr` subclasses
+  # `Stdout` and only overrides `write` to target the stderr stream.
+  class Stdout
+    def <<(object)
+      write(object)
+      self
+    end
+
+    def flush
+      self
+    end
+
+    def print(*args)
+      write(*args)
+      nil
+    end
+
+    def puts(*args)
+      if args.empty?
+        write("\n")
+        return nil
+      end
+
+      args.each do |arg|
+        if arg.is_a?(Array)
+          arg.each { |item| puts(item) }
+        else
+          line = arg.to_s
+          line += "\n" unless line.end_with?("\n")
+          write(line)
+        end
+      end
+      nil
+    end
+
+    def sync
+      true
+    end
+
+    def sync=(mode)
+      mode
+    end
+  end
+
+  class Stderr < Stdout; end
+end
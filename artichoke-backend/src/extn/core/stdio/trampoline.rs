@@ -0,0 +1,27 @@
+use crate::extn::prelude::*;
+
+pub fn stdout_write<T>(interp: &mut Artichoke, args: T) -> Result<Value, Error>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let mut bytes_written = 0_usize;
+    for value in args {
+        let display = value.to_s(interp);
+        bytes_written += display.len();
+        interp.print(display)?;
+    }
+    interp.try_convert(bytes_written)
+}
+
+pub fn stderr_write<T>(interp: &mut Artichoke, args: T) -> Result<Value, Error>
+where
+    T: IntoIterator<Item = Value>,
+{
+    let mut bytes_written = 0_usize;
+    for value in args {
+        let display = value.to_s(interp);
+        bytes_written += display.len();
+        interp.print_err(display)?;
+    }
+    interp.try_convert(bytes_written)
+}
@@ -140,11 +140,37 @@ impl<'a> Deref for UnboxedValueGuard<'a, String> {
 
 #[cfg(test)]
 mod tests {
+    use bstr::ByteSlice;
+
     use crate::test::prelude::*;
 
     const SUBJECT: &str = "String";
     const FUNCTIONAL_TEST: &[u8] = include_bytes!("string_test.rb");
 
+    #[test]
+    fn dump_output_round_trips_through_eval() {
+        let fixtures: &[&[u8]] = &[
+            // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L147-L157
+            "Ω≈ç√∫˜µ≤≥÷".as_bytes(),
+            // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L202-L224
+            "表ポあA鷗ŒéＢ逍Üßªąñ丂㐀𠀀".as_bytes(),
+            b"#{}#$-#@-\\ '\" \x00\x1B\x7F",
+            b"invalid-\xFF-utf8",
+        ];
+        let mut interp = interpreter().unwrap();
+        for &fixture in fixtures {
+            let s = interp.try_convert_mut(fixture).unwrap();
+            interp.set_global_variable(&b"$fixture"[..], &s).unwrap();
+            let result = interp.eval(b"eval($fixture.dump) == $fixture").unwrap();
+            let round_trips = result.try_convert_into::<bool>(&interp).unwrap();
+            assert!(
+                round_trips,
+                "expected eval({:?}.dump) to round trip",
+                fixture.as_bstr()
+            );
+        }
+    }
+
     #[test]
     #[cfg(feature = "core-regexp")]
     fn functional() {
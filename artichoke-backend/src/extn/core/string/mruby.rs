@@ -2,6 +2,7 @@ use std::ffi::CStr;
 
 use crate::extn::core::string::{self, trampoline};
 use crate::extn::prelude::*;
+use crate::method::signature::Parameter;
 
 const STRING_CSTR: &CStr = cstr::cstr!("String");
 
@@ -28,6 +29,7 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("casecmp", string_casecmp_ascii, sys::mrb_args_req(1))?
         .add_method("casecmp?", string_casecmp_unicode, sys::mrb_args_req(1))?
         .add_method("center", string_center, sys::mrb_args_req_and_opt(1, 1))?
+        .add_method_signature("center", vec![Parameter::Req("width"), Parameter::Opt("padstr")])?
         .add_method("chars", string_chars, sys::mrb_args_none())? // This does not support the deprecated block form
         .add_method("chomp", string_chomp, sys::mrb_args_opt(1))?
         .add_method("chomp!", string_chomp_bang, sys::mrb_args_opt(1))?
@@ -39,6 +41,7 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("concat", string_concat, sys::mrb_args_any())?
         .add_method("downcase", string_downcase, sys::mrb_args_any())?
         .add_method("downcase!", string_downcase_bang, sys::mrb_args_any())?
+        .add_method("dump", string_dump, sys::mrb_args_none())?
         .add_method("empty?", string_empty, sys::mrb_args_none())?
         .add_method("eql?", string_eql, sys::mrb_args_req(1))?
         .add_method("getbyte", string_getbyte, sys::mrb_args_req(1))?
@@ -51,10 +54,12 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("intern", string_intern, sys::mrb_args_none())?
         .add_method("length", string_length, sys::mrb_args_none())?
         .add_method("ord", string_ord, sys::mrb_args_none())?
+        .add_method("partition", string_partition, sys::mrb_args_req(1))?
         .add_method("replace", string_replace, sys::mrb_args_req(1))?
         .add_method("reverse", string_reverse, sys::mrb_args_none())?
         .add_method("reverse!", string_reverse_bang, sys::mrb_args_none())?
         .add_method("rindex", string_rindex, sys::mrb_args_req_and_opt(1, 1))?
+        .add_method("rpartition", string_rpartition, sys::mrb_args_req(1))?
         .add_method("scan", string_scan, sys::mrb_args_req(1))?
         .add_method("setbyte", string_setbyte, sys::mrb_args_req(2))?
         .add_method("size", string_length, sys::mrb_args_none())?
@@ -67,6 +72,7 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("to_sym", string_intern, sys::mrb_args_none())?
         .add_method("upcase", string_upcase, sys::mrb_args_any())?
         .add_method("upcase!", string_upcase_bang, sys::mrb_args_any())?
+        .add_method("upto", string_upto, sys::mrb_args_req_and_opt(1, 1) | sys::mrb_args_block())?
         .add_method("valid_encoding?", string_valid_encoding, sys::mrb_args_none())?
         .define()?;
     interp.def_class::<string::String>(spec)?;
@@ -398,6 +404,17 @@ unsafe extern "C" fn string_downcase_bang(mrb: *mut sys::mrb_state, slf: sys::mr
     }
 }
 
+unsafe extern "C" fn string_dump(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::dump(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn string_empty(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
     mrb_get_args!(mrb, none);
     unwrap_interpreter!(mrb, to => guard);
@@ -537,6 +554,18 @@ unsafe extern "C" fn string_ord(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -
     }
 }
 
+unsafe extern "C" fn string_partition(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::partition(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn string_replace(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
     let other = mrb_get_args!(mrb, required = 1);
     unwrap_interpreter!(mrb, to => guard);
@@ -584,6 +613,18 @@ unsafe extern "C" fn string_rindex(mrb: *mut sys::mrb_state, slf: sys::mrb_value
     }
 }
 
+unsafe extern "C" fn string_rpartition(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::rpartition(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn string_scan(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
     let (pattern, block) = mrb_get_args!(mrb, required = 1, &block);
     unwrap_interpreter!(mrb, to => guard);
@@ -688,6 +729,19 @@ unsafe extern "C" fn string_upcase_bang(mrb: *mut sys::mrb_state, slf: sys::mrb_
     }
 }
 
+unsafe extern "C" fn string_upto(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let (max, exclusive, block) = mrb_get_args!(mrb, required = 1, optional = 1, &block);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let max = Value::from(max);
+    let exclusive = exclusive.map(Value::from);
+    let result = trampoline::upto(&mut guard, value, max, exclusive, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn string_valid_encoding(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
     mrb_get_args!(mrb, none);
     unwrap_interpreter!(mrb, to => guard);
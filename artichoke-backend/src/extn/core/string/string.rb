@@ -418,7 +418,7 @@ class String
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-each_byte
   def each_byte(&block)
-    return to_enum(:each_byte, &block) unless block
+    return to_enum(:each_byte) { bytesize } unless block
 
     bytes = self.bytes
     pos = 0
@@ -431,7 +431,7 @@ class String
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-each_char
   def each_char(&block)
-    return to_enum(:each_char, &block) unless block
+    return to_enum(:each_char) { length } unless block
 
     chars = self.chars
     pos = 0
@@ -444,7 +444,7 @@ class String
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-each_codepoint
   def each_codepoint
-    return to_enum(:each_codepoint) unless block_given?
+    return to_enum(:each_codepoint) { length } unless block_given?
 
     codepoints = self.codepoints
     pos = 0
@@ -752,12 +752,10 @@ class String
   # def ord; end
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-partition
-  def partition(pattern)
-    pattern = Regexp.compile(Regexp.escape(pattern)) if pattern.is_a?(String)
-
-    match = pattern.match(self)
-    [match.pre_match, match[0], match.post_match]
-  end
+  #
+  # NOTE: Implemented in native code.
+  #
+  # def partition(pattern); end
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-prepend
   def prepend(*args)
@@ -800,12 +798,10 @@ class String
   end
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-rpartition
-  def rpartition(pattern)
-    pattern = Regexp.compile(Regexp.escape(pattern)) if pattern.is_a?(String)
-
-    _ = pattern
-    raise NotImplementedError
-  end
+  #
+  # NOTE: Implemented in native code.
+  #
+  # def rpartition(pattern); end
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-rstrip
   def rstrip
@@ -1091,7 +1087,7 @@ class String
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-tr-21
   def tr!(from_str, to_str)
-    raise FrozenError if frozen?
+    raise FrozenError, "can't modify frozen String: #{inspect}" if frozen?
 
     replaced = tr(from_str, to_str)
     replace(replaced) unless self == replaced
@@ -1104,7 +1100,7 @@ class String
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-tr_s-21
   def tr_s!(from_str, to_str)
-    raise FrozenError if frozen?
+    raise FrozenError, "can't modify frozen String: #{inspect}" if frozen?
 
     replaced = tr_s(from_str, to_str)
     replace(replaced) unless self == replaced
@@ -1155,52 +1151,10 @@ class String
   # def upccase!; end
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-upto
-  def upto(max, exclusive = false, &block) # rubocop:disable Style/OptionalBooleanParameter
-    return to_enum(:upto, max, exclusive) unless block
-    raise TypeError, "no implicit conversion of #{max.class} into String" unless max.is_a?(String)
-
-    len = length
-    maxlen = max.length
-    # single character
-    if len == 1 && maxlen == 1
-      c = ord
-      e = max.ord
-      while c <= e
-        break if exclusive && c == e
-
-        yield c.chr
-        c += 1
-      end
-      return self
-    end
-    # both edges are all digits
-    bi = to_i(10)
-    ei = max.to_i(10)
-    if (bi.positive? || bi == '0' * len) && (ei.positive? || ei == '0' * maxlen)
-      while bi <= ei
-        break if exclusive && bi == ei
-
-        s = bi.to_s
-        s = s.rjust(len, '0') if s.length < len
-
-        yield s
-        bi += 1
-      end
-      return self
-    end
-    bs = self
-    loop do
-      n = (bs <=> max)
-      break if n.positive?
-      break if exclusive && n.zero?
-
-      yield bs
-      break if n.zero?
-
-      bs = bs.succ
-    end
-    self
-  end
+  #
+  # NOTE: Implemented in native code.
+  #
+  # def upto(max, exclusive = false, &block); end
 
   # https://ruby-doc.org/core-3.0.2/String.html#method-i-valid_encoding-3F
   #
@@ -5,9 +5,12 @@
 def spec
   string_match_operator
   string_element_reference_regexp
+  string_partition
+  string_rpartition
   string_scan
   string_unary_minus
   string_tr
+  string_upto
 
   true
 end
@@ -29,6 +32,20 @@ def string_element_reference_regexp
   raise unless 'hello there'[/(?<vowel>[aeiou])(?<non_vowel>[^aeiou])/, 'vowel'] == 'e'
 end
 
+def string_partition
+  raise unless 'hello'.partition('l') == %w[he l lo]
+  raise unless 'hello'.partition('x') == ['hello', '', '']
+  raise unless 'hello'.partition('') == ['', '', 'hello']
+  raise unless 'hello'.partition(/l+/) == %w[he ll o]
+  raise unless 'hello'.partition(/x/) == ['hello', '', '']
+end
+
+def string_rpartition
+  raise unless 'hello'.rpartition('l') == %w[hel l o]
+  raise unless 'hello'.rpartition('x') == ['', '', 'hello']
+  raise unless 'hello'.rpartition('') == ['hello', '', '']
+end
+
 def string_scan
   s = 'abababa'
   raise unless s.scan(/./) == %w[a b a b a b a]
@@ -47,4 +64,27 @@ def string_tr
   raise unless 'abcd'.tr('a-z', 'xxx') == 'xxxx'
 end
 
+def string_upto
+  raise unless 'a8'.upto('b1').to_a == %w[a8 a9 b0 b1]
+  raise unless 'a8'.upto('b1', true).to_a == %w[a8 a9 b0]
+  raise unless '9'.upto('11').to_a == %w[9 10 11]
+  raise unless '08'.upto('10').to_a == %w[08 09 10]
+  raise unless 'Y'.upto('b').to_a == ['Y', 'Z', '[', '\\', ']', '^', '_', '`', 'a', 'b']
+  raise unless 'az'.upto('ba').to_a == %w[az ba]
+  raise unless 'b'.upto('a').to_a == []
+
+  receiver = 'a8'
+  raise unless receiver.upto('a9') {}.equal?(receiver)
+
+  begin
+    'a'.upto(9)
+    raise 'expected TypeError'
+  rescue TypeError => e
+    raise unless e.message == 'no implicit conversion of Integer into String'
+  end
+
+  raise unless ('a'..'e').to_a == %w[a b c d e]
+  raise unless ('a'...'e').to_a == %w[a b c d]
+end
+
 spec if $PROGRAM_NAME == __FILE__
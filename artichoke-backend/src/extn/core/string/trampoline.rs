@@ -17,6 +17,18 @@ use crate::extn::core::symbol::Symbol;
 use crate::extn::prelude::*;
 use crate::sys::protect;
 
+// Mutating methods are implemented in Rust and do not go through the mruby
+// string modification routines, so the frozen check in `mrb_str_modify` is
+// reimplemented here with MRI's error message format.
+fn check_frozen(interp: &mut Artichoke, value: Value) -> Result<(), Error> {
+    if value.is_frozen(interp) {
+        let mut message = b"can't modify frozen String: ".to_vec();
+        message.extend_from_slice(&value.inspect(interp));
+        return Err(FrozenError::from(message).into());
+    }
+    Ok(())
+}
+
 pub fn mul(interp: &mut Artichoke, mut value: Value, count: Value) -> Result<Value, Error> {
     let count = implicitly_convert_to_int(interp, count)?;
     let count = usize::try_from(count).map_err(|_| ArgumentError::with_message("negative argument"))?;
@@ -46,6 +58,7 @@ pub fn add(interp: &mut Artichoke, mut value: Value, mut other: Value) -> Result
 }
 
 pub fn push(interp: &mut Artichoke, mut value: Value, mut other: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     // Safety:
     //
@@ -582,6 +595,7 @@ pub fn capitalize(interp: &mut Artichoke, mut value: Value) -> Result<Value, Err
 }
 
 pub fn capitalize_bang(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     // Safety:
     //
@@ -681,6 +695,7 @@ pub fn chomp(interp: &mut Artichoke, mut value: Value, separator: Option<Value>)
 }
 
 pub fn chomp_bang(interp: &mut Artichoke, mut value: Value, separator: Option<Value>) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     unsafe {
         let string_mut = s.as_inner_mut();
@@ -706,6 +721,7 @@ pub fn chop(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
 }
 
 pub fn chop_bang(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     if s.is_empty() {
         return Ok(Value::nil());
@@ -728,6 +744,7 @@ pub fn chr(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
 }
 
 pub fn clear(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     // Safety:
     //
@@ -765,6 +782,7 @@ pub fn downcase(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error
 }
 
 pub fn downcase_bang(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     // Safety:
     //
@@ -782,6 +800,12 @@ pub fn downcase_bang(interp: &mut Artichoke, mut value: Value) -> Result<Value,
     }
 }
 
+pub fn dump(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
+    let dump = s.dump().collect::<super::String>();
+    super::String::alloc_value(dump, interp)
+}
+
 pub fn is_empty(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
     let s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     Ok(interp.convert(s.is_empty()))
@@ -847,15 +871,7 @@ pub fn index(
     let needle = unsafe { implicitly_convert_to_string(interp, &mut needle)? };
     let index = if let Some(offset) = offset {
         let offset = implicitly_convert_to_int(interp, offset)?;
-        let offset = if let Ok(offset) = usize::try_from(offset) {
-            Some(offset)
-        } else {
-            offset
-                .checked_neg()
-                .and_then(|offset| usize::try_from(offset).ok())
-                .and_then(|offset| s.len().checked_sub(offset))
-        };
-        s.index(needle, offset)
+        s.index(needle, Some(offset))
     } else {
         s.index(needle, None)
     };
@@ -922,6 +938,7 @@ pub fn initialize(interp: &mut Artichoke, mut value: Value, from: Option<Value>)
 }
 
 pub fn initialize_copy(interp: &mut Artichoke, mut value: Value, mut other: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     // Safety:
     //
     // The extracted slice is immediately copied to an owned buffer.
@@ -973,6 +990,47 @@ pub fn ord(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
     Ok(interp.convert(ord))
 }
 
+fn partition_result(
+    interp: &mut Artichoke,
+    encoding: super::Encoding,
+    head: &[u8],
+    sep: &[u8],
+    tail: &[u8],
+) -> Result<Value, Error> {
+    let head = super::String::with_bytes_and_encoding(head.to_vec(), encoding);
+    let sep = super::String::with_bytes_and_encoding(sep.to_vec(), encoding);
+    let tail = super::String::with_bytes_and_encoding(tail.to_vec(), encoding);
+    let result = Array::from(vec![
+        super::String::alloc_value(head, interp)?,
+        super::String::alloc_value(sep, interp)?,
+        super::String::alloc_value(tail, interp)?,
+    ]);
+    Array::alloc_value(result, interp)
+}
+
+pub fn partition(interp: &mut Artichoke, mut value: Value, mut pattern: Value) -> Result<Value, Error> {
+    if let Ruby::Symbol = pattern.ruby_type() {
+        let mut message = String::from("wrong argument type ");
+        message.push_str(interp.inspect_type_name_for_value(pattern));
+        message.push_str(" (expected Regexp)");
+        return Err(TypeError::from(message).into());
+    }
+    let s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
+    #[cfg(feature = "core-regexp")]
+    if let Ok(regexp) = unsafe { Regexp::unbox_from_value(&mut pattern, interp) } {
+        let haystack = s.as_slice();
+        let (head, sep, tail) = if let Some((start, end)) = regexp.inner().pos(haystack, 0)? {
+            (&haystack[..start], &haystack[start..end], &haystack[end..])
+        } else {
+            (haystack, &[][..], &[][..])
+        };
+        return partition_result(interp, s.encoding(), head, sep, tail);
+    }
+    let pattern = unsafe { implicitly_convert_to_string(interp, &mut pattern)? };
+    let (head, sep, tail) = s.partition(pattern);
+    partition_result(interp, s.encoding(), head, sep, tail)
+}
+
 pub fn replace(interp: &mut Artichoke, value: Value, other: Value) -> Result<Value, Error> {
     initialize_copy(interp, value, other)
 }
@@ -985,6 +1043,7 @@ pub fn reverse(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error>
 }
 
 pub fn reverse_bang(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     // Safety:
     //
@@ -1014,15 +1073,7 @@ pub fn rindex(
     let needle = unsafe { implicitly_convert_to_string(interp, &mut needle)? };
     let index = if let Some(offset) = offset {
         let offset = implicitly_convert_to_int(interp, offset)?;
-        let offset = if let Ok(offset) = usize::try_from(offset) {
-            Some(offset)
-        } else {
-            offset
-                .checked_neg()
-                .and_then(|offset| usize::try_from(offset).ok())
-                .and_then(|offset| s.len().checked_sub(offset))
-        };
-        s.rindex(needle, offset)
+        s.rindex(needle, Some(offset))
     } else {
         s.rindex(needle, None)
     };
@@ -1030,6 +1081,23 @@ pub fn rindex(
     interp.try_convert(index)
 }
 
+pub fn rpartition(interp: &mut Artichoke, mut value: Value, mut pattern: Value) -> Result<Value, Error> {
+    if let Ruby::Symbol = pattern.ruby_type() {
+        let mut message = String::from("wrong argument type ");
+        message.push_str(interp.inspect_type_name_for_value(pattern));
+        message.push_str(" (expected Regexp)");
+        return Err(TypeError::from(message).into());
+    }
+    let s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
+    #[cfg(feature = "core-regexp")]
+    if let Ok(_pattern) = unsafe { Regexp::unbox_from_value(&mut pattern, interp) } {
+        return Err(NotImplementedError::from("String#rpartition with Regexp pattern").into());
+    }
+    let pattern = unsafe { implicitly_convert_to_string(interp, &mut pattern)? };
+    let (head, sep, tail) = s.rpartition(pattern);
+    partition_result(interp, s.encoding(), head, sep, tail)
+}
+
 pub fn scan(interp: &mut Artichoke, value: Value, mut pattern: Value, block: Option<Block>) -> Result<Value, Error> {
     if let Ruby::Symbol = pattern.ruby_type() {
         let mut message = String::from("wrong argument type ");
@@ -1148,6 +1216,7 @@ pub fn scan(interp: &mut Artichoke, value: Value, mut pattern: Value, block: Opt
 }
 
 pub fn setbyte(interp: &mut Artichoke, mut value: Value, index: Value, byte: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     let index = implicitly_convert_to_int(interp, index)?;
     let index = if let Ok(index) = usize::try_from(index) {
@@ -1299,6 +1368,7 @@ pub fn upcase(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error>
 }
 
 pub fn upcase_bang(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    check_frozen(interp, value)?;
     let mut s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     // Safety:
     //
@@ -1316,6 +1386,47 @@ pub fn upcase_bang(interp: &mut Artichoke, mut value: Value) -> Result<Value, Er
     }
 }
 
+pub fn upto(
+    interp: &mut Artichoke,
+    mut value: Value,
+    mut max: Value,
+    exclusive: Option<Value>,
+    block: Option<Block>,
+) -> Result<Value, Error> {
+    // any falsy value for `exclusive` yields `false`, otherwise `true`.
+    let exclusive = if let Some(exclusive) = exclusive {
+        if let Ok(exclusive) = TryConvert::<_, Option<bool>>::try_convert(interp, exclusive) {
+            exclusive.unwrap_or_default()
+        } else {
+            true
+        }
+    } else {
+        false
+    };
+    let block = if let Some(block) = block {
+        block
+    } else {
+        let method = interp.intern_bytes(&b"upto"[..])?;
+        let method = Symbol::alloc_value(method.into(), interp)?;
+        let exclusive = interp.convert(exclusive);
+        return value.funcall(interp, "to_enum", &[method, max, exclusive], None);
+    };
+    // Safety:
+    //
+    // Convert `max_bytes` to an owned byte vec to ensure the underlying
+    // `RString` is not garbage collected when yielding steps to the block.
+    let max_bytes = unsafe { implicitly_convert_to_string(interp, &mut max)? }.to_vec();
+    let begin = {
+        let s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
+        s.clone()
+    };
+    for step in begin.upto(&max_bytes, exclusive) {
+        let step = super::String::alloc_value(step, interp)?;
+        block.yield_arg(interp, &step)?;
+    }
+    Ok(value)
+}
+
 pub fn is_valid_encoding(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
     let s = unsafe { super::String::unbox_from_value(&mut value, interp)? };
     Ok(interp.convert(s.is_valid_encoding()))
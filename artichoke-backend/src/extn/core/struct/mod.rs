@@ -0,0 +1,205 @@
+//! The Ruby Struct core class.
+//!
+//! `Struct.new` generates new subclasses of `Struct`. The member list and
+//! constructor mode of each generated class are recorded in the interpreter
+//! [`State`] rather than in instance variables on the generated class, and
+//! member accessors and `#initialize` are defined on the generated class at
+//! class-generation time with closure-backed methods.
+//!
+//! [`State`]: crate::state::State
+
+use std::collections::HashMap;
+
+use crate::sys;
+
+pub mod mruby;
+pub mod trampoline;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Struct;
+
+/// Member list and constructor mode of a `Struct` subclass generated by
+/// `Struct.new`.
+#[derive(Debug, Clone)]
+pub struct Spec {
+    members: Vec<Vec<u8>>,
+    keyword_init: bool,
+}
+
+impl Spec {
+    /// Construct a new `Spec` from an ordered list of member names.
+    #[must_use]
+    pub fn new(members: Vec<Vec<u8>>, keyword_init: bool) -> Self {
+        Self { members, keyword_init }
+    }
+
+    /// The ordered member names of the generated class.
+    #[must_use]
+    pub fn members(&self) -> &[Vec<u8>] {
+        &self.members
+    }
+
+    /// Whether the generated class's constructor takes keyword arguments.
+    #[must_use]
+    pub fn is_keyword_init(&self) -> bool {
+        self.keyword_init
+    }
+}
+
+/// Key identifying a generated `Struct` subclass in the interpreter
+/// [`State`].
+///
+/// [`State`]: crate::state::State
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ClassKey(*const sys::RClass);
+
+/// Interpreter-level table of `Struct` subclasses generated by `Struct.new`,
+/// keyed by the generated class.
+///
+/// The table is stored in the interpreter [`State`], which is plain Rust
+/// memory owned by the interpreter, so member lists are invisible to Ruby
+/// code: they do not appear in the instance variables of the generated class.
+///
+/// [`State`]: crate::state::State
+#[derive(Debug, Default)]
+pub struct Registry(HashMap<ClassKey, Spec>);
+
+impl Registry {
+    /// Construct a new, empty `Registry`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the [`Spec`] for a generated `Struct` subclass.
+    pub fn insert(&mut self, class: *const sys::RClass, spec: Spec) {
+        self.0.insert(ClassKey(class), spec);
+    }
+
+    /// Look up the [`Spec`] for a generated `Struct` subclass.
+    #[must_use]
+    pub fn get(&self, class: *const sys::RClass) -> Option<&Spec> {
+        self.0.get(&ClassKey(class))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn accessors_read_and_write_members() {
+        let mut interp = interpreter().unwrap();
+        let values = interp
+            .eval(b"p = Struct.new(:x, :y).new(1, 2); p.x = 5; [p.x, p.y]")
+            .unwrap()
+            .try_convert_into::<Vec<i64>>(&interp)
+            .unwrap();
+        assert_eq!(values, vec![5, 2]);
+    }
+
+    #[test]
+    fn member_list_is_not_stored_in_instance_variables() {
+        let mut interp = interpreter().unwrap();
+        let hidden = interp
+            .eval(b"s = Struct.new(:a, :b); s.instance_variables.empty? && s.members == [:a, :b]")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(hidden);
+    }
+
+    #[test]
+    fn keyword_init_constructor_takes_keywords() {
+        let mut interp = interpreter().unwrap();
+        let values = interp
+            .eval(b"k = Struct.new(:a, :b, keyword_init: true); i = k.new(b: 2, a: 1); [i.a, i.b]")
+            .unwrap()
+            .try_convert_into::<Vec<i64>>(&interp)
+            .unwrap();
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn keyword_init_rejects_unknown_keywords() {
+        let mut interp = interpreter().unwrap();
+        let err = interp
+            .eval(b"Struct.new(:a, keyword_init: true).new(a: 1, b: 2, c: 3)")
+            .unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        assert_eq!(err.message().as_ref(), &b"unknown keywords: b, c"[..]);
+    }
+
+    #[test]
+    fn positional_constructor_rejects_excess_arguments() {
+        let mut interp = interpreter().unwrap();
+        let err = interp.eval(b"Struct.new(:a).new(1, 2)").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        assert_eq!(err.message().as_ref(), &b"struct size differs"[..]);
+    }
+
+    #[test]
+    fn named_struct_is_registered_under_struct_namespace() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"Struct.new('Point', :x, :y)").unwrap();
+        let members_match = interp
+            .eval(b"Struct::Point.members == [:x, :y] && Struct::Point.new(1, 2).x == 1")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(members_match);
+    }
+
+    #[test]
+    fn anonymous_struct_class_is_not_registered_as_a_constant() {
+        let mut interp = interpreter().unwrap();
+        let anonymous = interp
+            .eval(b"s = Struct.new(:a); s.instance_of?(Class) && Struct.constants.empty?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(anonymous);
+    }
+
+    #[test]
+    fn equality_compares_class_and_member_values() {
+        let mut interp = interpreter().unwrap();
+        let eq = interp
+            .eval(b"a = Struct.new(:x); b = Struct.new(:x); [a.new(1) == a.new(1), a.new(1) == b.new(1), a.new(1) == a.new(2)]")
+            .unwrap()
+            .try_convert_into::<Vec<bool>>(&interp)
+            .unwrap();
+        assert_eq!(eq, vec![true, false, false]);
+    }
+
+    #[test]
+    fn dig_delegates_through_members() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(b"k = Struct.new(:a); $i = k.new(k.new({ b: [1, 2, 3] }))")
+            .unwrap();
+        let nested = interp
+            .eval(b"$i.dig(:a, :a, :b, 0)")
+            .unwrap()
+            .try_convert_into::<i64>(&interp)
+            .unwrap();
+        assert_eq!(nested, 1);
+        let missing_member_is_nil = interp
+            .eval(b"$i.dig(:b, 0).nil?")
+            .unwrap()
+            .try_convert_into::<bool>(&interp)
+            .unwrap();
+        assert!(missing_member_is_nil);
+    }
+
+    #[test]
+    fn enumerable_is_included() {
+        let mut interp = interpreter().unwrap();
+        let doubled = interp
+            .eval(b"Struct.new(:a, :b).new(1, 2).map { |v| v * 2 }")
+            .unwrap()
+            .try_convert_into::<Vec<i64>>(&interp)
+            .unwrap();
+        assert_eq!(doubled, vec![2, 4]);
+    }
+}
@@ -0,0 +1,86 @@
+//! FFI glue between the Rust trampolines and the mruby C interpreter.
+
+use std::ffi::CStr;
+
+use crate::extn::core::r#struct::{trampoline, Struct};
+use crate::extn::prelude::*;
+
+const STRUCT_CSTR: &CStr = cstr::cstr!("Struct");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    if interp.is_class_defined::<Struct>() {
+        return Ok(());
+    }
+    let spec = class::Spec::new("Struct", STRUCT_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .add_self_method("new", struct_new, sys::mrb_args_any())?
+        .add_self_method("members", struct_members, sys::mrb_args_none())?
+        .add_method("members", struct_members, sys::mrb_args_none())?
+        .add_method("[]", struct_element_reference, sys::mrb_args_req(1))?
+        .add_method("[]=", struct_element_set, sys::mrb_args_req(2))?
+        .add_method("dig", struct_dig, sys::mrb_args_any())?
+        .define()?;
+    interp.def_class::<Struct>(spec)?;
+    interp.eval(&include_bytes!("struct.rb")[..])?;
+    trace!("Patched Struct onto interpreter");
+    Ok(())
+}
+
+unsafe extern "C" fn struct_new(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let (args, block) = mrb_get_args!(mrb, *args, &block);
+    unwrap_interpreter!(mrb, to => guard);
+    let args = args.iter().copied().map(Value::from).collect::<Vec<_>>();
+    let result = trampoline::new(&mut guard, Value::from(slf), &args, block);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn struct_members(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::members(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn struct_element_reference(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let key = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let key = Value::from(key);
+    let result = trampoline::element_reference(&mut guard, value, key);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn struct_element_set(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let (key, value) = mrb_get_args!(mrb, required = 2);
+    unwrap_interpreter!(mrb, to => guard);
+    let slf = Value::from(slf);
+    let key = Value::from(key);
+    let value = Value::from(value);
+    let result = trampoline::element_set(&mut guard, slf, key, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn struct_dig(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let args = args.iter().copied().map(Value::from).collect::<Vec<_>>();
+    let result = trampoline::dig(&mut guard, value, &args);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -0,0 +1,50 @@
+# frozen_string_literal: true
+
+class Struct
+  include Enumerable
+
+  def ==(other)
+    self.class == other.class && to_a == other.to_a
+  end
+
+  def each
+    return to_enum(:each) unless block_given?
+
+    members.each { |member| yield self[member] }
+    self
+  end
+
+  def each_pair
+    return to_enum(:each_pair) unless block_given?
+
+    members.each { |member| yield [member, self[member]] }
+    self
+  end
+
+  def to_a
+    members.map { |member| self[member] }
+  end
+  alias values to_a
+
+  def to_h
+    hash = {}
+    members.each { |member| hash[member] = self[member] }
+    hash
+  end
+
+  def size
+    members.size
+  end
+  alias length size
+
+  def values_at(*indexes)
+    to_a.values_at(*indexes)
+  end
+
+  def inspect
+    name = self.class.name
+    prefix = name ? "#<struct #{name} " : '#<struct '
+    "#{prefix}#{members.map { |member| "#{member}=#{self[member].inspect}" }.join(', ')}>"
+  end
+  alias to_s inspect
+end
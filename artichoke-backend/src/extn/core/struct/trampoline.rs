@@ -0,0 +1,416 @@
+use std::ffi::CString;
+use std::ptr::{self, NonNull};
+
+use crate::extn::core::symbol::Symbol;
+use crate::extn::prelude::*;
+use crate::method::closure::ClosureMethod;
+
+use super::{Spec, Struct};
+
+/// Dispatch target for `Struct.new` and `new` on generated subclasses.
+///
+/// `Struct.new` called on the `Struct` base class generates a new subclass.
+/// Generated subclasses inherit the `new` singleton method, for which this
+/// trampoline restores the default `Class#new` behavior of allocating an
+/// instance and calling `#initialize` on it.
+pub fn new(interp: &mut Artichoke, slf: Value, args: &[Value], block: Option<Block>) -> Result<Value, Error> {
+    let base = base_rclass(interp)?;
+    let slf_rclass = unsafe { sys::mrb_sys_class_ptr(slf.inner()) };
+    if ptr::eq(slf_rclass, base.as_ptr()) {
+        generate(interp, args, block)
+    } else {
+        let instance = slf.funcall(interp, "allocate", &[], None)?;
+        let block = block.map(|block| Value::from(block.inner()));
+        instance.funcall(interp, "initialize", args, block)?;
+        Ok(instance)
+    }
+}
+
+pub fn members(interp: &mut Artichoke, value: Value) -> Result<Value, Error> {
+    let spec = member_spec_for(interp, value)?.ok_or_else(|| TypeError::with_message("uninitialized struct"))?;
+    let mut symbols = Vec::with_capacity(spec.members().len());
+    for member in spec.members() {
+        symbols.push(symbol_for(interp, member)?);
+    }
+    interp.try_convert_mut(symbols)
+}
+
+pub fn element_reference(interp: &mut Artichoke, slf: Value, key: Value) -> Result<Value, Error> {
+    let spec = member_spec_for(interp, slf)?.ok_or_else(|| TypeError::with_message("uninitialized struct"))?;
+    if let Some(member) = member_for_key(interp, &spec, key)? {
+        read_member(interp, slf, &member)
+    } else {
+        Err(member_not_found(interp, &spec, key)?)
+    }
+}
+
+pub fn element_set(interp: &mut Artichoke, slf: Value, key: Value, value: Value) -> Result<Value, Error> {
+    if slf.is_frozen(interp) {
+        return Err(FrozenError::with_message("can't modify frozen Struct").into());
+    }
+    let spec = member_spec_for(interp, slf)?.ok_or_else(|| TypeError::with_message("uninitialized struct"))?;
+    if let Some(member) = member_for_key(interp, &spec, key)? {
+        write_member(interp, slf, &member, value)?;
+        Ok(value)
+    } else {
+        Err(member_not_found(interp, &spec, key)?)
+    }
+}
+
+pub fn dig(interp: &mut Artichoke, slf: Value, args: &[Value]) -> Result<Value, Error> {
+    let (&first, rest) = args
+        .split_first()
+        .ok_or_else(|| ArgumentError::with_message("wrong number of arguments (given 0, expected 1+)"))?;
+    let spec = member_spec_for(interp, slf)?.ok_or_else(|| TypeError::with_message("uninitialized struct"))?;
+    // `Struct#dig` does not raise for unknown members; a missing member
+    // terminates the traversal with `nil`.
+    let value = if let Some(member) = member_for_key(interp, &spec, first)? {
+        read_member(interp, slf, &member)?
+    } else {
+        Value::nil()
+    };
+    if rest.is_empty() || value.is_nil() {
+        return Ok(value);
+    }
+    let dig = symbol_for(interp, b"dig")?;
+    let responds = value
+        .funcall(interp, "respond_to?", &[dig], None)?
+        .try_convert_into::<bool>(interp)?;
+    if !responds {
+        let class = value.funcall(interp, "class", &[], None)?;
+        let name = class.funcall(interp, "to_s", &[], None)?;
+        let name = name.try_convert_into_mut::<Vec<u8>>(interp)?;
+        let message = format!("{} does not have #dig method", String::from_utf8_lossy(&name));
+        return Err(TypeError::from(message).into());
+    }
+    value.funcall(interp, "dig", rest, None)
+}
+
+/// Generate a new subclass of `Struct` from a member list.
+///
+/// The member list and constructor mode are recorded in the interpreter
+/// [`State`], and `#initialize` and member accessors are defined on the
+/// generated class with closure-backed methods which capture the member
+/// names.
+///
+/// [`State`]: crate::state::State
+fn generate(interp: &mut Artichoke, args: &[Value], block: Option<Block>) -> Result<Value, Error> {
+    let mut args = args.to_vec();
+    let keyword_init = parse_options(interp, &mut args)?;
+    let name = parse_name(interp, &mut args)?;
+    if name.is_none() && args.is_empty() {
+        return Err(ArgumentError::with_message("wrong number of arguments (given 0, expected 1+)").into());
+    }
+    let members = parse_members(interp, &args)?;
+
+    let base = base_rclass(interp)?;
+    let rclass = if let Some(ref name) = name {
+        let name_cstr = CString::new(name.clone()).map_err(|_| {
+            NameError::from(format!(
+                "identifier {} needs to be constant",
+                String::from_utf8_lossy(name)
+            ))
+        })?;
+        unsafe {
+            interp.with_ffi_boundary(|mrb| {
+                sys::mrb_define_class_under(mrb, base.as_ptr(), name_cstr.as_ptr(), base.as_ptr())
+            })?
+        }
+    } else {
+        unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_class_new(mrb, base.as_ptr()))? }
+    };
+    let rclass = NonNull::new(rclass).ok_or_else(|| NotDefinedError::class("Struct"))?;
+
+    let state = interp.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+    state
+        .struct_specs
+        .insert(rclass.as_ptr(), Spec::new(members.clone(), keyword_init));
+
+    define_initialize(interp, rclass, members.clone(), keyword_init)?;
+    for member in members {
+        define_accessors(interp, rclass, member)?;
+    }
+
+    let class = Value::from(unsafe { sys::mrb_sys_class_value(rclass.as_ptr()) });
+    if let Some(block) = block {
+        class.funcall(interp, "class_eval", &[], Some(Value::from(block.inner())))?;
+    }
+    Ok(class)
+}
+
+/// Extract a trailing options hash from the member list.
+///
+/// `keyword_init: true` switches the generated class's constructor from
+/// positional to keyword arguments.
+fn parse_options(interp: &mut Artichoke, args: &mut Vec<Value>) -> Result<bool, Error> {
+    let options = match args.last().copied() {
+        Some(last) if matches!(last.ruby_type(), Ruby::Hash) => last,
+        _ => return Ok(false),
+    };
+    args.pop();
+    let pairs: Vec<(Value, Value)> = interp.try_convert_mut(options)?;
+    let mut keyword_init = false;
+    for (key, value) in pairs {
+        let name = key.funcall(interp, "to_s", &[], None)?;
+        let name = name.try_convert_into_mut::<Vec<u8>>(interp)?;
+        if name == b"keyword_init" {
+            keyword_init = if value.is_nil() {
+                false
+            } else {
+                value.try_convert_into::<bool>(interp)?
+            };
+        } else {
+            let message = format!("unknown keyword: {}", String::from_utf8_lossy(&name));
+            return Err(ArgumentError::from(message).into());
+        }
+    }
+    Ok(keyword_init)
+}
+
+/// Extract a leading class name from the member list.
+///
+/// A leading `String` argument names the generated class, which is registered
+/// as a constant under `Struct`'s namespace.
+fn parse_name(interp: &mut Artichoke, args: &mut Vec<Value>) -> Result<Option<Vec<u8>>, Error> {
+    let first = match args.first().copied() {
+        Some(first) if matches!(first.ruby_type(), Ruby::String) => first,
+        _ => return Ok(None),
+    };
+    args.remove(0);
+    let name = first.try_convert_into_mut::<Vec<u8>>(interp)?;
+    if !name.first().copied().map_or(false, |byte| byte.is_ascii_uppercase()) {
+        let message = format!("identifier {} needs to be constant", String::from_utf8_lossy(&name));
+        return Err(NameError::from(message).into());
+    }
+    Ok(Some(name))
+}
+
+fn parse_members(interp: &mut Artichoke, args: &[Value]) -> Result<Vec<Vec<u8>>, Error> {
+    let mut members = Vec::with_capacity(args.len());
+    for &member in args {
+        if !matches!(member.ruby_type(), Ruby::Symbol | Ruby::String) {
+            let inspect = member.inspect(interp);
+            let message = format!("{} is not a symbol", String::from_utf8_lossy(&inspect));
+            return Err(TypeError::from(message).into());
+        }
+        let member = member.funcall(interp, "to_s", &[], None)?;
+        let name = member.try_convert_into_mut::<Vec<u8>>(interp)?;
+        if members.contains(&name) {
+            let message = format!("duplicate member: {}", String::from_utf8_lossy(&name));
+            return Err(ArgumentError::from(message).into());
+        }
+        members.push(name);
+    }
+    Ok(members)
+}
+
+fn define_initialize(
+    interp: &mut Artichoke,
+    mut rclass: NonNull<sys::RClass>,
+    members: Vec<Vec<u8>>,
+    keyword_init: bool,
+) -> Result<(), Error> {
+    let initialize = ClosureMethod::new(
+        "initialize",
+        Box::new(move |interp, slf, args| {
+            if keyword_init {
+                initialize_from_keywords(interp, slf, &members, args)
+            } else {
+                initialize_from_positional_args(interp, slf, &members, args)
+            }
+        }),
+        sys::mrb_args_any(),
+    )?;
+    unsafe { initialize.define(interp, rclass.as_mut()) }
+}
+
+fn initialize_from_positional_args(
+    interp: &mut Artichoke,
+    slf: Value,
+    members: &[Vec<u8>],
+    args: &[Value],
+) -> Result<Value, Error> {
+    if args.len() > members.len() {
+        return Err(ArgumentError::with_message("struct size differs").into());
+    }
+    for (member, value) in members.iter().zip(args.iter().copied()) {
+        write_member(interp, slf, member, value)?;
+    }
+    Ok(Value::nil())
+}
+
+fn initialize_from_keywords(
+    interp: &mut Artichoke,
+    slf: Value,
+    members: &[Vec<u8>],
+    args: &[Value],
+) -> Result<Value, Error> {
+    let keywords = match args {
+        [] => return Ok(Value::nil()),
+        [keywords] if matches!(keywords.ruby_type(), Ruby::Hash) => *keywords,
+        _ => {
+            let message = format!("wrong number of arguments (given {}, expected 0)", args.len());
+            return Err(ArgumentError::from(message).into());
+        }
+    };
+    let pairs: Vec<(Value, Value)> = interp.try_convert_mut(keywords)?;
+    let mut unknown = Vec::new();
+    for (key, value) in pairs {
+        let key = key.funcall(interp, "to_s", &[], None)?;
+        let name = key.try_convert_into_mut::<Vec<u8>>(interp)?;
+        if members.iter().any(|member| *member == name) {
+            write_member(interp, slf, &name, value)?;
+        } else {
+            unknown.push(String::from_utf8_lossy(&name).into_owned());
+        }
+    }
+    if !unknown.is_empty() {
+        let message = format!("unknown keywords: {}", unknown.join(", "));
+        return Err(ArgumentError::from(message).into());
+    }
+    Ok(Value::nil())
+}
+
+fn define_accessors(interp: &mut Artichoke, mut rclass: NonNull<sys::RClass>, member: Vec<u8>) -> Result<(), Error> {
+    let name = String::from_utf8_lossy(&member).into_owned();
+    let reader_member = member.clone();
+    let reader = ClosureMethod::new(
+        name.clone(),
+        Box::new(move |interp, slf, _args| read_member(interp, slf, &reader_member)),
+        sys::mrb_args_none(),
+    )?;
+    unsafe {
+        reader.define(interp, rclass.as_mut())?;
+    }
+
+    let mut writer_name = name;
+    writer_name.push('=');
+    let writer = ClosureMethod::new(
+        writer_name,
+        Box::new(move |interp, slf, args| {
+            if slf.is_frozen(interp) {
+                return Err(FrozenError::with_message("can't modify frozen Struct").into());
+            }
+            let value = args[0];
+            write_member(interp, slf, &member, value)?;
+            Ok(value)
+        }),
+        sys::mrb_args_req(1),
+    )?;
+    unsafe {
+        writer.define(interp, rclass.as_mut())?;
+    }
+    Ok(())
+}
+
+/// Look up the [`Spec`] for the receiver's class, walking the superclass
+/// chain so subclasses of generated classes resolve the inherited member
+/// list.
+fn member_spec_for(interp: &mut Artichoke, value: Value) -> Result<Option<Spec>, Error> {
+    let mut class = if matches!(value.ruby_type(), Ruby::Class) {
+        value
+    } else {
+        value.funcall(interp, "class", &[], None)?
+    };
+    while !class.is_nil() {
+        let rclass = unsafe { sys::mrb_sys_class_ptr(class.inner()) };
+        let state = interp.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        if let Some(spec) = state.struct_specs.get(rclass) {
+            return Ok(Some(spec.clone()));
+        }
+        class = class.funcall(interp, "superclass", &[], None)?;
+    }
+    Ok(None)
+}
+
+/// Resolve a `Symbol`, `String`, or `Integer` index to a member name.
+///
+/// Unknown members and out of range offsets resolve to `None`; the caller
+/// decides whether that is an error (`Struct#[]`) or a `nil` (`Struct#dig`).
+fn member_for_key(interp: &mut Artichoke, spec: &Spec, key: Value) -> Result<Option<Vec<u8>>, Error> {
+    match key.ruby_type() {
+        Ruby::Symbol | Ruby::String => {
+            let key = key.funcall(interp, "to_s", &[], None)?;
+            let name = key.try_convert_into_mut::<Vec<u8>>(interp)?;
+            Ok(spec.members().iter().find(|member| **member == name).cloned())
+        }
+        Ruby::Fixnum => {
+            let index = key.try_convert_into::<i64>(interp)?;
+            let len = i64::try_from(spec.members().len()).unwrap_or(i64::MAX);
+            let index = if index < 0 { index + len } else { index };
+            if index < 0 || index >= len {
+                return Ok(None);
+            }
+            let index = usize::try_from(index).unwrap_or_default();
+            Ok(spec.members().get(index).cloned())
+        }
+        _ => {
+            let inspect = key.inspect(interp);
+            let message = format!(
+                "no implicit conversion of {} into Integer",
+                String::from_utf8_lossy(&inspect)
+            );
+            Err(TypeError::from(message).into())
+        }
+    }
+}
+
+/// Build the exception for a member lookup miss in `Struct#[]` and
+/// `Struct#[]=`.
+fn member_not_found(interp: &mut Artichoke, spec: &Spec, key: Value) -> Result<Error, Error> {
+    if let Ruby::Fixnum = key.ruby_type() {
+        let index = key.try_convert_into::<i64>(interp)?;
+        let size = spec.members().len();
+        let message = if index < 0 {
+            format!("offset {} too small for struct(size:{})", index, size)
+        } else {
+            format!("offset {} too large for struct(size:{})", index, size)
+        };
+        Ok(IndexError::from(message).into())
+    } else {
+        let key = key.funcall(interp, "to_s", &[], None)?;
+        let name = key.try_convert_into_mut::<Vec<u8>>(interp)?;
+        let message = format!("no member '{}' in struct", String::from_utf8_lossy(&name));
+        Ok(NameError::from(message).into())
+    }
+}
+
+fn read_member(interp: &mut Artichoke, slf: Value, member: &[u8]) -> Result<Value, Error> {
+    let sym = interp.intern_bytes_with_trailing_nul(ivar_name(member))?;
+    let value = unsafe { interp.with_ffi_boundary(|mrb| sys::mrb_iv_get(mrb, slf.inner(), sym.into()))? };
+    Ok(Value::from(value))
+}
+
+fn write_member(interp: &mut Artichoke, slf: Value, member: &[u8], value: Value) -> Result<(), Error> {
+    let sym = interp.intern_bytes_with_trailing_nul(ivar_name(member))?;
+    unsafe {
+        interp.with_ffi_boundary(|mrb| sys::mrb_iv_set(mrb, slf.inner(), sym.into(), value.inner()))?;
+    }
+    Ok(())
+}
+
+/// Member values are stored in instance variables on the struct instance
+/// named after the member.
+fn ivar_name(member: &[u8]) -> Vec<u8> {
+    let mut ivar = Vec::with_capacity(member.len() + 2);
+    ivar.push(b'@');
+    ivar.extend_from_slice(member);
+    ivar.push(b'\0');
+    ivar
+}
+
+fn symbol_for(interp: &mut Artichoke, name: &[u8]) -> Result<Value, Error> {
+    let mut bytes = name.to_vec();
+    bytes.push(b'\0');
+    let sym = interp.intern_bytes_with_trailing_nul(bytes)?;
+    Symbol::alloc_value(sym.into(), interp)
+}
+
+fn base_rclass(interp: &mut Artichoke) -> Result<NonNull<sys::RClass>, Error> {
+    let rclass = interp
+        .class_spec::<Struct>()?
+        .map(class::Spec::rclass)
+        .ok_or_else(|| NotDefinedError::class("Struct"))?;
+    let rclass = unsafe { interp.with_ffi_boundary(|mrb| rclass.resolve(mrb))? };
+    rclass.ok_or_else(|| NotDefinedError::class("Struct").into())
+}
@@ -60,3 +60,41 @@ impl BoxUnboxVmValue for Symbol {
         let _ = data;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use crate::test::prelude::*;
+
+    const SUBJECT: &str = "Symbol";
+    const FUNCTIONAL_TEST: &[u8] = include_bytes!("symbol_functional_test.rb");
+
+    #[test]
+    fn functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+    }
+
+    // Benchmark for symbol proc dispatch. Run with:
+    //
+    // ```console
+    // $ cargo test -p artichoke-backend --release -- --ignored --nocapture symbol_proc_benchmark
+    // ```
+    #[test]
+    #[ignore]
+    fn symbol_proc_benchmark() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(&b"ARY = (1..10_000).to_a.freeze"[..]).unwrap();
+        let start = Instant::now();
+        interp.eval(&b"50.times { ARY.map(&:to_s) }"[..]).unwrap();
+        let symbol_proc = start.elapsed();
+        let start = Instant::now();
+        interp.eval(&b"50.times { ARY.map { |item| item.to_s } }"[..]).unwrap();
+        let block = start.elapsed();
+        println!("Array#map(&:to_s): {:?}, Array#map with block: {:?}", symbol_proc, block);
+    }
+}
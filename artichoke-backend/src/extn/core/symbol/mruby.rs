@@ -13,6 +13,7 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     class::Builder::for_spec(interp, &spec)
         .add_self_method("all_symbols", symbol_all_symbols, sys::mrb_args_none())?
         .add_method("==", symbol_equal_equal, sys::mrb_args_req(1))?
+        .add_method("__to_proc", symbol_to_proc, sys::mrb_args_none())?
         .add_method("casecmp", symbol_ascii_casecmp, sys::mrb_args_req(1))?
         .add_method("casecmp?", symbol_unicode_casecmp, sys::mrb_args_req(1))?
         .add_method("empty?", symbol_empty, sys::mrb_args_none())?
@@ -105,6 +106,17 @@ unsafe extern "C" fn symbol_length(mrb: *mut sys::mrb_state, slf: sys::mrb_value
     }
 }
 
+unsafe extern "C" fn symbol_to_proc(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let sym = Value::from(slf);
+    let result = trampoline::to_proc(&mut guard, sym);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
 unsafe extern "C" fn symbol_to_s(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
     mrb_get_args!(mrb, none);
     unwrap_interpreter!(mrb, to => guard);
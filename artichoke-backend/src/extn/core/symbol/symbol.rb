@@ -74,12 +74,10 @@ class Symbol
   end
 
   def to_proc
-    pr = lambda do |*args, &block|
-      raise ArgumentError, 'no receiver given' if args.empty?
-
-      obj, *rest = *args
-      obj.__send__(self, *rest, &block)
-    end
+    # `Symbol#__to_proc` is implemented in native code and returns a proc
+    # that carries this symbol in native data so Rust-backed block invocation
+    # can dispatch directly to the named method.
+    pr = __to_proc
 
     def pr.parameters
       [[:rest]]
@@ -87,6 +85,7 @@ class Symbol
 
     pr
   end
+  private :__to_proc
 
   # Implemented in native code.
   # def to_s; end
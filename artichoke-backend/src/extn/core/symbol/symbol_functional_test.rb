@@ -0,0 +1,56 @@
+# frozen_string_literal: true
+
+def spec
+  to_proc_returns_a_proc
+  to_proc_dispatches_to_named_method
+  to_proc_with_arguments
+  to_proc_with_block_argument
+  to_proc_without_receiver
+  to_proc_no_method_error
+  to_proc_parameters
+
+  true
+end
+
+def to_proc_returns_a_proc
+  pr = :to_s.to_proc
+  raise unless pr.is_a?(Proc)
+  raise if pr.lambda?
+end
+
+def to_proc_dispatches_to_named_method
+  raise unless %i[one two].map(&:to_s) == %w[one two]
+  raise unless [1, 2, 3].map(&:to_s) == %w[1 2 3]
+  raise unless [[1], [], [2]].reject(&:empty?) == [[1], [2]]
+  raise unless :upcase.to_proc.call('cat') == 'CAT'
+end
+
+def to_proc_with_arguments
+  raise unless :+.to_proc.call(1, 2) == 3
+  raise unless :[].to_proc.call([4, 5, 6], 1) == 5
+end
+
+def to_proc_with_block_argument
+  pr = :map.to_proc
+  raise unless pr.call([1, 2]) { |item| item * 10 } == [10, 20]
+end
+
+def to_proc_without_receiver
+  :to_s.to_proc.call
+  raise
+rescue ArgumentError => e
+  raise unless e.message == 'no receiver given'
+end
+
+def to_proc_no_method_error
+  :does_not_exist.to_proc.call(7)
+  raise
+rescue NoMethodError => e
+  raise unless e.message.include?('does_not_exist')
+end
+
+def to_proc_parameters
+  raise unless :to_s.to_proc.parameters == [[:rest]]
+end
+
+spec if $PROGRAM_NAME == __FILE__
@@ -1,8 +1,11 @@
 use spinoso_symbol::{CaseFold, InternerAllSymbols};
 
+use crate::exception_handler;
 use crate::extn::core::array::Array;
 use crate::extn::core::symbol::Symbol;
 use crate::extn::prelude::*;
+use crate::sys::protect;
+use crate::value::ArgCountError;
 
 pub fn all_symbols(interp: &mut Artichoke) -> Result<Value, Error> {
     let all_symbols = interp
@@ -67,3 +70,86 @@ pub fn inspect(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error>
     let debug = inspect.collect::<String>();
     interp.try_convert_mut(debug)
 }
+
+pub fn to_proc(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    // Ensure the receiver is a `Symbol` before capturing it in the proc.
+    let _ = unsafe { Symbol::unbox_from_value(&mut value, interp)? };
+    let env = [value.inner()];
+    // The symbol is stored in the proc's cfunc environment, which lets
+    // `Block::yield_arg` recognize symbol procs and dispatch directly to the
+    // named method without setting up a proc call frame.
+    let proc = unsafe {
+        interp.with_ffi_boundary(|mrb| {
+            let proc = sys::mrb_proc_new_cfunc_with_env(mrb, Some(symbol_proc_call), 1, env.as_ptr());
+            sys::mrb_sys_proc_value(mrb, proc)
+        })?
+    };
+    Ok(interp.protect(Value::from(proc)))
+}
+
+/// Proc body for the procs returned by [`to_proc`].
+///
+/// The first argument is the receiver; remaining arguments and the block are
+/// forwarded to the method named by the symbol in the proc's cfunc
+/// environment.
+pub(crate) unsafe extern "C" fn symbol_proc_call(mrb: *mut sys::mrb_state, _slf: sys::mrb_value) -> sys::mrb_value {
+    let (args, block) = mrb_get_args!(mrb, *args, &block);
+    let method = sys::mrb_proc_cfunc_env_get(mrb, 0).value.sym;
+    unwrap_interpreter!(mrb, to => guard);
+    let result = if let Some((&receiver, args)) = args.split_first() {
+        // The args slice points into the mruby VM stack, which may be
+        // reallocated by the method call below. Copy the args out first.
+        let args = args.to_vec();
+        symbol_proc_dispatch(&mut guard, method, Value::from(receiver), &args, block)
+    } else {
+        Err(ArgumentError::with_message("no receiver given").into())
+    };
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+/// Invoke `method` on `receiver`, skipping proc frame setup.
+///
+/// This is the fast path behind symbol procs. It mirrors `Value::funcall` but
+/// dispatches on an already interned method symbol.
+pub(crate) fn symbol_proc_dispatch(
+    interp: &mut Artichoke,
+    method: sys::mrb_sym,
+    receiver: Value,
+    args: &[sys::mrb_value],
+    block: Option<Block>,
+) -> Result<Value, Error> {
+    if receiver.is_dead(interp) {
+        return Err(Fatal::from("Value receiver for function call is dead. This indicates a bug in the mruby garbage collector. Please leave a comment at https://github.com/artichoke/artichoke/issues/1336.").into());
+    }
+    if let Ok(arg_count_error) = ArgCountError::try_from(args) {
+        warn!("{}", arg_count_error);
+        return Err(arg_count_error.into());
+    }
+    let result = unsafe {
+        interp.with_ffi_boundary(|mrb| {
+            protect::funcall(mrb, receiver.inner(), method, args, block.as_ref().map(Block::inner))
+        })?
+    };
+    match result {
+        Ok(value) => {
+            let value = Value::from(value);
+            if value.is_unreachable() {
+                // Unreachable values are internal to the mruby interpreter
+                // and interacting with them via the C API is unspecified
+                // and may result in a segfault.
+                //
+                // See: https://github.com/mruby/mruby/issues/4460
+                Err(Fatal::from("Unreachable Ruby value").into())
+            } else {
+                Ok(interp.protect(value))
+            }
+        }
+        Err(exception) => {
+            let exception = interp.protect(Value::from(exception));
+            Err(exception_handler::last_error(interp, exception)?)
+        }
+    }
+}
@@ -4,6 +4,10 @@ use crate::extn::prelude::*;
 
 const THREAD_CSTR: &CStr = cstr::cstr!("Thread");
 const MUTEX_CSTR: &CStr = cstr::cstr!("Mutex");
+const QUEUE_CSTR: &CStr = cstr::cstr!("Queue");
+const SIZED_QUEUE_CSTR: &CStr = cstr::cstr!("SizedQueue");
+const CONDITION_VARIABLE_CSTR: &CStr = cstr::cstr!("ConditionVariable");
+const CLOSED_QUEUE_ERROR_CSTR: &CStr = cstr::cstr!("ClosedQueueError");
 
 pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     if interp.is_class_defined::<Thread>() {
@@ -16,6 +20,22 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     interp.def_class::<Thread>(spec)?;
     let spec = class::Spec::new("Mutex", MUTEX_CSTR, None, None)?;
     interp.def_class::<Mutex>(spec)?;
+    let spec = class::Spec::new("Queue", QUEUE_CSTR, None, None)?;
+    interp.def_class::<Queue>(spec)?;
+    let spec = class::Spec::new("SizedQueue", SIZED_QUEUE_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .with_super_class::<Queue, _>("Queue")?
+        .define()?;
+    interp.def_class::<SizedQueue>(spec)?;
+    let spec = class::Spec::new("ConditionVariable", CONDITION_VARIABLE_CSTR, None, None)?;
+    interp.def_class::<ConditionVariable>(spec)?;
+    // `ClosedQueueError` subclasses `StopIteration` so `Queue#pop` consumers
+    // can treat a closed queue like an exhausted enumerator.
+    let spec = class::Spec::new("ClosedQueueError", CLOSED_QUEUE_ERROR_CSTR, None, None)?;
+    class::Builder::for_spec(interp, &spec)
+        .with_super_class::<StopIteration, _>("StopIteration")?
+        .define()?;
+    interp.def_class::<ClosedQueueError>(spec)?;
     // TODO: Don't add a source file and don't add an explicit require below.
     // Instead, have thread be a default loaded feature in `mezzaluna-feature-loader`.
     interp.def_rb_source_file("thread.rb", &include_bytes!("thread.rb")[..])?;
@@ -24,6 +44,10 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     interp.eval(&b"require 'thread'"[..])?;
     trace!("Patched Thread onto interpreter");
     trace!("Patched Mutex onto interpreter");
+    trace!("Patched Queue onto interpreter");
+    trace!("Patched SizedQueue onto interpreter");
+    trace!("Patched ConditionVariable onto interpreter");
+    trace!("Patched ClosedQueueError onto interpreter");
     Ok(())
 }
 
@@ -33,6 +57,18 @@ pub struct Thread;
 #[derive(Debug, Clone, Copy)]
 pub struct Mutex;
 
+#[derive(Debug, Clone, Copy)]
+pub struct Queue;
+
+#[derive(Debug, Clone, Copy)]
+pub struct SizedQueue;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionVariable;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClosedQueueError;
+
 #[cfg(test)]
 mod tests {
     use crate::test::prelude::*;
@@ -40,6 +76,9 @@ mod tests {
     const SUBJECT: &str = "Thread";
     const FUNCTIONAL_TEST: &[u8] = include_bytes!("thread_test.rb");
 
+    const QUEUE_SUBJECT: &str = "Queue";
+    const QUEUE_FUNCTIONAL_TEST: &[u8] = include_bytes!("queue_test.rb");
+
     #[test]
     fn functional() {
         let mut interp = interpreter().unwrap();
@@ -48,4 +87,13 @@ mod tests {
         let result = interp.eval(b"spec");
         unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
     }
+
+    #[test]
+    fn queue_functional() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(QUEUE_FUNCTIONAL_TEST);
+        unwrap_or_panic_with_backtrace(&mut interp, QUEUE_SUBJECT, result);
+        let result = interp.eval(b"spec");
+        unwrap_or_panic_with_backtrace(&mut interp, QUEUE_SUBJECT, result);
+    }
 }
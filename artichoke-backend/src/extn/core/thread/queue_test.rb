@@ -0,0 +1,134 @@
+# frozen_string_literal: true
+
+def spec
+  queue_constants_defined?
+  queue_push_pop_order
+  queue_non_block_pop
+  queue_close
+  queue_clear_and_size
+  sized_queue_max
+  sized_queue_full
+  closed_queue_error_hierarchy
+  condition_variable
+
+  true
+end
+
+def queue_constants_defined?
+  raise unless Object.const_defined?(:Queue)
+  raise unless Object.const_defined?(:SizedQueue)
+  raise unless Object.const_defined?(:ConditionVariable)
+  raise unless Object.const_defined?(:ClosedQueueError)
+end
+
+def queue_push_pop_order
+  q = Queue.new
+  raise unless q.push(1).equal?(q)
+
+  q << 2
+  q.enq(3)
+  raise unless q.size == 3
+  raise unless q.pop == 1
+  raise unless q.deq == 2
+  raise unless q.shift == 3
+  raise unless q.empty?
+end
+
+def queue_non_block_pop
+  q = Queue.new
+  raised = false
+  begin
+    q.pop(true)
+  rescue ThreadError
+    raised = true
+  end
+  raise unless raised
+end
+
+def queue_close
+  q = Queue.new
+  q.push(1)
+  raise unless q.close.equal?(q)
+  raise unless q.closed?
+  raise unless q.pop == 1
+  raise unless q.pop.nil?
+
+  raised = false
+  begin
+    q.push(2)
+  rescue ClosedQueueError
+    raised = true
+  end
+  raise unless raised
+end
+
+def queue_clear_and_size
+  q = Queue.new
+  q.push(1)
+  q.push(2)
+  raise unless q.size == 2
+  raise unless q.length == 2
+  raise unless q.num_waiting.zero?
+
+  q.clear
+  raise unless q.empty?
+  raise unless q.size.zero?
+  raise if q.closed?
+end
+
+def sized_queue_max
+  q = SizedQueue.new(2)
+  raise unless q.max == 2
+
+  q.max = 3
+  raise unless q.max == 3
+
+  raised = false
+  begin
+    SizedQueue.new(0)
+  rescue ArgumentError
+    raised = true
+  end
+  raise unless raised
+end
+
+def sized_queue_full
+  q = SizedQueue.new(1)
+  q << 1
+  raised = false
+  begin
+    q.push(2, true)
+  rescue ThreadError
+    raised = true
+  end
+  raise unless raised
+  raise unless q.pop == 1
+end
+
+def closed_queue_error_hierarchy
+  raise unless ClosedQueueError.ancestors.include?(StopIteration)
+
+  q = Queue.new
+  q.close
+  raised = false
+  begin
+    q << 1
+  rescue StopIteration => e
+    raised = true
+    raise unless e.is_a?(ClosedQueueError)
+  end
+  raise unless raised
+end
+
+def condition_variable
+  mutex = Mutex.new
+  cond = ConditionVariable.new
+  raise unless cond.signal.equal?(cond)
+  raise unless cond.broadcast.equal?(cond)
+
+  mutex.synchronize do
+    raise unless cond.wait(mutex).equal?(cond)
+  end
+end
+
+spec if $PROGRAM_NAME == __FILE__
@@ -113,14 +113,21 @@ class Thread
     @name = self.class.__gen_thread_name(object_id)
     @report_on_exception = false
     @terminated_with_exception = nil
+    @exception = nil
     # mruby is not multi-threaded. Threads are executed synchronously.
     @alive = true
     @value = blk.call
   rescue StandardError => e
     if @__unwind_with_exception.nil?
       @terminated_with_exception = true
+      @exception = e
       @value = e
-      self.class.__mark_unwind(e) if self.class.abort_on_exception || abort_on_exception
+      if self.class.abort_on_exception || abort_on_exception
+        self.class.__mark_unwind(e)
+      elsif self.class.report_on_exception || report_on_exception
+        warn "#{inspect} terminated with exception (report_on_exception is true):"
+        warn "#{e.class}: #{e.message}"
+      end
     end
   ensure
     @alive = false unless root
@@ -158,6 +165,10 @@ class Thread
     nil
   end
 
+  # The exception which terminated this thread, or `nil` if the thread
+  # completed normally. `Thread#value` re-raises this exception in the caller.
+  attr_reader :exception
+
   def exit
     # Because mruby Thread instances are run synchronously on initialize, this
     # method is a noop.
@@ -276,7 +287,7 @@ class Thread
   end
 
   def value
-    __raise__ @value if @terminated_with_exception
+    __raise__ @exception if @terminated_with_exception
 
     @value
   end
@@ -288,7 +299,7 @@ class Thread
   end
 end
 
-class ThreadError < StandardError; end
+# `ThreadError` is defined in Rust in the exception init routine.
 
 class Mutex
   def initialize
@@ -296,7 +307,10 @@ class Mutex
   end
 
   def lock
-    raise ThreadError, 'locked by current Thread' if owned?
+    raise ThreadError, 'deadlock; recursive locking' if owned?
+    # A lock held by another thread can never be released in Artichoke's
+    # synchronous threading model, so acquiring it would deadlock.
+    raise ThreadError, 'deadlock; lock is held by another thread' if locked?
 
     @owner = Thread.current
     self
@@ -310,35 +324,190 @@ class Mutex
     @owner == Thread.current
   end
 
-  # This method does not actually sleep
+  # This method does not actually sleep. The lock is released and immediately
+  # reacquired, and the method reports the requested duration as slept.
   def sleep(timeout = nil)
     unlock
     lock
-    timeout
+    timeout.nil? ? 0 : timeout
   end
 
   # mruby interpreters are single threaded and are not `Send`. A `Mutex` can
   # never be contended, so synchronize just immediately yields.
+  #
+  # The lock is only released when it was acquired by this call, so a
+  # recursive `synchronize` raises ThreadError and leaves the outer lock
+  # held.
   def synchronize
     lock
-    yield
-  ensure
-    unlock
+    begin
+      yield
+    ensure
+      unlock
+    end
   end
 
   def try_lock
+    return false if locked?
+
     lock
-    locked? && owned?
+    true
   end
 
   def unlock
-    raise ThreadError, 'not locked by current Thread' unless owned?
+    raise ThreadError, 'Attempt to unlock a mutex which is not locked' unless locked?
+    raise ThreadError, 'Attempt to unlock a mutex which is locked by another thread' unless owned?
 
     @owner = nil
     self
   end
 end
 
+class ConditionVariable
+  def broadcast
+    # noop since there is no scheduler and no thread can be waiting
+    self
+  end
+
+  def signal
+    # noop since there is no scheduler and no thread can be waiting
+    self
+  end
+
+  def wait(mutex, timeout = nil)
+    mutex.sleep(timeout)
+    self
+  end
+end
+
+# `ClosedQueueError` is defined in Rust in the Thread init routine so it
+# subclasses `StopIteration` before this file is evaluated.
+
+class Queue
+  def initialize
+    @items = []
+    @closed = false
+  end
+
+  def clear
+    @items.clear
+    self
+  end
+
+  def close
+    @closed = true
+    self
+  end
+
+  def closed?
+    @closed
+  end
+
+  def empty?
+    @items.empty?
+  end
+
+  def length
+    @items.length
+  end
+
+  alias size length
+
+  def num_waiting
+    # Artichoke threads are executed synchronously, so no thread can be
+    # parked waiting on a queue.
+    0
+  end
+
+  def pop(non_block = false)
+    if empty?
+      return nil if closed?
+      raise ThreadError, 'queue empty' if non_block
+
+      # No other thread can fill the queue in Artichoke's synchronous
+      # threading model, so a blocking pop on an empty queue deadlocks.
+      raise ThreadError, 'No live threads left. Deadlock?'
+    end
+    @items.shift
+  end
+
+  alias deq pop
+  alias shift pop
+
+  def push(obj)
+    raise ClosedQueueError, 'queue closed' if closed?
+
+    @items.push(obj)
+    self
+  end
+
+  alias << push
+  alias enq push
+end
+
+class SizedQueue < Queue
+  attr_reader :max
+
+  def initialize(max)
+    raise ArgumentError, "queue size #{max} must be positive" unless max.is_a?(Integer) && max.positive?
+
+    super()
+    @max = max
+  end
+
+  def max=(number)
+    raise ArgumentError, "queue size #{number} must be positive" unless number.is_a?(Integer) && number.positive?
+
+    @max = number
+  end
+
+  def push(obj, non_block = false)
+    raise ClosedQueueError, 'queue closed' if closed?
+
+    if length >= @max
+      raise ThreadError, 'queue full' if non_block
+
+      # No other thread can drain the queue in Artichoke's synchronous
+      # threading model, so a blocking push on a full queue deadlocks.
+      raise ThreadError, 'No live threads left. Deadlock?'
+    end
+    super(obj)
+  end
+
+  alias << push
+  alias enq push
+end
+
+class Thread
+  class Backtrace
+    # A minimal port of MRI's `Thread::Backtrace::Location`.
+    #
+    # `Kernel#caller_locations` constructs these from frames captured off the
+    # interpreter call stack.
+    class Location
+      attr_reader :path, :lineno, :label
+
+      def initialize(path, lineno, label)
+        @path = path
+        @lineno = lineno
+        @label = label
+      end
+
+      def inspect
+        to_s.inspect
+      end
+
+      def to_s
+        if lineno.nil? || lineno.zero?
+          "#{path}:in `#{label}'"
+        else
+          "#{path}:#{lineno}:in `#{label}'"
+        end
+      end
+    end
+  end
+end
+
 # Spawn the special "root" thread that never terminates.
 # rubocop:disable Lint/EmptyBlock
 Thread.new(root: true) {}
@@ -8,6 +8,15 @@ def spec
   thread_spawn
   thread_locals
   thread_abort_on_exception
+  thread_value_reraises_exception
+  thread_exception_accessor
+  thread_status_after_exception
+  thread_report_on_exception
+  mutex_recursive_lock
+  mutex_foreign_unlock
+  mutex_owned
+  mutex_sleep_returns_duration
+  mutex_try_lock
 
   true
 end
@@ -125,4 +134,128 @@ def thread_abort_on_exception
   end
 end
 
+def thread_value_reraises_exception
+  Thread.abort_on_exception = false
+  t = Thread.new { raise ArgumentError, 'failboat' }.join
+  raised = false
+  begin
+    t.value
+  rescue ArgumentError => e
+    raised = true
+    raise unless e.message == 'failboat'
+  end
+  raise unless raised
+end
+
+def thread_exception_accessor
+  Thread.abort_on_exception = false
+  t = Thread.new { raise 'failboat' }.join
+  raise unless t.exception.is_a?(RuntimeError)
+  raise unless t.exception.message == 'failboat'
+
+  t = Thread.new { 42 }.join
+  raise unless t.exception.nil?
+  raise unless t.value == 42
+end
+
+def thread_status_after_exception
+  Thread.abort_on_exception = false
+  t = Thread.new { raise 'failboat' }.join
+  raise unless t.status.nil?
+  raise if t.alive?
+
+  t = Thread.new { 42 }.join
+  raise unless t.status == false
+end
+
+def thread_report_on_exception
+  Thread.abort_on_exception = false
+  raise unless Thread.report_on_exception == false
+
+  Thread.report_on_exception = true
+  t = Thread.new { raise 'failboat' }.join
+  raise unless t.exception.message == 'failboat'
+ensure
+  Thread.report_on_exception = false
+end
+
+def mutex_recursive_lock
+  m = Mutex.new
+  m.lock
+  raised = false
+  begin
+    m.lock
+  rescue ThreadError
+    raised = true
+  end
+  raise unless raised
+
+  m.unlock
+  m.synchronize do
+    raised = false
+    begin
+      m.synchronize { nil }
+    rescue ThreadError
+      raised = true
+    end
+    raise unless raised
+    raise unless m.owned?
+  end
+  raise if m.locked?
+end
+
+def mutex_foreign_unlock
+  m = Mutex.new
+  raised = false
+  begin
+    m.unlock
+  rescue ThreadError
+    raised = true
+  end
+  raise unless raised
+
+  m.lock
+  raised = false
+  Thread.new do
+    m.unlock
+  rescue ThreadError
+    raised = true
+  end.join
+  raise unless raised
+  raise unless m.locked?
+  m.unlock
+end
+
+def mutex_owned
+  m = Mutex.new
+  raise if m.owned?
+
+  m.synchronize do
+    raise unless m.owned?
+    raise unless m.locked?
+    raise unless Thread.new { m.owned? }.join.value == false
+    raise unless Thread.new { m.locked? }.join.value == true
+  end
+  raise if m.owned?
+  raise if m.locked?
+end
+
+def mutex_sleep_returns_duration
+  m = Mutex.new
+  m.lock
+  raise unless m.sleep(5) == 5
+  raise unless m.sleep.zero?
+  raise unless m.owned?
+  m.unlock
+end
+
+def mutex_try_lock
+  m = Mutex.new
+  raise unless m.try_lock
+  raise if m.try_lock
+
+  m.unlock
+  raise if m.locked?
+end
+
 spec if $PROGRAM_NAME == __FILE__
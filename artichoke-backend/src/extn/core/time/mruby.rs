@@ -92,6 +92,10 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
         .add_method("subsec", time_subsec, sys::mrb_args_none())?
         // Time format
         .add_method("strftime", time_strftime, sys::mrb_args_req(1))?
+        .add_method("iso8601", time_iso8601, sys::mrb_args_none())?
+        .add_method("xmlschema", time_iso8601, sys::mrb_args_none())?
+        .add_method("rfc2822", time_rfc2822, sys::mrb_args_none())?
+        .add_method("rfc822", time_rfc2822, sys::mrb_args_none())?
         .define()?;
     interp.def_class::<time::Time>(spec)?;
 
@@ -636,3 +640,25 @@ unsafe extern "C" fn time_strftime(mrb: *mut sys::mrb_state, slf: sys::mrb_value
         Err(exception) => error::raise(guard, exception),
     }
 }
+
+unsafe extern "C" fn time_iso8601(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let time = Value::from(slf);
+    let result = trampoline::iso8601(&mut guard, time);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn time_rfc2822(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let time = Value::from(slf);
+    let result = trampoline::rfc2822(&mut guard, time);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
@@ -2,7 +2,7 @@
 
 use spinoso_time::MICROS_IN_NANO;
 
-use crate::convert::implicitly_convert_to_int;
+use crate::convert::{implicitly_convert_to_int, implicitly_convert_to_string};
 use crate::extn::core::time::Time;
 use crate::extn::prelude::*;
 
@@ -380,10 +380,26 @@ pub fn subsec(interp: &mut Artichoke, time: Value) -> Result<Value, Error> {
 
 // Time format
 
-pub fn strftime(interp: &mut Artichoke, time: Value, format: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = time;
-    let _ = format;
-    // Requires a parser.
-    Err(NotImplementedError::new().into())
+pub fn strftime(interp: &mut Artichoke, mut time: Value, mut format: Value) -> Result<Value, Error> {
+    let time = unsafe { Time::unbox_from_value(&mut time, interp)? };
+    let time = *time;
+    // Safety:
+    //
+    // The byteslice is immediately used and discarded after extraction. There
+    // are no intervening interpreter accesses.
+    let format = unsafe { implicitly_convert_to_string(interp, &mut format)? };
+    let formatted = time.strftime(format);
+    interp.try_convert_mut(formatted)
+}
+
+pub fn iso8601(interp: &mut Artichoke, mut time: Value) -> Result<Value, Error> {
+    let time = unsafe { Time::unbox_from_value(&mut time, interp)? };
+    let formatted = time.iso8601();
+    interp.try_convert_mut(formatted)
+}
+
+pub fn rfc2822(interp: &mut Artichoke, mut time: Value) -> Result<Value, Error> {
+    let time = unsafe { Time::unbox_from_value(&mut time, interp)? };
+    let formatted = time.rfc2822();
+    interp.try_convert_mut(formatted)
 }
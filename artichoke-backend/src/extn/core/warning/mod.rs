@@ -18,3 +18,57 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
 
 #[derive(Debug, Clone, Copy)]
 pub struct Warning;
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    const OVERRIDE: &[u8] = b"module Warning
+  def self.warn(message, category = nil)
+    $captured_warnings ||= []
+    $captured_warnings << [message, category]
+    nil
+  end
+end";
+
+    #[test]
+    fn kernel_warn_dispatches_through_warning_module() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(OVERRIDE).unwrap();
+        interp.eval(b"warn 'x'").unwrap();
+        let captured = interp.eval(b"$captured_warnings == [[\"x\\n\", nil]]").unwrap();
+        let captured = captured.try_convert_into::<bool>(&interp).unwrap();
+        assert!(captured);
+    }
+
+    #[test]
+    fn rust_warn_dispatches_through_warning_module() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(OVERRIDE).unwrap();
+        interp.warn(b"from native code").unwrap();
+        let captured = interp
+            .eval(b"$captured_warnings == [['from native code', nil]]")
+            .unwrap();
+        let captured = captured.try_convert_into::<bool>(&interp).unwrap();
+        assert!(captured);
+    }
+
+    #[test]
+    fn rust_warn_with_category_passes_the_category() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(OVERRIDE).unwrap();
+        interp
+            .warn_with_category(b"old API", WarningCategory::Deprecated)
+            .unwrap();
+        let captured = interp.eval(b"$captured_warnings == [['old API', :deprecated]]").unwrap();
+        let captured = captured.try_convert_into::<bool>(&interp).unwrap();
+        assert!(captured);
+    }
+
+    #[test]
+    fn default_warning_warn_writes_to_stderr() {
+        let mut interp = interpreter().unwrap();
+        interp.eval(b"Warning.warn('from default')").unwrap();
+        // does not raise even though no override is installed
+    }
+}
@@ -1,9 +1,11 @@
 # frozen_string_literal: true
 
 module Warning
-  # TODO: This method should be defined, but due to method visibility
-  # limitations of the mruby VM, we cannot shadow the warn method in `Kernel`.
-  # def warn(message)
-  #   $stderr.print(message)
-  # end
+  # NOTE: Unlike MRI, the category is an optional positional argument because
+  # native code cannot dispatch with keyword arguments on the mruby VM.
+  def self.warn(message, _category = nil)
+    out = $stderr || $stdout
+    out&.print(message)
+    nil
+  end
 end
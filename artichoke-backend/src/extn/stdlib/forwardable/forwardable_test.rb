@@ -18,6 +18,9 @@ def spec
   # reqires STDOUT impl
   object_extend
   another_example
+  delegator_alias_form
+  nil_receiver_error_message
+  backtrace_excludes_forwardable_frames
 
   true
 end
@@ -86,4 +89,39 @@ def another_example
   raise unless q.first.nil?
 end
 
+# re-open RecordCollection class with the aliased `def_delegator` form
+class RecordCollection
+  def_delegator :@records, :size, :record_count
+end
+
+def delegator_alias_form
+  r = RecordCollection.new
+  r.records = [4, 5, 6]
+  raise unless r.record_count == 3
+end
+
+def nil_receiver_error_message
+  r = RecordCollection.new
+  begin
+    r.size
+    raise 'expected NoMethodError to be raised'
+  rescue NoMethodError => e
+    raise unless e.message.include?("undefined method `size' for nil")
+    raise unless e.message.include?('(delegated via records)')
+  end
+end
+
+def backtrace_excludes_forwardable_frames
+  r = RecordCollection.new
+  begin
+    r.size
+    raise 'expected NoMethodError to be raised'
+  rescue NoMethodError => e
+    backtrace = e.backtrace
+    return true if backtrace.nil? || backtrace.empty?
+
+    raise unless backtrace.none? { |frame| frame.start_with?('forwardable') }
+  end
+end
+
 spec if $PROGRAM_NAME == __FILE__
@@ -54,7 +54,7 @@ pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
     #[cfg(feature = "stdlib-shellwords")]
     shellwords::init(interp)?;
     #[cfg(feature = "stdlib-strscan")]
-    strscan::init(interp)?;
+    strscan::mruby::init(interp)?;
     #[cfg(feature = "stdlib-time")]
     time::init(interp)?;
     #[cfg(feature = "stdlib-uri")]
@@ -1,18 +1,557 @@
-use std::ffi::CStr;
+//! Rust-backed `StringScanner` from the Ruby standard library `strscan`
+//! package.
+//!
+//! `StringScanner` provides lexical scanning operations on a `String`. The
+//! scanner records a byte position into its subject and the extent of the most
+//! recent match. Regexp-based operations match against a subslice of the
+//! subject starting at the scan pointer, so patterns are anchored at the
+//! current position without copying the subject.
+//!
+//! Positions are byte offsets like MRI, even when the subject contains
+//! multibyte characters. [`StringScanner::getch`] is encoding-aware and
+//! consumes whole UTF-8 characters.
 
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+use std::str;
+
+use crate::convert::HeapAllocatedData;
+use crate::extn::core::regexp::{NameToCaptureLocations, NilableString, Regexp};
 use crate::extn::prelude::*;
 
-const STRING_SCANNER_CSTR: &CStr = cstr::cstr!("StringScanner");
+pub mod mruby;
+pub mod trampoline;
+
+/// The extent and captures of a `StringScanner` match.
+///
+/// `start` and `end` are absolute byte offsets into the scanner's subject.
+/// `captures` includes the full match as group 0.
+#[derive(Debug, Clone)]
+struct ScanMatch {
+    start: usize,
+    end: usize,
+    captures: Vec<NilableString>,
+    names: NameToCaptureLocations,
+}
+
+/// A lexical scanner over a byte string with a scan pointer and a record of
+/// the most recent match.
+#[derive(Default, Debug, Clone)]
+pub struct StringScanner {
+    string: Vec<u8>,
+    frozen: bool,
+    pos: usize,
+    prev_pos: Option<usize>,
+    last_match: Option<ScanMatch>,
+}
+
+impl HeapAllocatedData for StringScanner {
+    const RUBY_TYPE: &'static str = "StringScanner";
+}
+
+/// Return the length in bytes of the UTF-8 character at the start of the
+/// given byte slice.
+///
+/// Invalid UTF-8 sequences are consumed one byte at a time, which matches how
+/// MRI's `StringScanner#getch` degrades on binary data.
+fn next_char_len(bytes: &[u8]) -> usize {
+    for len in 1..=4 {
+        match bytes.get(..len) {
+            Some(slice) if str::from_utf8(slice).is_ok() => return len,
+            Some(_) => {}
+            None => break,
+        }
+    }
+    1
+}
+
+impl StringScanner {
+    /// Construct a new scanner over the given subject.
+    ///
+    /// The subject's frozen-ness is captured so mutating operations like
+    /// [`concat`](Self::concat) can reject frozen subjects.
+    #[must_use]
+    pub fn new(string: Vec<u8>, frozen: bool) -> Self {
+        Self {
+            string,
+            frozen,
+            pos: 0,
+            prev_pos: None,
+            last_match: None,
+        }
+    }
+
+    /// The subject of the scanner.
+    #[must_use]
+    pub fn string(&self) -> &[u8] {
+        &self.string
+    }
+
+    /// Replace the subject and reset the scanner.
+    pub fn set_string(&mut self, string: Vec<u8>, frozen: bool) {
+        self.string = string;
+        self.frozen = frozen;
+        self.pos = 0;
+        self.prev_pos = None;
+        self.last_match = None;
+    }
+
+    /// Append bytes to the subject without touching the scan pointer.
+    ///
+    /// # Errors
+    ///
+    /// If the subject was frozen when it was handed to the scanner, a
+    /// [`FrozenError`] is returned.
+    pub fn concat(&mut self, tail: &[u8]) -> Result<(), Error> {
+        if self.frozen {
+            return Err(FrozenError::with_message("can't modify frozen String").into());
+        }
+        self.string.extend_from_slice(tail);
+        Ok(())
+    }
+
+    /// The byte position of the scan pointer.
+    #[must_use]
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Move the scan pointer to the given byte position.
+    ///
+    /// Negative positions count back from the end of the subject.
+    ///
+    /// # Errors
+    ///
+    /// If the position is outside the subject, a [`RangeError`] is returned.
+    pub fn set_pos(&mut self, pos: i64) -> Result<(), Error> {
+        let len = self.string.len();
+        let pos = if pos < 0 {
+            pos.checked_neg()
+                .and_then(|pos| usize::try_from(pos).ok())
+                .and_then(|pos| len.checked_sub(pos))
+        } else {
+            usize::try_from(pos).ok().filter(|&pos| pos <= len)
+        };
+        if let Some(pos) = pos {
+            self.pos = pos;
+            Ok(())
+        } else {
+            Err(RangeError::with_message("index out of range").into())
+        }
+    }
+
+    /// The character position of the scan pointer in the subject.
+    ///
+    /// Unlike [`pos`](Self::pos), multibyte characters count as one.
+    #[must_use]
+    pub fn charpos(&self) -> usize {
+        let scanned = self.string.get(..self.pos).unwrap_or(&self.string);
+        String::from_utf8_lossy(scanned).chars().count()
+    }
+
+    /// Whether the scan pointer is at the end of the subject.
+    #[must_use]
+    pub fn is_eos(&self) -> bool {
+        self.pos >= self.string.len()
+    }
+
+    /// The portion of the subject after the scan pointer.
+    #[must_use]
+    pub fn rest(&self) -> &[u8] {
+        self.string.get(self.pos..).unwrap_or_default()
+    }
+
+    /// At most `len` bytes of the subject after the scan pointer.
+    #[must_use]
+    pub fn peek(&self, len: usize) -> &[u8] {
+        let rest = self.rest();
+        rest.get(..len).unwrap_or(rest)
+    }
+
+    /// Move the scan pointer to the end of the subject and clear the match
+    /// record.
+    pub fn terminate(&mut self) {
+        self.pos = self.string.len();
+        self.prev_pos = None;
+        self.last_match = None;
+    }
+
+    /// Move the scan pointer to the beginning of the subject and clear the
+    /// match record.
+    pub fn reset(&mut self) {
+        self.pos = 0;
+        self.prev_pos = None;
+        self.last_match = None;
+    }
+
+    /// Undo the most recent successful scan, restoring the scan pointer and
+    /// clearing the match record.
+    ///
+    /// # Errors
+    ///
+    /// If the most recent scan failed or there is no match record, a
+    /// [`ScanError`] is returned.
+    pub fn unscan(&mut self) -> Result<(), Error> {
+        if let Some(prev_pos) = self.prev_pos.take() {
+            self.pos = prev_pos;
+            self.last_match = None;
+            Ok(())
+        } else {
+            Err(ScanError::new().into())
+        }
+    }
+
+    /// Scan one character forward, returning the whole UTF-8 character at the
+    /// scan pointer.
+    pub fn getch(&mut self) -> Option<Vec<u8>> {
+        let rest = self.rest();
+        if rest.is_empty() {
+            return None;
+        }
+        let len = next_char_len(rest);
+        let start = self.pos;
+        let end = start + len;
+        let matched = self.string[start..end].to_vec();
+        self.record_advance(ScanMatch {
+            start,
+            end,
+            captures: vec![Some(matched.clone())],
+            names: Vec::new(),
+        });
+        Some(matched)
+    }
+
+    /// Scan one byte forward.
+    pub fn get_byte(&mut self) -> Option<Vec<u8>> {
+        let rest = self.rest();
+        if rest.is_empty() {
+            return None;
+        }
+        let start = self.pos;
+        let end = start + 1;
+        let matched = self.string[start..end].to_vec();
+        self.record_advance(ScanMatch {
+            start,
+            end,
+            captures: vec![Some(matched.clone())],
+            names: Vec::new(),
+        });
+        Some(matched)
+    }
+
+    /// Match `pattern` anchored at the scan pointer, advance past the match,
+    /// and return the matched bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn scan(&mut self, pattern: &Regexp) -> Result<Option<Vec<u8>>, Error> {
+        if let Some(found) = self.find(pattern, true)? {
+            let matched = self.string[found.start..found.end].to_vec();
+            self.record_advance(found);
+            Ok(Some(matched))
+        } else {
+            self.clear_match();
+            Ok(None)
+        }
+    }
+
+    /// Match `pattern` anywhere at or after the scan pointer, advance past
+    /// the match, and return the bytes up to and including the match.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn scan_until(&mut self, pattern: &Regexp) -> Result<Option<Vec<u8>>, Error> {
+        if let Some(found) = self.find(pattern, false)? {
+            let skipped = self.string[self.pos..found.end].to_vec();
+            self.record_advance(found);
+            Ok(Some(skipped))
+        } else {
+            self.clear_match();
+            Ok(None)
+        }
+    }
+
+    /// Like [`scan`](Self::scan), but return the length of the match.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn skip(&mut self, pattern: &Regexp) -> Result<Option<usize>, Error> {
+        let matched = self.scan(pattern)?;
+        Ok(matched.map(|matched| matched.len()))
+    }
+
+    /// Like [`scan_until`](Self::scan_until), but return the number of bytes
+    /// advanced.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn skip_until(&mut self, pattern: &Regexp) -> Result<Option<usize>, Error> {
+        let skipped = self.scan_until(pattern)?;
+        Ok(skipped.map(|skipped| skipped.len()))
+    }
+
+    /// Like [`scan`](Self::scan), but record the match without moving the
+    /// scan pointer.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn check(&mut self, pattern: &Regexp) -> Result<Option<Vec<u8>>, Error> {
+        if let Some(found) = self.find(pattern, true)? {
+            let matched = self.string[found.start..found.end].to_vec();
+            self.record(found);
+            Ok(Some(matched))
+        } else {
+            self.clear_match();
+            Ok(None)
+        }
+    }
+
+    /// Like [`scan_until`](Self::scan_until), but record the match without
+    /// moving the scan pointer.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn check_until(&mut self, pattern: &Regexp) -> Result<Option<Vec<u8>>, Error> {
+        if let Some(found) = self.find(pattern, false)? {
+            let skipped = self.string[self.pos..found.end].to_vec();
+            self.record(found);
+            Ok(Some(skipped))
+        } else {
+            self.clear_match();
+            Ok(None)
+        }
+    }
+
+    /// Like [`check`](Self::check), but return the length of the match.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn is_match(&mut self, pattern: &Regexp) -> Result<Option<usize>, Error> {
+        let matched = self.check(pattern)?;
+        Ok(matched.map(|matched| matched.len()))
+    }
+
+    /// Like [`check_until`](Self::check_until), but return the byte offset of
+    /// the end of the match relative to the scan pointer.
+    ///
+    /// # Errors
+    ///
+    /// If the regexp engine returns an error, that error is returned.
+    pub fn exist(&mut self, pattern: &Regexp) -> Result<Option<usize>, Error> {
+        let skipped = self.check_until(pattern)?;
+        Ok(skipped.map(|skipped| skipped.len()))
+    }
+
+    /// The bytes of the most recent match.
+    #[must_use]
+    pub fn matched(&self) -> Option<&[u8]> {
+        let last_match = self.last_match.as_ref()?;
+        self.string.get(last_match.start..last_match.end)
+    }
+
+    /// The length in bytes of the most recent match.
+    #[must_use]
+    pub fn matched_size(&self) -> Option<usize> {
+        let last_match = self.last_match.as_ref()?;
+        Some(last_match.end - last_match.start)
+    }
+
+    /// The portion of the subject before the most recent match.
+    #[must_use]
+    pub fn pre_match(&self) -> Option<&[u8]> {
+        let last_match = self.last_match.as_ref()?;
+        self.string.get(..last_match.start)
+    }
+
+    /// The portion of the subject after the most recent match.
+    #[must_use]
+    pub fn post_match(&self) -> Option<&[u8]> {
+        let last_match = self.last_match.as_ref()?;
+        self.string.get(last_match.end..)
+    }
+
+    /// The capture at the given index in the most recent match. Group 0 is
+    /// the full match; negative indexes count back from the last group.
+    #[must_use]
+    pub fn capture_at_index(&self, index: i64) -> Option<Vec<u8>> {
+        let last_match = self.last_match.as_ref()?;
+        let len = i64::try_from(last_match.captures.len()).ok()?;
+        let index = if index < 0 { index.checked_add(len)? } else { index };
+        let index = usize::try_from(index).ok()?;
+        last_match.captures.get(index)?.clone()
+    }
+
+    /// The capture for the given group name in the most recent match.
+    ///
+    /// # Errors
+    ///
+    /// If the pattern of the most recent match does not define the given
+    /// group name, an [`IndexError`] is returned.
+    pub fn capture_at_name(&self, name: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+        let last_match = match self.last_match.as_ref() {
+            Some(last_match) => last_match,
+            None => return Ok(None),
+        };
+        for (group, indexes) in &last_match.names {
+            if group != name {
+                continue;
+            }
+            for &index in indexes.iter().rev() {
+                let index = match usize::try_from(index) {
+                    Ok(index) => index,
+                    Err(_) => continue,
+                };
+                if let Some(Some(capture)) = last_match.captures.get(index) {
+                    return Ok(Some(capture.clone()));
+                }
+            }
+            return Ok(None);
+        }
+        let mut message = String::from("undefined group name reference: ");
+        message.push_str(String::from_utf8_lossy(name).as_ref());
+        Err(IndexError::from(message).into())
+    }
+
+    /// The captures of the most recent match, excluding the full match.
+    #[must_use]
+    pub fn captures(&self) -> Option<Vec<NilableString>> {
+        let last_match = self.last_match.as_ref()?;
+        Some(last_match.captures.get(1..).unwrap_or_default().to_vec())
+    }
+
+    /// The number of capture groups in the most recent match, including the
+    /// full match.
+    #[must_use]
+    pub fn size(&self) -> Option<usize> {
+        let last_match = self.last_match.as_ref()?;
+        Some(last_match.captures.len())
+    }
+
+    /// Match `pattern` against the subject starting at the scan pointer.
+    ///
+    /// The subject is matched as a subslice beginning at the scan pointer so
+    /// the engine anchors at the current position without copying. When
+    /// `anchored` is true, matches which do not begin exactly at the scan
+    /// pointer are rejected.
+    fn find(&self, pattern: &Regexp, anchored: bool) -> Result<Option<ScanMatch>, Error> {
+        let haystack = self.rest();
+        let (start, end) = match pattern.inner().pos(haystack, 0)? {
+            Some(match_pos) => match_pos,
+            None => return Ok(None),
+        };
+        // The engine returns the leftmost match. If the leftmost match does
+        // not begin at the scan pointer, no match does.
+        if anchored && start != 0 {
+            return Ok(None);
+        }
+        let captures = pattern.inner().captures(haystack)?.unwrap_or_default();
+        let names = pattern.inner().named_captures()?;
+        Ok(Some(ScanMatch {
+            start: self.pos + start,
+            end: self.pos + end,
+            captures,
+            names,
+        }))
+    }
+
+    fn record(&mut self, found: ScanMatch) {
+        self.prev_pos = Some(self.pos);
+        self.last_match = Some(found);
+    }
+
+    fn record_advance(&mut self, found: ScanMatch) {
+        self.prev_pos = Some(self.pos);
+        self.pos = found.end;
+        self.last_match = Some(found);
+    }
 
-pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
-    let spec = class::Spec::new("StringScanner", STRING_SCANNER_CSTR, None, None)?;
-    interp.def_class::<StringScanner>(spec)?;
-    interp.def_rb_source_file("strscan.rb", &include_bytes!("strscan.rb")[..])?;
-    Ok(())
+    fn clear_match(&mut self) {
+        self.prev_pos = None;
+        self.last_match = None;
+    }
+}
+
+/// Error raised by [`StringScanner::unscan`] when there is no previous match
+/// record to restore.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ScanError {
+    _private: (),
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct StringScanner;
+impl ScanError {
+    /// Construct a new `ScanError`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Retrieve the exception message associated with this error.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        "unscan failed: previous match record not exist"
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl error::Error for ScanError {}
+
+impl RubyException for ScanError {
+    fn message(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(ScanError::message(*self).as_bytes())
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "ScanError".into()
+    }
+
+    fn vm_backtrace(&self, interp: &mut Artichoke) -> Option<Vec<Vec<u8>>> {
+        let _ = interp;
+        None
+    }
+
+    fn as_mrb_value(&self, interp: &mut Artichoke) -> Option<sys::mrb_value> {
+        let message = interp.try_convert_mut(RubyException::message(self)).ok()?;
+        let value = interp.new_instance::<Self>(&[message]).ok().flatten()?;
+        Some(value.inner())
+    }
+}
+
+impl From<ScanError> for Error {
+    fn from(exception: ScanError) -> Self {
+        Self::from(Box::<dyn RubyException>::from(exception))
+    }
+}
+
+impl From<Box<ScanError>> for Error {
+    fn from(exception: Box<ScanError>) -> Self {
+        Self::from(Box::<dyn RubyException>::from(exception))
+    }
+}
+
+impl From<ScanError> for Box<dyn RubyException> {
+    fn from(exception: ScanError) -> Box<dyn RubyException> {
+        Box::new(exception)
+    }
+}
+
+impl From<Box<ScanError>> for Box<dyn RubyException> {
+    fn from(exception: Box<ScanError>) -> Box<dyn RubyException> {
+        exception
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -29,4 +568,146 @@ mod tests {
         let result = interp.eval(b"spec");
         unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
     }
+
+    #[test]
+    fn unscan_without_scan_raises_scan_error() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(
+            br#"
+            require 'strscan'
+            s = StringScanner.new('test string')
+            begin
+              s.unscan
+              false
+            rescue ScanError => e
+              e.message == 'unscan failed: previous match record not exist'
+            end
+            "#,
+        );
+        let result = unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn unscan_after_failed_scan_raises_scan_error() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(
+            br#"
+            require 'strscan'
+            s = StringScanner.new('test string')
+            s.scan(/test/)
+            s.scan(/NOPE/)
+            begin
+              s.unscan
+              false
+            rescue ScanError
+              true
+            end
+            "#,
+        );
+        let result = unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn unscan_restores_position_and_clears_match() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(
+            br#"
+            require 'strscan'
+            s = StringScanner.new('test string')
+            s.scan(/test /)
+            s.unscan
+            s.pos.zero? && s.matched.nil? && s.scan(/test/) == 'test'
+            "#,
+        );
+        let result = unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn terminate_moves_to_eos_and_clears_match() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(
+            br#"
+            require 'strscan'
+            s = StringScanner.new('test string')
+            s.scan(/test/)
+            s.terminate
+            s.eos? && s.pos == 11 && s.matched.nil? && s.scan(/str/).nil?
+            "#,
+        );
+        let result = unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn terminate_then_unscan_raises_scan_error() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(
+            br#"
+            require 'strscan'
+            s = StringScanner.new('test string')
+            s.scan(/test/)
+            s.terminate
+            begin
+              s.unscan
+              false
+            rescue ScanError
+              true
+            end
+            "#,
+        );
+        let result = unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn getch_consumes_whole_utf8_characters_with_byte_positions() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(
+            br#"
+            require 'strscan'
+            s = StringScanner.new("\xC3\xA4b")
+            s.getch == "\xC3\xA4" && s.pos == 2 && s.getch == 'b' && s.pos == 3 && s.getch.nil?
+            "#,
+        );
+        let result = unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn set_pos_past_eos_raises_range_error() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval(
+            br#"
+            require 'strscan'
+            s = StringScanner.new('test string')
+            raised = []
+            begin
+              s.pos = 12
+              raised << false
+            rescue RangeError
+              raised << true
+            end
+            begin
+              s.pos = -12
+              raised << false
+            rescue RangeError
+              raised << true
+            end
+            s.pos = -4
+            raised.all? && s.rest == 'ring'
+            "#,
+        );
+        let result = unwrap_or_panic_with_backtrace(&mut interp, SUBJECT, result);
+        let result = result.try_convert_into::<bool>(&interp).unwrap();
+        assert!(result);
+    }
 }
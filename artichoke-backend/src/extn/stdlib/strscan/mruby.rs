@@ -0,0 +1,437 @@
+//! FFI glue between the Rust trampolines and the mruby C interpreter.
+
+use std::ffi::CStr;
+
+use crate::extn::prelude::*;
+use crate::extn::stdlib::strscan::{self, trampoline};
+
+const STRING_SCANNER_CSTR: &CStr = cstr::cstr!("StringScanner");
+const SCAN_ERROR_CSTR: &CStr = cstr::cstr!("ScanError");
+
+pub fn init(interp: &mut Artichoke) -> InitializeResult<()> {
+    interp.def_file_for_type::<_, StrscanFile>("strscan.rb")?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct StrscanFile {
+    // Ensure this type is not constructable
+    _private: (),
+}
+
+impl File for StrscanFile {
+    type Artichoke = Artichoke;
+    type Error = Error;
+
+    fn require(interp: &mut Self::Artichoke) -> Result<(), Self::Error> {
+        if interp.is_class_defined::<strscan::StringScanner>() {
+            return Ok(());
+        }
+        let scan_error_spec = class::Spec::new("ScanError", SCAN_ERROR_CSTR, None, None)?;
+        class::Builder::for_spec(interp, &scan_error_spec)
+            .with_super_class::<StandardError, _>("StandardError")?
+            .define()?;
+        interp.def_class::<strscan::ScanError>(scan_error_spec)?;
+
+        let spec = class::Spec::new(
+            "StringScanner",
+            STRING_SCANNER_CSTR,
+            None,
+            Some(def::box_unbox_free::<strscan::StringScanner>),
+        )?;
+        class::Builder::for_spec(interp, &spec)
+            .value_is_rust_object()
+            .add_method("initialize", strscan_initialize, sys::mrb_args_req(1))?
+            .add_method("string", strscan_string, sys::mrb_args_none())?
+            .add_method("string=", strscan_set_string, sys::mrb_args_req(1))?
+            .add_method("<<", strscan_concat, sys::mrb_args_req(1))?
+            .add_method("scan", strscan_scan, sys::mrb_args_req(1))?
+            .add_method("scan_until", strscan_scan_until, sys::mrb_args_req(1))?
+            .add_method("skip", strscan_skip, sys::mrb_args_req(1))?
+            .add_method("skip_until", strscan_skip_until, sys::mrb_args_req(1))?
+            .add_method("check", strscan_check, sys::mrb_args_req(1))?
+            .add_method("check_until", strscan_check_until, sys::mrb_args_req(1))?
+            .add_method("match?", strscan_is_match, sys::mrb_args_req(1))?
+            .add_method("exist?", strscan_exist, sys::mrb_args_req(1))?
+            .add_method("getch", strscan_getch, sys::mrb_args_none())?
+            .add_method("get_byte", strscan_get_byte, sys::mrb_args_none())?
+            .add_method("peek", strscan_peek, sys::mrb_args_req(1))?
+            .add_method("pos", strscan_pos, sys::mrb_args_none())?
+            .add_method("pos=", strscan_set_pos, sys::mrb_args_req(1))?
+            .add_method("charpos", strscan_charpos, sys::mrb_args_none())?
+            .add_method("eos?", strscan_eos, sys::mrb_args_none())?
+            .add_method("rest", strscan_rest, sys::mrb_args_none())?
+            .add_method("matched", strscan_matched, sys::mrb_args_none())?
+            .add_method("matched?", strscan_is_matched, sys::mrb_args_none())?
+            .add_method("matched_size", strscan_matched_size, sys::mrb_args_none())?
+            .add_method("pre_match", strscan_pre_match, sys::mrb_args_none())?
+            .add_method("post_match", strscan_post_match, sys::mrb_args_none())?
+            .add_method("[]", strscan_element_reference, sys::mrb_args_req(1))?
+            .add_method("captures", strscan_captures, sys::mrb_args_none())?
+            .add_method("size", strscan_size, sys::mrb_args_none())?
+            .add_method("unscan", strscan_unscan, sys::mrb_args_none())?
+            .add_method("terminate", strscan_terminate, sys::mrb_args_none())?
+            .add_method("reset", strscan_reset, sys::mrb_args_none())?
+            .define()?;
+        interp.def_class::<strscan::StringScanner>(spec)?;
+        interp.eval(&include_bytes!("strscan.rb")[..])?;
+
+        trace!("Patched StringScanner onto interpreter");
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn strscan_initialize(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let string = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let into = Value::from(slf);
+    let string = Value::from(string);
+    let result = trampoline::initialize(&mut guard, string, into);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_string(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::string(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_set_string(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let string = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let string = Value::from(string);
+    let result = trampoline::set_string(&mut guard, value, string);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_concat(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let tail = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let tail = Value::from(tail);
+    let result = trampoline::concat(&mut guard, value, tail);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_scan(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::scan(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_scan_until(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::scan_until(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_skip(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::skip(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_skip_until(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::skip_until(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_check(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::check(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_check_until(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::check_until(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_is_match(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::is_match(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_exist(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pattern = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pattern = Value::from(pattern);
+    let result = trampoline::exist(&mut guard, value, pattern);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_getch(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::getch(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_get_byte(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::get_byte(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_peek(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let len = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let len = Value::from(len);
+    let result = trampoline::peek(&mut guard, value, len);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_pos(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::pos(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_set_pos(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let pos = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let pos = Value::from(pos);
+    let result = trampoline::set_pos(&mut guard, value, pos);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_charpos(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::charpos(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_eos(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::eos(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_rest(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::rest(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_matched(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::matched(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_is_matched(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::is_matched(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_matched_size(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::matched_size(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_pre_match(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::pre_match(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_post_match(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::post_match(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_element_reference(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let elem = mrb_get_args!(mrb, required = 1);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let elem = Value::from(elem);
+    let result = trampoline::element_reference(&mut guard, value, elem);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_captures(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::captures(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_size(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::size(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_unscan(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::unscan(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_terminate(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::terminate(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
+
+unsafe extern "C" fn strscan_reset(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    mrb_get_args!(mrb, none);
+    unwrap_interpreter!(mrb, to => guard);
+    let value = Value::from(slf);
+    let result = trampoline::reset(&mut guard, value);
+    match result {
+        Ok(value) => value.inner(),
+        Err(exception) => error::raise(guard, exception),
+    }
+}
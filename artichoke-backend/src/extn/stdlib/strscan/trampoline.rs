@@ -0,0 +1,259 @@
+use crate::convert::{implicitly_convert_to_int, implicitly_convert_to_string};
+use crate::extn::core::matchdata::CaptureExtract;
+use crate::extn::core::regexp::Regexp;
+use crate::extn::prelude::*;
+use crate::extn::stdlib::strscan::StringScanner;
+
+/// Unbox a `Regexp` pattern argument.
+///
+/// Unlike MRI, `StringScanner` in Artichoke does not accept `String`
+/// patterns; raise `TypeError` for everything that is not a `Regexp`.
+fn pattern_from_value(interp: &mut Artichoke, mut pattern: Value) -> Result<Regexp, Error> {
+    if let Ok(regexp) = unsafe { Regexp::unbox_from_value(&mut pattern, interp) } {
+        Ok(regexp.clone())
+    } else {
+        let mut message = String::from("wrong argument type ");
+        message.push_str(interp.inspect_type_name_for_value(pattern));
+        message.push_str(" (expected Regexp)");
+        Err(TypeError::from(message).into())
+    }
+}
+
+pub fn initialize(interp: &mut Artichoke, mut string: Value, into: Value) -> Result<Value, Error> {
+    let frozen = string.is_frozen(interp);
+    let bytes = unsafe { implicitly_convert_to_string(interp, &mut string)? }.to_vec();
+    let scanner = StringScanner::new(bytes, frozen);
+    let scanner = StringScanner::box_into_value(scanner, into, interp)?;
+    Ok(scanner)
+}
+
+pub fn string(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let string = scanner.string().to_vec();
+    interp.try_convert_mut(string)
+}
+
+pub fn set_string(interp: &mut Artichoke, mut value: Value, mut string: Value) -> Result<Value, Error> {
+    let frozen = string.is_frozen(interp);
+    let bytes = unsafe { implicitly_convert_to_string(interp, &mut string)? }.to_vec();
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    scanner.set_string(bytes, frozen);
+    Ok(string)
+}
+
+pub fn concat(interp: &mut Artichoke, mut value: Value, mut tail: Value) -> Result<Value, Error> {
+    let tail = unsafe { implicitly_convert_to_string(interp, &mut tail)? }.to_vec();
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    scanner.concat(&tail)?;
+    Ok(value)
+}
+
+pub fn scan(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let matched = scanner.scan(&pattern)?;
+    interp.try_convert_mut(matched)
+}
+
+pub fn scan_until(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let skipped = scanner.scan_until(&pattern)?;
+    interp.try_convert_mut(skipped)
+}
+
+pub fn skip(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let len = scanner.skip(&pattern)?;
+    match len.map(i64::try_from) {
+        Some(Ok(len)) => Ok(interp.convert(len)),
+        Some(Err(_)) => Err(ArgumentError::with_message("input string too long").into()),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn skip_until(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let len = scanner.skip_until(&pattern)?;
+    match len.map(i64::try_from) {
+        Some(Ok(len)) => Ok(interp.convert(len)),
+        Some(Err(_)) => Err(ArgumentError::with_message("input string too long").into()),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn check(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let matched = scanner.check(&pattern)?;
+    interp.try_convert_mut(matched)
+}
+
+pub fn check_until(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let skipped = scanner.check_until(&pattern)?;
+    interp.try_convert_mut(skipped)
+}
+
+pub fn is_match(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let len = scanner.is_match(&pattern)?;
+    match len.map(i64::try_from) {
+        Some(Ok(len)) => Ok(interp.convert(len)),
+        Some(Err(_)) => Err(ArgumentError::with_message("input string too long").into()),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn exist(interp: &mut Artichoke, mut value: Value, pattern: Value) -> Result<Value, Error> {
+    let pattern = pattern_from_value(interp, pattern)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let len = scanner.exist(&pattern)?;
+    match len.map(i64::try_from) {
+        Some(Ok(len)) => Ok(interp.convert(len)),
+        Some(Err(_)) => Err(ArgumentError::with_message("input string too long").into()),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn getch(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let matched = scanner.getch();
+    interp.try_convert_mut(matched)
+}
+
+pub fn get_byte(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let matched = scanner.get_byte();
+    interp.try_convert_mut(matched)
+}
+
+pub fn peek(interp: &mut Artichoke, mut value: Value, len: Value) -> Result<Value, Error> {
+    let len = implicitly_convert_to_int(interp, len)?;
+    let len = usize::try_from(len).map_err(|_| ArgumentError::with_message("negative string size (or size too big)"))?;
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let peeked = scanner.peek(len).to_vec();
+    interp.try_convert_mut(peeked)
+}
+
+pub fn pos(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let pos = scanner.pos();
+    let pos = i64::try_from(pos).map_err(|_| ArgumentError::with_message("input string too long"))?;
+    Ok(interp.convert(pos))
+}
+
+pub fn set_pos(interp: &mut Artichoke, mut value: Value, pos: Value) -> Result<Value, Error> {
+    let pos = implicitly_convert_to_int(interp, pos)?;
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    scanner.set_pos(pos)?;
+    Ok(interp.convert(pos))
+}
+
+pub fn charpos(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let charpos = scanner.charpos();
+    let charpos = i64::try_from(charpos).map_err(|_| ArgumentError::with_message("input string too long"))?;
+    Ok(interp.convert(charpos))
+}
+
+pub fn eos(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    Ok(interp.convert(scanner.is_eos()))
+}
+
+pub fn rest(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let rest = scanner.rest().to_vec();
+    interp.try_convert_mut(rest)
+}
+
+pub fn matched(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let matched = scanner.matched().map(<[u8]>::to_vec);
+    interp.try_convert_mut(matched)
+}
+
+pub fn is_matched(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    Ok(interp.convert(scanner.matched().is_some()))
+}
+
+pub fn matched_size(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    match scanner.matched_size().map(i64::try_from) {
+        Some(Ok(len)) => Ok(interp.convert(len)),
+        Some(Err(_)) => Err(ArgumentError::with_message("input string too long").into()),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn pre_match(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let pre_match = scanner.pre_match().map(<[u8]>::to_vec);
+    interp.try_convert_mut(pre_match)
+}
+
+pub fn post_match(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let post_match = scanner.post_match().map(<[u8]>::to_vec);
+    interp.try_convert_mut(post_match)
+}
+
+pub fn element_reference(interp: &mut Artichoke, mut value: Value, mut elem: Value) -> Result<Value, Error> {
+    enum Lookup {
+        Index(i64),
+        Name(Vec<u8>),
+    }
+    let lookup = match interp.try_convert_mut(&mut elem)? {
+        CaptureExtract::GroupIndex(index) => Lookup::Index(index),
+        CaptureExtract::GroupName(name) => Lookup::Name(name.to_vec()),
+        CaptureExtract::Symbol(symbol) => Lookup::Name(symbol.bytes(interp).to_vec()),
+    };
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    let capture = match lookup {
+        Lookup::Index(index) => scanner.capture_at_index(index),
+        Lookup::Name(name) => scanner.capture_at_name(&name)?,
+    };
+    interp.try_convert_mut(capture)
+}
+
+pub fn captures(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    if let Some(captures) = scanner.captures() {
+        interp.try_convert_mut(captures)
+    } else {
+        Ok(Value::nil())
+    }
+}
+
+pub fn size(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    match scanner.size().map(i64::try_from) {
+        Some(Ok(size)) => Ok(interp.convert(size)),
+        Some(Err(_)) => Err(ArgumentError::with_message("input string too long").into()),
+        None => Ok(Value::nil()),
+    }
+}
+
+pub fn unscan(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    scanner.unscan()?;
+    Ok(value)
+}
+
+pub fn terminate(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    scanner.terminate();
+    Ok(value)
+}
+
+pub fn reset(interp: &mut Artichoke, mut value: Value) -> Result<Value, Error> {
+    let mut scanner = unsafe { StringScanner::unbox_from_value(&mut value, interp)? };
+    scanner.reset();
+    Ok(value)
+}
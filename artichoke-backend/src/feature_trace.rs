@@ -0,0 +1,219 @@
+//! Observe which features are loaded during a window of execution.
+//!
+//! Embedding applications can snapshot the set of sources pulled into the
+//! interpreter by an eval, for example to precompile a dependency set. A
+//! feature trace records every feature loaded between
+//! [`Artichoke::begin_feature_trace`] and [`Artichoke::end_feature_trace`] by
+//! instrumenting the same `require` and `load` code paths that update the
+//! loaded feature set in the virtual file system, so `Kernel#require`,
+//! `Kernel#require_relative`, and `Kernel#load` are all captured.
+//!
+//! Traces nest: every active trace observes each event, so the events
+//! recorded by an inner trace are a subset of the events recorded by the
+//! trace enclosing it.
+
+use crate::error::Error;
+use crate::ffi::InterpreterExtractError;
+use crate::Artichoke;
+
+/// A feature load observed by an active feature trace.
+///
+/// Events store the absolutized path of the loaded feature, matching the
+/// entries in the interpreter's loaded feature set. `Kernel#load` bypasses
+/// the loaded feature set and is recorded distinctly from `Kernel#require`
+/// and `Kernel#require_relative`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum FeatureEvent {
+    /// The feature at the given path was loaded with `Kernel#require` or
+    /// `Kernel#require_relative` and marked in the loaded feature set.
+    Require(Vec<u8>),
+    /// The source at the given path was executed with `Kernel#load`.
+    Load(Vec<u8>),
+}
+
+impl FeatureEvent {
+    /// Return the absolutized path of the loaded feature.
+    #[must_use]
+    pub fn path(&self) -> &[u8] {
+        match self {
+            Self::Require(path) | Self::Load(path) => path,
+        }
+    }
+
+    /// Whether this event was recorded by `Kernel#load`.
+    ///
+    /// `Kernel#load` executes a source unconditionally, so unlike
+    /// [`Require`] events, the same path may appear in multiple `Load`
+    /// events within one trace.
+    ///
+    /// [`Require`]: Self::Require
+    #[must_use]
+    pub fn is_load(&self) -> bool {
+        matches!(self, Self::Load(_))
+    }
+}
+
+/// Token identifying an active feature trace on an interpreter.
+///
+/// Returned by [`Artichoke::begin_feature_trace`] and redeemed with
+/// [`Artichoke::end_feature_trace`].
+#[derive(Debug)]
+#[must_use = "feature traces record until ended with `Artichoke::end_feature_trace`"]
+pub struct FeatureTraceId(usize);
+
+impl Artichoke {
+    /// Begin recording the features loaded into the interpreter.
+    ///
+    /// The returned [`FeatureTraceId`] is redeemed with
+    /// [`end_feature_trace`] to retrieve the recorded events. Traces may be
+    /// nested; each active trace observes every event.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state is not accessible, an error is returned.
+    ///
+    /// [`end_feature_trace`]: Self::end_feature_trace
+    pub fn begin_feature_trace(&mut self) -> Result<FeatureTraceId, Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let id = state.feature_traces.len();
+        state.feature_traces.push(Vec::new());
+        Ok(FeatureTraceId(id))
+    }
+
+    /// Stop the given feature trace and return the events it recorded, in
+    /// the order the features finished loading.
+    ///
+    /// Ending a trace also ends any traces begun after it; if an enclosing
+    /// trace was ended first, this trace's events are discarded and an empty
+    /// `Vec` is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state is not accessible, an error is returned.
+    pub fn end_feature_trace(&mut self, id: FeatureTraceId) -> Result<Vec<FeatureEvent>, Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let FeatureTraceId(index) = id;
+        if index >= state.feature_traces.len() {
+            return Ok(Vec::new());
+        }
+        let mut traces = state.feature_traces.split_off(index);
+        Ok(traces.swap_remove(0))
+    }
+
+    /// Record a feature load on every active feature trace.
+    pub(crate) fn record_feature_event(&mut self, event: FeatureEvent) -> Result<(), Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        if let [outer_traces @ .., innermost] = state.feature_traces.as_mut_slice() {
+            for trace in outer_traces {
+                trace.push(event.clone());
+            }
+            innermost.push(event);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bstr::ByteSlice;
+
+    use crate::test::prelude::*;
+
+    fn interpreter_with_fixture_tree() -> Artichoke {
+        let mut interp = interpreter().unwrap();
+        interp
+            .def_rb_source_file("trace_a.rb", &b"require 'trace_b'"[..])
+            .unwrap();
+        interp.def_rb_source_file("trace_b.rb", &b"# b"[..]).unwrap();
+        interp.def_rb_source_file("trace_c.rb", &b"# c"[..]).unwrap();
+        interp
+    }
+
+    #[test]
+    fn trace_records_requires_in_load_order() {
+        let mut interp = interpreter_with_fixture_tree();
+        let trace = interp.begin_feature_trace().unwrap();
+        interp.eval(b"require 'trace_a'").unwrap();
+        let events = interp.end_feature_trace(trace).unwrap();
+
+        // `trace_b.rb` finishes loading before the `trace_a.rb` source that
+        // requires it.
+        let paths = events.iter().map(|event| event.path()).collect::<Vec<_>>();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with_str("trace_b.rb"));
+        assert!(paths[1].ends_with_str("trace_a.rb"));
+        assert!(events.iter().all(|event| !event.is_load()));
+    }
+
+    #[test]
+    fn already_required_features_are_not_recorded() {
+        let mut interp = interpreter_with_fixture_tree();
+        interp.eval(b"require 'trace_b'").unwrap();
+
+        let trace = interp.begin_feature_trace().unwrap();
+        interp.eval(b"require 'trace_a'").unwrap();
+        interp.eval(b"require 'trace_b'").unwrap();
+        let events = interp.end_feature_trace(trace).unwrap();
+
+        // `trace_b.rb` was required before the trace began, so only
+        // `trace_a.rb` is recorded.
+        let paths = events.iter().map(|event| event.path()).collect::<Vec<_>>();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with_str("trace_a.rb"));
+    }
+
+    #[test]
+    fn load_is_recorded_distinctly_and_is_not_deduplicated() {
+        let mut interp = interpreter_with_fixture_tree();
+        interp.eval(b"require 'trace_c'").unwrap();
+
+        let trace = interp.begin_feature_trace().unwrap();
+        interp.eval(b"load 'trace_c.rb'").unwrap();
+        interp.eval(b"load 'trace_c.rb'").unwrap();
+        let events = interp.end_feature_trace(trace).unwrap();
+
+        assert_eq!(events.len(), 2);
+        for event in &events {
+            assert!(event.is_load());
+            assert!(event.path().ends_with_str("trace_c.rb"));
+        }
+    }
+
+    #[test]
+    fn nested_trace_events_are_a_subset_of_the_enclosing_trace() {
+        let mut interp = interpreter_with_fixture_tree();
+
+        let outer = interp.begin_feature_trace().unwrap();
+        interp.eval(b"require 'trace_b'").unwrap();
+        let inner = interp.begin_feature_trace().unwrap();
+        interp.eval(b"require 'trace_c'").unwrap();
+        let inner_events = interp.end_feature_trace(inner).unwrap();
+        interp.eval(b"require 'trace_a'").unwrap();
+        let outer_events = interp.end_feature_trace(outer).unwrap();
+
+        let inner_paths = inner_events.iter().map(|event| event.path()).collect::<Vec<_>>();
+        assert_eq!(inner_paths.len(), 1);
+        assert!(inner_paths[0].ends_with_str("trace_c.rb"));
+
+        let outer_paths = outer_events.iter().map(|event| event.path()).collect::<Vec<_>>();
+        assert_eq!(outer_paths.len(), 3);
+        assert!(outer_paths[0].ends_with_str("trace_b.rb"));
+        assert!(outer_paths[1].ends_with_str("trace_c.rb"));
+        assert!(outer_paths[2].ends_with_str("trace_a.rb"));
+        assert!(inner_events.iter().all(|event| outer_events.contains(event)));
+    }
+
+    #[test]
+    fn ending_an_enclosing_trace_discards_inner_traces() {
+        let mut interp = interpreter_with_fixture_tree();
+
+        let outer = interp.begin_feature_trace().unwrap();
+        let inner = interp.begin_feature_trace().unwrap();
+        interp.eval(b"require 'trace_b'").unwrap();
+        let outer_events = interp.end_feature_trace(outer).unwrap();
+        let inner_events = interp.end_feature_trace(inner).unwrap();
+
+        assert_eq!(outer_events.len(), 1);
+        assert!(inner_events.is_empty());
+    }
+}
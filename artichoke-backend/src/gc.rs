@@ -1,3 +1,4 @@
+use crate::ffi::InterpreterExtractError;
 use crate::sys;
 use crate::value::Value;
 use crate::{Artichoke, Error};
@@ -54,6 +55,17 @@ pub trait MrbGarbageCollection {
     ///
     /// Returns the prior GC enabled state.
     fn disable_gc(&mut self) -> Result<State, Error>;
+
+    /// Retrieve a snapshot of interpreter heap statistics.
+    ///
+    /// See [`HeapStats`] for the metrics included in the snapshot.
+    fn heap_stats(&mut self) -> Result<HeapStats, Error>;
+
+    /// Perform the garbage collection operation given by [`GcControl`].
+    ///
+    /// Returns the GC enabled state prior to the operation, which is
+    /// unchanged by [`GcControl::Full`] and [`GcControl::Incremental`].
+    fn gc(&mut self, control: GcControl) -> Result<State, Error>;
 }
 
 impl MrbGarbageCollection for Artichoke {
@@ -79,6 +91,8 @@ impl MrbGarbageCollection for Artichoke {
         unsafe {
             self.with_ffi_boundary(|mrb| sys::mrb_incremental_gc(mrb))?;
         }
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.gc_runs.incremental += 1;
         Ok(())
     }
 
@@ -87,6 +101,8 @@ impl MrbGarbageCollection for Artichoke {
         unsafe {
             self.with_ffi_boundary(|mrb| sys::mrb_full_gc(mrb))?;
         }
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.gc_runs.full += 1;
         Ok(())
     }
 
@@ -115,6 +131,66 @@ impl MrbGarbageCollection for Artichoke {
             Ok(state)
         }
     }
+
+    fn heap_stats(&mut self) -> Result<HeapStats, Error> {
+        let (live_objects, pages, capacity, phase, enabled) = unsafe {
+            self.with_ffi_boundary(|mrb| {
+                let live_objects = sys::mrb_sys_gc_live_objects(mrb);
+                let pages = sys::mrb_sys_gc_heap_pages(mrb);
+                let capacity = sys::mrb_sys_gc_heap_capacity(mrb);
+                let phase = Phase::from_mrb_gc_state(sys::mrb_sys_gc_phase(mrb));
+                let enabled = if sys::mrb_sys_gc_is_enabled(mrb) {
+                    State::Enabled
+                } else {
+                    State::Disabled
+                };
+                (live_objects, pages, capacity, phase, enabled)
+            })?
+        };
+        let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        Ok(HeapStats {
+            live_objects,
+            pages,
+            capacity,
+            phase,
+            enabled,
+            full_gc_runs: state.gc_runs.full,
+            incremental_gc_runs: state.gc_runs.incremental,
+        })
+    }
+
+    fn gc(&mut self, control: GcControl) -> Result<State, Error> {
+        match control {
+            GcControl::Full => {
+                let enabled = self.gc_enabled_state()?;
+                self.full_gc()?;
+                Ok(enabled)
+            }
+            GcControl::Incremental => {
+                let enabled = self.gc_enabled_state()?;
+                self.incremental_gc()?;
+                Ok(enabled)
+            }
+            GcControl::Enable => self.enable_gc(),
+            GcControl::Disable => self.disable_gc(),
+        }
+    }
+}
+
+impl Artichoke {
+    /// Query the GC enabled state without modifying it.
+    fn gc_enabled_state(&mut self) -> Result<State, Error> {
+        unsafe {
+            let state = self.with_ffi_boundary(|mrb| {
+                if sys::mrb_sys_gc_is_enabled(mrb) {
+                    State::Enabled
+                } else {
+                    State::Disabled
+                }
+            })?;
+            Ok(state)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -123,6 +199,90 @@ pub enum State {
     Enabled,
 }
 
+/// Garbage collection operations accepted by
+/// [`MrbGarbageCollection::gc`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum GcControl {
+    /// Perform a [full GC](MrbGarbageCollection::full_gc).
+    Full,
+    /// Perform an [incremental GC](MrbGarbageCollection::incremental_gc).
+    Incremental,
+    /// [Disable GC](MrbGarbageCollection::disable_gc).
+    Disable,
+    /// [Enable GC](MrbGarbageCollection::enable_gc).
+    Enable,
+}
+
+/// Phase of the mruby incremental garbage collector.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Phase {
+    /// The GC is idle between collection cycles.
+    Root,
+    /// The GC is incrementally marking reachable objects.
+    Mark,
+    /// The GC is incrementally sweeping dead objects.
+    Sweep,
+}
+
+impl Phase {
+    /// Map an `mrb_gc_state` discriminant to a `Phase`.
+    fn from_mrb_gc_state(state: i32) -> Self {
+        const MARK: i32 = sys::mrb_gc_state_MRB_GC_STATE_MARK as i32;
+        const SWEEP: i32 = sys::mrb_gc_state_MRB_GC_STATE_SWEEP as i32;
+        match state {
+            MARK => Self::Mark,
+            SWEEP => Self::Sweep,
+            _ => Self::Root,
+        }
+    }
+}
+
+/// A snapshot of interpreter heap statistics.
+///
+/// `HeapStats` is returned by [`MrbGarbageCollection::heap_stats`]. The same
+/// metrics are reported to Ruby code by `GC.stat`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Count of live objects on the interpreter heap.
+    pub live_objects: i32,
+    /// Count of heap pages allocated by the GC.
+    pub pages: usize,
+    /// Total object capacity of the allocated heap pages.
+    pub capacity: usize,
+    /// Current [`Phase`] of the incremental GC.
+    pub phase: Phase,
+    /// Whether the GC is enabled.
+    pub enabled: State,
+    /// Count of full GC runs triggered through this API or the Ruby `GC`
+    /// module.
+    ///
+    /// Collections triggered by the mruby allocator are not counted.
+    pub full_gc_runs: usize,
+    /// Count of incremental GC runs triggered through this API or the Ruby
+    /// `GC` module.
+    ///
+    /// Collections triggered by the mruby allocator are not counted.
+    pub incremental_gc_runs: usize,
+}
+
+/// Counters for GC runs triggered through
+/// [`MrbGarbageCollection`](crate::gc::MrbGarbageCollection).
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct GcRuns {
+    /// Count of full GC runs.
+    pub full: usize,
+    /// Count of incremental GC runs.
+    pub incremental: usize,
+}
+
+impl GcRuns {
+    /// Construct new zeroed GC run counters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::prelude::*;
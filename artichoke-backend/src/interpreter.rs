@@ -9,6 +9,7 @@ use crate::extn;
 use crate::extn::core::exception::Fatal;
 use crate::ffi;
 use crate::gc::{MrbGarbageCollection, State as GcState};
+use crate::interrupt;
 use crate::release_metadata::ReleaseMetadata;
 use crate::state::State;
 use crate::sys;
@@ -41,6 +42,12 @@ pub fn interpreter_with_config(config: ReleaseMetadata<'_>) -> Result<Artichoke,
 
     let mut interp = unsafe { ffi::from_user_data(raw).map_err(|_| InterpreterAllocError::new())? };
 
+    // Install the code fetch hook which delivers pending interrupts from
+    // `InterruptHandle`s between VM instructions.
+    unsafe {
+        interp.mrb.as_mut().code_fetch_hook = Some(interrupt::code_fetch_hook);
+    }
+
     if let Some(ref mut state) = interp.state {
         if let Some(mrb) = unsafe { raw.as_mut() } {
             state.try_init_parser(mrb);
@@ -0,0 +1,213 @@
+//! Interrupt Ruby code running on an interpreter from another thread.
+//!
+//! An [`InterruptHandle`] is a [`Send`] + [`Sync`] token obtained from an
+//! interpreter with [`Artichoke::interrupt_handle`]. Calling
+//! [`InterruptHandle::interrupt`] causes the eval currently running on the
+//! owning interpreter to abort with an `Interrupt` exception at the next VM
+//! instruction boundary.
+//!
+//! Interrupts are delivered by a [code fetch hook] installed on the underlying
+//! [`sys::mrb_state`] which checks a shared atomic flag between VM
+//! instructions. Because the exception is raised from inside the VM dispatch
+//! loop, `ensure` blocks in the interrupted Ruby code still run as the stack
+//! unwinds.
+//!
+//! [code fetch hook]: sys::mrb_state::code_fetch_hook
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::core::Eval;
+use crate::error::Error;
+use crate::ffi::InterpreterExtractError;
+use crate::state::State;
+use crate::sys;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// A thread-safe handle for interrupting an in-progress eval.
+///
+/// Handles are created with [`Artichoke::interrupt_handle`] and remain valid
+/// for the lifetime of the interpreter they were extracted from. Handles are
+/// cheap to [`Clone`] and may be moved to and signaled from any thread.
+///
+/// Calling [`interrupt`](Self::interrupt) on a handle whose interpreter is not
+/// currently evaluating code is not an error; the pending interrupt is
+/// delivered to the next eval.
+#[derive(Debug, Clone)]
+pub struct InterruptHandle {
+    flag: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Request that the interpreter abort the currently running eval.
+    ///
+    /// The eval aborts with an `Interrupt` exception at the next VM
+    /// instruction boundary. Native code that does not return control to the
+    /// VM, such as a long-running method implemented in Rust, cannot be
+    /// interrupted until it yields back to the VM.
+    pub fn interrupt(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+}
+
+impl Artichoke {
+    /// Create an [`InterruptHandle`] for interrupting evals on this
+    /// interpreter from other threads.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state is not accessible, an error is returned.
+    pub fn interrupt_handle(&self) -> Result<InterruptHandle, Error> {
+        let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        let flag = Arc::clone(&state.interrupt);
+        Ok(InterruptHandle { flag })
+    }
+
+    /// Eval code on the interpreter, aborting with an `Interrupt` exception if
+    /// the eval runs longer than the given timeout.
+    ///
+    /// This method spawns a watchdog thread which signals an
+    /// [`InterruptHandle`] once the deadline elapses. The watchdog is always
+    /// joined before this method returns, and a deadline that fires after the
+    /// eval has already completed does not affect subsequent evals.
+    ///
+    /// # Errors
+    ///
+    /// If the timeout elapses before the eval completes, an `Interrupt` error
+    /// is returned.
+    ///
+    /// If an exception is raised on the interpreter, then an error is
+    /// returned.
+    pub fn eval_with_timeout(&mut self, code: &[u8], timeout: Duration) -> Result<Value, Error> {
+        let handle = self.interrupt_handle()?;
+        let flag = Arc::clone(&handle.flag);
+        let (alive, watch) = mpsc::channel::<()>();
+        let watchdog = thread::spawn(move || {
+            // A recv error other than a timeout means the eval completed and
+            // the sending half was dropped, in which case there is nothing to
+            // interrupt.
+            if let Err(RecvTimeoutError::Timeout) = watch.recv_timeout(timeout) {
+                handle.interrupt();
+            }
+        });
+        let result = self.eval(code);
+        // Wake the watchdog if the eval beat the deadline.
+        drop(alive);
+        let _ = watchdog.join();
+        // If the deadline elapsed after the eval completed but before the
+        // watchdog was joined, a pending interrupt may be left behind. Clear
+        // it so subsequent evals are not spuriously interrupted.
+        flag.store(false, Ordering::SeqCst);
+        result
+    }
+}
+
+/// Code fetch hook which raises `Interrupt` when an interrupt is pending.
+///
+/// This hook is installed on the [`sys::mrb_state`] at interpreter boot and is
+/// invoked by the VM between instructions.
+pub(crate) unsafe extern "C" fn code_fetch_hook(
+    mrb: *mut sys::mrb_state,
+    _irep: *const sys::mrb_irep,
+    _pc: *const sys::mrb_code,
+    _regs: *mut sys::mrb_value,
+) {
+    // Safety:
+    //
+    // While the VM is executing instructions, the `State` is owned by the
+    // `mrb_state` userdata pointer. The state is only borrowed to read the
+    // interrupt flag; the atomic flag is safe to access concurrently with the
+    // `InterruptHandle`s that share it.
+    let state = if let Some(mrb) = mrb.as_ref() {
+        mrb.ud.cast::<State>()
+    } else {
+        return;
+    };
+    let state = if let Some(state) = state.as_ref() {
+        state
+    } else {
+        return;
+    };
+    // Consume the pending interrupt before raising so the `ensure` blocks run
+    // while unwinding and subsequent evals are not interrupted.
+    if state.interrupt.swap(false, Ordering::SeqCst) {
+        // Only `Copy` values are alive in this frame, so it is safe to raise.
+        //
+        // `mrb_sys_raise` will call longjmp which will unwind the stack into
+        // the VM dispatch loop.
+        sys::mrb_sys_raise(mrb, "Interrupt\0".as_ptr().cast::<i8>(), "Interrupt\0".as_ptr().cast::<i8>());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::test::prelude::*;
+    use crate::InterruptHandle;
+
+    #[test]
+    fn interrupt_handle_is_send_and_sync() {
+        fn check_send<T: Send>() {}
+        fn check_sync<T: Sync>() {}
+
+        check_send::<InterruptHandle>();
+        check_sync::<InterruptHandle>();
+    }
+
+    #[test]
+    fn interrupt_aborts_infinite_loop() {
+        let mut interp = interpreter().unwrap();
+        let handle = interp.interrupt_handle().unwrap();
+        let watchdog = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            handle.interrupt();
+        });
+        let err = interp.eval(b"loop {}").unwrap_err();
+        assert_eq!("Interrupt", err.name().as_ref());
+        watchdog.join().unwrap();
+
+        // The interrupt flag is consumed when the exception is raised, so
+        // subsequent evals run to completion.
+        let result = interp.eval(b"2 + 2").unwrap();
+        assert_eq!(4, result.try_convert_into::<i64>(&interp).unwrap());
+    }
+
+    #[test]
+    fn eval_with_timeout_interrupts_infinite_loop() {
+        let mut interp = interpreter().unwrap();
+        let err = interp
+            .eval_with_timeout(b"loop {}", Duration::from_millis(200))
+            .unwrap_err();
+        assert_eq!("Interrupt", err.name().as_ref());
+
+        let result = interp.eval(b"2 + 2").unwrap();
+        assert_eq!(4, result.try_convert_into::<i64>(&interp).unwrap());
+    }
+
+    #[test]
+    fn eval_with_timeout_returns_result_before_deadline() {
+        let mut interp = interpreter().unwrap();
+        let result = interp.eval_with_timeout(b"2 + 2", Duration::from_secs(10)).unwrap();
+        assert_eq!(4, result.try_convert_into::<i64>(&interp).unwrap());
+
+        let result = interp.eval(b"2 + 2").unwrap();
+        assert_eq!(4, result.try_convert_into::<i64>(&interp).unwrap());
+    }
+
+    #[test]
+    fn ensure_blocks_run_when_eval_is_interrupted() {
+        let mut interp = interpreter().unwrap();
+        let code = b"$ensure_ran = false\nbegin\n  loop {}\nensure\n  $ensure_ran = true\nend";
+        let err = interp.eval_with_timeout(code, Duration::from_millis(200)).unwrap_err();
+        assert_eq!("Interrupt", err.name().as_ref());
+
+        let result = interp.eval(b"$ensure_ran").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+    }
+}
@@ -7,10 +7,31 @@ use crate::core::{ClassRegistry, Io, TryConvertMut};
 use crate::error::{Error, RubyException};
 use crate::extn::core::exception;
 use crate::ffi::InterpreterExtractError;
-use crate::state::output::Output;
+use crate::state::input::{self, Input};
+use crate::state::output::{self, Output};
 use crate::sys;
 use crate::Artichoke;
 
+impl Artichoke {
+    /// Replace the interpreter's input and output streams.
+    ///
+    /// The interpreter reads from and writes to the stdio streams of the
+    /// current process by default. Embedders can swap in in-memory streams —
+    /// [`input::Strategy::scripted`] and [`output::Strategy::captured`] — to
+    /// feed scripted lines to `Kernel#gets` and capture writes made by
+    /// `Kernel#puts` and friends.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be extracted, an error is returned.
+    pub fn set_io(&mut self, input: input::Strategy, output: output::Strategy) -> Result<(), Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.input = input;
+        state.output = output;
+        Ok(())
+    }
+}
+
 impl Io for Artichoke {
     type Error = Error;
 
@@ -41,6 +62,32 @@ impl Io for Artichoke {
         state.output.write_stdout(b"\n")?;
         Ok(())
     }
+
+    /// Writes the given bytes to the interpreter stderr stream.
+    ///
+    /// This implementation delegates to the underlying output strategy.
+    ///
+    /// # Errors
+    ///
+    /// If the output stream encounters an error, an error is returned.
+    fn print_err<T: AsRef<[u8]>>(&mut self, message: T) -> Result<(), Self::Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.output.write_stderr(message.as_ref())?;
+        Ok(())
+    }
+
+    /// Reads a line from the interpreter stdin stream.
+    ///
+    /// This implementation delegates to the underlying input strategy.
+    ///
+    /// # Errors
+    ///
+    /// If the input stream encounters an error, an error is returned.
+    fn read_line(&mut self) -> Result<Option<Vec<u8>>, Self::Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let line = state.input.read_line()?;
+        Ok(line)
+    }
 }
 
 #[derive(Debug)]
@@ -115,3 +162,46 @@ impl From<Box<IoError>> for Box<dyn RubyException> {
         exception
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::state::{input, output};
+    use crate::test::prelude::*;
+
+    #[test]
+    fn captured_output_collects_puts_and_print_writes() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_io(input::Strategy::new(), output::Strategy::captured())
+            .unwrap();
+        interp.eval(b"puts 'hello'; print 'wor', 'ld'").unwrap();
+        let state = interp.state.as_deref().unwrap();
+        let captured = state.output.as_captured().unwrap();
+        assert_eq!(captured.stdout(), b"hello\nworld");
+        assert_eq!(captured.stderr(), b"");
+    }
+
+    #[test]
+    fn print_err_writes_to_the_stderr_stream() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_io(input::Strategy::new(), output::Strategy::captured())
+            .unwrap();
+        interp.print_err(b"oh no").unwrap();
+        let state = interp.state.as_deref().unwrap();
+        let captured = state.output.as_captured().unwrap();
+        assert_eq!(captured.stdout(), b"");
+        assert_eq!(captured.stderr(), b"oh no");
+    }
+
+    #[test]
+    fn scripted_input_yields_lines_to_read_line() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .set_io(input::Strategy::scripted("first\nsecond"), output::Strategy::captured())
+            .unwrap();
+        assert_eq!(interp.read_line().unwrap().as_deref(), Some(&b"first\n"[..]));
+        assert_eq!(interp.read_line().unwrap().as_deref(), Some(&b"second"[..]));
+        assert_eq!(interp.read_line().unwrap(), None);
+    }
+}
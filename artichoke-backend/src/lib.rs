@@ -114,9 +114,11 @@ pub mod macros;
 
 mod artichoke;
 pub mod block;
+pub mod call_stack;
 pub mod class;
 pub mod class_registry;
 mod coerce_to_numeric;
+mod compare;
 mod constant;
 pub mod convert;
 mod debug;
@@ -125,12 +127,14 @@ pub mod error;
 mod eval;
 pub mod exception_handler;
 pub mod extn;
+mod feature_trace;
 pub mod ffi;
 pub mod gc;
 mod globals;
 mod hash;
 mod intern;
 mod interpreter;
+mod interrupt;
 mod io;
 mod load;
 pub mod load_path;
@@ -141,6 +145,7 @@ mod parser;
 pub mod platform_string;
 #[cfg(feature = "core-random")]
 mod prng;
+mod process;
 mod regexp;
 pub mod release_metadata;
 pub mod state;
@@ -158,7 +163,9 @@ pub use artichoke_core::prelude as core;
 
 pub use crate::artichoke::{Artichoke, Guard};
 pub use crate::error::{Error, RubyException};
+pub use crate::feature_trace::{FeatureEvent, FeatureTraceId};
 pub use crate::interpreter::{interpreter, interpreter_with_config};
+pub use crate::interrupt::InterruptHandle;
 
 /// A "prelude" for users of the `artichoke-backend` crate.
 ///
@@ -4,11 +4,24 @@ use std::path::Path;
 
 use crate::core::{Eval, File, LoadSources};
 use crate::error::Error;
+use crate::feature_trace::FeatureEvent;
 use crate::ffi::InterpreterExtractError;
+use crate::platform_string::{bytes_to_os_str, os_str_to_bytes};
+use crate::state::State;
 use crate::Artichoke;
 
 const RUBY_EXTENSION: &str = "rb";
 
+/// Resolve the absolutized feature path recorded for `path` in the loaded
+/// feature set, falling back to the byte representation of `path` itself.
+fn feature_path(state: &State, path: &Path) -> Result<Vec<u8>, Error> {
+    if let Some(feature) = state.load_path_vfs.resolve_file(path) {
+        Ok(feature)
+    } else {
+        Ok(os_str_to_bytes(path.as_os_str())?.to_vec())
+    }
+}
+
 impl LoadSources for Artichoke {
     type Artichoke = Self;
     type Error = Error;
@@ -123,6 +136,9 @@ impl LoadSources for Artichoke {
         };
         let contents = self.read_source_file_contents(path)?.into_owned();
         self.eval(contents.as_ref())?;
+        let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        let feature = feature_path(state, path)?;
+        self.record_feature_event(FeatureEvent::Load(feature))?;
         trace!("Successful load of {}", path.display());
         Ok(true)
     }
@@ -167,6 +183,8 @@ impl LoadSources for Artichoke {
                             self.eval(&contents)?;
                             let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
                             state.load_path_vfs.mark_required(path)?;
+                            let feature = feature_path(state, path)?;
+                            self.record_feature_event(FeatureEvent::Require(feature))?;
                             trace!("Successful require of {}", path.display());
                             return Ok(true);
                         }
@@ -182,10 +200,24 @@ impl LoadSources for Artichoke {
         self.eval(contents.as_ref())?;
         let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
         state.load_path_vfs.mark_required(path)?;
+        let feature = feature_path(state, path)?;
+        self.record_feature_event(FeatureEvent::Require(feature))?;
         trace!("Successful require of {}", path.display());
         Ok(true)
     }
 
+    fn unload_feature(&mut self, path: &[u8]) -> Result<bool, Self::Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let path = bytes_to_os_str(path)?;
+        let removed = state.load_path_vfs.unload_feature(Path::new(path));
+        Ok(removed.unwrap_or_default())
+    }
+
+    fn loaded_features(&self) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        Ok(state.load_path_vfs.loaded_features())
+    }
+
     fn read_source_file_contents<P>(&self, path: P) -> Result<Cow<'_, [u8]>, Self::Error>
     where
         P: AsRef<Path>,
@@ -196,3 +228,59 @@ impl LoadSources for Artichoke {
         Ok(contents.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bstr::ByteSlice;
+
+    use crate::test::prelude::*;
+
+    #[test]
+    fn loaded_features_records_required_sources() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .def_rb_source_file("feature.rb", &b"# feature"[..])
+            .unwrap();
+        assert!(!interp
+            .loaded_features()
+            .unwrap()
+            .iter()
+            .any(|feature| feature.ends_with_str("feature.rb")));
+        interp.eval(b"require 'feature'").unwrap();
+        assert!(interp
+            .loaded_features()
+            .unwrap()
+            .iter()
+            .any(|feature| feature.ends_with_str("feature.rb")));
+    }
+
+    #[test]
+    fn unload_feature_allows_re_require() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .def_rb_source_file("counter.rb", &b"$counter = $counter.to_i + 1"[..])
+            .unwrap();
+
+        let result = interp.eval(b"require 'counter'").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"require 'counter'").unwrap();
+        assert!(!result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"$counter").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 1);
+
+        let feature = interp
+            .loaded_features()
+            .unwrap()
+            .into_iter()
+            .find(|feature| feature.ends_with_str("counter.rb"))
+            .unwrap();
+        assert!(interp.unload_feature(&feature).unwrap());
+        // Unloading a feature that is not loaded is a no-op.
+        assert!(!interp.unload_feature(&feature).unwrap());
+
+        let result = interp.eval(b"require 'counter'").unwrap();
+        assert!(result.try_convert_into::<bool>(&interp).unwrap());
+        let result = interp.eval(b"$counter").unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 2);
+    }
+}
@@ -101,7 +101,7 @@ where
 }
 
 pub fn normalize_slashes(path: PathBuf) -> Result<Vec<u8>, ConvertBytesError> {
-    let mut path = Vec::from_path_buf(path).map_err(|_| ConvertBytesError::new())?;
+    let mut path = Vec::from_path_buf(path).map_err(|path| ConvertBytesError::with_platform_string(path.as_os_str()))?;
     if cfg!(windows) {
         for byte in &mut path {
             if *byte == b'\\' {
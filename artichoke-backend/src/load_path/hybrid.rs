@@ -213,6 +213,52 @@ impl Hybrid {
                 .or_else(|_| self.native.mark_required(path))
         }
     }
+
+    /// Remove a source at `path` from the set of loaded features.
+    ///
+    /// Returns `true` if the source was previously marked as required and has
+    /// been removed, which permits `Kernel#require` to load it again.
+    ///
+    /// This API is infallible and will return [`None`] for paths that do not
+    /// map to any of the underlying file systems.
+    pub fn unload_feature(&mut self, path: &Path) -> Option<bool> {
+        if is_explicit_relative(path) {
+            if let Some(removed) = self.memory.unload_feature(path) {
+                return Some(removed);
+            }
+            return self.native.unload_feature(path);
+        }
+        if let Some(ref mut rubylib) = self.rubylib {
+            if let Some(removed) = rubylib.unload_feature(path) {
+                return Some(removed);
+            }
+        }
+        if let Some(removed) = self.memory.unload_feature(path) {
+            Some(removed)
+        } else {
+            self.native.unload_feature(path)
+        }
+    }
+
+    /// Retrieve the set of sources that have been marked as required.
+    ///
+    /// Each entry is the absolutized path a feature was required at. Entries
+    /// are returned in an arbitrary order.
+    #[must_use]
+    pub fn loaded_features(&self) -> Vec<Vec<u8>> {
+        let mut features = self.memory.loaded_features();
+        features.extend(self.native.loaded_features());
+        #[cfg(feature = "load-path-rubylib-native-file-system-loader")]
+        if let Some(ref rubylib) = self.rubylib {
+            features.extend(
+                rubylib
+                    .loaded_features()
+                    .into_iter()
+                    .filter_map(|path| os_string_to_bytes(path.to_owned().into()).ok()),
+            );
+        }
+        features
+    }
 }
 
 /// Test for relative paths that start with `.` or `..`.
@@ -230,9 +276,64 @@ fn is_explicit_relative(path: &Path) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use std::env;
+    use std::fs;
     use std::path::Path;
 
-    use super::is_explicit_relative;
+    use super::{is_explicit_relative, Hybrid, Native};
+    use crate::load_path::RUBY_LOAD_PATH;
+
+    #[test]
+    fn memory_shadows_native_for_virtual_paths() {
+        let mut hybrid = Hybrid::new();
+        let path = Path::new(RUBY_LOAD_PATH).join("memory_only.rb");
+        hybrid.write_file(&path, (&b"# memory only"[..]).into()).unwrap();
+        assert!(hybrid.is_file(&path));
+        assert_eq!(hybrid.read_file(&path).unwrap(), b"# memory only");
+        // Virtual paths are not visible to the native loader, so sources
+        // registered in memory cannot be shadowed by files on the host file
+        // system.
+        let native = Native::new();
+        assert!(!native.is_file(&path));
+    }
+
+    #[test]
+    fn native_files_are_readable_through_the_facade() {
+        let dir = env::temp_dir().join(format!(
+            "artichoke-hybrid-loader-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("disk.rb");
+        fs::write(&path, b"# disk").unwrap();
+
+        let hybrid = Hybrid::new();
+        assert!(hybrid.is_file(&path));
+        assert_eq!(hybrid.read_file(&path).unwrap(), b"# disk");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn required_virtual_paths_are_tracked() {
+        let mut hybrid = Hybrid::new();
+        let path = Path::new(RUBY_LOAD_PATH).join("feature.rb");
+        hybrid.write_file(&path, (&b"# feature"[..]).into()).unwrap();
+        assert_eq!(hybrid.is_required(&path), Some(false));
+        hybrid.mark_required(&path).unwrap();
+        assert_eq!(hybrid.is_required(&path), Some(true));
+    }
+
+    #[test]
+    fn writes_outside_the_virtual_root_are_rejected() {
+        let mut hybrid = Hybrid::new();
+        let path = if cfg!(windows) {
+            Path::new("c:/not/the/virtual/root/feature.rb")
+        } else {
+            Path::new("/not/the/virtual/root/feature.rb")
+        };
+        assert!(hybrid.write_file(path, (&b"# nope"[..]).into()).is_err());
+    }
 
     #[test]
     #[cfg(windows)]
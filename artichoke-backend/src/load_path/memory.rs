@@ -460,6 +460,34 @@ impl Memory {
             Err(err) => Err(io::Error::new(io::ErrorKind::NotFound, err)),
         }
     }
+
+    /// Remove a source at `path` from the set of loaded features.
+    ///
+    /// Returns `true` if the source was previously marked as required and has
+    /// been removed, which permits `Kernel#require` to load it again.
+    ///
+    /// This API is infallible and will return [`None`] for paths that do not
+    /// map to the virtual file system.
+    pub fn unload_feature(&mut self, path: &Path) -> Option<bool> {
+        let path = absolutize_relative_to(path, &self.cwd);
+        if path.strip_prefix(RUBY_LOAD_PATH).is_err() {
+            return None;
+        }
+        if let Ok(path) = normalize_slashes(path) {
+            Some(self.loaded_features.remove(path.as_bstr()))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the set of sources that have been marked as required.
+    ///
+    /// Each entry is the absolutized path a feature was required at. Entries
+    /// are returned in an arbitrary order.
+    #[must_use]
+    pub fn loaded_features(&self) -> Vec<Vec<u8>> {
+        self.loaded_features.iter().map(|path| path.to_vec()).collect()
+    }
 }
 
 #[cfg(test)]
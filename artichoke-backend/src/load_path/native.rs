@@ -49,6 +49,49 @@ impl Native {
         }
     }
 
+    /// Resolve a `require` name against an ordered list of load path entries.
+    ///
+    /// If `name` is an absolute path to an existing file, it is returned
+    /// unchanged. Otherwise, each entry in `load_path` is tried in order. The
+    /// name is joined to the entry and probed both as given and with a `.rb`
+    /// extension appended. Directories do not satisfy a `require`, even if
+    /// they match the name exactly, so a directory cannot shadow a Ruby
+    /// source of the same name.
+    ///
+    /// Returned paths are absolutized relative to the current working
+    /// directory and dedotted, which means the same file resolved via two
+    /// spellings yields the same path and is only marked as loaded once by
+    /// [`mark_required`].
+    ///
+    /// This API is infallible and will return [`None`] if the name cannot be
+    /// resolved to a file.
+    ///
+    /// [`mark_required`]: Self::mark_required
+    #[must_use]
+    pub fn resolve_require(&self, name: &Path, load_path: &[PathBuf]) -> Option<PathBuf> {
+        if name.is_absolute() {
+            return if self.is_file(name) { Some(name.to_owned()) } else { None };
+        }
+        let cwd = env::current_dir().ok()?;
+        for entry in load_path {
+            let entry = absolutize_relative_to(entry, &cwd);
+            let path = absolutize_relative_to(name, &entry);
+            if self.is_file(&path) {
+                return Some(path);
+            }
+            // `require 'foo'` in MRI loads `foo.rb` from the load path. The
+            // extension is appended rather than set so a name like `foo.bar`
+            // probes `foo.bar.rb` instead of replacing the "extension".
+            let mut with_ext = path.into_os_string();
+            with_ext.push(".rb");
+            let path = PathBuf::from(with_ext);
+            if self.is_file(&path) {
+                return Some(path);
+            }
+        }
+        None
+    }
+
     /// Read file contents for the file at `path`.
     ///
     /// Returns a byte slice of complete file contents. If `path` is relative,
@@ -98,4 +141,145 @@ impl Native {
         self.loaded_features.insert(path.into());
         Ok(())
     }
+
+    /// Remove a source at `path` from the set of loaded features.
+    ///
+    /// Returns `true` if the source was previously marked as required and has
+    /// been removed, which permits `Kernel#require` to load it again.
+    ///
+    /// This API is infallible and will return [`None`] for paths that cannot
+    /// be absolutized.
+    pub fn unload_feature(&mut self, path: &Path) -> Option<bool> {
+        let path = if let Ok(cwd) = env::current_dir() {
+            absolutize_relative_to(path, &cwd)
+        } else {
+            return None;
+        };
+        if let Ok(path) = normalize_slashes(path) {
+            Some(self.loaded_features.remove(path.as_bstr()))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the set of sources that have been marked as required.
+    ///
+    /// Each entry is the absolutized path a feature was required at. Entries
+    /// are returned in an arbitrary order.
+    #[must_use]
+    pub fn loaded_features(&self) -> Vec<Vec<u8>> {
+        self.loaded_features.iter().map(|path| path.to_vec()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::Native;
+
+    // A scratch directory on the host file system with the layout:
+    //
+    // ```text
+    // lib/
+    //   foo/
+    //   foo.rb
+    //   bar
+    // ```
+    //
+    // The fixture tree is removed when the `Fixture` is dropped.
+    struct Fixture {
+        root: PathBuf,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            static ID: AtomicUsize = AtomicUsize::new(0);
+
+            let unique = format!(
+                "artichoke-native-loader-test-{}-{}",
+                std::process::id(),
+                ID.fetch_add(1, Ordering::SeqCst)
+            );
+            let root = env::temp_dir().join(unique);
+            fs::create_dir_all(root.join("lib").join("foo")).unwrap();
+            fs::write(root.join("lib").join("foo.rb"), b"# foo").unwrap();
+            fs::write(root.join("lib").join("bar"), b"# bar").unwrap();
+            Self { root }
+        }
+
+        fn load_path(&self) -> Vec<PathBuf> {
+            vec![self.root.join("lib")]
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.root);
+        }
+    }
+
+    #[test]
+    fn resolve_require_infers_rb_extension() {
+        let fixture = Fixture::new();
+        let native = Native::new();
+        let resolved = native.resolve_require(Path::new("foo"), &fixture.load_path());
+        assert_eq!(resolved, Some(fixture.root.join("lib").join("foo.rb")));
+    }
+
+    #[test]
+    fn resolve_require_finds_exact_name() {
+        let fixture = Fixture::new();
+        let native = Native::new();
+        let resolved = native.resolve_require(Path::new("bar"), &fixture.load_path());
+        assert_eq!(resolved, Some(fixture.root.join("lib").join("bar")));
+    }
+
+    #[test]
+    fn resolve_require_skips_directory_shadowing_source() {
+        let fixture = Fixture::new();
+        let native = Native::new();
+        // `lib/foo` is a directory, which does not satisfy `require 'foo'`;
+        // the sibling `lib/foo.rb` does.
+        let resolved = native.resolve_require(Path::new("foo"), &fixture.load_path());
+        assert_eq!(resolved, Some(fixture.root.join("lib").join("foo.rb")));
+        // A directory with no sibling source does not resolve at all.
+        fs::create_dir_all(fixture.root.join("lib").join("baz")).unwrap();
+        let resolved = native.resolve_require(Path::new("baz"), &fixture.load_path());
+        assert_eq!(resolved, None);
+    }
+
+    #[test]
+    fn resolve_require_absolute_path_is_returned_unchanged() {
+        let fixture = Fixture::new();
+        let native = Native::new();
+        let absolute = fixture.root.join("lib").join("foo.rb");
+        let resolved = native.resolve_require(&absolute, &[]);
+        assert_eq!(resolved, Some(absolute));
+
+        let missing = fixture.root.join("lib").join("missing.rb");
+        let resolved = native.resolve_require(&missing, &fixture.load_path());
+        assert_eq!(resolved, None);
+    }
+
+    #[test]
+    fn resolve_require_collapses_spellings_to_one_feature() {
+        let fixture = Fixture::new();
+        let mut native = Native::new();
+        let canonical = fixture.load_path();
+        // The same directory spelled with a dedottable path.
+        let dotted = vec![fixture.root.join("lib").join("foo").join("..")];
+
+        let first = native.resolve_require(Path::new("foo"), &canonical).unwrap();
+        let second = native.resolve_require(Path::new("foo"), &dotted).unwrap();
+        assert_eq!(first, second);
+
+        assert_eq!(native.is_required(&first), Some(false));
+        native.mark_required(&first).unwrap();
+        assert_eq!(native.is_required(&first), Some(true));
+        assert_eq!(native.is_required(&second), Some(true));
+    }
 }
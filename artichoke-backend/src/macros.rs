@@ -56,6 +56,8 @@ pub mod argspec {
     pub const NONE: &[u8] = b"\0";
     pub const REQ1: &[u8] = b"o\0";
     pub const OPT1: &[u8] = b"|o\0";
+    pub const OPT1_OPTBLOCK: &[u8] = b"&|o?\0";
+    pub const REQBLOCK: &[u8] = b"&\0";
     pub const REQ1_OPT1: &[u8] = b"o|o\0";
     pub const REQ1_OPT2: &[u8] = b"o|oo\0";
     pub const REQ1_REQBLOCK: &[u8] = b"o&\0";
@@ -65,6 +67,7 @@ pub mod argspec {
     pub const OPT2_OPTBLOCK: &[u8] = b"&|o?o?\0";
     pub const REQ2_OPT1: &[u8] = b"oo|o\0";
     pub const REST: &[u8] = b"*\0";
+    pub const REST_OPTBLOCK: &[u8] = b"*&\0";
 }
 
 /// Extract [`sys::mrb_value`]s from a [`sys::mrb_state`] to adapt a C
@@ -111,6 +114,32 @@ macro_rules! mrb_get_args {
             _ => unreachable!("mrb_get_args should have raised"),
         }
     }};
+    ($mrb:expr, optional = 1, &block) => {{
+        let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let mut has_opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_bool>::uninit();
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::OPT1_OPTBLOCK.as_ptr() as *const i8,
+            block.as_mut_ptr(),
+            opt1.as_mut_ptr(),
+            has_opt1.as_mut_ptr(),
+        );
+        let has_opt1 = has_opt1.assume_init() != 0;
+        let opt1 = if has_opt1 { Some(opt1.assume_init()) } else { None };
+        let block = block.assume_init();
+        (opt1, $crate::block::Block::new(block))
+    }};
+    ($mrb:expr, &block) => {{
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::REQBLOCK.as_ptr() as *const i8,
+            block.as_mut_ptr(),
+        );
+        let block = block.assume_init();
+        $crate::block::Block::new(block)
+    }};
     ($mrb:expr, required = 1, optional = 1) => {{
         let mut req1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
         let mut opt1 = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
@@ -316,4 +345,18 @@ macro_rules! mrb_get_args {
         );
         std::slice::from_raw_parts(args.assume_init(), count.assume_init())
     }};
+    ($mrb:expr, *args, &block) => {{
+        let mut args = std::mem::MaybeUninit::<*const $crate::sys::mrb_value>::uninit();
+        let mut count = std::mem::MaybeUninit::<usize>::uninit();
+        let mut block = std::mem::MaybeUninit::<$crate::sys::mrb_value>::uninit();
+        let _argc = $crate::sys::mrb_get_args(
+            $mrb,
+            $crate::macros::argspec::REST_OPTBLOCK.as_ptr() as *const i8,
+            args.as_mut_ptr(),
+            count.as_mut_ptr(),
+            block.as_mut_ptr(),
+        );
+        let args = std::slice::from_raw_parts(args.assume_init(), count.assume_init());
+        (args, $crate::block::Block::new(block.assume_init()))
+    }};
 }
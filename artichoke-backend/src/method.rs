@@ -6,6 +6,9 @@ use crate::def::{ConstantNameError, Method, NotDefinedError};
 use crate::sys;
 use crate::Artichoke;
 
+pub mod closure;
+pub mod signature;
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Type {
     Class,
@@ -0,0 +1,361 @@
+//! Define Ruby methods backed by Rust closures.
+//!
+//! Extension methods are typically free functions with the mruby C ABI
+//! signature. Closure-backed methods allow defining small behaviors that are
+//! parameterized at init time, e.g. methods generated per interpreter
+//! configuration, by capturing state in a Rust closure.
+//!
+//! Closures are registered with [`Builder::add_closure_method`] and invoked by
+//! a shared trampoline which looks up the closure for the method being called.
+//! Closures are stored in the interpreter [`State`], which is plain Rust
+//! memory owned by the interpreter: the closure table lives as long as the
+//! interpreter and is invisible to the Ruby garbage collector.
+//!
+//! Panics in a closure are caught at the FFI boundary and converted into Ruby
+//! `RuntimeError` exceptions rather than unwinding across the trampoline.
+//!
+//! [`Builder::add_closure_method`]: crate::class::Builder::add_closure_method
+//! [`State`]: crate::state::State
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use crate::def::ConstantNameError;
+use crate::error::{self, Error};
+use crate::extn::core::exception::{ArgumentError, RuntimeError};
+use crate::ffi::InterpreterExtractError;
+use crate::sys;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// Boxed Rust closure that implements a Ruby method.
+///
+/// The closure receives the interpreter, the receiver (`self`), and the
+/// positional arguments of the method call. Errors returned from the closure
+/// are raised as Ruby exceptions.
+pub type Closure = Box<dyn Fn(&mut Artichoke, Value, &[Value]) -> Result<Value, Error> + 'static>;
+
+/// Key identifying a closure-backed method in the interpreter [`State`].
+///
+/// Methods are keyed by the class or module they are defined on and the
+/// interned method name. This pair is recoverable from the mruby callinfo in
+/// the trampoline, which is how the trampoline locates the closure for the
+/// method being invoked.
+///
+/// [`State`]: crate::state::State
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct MethodKey {
+    target_class: *const sys::RClass,
+    method: sys::mrb_sym,
+}
+
+struct Entry {
+    args: sys::mrb_aspec,
+    closure: Rc<dyn Fn(&mut Artichoke, Value, &[Value]) -> Result<Value, Error> + 'static>,
+}
+
+/// Interpreter-level table of closure-backed methods.
+///
+/// The table is stored in the interpreter [`State`] and is dropped with it,
+/// which guarantees registered closures outlive every Ruby object that can
+/// invoke them. Because the table is not reachable from the Ruby heap, it is
+/// excluded from garbage collection.
+///
+/// [`State`]: crate::state::State
+#[derive(Default)]
+pub struct Registry(HashMap<MethodKey, Entry>);
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry").field("methods", &self.0.len()).finish()
+    }
+}
+
+impl Registry {
+    /// Construct a new, empty `Registry`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A closure-backed method waiting to be defined on a class or module.
+///
+/// This is the closure analog to [`method::Spec`] and is constructed by
+/// [`Builder::add_closure_method`].
+///
+/// [`method::Spec`]: crate::method::Spec
+/// [`Builder::add_closure_method`]: crate::class::Builder::add_closure_method
+pub struct ClosureMethod {
+    name: Cow<'static, str>,
+    cstring: Box<CStr>,
+    args: sys::mrb_aspec,
+    closure: Closure,
+}
+
+impl fmt::Debug for ClosureMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClosureMethod")
+            .field("name", &self.name)
+            .field("args", &self.args)
+            .field("closure", &"<closure>")
+            .finish()
+    }
+}
+
+impl ClosureMethod {
+    pub fn new<T>(name: T, closure: Closure, args: sys::mrb_aspec) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let name = name.into();
+        if let Ok(cstring) = CString::new(name.as_ref()) {
+            Ok(Self {
+                name,
+                cstring: cstring.into_boxed_c_str(),
+                args,
+                closure,
+            })
+        } else {
+            Err(name.into())
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Cow<'static, str> {
+        match &self.name {
+            Cow::Borrowed(name) => Cow::Borrowed(name),
+            Cow::Owned(name) => name.clone().into(),
+        }
+    }
+
+    /// Define this method on the class-like pointed to by `into`.
+    ///
+    /// The closure is stored in the interpreter [`State`] keyed by `into` and
+    /// the interned method name, and the method is defined with a shared
+    /// trampoline which dispatches to the stored closure.
+    ///
+    /// Only instance methods are supported: the trampoline identifies the
+    /// closure by the class the method invocation resolves to, which for
+    /// class-level and module-level methods is a singleton class rather than
+    /// `into`.
+    ///
+    /// # Safety
+    ///
+    /// This method requires that `into` is non-null and points to a valid
+    /// [`sys::RClass`].
+    ///
+    /// [`State`]: crate::state::State
+    pub(crate) unsafe fn define(self, interp: &mut Artichoke, into: &mut sys::RClass) -> Result<(), Error> {
+        let method = interp.intern_bytes_with_trailing_nul(self.cstring.to_bytes_with_nul().to_vec())?;
+        let target_class: *const sys::RClass = into;
+        let state = interp.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let key = MethodKey { target_class, method };
+        let entry = Entry {
+            args: self.args,
+            closure: Rc::from(self.closure),
+        };
+        state.method_closures.0.insert(key, entry);
+        interp.with_ffi_boundary(|mrb| {
+            sys::mrb_define_method(mrb, into, self.cstring.as_ptr(), Some(closure_trampoline), self.args);
+        })?;
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn closure_trampoline(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+    let args = mrb_get_args!(mrb, *args);
+    // The callinfo for the currently executing method records the method
+    // symbol and the class the method invocation resolved to. Together these
+    // identify the closure to dispatch to.
+    //
+    // This is a plain memory read of `mrb_state` fields, no `MRB_API`
+    // functions are called.
+    let ci = (*(*mrb).c).ci;
+    let method = (*ci).mid;
+    let target_class: *const sys::RClass = (*ci).u.target_class;
+    unwrap_interpreter!(mrb, to => guard);
+    // Panics must not unwind across the FFI boundary into the mruby VM, which
+    // is undefined behavior. Convert them into `RuntimeError`s instead.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        dispatch(&mut guard, target_class, method, Value::from(slf), args)
+    }));
+    match result {
+        Ok(Ok(value)) => value.inner(),
+        Ok(Err(exception)) => error::raise(guard, exception),
+        Err(cause) => {
+            let message = if let Some(message) = cause.downcast_ref::<&'static str>() {
+                String::from(*message)
+            } else if let Some(message) = cause.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                String::from("panic in closure-backed method")
+            };
+            error::raise(guard, RuntimeError::from(message))
+        }
+    }
+}
+
+fn dispatch(
+    interp: &mut Artichoke,
+    target_class: *const sys::RClass,
+    method: sys::mrb_sym,
+    slf: Value,
+    args: &[sys::mrb_value],
+) -> Result<Value, Error> {
+    let state = interp.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+    let key = MethodKey { target_class, method };
+    let entry = state
+        .method_closures
+        .0
+        .get(&key)
+        .ok_or_else(|| RuntimeError::with_message("closure for method call is not registered"))?;
+    // Clone the closure out of the `State` so re-entrant calls, e.g. the
+    // closure calling back into `eval`, can look up other closure-backed
+    // methods while this one is executing.
+    let closure = Rc::clone(&entry.closure);
+    check_arity(args.len(), entry.args)?;
+    let args = args.iter().copied().map(Value::from).collect::<Vec<_>>();
+    closure(interp, slf, &args)
+}
+
+/// Enforce the method arity declared in an [`sys::mrb_aspec`].
+///
+/// mruby only enforces arity for C functions through `mrb_get_args` format
+/// strings. The trampoline collects arguments with a rest format specifier, so
+/// the declared aspec is enforced here instead.
+fn check_arity(given: usize, args: sys::mrb_aspec) -> Result<(), Error> {
+    // `mrb_aspec` is a bitfield: required argument count is stored in bits
+    // 18..23, optional argument count in bits 13..18, and the rest argument
+    // flag in bit 12. See `MRB_ARGS_REQ`, `MRB_ARGS_OPT`, and `MRB_ARGS_REST`
+    // in `mruby.h`.
+    let required = usize::try_from((args >> 18) & 0x1f).unwrap_or_default();
+    let optional = usize::try_from((args >> 13) & 0x1f).unwrap_or_default();
+    let rest = args & (1 << 12) != 0;
+    if given < required || (!rest && given > required + optional) {
+        let expected = match (optional, rest) {
+            (0, false) => required.to_string(),
+            (_, false) => format!("{}..{}", required, required + optional),
+            (_, true) => format!("{}+", required),
+        };
+        let message = format!("wrong number of arguments (given {}, expected {})", given, expected);
+        return Err(ArgumentError::from(message).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[derive(Debug)]
+    struct Greeter;
+
+    // An example extension converted to the closure API: the greeting is
+    // configuration captured at init time instead of being hard-coded in a
+    // free function with the mruby C ABI signature.
+    fn define_greeter(interp: &mut Artichoke, greeting: &str) {
+        let spec = class::Spec::new("Greeter", cstr::cstr!("Greeter"), None, None).unwrap();
+        let greeting = greeting.to_owned();
+        class::Builder::for_spec(interp, &spec)
+            .add_closure_method(
+                "greet",
+                Box::new(move |interp, _slf, args| {
+                    let mut name = args[0];
+                    let name = name.try_convert_into_mut::<Vec<u8>>(interp)?;
+                    let mut message = greeting.clone().into_bytes();
+                    message.extend_from_slice(b", ");
+                    message.extend_from_slice(&name);
+                    interp.try_convert_mut(message)
+                }),
+                sys::mrb_args_req(1),
+            )
+            .unwrap()
+            .add_closure_method(
+                "fail",
+                Box::new(|_interp, _slf, _args| Err(RuntimeError::with_message("closure failure").into())),
+                sys::mrb_args_none(),
+            )
+            .unwrap()
+            .add_closure_method(
+                "panic",
+                Box::new(|_interp, _slf, _args| panic!("closure panic")),
+                sys::mrb_args_none(),
+            )
+            .unwrap()
+            .add_closure_method(
+                "reenter",
+                Box::new(|interp, _slf, _args| interp.eval(b"Greeter.new.greet('nested')")),
+                sys::mrb_args_none(),
+            )
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Greeter>(spec).unwrap();
+    }
+
+    #[test]
+    fn closure_captures_init_time_configuration() {
+        let mut interp = interpreter().unwrap();
+        define_greeter(&mut interp, "Hello");
+        let result = interp.eval(b"Greeter.new.greet('world')").unwrap();
+        let result = result.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(result, "Hello, world");
+    }
+
+    #[test]
+    fn arity_is_enforced() {
+        let mut interp = interpreter().unwrap();
+        define_greeter(&mut interp, "Hello");
+        let err = interp.eval(b"Greeter.new.greet").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+        assert_eq!(
+            err.message().as_ref(),
+            &b"wrong number of arguments (given 0, expected 1)"[..]
+        );
+        let err = interp.eval(b"Greeter.new.greet('a', 'b')").unwrap_err();
+        assert_eq!("ArgumentError", err.name().as_ref());
+    }
+
+    #[test]
+    fn errors_from_closures_are_raised() {
+        let mut interp = interpreter().unwrap();
+        define_greeter(&mut interp, "Hello");
+        let err = interp.eval(b"Greeter.new.fail").unwrap_err();
+        assert_eq!("RuntimeError", err.name().as_ref());
+        assert_eq!(err.message().as_ref(), &b"closure failure"[..]);
+    }
+
+    #[test]
+    fn panics_are_converted_to_runtime_error() {
+        let mut interp = interpreter().unwrap();
+        define_greeter(&mut interp, "Hello");
+        let err = interp.eval(b"Greeter.new.panic").unwrap_err();
+        assert_eq!("RuntimeError", err.name().as_ref());
+        assert_eq!(err.message().as_ref(), &b"closure panic"[..]);
+    }
+
+    #[test]
+    fn closures_can_reenter_the_interpreter() {
+        let mut interp = interpreter().unwrap();
+        define_greeter(&mut interp, "Hello");
+        let result = interp.eval(b"Greeter.new.reenter").unwrap();
+        let result = result.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(result, "Hello, nested");
+    }
+
+    #[test]
+    fn closures_are_rescuable_in_ruby() {
+        let mut interp = interpreter().unwrap();
+        define_greeter(&mut interp, "Hello");
+        let result = interp
+            .eval(b"begin; Greeter.new.fail; rescue RuntimeError => e; e.message; end")
+            .unwrap();
+        let result = result.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(result, "closure failure");
+    }
+}
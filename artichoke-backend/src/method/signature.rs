@@ -0,0 +1,286 @@
+//! Declared parameter specifications for Rust-defined Ruby methods.
+//!
+//! mruby derives `Method#arity` and `Method#parameters` from the method's
+//! underlying proc. For methods implemented in Rust, the proc is a C function
+//! and the best mruby can report is an arity of `-1` and a parameter list of
+//! `[[:rest]]`.
+//!
+//! Extensions can do better: the Ruby-visible signature of a Rust-defined
+//! method is known at registration time. [`Builder::add_method_signature`]
+//! records a parameter specification in the interpreter [`State`], keyed by
+//! the class or module the method is defined on and the interned method name.
+//! `Method#parameters` and `Method#arity` (and their `UnboundMethod`
+//! counterparts) consult this table before falling back to the proc-derived
+//! guess.
+//!
+//! Like the closure table, the signature table lives in plain Rust memory
+//! owned by the interpreter and is invisible to the Ruby garbage collector.
+//!
+//! [`Builder::add_method_signature`]: crate::class::Builder::add_method_signature
+//! [`State`]: crate::state::State
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+use crate::def::ConstantNameError;
+use crate::error::Error;
+use crate::ffi::InterpreterExtractError;
+use crate::sys;
+use crate::Artichoke;
+
+/// A single parameter in a method signature.
+///
+/// Variants correspond to the parameter kinds reported by MRI's
+/// `Method#parameters`: required and optional positional parameters, a rest
+/// parameter, required and optional keyword parameters, and a block parameter.
+/// Each variant carries the parameter name as it would appear in an equivalent
+/// Ruby method definition.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Parameter {
+    /// A required positional parameter, e.g. `width` in `center(width)`.
+    Req(&'static str),
+    /// An optional positional parameter, e.g. `padstr` in
+    /// `center(width, padstr = ' ')`.
+    Opt(&'static str),
+    /// A rest parameter, e.g. `args` in `push(*args)`.
+    Rest(&'static str),
+    /// A required keyword parameter, e.g. `base` in `convert(base:)`.
+    Keyreq(&'static str),
+    /// An optional keyword parameter, e.g. `exception` in
+    /// `Integer(arg, exception: true)`.
+    Key(&'static str),
+    /// A block parameter, e.g. `blk` in `each(&blk)`.
+    Block(&'static str),
+}
+
+impl Parameter {
+    /// The parameter kind as it appears in `Method#parameters`, e.g. `req`.
+    #[must_use]
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::Req(_) => "req",
+            Self::Opt(_) => "opt",
+            Self::Rest(_) => "rest",
+            Self::Keyreq(_) => "keyreq",
+            Self::Key(_) => "key",
+            Self::Block(_) => "block",
+        }
+    }
+
+    /// The parameter name as it appears in `Method#parameters`, e.g. `width`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Req(name)
+            | Self::Opt(name)
+            | Self::Rest(name)
+            | Self::Keyreq(name)
+            | Self::Key(name)
+            | Self::Block(name) => name,
+        }
+    }
+}
+
+/// An ordered parameter specification for a method.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Signature(Box<[Parameter]>);
+
+impl From<Vec<Parameter>> for Signature {
+    fn from(parameters: Vec<Parameter>) -> Self {
+        Self(parameters.into_boxed_slice())
+    }
+}
+
+impl Signature {
+    /// The parameters in this signature, in declaration order.
+    #[must_use]
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.0
+    }
+
+    /// The arity of a Ruby method with this signature.
+    ///
+    /// This follows the rules documented for MRI's `Method#arity`: methods
+    /// that accept a fixed number of arguments have a non-negative arity equal
+    /// to that number; methods that accept a variable number of arguments have
+    /// arity `-n-1` where `n` is the number of required arguments. Keyword
+    /// parameters collectively count as a single trailing argument, which is
+    /// required if any keyword parameter is required. Block parameters do not
+    /// contribute to arity.
+    #[must_use]
+    pub fn arity(&self) -> i64 {
+        let mut required = 0_i64;
+        let mut variable = false;
+        let mut optional_keywords = false;
+        let mut required_keywords = false;
+        for parameter in self.parameters() {
+            match parameter {
+                Parameter::Req(_) => required += 1,
+                Parameter::Opt(_) | Parameter::Rest(_) => variable = true,
+                Parameter::Keyreq(_) => required_keywords = true,
+                Parameter::Key(_) => optional_keywords = true,
+                Parameter::Block(_) => {}
+            }
+        }
+        if required_keywords {
+            required += 1;
+        }
+        if variable || (optional_keywords && !required_keywords) {
+            -required - 1
+        } else {
+            required
+        }
+    }
+}
+
+/// Key identifying a declared method signature in the interpreter [`State`].
+///
+/// Signatures are keyed by the class or module the method is defined on and
+/// the interned method name. This pair is recoverable from a `Method` or
+/// `UnboundMethod` object via its `owner` and `name`.
+///
+/// [`State`]: crate::state::State
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct SignatureKey {
+    owner: *const sys::RClass,
+    method: sys::mrb_sym,
+}
+
+/// Interpreter-level table of declared method signatures.
+///
+/// The table is stored in the interpreter [`State`] and is dropped with it.
+/// Because the table is not reachable from the Ruby heap, it is excluded from
+/// garbage collection.
+///
+/// [`State`]: crate::state::State
+#[derive(Default)]
+pub struct Registry(HashMap<SignatureKey, Signature>);
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry").field("signatures", &self.0.len()).finish()
+    }
+}
+
+impl Registry {
+    /// Construct a new, empty `Registry`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieve the signature declared for `method` on `owner`, if any.
+    #[must_use]
+    pub fn get(&self, owner: *const sys::RClass, method: sys::mrb_sym) -> Option<&Signature> {
+        let key = SignatureKey { owner, method };
+        self.0.get(&key)
+    }
+}
+
+/// A method signature waiting to be declared for a class or module.
+///
+/// This is the signature analog to [`method::Spec`] and is constructed by
+/// [`Builder::add_method_signature`].
+///
+/// [`method::Spec`]: crate::method::Spec
+/// [`Builder::add_method_signature`]: crate::class::Builder::add_method_signature
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct MethodSignature {
+    name: Cow<'static, str>,
+    cstring: Box<CStr>,
+    signature: Signature,
+}
+
+impl MethodSignature {
+    pub fn new<T>(name: T, signature: Signature) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let name = name.into();
+        if let Ok(cstring) = CString::new(name.as_ref()) {
+            Ok(Self {
+                name,
+                cstring: cstring.into_boxed_c_str(),
+                signature,
+            })
+        } else {
+            Err(name.into())
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Cow<'static, str> {
+        match &self.name {
+            Cow::Borrowed(name) => Cow::Borrowed(name),
+            Cow::Owned(name) => name.clone().into(),
+        }
+    }
+
+    /// Record this signature for the class-like pointed to by `owner`.
+    ///
+    /// The signature is stored in the interpreter [`State`] keyed by `owner`
+    /// and the interned method name. Declaring a signature does not define a
+    /// method: the method may be implemented in Rust or in Ruby, and may be
+    /// defined before or after its signature is declared.
+    ///
+    /// [`State`]: crate::state::State
+    pub(crate) fn declare(self, interp: &mut Artichoke, owner: *const sys::RClass) -> Result<(), Error> {
+        let method = interp.intern_bytes_with_trailing_nul(self.cstring.to_bytes_with_nul().to_vec())?;
+        let state = interp.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let key = SignatureKey { owner, method };
+        state.method_signatures.0.insert(key, self.signature);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parameter, Signature};
+
+    #[test]
+    fn arity_of_fixed_signatures_is_the_required_count() {
+        let signature = Signature::from(vec![]);
+        assert_eq!(signature.arity(), 0);
+        let signature = Signature::from(vec![Parameter::Req("a"), Parameter::Req("b")]);
+        assert_eq!(signature.arity(), 2);
+    }
+
+    #[test]
+    fn optional_and_rest_parameters_make_arity_negative() {
+        let signature = Signature::from(vec![Parameter::Req("width"), Parameter::Opt("padstr")]);
+        assert_eq!(signature.arity(), -2);
+        let signature = Signature::from(vec![Parameter::Rest("args")]);
+        assert_eq!(signature.arity(), -1);
+        let signature = Signature::from(vec![Parameter::Req("a"), Parameter::Req("b"), Parameter::Rest("rest")]);
+        assert_eq!(signature.arity(), -3);
+    }
+
+    #[test]
+    fn keywords_count_as_a_single_trailing_argument() {
+        // A required keyword makes the keyword argument mandatory.
+        let signature = Signature::from(vec![Parameter::Req("a"), Parameter::Keyreq("b"), Parameter::Key("c")]);
+        assert_eq!(signature.arity(), 2);
+        // Optional keywords alone make the signature variable length.
+        let signature = Signature::from(vec![Parameter::Req("arg"), Parameter::Key("exception")]);
+        assert_eq!(signature.arity(), -2);
+    }
+
+    #[test]
+    fn block_parameters_do_not_contribute_to_arity() {
+        let signature = Signature::from(vec![Parameter::Req("a"), Parameter::Block("blk")]);
+        assert_eq!(signature.arity(), 1);
+    }
+
+    #[test]
+    fn parameter_kinds_and_names() {
+        assert_eq!(Parameter::Req("width").kind(), "req");
+        assert_eq!(Parameter::Req("width").name(), "width");
+        assert_eq!(Parameter::Opt("padstr").kind(), "opt");
+        assert_eq!(Parameter::Rest("args").kind(), "rest");
+        assert_eq!(Parameter::Keyreq("base").kind(), "keyreq");
+        assert_eq!(Parameter::Key("exception").kind(), "key");
+        assert_eq!(Parameter::Block("blk").kind(), "block");
+    }
+}
@@ -21,6 +21,7 @@ pub struct Builder<'a> {
     interp: &'a mut Artichoke,
     spec: &'a Spec,
     methods: HashSet<method::Spec>,
+    method_signatures: Vec<method::signature::MethodSignature>,
 }
 
 impl<'a> Builder<'a> {
@@ -30,6 +31,7 @@ impl<'a> Builder<'a> {
             interp,
             spec,
             methods: HashSet::default(),
+            method_signatures: Vec::new(),
         }
     }
 
@@ -42,6 +44,30 @@ impl<'a> Builder<'a> {
         Ok(self)
     }
 
+    /// Declare the parameter specification of an instance method.
+    ///
+    /// The signature is recorded in the interpreter state and is consulted by
+    /// `Method#parameters` and `Method#arity` before falling back to the
+    /// proc-derived guess. See [`method::signature`] for details.
+    ///
+    /// Declaring a signature does not define a method: the method may be
+    /// implemented in Rust, e.g. with [`add_method`], or in Ruby sources
+    /// evaluated during init.
+    ///
+    /// [`add_method`]: Self::add_method
+    pub fn add_method_signature<T>(
+        mut self,
+        name: T,
+        parameters: Vec<method::signature::Parameter>,
+    ) -> Result<Self, ConstantNameError>
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        let signature = method::signature::MethodSignature::new(name.into(), parameters.into())?;
+        self.method_signatures.push(signature);
+        Ok(self)
+    }
+
     pub fn add_self_method<T>(
         mut self,
         name: T,
@@ -101,6 +127,13 @@ impl<'a> Builder<'a> {
                 method.define(self.interp, rclass.as_mut())?;
             }
         }
+
+        for signature in self.method_signatures {
+            let name = signature.name();
+            signature
+                .declare(self.interp, rclass.as_ptr())
+                .map_err(|_| NotDefinedError::method(name))?;
+        }
         Ok(())
     }
 }
@@ -12,10 +12,55 @@ use crate::core::{ClassRegistry, IncrementLinenoError, Parser, TryConvertMut};
 use crate::error::{Error, RubyException};
 use crate::extn::core::exception::ScriptError;
 use crate::ffi::InterpreterExtractError;
-use crate::state::parser::Context;
+use crate::state::parser::{CodeParseState, Context};
 use crate::sys;
 use crate::Artichoke;
 
+impl Artichoke {
+    /// Classify whether `code` parses as a complete expression.
+    ///
+    /// This function invokes the mruby parser without executing the parsed
+    /// code. A REPL can use the classification to decide whether to show a
+    /// continuation prompt and keep reading input or to report a syntax error.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be extracted, an error is returned.
+    pub fn parse_state(&mut self, code: &[u8]) -> Result<CodeParseState, Error> {
+        let mrb = unsafe { self.mrb.as_mut() };
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let parser = state.parser.as_mut().ok_or_else(InterpreterExtractError::new)?;
+        Ok(parser.parse_state(mrb, code))
+    }
+
+    /// Interpreter-wide `frozen_string_literal` override, if one is set.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be extracted, an error is returned.
+    pub fn frozen_string_literal(&self) -> Result<Option<bool>, Error> {
+        let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        Ok(state.frozen_string_literal)
+    }
+
+    /// Set the interpreter-wide `frozen_string_literal` override.
+    ///
+    /// `Some(true)` and `Some(false)` force string literals to be frozen or
+    /// unfrozen regardless of per-file `frozen_string_literal` magic comments.
+    /// `None` restores the default behavior of honoring the magic comment.
+    /// The `ruby` CLI frontend maps the `--enable=frozen-string-literal` and
+    /// `--disable=frozen-string-literal` flags onto this setting.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be extracted, an error is returned.
+    pub fn set_frozen_string_literal(&mut self, frozen_string_literal: Option<bool>) -> Result<(), Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.frozen_string_literal = frozen_string_literal;
+        Ok(())
+    }
+}
+
 impl Parser for Artichoke {
     type Context = Context;
     type Error = Error;
@@ -3,6 +3,7 @@
 //! [platform strings]: OsString
 
 use core::fmt;
+use std::borrow::Cow;
 use std::error;
 use std::ffi::{OsStr, OsString};
 
@@ -31,7 +32,7 @@ mod impls;
 /// is returned.
 #[inline]
 pub fn bytes_to_os_str(value: &[u8]) -> Result<&OsStr, ConvertBytesError> {
-    let platform_string = value.to_os_str()?;
+    let platform_string = value.to_os_str().map_err(|_| ConvertBytesError::with_path(value))?;
     Ok(platform_string)
 }
 
@@ -56,7 +57,7 @@ pub fn bytes_to_os_str(value: &[u8]) -> Result<&OsStr, ConvertBytesError> {
 /// is returned.
 #[inline]
 pub fn os_str_to_bytes(value: &OsStr) -> Result<&[u8], ConvertBytesError> {
-    <[u8]>::from_os_str(value).ok_or_else(ConvertBytesError::new)
+    <[u8]>::from_os_str(value).ok_or_else(|| ConvertBytesError::with_platform_string(value))
 }
 
 /// Convert a platform-specific [`OsString`] to a byte vec.
@@ -94,10 +95,14 @@ pub fn os_string_to_bytes(value: OsString) -> Result<Vec<u8>, ConvertBytesError>
 /// This error is returned by [`bytes_to_os_str`], [`os_string_to_bytes`] and
 /// [`os_str_to_bytes`]. See their documentation for more details.
 ///
+/// When the conversion site can capture it, the error records the offending
+/// path so error messages and the raised Ruby `ArgumentError` can name the
+/// path that failed to convert.
+///
 /// [platform string]: OsString
-#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ConvertBytesError {
-    _private: (),
+    path: Option<Vec<u8>>,
 }
 
 impl ConvertBytesError {
@@ -113,29 +118,92 @@ impl ConvertBytesError {
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
-        Self { _private: () }
+        Self { path: None }
+    }
+
+    /// Construct a new `ConvertBytesError` which records the offending path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use artichoke_backend::platform_string::ConvertBytesError;
+    /// let err = ConvertBytesError::with_path(&b"/tmp/\xFF\xFEfixture.rb"[..]);
+    /// assert_eq!(err.path(), Some(&b"/tmp/\xFF\xFEfixture.rb"[..]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_path<T>(path: T) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        Self { path: Some(path.into()) }
+    }
+
+    /// Construct a new `ConvertBytesError` which records the offending
+    /// platform string.
+    ///
+    /// Platform strings which cannot be converted to bytes are captured
+    /// lossily, so the recorded path may contain U+FFFD replacement
+    /// characters.
+    #[inline]
+    #[must_use]
+    pub fn with_platform_string(path: &OsStr) -> Self {
+        let path = path.to_string_lossy().into_owned().into_bytes();
+        Self { path: Some(path) }
+    }
+
+    /// The offending path, if the conversion site captured it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use artichoke_backend::platform_string::ConvertBytesError;
+    /// assert_eq!(ConvertBytesError::new().path(), None);
+    /// assert_eq!(
+    ///     ConvertBytesError::with_path(&b"fixture.rb"[..]).path(),
+    ///     Some(&b"fixture.rb"[..])
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&[u8]> {
+        self.path.as_deref()
     }
 
     /// Retrieve the exception message associated with this convert bytes error.
     ///
+    /// If the conversion site captured the offending path, it is included in
+    /// the message.
+    ///
     /// # Examples
     ///
     /// ```
     /// # use artichoke_backend::platform_string::ConvertBytesError;
     /// let err = ConvertBytesError::new();
     /// assert_eq!(err.message(), "Could not convert between bytes and platform string");
+    ///
+    /// let err = ConvertBytesError::with_path(&b"fixture.rb"[..]);
+    /// assert_eq!(
+    ///     err.message(),
+    ///     "Could not convert between bytes and platform string - fixture.rb"
+    /// );
     /// ```
     #[inline]
     #[must_use]
-    #[allow(clippy::unused_self)]
-    pub const fn message(self) -> &'static str {
-        "Could not convert between bytes and platform string"
+    pub fn message(&self) -> Cow<'_, str> {
+        if let Some(ref path) = self.path {
+            let mut message = String::from("Could not convert between bytes and platform string - ");
+            message.push_str(&String::from_utf8_lossy(path));
+            message.into()
+        } else {
+            Cow::Borrowed("Could not convert between bytes and platform string")
+        }
     }
 }
 
 impl fmt::Display for ConvertBytesError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.message())
+        f.write_str(self.message().as_ref())
     }
 }
 
@@ -145,14 +213,74 @@ impl From<Utf8Error> for ConvertBytesError {
     #[inline]
     fn from(err: Utf8Error) -> Self {
         let _ = err;
-        Self { _private: () }
+        Self { path: None }
     }
 }
 
 impl From<OsString> for ConvertBytesError {
     #[inline]
     fn from(err: OsString) -> Self {
-        drop(err);
-        Self { _private: () }
+        Self::with_platform_string(&err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes_to_os_str, os_str_to_bytes, os_string_to_bytes, ConvertBytesError};
+
+    #[test]
+    #[cfg(unix)]
+    fn non_utf8_bytes_round_trip_on_unix() {
+        use std::ffi::OsString;
+        use std::os::unix::ffi::OsStringExt;
+
+        let bytes: &[u8] = b"/tmp/\xFF\xFEfixture.rb";
+        let platform_string = bytes_to_os_str(bytes).unwrap();
+        assert_eq!(os_str_to_bytes(platform_string), Ok(bytes));
+
+        let platform_string = OsString::from_vec(bytes.to_vec());
+        assert_eq!(os_string_to_bytes(platform_string), Ok(bytes.to_vec()));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn non_utf8_bytes_record_the_offending_path_on_windows() {
+        let bytes: &[u8] = b"C:\\fixtures\\\xFF\xFEfixture.rb";
+        let err = bytes_to_os_str(bytes).unwrap_err();
+        assert_eq!(err.path(), Some(bytes));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn unpaired_surrogates_record_the_offending_path_on_windows() {
+        use std::ffi::OsString;
+        use std::os::windows::ffi::OsStringExt;
+
+        // `\u{D800}` is an unpaired surrogate, which is representable in a
+        // platform string but not in UTF-8.
+        let platform_string = OsString::from_wide(&[0xD800]);
+        let err = os_str_to_bytes(&platform_string).unwrap_err();
+        assert!(err.path().is_some());
+
+        let err = os_string_to_bytes(platform_string).unwrap_err();
+        assert!(err.path().is_some());
+    }
+
+    #[test]
+    fn message_includes_the_offending_path() {
+        let err = ConvertBytesError::new();
+        assert_eq!(err.message(), "Could not convert between bytes and platform string");
+
+        let err = ConvertBytesError::with_path(&b"fixture.rb"[..]);
+        assert_eq!(
+            err.message(),
+            "Could not convert between bytes and platform string - fixture.rb"
+        );
+
+        let err = ConvertBytesError::with_path(&b"\xFF.rb"[..]);
+        assert_eq!(
+            err.message(),
+            "Could not convert between bytes and platform string - \u{FFFD}.rb"
+        );
     }
 }
@@ -11,7 +11,13 @@ use crate::Artichoke;
 
 impl RubyException for ConvertBytesError {
     fn message(&self) -> Cow<'_, [u8]> {
-        Cow::Borrowed(b"invalid byte sequence")
+        if let Some(path) = self.path() {
+            let mut message = b"invalid byte sequence - ".to_vec();
+            message.extend_from_slice(path);
+            message.into()
+        } else {
+            Cow::Borrowed(b"invalid byte sequence")
+        }
     }
 
     fn name(&self) -> Cow<'_, str> {
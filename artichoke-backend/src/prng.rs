@@ -16,4 +16,69 @@ impl Prng for Artichoke {
         let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
         Ok(&mut state.prng)
     }
+
+    fn prng_seed(&self) -> Result<u64, Self::Error> {
+        let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        // The PRNG stores its reseeding key as little-endian `u32` words.
+        // Reassemble the low 64 bits into the integer seed.
+        let [low, high, _, _] = state.prng.as_ref().seed();
+        let seed = u64::from(high) << 32 | u64::from(low);
+        Ok(seed)
+    }
+
+    fn prng_reseed(&mut self, seed: Option<u64>) -> Result<u64, Self::Error> {
+        let old_seed = self.prng_seed()?;
+        let new_random = Random::with_seed(seed)?;
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.prng = new_random;
+        Ok(old_seed)
+    }
+
+    fn prng_fill_bytes(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.prng.fill_bytes(buf);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::prelude::*;
+
+    #[test]
+    fn prng_reseed_returns_previous_seed() {
+        let mut interp = interpreter().unwrap();
+        interp.prng_reseed(Some(33)).unwrap();
+        assert_eq!(interp.prng_seed().unwrap(), 33);
+        let previous = interp.prng_reseed(Some(42)).unwrap();
+        assert_eq!(previous, 33);
+        assert_eq!(interp.prng_seed().unwrap(), 42);
+    }
+
+    #[test]
+    fn identically_seeded_interpreters_fill_identical_bytes() {
+        let mut interp = interpreter().unwrap();
+        let mut other = interpreter().unwrap();
+        interp.prng_reseed(Some(42)).unwrap();
+        other.prng_reseed(Some(42)).unwrap();
+
+        let mut buf = [0_u8; 32];
+        let mut other_buf = [0_u8; 32];
+        interp.prng_fill_bytes(&mut buf).unwrap();
+        other.prng_fill_bytes(&mut other_buf).unwrap();
+        assert_eq!(buf, other_buf);
+        assert_ne!(buf, [0_u8; 32]);
+    }
+
+    #[test]
+    fn srand_rand_sequences_are_reproducible() {
+        let mut interp = interpreter().unwrap();
+        let mut other = interpreter().unwrap();
+
+        let sequence = interp.eval(b"srand(42); Array.new(10) { rand(100_000) }").unwrap();
+        let sequence = sequence.try_convert_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        let other_sequence = other.eval(b"srand(42); Array.new(10) { rand(100_000) }").unwrap();
+        let other_sequence = other_sequence.try_convert_into_mut::<Vec<i64>>(&mut other).unwrap();
+        assert_eq!(sequence, other_sequence);
+    }
 }
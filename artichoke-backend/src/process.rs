@@ -0,0 +1,56 @@
+use crate::core::{ChildHandle, ChildStatus, CommandSpec, SpawnProcess};
+use crate::error::Error;
+use crate::extn::core::exception::{IOError, SecurityError};
+use crate::ffi::InterpreterExtractError;
+use crate::state::process::{self, SpawnError};
+use crate::Artichoke;
+
+impl Artichoke {
+    /// Replace the interpreter's subprocess backend.
+    ///
+    /// The interpreter spawns child processes in the host operating system by
+    /// default. Embedders can swap in the deny-all strategy —
+    /// [`process::Strategy::deny`] — to raise a `SecurityError` whenever Ruby
+    /// code reaches for `Kernel#system`, backticks, or `Process.spawn`.
+    ///
+    /// # Errors
+    ///
+    /// If the interpreter state cannot be extracted, an error is returned.
+    pub fn set_spawn_process(&mut self, process: process::Strategy) -> Result<(), Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        state.process = process;
+        Ok(())
+    }
+}
+
+impl From<SpawnError> for Error {
+    fn from(err: SpawnError) -> Self {
+        match err {
+            SpawnError::Denied => {
+                SecurityError::with_message("spawning child processes is disabled in this interpreter").into()
+            }
+            err @ (SpawnError::UnknownChild(_) | SpawnError::Io(_)) => IOError::from(err.to_string()).into(),
+        }
+    }
+}
+
+impl SpawnProcess for Artichoke {
+    type Error = Error;
+
+    fn spawn(&mut self, command: CommandSpec) -> Result<Option<ChildHandle>, Self::Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let child = state.process.spawn(command)?;
+        Ok(child)
+    }
+
+    fn wait(&mut self, child: ChildHandle) -> Result<ChildStatus, Self::Error> {
+        let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
+        let status = state.process.wait(child)?;
+        Ok(status)
+    }
+
+    fn last_status(&self) -> Result<Option<ChildStatus>, Self::Error> {
+        let state = self.state.as_deref().ok_or_else(InterpreterExtractError::new)?;
+        Ok(state.process.last_status().cloned())
+    }
+}
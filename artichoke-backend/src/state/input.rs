@@ -0,0 +1,131 @@
+use std::fmt;
+use std::io::{self, BufRead};
+
+use bstr::BString;
+
+pub trait Input: Send + Sync + fmt::Debug {
+    /// Reads a line from the input stream.
+    ///
+    /// The returned bytes include the trailing newline if the stream contains
+    /// one. `None` is returned when the stream is at EOF.
+    fn read_line(&mut self) -> io::Result<Option<Vec<u8>>>;
+}
+
+/// Runtime-selectable input backend for the interpreter stdin stream.
+///
+/// The default strategy reads from the stdin stream of the current process,
+/// but an embedder can swap in scripted in-memory input at runtime, for
+/// example with [`Artichoke::set_io`].
+///
+/// [`Artichoke::set_io`]: crate::Artichoke::set_io
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strategy {
+    /// Read from the stdin stream of the current process.
+    Process(Process),
+    /// Read scripted lines from an in-memory buffer.
+    Scripted(Scripted),
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Strategy {
+    /// Constructs the default [`Process`] input strategy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::Process(Process::new())
+    }
+
+    /// Constructs a [`Scripted`] input strategy which yields lines from the
+    /// given bytes.
+    #[must_use]
+    pub fn scripted<T: AsRef<[u8]>>(bytes: T) -> Self {
+        Self::Scripted(Scripted::with_bytes(bytes))
+    }
+}
+
+impl Input for Strategy {
+    fn read_line(&mut self) -> io::Result<Option<Vec<u8>>> {
+        match self {
+            Self::Process(strategy) => strategy.read_line(),
+            Self::Scripted(strategy) => strategy.read_line(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Process {
+    _private: (),
+}
+
+impl Process {
+    /// Constructs a new, default `Process` input strategy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl Input for Process {
+    fn read_line(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let mut buf = Vec::new();
+        let read = io::stdin().lock().read_until(b'\n', &mut buf)?;
+        if read == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(buf))
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Scripted {
+    bytes: BString,
+    pos: usize,
+}
+
+impl Scripted {
+    /// Constructs a new, empty `Scripted` input strategy.
+    ///
+    /// An empty script is always at EOF.
+    // This method cannot be const because of:
+    // https://github.com/BurntSushi/bstr/issues/73
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a `Scripted` input strategy which yields lines from the
+    /// given bytes.
+    #[must_use]
+    pub fn with_bytes<T: AsRef<[u8]>>(bytes: T) -> Self {
+        Self {
+            bytes: bytes.as_ref().into(),
+            pos: 0,
+        }
+    }
+
+    /// Appends bytes to the end of the script.
+    pub fn feed<T: AsRef<[u8]>>(&mut self, bytes: T) {
+        self.bytes.extend_from_slice(bytes.as_ref());
+    }
+}
+
+impl Input for Scripted {
+    fn read_line(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let remaining = match self.bytes.get(self.pos..) {
+            Some(remaining) if !remaining.is_empty() => remaining,
+            _ => return Ok(None),
+        };
+        let line = if let Some(index) = remaining.iter().position(|&byte| byte == b'\n') {
+            &remaining[..=index]
+        } else {
+            remaining
+        };
+        self.pos += line.len();
+        Ok(Some(line.to_vec()))
+    }
+}
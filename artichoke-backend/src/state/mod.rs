@@ -1,17 +1,25 @@
 use std::collections::hash_map::RandomState;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 use intaglio::bytes::SymbolTable;
 
 use crate::class;
 #[cfg(feature = "core-random")]
 use crate::extn::core::random::Random;
+use crate::extn::core::r#struct;
+use crate::feature_trace::FeatureEvent;
+use crate::gc;
 use crate::interpreter::InterpreterAllocError;
 use crate::load_path;
+use crate::method;
 use crate::module;
 use crate::sys;
 
+pub mod input;
 pub mod output;
 pub mod parser;
+pub mod process;
 pub mod regexp;
 
 /// Container for interpreter global state.
@@ -28,11 +36,23 @@ pub struct State {
     pub parser: Option<parser::State>,
     pub classes: class::Registry,
     pub modules: module::Registry,
+    pub method_closures: method::closure::Registry,
+    pub method_signatures: method::signature::Registry,
+    pub struct_specs: r#struct::Registry,
     pub load_path_vfs: load_path::Adapter,
     pub regexp: regexp::State,
     pub symbols: SymbolTable,
     pub output: output::Strategy,
+    pub input: input::Strategy,
+    pub process: process::Strategy,
     pub hash_builder: RandomState,
+    pub interrupt: Arc<AtomicBool>,
+    pub feature_traces: Vec<Vec<FeatureEvent>>,
+    pub gc_runs: gc::GcRuns,
+    /// Interpreter-wide `frozen_string_literal` override set from the CLI.
+    ///
+    /// When present, this takes precedence over per-file magic comments.
+    pub frozen_string_literal: Option<bool>,
     #[cfg(feature = "core-random")]
     pub prng: Random,
 }
@@ -68,11 +88,20 @@ impl State {
             parser: None,
             classes: class::Registry::new(),
             modules: module::Registry::new(),
+            method_closures: method::closure::Registry::new(),
+            method_signatures: method::signature::Registry::new(),
+            struct_specs: r#struct::Registry::new(),
             load_path_vfs: load_path::Adapter::new(),
             regexp: regexp::State::new(),
             symbols: SymbolTable::new(),
             output: output::Strategy::new(),
+            input: input::Strategy::new(),
+            process: process::Strategy::new(),
             hash_builder: RandomState::new(),
+            interrupt: Arc::new(AtomicBool::new(false)),
+            feature_traces: Vec::new(),
+            gc_runs: gc::GcRuns::new(),
+            frozen_string_literal: None,
             #[cfg(feature = "core-random")]
             prng: Random::new().map_err(|_| InterpreterAllocError::new())?,
         })
@@ -3,12 +3,104 @@ use std::io::{self, Write};
 
 use bstr::BString;
 
-#[cfg(all(not(feature = "output-strategy-capture"), not(feature = "output-strategy-null")))]
-pub type Strategy = Process;
-#[cfg(all(feature = "output-strategy-capture", not(feature = "output-strategy-null")))]
-pub type Strategy = Captured;
-#[cfg(all(feature = "output-strategy-capture", feature = "output-strategy-null"))]
-pub type Strategy = Null;
+/// Runtime-selectable output backend for the interpreter stdout and stderr
+/// streams.
+///
+/// The default strategy is chosen at compile time by the
+/// `output-strategy-capture` and `output-strategy-null` features, but an
+/// embedder can swap in a different strategy at runtime, for example with
+/// [`Artichoke::set_io`].
+///
+/// [`Artichoke::set_io`]: crate::Artichoke::set_io
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Strategy {
+    /// Write through to the stdio streams of the current process.
+    Process(Process),
+    /// Capture writes in in-memory buffers.
+    Captured(Captured),
+    /// Discard all writes.
+    Null(Null),
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Strategy {
+    /// Constructs the default output strategy selected at compile time.
+    #[must_use]
+    pub fn new() -> Self {
+        #[cfg(all(not(feature = "output-strategy-capture"), not(feature = "output-strategy-null")))]
+        {
+            Self::Process(Process::new())
+        }
+        #[cfg(all(feature = "output-strategy-capture", not(feature = "output-strategy-null")))]
+        {
+            Self::Captured(Captured::new())
+        }
+        #[cfg(all(feature = "output-strategy-capture", feature = "output-strategy-null"))]
+        {
+            Self::Null(Null::new())
+        }
+    }
+
+    /// Constructs a [`Process`] output strategy.
+    #[must_use]
+    pub const fn process() -> Self {
+        Self::Process(Process::new())
+    }
+
+    /// Constructs a [`Captured`] output strategy with empty buffers.
+    #[must_use]
+    pub fn captured() -> Self {
+        Self::Captured(Captured::new())
+    }
+
+    /// Constructs a [`Null`] output strategy.
+    #[must_use]
+    pub const fn null() -> Self {
+        Self::Null(Null::new())
+    }
+
+    /// Returns the [`Captured`] buffers if this strategy captures output.
+    #[must_use]
+    pub fn as_captured(&self) -> Option<&Captured> {
+        if let Self::Captured(captured) = self {
+            Some(captured)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the [`Captured`] buffers if this strategy captures output.
+    pub fn as_captured_mut(&mut self) -> Option<&mut Captured> {
+        if let Self::Captured(captured) = self {
+            Some(captured)
+        } else {
+            None
+        }
+    }
+}
+
+impl Output for Strategy {
+    fn write_stdout<T: AsRef<[u8]>>(&mut self, bytes: T) -> io::Result<()> {
+        match self {
+            Self::Process(strategy) => strategy.write_stdout(bytes),
+            Self::Captured(strategy) => strategy.write_stdout(bytes),
+            Self::Null(strategy) => strategy.write_stdout(bytes),
+        }
+    }
+
+    fn write_stderr<T: AsRef<[u8]>>(&mut self, bytes: T) -> io::Result<()> {
+        match self {
+            Self::Process(strategy) => strategy.write_stderr(bytes),
+            Self::Captured(strategy) => strategy.write_stderr(bytes),
+            Self::Null(strategy) => strategy.write_stderr(bytes),
+        }
+    }
+}
 
 pub trait Output: Send + Sync + fmt::Debug {
     fn write_stdout<T: AsRef<[u8]>>(&mut self, bytes: T) -> io::Result<()>;
@@ -117,6 +117,142 @@ impl State {
     pub fn peek_context(&self) -> Option<&Context> {
         self.stack.last()
     }
+
+    /// Returns a mutable reference to the last [`Context`], or `None` if the
+    /// context stack is empty.
+    #[must_use]
+    pub fn peek_context_mut(&mut self) -> Option<&mut Context> {
+        self.stack.last_mut()
+    }
+
+    /// Returns the whole [`Context`] stack, from least to most recently
+    /// pushed.
+    #[must_use]
+    pub fn context_stack(&self) -> &[Context] {
+        self.stack.as_slice()
+    }
+
+    /// Classify whether `code` parses as a complete expression.
+    ///
+    /// This function invokes the mruby parser without executing the parsed
+    /// code. A REPL can use the classification to decide whether to show a
+    /// continuation prompt and keep reading input or to report a syntax error.
+    pub fn parse_state(&mut self, mrb: &mut sys::mrb_state, code: &[u8]) -> CodeParseState {
+        let len = if let Ok(len) = isize::try_from(code.len()) {
+            len
+        } else {
+            return CodeParseState::Invalid {
+                message: String::from("code is too long"),
+                line: 0,
+            };
+        };
+        let parser = unsafe { sys::mrb_parser_new(mrb) };
+        let mut parser = if let Some(parser) = NonNull::new(parser) {
+            parser
+        } else {
+            return CodeParseState::Invalid {
+                message: String::from("failed to allocate parser"),
+                line: 0,
+            };
+        };
+        let state = unsafe {
+            let parser = parser.as_mut();
+            let context = self.context.as_mut();
+            let ptr = code.as_ptr().cast::<i8>();
+            parser.s = ptr;
+            parser.send = ptr.offset(len);
+            if context.lineno > 0 {
+                parser.lineno = context.lineno;
+            }
+            sys::mrb_parser_parse(parser, context);
+            classify_parse(parser)
+        };
+        unsafe {
+            sys::mrb_parser_free(parser.as_mut());
+        }
+        state
+    }
+
+    /// Returns the number of [`Context`]s on the stack.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+/// Classification of a parse attempt on partial input.
+///
+/// See [`State::parse_state`].
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CodeParseState {
+    /// The code parses as a complete expression and is fit to eval.
+    Complete,
+    /// The parser expects more input to terminate the current expression.
+    Incomplete {
+        /// Why the parser expects more input.
+        reason: IncompleteReason,
+    },
+    /// The code contains a syntax error that more input cannot fix.
+    Invalid {
+        /// Error message reported by the parser.
+        message: String,
+        /// Line number on which the error was reported.
+        line: usize,
+    },
+}
+
+/// Why the parser classified code as [`CodeParseState::Incomplete`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IncompleteReason {
+    /// The current expression contains an unterminated heredoc.
+    UnterminatedHeredoc,
+    /// The current expression contains an unterminated `String` literal.
+    UnterminatedString,
+    /// The parser reached the end of input with an open block, `def`, or
+    /// trailing operator.
+    UnterminatedExpression,
+}
+
+#[allow(clippy::enum_glob_use)]
+fn classify_parse(parser: &sys::mrb_parser_state) -> CodeParseState {
+    use sys::mrb_lex_state_enum::*;
+
+    if !parser.parsing_heredoc.is_null() {
+        return CodeParseState::Incomplete {
+            reason: IncompleteReason::UnterminatedHeredoc,
+        };
+    }
+    if !parser.lex_strterm.is_null() {
+        return CodeParseState::Incomplete {
+            reason: IncompleteReason::UnterminatedString,
+        };
+    }
+    if parser.nerr > 0 {
+        let err = &parser.error_buffer[0];
+        let line = usize::from(err.lineno);
+        let message = if err.message.is_null() {
+            String::from("syntax error")
+        } else {
+            let cstring = unsafe { CStr::from_ptr(err.message) };
+            String::from_utf8_lossy(cstring.to_bytes()).into_owned()
+        };
+        // The parser ran off the end of the input with an expression still
+        // open, for example an unterminated `def` or a trailing operator.
+        if message == "syntax error, unexpected $end" {
+            return CodeParseState::Incomplete {
+                reason: IncompleteReason::UnterminatedExpression,
+            };
+        }
+        return CodeParseState::Invalid { message, line };
+    }
+    match parser.lstate {
+        // a message dot, method name, `class` keyword, or control flow keyword
+        // was the last token; more input must follow.
+        EXPR_DOT | EXPR_FNAME | EXPR_CLASS | EXPR_VALUE => CodeParseState::Incomplete {
+            reason: IncompleteReason::UnterminatedExpression,
+        },
+        _ => CodeParseState::Complete,
+    }
 }
 
 fn reset_context_filename(mrb: &mut sys::mrb_state, context: &mut sys::mrbc_context) {
@@ -140,6 +276,9 @@ pub struct Context {
     filename: Cow<'static, [u8]>,
     /// FFI NUL-terminated C string variant of `filename` field.
     filename_cstr: Box<CStr>,
+    /// Value of the `frozen_string_literal` magic comment detected in the
+    /// source compiled under this context, if any.
+    frozen_string_literal: Option<bool>,
 }
 
 impl Default for Context {
@@ -164,6 +303,7 @@ impl Context {
         Some(Self {
             filename,
             filename_cstr: cstring.into_boxed_c_str(),
+            frozen_string_literal: None,
         })
     }
 
@@ -182,6 +322,7 @@ impl Context {
         Self {
             filename,
             filename_cstr: cstring.into_boxed_c_str(),
+            frozen_string_literal: None,
         }
     }
 
@@ -206,13 +347,284 @@ impl Context {
     pub fn filename_as_c_str(&self) -> &CStr {
         self.filename_cstr.as_ref()
     }
+
+    /// Value of the `frozen_string_literal` magic comment detected in the
+    /// source compiled under this `Context`, if any.
+    ///
+    /// [`eval`] records the result of [`detect_frozen_string_literal`] here
+    /// before compiling each source.
+    ///
+    /// [`eval`]: crate::core::Eval
+    #[must_use]
+    pub const fn frozen_string_literal(&self) -> Option<bool> {
+        self.frozen_string_literal
+    }
+
+    /// Record the value of the `frozen_string_literal` magic comment for the
+    /// source compiled under this `Context`.
+    pub fn set_frozen_string_literal(&mut self, frozen_string_literal: Option<bool>) {
+        self.frozen_string_literal = frozen_string_literal;
+    }
+
+    /// Directory portion of the filename of this `Context`.
+    ///
+    /// Returns the dirname of the filename bytes without allocating. Returns
+    /// `None` if the filename has no directory component, for example the
+    /// "(eval)" filename of the root context.
+    #[must_use]
+    pub fn directory(&self) -> Option<&[u8]> {
+        let filename = self.filename.as_ref();
+        match filename.iter().rposition(|&byte| byte == b'/')? {
+            0 => Some(b"/"),
+            idx => Some(&filename[..idx]),
+        }
+    }
+}
+
+/// Scan the leading comment block of a Ruby source for a
+/// `frozen_string_literal` magic comment.
+///
+/// Returns `Some(true)` or `Some(false)` if the magic comment is present with
+/// a recognized value and `None` otherwise. Only blank lines, a shebang on the
+/// first line, and comment lines before the first line of code are scanned,
+/// which matches where MRI honors the magic comment.
+#[must_use]
+pub fn detect_frozen_string_literal(code: &[u8]) -> Option<bool> {
+    for (idx, line) in code.split(|&byte| byte == b'\n').enumerate() {
+        let line = trim_ascii_whitespace(line);
+        if line.is_empty() {
+            continue;
+        }
+        if idx == 0 && line.starts_with(b"#!") {
+            continue;
+        }
+        if !line.starts_with(b"#") {
+            return None;
+        }
+        if let Some(value) = parse_frozen_string_literal_comment(line) {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn parse_frozen_string_literal_comment(comment: &[u8]) -> Option<bool> {
+    const DIRECTIVE: &[u8] = b"frozen_string_literal";
+
+    let start = comment.windows(DIRECTIVE.len()).position(|window| window == DIRECTIVE)?;
+    let rest = &comment[start + DIRECTIVE.len()..];
+    let rest = trim_ascii_whitespace(rest);
+    let rest = rest.strip_prefix(b":")?;
+    let rest = trim_ascii_whitespace(rest);
+    if rest.starts_with(b"true") {
+        Some(true)
+    } else if rest.starts_with(b"false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn trim_ascii_whitespace(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if first.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    while let [rest @ .., last] = bytes {
+        if last.is_ascii_whitespace() {
+            bytes = rest;
+        } else {
+            break;
+        }
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod magic_comment_test {
+    use super::detect_frozen_string_literal;
+
+    #[test]
+    fn detects_true_and_false() {
+        assert_eq!(detect_frozen_string_literal(b"# frozen_string_literal: true\n'a'"), Some(true));
+        assert_eq!(
+            detect_frozen_string_literal(b"# frozen_string_literal: false\n'a'"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn absent_magic_comment_is_none() {
+        assert_eq!(detect_frozen_string_literal(b"'a'.frozen?"), None);
+        assert_eq!(detect_frozen_string_literal(b"# just a comment\n'a'"), None);
+        assert_eq!(detect_frozen_string_literal(b""), None);
+    }
+
+    #[test]
+    fn detected_after_shebang_and_blank_lines() {
+        let code = b"#!/usr/bin/env ruby\n\n# frozen_string_literal: true\nputs 'a'";
+        assert_eq!(detect_frozen_string_literal(code), Some(true));
+    }
+
+    #[test]
+    fn only_leading_comments_are_scanned() {
+        let code = b"x = 1\n# frozen_string_literal: true\n";
+        assert_eq!(detect_frozen_string_literal(code), None);
+    }
+
+    #[test]
+    fn comment_in_string_literal_is_not_detected() {
+        let code = b"s = '# frozen_string_literal: true'";
+        assert_eq!(detect_frozen_string_literal(code), None);
+    }
+
+    #[test]
+    fn emacs_style_comment_is_detected() {
+        let code = b"# -*- frozen_string_literal: true -*-\n'a'";
+        assert_eq!(detect_frozen_string_literal(code), Some(true));
+    }
+
+    #[test]
+    fn unrecognized_value_is_none() {
+        let code = b"# frozen_string_literal: maybe\n'a'";
+        assert_eq!(detect_frozen_string_literal(code), None);
+    }
 }
 
 #[cfg(test)]
 mod context_test {
+    use super::Context;
+
     #[test]
     fn top_filename_does_not_contain_nul_byte() {
         let contains_nul_byte = super::TOP_FILENAME.iter().copied().any(|b| b == b'\0');
         assert!(!contains_nul_byte);
     }
+
+    #[test]
+    fn directory_of_absolute_filename() {
+        let context = Context::new(&b"/src/lib/foo.rb"[..]).unwrap();
+        assert_eq!(context.directory(), Some(&b"/src/lib"[..]));
+    }
+
+    #[test]
+    fn directory_of_filename_in_root() {
+        let context = Context::new(&b"/foo.rb"[..]).unwrap();
+        assert_eq!(context.directory(), Some(&b"/"[..]));
+    }
+
+    #[test]
+    fn directory_of_bare_filename_is_none() {
+        let context = Context::new(&b"foo.rb"[..]).unwrap();
+        assert_eq!(context.directory(), None);
+        assert_eq!(Context::root().directory(), None);
+    }
+}
+
+#[cfg(test)]
+mod parse_state_test {
+    use crate::state::parser::{CodeParseState, IncompleteReason};
+    use crate::test::prelude::*;
+
+    #[test]
+    fn complete_expression() {
+        let mut interp = interpreter().unwrap();
+        assert_eq!(interp.parse_state(b"1 + 2").unwrap(), CodeParseState::Complete);
+        assert_eq!(
+            interp.parse_state(b"def foo; 2 + 7; end").unwrap(),
+            CodeParseState::Complete
+        );
+    }
+
+    #[test]
+    fn open_def_is_incomplete() {
+        let mut interp = interpreter().unwrap();
+        assert_eq!(
+            interp.parse_state(b"def foo").unwrap(),
+            CodeParseState::Incomplete {
+                reason: IncompleteReason::UnterminatedExpression
+            }
+        );
+    }
+
+    #[test]
+    fn unterminated_string_is_incomplete() {
+        let mut interp = interpreter().unwrap();
+        assert_eq!(
+            interp.parse_state(b"\"unterminated").unwrap(),
+            CodeParseState::Incomplete {
+                reason: IncompleteReason::UnterminatedString
+            }
+        );
+    }
+
+    #[test]
+    fn trailing_operator_is_incomplete() {
+        let mut interp = interpreter().unwrap();
+        assert_eq!(
+            interp.parse_state(b"1 +").unwrap(),
+            CodeParseState::Incomplete {
+                reason: IncompleteReason::UnterminatedExpression
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_token_reports_message_and_line() {
+        let mut interp = interpreter().unwrap();
+        match interp.parse_state(b"def 1; end").unwrap() {
+            CodeParseState::Invalid { message, line } => {
+                assert!(message.starts_with("syntax error"), "unexpected message: {}", message);
+                assert_eq!(line, 1);
+            }
+            state => panic!("expected Invalid, got {:?}", state),
+        }
+        match interp.parse_state(b"# a comment\ndef 1; end").unwrap() {
+            CodeParseState::Invalid { line, .. } => assert_eq!(line, 2),
+            state => panic!("expected Invalid, got {:?}", state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::parser::Context;
+    use crate::test::prelude::*;
+
+    // `push_context` and `pop_context` update the `mrbc_filename` on the
+    // underlying parser, which is observable through the `__FILE__` magic
+    // constant.
+    #[test]
+    fn push_and_pop_context_update_parser_filename() {
+        let mut interp = interpreter().unwrap();
+
+        let file = interp.eval(b"__FILE__").unwrap();
+        let file = file.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(file, "(eval)");
+
+        let context = Context::new(&b"/src/lib/foo.rb"[..]).unwrap();
+        interp.push_context(context).unwrap();
+        let file = interp.eval(b"__FILE__").unwrap();
+        let file = file.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(file, "/src/lib/foo.rb");
+
+        let context = Context::new(&b"/src/lib/foo/bar.rb"[..]).unwrap();
+        interp.push_context(context).unwrap();
+        let file = interp.eval(b"__FILE__").unwrap();
+        let file = file.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(file, "/src/lib/foo/bar.rb");
+
+        interp.pop_context().unwrap();
+        let file = interp.eval(b"__FILE__").unwrap();
+        let file = file.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(file, "/src/lib/foo.rb");
+
+        interp.pop_context().unwrap();
+        let file = interp.eval(b"__FILE__").unwrap();
+        let file = file.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!(file, "(eval)");
+    }
 }
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::io;
+use std::process::{Child, Command, Stdio};
+
+use crate::core::{ChildHandle, ChildStatus, CommandSpec, Program};
+use crate::platform_string::bytes_to_os_str;
+
+/// Runtime-selectable subprocess backend for `Kernel#system`, backticks, and
+/// `Process.spawn`.
+///
+/// The default strategy proxies the host operating system, except on
+/// WebAssembly targets where all subprocess execution is denied. An embedder
+/// can swap in the [`Deny`] strategy at runtime with
+/// [`Artichoke::set_spawn_process`] to sandbox an interpreter.
+///
+/// [`Artichoke::set_spawn_process`]: crate::Artichoke::set_spawn_process
+#[derive(Debug)]
+pub enum Strategy {
+    /// Spawn child processes in the host operating system.
+    Host(Host),
+    /// Deny all subprocess execution.
+    Deny(Deny),
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Strategy {
+    /// Constructs the default subprocess strategy for the compilation target.
+    #[must_use]
+    pub fn new() -> Self {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            Self::Host(Host::new())
+        }
+        #[cfg(target_family = "wasm")]
+        {
+            Self::Deny(Deny::new())
+        }
+    }
+
+    /// Constructs a [`Host`] subprocess strategy.
+    #[must_use]
+    pub fn host() -> Self {
+        Self::Host(Host::new())
+    }
+
+    /// Constructs a [`Deny`] subprocess strategy.
+    #[must_use]
+    pub const fn deny() -> Self {
+        Self::Deny(Deny::new())
+    }
+
+    /// Spawn a child process described by `command`.
+    ///
+    /// Returns `Ok(None)` if the command cannot be executed, for example if
+    /// the program does not exist.
+    ///
+    /// # Errors
+    ///
+    /// If this strategy denies subprocess execution, an error is returned.
+    pub fn spawn(&mut self, command: CommandSpec) -> Result<Option<ChildHandle>, SpawnError> {
+        match self {
+            Self::Host(strategy) => strategy.spawn(command),
+            Self::Deny(_) => Err(SpawnError::Denied),
+        }
+    }
+
+    /// Wait for the child process behind `child` to exit, recording its
+    /// status as the last status.
+    ///
+    /// # Errors
+    ///
+    /// If `child` does not refer to a spawned child process, an error is
+    /// returned.
+    ///
+    /// If this strategy denies subprocess execution, an error is returned.
+    pub fn wait(&mut self, child: ChildHandle) -> Result<ChildStatus, SpawnError> {
+        match self {
+            Self::Host(strategy) => strategy.wait(child),
+            Self::Deny(_) => Err(SpawnError::Denied),
+        }
+    }
+
+    /// Return the status of the most recently waited-on child process.
+    #[must_use]
+    pub fn last_status(&self) -> Option<&ChildStatus> {
+        match self {
+            Self::Host(strategy) => strategy.last_status(),
+            Self::Deny(_) => None,
+        }
+    }
+}
+
+/// Errors encountered when spawning and waiting on child processes.
+#[derive(Debug)]
+pub enum SpawnError {
+    /// The active strategy denies all subprocess execution.
+    Denied,
+    /// The waited-on handle does not refer to a spawned child process.
+    UnknownChild(ChildHandle),
+    /// The host operating system returned an error while waiting on a child.
+    Io(io::Error),
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Denied => f.write_str("spawning child processes is disabled in this interpreter"),
+            Self::UnknownChild(child) => write!(f, "no child process with pid {}", child.pid()),
+            Self::Io(err) => write!(f, "failed to wait on child process: {}", err),
+        }
+    }
+}
+
+impl error::Error for SpawnError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        if let Self::Io(err) = self {
+            Some(err)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<io::Error> for SpawnError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Subprocess strategy which spawns child processes in the host operating
+/// system with [`std::process`].
+#[derive(Default, Debug)]
+pub struct Host {
+    children: HashMap<i64, Child>,
+    last_status: Option<ChildStatus>,
+}
+
+impl Host {
+    /// Constructs a new, default `Host` subprocess strategy.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn spawn(&mut self, command: CommandSpec) -> Result<Option<ChildHandle>, SpawnError> {
+        let mut cmd = match command.program() {
+            Program::Shell(line) => {
+                let line = match bytes_to_os_str(line) {
+                    Ok(line) => line,
+                    Err(_) => return Ok(None),
+                };
+                let mut cmd = if cfg!(windows) {
+                    let mut cmd = Command::new("cmd");
+                    cmd.arg("/C");
+                    cmd
+                } else {
+                    let mut cmd = Command::new("sh");
+                    cmd.arg("-c");
+                    cmd
+                };
+                cmd.arg(line);
+                cmd
+            }
+            Program::Argv(argv) => {
+                let (program, args) = match argv.split_first() {
+                    Some(argv) => argv,
+                    None => return Ok(None),
+                };
+                let program = match bytes_to_os_str(program) {
+                    Ok(program) => program,
+                    Err(_) => return Ok(None),
+                };
+                let mut cmd = Command::new(program);
+                for arg in args {
+                    let arg = match bytes_to_os_str(arg) {
+                        Ok(arg) => arg,
+                        Err(_) => return Ok(None),
+                    };
+                    cmd.arg(arg);
+                }
+                cmd
+            }
+        };
+        if command.captures_stdout() {
+            cmd.stdout(Stdio::piped());
+        }
+        let child = match cmd.spawn() {
+            Ok(child) => child,
+            // Command execution failures — most commonly a missing program —
+            // are not errors: `Kernel#system` returns `nil` for them.
+            Err(_) => return Ok(None),
+        };
+        let pid = i64::from(child.id());
+        self.children.insert(pid, child);
+        Ok(Some(ChildHandle::new(pid)))
+    }
+
+    fn wait(&mut self, child: ChildHandle) -> Result<ChildStatus, SpawnError> {
+        let process = self
+            .children
+            .remove(&child.pid())
+            .ok_or(SpawnError::UnknownChild(child))?;
+        let captured = process.stdout.is_some();
+        let output = process.wait_with_output()?;
+        let status = if let Some(exitstatus) = output.status.code() {
+            ChildStatus::exited(child.pid(), i64::from(exitstatus))
+        } else {
+            ChildStatus::signaled(child.pid(), i64::from(termsig(output.status)))
+        };
+        let status = if captured {
+            status.with_stdout(output.stdout)
+        } else {
+            status
+        };
+        self.last_status = Some(status.clone());
+        Ok(status)
+    }
+
+    fn last_status(&self) -> Option<&ChildStatus> {
+        self.last_status.as_ref()
+    }
+}
+
+/// Subprocess strategy which denies all subprocess execution.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deny {
+    _private: (),
+}
+
+impl Deny {
+    /// Constructs a new, default `Deny` subprocess strategy.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+#[cfg(unix)]
+fn termsig(status: std::process::ExitStatus) -> i32 {
+    use std::os::unix::process::ExitStatusExt;
+
+    status.signal().unwrap_or_default()
+}
+
+#[cfg(not(unix))]
+fn termsig(_status: std::process::ExitStatus) -> i32 {
+    0
+}
@@ -1,17 +1,21 @@
+use std::any::{self, Any};
 use std::borrow::Cow;
 use std::error;
+use std::ffi::c_void;
 use std::fmt;
 use std::ptr;
 
 use artichoke_core::convert::{Convert, ConvertMut, TryConvert, TryConvertMut};
+use artichoke_core::debug::Debug as _;
 use artichoke_core::intern::Intern;
 use artichoke_core::value::Value as ValueCore;
 
 use crate::convert::BoxUnboxVmValue;
 use crate::core::ClassRegistry;
+use crate::def::NotDefinedError;
 use crate::error::{Error, RubyException};
 use crate::exception_handler;
-use crate::extn::core::exception::{ArgumentError, Fatal};
+use crate::extn::core::exception::{ArgumentError, Fatal, TypeError};
 use crate::extn::core::symbol::Symbol;
 use crate::gc::MrbGarbageCollection;
 use crate::sys::{self, protect};
@@ -21,6 +25,11 @@ use crate::Artichoke;
 /// Max argument count for function calls including initialize and yield.
 pub const MRB_FUNCALL_ARGC_MAX: usize = 16;
 
+/// Object flag bit which marks an object as frozen.
+///
+/// This is `MRB_FL_OBJ_IS_FROZEN` in `mruby/object.h`.
+const MRB_FL_OBJ_IS_FROZEN: u32 = 1 << 20;
+
 /// Boxed Ruby value in the [`Artichoke`] interpreter.
 #[derive(Default, Debug, Clone, Copy)]
 pub struct Value(sys::mrb_value);
@@ -191,6 +200,46 @@ impl ValueCore for Value {
         }
     }
 
+    fn dup(&self, interp: &mut Self::Artichoke) -> Result<Self::Value, Self::Error> {
+        if let Ruby::Bool | Ruby::Fixnum | Ruby::Float | Ruby::Nil | Ruby::Symbol = self.ruby_type() {
+            // Immediate values are copied on assignment and have no object
+            // state to duplicate.
+            return Ok(*self);
+        }
+        // Dispatch through `#dup` so user-defined `initialize_copy` hooks
+        // fire. `Kernel#dup` does not copy the frozen state or singleton
+        // class of the receiver.
+        self.funcall(interp, "dup", &[], None)
+    }
+
+    fn clone_value(&self, interp: &mut Self::Artichoke, freeze: Option<bool>) -> Result<Self::Value, Self::Error> {
+        if let Ruby::Bool | Ruby::Fixnum | Ruby::Float | Ruby::Nil | Ruby::Symbol = self.ruby_type() {
+            // Immediate values are copied on assignment and have no object
+            // state to duplicate.
+            return Ok(*self);
+        }
+        // Dispatch through `#clone` so user-defined `initialize_copy` hooks
+        // fire. `Kernel#clone` copies the frozen state and singleton class of
+        // the receiver.
+        let clone = self.funcall(interp, "clone", &[], None)?;
+        if let Some(freeze) = freeze {
+            // `Kernel#clone` in mruby does not support the `freeze` keyword
+            // argument, so apply the requested frozen state to the copy
+            // directly.
+            unsafe {
+                let basic = sys::mrb_sys_basic_ptr(clone.inner());
+                let flags = basic.as_ref().unwrap().flags();
+                let flags = if freeze {
+                    flags | MRB_FL_OBJ_IS_FROZEN
+                } else {
+                    flags & !MRB_FL_OBJ_IS_FROZEN
+                };
+                basic.as_mut().unwrap().set_flags(flags);
+            }
+        }
+        Ok(clone)
+    }
+
     fn freeze(&mut self, interp: &mut Self::Artichoke) -> Result<(), Self::Error> {
         self.funcall(interp, "freeze", &[], None)?;
         Ok(())
@@ -385,6 +434,197 @@ impl From<Box<ArgCountError>> for Box<dyn RubyException> {
     }
 }
 
+/// A type-erased Rust value stored inside a Ruby data object.
+///
+/// [`wrap`] boxes values of arbitrary `'static` Rust types together with their
+/// type name so [`unwrap_ref`] and [`unwrap_mut`] can check the requested type
+/// before handing out references.
+#[derive(Debug)]
+struct WrappedData {
+    type_name: &'static str,
+    data: Box<dyn Any>,
+}
+
+/// Free function for class specs whose instances are created with [`wrap`].
+///
+/// Class specs passed to [`wrap`] and [`wrap_into`] must be constructed with
+/// this function as their free function and built with
+/// [`value_is_rust_object`](crate::class::Builder::value_is_rust_object).
+/// [`unwrap_ref`] and [`unwrap_mut`] use this function's address to recognize
+/// data objects holding a [`wrap`]-created Rust value.
+pub unsafe extern "C" fn wrap_free(mrb: *mut sys::mrb_state, data: *mut c_void) {
+    let _ = mrb;
+    // `Class#allocate` creates data objects with a NULL pointer because
+    // `initialize` never ran.
+    if data.is_null() {
+        return;
+    }
+    drop(Box::from_raw(data.cast::<WrappedData>()));
+}
+
+/// Wrap a Rust value in a new instance of the Ruby class described by `spec`.
+///
+/// This is the `Data_Wrap_Struct` pattern from MRI. The value is moved to the
+/// heap and owned by the returned Ruby object; the mruby garbage collector
+/// frees it through [`wrap_free`] when the object is collected. Use
+/// [`unwrap_ref`] and [`unwrap_mut`] to get the value back.
+pub fn wrap<T>(interp: &mut Artichoke, spec: &crate::class::Spec, value: T) -> Result<Value, Error>
+where
+    T: Any,
+{
+    let mut rclass = {
+        let rclass = spec.rclass();
+        unsafe { interp.with_ffi_boundary(|mrb| rclass.resolve(mrb)) }?
+            .ok_or_else(|| NotDefinedError::class(spec.name()))?
+    };
+
+    let data = Box::new(WrappedData {
+        type_name: any::type_name::<T>(),
+        data: Box::new(value),
+    });
+    let ptr = Box::into_raw(data);
+
+    let obj = unsafe {
+        interp.with_ffi_boundary(|mrb| {
+            let alloc = sys::mrb_data_object_alloc(mrb, rclass.as_mut(), ptr.cast::<c_void>(), spec.data_type());
+            sys::mrb_sys_obj_value(alloc.cast::<c_void>())
+        })?
+    };
+    Ok(interp.protect(Value::from(obj)))
+}
+
+/// Wrap a Rust value into an existing Ruby data object, e.g. the receiver of
+/// `initialize`.
+///
+/// This supports the `Class#allocate` path: data objects are allocated with a
+/// NULL data pointer and `initialize` injects the Rust value. Any previously
+/// wrapped value is dropped first, so re-running `initialize` does not leak.
+pub fn wrap_into<T>(spec: &crate::class::Spec, value: T, into: Value) -> Result<Value, Error>
+where
+    T: Any,
+{
+    if into.ruby_type() != Ruby::Data {
+        return Err(TypeError::with_message("cannot wrap Rust value in non-data Ruby object").into());
+    }
+
+    let data = Box::new(WrappedData {
+        type_name: any::type_name::<T>(),
+        data: Box::new(value),
+    });
+    let ptr = Box::into_raw(data);
+
+    let mut obj = into.inner();
+    unsafe {
+        let rdata = obj.value.p.cast::<sys::RData>();
+        let old_type = (*rdata).type_;
+        let old_data = (*rdata).data;
+        // See the comment in `wrapped_data` on function pointer identity.
+        #[allow(clippy::fn_address_comparisons)]
+        if !old_data.is_null() && !old_type.is_null() && (*old_type).dfree == Some(wrap_free as _) {
+            drop(Box::from_raw(old_data.cast::<WrappedData>()));
+        }
+        sys::mrb_sys_data_init(&mut obj, ptr.cast::<c_void>(), spec.data_type());
+    }
+    Ok(Value::from(obj))
+}
+
+/// Extract the [`WrappedData`] stored in a Ruby data object created by
+/// [`wrap`] or [`wrap_into`].
+unsafe fn wrapped_data<'a>(value: &'a Value, interp: &mut Artichoke) -> Result<&'a WrappedData, Error> {
+    if value.ruby_type() != Ruby::Data {
+        let mut message = String::from("wrong argument type ");
+        message.push_str(interp.class_name_for_value(*value));
+        message.push_str(" (expected wrapped Rust data)");
+        return Err(TypeError::from(message).into());
+    }
+    let rdata = value.inner().value.p.cast::<sys::RData>();
+    let data_type = (*rdata).type_;
+    // Function pointer identity is sufficient here: only data objects created
+    // by `wrap` or `wrap_into` have a data type whose free function is
+    // `wrap_free`, so only those hold a `WrappedData`.
+    #[allow(clippy::fn_address_comparisons)]
+    if data_type.is_null() || (*data_type).dfree != Some(wrap_free as _) {
+        let mut message = String::from("wrong argument type ");
+        message.push_str(interp.class_name_for_value(*value));
+        message.push_str(" (expected wrapped Rust data)");
+        return Err(TypeError::from(message).into());
+    }
+    let data = (*rdata).data;
+    if data.is_null() {
+        // `Class#allocate` can be used to create data objects without calling
+        // `initialize`. These objects have a NULL data pointer.
+        let mut message = String::from("uninitialized ");
+        message.push_str(interp.class_name_for_value(*value));
+        return Err(TypeError::from(message).into());
+    }
+    Ok(&*data.cast::<WrappedData>())
+}
+
+/// Extract a shared reference to the Rust value of type `T` wrapped in a Ruby
+/// data object by [`wrap`] or [`wrap_into`].
+///
+/// # Errors
+///
+/// If the value is not a data object created by [`wrap`], if the data object
+/// was allocated but never initialized, or if the wrapped value is not a `T`,
+/// a [`TypeError`] is returned. The wrong-type error names both the expected
+/// and the actual Rust type.
+///
+/// # Safety
+///
+/// The returned reference must not outlive the underlying `mrb_value`, which
+/// may be garbage collected by mruby. Callers should not hold the reference
+/// for longer than the current FFI trampoline entry point.
+pub unsafe fn unwrap_ref<'a, T>(value: &'a Value, interp: &mut Artichoke) -> Result<&'a T, Error>
+where
+    T: Any,
+{
+    let data = wrapped_data(value, interp)?;
+    if let Some(unwrapped) = data.data.downcast_ref::<T>() {
+        Ok(unwrapped)
+    } else {
+        let mut message = String::from("Could not extract Rust type ");
+        message.push_str(any::type_name::<T>());
+        message.push_str(" from receiver; found ");
+        message.push_str(data.type_name);
+        Err(TypeError::from(message).into())
+    }
+}
+
+/// Extract a unique reference to the Rust value of type `T` wrapped in a Ruby
+/// data object by [`wrap`] or [`wrap_into`].
+///
+/// # Errors
+///
+/// If the value is not a data object created by [`wrap`], if the data object
+/// was allocated but never initialized, or if the wrapped value is not a `T`,
+/// a [`TypeError`] is returned. The wrong-type error names both the expected
+/// and the actual Rust type.
+///
+/// # Safety
+///
+/// The returned reference must not outlive the underlying `mrb_value`, which
+/// may be garbage collected by mruby. Callers should not hold the reference
+/// for longer than the current FFI trampoline entry point.
+pub unsafe fn unwrap_mut<'a, T>(value: &'a mut Value, interp: &mut Artichoke) -> Result<&'a mut T, Error>
+where
+    T: Any,
+{
+    let data = wrapped_data(value, interp)?;
+    let type_name = data.type_name;
+    let rdata = value.inner().value.p.cast::<sys::RData>();
+    let data = &mut *(*rdata).data.cast::<WrappedData>();
+    if let Some(unwrapped) = data.data.downcast_mut::<T>() {
+        Ok(unwrapped)
+    } else {
+        let mut message = String::from("Could not extract Rust type ");
+        message.push_str(any::type_name::<T>());
+        message.push_str(" from receiver; found ");
+        message.push_str(type_name);
+        Err(TypeError::from(message).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bstr::ByteSlice;
@@ -636,4 +876,241 @@ mod tests {
             err.message().as_ref().as_bstr()
         );
     }
+
+    #[test]
+    fn dup_of_frozen_string_is_not_frozen() {
+        let mut interp = interpreter().unwrap();
+        let mut value = interp.try_convert_mut("artichoke").unwrap();
+        value.freeze(&mut interp).unwrap();
+        assert!(value.is_frozen(&mut interp));
+
+        let dup = value.dup(&mut interp).unwrap();
+        assert!(!dup.is_frozen(&mut interp));
+        let contents = dup.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!("artichoke", contents);
+    }
+
+    #[test]
+    fn clone_of_frozen_string_is_frozen() {
+        let mut interp = interpreter().unwrap();
+        let mut value = interp.try_convert_mut("artichoke").unwrap();
+        value.freeze(&mut interp).unwrap();
+
+        let clone = value.clone_value(&mut interp, None).unwrap();
+        assert!(clone.is_frozen(&mut interp));
+        let contents = clone.try_convert_into_mut::<String>(&mut interp).unwrap();
+        assert_eq!("artichoke", contents);
+    }
+
+    #[test]
+    fn clone_with_freeze_false_yields_unfrozen_copy() {
+        let mut interp = interpreter().unwrap();
+        let mut value = interp.try_convert_mut("artichoke").unwrap();
+        value.freeze(&mut interp).unwrap();
+
+        let clone = value.clone_value(&mut interp, Some(false)).unwrap();
+        assert!(!clone.is_frozen(&mut interp));
+    }
+
+    #[test]
+    fn clone_with_freeze_true_yields_frozen_copy() {
+        let mut interp = interpreter().unwrap();
+        let value = interp.try_convert_mut("artichoke").unwrap();
+        assert!(!value.is_frozen(&mut interp));
+
+        let clone = value.clone_value(&mut interp, Some(true)).unwrap();
+        assert!(clone.is_frozen(&mut interp));
+    }
+
+    #[test]
+    fn dup_of_immediate_returns_self() {
+        let mut interp = interpreter().unwrap();
+        let value = interp.convert(29);
+        let dup = value.dup(&mut interp).unwrap();
+        assert_eq!(29, dup.try_convert_into::<i64>(&interp).unwrap());
+    }
+
+    #[test]
+    fn dup_and_clone_dispatch_initialize_copy() {
+        let mut interp = interpreter().unwrap();
+        interp
+            .eval(
+                b"class CopyObserver
+                    attr_reader :copied
+
+                    def initialize_copy(other)
+                      @copied = true
+                      super
+                    end
+                  end",
+            )
+            .unwrap();
+        let value = interp.eval(b"CopyObserver.new").unwrap();
+
+        let dup = value.dup(&mut interp).unwrap();
+        let result = dup
+            .funcall(&mut interp, "copied", &[], None)
+            .and_then(|value| value.try_convert_into::<bool>(&interp));
+        let observed = unwrap_or_panic_with_backtrace(&mut interp, "Value::dup", result);
+        assert!(observed);
+
+        let clone = value.clone_value(&mut interp, None).unwrap();
+        let result = clone
+            .funcall(&mut interp, "copied", &[], None)
+            .and_then(|value| value.try_convert_into::<bool>(&interp));
+        let observed = unwrap_or_panic_with_backtrace(&mut interp, "Value::clone_value", result);
+        assert!(observed);
+    }
+}
+
+#[cfg(test)]
+mod wrap_tests {
+    use crate::test::prelude::*;
+
+    // Example extension: a `Stopwatch` class which wraps a Rust struct that
+    // tracks accumulated ticks.
+    #[derive(Default, Debug, Clone, Copy)]
+    struct Stopwatch {
+        ticks: i64,
+    }
+
+    #[derive(Default, Debug, Clone, Copy)]
+    struct NotAStopwatch;
+
+    unsafe extern "C" fn stopwatch_initialize(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        unwrap_interpreter!(mrb, to => guard);
+        let result = guard
+            .class_spec::<Stopwatch>()
+            .and_then(|spec| spec.ok_or_else(|| NotDefinedError::class("Stopwatch").into()))
+            .and_then(|spec| super::wrap_into(spec, Stopwatch::default(), Value::from(slf)));
+        match result {
+            Ok(value) => value.inner(),
+            Err(exception) => error::raise(guard, exception),
+        }
+    }
+
+    unsafe extern "C" fn stopwatch_tick(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        unwrap_interpreter!(mrb, to => guard);
+        let mut value = Value::from(slf);
+        let result = super::unwrap_mut::<Stopwatch>(&mut value, &mut guard);
+        match result {
+            Ok(stopwatch) => {
+                stopwatch.ticks += 1;
+                slf
+            }
+            Err(exception) => error::raise(guard, exception),
+        }
+    }
+
+    unsafe extern "C" fn stopwatch_ticks(mrb: *mut sys::mrb_state, slf: sys::mrb_value) -> sys::mrb_value {
+        mrb_get_args!(mrb, none);
+        unwrap_interpreter!(mrb, to => guard);
+        let value = Value::from(slf);
+        let result = super::unwrap_ref::<Stopwatch>(&value, &mut guard).map(|stopwatch| stopwatch.ticks);
+        match result {
+            Ok(ticks) => guard.convert(ticks).inner(),
+            Err(exception) => error::raise(guard, exception),
+        }
+    }
+
+    fn define_stopwatch(interp: &mut Artichoke) {
+        let spec = class::Spec::new("Stopwatch", cstr::cstr!("Stopwatch"), None, Some(super::wrap_free)).unwrap();
+        class::Builder::for_spec(interp, &spec)
+            .value_is_rust_object()
+            .add_method("initialize", stopwatch_initialize, sys::mrb_args_none())
+            .unwrap()
+            .add_method("tick", stopwatch_tick, sys::mrb_args_none())
+            .unwrap()
+            .add_method("ticks", stopwatch_ticks, sys::mrb_args_none())
+            .unwrap()
+            .define()
+            .unwrap();
+        interp.def_class::<Stopwatch>(spec).unwrap();
+    }
+
+    #[test]
+    fn wrap_roundtrip_from_ruby() {
+        let mut interp = interpreter().unwrap();
+        define_stopwatch(&mut interp);
+
+        let result = interp.eval(b"watch = Stopwatch.new; 3.times { watch.tick }; watch.ticks").unwrap();
+        let ticks = result.try_convert_into::<i64>(&interp).unwrap();
+        assert_eq!(ticks, 3);
+    }
+
+    #[test]
+    fn wrap_roundtrip_from_rust() {
+        // Extension authors keep the `Spec` alive for as long as the
+        // interpreter; declare it first so the data type it owns outlives the
+        // final garbage collection on teardown.
+        let spec = class::Spec::new("Stopwatch", cstr::cstr!("Stopwatch"), None, Some(super::wrap_free)).unwrap();
+        let mut interp = interpreter().unwrap();
+        define_stopwatch(&mut interp);
+
+        let value = super::wrap(&mut interp, &spec, Stopwatch { ticks: 7 }).unwrap();
+        let stopwatch = unsafe { super::unwrap_ref::<Stopwatch>(&value, &mut interp) }.unwrap();
+        assert_eq!(stopwatch.ticks, 7);
+
+        let ticks = value.funcall(&mut interp, "ticks", &[], None).unwrap();
+        let ticks = ticks.try_convert_into::<i64>(&interp).unwrap();
+        assert_eq!(ticks, 7);
+    }
+
+    #[test]
+    fn wrapped_values_survive_gc_inside_arrays() {
+        let mut interp = interpreter().unwrap();
+        define_stopwatch(&mut interp);
+
+        interp
+            .eval(b"WATCHES = []; 100.times { WATCHES << Stopwatch.new }")
+            .unwrap();
+        interp.full_gc().unwrap();
+        let result = interp
+            .eval(b"WATCHES.each { |watch| 2.times { watch.tick } }; WATCHES.map(&:ticks).uniq")
+            .unwrap();
+        let ticks = result.try_convert_into_mut::<Vec<i64>>(&mut interp).unwrap();
+        assert_eq!(ticks, vec![2]);
+
+        // Dropping the only reference and collecting must free the wrapped
+        // values without crashing.
+        interp.eval(b"Object.send(:remove_const, :WATCHES)").unwrap();
+        interp.full_gc().unwrap();
+    }
+
+    #[test]
+    fn unwrap_with_wrong_type_is_type_error() {
+        let mut interp = interpreter().unwrap();
+        define_stopwatch(&mut interp);
+
+        let value = interp.eval(b"Stopwatch.new").unwrap();
+        let error = unsafe { super::unwrap_ref::<NotAStopwatch>(&value, &mut interp) }.unwrap_err();
+        assert_eq!(error.name(), "TypeError");
+        let message = String::from_utf8(error.message().into_owned()).unwrap();
+        assert!(message.contains("NotAStopwatch"));
+        assert!(message.contains("Stopwatch"));
+    }
+
+    #[test]
+    fn unwrap_before_initialize_is_type_error() {
+        let mut interp = interpreter().unwrap();
+        define_stopwatch(&mut interp);
+
+        let value = interp.eval(b"Stopwatch.allocate").unwrap();
+        let error = unsafe { super::unwrap_ref::<Stopwatch>(&value, &mut interp) }.unwrap_err();
+        assert_eq!(error.name(), "TypeError");
+        let message = String::from_utf8(error.message().into_owned()).unwrap();
+        assert_eq!(message, "uninitialized Stopwatch");
+    }
+
+    #[test]
+    fn unwrap_non_data_object_is_type_error() {
+        let mut interp = interpreter().unwrap();
+        define_stopwatch(&mut interp);
+
+        let value = interp.eval(b"'not a stopwatch'").unwrap();
+        let error = unsafe { super::unwrap_ref::<Stopwatch>(&value, &mut interp) }.unwrap_err();
+        assert_eq!(error.name(), "TypeError");
+    }
 }
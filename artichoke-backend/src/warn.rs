@@ -1,9 +1,10 @@
 use std::fmt::Write;
 
-use crate::core::{ModuleRegistry, TryConvertMut, Value as _, Warn};
+use crate::core::{Intern, ModuleRegistry, TryConvertMut, Value as _, Warn, WarningCategory};
 use crate::def::NotDefinedError;
 use crate::error::Error;
 use crate::extn::core::exception::IOError;
+use crate::extn::core::symbol::Symbol;
 use crate::extn::core::warning::Warning;
 use crate::ffi::InterpreterExtractError;
 use crate::state::output::Output;
@@ -14,6 +15,33 @@ impl Warn for Artichoke {
     type Error = Error;
 
     fn warn(&mut self, message: &[u8]) -> Result<(), Self::Error> {
+        self.warn_with_category(message, WarningCategory::None)
+    }
+
+    fn warn_with_category(&mut self, message: &[u8], category: WarningCategory) -> Result<(), Self::Error> {
+        // Deliver the warning by funcalling `Warning.warn` so programs that
+        // override it can intercept warnings emitted by the interpreter.
+        //
+        // NOTE: Unlike MRI, the category is passed as an optional positional
+        // argument because native code cannot dispatch with keyword arguments
+        // on the mruby VM.
+        let warning = self
+            .module_of::<Warning>()?
+            .ok_or_else(|| NotDefinedError::module("Warning"))?;
+        let message_value = self.try_convert_mut(message)?;
+        let result = if let Some(category) = category.as_symbol() {
+            let category = self.intern_bytes(category.as_bytes())?;
+            let category = Symbol::alloc_value(category.into(), self)?;
+            warning.funcall(self, "warn", &[message_value, category], None)
+        } else {
+            warning.funcall(self, "warn", &[message_value], None)
+        };
+        if result.is_ok() {
+            return Ok(());
+        }
+
+        // If `Warning.warn` raises, fall back to writing the message directly
+        // to stderr so the warning is not lost.
         let state = self.state.as_deref_mut().ok_or_else(InterpreterExtractError::new)?;
         if let Err(err) = state.output.write_stderr(b"rb warning: ") {
             let mut message = String::from("Failed to write warning to $stderr: ");
@@ -30,11 +58,6 @@ impl Warn for Artichoke {
             write!(&mut message, "{}", err).map_err(WriteError::from)?;
             return Err(IOError::from(message).into());
         }
-        let warning = self
-            .module_of::<Warning>()?
-            .ok_or_else(|| NotDefinedError::module("Warning"))?;
-        let message = self.try_convert_mut(message)?;
-        warning.funcall(self, "warn", &[message], None)?;
         Ok(())
     }
 }
@@ -0,0 +1,371 @@
+//! Compare Ruby values with the spaceship operator.
+
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::convert::TryConvert;
+use crate::value::Value;
+
+/// Errors encountered when comparing Ruby values with `<=>`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompareError {
+    /// `<=>` returned nil, which means the two values are not comparable.
+    ///
+    /// This error corresponds to the `ArgumentError` raised by `Comparable`
+    /// methods when operands cannot be compared.
+    ComparisonFailed {
+        /// Class name of the receiver of the comparison.
+        left: String,
+        /// Class name of the value the receiver was compared to.
+        right: String,
+    },
+    /// [`Compare::clamp`] was called with a min argument that is greater than
+    /// the max argument.
+    MinGreaterThanMax,
+}
+
+impl fmt::Display for CompareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ComparisonFailed { left, right } => {
+                write!(f, "comparison of {} with {} failed", left, right)
+            }
+            Self::MinGreaterThanMax => f.write_str("min argument must be smaller than max argument"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompareError {}
+
+/// Compare Ruby values with the spaceship operator.
+///
+/// This trait provides `Comparable`-aware helpers on top of
+/// [`Value::funcall`] so extensions do not have to reimplement spaceship
+/// dispatch, integer mapping, and the canonical `ArgumentError` for
+/// incomparable operands.
+///
+/// `Compare` is implemented for every [`Value`] whose interpreter can convert
+/// the result of `<=>` to [`i64`] and whose error type can represent a
+/// [`CompareError`].
+pub trait Compare: Value<Arg = Self, Value = Self> + Clone + Sized
+where
+    Self::Artichoke: TryConvert<Self, i64, Error = Self::Error>,
+    Self::Error: From<CompareError>,
+{
+    /// Compare this value to another by funcalling `<=>`.
+    ///
+    /// A nil result from `<=>` maps to [`None`]; integer results map to an
+    /// [`Ordering`] by their sign.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is
+    /// returned.
+    ///
+    /// If `<=>` returns a non-nil value that cannot be converted to an
+    /// integer, then an error is returned.
+    fn compare(&self, interp: &mut Self::Artichoke, other: &Self) -> Result<Option<Ordering>, Self::Error> {
+        let result = self.funcall(interp, "<=>", &[other.clone()], None)?;
+        if result.is_nil() {
+            return Ok(None);
+        }
+        let cmp = result.try_convert_into::<i64>(interp)?;
+        Ok(Some(cmp.cmp(&0)))
+    }
+
+    /// Whether this value is between `min` and `max`, inclusive.
+    ///
+    /// This method implements `Comparable#between?`.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is
+    /// returned.
+    ///
+    /// If this value is not comparable to `min` or `max`, then a
+    /// [`CompareError::ComparisonFailed`] error is returned.
+    fn between(&self, interp: &mut Self::Artichoke, min: &Self, max: &Self) -> Result<bool, Self::Error> {
+        let lower = match self.compare(interp, min)? {
+            Some(ordering) => ordering,
+            None => return Err(comparison_failed(interp, self, min).into()),
+        };
+        if lower == Ordering::Less {
+            return Ok(false);
+        }
+        let upper = match self.compare(interp, max)? {
+            Some(ordering) => ordering,
+            None => return Err(comparison_failed(interp, self, max).into()),
+        };
+        Ok(upper != Ordering::Greater)
+    }
+
+    /// Clamp this value to the range `min..=max`.
+    ///
+    /// This method implements `Comparable#clamp` with a min and max argument:
+    /// it returns `min` if this value is less than `min`, `max` if it is
+    /// greater than `max`, and this value otherwise.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is
+    /// returned.
+    ///
+    /// If `min` is greater than `max`, then a
+    /// [`CompareError::MinGreaterThanMax`] error is returned.
+    ///
+    /// If this value is not comparable to `min` or `max`, then a
+    /// [`CompareError::ComparisonFailed`] error is returned.
+    fn clamp(&self, interp: &mut Self::Artichoke, min: &Self, max: &Self) -> Result<Self, Self::Error> {
+        match min.compare(interp, max)? {
+            Some(Ordering::Greater) => return Err(CompareError::MinGreaterThanMax.into()),
+            Some(_) => {}
+            None => return Err(comparison_failed(interp, min, max).into()),
+        }
+        match self.compare(interp, min)? {
+            Some(Ordering::Less) => return Ok(min.clone()),
+            Some(_) => {}
+            None => return Err(comparison_failed(interp, self, min).into()),
+        }
+        match self.compare(interp, max)? {
+            Some(Ordering::Greater) => Ok(max.clone()),
+            Some(_) => Ok(self.clone()),
+            None => Err(comparison_failed(interp, self, max).into()),
+        }
+    }
+}
+
+impl<T> Compare for T
+where
+    T: Value<Arg = T, Value = T> + Clone,
+    T::Artichoke: TryConvert<T, i64, Error = T::Error>,
+    T::Error: From<CompareError>,
+{
+}
+
+/// Construct the canonical `comparison of X with Y failed` error for a failed
+/// comparison.
+fn comparison_failed<T>(interp: &mut T::Artichoke, left: &T, right: &T) -> CompareError
+where
+    T: Compare,
+    T::Artichoke: TryConvert<T, i64, Error = T::Error>,
+    T::Error: From<CompareError>,
+{
+    CompareError::ComparisonFailed {
+        left: class_name(interp, left),
+        right: class_name(interp, right),
+    }
+}
+
+/// Retrieve the class name of a value, falling back to a placeholder if the
+/// interpreter raises while computing it.
+fn class_name<T>(interp: &mut T::Artichoke, value: &T) -> String
+where
+    T: Compare,
+    T::Artichoke: TryConvert<T, i64, Error = T::Error>,
+    T::Error: From<CompareError>,
+{
+    if let Ok(class) = value.funcall(interp, "class", &[], None) {
+        String::from_utf8_lossy(&class.to_s(interp)).into_owned()
+    } else {
+        String::from("?")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use super::{Compare, CompareError};
+    use crate::convert::TryConvert;
+    use crate::types::Ruby;
+    use crate::value::Value;
+
+    struct Interp;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum MockError {
+        UnexpectedCall,
+        Convert,
+        Compare(CompareError),
+    }
+
+    impl From<CompareError> for MockError {
+        fn from(err: CompareError) -> Self {
+            Self::Compare(err)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Mock {
+        Int(i64),
+        Incomparable,
+        Nil,
+        Class(&'static str),
+    }
+
+    impl Value for Mock {
+        type Artichoke = Interp;
+        type Arg = Self;
+        type Value = Self;
+        type Block = ();
+        type Error = MockError;
+
+        fn funcall(
+            &self,
+            _interp: &mut Interp,
+            func: &str,
+            args: &[Self],
+            _block: Option<()>,
+        ) -> Result<Self, MockError> {
+            match func {
+                "<=>" => match (self, args.first()) {
+                    (Self::Int(left), Some(Self::Int(right))) => Ok(Self::Int(match left.cmp(right) {
+                        Ordering::Less => -1,
+                        Ordering::Equal => 0,
+                        Ordering::Greater => 1,
+                    })),
+                    _ => Ok(Self::Nil),
+                },
+                "class" => match self {
+                    Self::Int(_) => Ok(Self::Class("Integer")),
+                    Self::Incomparable => Ok(Self::Class("Incomparable")),
+                    Self::Nil => Ok(Self::Class("NilClass")),
+                    Self::Class(_) => Ok(Self::Class("Class")),
+                },
+                _ => Err(MockError::UnexpectedCall),
+            }
+        }
+
+        fn dup(&self, _interp: &mut Interp) -> Result<Self, MockError> {
+            Ok(self.clone())
+        }
+
+        fn clone_value(&self, _interp: &mut Interp, _freeze: Option<bool>) -> Result<Self, MockError> {
+            Ok(self.clone())
+        }
+
+        fn freeze(&mut self, _interp: &mut Interp) -> Result<(), MockError> {
+            Ok(())
+        }
+
+        fn is_frozen(&self, _interp: &mut Interp) -> bool {
+            false
+        }
+
+        fn is_nil(&self) -> bool {
+            matches!(self, Self::Nil)
+        }
+
+        fn respond_to(&self, _interp: &mut Interp, _method: &str) -> Result<bool, MockError> {
+            Ok(false)
+        }
+
+        fn inspect(&self, interp: &mut Interp) -> Vec<u8> {
+            self.to_s(interp)
+        }
+
+        fn to_s(&self, _interp: &mut Interp) -> Vec<u8> {
+            match self {
+                Self::Int(int) => int.to_string().into_bytes(),
+                Self::Incomparable => Vec::from(&b"incomparable"[..]),
+                Self::Nil => Vec::new(),
+                Self::Class(name) => Vec::from(name.as_bytes()),
+            }
+        }
+
+        fn ruby_type(&self) -> Ruby {
+            Ruby::Object
+        }
+    }
+
+    impl TryConvert<Mock, i64> for Interp {
+        type Error = MockError;
+
+        fn try_convert(&self, value: Mock) -> Result<i64, MockError> {
+            if let Mock::Int(int) = value {
+                Ok(int)
+            } else {
+                Err(MockError::Convert)
+            }
+        }
+    }
+
+    #[test]
+    fn compare_maps_spaceship_results_to_orderings() {
+        let mut interp = Interp;
+        assert_eq!(
+            Mock::Int(1).compare(&mut interp, &Mock::Int(2)),
+            Ok(Some(Ordering::Less))
+        );
+        assert_eq!(
+            Mock::Int(2).compare(&mut interp, &Mock::Int(2)),
+            Ok(Some(Ordering::Equal))
+        );
+        assert_eq!(
+            Mock::Int(3).compare(&mut interp, &Mock::Int(2)),
+            Ok(Some(Ordering::Greater))
+        );
+    }
+
+    #[test]
+    fn compare_maps_nil_spaceship_results_to_none() {
+        let mut interp = Interp;
+        assert_eq!(Mock::Int(1).compare(&mut interp, &Mock::Incomparable), Ok(None));
+    }
+
+    #[test]
+    fn between_is_inclusive_of_both_bounds() {
+        let mut interp = Interp;
+        let min = Mock::Int(1);
+        let max = Mock::Int(10);
+        assert_eq!(Mock::Int(5).between(&mut interp, &min, &max), Ok(true));
+        assert_eq!(Mock::Int(1).between(&mut interp, &min, &max), Ok(true));
+        assert_eq!(Mock::Int(10).between(&mut interp, &min, &max), Ok(true));
+        assert_eq!(Mock::Int(0).between(&mut interp, &min, &max), Ok(false));
+        assert_eq!(Mock::Int(11).between(&mut interp, &min, &max), Ok(false));
+    }
+
+    #[test]
+    fn clamp_clamps_to_the_bounds() {
+        let mut interp = Interp;
+        let min = Mock::Int(1);
+        let max = Mock::Int(10);
+        assert_eq!(Mock::Int(5).clamp(&mut interp, &min, &max), Ok(Mock::Int(5)));
+        assert_eq!(Mock::Int(0).clamp(&mut interp, &min, &max), Ok(Mock::Int(1)));
+        assert_eq!(Mock::Int(11).clamp(&mut interp, &min, &max), Ok(Mock::Int(10)));
+    }
+
+    #[test]
+    fn clamp_rejects_min_greater_than_max() {
+        let mut interp = Interp;
+        assert_eq!(
+            Mock::Int(5).clamp(&mut interp, &Mock::Int(10), &Mock::Int(1)),
+            Err(MockError::Compare(CompareError::MinGreaterThanMax))
+        );
+    }
+
+    #[test]
+    fn incomparable_operands_produce_the_canonical_error() {
+        let mut interp = Interp;
+        let err = Mock::Int(5)
+            .between(&mut interp, &Mock::Incomparable, &Mock::Int(10))
+            .unwrap_err();
+        let err = match err {
+            MockError::Compare(err) => err,
+            err => panic!("expected comparison failure, got {:?}", err),
+        };
+        assert_eq!(
+            err,
+            CompareError::ComparisonFailed {
+                left: String::from("Integer"),
+                right: String::from("Incomparable"),
+            }
+        );
+        assert_eq!(err.to_string(), "comparison of Integer with Incomparable failed");
+    }
+}
@@ -55,4 +55,74 @@ pub trait DefineConstant {
     fn define_module_constant<T>(&mut self, constant: &str, value: Self::Value) -> Result<(), Self::Error>
     where
         T: 'static;
+
+    /// Retrieve the value of a global constant.
+    ///
+    /// Returns [`None`] if the constant is not defined.
+    ///
+    /// # Errors
+    ///
+    /// If the given constant name is not valid, an error is returned.
+    ///
+    /// If the interpreter cannot read the constant table, an error is returned.
+    fn get_global_constant(&mut self, constant: &str) -> Result<Option<Self::Value>, Self::Error>;
+
+    /// Retrieve the value of a class constant.
+    ///
+    /// The class is specified by the type parameter `T`. Returns [`None`] if
+    /// the constant is not defined.
+    ///
+    /// # Errors
+    ///
+    /// If the class named by type `T` is not defined, an error is returned.
+    ///
+    /// If the given constant name is not valid, an error is returned.
+    ///
+    /// If the interpreter cannot read the constant table, an error is returned.
+    fn get_class_constant<T>(&mut self, constant: &str) -> Result<Option<Self::Value>, Self::Error>
+    where
+        T: 'static;
+
+    /// Retrieve the value of a module constant.
+    ///
+    /// The module is specified by the type parameter `T`. Returns [`None`] if
+    /// the constant is not defined.
+    ///
+    /// # Errors
+    ///
+    /// If the module named by type `T` is not defined, an error is returned.
+    ///
+    /// If the given constant name is not valid, an error is returned.
+    ///
+    /// If the interpreter cannot read the constant table, an error is returned.
+    fn get_module_constant<T>(&mut self, constant: &str) -> Result<Option<Self::Value>, Self::Error>
+    where
+        T: 'static;
+
+    /// Check whether a constant is defined on the class or module specified by
+    /// the type parameter `T`.
+    ///
+    /// # Errors
+    ///
+    /// If the class or module named by type `T` is not defined, an error is
+    /// returned.
+    ///
+    /// If the given constant name is not valid, an error is returned.
+    fn constant_defined<T>(&mut self, constant: &str) -> Result<bool, Self::Error>
+    where
+        T: 'static;
+
+    /// Remove a class constant and return the removed value.
+    ///
+    /// The class is specified by the type parameter `T`.
+    ///
+    /// # Errors
+    ///
+    /// If the class named by type `T` is not defined, an error is returned.
+    ///
+    /// If the given constant name is not valid or the constant is not defined,
+    /// an error is returned.
+    fn remove_class_constant<T>(&mut self, constant: &str) -> Result<Self::Value, Self::Error>
+    where
+        T: 'static;
 }
@@ -0,0 +1,49 @@
+//! Get and set environment variables on an interpreter.
+//!
+//! Environment variables are accessed from Ruby with the `ENV` singleton
+//! object. Implementations may proxy the host process environment or emulate
+//! an environment with an in-memory store, which keeps `ENV` functional on
+//! targets without process environment APIs, such as WebAssembly.
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+/// Get and set environment variables on an interpreter.
+///
+/// Environment variable names and values are byte vectors. Implementations
+/// that proxy the host system are expected to convert names and values to
+/// platform strings internally.
+pub trait Environ {
+    /// Concrete error type for failures accessing the environment.
+    type Error;
+
+    /// Retrieve the value of the environment variable pointed to by `name`.
+    ///
+    /// Returns [`None`] if the variable is not set.
+    ///
+    /// # Errors
+    ///
+    /// If `name` contains a NUL byte, an error is returned.
+    fn get(&self, name: &[u8]) -> Result<Option<Cow<'_, [u8]>>, Self::Error>;
+
+    /// Set the environment variable pointed to by `name` to `value`.
+    ///
+    /// If `value` is [`None`], the variable is removed from the environment.
+    /// Removing a variable that is not set is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// If `name` contains an `=` or NUL byte, an error is returned.
+    ///
+    /// If `value` is [`Some`] and contains a NUL byte, an error is returned.
+    fn set(&mut self, name: &[u8], value: Option<&[u8]>) -> Result<(), Self::Error>;
+
+    /// Retrieve a snapshot of the environment as name-value pairs.
+    ///
+    /// Pairs are returned in an arbitrary order.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying platform APIs return an error, an error is returned.
+    fn to_map(&self) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Self::Error>;
+}
@@ -1,5 +1,7 @@
 //! I/O read and write APIs.
 
+use alloc::vec::Vec;
+
 /// Make I/O external to the interpreter.
 pub trait Io {
     /// Concrete error type for errors encountered when reading and writing.
@@ -25,4 +27,21 @@ pub trait Io {
         self.print("\n")?;
         Ok(())
     }
+
+    /// Writes the given bytes to the interpreter stderr stream.
+    ///
+    /// # Errors
+    ///
+    /// If the output stream encounters an error, an error is returned.
+    fn print_err<T: AsRef<[u8]>>(&mut self, message: T) -> Result<(), Self::Error>;
+
+    /// Reads a line from the interpreter stdin stream.
+    ///
+    /// The returned bytes include the trailing newline if the stream contains
+    /// one. `None` is returned when the stream is at EOF.
+    ///
+    /// # Errors
+    ///
+    /// If the input stream encounters an error, an error is returned.
+    fn read_line(&mut self) -> Result<Option<Vec<u8>>, Self::Error>;
 }
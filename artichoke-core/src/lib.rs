@@ -27,8 +27,12 @@
 //!
 //! Artichoke Core defines traits for the following interpreter capabilities:
 //!
+//! - [`Compare`](compare::Compare): Compare Ruby [`Value`](value::Value)s with
+//!   the spaceship operator.
 //! - [`DefineConstant`](constant::DefineConstant): Define global, class, and
 //!   module constants to be arbitrary Ruby [`Value`](value::Value)s.
+//! - [`Environ`](env::Environ): Get and set environment variables for the
+//!   `ENV` singleton object.
 //! - [`Eval`](eval::Eval): Execute Ruby source code on an interpreter from
 //!   various sources.
 //! - [`Globals`](globals::Globals): Get, set, and unset interpreter-level
@@ -47,6 +51,8 @@
 //! - [`Regexp`](regexp::Regexp): Manipulate [`Regexp`] global state.
 //! - [`ReleaseMetadata`](release_metadata::ReleaseMetadata): Enable
 //!   interpreters to describe themselves.
+//! - [`SpawnProcess`](process::SpawnProcess): Spawn and wait on child
+//!   processes for `Kernel#system`, backticks, and `Process.spawn`.
 //! - [`TopSelf`](top_self::TopSelf): Access to the root execution context.
 //! - [`Warn`](warn::Warn): Emit warnings.
 //!
@@ -95,9 +101,11 @@ extern crate std;
 
 pub mod class_registry;
 pub mod coerce_to_numeric;
+pub mod compare;
 pub mod constant;
 pub mod convert;
 pub mod debug;
+pub mod env;
 pub mod eval;
 pub mod file;
 pub mod globals;
@@ -108,6 +116,7 @@ pub mod load;
 pub mod module_registry;
 pub mod parser;
 pub mod prng;
+pub mod process;
 pub mod regexp;
 pub mod release_metadata;
 pub mod top_self;
@@ -129,9 +138,11 @@ pub mod warn;
 pub mod prelude {
     pub use crate::class_registry::ClassRegistry;
     pub use crate::coerce_to_numeric::CoerceToNumeric;
+    pub use crate::compare::{Compare, CompareError};
     pub use crate::constant::DefineConstant;
     pub use crate::convert::{Convert, ConvertMut, TryConvert, TryConvertMut};
     pub use crate::debug::Debug;
+    pub use crate::env::Environ;
     pub use crate::eval::Eval;
     pub use crate::file::File;
     pub use crate::globals::Globals;
@@ -142,10 +153,11 @@ pub mod prelude {
     pub use crate::module_registry::ModuleRegistry;
     pub use crate::parser::{IncrementLinenoError, Parser};
     pub use crate::prng::Prng;
+    pub use crate::process::{ChildHandle, ChildStatus, CommandSpec, Program, SpawnProcess};
     pub use crate::regexp::Regexp;
     pub use crate::release_metadata::ReleaseMetadata;
     pub use crate::top_self::TopSelf;
     pub use crate::types::{Ruby, Rust};
     pub use crate::value::Value;
-    pub use crate::warn::Warn;
+    pub use crate::warn::{Warn, WarningCategory};
 }
@@ -140,6 +140,39 @@ pub trait LoadSources {
     where
         P: AsRef<Path>;
 
+    /// Remove a feature from the set of loaded features.
+    ///
+    /// Returns `true` if the feature at `path` was previously loaded and has
+    /// been removed, `false` if it was not loaded. Once a feature is unloaded,
+    /// [`require_source`] will evaluate its source again, which enables the
+    /// `$LOADED_FEATURES.delete(...)` and re-require pattern used to test
+    /// `require` semantics without constructing a fresh interpreter.
+    ///
+    /// The given `path` should match an entry returned by
+    /// [`loaded_features`], which is the absolutized path the feature was
+    /// required at.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying file system is inaccessible, an error is returned.
+    ///
+    /// [`require_source`]: Self::require_source
+    /// [`loaded_features`]: Self::loaded_features
+    fn unload_feature(&mut self, path: &[u8]) -> Result<bool, Self::Error>;
+
+    /// Retrieve the set of loaded features.
+    ///
+    /// Each entry is the absolutized path of a feature that has been loaded
+    /// with [`require_source`], equivalent to an entry in the
+    /// `$LOADED_FEATURES` global. Entries are returned in an arbitrary order.
+    ///
+    /// # Errors
+    ///
+    /// If the underlying file system is inaccessible, an error is returned.
+    ///
+    /// [`require_source`]: Self::require_source
+    fn loaded_features(&self) -> Result<Vec<Vec<u8>>, Self::Error>;
+
     /// Retrieve file contents for a source file.
     ///
     /// Query the underlying virtual file system for the file contents of the
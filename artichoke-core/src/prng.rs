@@ -25,4 +25,38 @@ pub trait Prng {
     ///
     /// If the PRNG is inaccessible, an error is returned.
     fn prng_mut(&mut self) -> Result<&mut Self::Prng, Self::Error>;
+
+    /// Return the seed used to initialize the interpreter pseudorandom number
+    /// generator.
+    ///
+    /// This seed backs `Random::DEFAULT.seed`.
+    ///
+    /// # Errors
+    ///
+    /// If the PRNG is inaccessible, an error is returned.
+    fn prng_seed(&self) -> Result<u64, Self::Error>;
+
+    /// Reseed the interpreter pseudorandom number generator and return the
+    /// previous seed.
+    ///
+    /// If `seed` is [`None`], the PRNG is reseeded with platform-provided
+    /// randomness. This API backs `Kernel#srand`, which returns the previous
+    /// seed.
+    ///
+    /// # Errors
+    ///
+    /// If the PRNG is inaccessible, an error is returned.
+    ///
+    /// If the PRNG cannot be reseeded, an error is returned.
+    fn prng_reseed(&mut self, seed: Option<u64>) -> Result<u64, Self::Error>;
+
+    /// Fill `buf` with bytes from the interpreter pseudorandom number
+    /// generator.
+    ///
+    /// This API backs `Random#bytes` and `SecureRandom`-style helpers.
+    ///
+    /// # Errors
+    ///
+    /// If the PRNG is inaccessible, an error is returned.
+    fn prng_fill_bytes(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
 }
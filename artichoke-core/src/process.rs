@@ -0,0 +1,266 @@
+//! Spawn and wait on child processes from an interpreter.
+//!
+//! Subprocess execution is reachable from Ruby through `Kernel#system`,
+//! backticks, and `Process.spawn`. Implementations may proxy the host
+//! operating system or deny all execution, which keeps these APIs safe to
+//! expose from sandboxed embedders and on targets without process APIs, such
+//! as WebAssembly.
+
+use alloc::vec::Vec;
+
+/// Shell metacharacters which force a command line through the shell.
+///
+/// This byte set matches the `posix_sh_chars` list MRI uses to decide whether
+/// a command string given to `Kernel#system` and friends is executed directly
+/// or via `sh -c`.
+const SHELL_METACHARACTERS: &[u8] = b"*?{}[]<>()~&|\\$;'`\"\n#";
+
+/// A description of a child process to spawn.
+///
+/// A `CommandSpec` captures the program to run — either an argument vector
+/// executed directly or a command line interpreted by the platform shell —
+/// and whether the child's stdout should be captured, which backs backticks.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct CommandSpec {
+    program: Program,
+    capture_stdout: bool,
+}
+
+impl CommandSpec {
+    /// Construct a spec which runs `command` through the platform shell.
+    #[must_use]
+    pub fn shell(command: Vec<u8>) -> Self {
+        Self {
+            program: Program::Shell(command),
+            capture_stdout: false,
+        }
+    }
+
+    /// Construct a spec which executes an argument vector directly.
+    ///
+    /// The first element of `argv` is the program to execute; the remaining
+    /// elements are passed to it as arguments.
+    #[must_use]
+    pub fn argv(argv: Vec<Vec<u8>>) -> Self {
+        Self {
+            program: Program::Argv(argv),
+            capture_stdout: false,
+        }
+    }
+
+    /// Construct a spec from a single command string with MRI's shell
+    /// dispatch rules.
+    ///
+    /// Command strings which contain shell metacharacters are run through the
+    /// platform shell; all other command strings are split on whitespace and
+    /// executed directly.
+    #[must_use]
+    pub fn parse(command: &[u8]) -> Self {
+        if command.iter().any(|byte| SHELL_METACHARACTERS.contains(byte)) {
+            return Self::shell(command.to_vec());
+        }
+        let argv = command
+            .split(|&byte| matches!(byte, b' ' | b'\t'))
+            .filter(|word| !word.is_empty())
+            .map(<[u8]>::to_vec)
+            .collect::<Vec<_>>();
+        if argv.is_empty() {
+            Self::shell(command.to_vec())
+        } else {
+            Self::argv(argv)
+        }
+    }
+
+    /// Capture the child's stdout instead of inheriting the parent's stream.
+    ///
+    /// Captured output is returned by [`ChildStatus::stdout`] after the child
+    /// is waited on. This backs backticks.
+    #[must_use]
+    pub fn capture_stdout(mut self) -> Self {
+        self.capture_stdout = true;
+        self
+    }
+
+    /// Return the program this spec executes.
+    #[must_use]
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Whether the child's stdout is captured.
+    #[must_use]
+    pub fn captures_stdout(&self) -> bool {
+        self.capture_stdout
+    }
+}
+
+/// The program a [`CommandSpec`] executes.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum Program {
+    /// A command line interpreted by the platform shell, e.g. `sh -c`.
+    Shell(Vec<u8>),
+    /// A program and arguments executed directly without shell expansion.
+    Argv(Vec<Vec<u8>>),
+}
+
+/// An opaque handle to a spawned child process.
+///
+/// Handles are redeemed for a [`ChildStatus`] with [`SpawnProcess::wait`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChildHandle {
+    pid: i64,
+}
+
+impl ChildHandle {
+    /// Construct a handle to the child process with the given pid.
+    #[must_use]
+    pub fn new(pid: i64) -> Self {
+        Self { pid }
+    }
+
+    /// Return the process ID of the child.
+    #[must_use]
+    pub fn pid(self) -> i64 {
+        self.pid
+    }
+}
+
+/// The exit disposition of a waited-on child process.
+///
+/// This is the backing data for `Process::Status` and the `$?` global.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ChildStatus {
+    pid: i64,
+    exitstatus: Option<i64>,
+    termsig: Option<i64>,
+    stdout: Option<Vec<u8>>,
+}
+
+impl ChildStatus {
+    /// Construct a status for a child which exited with the given exit code.
+    #[must_use]
+    pub fn exited(pid: i64, exitstatus: i64) -> Self {
+        Self {
+            pid,
+            exitstatus: Some(exitstatus),
+            termsig: None,
+            stdout: None,
+        }
+    }
+
+    /// Construct a status for a child terminated by the given signal.
+    #[must_use]
+    pub fn signaled(pid: i64, termsig: i64) -> Self {
+        Self {
+            pid,
+            exitstatus: None,
+            termsig: Some(termsig),
+            stdout: None,
+        }
+    }
+
+    /// Attach the stdout bytes captured from the child.
+    #[must_use]
+    pub fn with_stdout(mut self, stdout: Vec<u8>) -> Self {
+        self.stdout = Some(stdout);
+        self
+    }
+
+    /// Return the process ID of the child.
+    #[must_use]
+    pub fn pid(&self) -> i64 {
+        self.pid
+    }
+
+    /// Return the exit code if the child exited normally.
+    ///
+    /// This backs `Process::Status#exitstatus`, which returns `nil` for
+    /// children terminated by a signal.
+    #[must_use]
+    pub fn exitstatus(&self) -> Option<i64> {
+        self.exitstatus
+    }
+
+    /// Return the signal which terminated the child, if any.
+    #[must_use]
+    pub fn termsig(&self) -> Option<i64> {
+        self.termsig
+    }
+
+    /// Whether the child was terminated by a signal.
+    ///
+    /// This backs `Process::Status#signaled?`.
+    #[must_use]
+    pub fn is_signaled(&self) -> bool {
+        self.termsig.is_some()
+    }
+
+    /// Whether the child exited normally with a zero exit code.
+    ///
+    /// Returns [`None`] if the child did not exit normally, which backs
+    /// `Process::Status#success?` returning `nil` for signaled children.
+    #[must_use]
+    pub fn is_success(&self) -> Option<bool> {
+        self.exitstatus.map(|exitstatus| exitstatus == 0)
+    }
+
+    /// Return the stdout bytes captured from the child, if the spawn
+    /// requested capturing.
+    #[must_use]
+    pub fn stdout(&self) -> Option<&[u8]> {
+        self.stdout.as_deref()
+    }
+
+    /// Take the stdout bytes captured from the child, if the spawn requested
+    /// capturing.
+    #[must_use]
+    pub fn take_stdout(&mut self) -> Option<Vec<u8>> {
+        self.stdout.take()
+    }
+}
+
+/// Spawn and wait on child processes.
+///
+/// Implementors of this trait back `Kernel#system`, backticks,
+/// `Process.spawn`, and `Process.wait`.
+pub trait SpawnProcess {
+    /// Concrete error type for failures spawning and waiting on children.
+    type Error;
+
+    /// Spawn a child process described by `command`.
+    ///
+    /// Returns [`None`] if the command cannot be executed, for example if the
+    /// program does not exist. This backs `Kernel#system` returning `nil`
+    /// when command execution fails.
+    ///
+    /// # Errors
+    ///
+    /// If the implementation denies subprocess execution, an error is
+    /// returned.
+    fn spawn(&mut self, command: CommandSpec) -> Result<Option<ChildHandle>, Self::Error>;
+
+    /// Wait for the child process behind `child` to exit and return its exit
+    /// disposition.
+    ///
+    /// Waiting records the returned status as the last status, which backs
+    /// the `$?` global.
+    ///
+    /// # Errors
+    ///
+    /// If `child` does not refer to a spawned child process, an error is
+    /// returned.
+    ///
+    /// If the implementation denies subprocess execution, an error is
+    /// returned.
+    fn wait(&mut self, child: ChildHandle) -> Result<ChildStatus, Self::Error>;
+
+    /// Return the status of the most recently waited-on child process.
+    ///
+    /// This backs the `$?` global. Returns [`None`] if no child has been
+    /// waited on.
+    ///
+    /// # Errors
+    ///
+    /// If the status is inaccessible, an error is returned.
+    fn last_status(&self) -> Result<Option<ChildStatus>, Self::Error>;
+}
@@ -67,6 +67,31 @@ pub trait Value {
         interp.try_convert_mut(self)
     }
 
+    /// Call `#dup` on this [`Value`].
+    ///
+    /// `dup` produces a shallow copy of this value. Per MRI semantics, the
+    /// copy does not carry the frozen state or singleton class of the
+    /// receiver. Classes which override `initialize_copy` observe the copy.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is returned.
+    fn dup(&self, interp: &mut Self::Artichoke) -> Result<Self::Value, Self::Error>;
+
+    /// Call `#clone` on this [`Value`].
+    ///
+    /// `clone` produces a shallow copy of this value which preserves the
+    /// frozen state and singleton class of the receiver. Passing
+    /// `Some(false)` for `freeze` is equivalent to MRI's
+    /// `clone(freeze: false)` and yields an unfrozen copy; `Some(true)`
+    /// freezes the copy unconditionally. Classes which override
+    /// `initialize_copy` observe the copy.
+    ///
+    /// # Errors
+    ///
+    /// If an exception is raised on the interpreter, then an error is returned.
+    fn clone_value(&self, interp: &mut Self::Artichoke, freeze: Option<bool>) -> Result<Self::Value, Self::Error>;
+
     /// Call `#freeze` on this [`Value`].
     ///
     /// # Errors
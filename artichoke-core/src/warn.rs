@@ -1,5 +1,40 @@
 //! Emit warnings during interpreter execution.
 
+/// The category of a warning.
+///
+/// MRI allows warnings to be tagged with a category so `Warning.warn`
+/// overrides can dispatch on the kind of warning being emitted.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WarningCategory {
+    /// Warnings for deprecated features that are scheduled for removal.
+    Deprecated,
+    /// Warnings for experimental features whose behavior may change.
+    Experimental,
+    /// Warnings that do not belong to a category.
+    None,
+}
+
+impl Default for WarningCategory {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl WarningCategory {
+    /// Return the name of this category as it appears in Ruby source, for
+    /// example in `Warning[:deprecated]`.
+    ///
+    /// [`WarningCategory::None`] has no name and returns [`None`].
+    #[must_use]
+    pub const fn as_symbol(self) -> Option<&'static str> {
+        match self {
+            Self::Deprecated => Some("deprecated"),
+            Self::Experimental => Some("experimental"),
+            Self::None => None,
+        }
+    }
+}
+
 /// Emit warnings during interpreter execution to stderr.
 ///
 /// Some functionality required to be compliant with ruby/spec is deprecated or
@@ -22,4 +57,23 @@ pub trait Warn {
     ///
     /// If an exception is raised on the interpreter, then an error is returned.
     fn warn(&mut self, message: &[u8]) -> Result<(), Self::Error>;
+
+    /// Emit a categorized warning message using `Warning#warn`.
+    ///
+    /// Implementations should deliver the category along with the message so
+    /// `Warning.warn` overrides can dispatch on it. The default implementation
+    /// discards the category and delegates to [`warn`].
+    ///
+    /// # Errors
+    ///
+    /// Interpreters should issue warnings by calling the `warn` method on the
+    /// `Warning` module.
+    ///
+    /// If an exception is raised on the interpreter, then an error is returned.
+    ///
+    /// [`warn`]: Self::warn
+    fn warn_with_category(&mut self, message: &[u8], category: WarningCategory) -> Result<(), Self::Error> {
+        let _ = category;
+        self.warn(message)
+    }
 }
@@ -311,4 +311,42 @@ impl Rubylib {
             "file not found in RUBYLIB load path",
         ))
     }
+
+    /// Remove a source at `path` from the set of loaded features.
+    ///
+    /// Returns `true` if the source was previously marked as required and has
+    /// been removed, which permits [`Kernel#require`] to load it again.
+    ///
+    /// If `path` is relative, it is absolutized relative to each path in the
+    /// `RUBYLIB` environment variable at the time this loader was initialized.
+    ///
+    /// This method is infallible and will return [`None`] for paths that do
+    /// not resolve to a file in the `RUBYLIB` load paths.
+    ///
+    /// [`Kernel#require`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-require
+    #[inline]
+    #[must_use]
+    pub fn unload_feature(&mut self, path: &Path) -> Option<bool> {
+        // The `Rubylib` loader only loads relative paths in `RUBYLIB`.
+        if path.is_absolute() {
+            return None;
+        }
+        for load_path in &*self.load_paths {
+            let path = load_path.join(path);
+            if let Ok(handle) = Handle::from_path(&path) {
+                return Some(self.loaded_features.remove(&handle).is_some());
+            }
+        }
+        None
+    }
+
+    /// Retrieve the set of sources that have been marked as required.
+    ///
+    /// Each entry is the absolutized path a feature was required at. Entries
+    /// are returned in an arbitrary order.
+    #[inline]
+    #[must_use]
+    pub fn loaded_features(&self) -> Vec<&Path> {
+        self.loaded_features.values().map(PathBuf::as_path).collect()
+    }
 }
@@ -0,0 +1,84 @@
+//! Micro-benchmark for the ASCII lookup tables.
+//!
+//! Compares per-byte classification and case conversion through the 256-entry
+//! lookup tables in `scolapasta_string_escape` against the equivalent chains
+//! of `u8::is_ascii_*` predicates on a multi-megabyte ASCII input.
+//!
+//! Run with:
+//!
+//! ```console
+//! cargo bench --bench ascii
+//! ```
+
+use std::time::Instant;
+
+use scolapasta_string_escape::{is_ident_continue_ascii, is_ruby_whitespace, to_ascii_lower, to_ascii_upper};
+
+const INPUT_LEN: usize = 8 * 1024 * 1024;
+const ITERATIONS: u32 = 16;
+
+/// Generate a multi-megabyte ASCII input resembling Ruby source: mixed-case
+/// identifiers separated by whitespace and punctuation.
+fn input() -> Vec<u8> {
+    const SAMPLE: &[u8] = b"def Spinoso_string42!\n\tattr_reader :bytesize\r\n  CASE_fold \x0B\x0C end\0";
+    SAMPLE.iter().copied().cycle().take(INPUT_LEN).collect()
+}
+
+fn time<F>(name: &str, input: &[u8], f: F)
+where
+    F: Fn(&[u8]) -> usize,
+{
+    // Warm up and keep a checksum alive so the classification loops are not
+    // optimized away.
+    let mut checksum = f(input);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        checksum = checksum.wrapping_add(f(input));
+    }
+    let elapsed = start.elapsed();
+    let processed = input.len() as f64 * f64::from(ITERATIONS);
+    let throughput = processed / elapsed.as_secs_f64() / (1024.0 * 1024.0);
+    println!("{:<40} {:>10.1} MB/s (checksum {})", name, throughput, checksum);
+}
+
+fn main() {
+    let input = input();
+    println!(
+        "classifying {} MB of ASCII input, {} iterations per benchmark",
+        INPUT_LEN / (1024 * 1024),
+        ITERATIONS
+    );
+
+    time("whitespace: is_ascii_* predicate chain", &input, |input| {
+        input
+            .iter()
+            .filter(|&&byte| byte.is_ascii_whitespace() || byte == 0x0B || byte == b'\0')
+            .count()
+    });
+    time("whitespace: is_ruby_whitespace table", &input, |input| {
+        input.iter().filter(|&&byte| is_ruby_whitespace(byte)).count()
+    });
+
+    time("ident: is_ascii_* predicate chain", &input, |input| {
+        input
+            .iter()
+            .filter(|&&byte| byte.is_ascii_alphanumeric() || byte == b'_')
+            .count()
+    });
+    time("ident: is_ident_continue_ascii table", &input, |input| {
+        input.iter().filter(|&&byte| is_ident_continue_ascii(byte)).count()
+    });
+
+    time("case: to_ascii_uppercase/lowercase", &input, |input| {
+        input
+            .iter()
+            .map(|&byte| usize::from(byte.to_ascii_uppercase()) + usize::from(byte.to_ascii_lowercase()))
+            .sum()
+    });
+    time("case: to_ascii_upper/lower table", &input, |input| {
+        input
+            .iter()
+            .map(|&byte| usize::from(to_ascii_upper(byte)) + usize::from(to_ascii_lower(byte)))
+            .sum()
+    });
+}
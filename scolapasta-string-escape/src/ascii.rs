@@ -0,0 +1,219 @@
+//! Branch-free ASCII classification and case conversion.
+//!
+//! Ruby String and Symbol APIs classify and case convert bytes in tight
+//! per-byte loops — capitalization, identifier parsing, and whitespace
+//! stripping. Chaining `u8::is_ascii_*` predicates compiles to a branch per
+//! class per byte. The routines in this module dispatch through 256-entry
+//! lookup tables instead, which classify or convert each byte with a single
+//! indexed load.
+
+/// Bit set in [`CTYPE`] for bytes Ruby treats as whitespace.
+const WHITESPACE: u8 = 1 << 0;
+/// Bit set in [`CTYPE`] for bytes which can begin an ASCII identifier.
+const IDENT_START: u8 = 1 << 1;
+/// Bit set in [`CTYPE`] for bytes which can continue an ASCII identifier.
+const IDENT_CONTINUE: u8 = 1 << 2;
+
+/// Byte classification table. Each entry is a bit set of the `WHITESPACE`,
+/// `IDENT_START`, and `IDENT_CONTINUE` flags.
+const CTYPE: [u8; 256] = ctype_table();
+
+/// Case conversion table mapping ASCII lowercase bytes to uppercase.
+const UPPER: [u8; 256] = case_table(true);
+
+/// Case conversion table mapping ASCII uppercase bytes to lowercase.
+const LOWER: [u8; 256] = case_table(false);
+
+const fn ctype_table() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut byte = 0_u8;
+    loop {
+        let mut flags = 0;
+        // Ruby's whitespace set for `String#strip` and friends: NUL, HT, LF,
+        // VT, FF, CR, and SP.
+        if matches!(byte, b'\0' | b'\t' | b'\n' | 0x0B | 0x0C | b'\r' | b' ') {
+            flags |= WHITESPACE;
+        }
+        if byte.is_ascii_alphabetic() || byte == b'_' {
+            flags |= IDENT_START | IDENT_CONTINUE;
+        }
+        if byte.is_ascii_digit() {
+            flags |= IDENT_CONTINUE;
+        }
+        table[byte as usize] = flags;
+        byte = match byte.checked_add(1) {
+            Some(byte) => byte,
+            None => return table,
+        };
+    }
+}
+
+const fn case_table(upper: bool) -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut byte = 0_u8;
+    loop {
+        table[byte as usize] = match byte {
+            b @ b'a'..=b'z' if upper => b & !0x20,
+            b @ b'A'..=b'Z' if !upper => b | 0x20,
+            b => b,
+        };
+        byte = match byte.checked_add(1) {
+            Some(byte) => byte,
+            None => return table,
+        };
+    }
+}
+
+/// Return whether the given byte is in Ruby's whitespace set.
+///
+/// Ruby's whitespace set — used by `String#strip` and friends — is ASCII
+/// whitespace (space, `\t`, `\n`, `\v`, `\f`, and `\r`) plus NUL.
+///
+/// # Examples
+///
+/// ```
+/// # use scolapasta_string_escape::is_ruby_whitespace;
+/// assert!(is_ruby_whitespace(b' '));
+/// assert!(is_ruby_whitespace(b'\t'));
+/// assert!(is_ruby_whitespace(b'\0'));
+/// assert!(!is_ruby_whitespace(b'a'));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_ruby_whitespace(byte: u8) -> bool {
+    CTYPE[byte as usize] & WHITESPACE != 0
+}
+
+/// Return whether the given byte can begin an ASCII Ruby identifier.
+///
+/// ASCII identifiers begin with an ASCII alphabetic byte or an underscore.
+/// Bytes outside the ASCII range are not classified by this predicate and
+/// always return `false`.
+///
+/// # Examples
+///
+/// ```
+/// # use scolapasta_string_escape::is_ident_start_ascii;
+/// assert!(is_ident_start_ascii(b'a'));
+/// assert!(is_ident_start_ascii(b'_'));
+/// assert!(!is_ident_start_ascii(b'1'));
+/// assert!(!is_ident_start_ascii(0xFF));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_ident_start_ascii(byte: u8) -> bool {
+    CTYPE[byte as usize] & IDENT_START != 0
+}
+
+/// Return whether the given byte can continue an ASCII Ruby identifier.
+///
+/// ASCII identifiers continue with an ASCII alphanumeric byte or an
+/// underscore. Bytes outside the ASCII range are not classified by this
+/// predicate and always return `false`.
+///
+/// # Examples
+///
+/// ```
+/// # use scolapasta_string_escape::is_ident_continue_ascii;
+/// assert!(is_ident_continue_ascii(b'a'));
+/// assert!(is_ident_continue_ascii(b'1'));
+/// assert!(is_ident_continue_ascii(b'_'));
+/// assert!(!is_ident_continue_ascii(b'-'));
+/// assert!(!is_ident_continue_ascii(0xFF));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_ident_continue_ascii(byte: u8) -> bool {
+    CTYPE[byte as usize] & IDENT_CONTINUE != 0
+}
+
+/// Convert the given byte to ASCII uppercase.
+///
+/// Bytes outside the ASCII lowercase range are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use scolapasta_string_escape::to_ascii_upper;
+/// assert_eq!(to_ascii_upper(b'a'), b'A');
+/// assert_eq!(to_ascii_upper(b'A'), b'A');
+/// assert_eq!(to_ascii_upper(b'1'), b'1');
+/// assert_eq!(to_ascii_upper(0xFF), 0xFF);
+/// ```
+#[inline]
+#[must_use]
+pub const fn to_ascii_upper(byte: u8) -> u8 {
+    UPPER[byte as usize]
+}
+
+/// Convert the given byte to ASCII lowercase.
+///
+/// Bytes outside the ASCII uppercase range are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use scolapasta_string_escape::to_ascii_lower;
+/// assert_eq!(to_ascii_lower(b'A'), b'a');
+/// assert_eq!(to_ascii_lower(b'a'), b'a');
+/// assert_eq!(to_ascii_lower(b'1'), b'1');
+/// assert_eq!(to_ascii_lower(0xFF), 0xFF);
+/// ```
+#[inline]
+#[must_use]
+pub const fn to_ascii_lower(byte: u8) -> u8 {
+    LOWER[byte as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_ident_continue_ascii, is_ident_start_ascii, is_ruby_whitespace, to_ascii_lower, to_ascii_upper};
+
+    #[test]
+    fn whitespace_matches_ascii_whitespace_plus_nul() {
+        for byte in 0..=u8::MAX {
+            // `u8::is_ascii_whitespace` excludes VT, which Ruby includes.
+            let expected = byte.is_ascii_whitespace() || byte == 0x0B || byte == b'\0';
+            assert_eq!(
+                is_ruby_whitespace(byte),
+                expected,
+                "whitespace mismatch for byte {}",
+                byte
+            );
+        }
+    }
+
+    #[test]
+    fn ident_start_matches_alphabetic_and_underscore() {
+        for byte in 0..=u8::MAX {
+            let expected = byte.is_ascii_alphabetic() || byte == b'_';
+            assert_eq!(
+                is_ident_start_ascii(byte),
+                expected,
+                "ident start mismatch for byte {}",
+                byte
+            );
+        }
+    }
+
+    #[test]
+    fn ident_continue_matches_alphanumeric_and_underscore() {
+        for byte in 0..=u8::MAX {
+            let expected = byte.is_ascii_alphanumeric() || byte == b'_';
+            assert_eq!(
+                is_ident_continue_ascii(byte),
+                expected,
+                "ident continue mismatch for byte {}",
+                byte
+            );
+        }
+    }
+
+    #[test]
+    fn case_conversion_matches_std() {
+        for byte in 0..=u8::MAX {
+            assert_eq!(to_ascii_upper(byte), byte.to_ascii_uppercase());
+            assert_eq!(to_ascii_lower(byte), byte.to_ascii_lowercase());
+        }
+    }
+}
@@ -98,8 +98,10 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod ascii;
 mod literal;
 mod string;
 
+pub use ascii::*;
 pub use literal::*;
 pub use string::*;
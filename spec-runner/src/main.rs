@@ -24,7 +24,9 @@
 //! `spec-runner` is invoked with a YAML manifest that specifies which specs to
 //! run. The manifest can run whole suites, like all of the `StringScanner`
 //! specs, or specific specs, like the `Array#drop` spec. The manifest supports
-//! marking specs as skipped.
+//! marking specs as skipped and marking individual examples as expected to
+//! fail. Expected failures still run: their failure counts as a success and an
+//! unexpected pass is reported as an error so the list can be pruned.
 //!
 //! ```toml
 //! [specs.core.array]
@@ -44,6 +46,9 @@
 //! [specs.library.uri]
 //! include = "all"
 //! skip = ["parse"]
+//! expected_failures = [
+//!   "URI#eql? returns false if the normalized forms are different",
+//! ]
 //! ```
 //!
 //! # Usage
@@ -64,6 +69,7 @@
 //! OPTIONS:
 //!     -f, --format <formatter>    Output spec results in YAML [default: artichoke]  [possible values: artichoke, summary,
 //!                                 tagger, yaml]
+//!     -j, --jobs <jobs>           Number of worker threads to shard spec suites across [default: 1]
 //!
 //! ARGS:
 //!     <config>    Path to TOML config file
@@ -75,6 +81,7 @@
 #[macro_use]
 extern crate rust_embed;
 
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::ffi::OsStr;
 use std::fs;
@@ -82,6 +89,8 @@ use std::io::Write;
 use std::path::{Component, Path, PathBuf};
 use std::process;
 use std::str;
+use std::sync::mpsc;
+use std::thread;
 
 use artichoke::backtrace;
 use artichoke::prelude::*;
@@ -102,6 +111,26 @@ struct Args {
     config: PathBuf,
     /// `MSpec` formatter.
     formatter: Formatter,
+    /// Number of worker threads to shard spec suites across.
+    jobs: usize,
+}
+
+/// A unit of spec execution: one suite's spec files and its manifest config.
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct SuiteJob {
+    /// `family/suite` key used for deterministic reporting.
+    key: String,
+    /// Additional spec sources to define on the interpreter before running.
+    ///
+    /// Embedded ruby/spec sources are loaded by [`rubyspec::init`]; this list
+    /// is for sources that are not part of the embedded suite.
+    sources: Vec<(String, Vec<u8>)>,
+    /// Spec files to run.
+    specs: Vec<String>,
+    /// Full example descriptions which are expected to fail.
+    expected_failures: Vec<String>,
+    /// Run this suite on a dedicated interpreter.
+    fresh_interpreter: bool,
 }
 
 /// Main entry point.
@@ -121,6 +150,15 @@ pub fn main() {
             .required(false)
             .help("Output spec results in YAML"),
     );
+    let app = app.arg(
+        Arg::with_name("jobs")
+            .long("jobs")
+            .short("j")
+            .takes_value(true)
+            .default_value("1")
+            .required(false)
+            .help("Number of worker threads to shard spec suites across"),
+    );
     let app = app.arg(
         Arg::with_name("quiet")
             .long("quiet")
@@ -157,10 +195,24 @@ pub fn main() {
     };
     let quiet = matches.is_present("quiet");
 
+    let jobs = matches
+        .value_of("jobs")
+        .expect("jobs has a default value, clap should ensure");
+    let jobs = match jobs.parse::<usize>() {
+        Ok(jobs) if jobs > 0 => jobs,
+        _ => {
+            // Suppress all errors at this point (e.g. from a broken pipe) since
+            // we're exiting with an error code anyway.
+            let _ignored = writeln!(&mut stderr, "jobs must be a positive integer");
+            process::exit(1);
+        }
+    };
+
     let args = if let Some(config) = matches.value_of_os("config") {
         Args {
             config: config.into(),
             formatter,
+            jobs,
         }
     } else {
         // Suppress all errors at this point (e.g. from a broken pipe) since
@@ -201,6 +253,11 @@ where
     let config = str::from_utf8(config.as_slice())?;
     let config = toml::from_str::<Config>(config)?;
 
+    if args.jobs > 1 {
+        let suite_jobs = collect_suite_jobs(&config);
+        return run_jobs(stderr, args.formatter, args.jobs, suite_jobs);
+    }
+
     let mut interp = artichoke::interpreter()?;
 
     rubyspec::init(&mut interp)?;
@@ -226,7 +283,13 @@ where
         }
     }
     mspec::init(&mut interp)?;
-    let result = match mspec::run(&mut interp, args.formatter, specs.iter().map(String::as_str)) {
+    let expected_failures = config.expected_failures();
+    let result = match mspec::run(
+        &mut interp,
+        args.formatter,
+        specs.iter().map(String::as_str),
+        &expected_failures,
+    ) {
         Ok(result) => Ok(result),
         Err(exc) => {
             backtrace::format_cli_trace_into(stderr, &mut interp, &exc)?;
@@ -237,32 +300,289 @@ where
     result
 }
 
+/// Find the declaring suite for an embedded ruby/spec source.
+///
+/// This function evaluates a ruby/spec source file against the parsed spec
+/// manifest config. If the source should be tested, the `family/suite` key and
+/// the declaring [`Suite`] config are returned.
+#[must_use]
+pub fn require_path_suite<'a>(config: &'a Config, name: &str) -> Option<(String, &'a Suite)> {
+    let path = Path::new(name);
+    let mut components = path.components();
+    let family = components.next()?.as_os_str();
+
+    let suites = config.suites_for_family(family)?;
+    let suite_name = components.next()?.as_os_str();
+    let (suite_key, suite) = suites.iter().find(|(name, _)| OsStr::new(name) == suite_name)?;
+    let spec_name = components.next()?.as_os_str().to_str()?;
+
+    let included = match suite {
+        Suite::All(ref all) if all.skip.iter().flatten().any(|name| spec_name.starts_with(name)) => false,
+        Suite::All(..) => true,
+        Suite::None => false,
+        Suite::Set(ref set) => set.specs.iter().any(|name| spec_name.starts_with(name)),
+    };
+    if !included {
+        return None;
+    }
+    let family = family.to_str()?;
+    Some((format!("{}/{}", family, suite_key), suite))
+}
+
 /// Determine if an embedded ruby/spec should be tested.
 ///
 /// This function evaluates a ruby/spec source file against the parsed spec
 /// manifest config to determine if the source should be tested.
 #[must_use]
 pub fn is_require_path(config: &Config, name: &str) -> bool {
-    // Use an inner function to allow short-circuiting `None` with the `?`
-    // operator.
-    fn inner(config: &Config, name: &str) -> Option<bool> {
-        let path = Path::new(name);
-        let mut components = path.components();
-        let family = components.next()?.as_os_str();
-
-        let suites = config.suites_for_family(family)?;
-        let suite_name = components.next()?.as_os_str();
-        let (_, suite) = suites.iter().find(|(name, _)| OsStr::new(name) == suite_name)?;
-        let spec_name = components.next()?.as_os_str().to_str()?;
-
-        match suite {
-            Suite::All(ref all) if all.skip.iter().flatten().any(|name| spec_name.starts_with(name)) => Some(false),
-            Suite::All(..) => Some(true),
-            Suite::None => Some(false),
-            Suite::Set(ref set) if set.specs.iter().any(|name| spec_name.starts_with(name)) => Some(true),
-            Suite::Set(..) => Some(false),
+    require_path_suite(config, name).is_some()
+}
+
+/// Partition the embedded ruby/spec sources into per-suite execution jobs.
+///
+/// Fixture and shared sources are excluded; they are loaded into every worker
+/// interpreter by [`worker_interpreter`].
+fn collect_suite_jobs(config: &Config) -> Vec<SuiteJob> {
+    let mut jobs = BTreeMap::new();
+    for name in rubyspec::Specs::iter() {
+        let path = Path::new(&*name);
+        let is_fixture = path
+            .components()
+            .map(Component::as_os_str)
+            .any(|component| component == OsStr::new("fixture"));
+        let is_shared = path
+            .components()
+            .map(Component::as_os_str)
+            .any(|component| component == OsStr::new("shared"));
+        if is_fixture || is_shared {
+            continue;
+        }
+        if let Some((key, suite)) = require_path_suite(config, &name) {
+            let job = jobs.entry(key.clone()).or_insert_with(|| {
+                let (expected_failures, fresh_interpreter) = match suite {
+                    Suite::All(ref all) => (
+                        all.expected_failures.clone().unwrap_or_default(),
+                        all.fresh_interpreter.unwrap_or(false),
+                    ),
+                    Suite::Set(ref set) => (
+                        set.expected_failures.clone().unwrap_or_default(),
+                        set.fresh_interpreter.unwrap_or(false),
+                    ),
+                    Suite::None => (vec![], false),
+                };
+                SuiteJob {
+                    key,
+                    sources: vec![],
+                    specs: vec![],
+                    expected_failures,
+                    fresh_interpreter,
+                }
+            });
+            job.specs.push(name.into_owned());
         }
     }
-    // And the convert to the expected `bool`.
-    matches!(inner(config, name), Some(true))
+    jobs.into_values().collect()
+}
+
+/// Construct an interpreter with ruby/spec and `MSpec` sources loaded.
+///
+/// Errors are converted to `String` so they can be sent across the worker
+/// result channel.
+fn worker_interpreter() -> Result<Artichoke, String> {
+    let mut interp = artichoke::interpreter().map_err(|err| err.to_string())?;
+    rubyspec::init(&mut interp).map_err(|err| err.to_string())?;
+    for name in rubyspec::Specs::iter() {
+        let path = Path::new(&*name);
+        let is_fixture = path
+            .components()
+            .map(Component::as_os_str)
+            .any(|component| component == OsStr::new("fixture"));
+        let is_shared = path
+            .components()
+            .map(Component::as_os_str)
+            .any(|component| component == OsStr::new("shared"));
+        if is_fixture || is_shared {
+            if let Some(contents) = mspec::Sources::get(&name) {
+                interp
+                    .def_rb_source_file(path, contents.data)
+                    .map_err(|err| err.to_string())?;
+            }
+        }
+    }
+    mspec::init(&mut interp).map_err(|err| err.to_string())?;
+    Ok(interp)
+}
+
+/// Execute one suite on the given interpreter.
+fn run_suite(interp: &mut Artichoke, formatter: Formatter, job: &SuiteJob) -> Result<bool, String> {
+    for (path, contents) in &job.sources {
+        interp
+            .def_rb_source_file(path.as_str(), contents.clone())
+            .map_err(|err| err.to_string())?;
+    }
+    mspec::run(
+        interp,
+        formatter,
+        job.specs.iter().map(String::as_str),
+        &job.expected_failures,
+    )
+    .map_err(|exc| exc.to_string())
+}
+
+/// Execute a shard of suites on a dedicated worker thread.
+///
+/// A single interpreter is shared by the shard's suites unless a suite is
+/// marked `fresh_interpreter`, in which case it runs on its own interpreter.
+fn run_worker(formatter: Formatter, shard: Vec<SuiteJob>, tx: &mpsc::Sender<(String, Result<bool, String>)>) {
+    let mut shared = None;
+    for job in shard {
+        let result = if job.fresh_interpreter {
+            worker_interpreter().and_then(|mut interp| {
+                let result = run_suite(&mut interp, formatter, &job);
+                interp.close();
+                result
+            })
+        } else {
+            if shared.is_none() {
+                match worker_interpreter() {
+                    Ok(interp) => shared = Some(interp),
+                    Err(err) => {
+                        let _ignored = tx.send((job.key, Err(err)));
+                        continue;
+                    }
+                }
+            }
+            let interp = shared.as_mut().expect("shared interpreter was just initialized");
+            run_suite(interp, formatter, &job)
+        };
+        if tx.send((job.key, result)).is_err() {
+            // The receiver is gone, so the main thread is exiting.
+            break;
+        }
+    }
+    if let Some(interp) = shared {
+        interp.close();
+    }
+}
+
+/// Shard suites across worker threads and aggregate their results.
+///
+/// Each worker constructs its own interpreter because the mruby interpreter is
+/// neither `Send` nor `Sync`. Results are sorted by suite key before the
+/// aggregated report is printed so output order is deterministic regardless of
+/// thread scheduling.
+fn run_jobs<W>(
+    mut stderr: W,
+    formatter: Formatter,
+    jobs: usize,
+    suite_jobs: Vec<SuiteJob>,
+) -> Result<bool, Box<dyn Error>>
+where
+    W: Write + WriteColor,
+{
+    let mut shards = vec![vec![]; jobs];
+    for (idx, job) in suite_jobs.into_iter().enumerate() {
+        shards[idx % jobs].push(job);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let mut handles = vec![];
+    for shard in shards {
+        if shard.is_empty() {
+            continue;
+        }
+        let tx = tx.clone();
+        handles.push(thread::spawn(move || run_worker(formatter, shard, &tx)));
+    }
+    drop(tx);
+
+    let mut results = rx.iter().collect::<Vec<_>>();
+    for handle in handles {
+        if handle.join().is_err() {
+            return Err("spec worker thread panicked".into());
+        }
+    }
+    results.sort();
+
+    let mut success = true;
+    for (key, result) in results {
+        match result {
+            Ok(true) => writeln!(stderr, "{}: ok", key)?,
+            Ok(false) => {
+                success = false;
+                writeln!(stderr, "{}: failed", key)?;
+            }
+            Err(message) => {
+                success = false;
+                writeln!(stderr, "{}: error: {}", key, message)?;
+            }
+        }
+    }
+    Ok(success)
+}
+
+#[cfg(test)]
+mod tests {
+    use termcolor::Ansi;
+
+    use super::{run_jobs, Formatter, SuiteJob};
+
+    const SPEC_A: &[u8] = b"describe 'ConflictA' do
+  it 'defines the shared constant' do
+    defined?(CONFLICT).should be_nil
+    Object.const_set(:CONFLICT, 1)
+    CONFLICT.should == 1
+  end
+end
+";
+    const SPEC_B: &[u8] = b"describe 'ConflictB' do
+  it 'defines the shared constant' do
+    defined?(CONFLICT).should be_nil
+    Object.const_set(:CONFLICT, 2)
+    CONFLICT.should == 2
+  end
+end
+";
+
+    fn conflicting_suite_jobs() -> Vec<SuiteJob> {
+        vec![
+            SuiteJob {
+                key: String::from("fake/conflict_a"),
+                sources: vec![(String::from("conflict_a_spec.rb"), SPEC_A.to_vec())],
+                specs: vec![String::from("conflict_a_spec.rb")],
+                expected_failures: vec![],
+                fresh_interpreter: false,
+            },
+            SuiteJob {
+                key: String::from("fake/conflict_b"),
+                sources: vec![(String::from("conflict_b_spec.rb"), SPEC_B.to_vec())],
+                specs: vec![String::from("conflict_b_spec.rb")],
+                expected_failures: vec![],
+                fresh_interpreter: false,
+            },
+        ]
+    }
+
+    #[test]
+    fn conflicting_suites_pass_when_sharded_across_interpreters() {
+        let stderr = Ansi::new(vec![]);
+        let result = run_jobs(stderr, Formatter::Artichoke, 2, conflicting_suite_jobs()).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn conflicting_suites_fail_on_a_shared_interpreter() {
+        let stderr = Ansi::new(vec![]);
+        let result = run_jobs(stderr, Formatter::Artichoke, 1, conflicting_suite_jobs()).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn fresh_interpreter_isolates_suites_within_a_worker() {
+        let stderr = Ansi::new(vec![]);
+        let mut suite_jobs = conflicting_suite_jobs();
+        suite_jobs[1].fresh_interpreter = true;
+        let result = run_jobs(stderr, Formatter::Artichoke, 1, suite_jobs).unwrap();
+        assert!(result);
+    }
 }
@@ -27,6 +27,37 @@ impl Config {
             _ => None,
         }
     }
+
+    /// Collect the full example descriptions of every expected failure
+    /// declared in the config.
+    ///
+    /// Expected failures are aggregated across all spec families and sorted so
+    /// callers observe a deterministic order.
+    #[must_use]
+    pub fn expected_failures(&self) -> Vec<String> {
+        let families = [
+            &self.specs.language,
+            &self.specs.core,
+            &self.specs.library,
+            &self.specs.command_line,
+            &self.specs.security,
+            &self.specs.optional,
+        ];
+        let mut expected_failures = vec![];
+        for family in families {
+            for suite in family.iter().flat_map(HashMap::values) {
+                let failures = match suite {
+                    Suite::All(ref all) => &all.expected_failures,
+                    Suite::Set(ref set) => &set.expected_failures,
+                    Suite::None => &None,
+                };
+                expected_failures.extend(failures.iter().flatten().cloned());
+            }
+        }
+        expected_failures.sort();
+        expected_failures.dedup();
+        expected_failures
+    }
 }
 
 /// The set of all ruby/specs to run, by top-level directory.
@@ -85,12 +116,26 @@ impl Suite {
 pub struct All {
     /// List of specs to always skip because they are known to fail.
     pub skip: Option<Vec<String>>,
+    /// List of full example descriptions that are expected to fail.
+    ///
+    /// Tagged examples still run. Their failure is treated as a success and an
+    /// unexpected pass is reported as an error so the list can be pruned.
+    pub expected_failures: Option<Vec<String>>,
+    /// Run this suite on a dedicated interpreter.
+    ///
+    /// Set this for suites that mutate global state. Only applies when specs
+    /// are sharded across multiple jobs.
+    pub fresh_interpreter: Option<bool>,
 }
 
 impl All {
     /// Construct a new `All` that executes all specs in a `Suite`.
     pub const fn new() -> Self {
-        Self { skip: None }
+        Self {
+            skip: None,
+            expected_failures: None,
+            fresh_interpreter: None,
+        }
     }
 }
 
@@ -98,4 +143,132 @@ impl All {
 pub struct Set {
     /// List of specs to include.
     pub specs: Vec<String>,
+    /// List of full example descriptions that are expected to fail.
+    ///
+    /// Tagged examples still run. Their failure is treated as a success and an
+    /// unexpected pass is reported as an error so the list can be pruned.
+    pub expected_failures: Option<Vec<String>>,
+    /// Run this suite on a dedicated interpreter.
+    ///
+    /// Set this for suites that mutate global state. Only applies when specs
+    /// are sharded across multiple jobs.
+    pub fresh_interpreter: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Suite};
+
+    #[test]
+    fn deserialize_all_with_expected_failures() {
+        let config = r#"
+[specs.library.uri]
+include = "all"
+skip = ["parse"]
+expected_failures = ["URI#eql? returns false if the normalized forms are different"]
+"#;
+        let config = toml::from_str::<Config>(config).unwrap();
+        let suites = config.specs.library.as_ref().unwrap();
+        if let Suite::All(ref all) = suites["uri"] {
+            assert_eq!(all.skip.as_deref(), Some(&["parse".to_owned()][..]));
+            assert_eq!(
+                all.expected_failures.as_deref(),
+                Some(&["URI#eql? returns false if the normalized forms are different".to_owned()][..])
+            );
+        } else {
+            panic!("expected an all suite");
+        }
+    }
+
+    #[test]
+    fn deserialize_set_with_expected_failures() {
+        let config = r#"
+[specs.core.array]
+include = "set"
+specs = ["any", "append"]
+expected_failures = ["Array#append returns the array itself"]
+"#;
+        let config = toml::from_str::<Config>(config).unwrap();
+        let suites = config.specs.core.as_ref().unwrap();
+        if let Suite::Set(ref set) = suites["array"] {
+            assert_eq!(set.specs, ["any", "append"]);
+            assert_eq!(
+                set.expected_failures.as_deref(),
+                Some(&["Array#append returns the array itself".to_owned()][..])
+            );
+        } else {
+            panic!("expected a set suite");
+        }
+    }
+
+    #[test]
+    fn expected_failures_default_to_none() {
+        let config = r#"
+[specs.library.stringscanner]
+include = "all"
+
+[specs.core.array]
+include = "set"
+specs = ["any"]
+"#;
+        let config = toml::from_str::<Config>(config).unwrap();
+        let suites = config.specs.library.as_ref().unwrap();
+        if let Suite::All(ref all) = suites["stringscanner"] {
+            assert_eq!(all.expected_failures, None);
+        } else {
+            panic!("expected an all suite");
+        }
+        assert!(config.expected_failures().is_empty());
+    }
+
+    #[test]
+    fn deserialize_fresh_interpreter() {
+        let config = r#"
+[specs.core.kernel]
+include = "all"
+fresh_interpreter = true
+
+[specs.core.array]
+include = "set"
+specs = ["any"]
+"#;
+        let config = toml::from_str::<Config>(config).unwrap();
+        let suites = config.specs.core.as_ref().unwrap();
+        if let Suite::All(ref all) = suites["kernel"] {
+            assert_eq!(all.fresh_interpreter, Some(true));
+        } else {
+            panic!("expected an all suite");
+        }
+        if let Suite::Set(ref set) = suites["array"] {
+            assert_eq!(set.fresh_interpreter, None);
+        } else {
+            panic!("expected a set suite");
+        }
+    }
+
+    #[test]
+    fn expected_failures_are_collected_across_families_and_sorted() {
+        let config = r#"
+[specs.library.uri]
+include = "all"
+expected_failures = ["URI.parse returns a URI::HTTP object", "URI#eql? returns false"]
+
+[specs.core.array]
+include = "set"
+specs = ["append"]
+expected_failures = ["Array#append returns the array itself"]
+
+[specs.core.time]
+include = "none"
+"#;
+        let config = toml::from_str::<Config>(config).unwrap();
+        assert_eq!(
+            config.expected_failures(),
+            [
+                "Array#append returns the array itself",
+                "URI#eql? returns false",
+                "URI.parse returns a URI::HTTP object",
+            ]
+        );
+    }
 }
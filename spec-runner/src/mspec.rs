@@ -71,10 +71,19 @@ impl Formatter {
 
 /// Load the Artichoke `MSpec` entry point end execute the specs.
 ///
+/// Examples whose full descriptions appear in `expected_failures` still run,
+/// but their failure is treated as a success and an unexpected pass is
+/// reported as an error.
+///
 /// # Errors
 ///
 /// If an exception is raised on the Artichoke interpreter, it is returned.
-pub fn run<'a, T>(interp: &mut Artichoke, formatter: Formatter, specs: T) -> Result<bool, Error>
+pub fn run<'a, T>(
+    interp: &mut Artichoke,
+    formatter: Formatter,
+    specs: T,
+    expected_failures: &[String],
+) -> Result<bool, Error>
 where
     T: IntoIterator<Item = &'a str>,
 {
@@ -88,8 +97,10 @@ where
     let artichoke_spec_formatter = interp.eval(formatter.into_ruby_class().as_bytes())?;
 
     let specs = interp.try_convert_mut(specs.into_iter().collect::<Vec<_>>())?;
+    let expected_failures =
+        interp.try_convert_mut(expected_failures.iter().map(String::as_str).collect::<Vec<_>>())?;
 
-    let result = artichoke_spec_formatter.funcall(interp, "run_specs", &[specs], None)?;
+    let result = artichoke_spec_formatter.funcall(interp, "run_specs", &[specs, expected_failures], None)?;
     interp.try_convert(result)
 }
 
@@ -103,7 +114,7 @@ mod tests {
     fn load_mspec_with_formatter(formatter: Formatter) {
         let mut interp = artichoke::interpreter().unwrap();
         init(&mut interp).unwrap();
-        match run(&mut interp, formatter, vec![]) {
+        match run(&mut interp, formatter, vec![], &[]) {
             Ok(true) => {}
             Ok(false) => {
                 panic!("mspec::run with {:?} formatter failed", formatter);
@@ -156,4 +167,51 @@ mod tests {
         // should not panic
         load_mspec_with_formatter(Formatter::Yaml);
     }
+
+    const FAILING_SPEC: &[u8] = b"describe 'Fake' do\n  it 'fails' do\n    raise 'boom'\n  end\nend\n";
+    const PASSING_SPEC: &[u8] = b"describe 'Fake' do\n  it 'passes' do\n    1.should == 1\n  end\nend\n";
+
+    #[test]
+    fn untagged_failure_fails_the_run() {
+        let mut interp = artichoke::interpreter().unwrap();
+        init(&mut interp).unwrap();
+        interp.def_rb_source_file("fake_spec.rb", FAILING_SPEC).unwrap();
+        let result = run(&mut interp, Formatter::Artichoke, vec!["fake_spec.rb"], &[]).unwrap();
+        assert!(!result);
+        interp.close();
+    }
+
+    #[test]
+    fn expected_failure_is_treated_as_success() {
+        let mut interp = artichoke::interpreter().unwrap();
+        init(&mut interp).unwrap();
+        interp.def_rb_source_file("fake_spec.rb", FAILING_SPEC).unwrap();
+        let expected_failures = [String::from("Fake fails")];
+        let result = run(
+            &mut interp,
+            Formatter::Artichoke,
+            vec!["fake_spec.rb"],
+            &expected_failures,
+        )
+        .unwrap();
+        assert!(result);
+        interp.close();
+    }
+
+    #[test]
+    fn unexpected_pass_is_reported_as_failure() {
+        let mut interp = artichoke::interpreter().unwrap();
+        init(&mut interp).unwrap();
+        interp.def_rb_source_file("fake_spec.rb", PASSING_SPEC).unwrap();
+        let expected_failures = [String::from("Fake passes")];
+        let result = run(
+            &mut interp,
+            Formatter::Artichoke,
+            vec!["fake_spec.rb"],
+            &expected_failures,
+        )
+        .unwrap();
+        assert!(!result);
+        interp.close();
+    }
 }
@@ -58,11 +58,11 @@ module Artichoke
         YELLOW = "\e[33m"
         PLAIN = "\e[0m"
 
-        def self.run_specs(*specs)
-          specs = specs.flatten
+        def self.run_specs(specs, expected_failures = [])
+          specs = Array(specs).flatten
           MSpec.register_files(specs)
 
-          collector = new
+          collector = new(expected_failures)
 
           MSpec.register(:start, collector)
           MSpec.register(:enter, collector)
@@ -73,11 +73,23 @@ module Artichoke
 
           MSpec.process
 
+          # Unregister the collector so repeated `run_specs` invocations on a
+          # long-lived interpreter do not deliver events to stale collectors.
+          MSpec.unregister(:start, collector)
+          MSpec.unregister(:enter, collector)
+          MSpec.unregister(:before, collector)
+          MSpec.unregister(:after, collector)
+          MSpec.unregister(:exception, collector)
+          MSpec.unregister(:finish, collector)
+
           collector.success?
         end
 
-        def initialize
+        def initialize(expected_failures = [])
           @errors = []
+          @expected_failures = expected_failures
+          @expected_failed = 0
+          @unexpected_passes = []
           @total = 0
           @successes = 0
           @failures = 0
@@ -85,10 +97,11 @@ module Artichoke
           @not_implemented = 0
           @current_description = nil
           @spec_state = nil
+          @example_failed = false
         end
 
         def success?
-          @errors.empty?
+          @errors.empty? && @unexpected_passes.empty?
         end
 
         def start
@@ -103,14 +116,26 @@ module Artichoke
         def before(_state)
           @total += 1
           @spec_state = nil
+          @example_failed = false
           print '.'
         end
 
-        def after(_state)
+        def after(state)
+          if !@example_failed && @expected_failures.include?(state.description)
+            @unexpected_passes << state.description
+            @spec_state = "\b#{RED}P#{PLAIN}"
+          end
           print @spec_state if @spec_state
         end
 
         def exception(state)
+          @example_failed = true
+          if @expected_failures.include?(state.description)
+            @expected_failed += 1
+            @spec_state = "\b#{YELLOW}F#{PLAIN}"
+            return
+          end
+
           skipped = false
           case state.exception
           when ArgumentError
@@ -128,6 +153,7 @@ module Artichoke
             skipped = true if state.message =~ /'tainted\?'/
             skipped = true if state.message =~ /'untrust'/
             skipped = true if state.message =~ /'untrusted\?'/
+            skipped = true if state.message =~ /undefined method 'Complex'/
             skipped = true if state.message =~ /undefined method 'Rational'/
           when NameError
             skipped = true if state.message =~ /uninitialized constant Bignum/
@@ -170,16 +196,19 @@ module Artichoke
 
         def finish
           failures = @errors.length
-          successes = @total - @skipped - @not_implemented - failures
+          unexpected_passes = @unexpected_passes.length
+          successes = @total - @skipped - @not_implemented - @expected_failed - unexpected_passes - failures
           successes = 0 if successes.negative?
           puts "\n"
 
-          if failures.zero?
+          if failures.zero? && unexpected_passes.zero?
             report(
               color: GREEN,
               successes: successes,
               skipped: @skipped,
               not_implemented: @not_implemented,
+              expected_failed: @expected_failed,
+              unexpected_passes: unexpected_passes,
               failed: failures
             )
             return
@@ -190,6 +219,8 @@ module Artichoke
             successes: successes,
             skipped: @skipped,
             not_implemented: @not_implemented,
+            expected_failed: @expected_failed,
+            unexpected_passes: unexpected_passes,
             failed: failures
           )
           @errors.each do |state|
@@ -198,29 +229,38 @@ module Artichoke
                  "#{RED}#{state.exception.class}: #{state.exception}#{PLAIN}"
             puts '', state.backtrace unless state.exception.is_a?(SystemStackError)
           end
+          @unexpected_passes.each do |description|
+            puts '',
+                 "#{RED}#{description}#{PLAIN}",
+                 "#{RED}unexpected pass: spec is tagged as an expected failure but passed#{PLAIN}"
+          end
           puts ''
           report(
             color: RED,
             successes: successes,
             skipped: @skipped,
             not_implemented: @not_implemented,
+            expected_failed: @expected_failed,
+            unexpected_passes: unexpected_passes,
             failed: failures
           )
         end
 
-        def report(color:, successes:, skipped:, not_implemented:, failed:)
+        def report(color:, successes:, skipped:, not_implemented:, expected_failed:, unexpected_passes:, failed:)
           print color
           print "Passed #{successes}, "
           print "skipped #{skipped}, "
           print "not implemented #{not_implemented}, "
+          print "expected failed #{expected_failed}, "
+          print "unexpectedly passed #{unexpected_passes}, "
           print "failed #{failed} specs."
           print PLAIN, "\n"
         end
       end
 
       class Summary
-        def self.run_specs(*specs)
-          specs = specs.flatten
+        def self.run_specs(specs, _expected_failures = [])
+          specs = Array(specs).flatten
           MSpec.register_files(specs)
 
           MSpecScript.set(:backtrace_filter, %r{/lib/mspec/})
@@ -238,8 +278,8 @@ module Artichoke
       end
 
       class Yaml
-        def self.run_specs(*specs)
-          specs = specs.flatten
+        def self.run_specs(specs, _expected_failures = [])
+          specs = Array(specs).flatten
           MSpec.register_files(specs)
 
           MSpecScript.set(:backtrace_filter, %r{/lib/mspec/})
@@ -257,8 +297,8 @@ module Artichoke
       end
 
       class Tagger
-        def self.run_specs(*specs)
-          specs = specs.flatten
+        def self.run_specs(specs, _expected_failures = [])
+          specs = Array(specs).flatten
           MSpec.register_files(specs)
 
           MSpecScript.set(:backtrace_filter, %r{/lib/mspec/})
@@ -355,5 +395,5 @@ if $PROGRAM_NAME == __FILE__
 
     false
   end
-  Artichoke::Spec::Formatter::Artichoke.run_specs(*specs)
+  Artichoke::Spec::Formatter::Artichoke.run_specs(specs)
 end
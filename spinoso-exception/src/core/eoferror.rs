@@ -1,9 +1,13 @@
 // @generated
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 #[cfg(feature = "std")]
 use std::error;
 
@@ -23,10 +27,11 @@ use crate::RubyException;
 /// [`Exception`]: https://ruby-doc.org/core-2.6.3/Exception.html
 /// [`Kernel#raise`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-raise
 /// [`NameError#name`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-name
-#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct EOFError {
     message: Cow<'static, [u8]>,
+    cause: Option<Arc<dyn RubyException + Send + Sync>>,
 }
 
 impl EOFError {
@@ -50,7 +55,7 @@ impl EOFError {
         // `raise RuntimeError` or `RuntimeError.new` have `message`
         // equal to the exception's class name.
         let message = Cow::Borrowed(DEFAULT_MESSAGE);
-        Self { message }
+        Self { message, cause: None }
     }
 
     /// Construct a new, `EOFError` Ruby exception with the given
@@ -67,7 +72,30 @@ impl EOFError {
     #[must_use]
     pub const fn with_message(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self { message, cause: None }
+    }
+
+    /// Attach a `cause` to this `EOFError` Ruby exception, corresponding
+    /// to [`Exception#cause`].
+    ///
+    /// The cause is the exception that was being handled when this exception
+    /// was raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let cause = RuntimeError::with_message("underlying failure");
+    /// let exception = EOFError::with_message("an error occurred").with_cause(Box::new(cause));
+    /// assert!(exception.cause().is_some());
+    /// ```
+    ///
+    /// [`Exception#cause`]: https://ruby-doc.org/core-2.6.3/Exception.html#method-i-cause
+    #[inline]
+    #[must_use]
+    pub fn with_cause(mut self, cause: Box<dyn RubyException + Send + Sync>) -> Self {
+        self.cause = Some(Arc::from(cause));
+        self
     }
 
     /// Return the message this Ruby exception was constructed with.
@@ -104,11 +132,43 @@ impl EOFError {
     }
 }
 
+// `cause` is a trait object and cannot take part in comparisons or hashing, so
+// these impls only consider the remaining fields.
+impl Hash for EOFError {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.message.hash(state);
+    }
+}
+
+impl PartialEq for EOFError {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
+impl Eq for EOFError {}
+
+impl PartialOrd for EOFError {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EOFError {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.message.cmp(&other.message)
+    }
+}
+
 impl From<String> for EOFError {
     #[inline]
     fn from(message: String) -> Self {
         let message = Cow::Owned(message.into_bytes());
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -116,7 +176,7 @@ impl From<&'static str> for EOFError {
     #[inline]
     fn from(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -127,7 +187,7 @@ impl From<Cow<'static, str>> for EOFError {
             Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
             Cow::Owned(s) => Cow::Owned(s.into_bytes()),
         };
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -135,7 +195,7 @@ impl From<Vec<u8>> for EOFError {
     #[inline]
     fn from(message: Vec<u8>) -> Self {
         let message = Cow::Owned(message);
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -143,14 +203,14 @@ impl From<&'static [u8]> for EOFError {
     #[inline]
     fn from(message: &'static [u8]) -> Self {
         let message = Cow::Borrowed(message);
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
 impl From<Cow<'static, [u8]>> for EOFError {
     #[inline]
     fn from(message: Cow<'static, [u8]>) -> Self {
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -179,4 +239,9 @@ impl RubyException for EOFError {
     fn name(&self) -> Cow<'_, str> {
         Cow::Borrowed(Self::name(self))
     }
+
+    #[inline]
+    fn cause(&self) -> Option<&dyn RubyException> {
+        self.cause.as_deref().map(|cause| -> &dyn RubyException { cause })
+    }
 }
@@ -1,9 +1,13 @@
 // @generated
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 #[cfg(feature = "std")]
 use std::error;
 
@@ -23,9 +27,10 @@ use crate::RubyException;
 /// [`Exception`]: https://ruby-doc.org/core-2.6.3/Exception.html
 /// [`Kernel#raise`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-raise
 /// [`NameError#name`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-name
-#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone)]
 pub struct Exception {
     message: Cow<'static, [u8]>,
+    cause: Option<Arc<dyn RubyException + Send + Sync>>,
 }
 
 impl Exception {
@@ -49,7 +54,7 @@ impl Exception {
         // `raise RuntimeError` or `RuntimeError.new` have `message`
         // equal to the exception's class name.
         let message = Cow::Borrowed(DEFAULT_MESSAGE);
-        Self { message }
+        Self { message, cause: None }
     }
 
     /// Construct a new, `Exception` Ruby exception with the given
@@ -66,7 +71,30 @@ impl Exception {
     #[must_use]
     pub const fn with_message(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self { message, cause: None }
+    }
+
+    /// Attach a `cause` to this `Exception` Ruby exception, corresponding
+    /// to [`Exception#cause`].
+    ///
+    /// The cause is the exception that was being handled when this exception
+    /// was raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let cause = RuntimeError::with_message("underlying failure");
+    /// let exception = Exception::with_message("an error occurred").with_cause(Box::new(cause));
+    /// assert!(exception.cause().is_some());
+    /// ```
+    ///
+    /// [`Exception#cause`]: https://ruby-doc.org/core-2.6.3/Exception.html#method-i-cause
+    #[inline]
+    #[must_use]
+    pub fn with_cause(mut self, cause: Box<dyn RubyException + Send + Sync>) -> Self {
+        self.cause = Some(Arc::from(cause));
+        self
     }
 
     /// Return the message this Ruby exception was constructed with.
@@ -103,11 +131,43 @@ impl Exception {
     }
 }
 
+// `cause` is a trait object and cannot take part in comparisons or hashing, so
+// these impls only consider the remaining fields.
+impl Hash for Exception {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.message.hash(state);
+    }
+}
+
+impl PartialEq for Exception {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+    }
+}
+
+impl Eq for Exception {}
+
+impl PartialOrd for Exception {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Exception {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.message.cmp(&other.message)
+    }
+}
+
 impl From<String> for Exception {
     #[inline]
     fn from(message: String) -> Self {
         let message = Cow::Owned(message.into_bytes());
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -115,7 +175,7 @@ impl From<&'static str> for Exception {
     #[inline]
     fn from(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -126,7 +186,7 @@ impl From<Cow<'static, str>> for Exception {
             Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
             Cow::Owned(s) => Cow::Owned(s.into_bytes()),
         };
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -134,7 +194,7 @@ impl From<Vec<u8>> for Exception {
     #[inline]
     fn from(message: Vec<u8>) -> Self {
         let message = Cow::Owned(message);
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -142,14 +202,14 @@ impl From<&'static [u8]> for Exception {
     #[inline]
     fn from(message: &'static [u8]) -> Self {
         let message = Cow::Borrowed(message);
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
 impl From<Cow<'static, [u8]>> for Exception {
     #[inline]
     fn from(message: Cow<'static, [u8]>) -> Self {
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -178,4 +238,9 @@ impl RubyException for Exception {
     fn name(&self) -> Cow<'_, str> {
         Cow::Borrowed(Self::name(self))
     }
+
+    #[inline]
+    fn cause(&self) -> Option<&dyn RubyException> {
+        self.cause.as_deref().map(|cause| -> &dyn RubyException { cause })
+    }
 }
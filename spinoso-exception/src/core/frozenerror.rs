@@ -1,9 +1,13 @@
 // @generated
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 #[cfg(feature = "std")]
 use std::error;
 
@@ -23,9 +27,11 @@ use crate::RubyException;
 /// [`Exception`]: https://ruby-doc.org/core-2.6.3/Exception.html
 /// [`Kernel#raise`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-raise
 /// [`NameError#name`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-name
-#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone)]
 pub struct FrozenError {
     message: Cow<'static, [u8]>,
+    receiver: Option<Vec<u8>>,
+    cause: Option<Arc<dyn RubyException + Send + Sync>>,
 }
 
 impl FrozenError {
@@ -49,7 +55,11 @@ impl FrozenError {
         // `raise RuntimeError` or `RuntimeError.new` have `message`
         // equal to the exception's class name.
         let message = Cow::Borrowed(DEFAULT_MESSAGE);
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
     }
 
     /// Construct a new, `FrozenError` Ruby exception with the given
@@ -66,7 +76,80 @@ impl FrozenError {
     #[must_use]
     pub const fn with_message(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
+    }
+
+    /// Attach a `cause` to this `FrozenError` Ruby exception, corresponding
+    /// to [`Exception#cause`].
+    ///
+    /// The cause is the exception that was being handled when this exception
+    /// was raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let cause = RuntimeError::with_message("underlying failure");
+    /// let exception = FrozenError::with_message("an error occurred").with_cause(Box::new(cause));
+    /// assert!(exception.cause().is_some());
+    /// ```
+    ///
+    /// [`Exception#cause`]: https://ruby-doc.org/core-2.6.3/Exception.html#method-i-cause
+    #[inline]
+    #[must_use]
+    pub fn with_cause(mut self, cause: Box<dyn RubyException + Send + Sync>) -> Self {
+        self.cause = Some(Arc::from(cause));
+        self
+    }
+
+    /// Construct a new `FrozenError` Ruby exception with the given message and
+    /// the frozen receiver, corresponding to [`FrozenError#receiver`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = FrozenError::with_receiver(b"can't modify frozen String".to_vec(), b"\"immutable\"".to_vec());
+    /// assert_eq!(exception.receiver(), Some(&b"\"immutable\""[..]));
+    /// ```
+    ///
+    /// [`FrozenError#receiver`]: https://ruby-doc.org/core-2.6.3/FrozenError.html#method-i-receiver
+    #[inline]
+    #[must_use]
+    pub fn with_receiver<T>(message: T, receiver: Vec<u8>) -> Self
+    where
+        T: Into<Cow<'static, [u8]>>,
+    {
+        let message = message.into();
+        Self {
+            message,
+            receiver: Some(receiver),
+            cause: None,
+        }
+    }
+
+    /// Return the frozen object on which modification was attempted,
+    /// corresponding to [`FrozenError#receiver`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = FrozenError::new();
+    /// assert_eq!(exception.receiver(), None);
+    /// let exception = FrozenError::with_receiver(b"message".to_vec(), b"[]".to_vec());
+    /// assert_eq!(exception.receiver(), Some(&b"[]"[..]));
+    /// ```
+    ///
+    /// [`FrozenError#receiver`]: https://ruby-doc.org/core-2.6.3/FrozenError.html#method-i-receiver
+    #[inline]
+    #[must_use]
+    pub fn receiver(&self) -> Option<&[u8]> {
+        self.receiver.as_deref()
     }
 
     /// Return the message this Ruby exception was constructed with.
@@ -103,11 +186,50 @@ impl FrozenError {
     }
 }
 
+// `cause` is a trait object and cannot take part in comparisons or hashing, so
+// these impls only consider the remaining fields.
+impl Hash for FrozenError {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.message.hash(state);
+        self.receiver.hash(state);
+    }
+}
+
+impl PartialEq for FrozenError {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.receiver == other.receiver
+    }
+}
+
+impl Eq for FrozenError {}
+
+impl PartialOrd for FrozenError {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FrozenError {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.message
+            .cmp(&other.message)
+            .then_with(|| self.receiver.cmp(&other.receiver))
+    }
+}
+
 impl From<String> for FrozenError {
     #[inline]
     fn from(message: String) -> Self {
         let message = Cow::Owned(message.into_bytes());
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -115,7 +237,11 @@ impl From<&'static str> for FrozenError {
     #[inline]
     fn from(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -126,7 +252,11 @@ impl From<Cow<'static, str>> for FrozenError {
             Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
             Cow::Owned(s) => Cow::Owned(s.into_bytes()),
         };
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -134,7 +264,11 @@ impl From<Vec<u8>> for FrozenError {
     #[inline]
     fn from(message: Vec<u8>) -> Self {
         let message = Cow::Owned(message);
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -142,14 +276,22 @@ impl From<&'static [u8]> for FrozenError {
     #[inline]
     fn from(message: &'static [u8]) -> Self {
         let message = Cow::Borrowed(message);
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
 impl From<Cow<'static, [u8]>> for FrozenError {
     #[inline]
     fn from(message: Cow<'static, [u8]>) -> Self {
-        Self { message }
+        Self {
+            message,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -161,6 +303,10 @@ impl fmt::Display for FrozenError {
         let message = self.message.as_ref();
         format_debug_escape_into(&mut f, message)?;
         f.write_str(")")?;
+        if let Some(receiver) = self.receiver.as_deref() {
+            f.write_str(" receiver: ")?;
+            format_debug_escape_into(&mut f, receiver)?;
+        }
         Ok(())
     }
 }
@@ -178,4 +324,9 @@ impl RubyException for FrozenError {
     fn name(&self) -> Cow<'_, str> {
         Cow::Borrowed(Self::name(self))
     }
+
+    #[inline]
+    fn cause(&self) -> Option<&dyn RubyException> {
+        self.cause.as_deref().map(|cause| -> &dyn RubyException { cause })
+    }
 }
@@ -1,9 +1,13 @@
 // @generated
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 #[cfg(feature = "std")]
 use std::error;
 
@@ -23,9 +27,11 @@ use crate::RubyException;
 /// [`Exception`]: https://ruby-doc.org/core-2.6.3/Exception.html
 /// [`Kernel#raise`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-raise
 /// [`NameError#name`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-name
-#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone)]
 pub struct NameError {
     message: Cow<'static, [u8]>,
+    name: Option<Vec<u8>>,
+    cause: Option<Arc<dyn RubyException + Send + Sync>>,
 }
 
 impl NameError {
@@ -49,7 +55,7 @@ impl NameError {
         // `raise RuntimeError` or `RuntimeError.new` have `message`
         // equal to the exception's class name.
         let message = Cow::Borrowed(DEFAULT_MESSAGE);
-        Self { message }
+        Self { message, name: None, cause: None }
     }
 
     /// Construct a new, `NameError` Ruby exception with the given
@@ -66,7 +72,78 @@ impl NameError {
     #[must_use]
     pub const fn with_message(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self { message, name: None, cause: None }
+    }
+
+    /// Attach a `cause` to this `NameError` Ruby exception, corresponding
+    /// to [`Exception#cause`].
+    ///
+    /// The cause is the exception that was being handled when this exception
+    /// was raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let cause = RuntimeError::with_message("underlying failure");
+    /// let exception = NameError::with_message("an error occurred").with_cause(Box::new(cause));
+    /// assert!(exception.cause().is_some());
+    /// ```
+    ///
+    /// [`Exception#cause`]: https://ruby-doc.org/core-2.6.3/Exception.html#method-i-cause
+    #[inline]
+    #[must_use]
+    pub fn with_cause(mut self, cause: Box<dyn RubyException + Send + Sync>) -> Self {
+        self.cause = Some(Arc::from(cause));
+        self
+    }
+
+    /// Construct a new `NameError` Ruby exception with the given message and
+    /// the name of the unresolved identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = NameError::with_name(b"undefined local variable or method `garble'".to_vec(), b"garble".to_vec());
+    /// assert_eq!(exception.unresolved_name(), Some(&b"garble"[..]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_name<T>(message: T, name: Vec<u8>) -> Self
+    where
+        T: Into<Cow<'static, [u8]>>,
+    {
+        let message = message.into();
+        Self {
+            message,
+            name: Some(name),
+            cause: None,
+        }
+    }
+
+    /// Return the name of the unresolved identifier this Ruby exception was
+    /// constructed with, corresponding to [`NameError#name`].
+    ///
+    /// This accessor is called `unresolved_name` because [`name`] returns the
+    /// exception's class name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = NameError::new();
+    /// assert_eq!(exception.unresolved_name(), None);
+    /// let exception = NameError::with_name(b"message".to_vec(), b"garble".to_vec());
+    /// assert_eq!(exception.unresolved_name(), Some(&b"garble"[..]));
+    /// ```
+    ///
+    /// [`NameError#name`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-name
+    /// [`name`]: Self::name
+    #[inline]
+    #[must_use]
+    pub fn unresolved_name(&self) -> Option<&[u8]> {
+        self.name.as_deref()
     }
 
     /// Return the message this Ruby exception was constructed with.
@@ -103,11 +180,46 @@ impl NameError {
     }
 }
 
+// `cause` is a trait object and cannot take part in comparisons or hashing, so
+// these impls only consider the remaining fields.
+impl Hash for NameError {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.message.hash(state);
+        self.name.hash(state);
+    }
+}
+
+impl PartialEq for NameError {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.name == other.name
+    }
+}
+
+impl Eq for NameError {}
+
+impl PartialOrd for NameError {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NameError {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.message
+            .cmp(&other.message)
+            .then_with(|| self.name.cmp(&other.name))
+    }
+}
+
 impl From<String> for NameError {
     #[inline]
     fn from(message: String) -> Self {
         let message = Cow::Owned(message.into_bytes());
-        Self { message }
+        Self { message, name: None, cause: None }
     }
 }
 
@@ -115,7 +227,7 @@ impl From<&'static str> for NameError {
     #[inline]
     fn from(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self { message, name: None, cause: None }
     }
 }
 
@@ -126,7 +238,7 @@ impl From<Cow<'static, str>> for NameError {
             Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
             Cow::Owned(s) => Cow::Owned(s.into_bytes()),
         };
-        Self { message }
+        Self { message, name: None, cause: None }
     }
 }
 
@@ -134,7 +246,7 @@ impl From<Vec<u8>> for NameError {
     #[inline]
     fn from(message: Vec<u8>) -> Self {
         let message = Cow::Owned(message);
-        Self { message }
+        Self { message, name: None, cause: None }
     }
 }
 
@@ -142,14 +254,14 @@ impl From<&'static [u8]> for NameError {
     #[inline]
     fn from(message: &'static [u8]) -> Self {
         let message = Cow::Borrowed(message);
-        Self { message }
+        Self { message, name: None, cause: None }
     }
 }
 
 impl From<Cow<'static, [u8]>> for NameError {
     #[inline]
     fn from(message: Cow<'static, [u8]>) -> Self {
-        Self { message }
+        Self { message, name: None, cause: None }
     }
 }
 
@@ -161,6 +273,10 @@ impl fmt::Display for NameError {
         let message = self.message.as_ref();
         format_debug_escape_into(&mut f, message)?;
         f.write_str(")")?;
+        if let Some(name) = self.name.as_deref() {
+            f.write_str(" name: ")?;
+            format_debug_escape_into(&mut f, name)?;
+        }
         Ok(())
     }
 }
@@ -178,4 +294,9 @@ impl RubyException for NameError {
     fn name(&self) -> Cow<'_, str> {
         Cow::Borrowed(Self::name(self))
     }
+
+    #[inline]
+    fn cause(&self) -> Option<&dyn RubyException> {
+        self.cause.as_deref().map(|cause| -> &dyn RubyException { cause })
+    }
 }
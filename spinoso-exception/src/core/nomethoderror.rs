@@ -1,9 +1,13 @@
 // @generated
 
 use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 #[cfg(feature = "std")]
 use std::error;
 
@@ -23,9 +27,12 @@ use crate::RubyException;
 /// [`Exception`]: https://ruby-doc.org/core-2.6.3/Exception.html
 /// [`Kernel#raise`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-raise
 /// [`NameError#name`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-name
-#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone)]
 pub struct NoMethodError {
     message: Cow<'static, [u8]>,
+    args: Option<Vec<u8>>,
+    receiver: Option<Vec<u8>>,
+    cause: Option<Arc<dyn RubyException + Send + Sync>>,
 }
 
 impl NoMethodError {
@@ -49,7 +56,12 @@ impl NoMethodError {
         // `raise RuntimeError` or `RuntimeError.new` have `message`
         // equal to the exception's class name.
         let message = Cow::Borrowed(DEFAULT_MESSAGE);
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
     }
 
     /// Construct a new, `NoMethodError` Ruby exception with the given
@@ -66,7 +78,105 @@ impl NoMethodError {
     #[must_use]
     pub const fn with_message(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
+    }
+
+    /// Attach a `cause` to this `NoMethodError` Ruby exception, corresponding
+    /// to [`Exception#cause`].
+    ///
+    /// The cause is the exception that was being handled when this exception
+    /// was raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let cause = RuntimeError::with_message("underlying failure");
+    /// let exception = NoMethodError::with_message("an error occurred").with_cause(Box::new(cause));
+    /// assert!(exception.cause().is_some());
+    /// ```
+    ///
+    /// [`Exception#cause`]: https://ruby-doc.org/core-2.6.3/Exception.html#method-i-cause
+    #[inline]
+    #[must_use]
+    pub fn with_cause(mut self, cause: Box<dyn RubyException + Send + Sync>) -> Self {
+        self.cause = Some(Arc::from(cause));
+        self
+    }
+
+    /// Construct a new `NoMethodError` Ruby exception with the given message,
+    /// the arguments of the failed method call, and the receiver of the call,
+    /// corresponding to [`NoMethodError#args`] and [`NameError#receiver`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = NoMethodError::with_args_and_receiver(b"undefined method `garble'".to_vec(), b"[1, 2]".to_vec(), b"nil".to_vec());
+    /// assert_eq!(exception.args(), Some(&b"[1, 2]"[..]));
+    /// assert_eq!(exception.receiver(), Some(&b"nil"[..]));
+    /// ```
+    ///
+    /// [`NoMethodError#args`]: https://ruby-doc.org/core-2.6.3/NoMethodError.html#method-i-args
+    /// [`NameError#receiver`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-receiver
+    #[inline]
+    #[must_use]
+    pub fn with_args_and_receiver<T>(message: T, args: Vec<u8>, receiver: Vec<u8>) -> Self
+    where
+        T: Into<Cow<'static, [u8]>>,
+    {
+        let message = message.into();
+        Self {
+            message,
+            args: Some(args),
+            receiver: Some(receiver),
+            cause: None,
+        }
+    }
+
+    /// Return the arguments of the failed method call, corresponding to
+    /// [`NoMethodError#args`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = NoMethodError::new();
+    /// assert_eq!(exception.args(), None);
+    /// let exception = NoMethodError::with_args_and_receiver(b"message".to_vec(), b"[1, 2]".to_vec(), b"nil".to_vec());
+    /// assert_eq!(exception.args(), Some(&b"[1, 2]"[..]));
+    /// ```
+    ///
+    /// [`NoMethodError#args`]: https://ruby-doc.org/core-2.6.3/NoMethodError.html#method-i-args
+    #[inline]
+    #[must_use]
+    pub fn args(&self) -> Option<&[u8]> {
+        self.args.as_deref()
+    }
+
+    /// Return the receiver of the failed method call, corresponding to
+    /// [`NameError#receiver`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = NoMethodError::new();
+    /// assert_eq!(exception.receiver(), None);
+    /// let exception = NoMethodError::with_args_and_receiver(b"message".to_vec(), b"[1, 2]".to_vec(), b"nil".to_vec());
+    /// assert_eq!(exception.receiver(), Some(&b"nil"[..]));
+    /// ```
+    ///
+    /// [`NameError#receiver`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-receiver
+    #[inline]
+    #[must_use]
+    pub fn receiver(&self) -> Option<&[u8]> {
+        self.receiver.as_deref()
     }
 
     /// Return the message this Ruby exception was constructed with.
@@ -103,11 +213,53 @@ impl NoMethodError {
     }
 }
 
+// `cause` is a trait object and cannot take part in comparisons or hashing, so
+// these impls only consider the remaining fields.
+impl Hash for NoMethodError {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.message.hash(state);
+        self.args.hash(state);
+        self.receiver.hash(state);
+    }
+}
+
+impl PartialEq for NoMethodError {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.args == other.args && self.receiver == other.receiver
+    }
+}
+
+impl Eq for NoMethodError {}
+
+impl PartialOrd for NoMethodError {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NoMethodError {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.message
+            .cmp(&other.message)
+            .then_with(|| self.args.cmp(&other.args))
+            .then_with(|| self.receiver.cmp(&other.receiver))
+    }
+}
+
 impl From<String> for NoMethodError {
     #[inline]
     fn from(message: String) -> Self {
         let message = Cow::Owned(message.into_bytes());
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -115,7 +267,12 @@ impl From<&'static str> for NoMethodError {
     #[inline]
     fn from(message: &'static str) -> Self {
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -126,7 +283,12 @@ impl From<Cow<'static, str>> for NoMethodError {
             Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
             Cow::Owned(s) => Cow::Owned(s.into_bytes()),
         };
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -134,7 +296,12 @@ impl From<Vec<u8>> for NoMethodError {
     #[inline]
     fn from(message: Vec<u8>) -> Self {
         let message = Cow::Owned(message);
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -142,14 +309,24 @@ impl From<&'static [u8]> for NoMethodError {
     #[inline]
     fn from(message: &'static [u8]) -> Self {
         let message = Cow::Borrowed(message);
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
 impl From<Cow<'static, [u8]>> for NoMethodError {
     #[inline]
     fn from(message: Cow<'static, [u8]>) -> Self {
-        Self { message }
+        Self {
+            message,
+            args: None,
+            receiver: None,
+            cause: None,
+        }
     }
 }
 
@@ -161,6 +338,14 @@ impl fmt::Display for NoMethodError {
         let message = self.message.as_ref();
         format_debug_escape_into(&mut f, message)?;
         f.write_str(")")?;
+        if let Some(args) = self.args.as_deref() {
+            f.write_str(" args: ")?;
+            format_debug_escape_into(&mut f, args)?;
+        }
+        if let Some(receiver) = self.receiver.as_deref() {
+            f.write_str(" receiver: ")?;
+            format_debug_escape_into(&mut f, receiver)?;
+        }
         Ok(())
     }
 }
@@ -178,4 +363,9 @@ impl RubyException for NoMethodError {
     fn name(&self) -> Cow<'_, str> {
         Cow::Borrowed(Self::name(self))
     }
+
+    #[inline]
+    fn cause(&self) -> Option<&dyn RubyException> {
+        self.cause.as_deref().map(|cause| -> &dyn RubyException { cause })
+    }
 }
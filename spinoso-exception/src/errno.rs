@@ -0,0 +1,367 @@
+//! System call exceptions generated from errno values.
+//!
+//! Ruby maps operating system errors onto the `Errno::*` exception family —
+//! one `SystemCallError` subclass per errno constant. The [`Errno`] type in
+//! this module carries an errno number and an optional context (most commonly
+//! a path) and formats its message the way MRI does, e.g.
+//! `No such file or directory - /nope`.
+//!
+//! The errno-to-class table follows Linux errno numbering. Errno values
+//! without an entry in the table fall back to the `SystemCallError` base
+//! class with an `Unknown error NNN` message, which matches MRI's handling of
+//! unrecognized errno numbers.
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+#[cfg(feature = "std")]
+use std::io;
+
+use scolapasta_string_escape::format_debug_escape_into;
+
+use crate::RubyException;
+
+macro_rules! errno_table {
+    ($($num:literal => $constant:ident, $description:literal;)*) => {
+        /// Table of supported errno constants.
+        ///
+        /// Each entry is an errno number, the bare constant name to define
+        /// under the `Errno` module, and the MRI message for the error.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # use spinoso_exception::errno::ERRNO_TABLE;
+        /// assert!(ERRNO_TABLE.contains(&(2, "ENOENT", "No such file or directory")));
+        /// ```
+        pub const ERRNO_TABLE: &[(i32, &str, &str)] = &[
+            $(($num, stringify!($constant), $description),)*
+        ];
+
+        const fn constant_for_errno(errno: i32) -> Option<&'static str> {
+            match errno {
+                $($num => Some(stringify!($constant)),)*
+                _ => None,
+            }
+        }
+
+        const fn class_name_for_errno(errno: i32) -> Option<&'static str> {
+            match errno {
+                $($num => Some(concat!("Errno::", stringify!($constant))),)*
+                _ => None,
+            }
+        }
+
+        const fn description_for_errno(errno: i32) -> Option<&'static str> {
+            match errno {
+                $($num => Some($description),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+// Aliased constants which share an errno number with an entry below — for
+// example `EDEADLOCK`/`EDEADLK` and `EWOULDBLOCK`/`EAGAIN` — are omitted so
+// each errno number maps to exactly one class.
+errno_table! {
+    1 => EPERM, "Operation not permitted";
+    2 => ENOENT, "No such file or directory";
+    3 => ESRCH, "No such process";
+    4 => EINTR, "Interrupted system call";
+    5 => EIO, "Input/output error";
+    6 => ENXIO, "No such device or address";
+    7 => E2BIG, "Argument list too long";
+    8 => ENOEXEC, "Exec format error";
+    9 => EBADF, "Bad file descriptor";
+    10 => ECHILD, "No child processes";
+    11 => EAGAIN, "Resource temporarily unavailable";
+    12 => ENOMEM, "Cannot allocate memory";
+    13 => EACCES, "Permission denied";
+    14 => EFAULT, "Bad address";
+    15 => ENOTBLK, "Block device required";
+    16 => EBUSY, "Device or resource busy";
+    17 => EEXIST, "File exists";
+    18 => EXDEV, "Invalid cross-device link";
+    19 => ENODEV, "No such device";
+    20 => ENOTDIR, "Not a directory";
+    21 => EISDIR, "Is a directory";
+    22 => EINVAL, "Invalid argument";
+    23 => ENFILE, "Too many open files in system";
+    24 => EMFILE, "Too many open files";
+    25 => ENOTTY, "Inappropriate ioctl for device";
+    26 => ETXTBSY, "Text file busy";
+    27 => EFBIG, "File too large";
+    28 => ENOSPC, "No space left on device";
+    29 => ESPIPE, "Illegal seek";
+    30 => EROFS, "Read-only file system";
+    31 => EMLINK, "Too many links";
+    32 => EPIPE, "Broken pipe";
+    33 => EDOM, "Numerical argument out of domain";
+    34 => ERANGE, "Numerical result out of range";
+    35 => EDEADLK, "Resource deadlock avoided";
+    36 => ENAMETOOLONG, "File name too long";
+    37 => ENOLCK, "No locks available";
+    38 => ENOSYS, "Function not implemented";
+    39 => ENOTEMPTY, "Directory not empty";
+    40 => ELOOP, "Too many levels of symbolic links";
+    98 => EADDRINUSE, "Address already in use";
+    99 => EADDRNOTAVAIL, "Cannot assign requested address";
+    103 => ECONNABORTED, "Software caused connection abort";
+    104 => ECONNRESET, "Connection reset by peer";
+    107 => ENOTCONN, "Transport endpoint is not connected";
+    110 => ETIMEDOUT, "Connection timed out";
+    111 => ECONNREFUSED, "Connection refused";
+}
+
+/// Ruby `Errno::*` error type.
+///
+/// An `Errno` carries an errno number and an optional context — most commonly
+/// the path or command which triggered the error. The exception's
+/// [`name`](Self::name) is the specific `Errno::EXXX` class name when the
+/// errno number is in [`ERRNO_TABLE`] and `SystemCallError` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # use spinoso_exception::Errno;
+/// let exception = Errno::with_context(2, Some(b"/nope"));
+/// assert_eq!(exception.name(), "Errno::ENOENT");
+/// assert_eq!(exception.message(), b"No such file or directory - /nope");
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Errno {
+    errno: i32,
+    message: Vec<u8>,
+}
+
+impl Errno {
+    /// Construct a new `Errno` Ruby exception from an errno number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::Errno;
+    /// let exception = Errno::new(13);
+    /// assert_eq!(exception.name(), "Errno::EACCES");
+    /// assert_eq!(exception.message(), b"Permission denied");
+    /// ```
+    #[must_use]
+    pub fn new(errno: i32) -> Self {
+        Self::with_context(errno, None)
+    }
+
+    /// Construct a new `Errno` Ruby exception from an errno number and an
+    /// optional context, such as a path.
+    ///
+    /// The context is appended to the errno description with MRI's ` - `
+    /// separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::Errno;
+    /// let exception = Errno::with_context(17, Some(b"/tmp/artichoke"));
+    /// assert_eq!(exception.message(), b"File exists - /tmp/artichoke");
+    /// ```
+    #[must_use]
+    pub fn with_context(errno: i32, context: Option<&[u8]>) -> Self {
+        let mut message = match description_for_errno(errno) {
+            Some(description) => description.as_bytes().to_vec(),
+            None => format!("Unknown error {}", errno).into_bytes(),
+        };
+        if let Some(context) = context {
+            message.extend_from_slice(b" - ");
+            message.extend_from_slice(context);
+        }
+        Self { errno, message }
+    }
+
+    /// Construct a new `Errno` Ruby exception from an [`io::Error`] and an
+    /// optional context, such as a path.
+    ///
+    /// The errno number is taken from the error's
+    /// [raw OS error](io::Error::raw_os_error) when present. Synthesized I/O
+    /// errors without an OS error are mapped from their [`io::ErrorKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// # use spinoso_exception::Errno;
+    /// let err = io::Error::new(io::ErrorKind::NotFound, "not found");
+    /// let exception = Errno::from_io_error(&err, Some(b"/nope"));
+    /// assert_eq!(exception.name(), "Errno::ENOENT");
+    /// assert_eq!(exception.message(), b"No such file or directory - /nope");
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[must_use]
+    pub fn from_io_error(err: &io::Error, context: Option<&[u8]>) -> Self {
+        let errno = if let Some(errno) = err.raw_os_error() {
+            errno
+        } else {
+            match err.kind() {
+                io::ErrorKind::NotFound => 2,            // ENOENT
+                io::ErrorKind::PermissionDenied => 13,   // EACCES
+                io::ErrorKind::ConnectionRefused => 111, // ECONNREFUSED
+                io::ErrorKind::ConnectionReset => 104,   // ECONNRESET
+                io::ErrorKind::ConnectionAborted => 103, // ECONNABORTED
+                io::ErrorKind::NotConnected => 107,      // ENOTCONN
+                io::ErrorKind::AddrInUse => 98,          // EADDRINUSE
+                io::ErrorKind::AddrNotAvailable => 99,   // EADDRNOTAVAIL
+                io::ErrorKind::BrokenPipe => 32,         // EPIPE
+                io::ErrorKind::AlreadyExists => 17,      // EEXIST
+                io::ErrorKind::WouldBlock => 11,         // EAGAIN
+                io::ErrorKind::InvalidInput => 22,       // EINVAL
+                io::ErrorKind::TimedOut => 110,          // ETIMEDOUT
+                io::ErrorKind::Interrupted => 4,         // EINTR
+                _ => 0,
+            }
+        };
+        Self::with_context(errno, context)
+    }
+
+    /// Return the errno number this exception was constructed with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::Errno;
+    /// let exception = Errno::new(2);
+    /// assert_eq!(exception.errno(), 2);
+    /// ```
+    #[must_use]
+    pub const fn errno(&self) -> i32 {
+        self.errno
+    }
+
+    /// Return the message this Ruby exception was constructed with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::Errno;
+    /// let exception = Errno::new(2);
+    /// assert_eq!(exception.message(), b"No such file or directory");
+    /// ```
+    #[must_use]
+    pub fn message(&self) -> &[u8] {
+        self.message.as_slice()
+    }
+
+    /// Return this Ruby exception's class name.
+    ///
+    /// Errno numbers without an entry in [`ERRNO_TABLE`] fall back to the
+    /// `SystemCallError` base class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::Errno;
+    /// let exception = Errno::new(2);
+    /// assert_eq!(exception.name(), "Errno::ENOENT");
+    /// let exception = Errno::new(9999);
+    /// assert_eq!(exception.name(), "SystemCallError");
+    /// ```
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match class_name_for_errno(self.errno) {
+            Some(name) => name,
+            None => "SystemCallError",
+        }
+    }
+
+    /// Return the bare constant name of this exception's class under the
+    /// `Errno` module, if the errno number is in [`ERRNO_TABLE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::Errno;
+    /// let exception = Errno::new(2);
+    /// assert_eq!(exception.constant(), Some("ENOENT"));
+    /// let exception = Errno::new(9999);
+    /// assert_eq!(exception.constant(), None);
+    /// ```
+    #[must_use]
+    pub const fn constant(&self) -> Option<&'static str> {
+        constant_for_errno(self.errno)
+    }
+}
+
+impl fmt::Display for Errno {
+    fn fmt(&self, mut f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())?;
+        f.write_str(" (")?;
+        let message = self.message.as_slice();
+        format_debug_escape_into(&mut f, message)?;
+        f.write_str(")")?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for Errno {}
+
+impl RubyException for Errno {
+    fn message(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(Self::message(self))
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(Self::name(self))
+    }
+
+    fn cause(&self) -> Option<&dyn RubyException> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::io;
+
+    use super::Errno;
+
+    #[test]
+    fn message_includes_context_when_present() {
+        let exception = Errno::new(2);
+        assert_eq!(exception.message(), b"No such file or directory");
+        let exception = Errno::with_context(2, Some(b"/nope"));
+        assert_eq!(exception.message(), b"No such file or directory - /nope");
+    }
+
+    #[test]
+    fn unknown_errno_falls_back_to_system_call_error() {
+        let exception = Errno::new(9999);
+        assert_eq!(exception.name(), "SystemCallError");
+        assert_eq!(exception.constant(), None);
+        assert_eq!(exception.message(), b"Unknown error 9999");
+        let exception = Errno::with_context(9999, Some(b"ctx"));
+        assert_eq!(exception.message(), b"Unknown error 9999 - ctx");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_error_kinds_map_to_errno_classes() {
+        let err = io::Error::new(io::ErrorKind::NotFound, "not found");
+        assert_eq!(Errno::from_io_error(&err, None).name(), "Errno::ENOENT");
+        let err = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        assert_eq!(Errno::from_io_error(&err, None).name(), "Errno::EACCES");
+        let err = io::Error::new(io::ErrorKind::AlreadyExists, "exists");
+        assert_eq!(Errno::from_io_error(&err, None).name(), "Errno::EEXIST");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn raw_os_errors_take_precedence_over_error_kinds() {
+        let err = io::Error::from_raw_os_error(13);
+        assert_eq!(Errno::from_io_error(&err, None).name(), "Errno::EACCES");
+        assert_eq!(Errno::from_io_error(&err, None).errno(), 13);
+    }
+}
@@ -127,12 +127,20 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+// `use ::core` instead of `use core` because this crate has a `core` module
+// which shadows the `core` crate in uniform paths.
+use ::core::fmt;
 use alloc::borrow::Cow;
 
+use scolapasta_string_escape::format_debug_escape_into;
+
 pub mod core;
+pub mod errno;
 
 #[doc(inline)]
 pub use self::core::*;
+#[doc(inline)]
+pub use self::errno::Errno;
 
 /// Polymorphic exception type that corresponds to Ruby's `Exception`.
 ///
@@ -157,7 +165,7 @@ pub use self::core::*;
 /// ```
 ///
 /// [object safe]: https://doc.rust-lang.org/book/ch17-02-trait-objects.html#object-safety-is-required-for-trait-objects
-pub trait RubyException {
+pub trait RubyException: fmt::Debug {
     /// The exception's message.
     ///
     /// # Examples
@@ -199,7 +207,90 @@ pub trait RubyException {
     ///
     /// [`Vec<u8>`]: alloc::vec::Vec
     fn name(&self) -> Cow<'_, str>;
+
+    /// The exception that was being handled when this exception was raised,
+    /// if any.
+    ///
+    /// This method corresponds to [`Exception#cause`]. In Ruby, the cause is
+    /// set implicitly when raising an exception inside a `rescue` block.
+    ///
+    /// The default implementation returns [`None`]. Concrete exception types
+    /// in this crate attach a cause with their `with_cause` builders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = RuntimeError::with_message("something went wrong");
+    /// assert!(exception.cause().is_none());
+    ///
+    /// let cause = RuntimeError::with_message("underlying failure");
+    /// let exception = ArgumentError::with_message("invalid argument").with_cause(Box::new(cause));
+    /// let cause = exception.cause().unwrap();
+    /// assert_eq!(cause.message(), &b"underlying failure"[..]);
+    /// ```
+    ///
+    /// [`Exception#cause`]: https://ruby-doc.org/core-2.6.3/Exception.html#method-i-cause
+    #[inline]
+    fn cause(&self) -> Option<&dyn RubyException> {
+        None
+    }
 }
 
 // Assert that `RubyException` is object-safe (i.e. supports dynamic dispatch).
 const _: Option<&dyn RubyException> = None;
+
+/// Write an exception and its chain of [`cause`]s into the given writer.
+///
+/// The exception is rendered as `Name (message)`, matching the `Display`
+/// impls of the exception types in this crate. Each cause in the chain is
+/// rendered on its own line prefixed with `caused by: `, outermost exception
+/// first.
+///
+/// `Display` for exception types does not include the cause; this helper is
+/// intended for top-level error reporting, such as printing an uncaught
+/// exception in a CLI.
+///
+/// # Examples
+///
+/// ```
+/// # use spinoso_exception::*;
+/// let root = RuntimeError::with_message("root failure");
+/// let middle = FrozenError::with_message("can't modify frozen String").with_cause(Box::new(root));
+/// let top = ArgumentError::with_message("invalid argument").with_cause(Box::new(middle));
+///
+/// let mut rendered = String::new();
+/// fmt_with_cause(&mut rendered, &top).unwrap();
+/// assert_eq!(
+///     rendered,
+///     "ArgumentError (invalid argument)\n\
+///      caused by: FrozenError (can't modify frozen String)\n\
+///      caused by: RuntimeError (root failure)"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// If the given writer returns an error, that error is returned.
+///
+/// [`cause`]: RubyException::cause
+pub fn fmt_with_cause<W>(mut f: W, exc: &dyn RubyException) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    f.write_str(&exc.name())?;
+    f.write_str(" (")?;
+    format_debug_escape_into(&mut f, exc.message())?;
+    f.write_str(")")?;
+
+    let mut cause = exc.cause();
+    while let Some(exc) = cause {
+        f.write_str("\ncaused by: ")?;
+        f.write_str(&exc.name())?;
+        f.write_str(" (")?;
+        format_debug_escape_into(&mut f, exc.message())?;
+        f.write_str(")")?;
+        cause = exc.cause();
+    }
+    Ok(())
+}
@@ -28,6 +28,27 @@ int32s_b = SAMPLES.times.map { r.rand INT32_MAX }
 
 raise 'not reproducible' unless int32s_a == int32s_b
 
+r = Random.new(33)
+rand_ten_32 = 64.times.map { r.rand 10 }
+
+r = Random.new(33)
+rand_2_pow_40_32 = 16.times.map { r.rand 2**40 }
+
+r = Random.new(1234)
+int32s_1234 = 8.times.map { r.rand INT32_MAX }
+
+r = Random.new(1234)
+floats_1234 = 16.times.map { r.rand }
+
+r = Random.new(1234)
+rand_six_1234 = 32.times.map { r.rand 6 }
+
+r = Random.new(8_675_309)
+floats_8675309 = 8.times.map { r.rand }
+
+r = Random.new(8_675_309)
+rand_ten_8675309 = 32.times.map { r.rand 10 }
+
 File.open('tests/vectors/mod.rs', 'w') do |rs|
   rs.puts '// This file is automatically generated.'
   rs.puts '//'
@@ -44,4 +65,32 @@ File.open('tests/vectors/mod.rs', 'w') do |rs|
 
   rs.puts '#[allow(clippy::unreadable_literal)]'
   rs.puts "pub static INT32_SEED_32: &[u32] = &#{int32s_a.inspect};"
+  rs.puts
+
+  rs.puts '#[allow(clippy::unreadable_literal)]'
+  rs.puts "pub static RAND_TEN_SEED_32: &[i64] = &#{rand_ten_32.inspect};"
+  rs.puts
+
+  rs.puts '#[allow(clippy::unreadable_literal)]'
+  rs.puts "pub static RAND_2_POW_40_SEED_32: &[i64] = &#{rand_2_pow_40_32.inspect};"
+  rs.puts
+
+  rs.puts '#[allow(clippy::unreadable_literal)]'
+  rs.puts "pub static INT32_SEED_1234: &[u32] = &#{int32s_1234.inspect};"
+  rs.puts
+
+  rs.puts '#[allow(clippy::unreadable_literal)]'
+  rs.puts "pub static REAL_SEED_1234: &[f64] = &#{floats_1234.inspect};"
+  rs.puts
+
+  rs.puts '#[allow(clippy::unreadable_literal)]'
+  rs.puts "pub static RAND_SIX_SEED_1234: &[i64] = &#{rand_six_1234.inspect};"
+  rs.puts
+
+  rs.puts '#[allow(clippy::unreadable_literal)]'
+  rs.puts "pub static REAL_SEED_8675309: &[f64] = &#{floats_8675309.inspect};"
+  rs.puts
+
+  rs.puts '#[allow(clippy::unreadable_literal)]'
+  rs.puts "pub static RAND_TEN_SEED_8675309: &[i64] = &#{rand_ten_8675309.inspect};"
 end
@@ -34,8 +34,9 @@
 //!
 //! # Implementation notes
 //!
-//! This RNG reproduces the same random bytes and floats as MRI. It may differ
-//! when returning elements confined to a distribution.
+//! This RNG reproduces the same random bytes, floats, and bounded integers as
+//! MRI for a given seed. Bounded integer draws use the same rejection
+//! sampling as MRI's `limited_rand`, so `rand(max)` sequences match exactly.
 //!
 //! # Examples
 //!
@@ -76,7 +77,7 @@
 //!
 //! - **random-rand** - Enables range sampling methods for the [`rand()`]
 //!   function.  Activating this feature also activates the **rand-traits**
-//!   feature. Dropping this feature removes the [`rand`] dependency.
+//!   feature.
 //! - **rand-traits** - Enables implementations of [`RngCore`] on the [`Random`]
 //!   type. Dropping this feature removes the [`rand_core`] dependency.
 //! - **std** - Enables a dependency on the Rust Standard Library. Activating
@@ -85,7 +86,6 @@
 //!
 //! [ruby-random]: https://ruby-doc.org/core-2.6.3/Random.html
 //! [`alloc`]: https://doc.rust-lang.org/alloc/
-//! [`rand`]: ::rand
 //! [`RngCore`]: rand_core::RngCore
 
 #![no_std]
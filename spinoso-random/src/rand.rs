@@ -1,7 +1,5 @@
 use core::fmt;
 
-use rand::Rng;
-
 use crate::{ArgumentError, Random};
 
 /// A range constraint for generating random numbers.
@@ -140,7 +138,9 @@ pub fn rand(rng: &mut Random, max: Max) -> Result<Rand, ArgumentError> {
             Ok(Rand::Float(number))
         }
         Max::Float(max) => {
-            let number = rng.gen_range(0.0..max);
+            // MRI scales the output of `genrand_real` by the max, so bounded
+            // float sequences are reproducible from the same seed.
+            let number = rng.next_real() * max;
             Ok(Rand::Float(number))
         }
         Max::Integer(max) if max < 1 => {
@@ -148,7 +148,12 @@ pub fn rand(rng: &mut Random, max: Max) -> Result<Rand, ArgumentError> {
             Err(err)
         }
         Max::Integer(max) => {
-            let number = rng.gen_range(0..max);
+            // `max` is positive, so `max - 1` is representable as a `u64`.
+            let limit = u64::try_from(max - 1).unwrap_or_default();
+            let number = limited_rand(rng, limit);
+            // The generated number is bounded by `limit`, which fits in an
+            // `i64`.
+            let number = i64::try_from(number).unwrap_or_default();
             Ok(Rand::Integer(number))
         }
         Max::None => {
@@ -158,11 +163,61 @@ pub fn rand(rng: &mut Random, max: Max) -> Result<Rand, ArgumentError> {
     }
 }
 
+/// Generate a random number on `0..=limit` with rejection sampling.
+///
+/// This is a port of `limited_rand` from MRI's `random.c`. The generator is
+/// sampled 32 bits at a time into the words covered by [`make_mask`] and the
+/// draw is retried if the masked value exceeds the limit. Because rejected
+/// draws consume the same generator outputs as MRI, bounded integer sequences
+/// match MRI exactly for a given seed.
+fn limited_rand(rng: &mut Random, limit: u64) -> u64 {
+    if limit == 0 {
+        return 0;
+    }
+    let mask = make_mask(limit);
+    'retry: loop {
+        let mut val = 0_u64;
+        // Draw the high word first, like MRI on 64-bit platforms.
+        for shift in [32_u32, 0] {
+            if (mask >> shift) & 0xffff_ffff != 0 {
+                val |= u64::from(rng.next_int32()) << shift;
+                val &= mask;
+                if limit < val {
+                    continue 'retry;
+                }
+            }
+        }
+        return val;
+    }
+}
+
+/// Smallest mask of contiguous one bits that covers `limit`.
+const fn make_mask(mut limit: u64) -> u64 {
+    limit |= limit >> 1;
+    limit |= limit >> 2;
+    limit |= limit >> 4;
+    limit |= limit >> 8;
+    limit |= limit >> 16;
+    limit |= limit >> 32;
+    limit
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{rand, Max, Rand};
+    use super::{make_mask, rand, Max, Rand};
     use crate::Random;
 
+    #[test]
+    fn make_mask_covers_limit() {
+        assert_eq!(make_mask(0), 0);
+        assert_eq!(make_mask(1), 1);
+        assert_eq!(make_mask(2), 3);
+        assert_eq!(make_mask(9), 15);
+        assert_eq!(make_mask(u64::from(u32::MAX)), u64::from(u32::MAX));
+        assert_eq!(make_mask(u64::from(u32::MAX) + 1), (1 << 33) - 1);
+        assert_eq!(make_mask(u64::MAX), u64::MAX);
+    }
+
     #[test]
     fn random_number_domain_error() {
         let mut random = Random::with_seed(33);
@@ -1,13 +1,14 @@
 use core::fmt;
 use core::mem::size_of;
 
-use rand_mt::Mt;
-
 use crate::{InitializeError, NewSeedError};
 
+mod mt;
 #[cfg(feature = "rand-traits")]
 mod rand;
 
+use mt::Mt;
+
 const DEFAULT_SEED_CNT: usize = 4;
 const DEFAULT_SEED_BYTES: usize = size_of::<u32>() * DEFAULT_SEED_CNT;
 
@@ -26,8 +27,8 @@ const DEFAULT_SEED: u32 = 5489_u32;
 /// PRNGs are currently implemented as a modified Mersenne Twister with a period
 /// of 2**19937-1.
 ///
-/// This RNG reproduces the same random bytes and floats as MRI. It may differ
-/// when returning elements confined to a distribution.
+/// This RNG reproduces the same random bytes, floats, and bounded integers as
+/// MRI for a given seed.
 ///
 /// # Examples
 ///
@@ -0,0 +1,215 @@
+//! Mersenne Twister (MT19937) random number generator.
+//!
+//! This is a port of the MT19937 generator used by MRI's `Random`: a 624-word
+//! state twisted in batches, with the `init_genrand` and `init_by_array`
+//! seeding schemes from the reference `mt19937ar` implementation. Reproducing
+//! MRI sequences requires matching the seeding scheme exactly: MRI seeds with
+//! `init_genrand` for seeds that fit in 32 bits and `init_by_array` for
+//! multi-word seeds.
+
+use core::fmt;
+use core::mem::size_of;
+
+/// Number of 32-bit words in the generator state.
+const N: usize = 624;
+/// Recurrence offset into the state.
+const M: usize = 397;
+/// Constant vector a.
+const MATRIX_A: u32 = 0x9908_b0df;
+/// Most significant w-r bits.
+const UPPER_MASK: u32 = 0x8000_0000;
+/// Least significant r bits.
+const LOWER_MASK: u32 = 0x7fff_ffff;
+
+/// An MT19937 random number generator.
+///
+/// The generator produces a deterministic sequence of 32-bit outputs from a
+/// seeded 624-word state with a period of 2**19937-1.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub(crate) struct Mt {
+    state: [u32; N],
+    index: usize,
+}
+
+impl fmt::Debug for Mt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Mt {}")
+    }
+}
+
+impl Mt {
+    /// Create a new generator seeded with a single 32-bit seed.
+    ///
+    /// This is the `init_genrand` seeding scheme from `mt19937ar`.
+    #[must_use]
+    pub fn new(seed: u32) -> Self {
+        let mut state = [0_u32; N];
+        state[0] = seed;
+        let mut index = 1_u32;
+        while (index as usize) < N {
+            let prev = state[index as usize - 1];
+            state[index as usize] = 1_812_433_253_u32
+                .wrapping_mul(prev ^ (prev >> 30))
+                .wrapping_add(index);
+            index += 1;
+        }
+        Self { state, index: N }
+    }
+
+    /// Create a new generator seeded with a key of 32-bit words.
+    ///
+    /// This is the `init_by_array` seeding scheme from `mt19937ar`, which MRI
+    /// uses for seeds wider than 32 bits.
+    #[must_use]
+    pub fn new_with_key<I>(key: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+        I::IntoIter: Clone,
+    {
+        let key = key.into_iter();
+        let key_len = key.clone().count();
+        let mut mt = Self::new(19_650_218);
+
+        let mut i = 1_usize;
+        let mut j = 0_u32;
+        let mut key_cycle = key.clone();
+        for _ in 0..N.max(key_len) {
+            let word = if let Some(word) = key_cycle.next() {
+                word
+            } else {
+                j = 0;
+                key_cycle = key.clone();
+                key_cycle.next().unwrap_or_default()
+            };
+            let prev = mt.state[i - 1];
+            mt.state[i] = (mt.state[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1_664_525))
+                .wrapping_add(word)
+                .wrapping_add(j);
+            i += 1;
+            j += 1;
+            if i >= N {
+                mt.state[0] = mt.state[N - 1];
+                i = 1;
+            }
+        }
+        for _ in 0..N - 1 {
+            let prev = mt.state[i - 1];
+            // `i` is bounded by `N`, so the index always fits in a `u32`.
+            let index = u32::try_from(i).unwrap_or_default();
+            mt.state[i] = (mt.state[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1_566_083_941)).wrapping_sub(index);
+            i += 1;
+            if i >= N {
+                mt.state[0] = mt.state[N - 1];
+                i = 1;
+            }
+        }
+        mt.state[0] = 0x8000_0000;
+        mt
+    }
+
+    /// Generate the next 32-bit output.
+    ///
+    /// This is `genrand_int32` from `mt19937ar`: the state is twisted in
+    /// batches of [`N`] words and outputs are tempered as they are read out.
+    #[must_use]
+    pub fn next_u32(&mut self) -> u32 {
+        if self.index >= N {
+            self.twist();
+        }
+        let mut word = self.state[self.index];
+        self.index += 1;
+        word ^= word >> 11;
+        word ^= (word << 7) & 0x9d2c_5680;
+        word ^= (word << 15) & 0xefc6_0000;
+        word ^= word >> 18;
+        word
+    }
+
+    /// Generate the next 64-bit output from two 32-bit outputs.
+    ///
+    /// The first output forms the most significant bits.
+    #[must_use]
+    pub fn next_u64(&mut self) -> u64 {
+        let hi = u64::from(self.next_u32());
+        let lo = u64::from(self.next_u32());
+        hi << 32 | lo
+    }
+
+    /// Fill a buffer with bytes generated from the RNG.
+    ///
+    /// Outputs are generated 32 bits at a time and written out in little
+    /// endian byte order, matching MRI's `Random#bytes`. A partial trailing
+    /// chunk takes the low bytes of one output and discards the rest.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(size_of::<u32>());
+        for chunk in chunks.by_ref() {
+            chunk.copy_from_slice(&self.next_u32().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let bytes = self.next_u32().to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
+
+    /// Twist the state to generate the next batch of [`N`] outputs.
+    fn twist(&mut self) {
+        for i in 0..N {
+            let word = (self.state[i] & UPPER_MASK) | (self.state[(i + 1) % N] & LOWER_MASK);
+            let mut next = word >> 1;
+            if word & 1 != 0 {
+                next ^= MATRIX_A;
+            }
+            self.state[i] = self.state[(i + M) % N] ^ next;
+        }
+        self.index = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mt;
+
+    #[test]
+    fn init_genrand_matches_reference_implementation() {
+        // First outputs of `mt19937ar` after `init_genrand(5489)`, the
+        // reference default seed.
+        let mut mt = Mt::new(5489);
+        let expected = [3_499_211_612_u32, 581_869_302, 3_890_346_734, 3_586_334_585];
+        for &word in &expected {
+            assert_eq!(mt.next_u32(), word);
+        }
+    }
+
+    #[test]
+    fn init_by_array_matches_reference_implementation() {
+        // First outputs of `mt19937ar` after
+        // `init_by_array({0x123, 0x234, 0x345, 0x456}, 4)`, the seeding used
+        // in the reference implementation's test driver.
+        let mut mt = Mt::new_with_key([0x123_u32, 0x234, 0x345, 0x456]);
+        let expected = [1_067_595_299_u32, 955_945_823, 477_289_528, 4_107_218_783, 4_228_976_476];
+        for &word in &expected {
+            assert_eq!(mt.next_u32(), word);
+        }
+    }
+
+    #[test]
+    fn fill_bytes_partial_chunk_takes_low_bytes() {
+        let mut mt = Mt::new(5489);
+        let first = mt.next_u32().to_le_bytes();
+
+        let mut mt = Mt::new(5489);
+        let mut buf = [0_u8; 3];
+        mt.fill_bytes(&mut buf);
+        assert_eq!(buf[..], first[..3]);
+    }
+
+    #[test]
+    fn clone_preserves_sequence() {
+        let mut mt = Mt::new(33);
+        let mut clone = mt.clone();
+        for _ in 0..1000 {
+            assert_eq!(mt.next_u32(), clone.next_u32());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use spinoso_random::Random;
+use spinoso_random::{rand, Max, Rand, Random};
 
 mod vectors;
 
@@ -13,10 +13,8 @@ fn bytes_reproducibility() {
 #[test]
 fn float_reproducibility() {
     let mut rng = Random::with_seed(33);
-    let mut samples = Vec::with_capacity(4096);
-    for cell in samples.iter_mut() {
-        *cell = rng.next_real();
-    }
+    let samples = (0..4096).map(|_| rng.next_real()).collect::<Vec<_>>();
+    assert_eq!(samples.len(), vectors::REAL_SEED_32.len());
     for (sample, expected) in samples.iter().zip(vectors::REAL_SEED_32.iter()) {
         assert!((sample - expected).abs() < f64::EPSILON);
     }
@@ -62,3 +60,70 @@ fn spec_bytes() {
     rng.fill_bytes(&mut buf);
     assert_eq!(buf[..], b"\xA1p"[..]);
 }
+
+fn bounded_draws(seed: u32, max: i64, len: usize) -> Vec<i64> {
+    let mut rng = Random::with_seed(seed);
+    (0..len)
+        .map(|_| match rand(&mut rng, Max::Integer(max)) {
+            Ok(Rand::Integer(num)) => num,
+            draw => panic!("unexpected bounded draw: {:?}", draw),
+        })
+        .collect()
+}
+
+#[test]
+fn bounded_integer_reproducibility() {
+    let samples = bounded_draws(33, 10, vectors::RAND_TEN_SEED_32.len());
+    assert_eq!(samples[..], vectors::RAND_TEN_SEED_32[..]);
+}
+
+#[test]
+fn bounded_integer_reproducibility_above_u32() {
+    // A max wider than 32 bits exercises the two-word path of the rejection
+    // sampling loop.
+    let samples = bounded_draws(33, 1 << 40, vectors::RAND_2_POW_40_SEED_32.len());
+    assert_eq!(samples[..], vectors::RAND_2_POW_40_SEED_32[..]);
+}
+
+#[test]
+fn bounded_integer_reproducibility_across_seeds() {
+    let samples = bounded_draws(1234, 6, vectors::RAND_SIX_SEED_1234.len());
+    assert_eq!(samples[..], vectors::RAND_SIX_SEED_1234[..]);
+
+    let samples = bounded_draws(8_675_309, 10, vectors::RAND_TEN_SEED_8675309.len());
+    assert_eq!(samples[..], vectors::RAND_TEN_SEED_8675309[..]);
+}
+
+#[test]
+fn float_reproducibility_across_seeds() {
+    let mut rng = Random::with_seed(1234);
+    for expected in vectors::REAL_SEED_1234 {
+        assert!((rng.next_real() - expected).abs() < f64::EPSILON);
+    }
+
+    let mut rng = Random::with_seed(8_675_309);
+    for expected in vectors::REAL_SEED_8675309 {
+        assert!((rng.next_real() - expected).abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn u32_reproducibility_across_seeds() {
+    let mut rng = Random::with_seed(1234);
+    for &expected in vectors::INT32_SEED_1234 {
+        assert_eq!(rng.next_int32(), expected);
+    }
+}
+
+#[test]
+fn bytes_lengths() {
+    // `Random#bytes` must fill buffers of any length, including lengths which
+    // are not a multiple of the generator's four byte output unit.
+    for len in [0, 1, 2, 3, 4, 5, 7, 16, 17, 4096] {
+        let mut rng = Random::with_seed(33);
+        let mut buf = vec![0; len];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf.len(), len);
+        assert_eq!(buf[..], vectors::BYTES_SEED_32[..len]);
+    }
+}
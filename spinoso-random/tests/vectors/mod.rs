@@ -4726,3 +4726,38 @@ pub static INT32_SEED_32: &[u32] = &[
     2523627839, 2022135766, 2410513072, 3625205557, 2126999610, 2104069062, 2849791542, 1799158561, 695586928,
     3309012871, 3381261469, 638058165, 1599999779, 4294780872,
 ];
+
+#[allow(clippy::unreadable_literal)]
+pub static RAND_TEN_SEED_32: &[i64] = &[
+    4, 7, 8, 2, 2, 9, 9, 3, 6, 3, 3, 1, 7, 6, 0, 0, 6, 6, 0, 4, 8, 8, 3, 7, 9, 3, 3, 7, 3, 7, 2, 1, 3, 6, 9, 0, 0, 4, 9, 2, 5, 7, 1, 1, 4, 1, 1, 8, 4, 8, 3, 5, 8, 0, 9, 7, 7, 9, 6, 9, 1, 5, 0, 3,
+];
+
+#[allow(clippy::unreadable_literal)]
+pub static RAND_2_POW_40_SEED_32: &[i64] = &[
+    88861510023, 930587476546, 631019564233, 245285333187, 441311856701, 908870727054, 1085984464810, 197052294947, 141848904525, 136654642348, 524937232103, 605431347595, 646235060048, 344057595754, 441289706742, 72860479572,
+];
+
+#[allow(clippy::unreadable_literal)]
+pub static INT32_SEED_1234: &[u32] = &[
+    822569775, 2137449171, 2671936806, 3512589365, 1880026316, 2629000564, 3373089432, 3312965625,
+];
+
+#[allow(clippy::unreadable_literal)]
+pub static REAL_SEED_1234: &[f64] = &[
+    0.1915194503788923, 0.6221087710398319, 0.4377277390071145, 0.7853585837137692, 0.7799758081188035, 0.2725926052826416, 0.2764642551430967, 0.8018721775350193, 0.9581393536837052, 0.8759326347420947, 0.35781726995786667, 0.5009951255234587, 0.6834629351721363, 0.7127020269829002, 0.37025075479039493, 0.5611961860656249,
+];
+
+#[allow(clippy::unreadable_literal)]
+pub static RAND_SIX_SEED_1234: &[i64] = &[
+    3, 5, 4, 4, 0, 1, 1, 1, 2, 3, 4, 4, 2, 2, 0, 0, 4, 5, 0, 1, 2, 0, 3, 4, 5, 2, 2, 3, 3, 0, 1, 3,
+];
+
+#[allow(clippy::unreadable_literal)]
+pub static REAL_SEED_8675309: &[f64] = &[
+    0.8124591174101188, 0.751045085465288, 0.3573665178596691, 0.20229477722142608, 0.4090011273795646, 0.08212472634183587, 0.21498652217742997, 0.4138809245395255,
+];
+
+#[allow(clippy::unreadable_literal)]
+pub static RAND_TEN_SEED_8675309: &[i64] = &[
+    1, 4, 0, 8, 1, 2, 7, 4, 4, 3, 8, 1, 7, 4, 4, 6, 6, 9, 3, 6, 9, 0, 5, 1, 8, 8, 3, 7, 8, 1, 1, 2,
+];
@@ -1,7 +1,10 @@
+use core::fmt;
 use core::iter::FusedIterator;
 
 use scolapasta_string_escape::InvalidUtf8ByteSequence;
 
+use crate::{Encoding, Options};
+
 #[derive(Debug, Clone)]
 struct Delimiters {
     bits: u8,
@@ -21,6 +24,11 @@ impl Delimiters {
         bits: Self::EMIT_LEFT_DELIMITER.bits | Self::EMIT_RIGHT_DELIMITER.bits,
     };
 
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
     #[inline]
     fn emit_left_delimiter(&mut self) -> Option<char> {
         if (self.bits & Self::EMIT_LEFT_DELIMITER.bits) == Self::EMIT_LEFT_DELIMITER.bits {
@@ -85,8 +93,8 @@ pub struct Debug<'a> {
     // `Regexp#inspect` prints `"/#{source}/"`.
     source: &'a [u8],
     literal: InvalidUtf8ByteSequence,
-    options: &'static str,
-    encoding: &'static str,
+    options: &'a str,
+    encoding: &'a str,
 }
 
 impl<'a> Debug<'a> {
@@ -118,7 +126,7 @@ impl<'a> Debug<'a> {
     ///
     /// [options modifiers]: crate::Options::as_display_modifier
     /// [encoding modifiers]: crate::Encoding::as_modifier_str
-    pub fn new(source: &'a [u8], options: &'static str, encoding: &'static str) -> Self {
+    pub fn new(source: &'a [u8], options: &'a str, encoding: &'a str) -> Self {
         Self {
             delimiters: Delimiters::DEFAULT,
             source,
@@ -127,6 +135,61 @@ impl<'a> Debug<'a> {
             encoding,
         }
     }
+
+    /// Construct a new `Debug` iterator with a regexp source and parsed
+    /// [`Options`] and [`Encoding`].
+    ///
+    /// The options and encoding are rendered with their canonical display
+    /// modifiers, [`Options::as_display_modifier`] and
+    /// [`Encoding::as_modifier_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_regexp::{Debug, Encoding, Flags, Options};
+    ///
+    /// let options = Options::from(Flags::IGNORECASE | Flags::MULTILINE);
+    /// let debug = Debug::with_options_and_encoding(b"artichoke", options, Encoding::new());
+    /// let s = debug.collect::<String>();
+    /// assert_eq!(s, "/artichoke/mi");
+    /// ```
+    pub fn with_options_and_encoding(source: &'a [u8], options: Options, encoding: Encoding) -> Self {
+        Self::new(source, options.as_display_modifier(), encoding.as_modifier_str())
+    }
+
+    /// Write the debug representation into the given [`fmt::Write`].
+    ///
+    /// This method is equivalent to driving the iterator to completion with
+    /// [`write_char`], but does not require collecting into an intermediate
+    /// `String`.
+    ///
+    /// # Errors
+    ///
+    /// If the given writer returns an error, that error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_regexp::Debug;
+    ///
+    /// let debug = Debug::new("crab 🦀 for Rust".as_bytes(), "mix", "");
+    /// let mut s = String::new();
+    /// debug.write_into(&mut s)?;
+    /// assert_eq!(s, "/crab 🦀 for Rust/mix");
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    ///
+    /// [`fmt::Write`]: core::fmt::Write
+    /// [`write_char`]: core::fmt::Write::write_char
+    pub fn write_into<W>(self, dest: &mut W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        for ch in self {
+            dest.write_char(ch)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'a> Iterator for Debug<'a> {
@@ -179,6 +242,20 @@ impl<'a> Iterator for Debug<'a> {
         }
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Pending escape literals, delimiters, options, and encoding each
+        // yield exactly one char per item. `InvalidUtf8ByteSequence` does not
+        // implement `size_hint`, but it holds at most 12 chars so counting a
+        // clone is cheap. Options and encoding modifiers are always ASCII.
+        let fixed = self.delimiters.remaining() + self.literal.clone().count() + self.options.len() + self.encoding.len();
+        // Each byte of the source yields at most 4 chars (a hex escape like
+        // `\xFF`) and each char consumes at least one byte, except that a
+        // multi-byte UTF-8 sequence of up to 4 bytes yields a single char.
+        let lower = fixed + (self.source.len() + 3) / 4;
+        let upper = fixed + self.source.len() * 4;
+        (lower, Some(upper))
+    }
 }
 
 impl<'a> FusedIterator for Debug<'a> {}
@@ -357,4 +434,47 @@ mod tests {
         let s = debug.collect::<String>();
         assert_eq!(s, r"/\xFF\xFE/");
     }
+
+    #[test]
+    fn iter_non_static_options_and_encoding() {
+        let options = String::from("mi");
+        let encoding = String::from("n");
+        let debug = Debug::new(b"Artichoke Ruby", &options, &encoding);
+        let s = debug.collect::<String>();
+        assert_eq!(s, "/Artichoke Ruby/min");
+    }
+
+    #[test]
+    fn size_hint_brackets_item_count() {
+        let cases: &[&[u8]] = &[
+            b"",
+            b"Artichoke Ruby",
+            b"\xFF\xFE",
+            b"a/b",
+            "crab 🦀 for Rust".as_bytes(),
+        ];
+        for &source in cases {
+            let mut debug = Debug::new(source, "mix", "n");
+            loop {
+                let (lower, upper) = debug.size_hint();
+                let count = debug.clone().count();
+                assert!(lower <= count, "lower bound {} exceeds count {}", lower, count);
+                let upper = upper.unwrap();
+                assert!(count <= upper, "count {} exceeds upper bound {}", count, upper);
+                if debug.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn write_into_matches_iterator_output() {
+        let debug = Debug::new(b"crab \xF0\x9F\xA6\x80 / \xFF", "ix", "n");
+        let collected = debug.clone().collect::<String>();
+        let mut written = String::new();
+        debug.write_into(&mut written).unwrap();
+        assert_eq!(written, collected);
+        assert_eq!(written, "/crab 🦀 \\/ \\xFF/ixn");
+    }
 }
@@ -0,0 +1,229 @@
+//! Escape a byte string for use as a literal pattern in a `Regexp`.
+//!
+//! This module implements the escaping routine used by [`Regexp::escape`] and
+//! its alias [`Regexp::quote`].
+//!
+//! [`Regexp::escape`]: https://ruby-doc.org/core-2.6.3/Regexp.html#method-c-escape
+//! [`Regexp::quote`]: https://ruby-doc.org/core-2.6.3/Regexp.html#method-c-quote
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Escape all `Regexp` metacharacters in the given byte string.
+///
+/// The returned byte string may be safely interpolated into a `Regexp`
+/// pattern, where it matches the original byte string literally.
+///
+/// Metacharacters and whitespace are escaped with a backslash. Multibyte UTF-8
+/// sequences are passed through untouched. Bytes which are not valid UTF-8 are
+/// hex-escaped to `\xNN`.
+///
+/// # Examples
+///
+/// ```
+/// # use spinoso_regexp::escape;
+/// // ```ruby
+/// // [2.6.6] > Regexp.escape('artichoke? ruby!')
+/// // => "artichoke\\?\\ ruby!"
+/// // ```
+/// assert_eq!(escape(b"artichoke? ruby!"), &b"artichoke\\?\\ ruby!"[..]);
+///
+/// // ```ruby
+/// // [2.6.6] > Regexp.escape("\n")
+/// // => "\\n"
+/// // ```
+/// assert_eq!(escape(b"\n"), &br"\n"[..]);
+/// assert_eq!(escape(b"\xFF"), &br"\xFF"[..]);
+/// ```
+#[must_use]
+pub fn escape(pattern: &[u8]) -> Vec<u8> {
+    let mut escaped = Vec::with_capacity(pattern.len());
+    let mut remainder = pattern;
+    while !remainder.is_empty() {
+        let (ch, size) = bstr::decode_utf8(remainder);
+        match ch {
+            Some(ch) if is_meta_character(ch) => {
+                escaped.push(b'\\');
+                escaped.extend_from_slice(&remainder[..size]);
+            }
+            Some(ch) => {
+                if let Some(sequence) = non_printable_escape(ch) {
+                    escaped.extend_from_slice(sequence.as_bytes());
+                } else {
+                    escaped.extend_from_slice(&remainder[..size]);
+                }
+            }
+            // Invalid UTF-8 bytes are hex-escaped so the returned pattern
+            // matches them literally regardless of the pattern encoding.
+            None => {
+                for &byte in &remainder[..size] {
+                    escaped.extend_from_slice(br"\x");
+                    escaped.push(HEX_DIGITS[usize::from(byte >> 4)]);
+                    escaped.push(HEX_DIGITS[usize::from(byte & 0xF)]);
+                }
+            }
+        }
+        remainder = &remainder[size..];
+    }
+    escaped
+}
+
+/// Return whether [`escape`] would modify the given byte string.
+///
+/// This check lets callers skip allocating an escaped copy of patterns which
+/// contain no metacharacters.
+///
+/// # Examples
+///
+/// ```
+/// # use spinoso_regexp::requires_escaping;
+/// assert!(!requires_escaping(b"artichoke"));
+/// assert!(requires_escaping(b"artichoke?"));
+/// assert!(requires_escaping(b"\xFF"));
+/// ```
+#[must_use]
+pub fn requires_escaping(pattern: &[u8]) -> bool {
+    let mut remainder = pattern;
+    while !remainder.is_empty() {
+        let (ch, size) = bstr::decode_utf8(remainder);
+        match ch {
+            Some(ch) if is_meta_character(ch) || non_printable_escape(ch).is_some() => return true,
+            Some(_) => {}
+            None => return true,
+        }
+        remainder = &remainder[size..];
+    }
+    false
+}
+
+/// Return true if the given character has significance in a `Regexp` pattern.
+///
+/// Ruby always escapes the ASCII space character in calls to `Regexp::escape`.
+const fn is_meta_character(ch: char) -> bool {
+    matches!(
+        ch,
+        '.' | '*'
+            | '?'
+            | '+'
+            | '['
+            | ']'
+            | '('
+            | ')'
+            | '{'
+            | '}'
+            | '|'
+            | '^'
+            | '$'
+            | '\\'
+            | '/'
+            | '#'
+            | '-'
+            | ' '
+    )
+}
+
+/// Return the escape sequence for a non-printable character which `Regexp`
+/// patterns spell with a backslash escape.
+const fn non_printable_escape(ch: char) -> Option<&'static str> {
+    match ch {
+        '\n' => Some(r"\n"),
+        '\r' => Some(r"\r"),
+        '\t' => Some(r"\t"),
+        // vertical tab aka "\v"
+        '\u{B}' => Some(r"\v"),
+        // form feed aka "\f"
+        '\u{C}' => Some(r"\f"),
+        '\0' => Some(r"\0"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, requires_escaping};
+
+    #[test]
+    fn escape_metacharacters() {
+        // ```ruby
+        // [2.6.6] > Regexp.escape('\*?{}.')
+        // => "\\\\\\*\\?\\{\\}\\."
+        // ```
+        assert_eq!(escape(br"\*?{}."), &br"\\\*\?\{\}\."[..]);
+        assert_eq!(
+            escape(br".*?+[](){}|^$\/#-"),
+            &br"\.\*\?\+\[\]\(\)\{\}\|\^\$\\\/\#\-"[..]
+        );
+    }
+
+    #[test]
+    fn escape_whitespace() {
+        // ```ruby
+        // [2.6.6] > Regexp.escape("\n\t\r\f\v ")
+        // => "\\n\\t\\r\\f\\v\\ "
+        // ```
+        assert_eq!(escape(b"\n"), &br"\n"[..]);
+        assert_eq!(escape(b"\t"), &br"\t"[..]);
+        assert_eq!(escape(b"\r"), &br"\r"[..]);
+        assert_eq!(escape(b"\x0C"), &br"\f"[..]);
+        assert_eq!(escape(b"\x0B"), &br"\v"[..]);
+        assert_eq!(escape(b" "), &br"\ "[..]);
+    }
+
+    #[test]
+    fn escape_nul_byte() {
+        // ```ruby
+        // [2.6.6] > Regexp.escape("\0")
+        // => "\\0"
+        // ```
+        assert_eq!(escape(b"\x00"), &br"\0"[..]);
+        assert_eq!(escape(b"artichoke\x00ruby"), &b"artichoke\\0ruby"[..]);
+    }
+
+    #[test]
+    fn escape_clean_patterns_are_unchanged() {
+        assert_eq!(escape(b"artichoke"), &b"artichoke"[..]);
+        assert_eq!(escape(b"artichoke_ruby_123"), &b"artichoke_ruby_123"[..]);
+        assert_eq!(escape(b"!@%:;\"'"), &b"!@%:;\"'"[..]);
+    }
+
+    #[test]
+    fn escape_multibyte_utf8_is_unchanged() {
+        assert_eq!(escape("aç".as_bytes()), "aç".as_bytes());
+        assert_eq!(escape("crab 🦀".as_bytes()), &b"crab\\ \xF0\x9F\xA6\x80"[..]);
+    }
+
+    #[test]
+    fn escape_invalid_utf8_bytes_are_hex_escaped() {
+        assert_eq!(escape(b"\xFF"), &br"\xFF"[..]);
+        assert_eq!(escape(b"\xFF\xFE"), &br"\xFF\xFE"[..]);
+        assert_eq!(escape(b"abc\xFFxyz"), &br"abc\xFFxyz"[..]);
+    }
+
+    #[test]
+    fn requires_escaping_is_a_faithful_predicate() {
+        assert!(!requires_escaping(b"artichoke"));
+        assert!(!requires_escaping("aç".as_bytes()));
+        assert!(requires_escaping(b"a.b"));
+        assert!(requires_escaping(b"artichoke ruby"));
+        assert!(requires_escaping(b"\n"));
+        assert!(requires_escaping(b"\x00"));
+        assert!(requires_escaping(b"\xFF"));
+    }
+
+    #[test]
+    fn requires_escaping_agrees_with_escape() {
+        let cases: &[&[u8]] = &[
+            b"artichoke",
+            b"a.b",
+            b" ",
+            b"\n",
+            b"\x00",
+            b"\xFF",
+            "aç".as_bytes(),
+            "crab 🦀".as_bytes(),
+            b"!@%:;\"'",
+        ];
+        for &pattern in cases {
+            assert_eq!(requires_escaping(pattern), escape(pattern) != pattern);
+        }
+    }
+}
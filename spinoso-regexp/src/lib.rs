@@ -35,13 +35,19 @@ use bstr::ByteSlice;
 mod debug;
 mod encoding;
 mod error;
+mod escape;
+mod match_data;
+mod named_captures;
 mod options;
 mod regexp;
 
 pub use debug::Debug;
 pub use encoding::{Encoding, InvalidEncodingError};
 pub use error::{ArgumentError, Error, RegexpError, SyntaxError};
-pub use options::{Options, RegexpOption};
+pub use escape::{escape, requires_escaping};
+pub use match_data::{Iter as MatchDataIter, MatchData, SubjectEncoding};
+pub use named_captures::{Iter as NamedCapturesIter, NamedCaptures, Names};
+pub use options::{Options, OptionsArg, RegexpOption};
 
 bitflags::bitflags! {
     #[derive(Default)]
@@ -0,0 +1,419 @@
+//! Engine-agnostic match result data.
+//!
+//! This module provides a representation of the data behind Ruby's
+//! [`MatchData`] core class that does not leak regexp engine types. Regexp
+//! engine backends produce byte offsets into the subject string; this type
+//! stores those offsets and implements the offset and capture access methods
+//! on top of them.
+//!
+//! [`MatchData`]: https://ruby-doc.org/core-2.6.3/MatchData.html
+
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::slice;
+use std::borrow::Cow;
+
+use crate::NamedCaptures;
+
+/// How a subject byte string is interpreted when computing `begin` and `end`
+/// offsets.
+///
+/// Ruby reports [`MatchData#begin`] and [`MatchData#end`] in character units,
+/// which only differ from byte units for UTF-8 subjects.
+///
+/// [`MatchData#begin`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-begin
+/// [`MatchData#end`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-end
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SubjectEncoding {
+    /// The subject is a conventionally UTF-8 byte string and offsets are
+    /// reported in characters.
+    ///
+    /// Characters are counted as in spinoso-string: each valid UTF-8 byte
+    /// sequence is one character and each byte in an invalid UTF-8 byte
+    /// sequence is one character.
+    Utf8,
+    /// The subject is a binary byte string and offsets are reported in bytes.
+    Binary,
+}
+
+impl Default for SubjectEncoding {
+    fn default() -> Self {
+        Self::Utf8
+    }
+}
+
+/// The result of a successful regexp match against a subject byte string.
+///
+/// `MatchData` owns or borrows the subject, the byte range of the overall
+/// match, a byte range for each capture group (or [`None`] for capture groups
+/// which did not participate in the match), and the [`NamedCaptures`] mapping
+/// of the pattern.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_regexp::{MatchData, SubjectEncoding};
+///
+/// // A match of `/b(c)(x)?/` against `"abc"`.
+/// let match_data = MatchData::new(&b"abc"[..], SubjectEncoding::Utf8, 1..3, vec![Some(2..3), None]);
+/// assert_eq!(match_data.get(0), Some(&b"bc"[..]));
+/// assert_eq!(match_data.get(1), Some(&b"c"[..]));
+/// assert_eq!(match_data.get(2), None);
+/// assert_eq!(match_data.pre_match(), b"a");
+/// assert_eq!(match_data.post_match(), b"");
+/// ```
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq)]
+pub struct MatchData<'a> {
+    subject: Cow<'a, [u8]>,
+    encoding: SubjectEncoding,
+    matched: Range<usize>,
+    captures: Vec<Option<Range<usize>>>,
+    named_captures: NamedCaptures,
+}
+
+impl<'a> MatchData<'a> {
+    /// Construct a new `MatchData` from the subject, the byte range of the
+    /// overall match, and the byte ranges of the capture groups in pattern
+    /// order.
+    ///
+    /// Capture groups which did not participate in the match are represented
+    /// by [`None`].
+    #[must_use]
+    pub fn new<T>(subject: T, encoding: SubjectEncoding, matched: Range<usize>, captures: Vec<Option<Range<usize>>>) -> Self
+    where
+        T: Into<Cow<'a, [u8]>>,
+    {
+        Self {
+            subject: subject.into(),
+            encoding,
+            matched,
+            captures,
+            named_captures: NamedCaptures::new(),
+        }
+    }
+
+    /// Attach the [`NamedCaptures`] mapping of the pattern that produced this
+    /// match.
+    #[must_use]
+    pub fn with_named_captures(self, named_captures: NamedCaptures) -> Self {
+        Self { named_captures, ..self }
+    }
+
+    /// The subject byte string this match was made against.
+    #[must_use]
+    pub fn subject(&self) -> &[u8] {
+        &self.subject
+    }
+
+    /// How the subject is interpreted when computing `begin` and `end`
+    /// offsets.
+    #[must_use]
+    pub fn encoding(&self) -> SubjectEncoding {
+        self.encoding
+    }
+
+    /// The [`NamedCaptures`] mapping of the pattern that produced this match.
+    #[must_use]
+    pub fn named_captures(&self) -> &NamedCaptures {
+        &self.named_captures
+    }
+
+    /// Returns the bytes matched by the given group.
+    ///
+    /// Group 0 is the overall match. Returns [`None`] for out of range groups
+    /// and for capture groups which did not participate in the match.
+    ///
+    /// This method can be used to implement [`MatchData#[]`][index] with an
+    /// integer argument.
+    ///
+    /// [index]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-5B-5D
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        let range = self.range(index)?;
+        self.subject.get(range.clone())
+    }
+
+    /// Returns the bytes matched by the given named capture group.
+    ///
+    /// If multiple capture groups share the given name, the last group which
+    /// participated in the match wins, which matches Oniguruma's name to
+    /// backref resolution. Returns [`None`] if the pattern has no capture
+    /// group with this name or if none of the groups with this name
+    /// participated in the match.
+    ///
+    /// This method can be used to implement [`MatchData#[]`][index] with a
+    /// `Symbol` or `String` argument.
+    ///
+    /// [index]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-5B-5D
+    #[must_use]
+    pub fn get_named(&self, name: &[u8]) -> Option<&[u8]> {
+        let indices = self.named_captures.indices_for(name)?;
+        let group = indices.iter().copied().rev().find(|&group| self.range(group).is_some())?;
+        self.get(group)
+    }
+
+    /// Returns the part of the subject before the overall match, like
+    /// [`MatchData#pre_match`].
+    ///
+    /// [`MatchData#pre_match`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-pre_match
+    #[must_use]
+    pub fn pre_match(&self) -> &[u8] {
+        self.subject.get(..self.matched.start).unwrap_or_default()
+    }
+
+    /// Returns the part of the subject after the overall match, like
+    /// [`MatchData#post_match`].
+    ///
+    /// [`MatchData#post_match`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-post_match
+    #[must_use]
+    pub fn post_match(&self) -> &[u8] {
+        self.subject.get(self.matched.end..).unwrap_or_default()
+    }
+
+    /// Returns the offset at which the given group begins, like
+    /// [`MatchData#begin`].
+    ///
+    /// The offset is in character units for [UTF-8 subjects] and in byte
+    /// units for [binary subjects]. Returns [`None`] for out of range groups
+    /// and for capture groups which did not participate in the match.
+    ///
+    /// [`MatchData#begin`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-begin
+    /// [UTF-8 subjects]: SubjectEncoding::Utf8
+    /// [binary subjects]: SubjectEncoding::Binary
+    #[must_use]
+    pub fn begin(&self, group: usize) -> Option<usize> {
+        let range = self.range(group)?;
+        self.offset_in_units(range.start)
+    }
+
+    /// Returns the offset at which the given group ends, like
+    /// [`MatchData#end`].
+    ///
+    /// The offset is in character units for [UTF-8 subjects] and in byte
+    /// units for [binary subjects]. Returns [`None`] for out of range groups
+    /// and for capture groups which did not participate in the match.
+    ///
+    /// [`MatchData#end`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-end
+    /// [UTF-8 subjects]: SubjectEncoding::Utf8
+    /// [binary subjects]: SubjectEncoding::Binary
+    #[must_use]
+    pub fn end(&self, group: usize) -> Option<usize> {
+        let range = self.range(group)?;
+        self.offset_in_units(range.end)
+    }
+
+    /// Returns the capture groups as owned byte strings in pattern order,
+    /// like [`MatchData#captures`].
+    ///
+    /// The overall match is not included. Capture groups which did not
+    /// participate in the match are represented by [`None`].
+    ///
+    /// [`MatchData#captures`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-captures
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<Option<Vec<u8>>> {
+        self.captures
+            .iter()
+            .map(|capture| {
+                let range = capture.as_ref()?;
+                Some(self.subject.get(range.clone())?.to_vec())
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the bytes matched by each group, beginning
+    /// with the overall match, like [`MatchData#to_a`].
+    ///
+    /// Capture groups which did not participate in the match are yielded as
+    /// [`None`].
+    ///
+    /// [`MatchData#to_a`]: https://ruby-doc.org/core-2.6.3/MatchData.html#method-i-to_a
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            subject: &self.subject,
+            matched: Some(&self.matched),
+            captures: self.captures.iter(),
+        }
+    }
+
+    /// Resolve a group index to its byte range in the subject.
+    fn range(&self, group: usize) -> Option<&Range<usize>> {
+        if group == 0 {
+            Some(&self.matched)
+        } else {
+            self.captures.get(group - 1)?.as_ref()
+        }
+    }
+
+    /// Convert a byte offset into the subject to offset units determined by
+    /// the subject encoding.
+    fn offset_in_units(&self, byte_offset: usize) -> Option<usize> {
+        let prefix = self.subject.get(..byte_offset)?;
+        match self.encoding {
+            SubjectEncoding::Utf8 => Some(conventionally_utf8_char_len(prefix)),
+            SubjectEncoding::Binary => Some(byte_offset),
+        }
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'a MatchData<'b> {
+    type Item = Option<&'a [u8]>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the bytes matched by each group, beginning with the
+/// overall match.
+///
+/// This struct is created by the [`iter`] method on [`MatchData`]. See its
+/// documentation for more.
+///
+/// [`iter`]: MatchData::iter
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    subject: &'a [u8],
+    matched: Option<&'a Range<usize>>,
+    captures: slice::Iter<'a, Option<Range<usize>>>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Option<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(matched) = self.matched.take() {
+            return Some(self.subject.get(matched.clone()));
+        }
+        let capture = self.captures.next()?;
+        Some(capture.as_ref().and_then(|range| self.subject.get(range.clone())))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {
+    fn len(&self) -> usize {
+        self.captures.len() + usize::from(self.matched.is_some())
+    }
+}
+
+impl<'a> FusedIterator for Iter<'a> {}
+
+/// Count characters in a conventionally UTF-8 byte string.
+///
+/// This mirrors the character counting in spinoso-string: each valid UTF-8
+/// byte sequence is one character and each byte in an invalid UTF-8 byte
+/// sequence is one character.
+fn conventionally_utf8_char_len(bytes: &[u8]) -> usize {
+    let mut char_len = 0;
+    // Call through the trait so the `bstr` implementation is used on all
+    // toolchains, including ones where `<[u8]>::utf8_chunks` exists in std.
+    for chunk in bstr::ByteSlice::utf8_chunks(bytes) {
+        char_len += chunk.valid().chars().count();
+        char_len += chunk.invalid().len();
+    }
+    char_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatchData, SubjectEncoding};
+    use crate::NamedCaptures;
+
+    // A match of `/l(l)(x)?(o)/` against `"hello"`.
+    fn match_with_unmatched_group() -> MatchData<'static> {
+        MatchData::new(
+            &b"hello"[..],
+            SubjectEncoding::Utf8,
+            2..5,
+            vec![Some(3..4), None, Some(4..5)],
+        )
+    }
+
+    #[test]
+    fn get_resolves_groups_and_rejects_out_of_range_indexes() {
+        let match_data = match_with_unmatched_group();
+        assert_eq!(match_data.get(0), Some(&b"llo"[..]));
+        assert_eq!(match_data.get(1), Some(&b"l"[..]));
+        assert_eq!(match_data.get(3), Some(&b"o"[..]));
+        assert_eq!(match_data.get(4), None);
+    }
+
+    #[test]
+    fn unmatched_optional_groups_are_none() {
+        let match_data = match_with_unmatched_group();
+        assert_eq!(match_data.get(2), None);
+        assert_eq!(match_data.begin(2), None);
+        assert_eq!(match_data.end(2), None);
+        assert_eq!(match_data.to_vec(), [Some(b"l".to_vec()), None, Some(b"o".to_vec())]);
+        let groups = match_data.iter().collect::<Vec<_>>();
+        assert_eq!(groups, [Some(&b"llo"[..]), Some(&b"l"[..]), None, Some(&b"o"[..])]);
+    }
+
+    #[test]
+    fn pre_and_post_match_split_the_subject() {
+        let match_data = match_with_unmatched_group();
+        assert_eq!(match_data.pre_match(), b"he");
+        assert_eq!(match_data.post_match(), b"");
+    }
+
+    #[test]
+    fn utf8_subjects_report_offsets_in_characters() {
+        // A match of `/w\u{f6}rld/` against `"h\u{e9}llo w\u{f6}rld"`; the
+        // subject has multibyte characters before the match, so byte and
+        // character offsets differ.
+        let subject = "héllo wörld".as_bytes();
+        let start = "héllo ".len();
+        let match_data = MatchData::new(subject, SubjectEncoding::Utf8, start..subject.len(), vec![]);
+        assert_eq!(match_data.begin(0), Some(6));
+        assert_eq!(match_data.end(0), Some(11));
+    }
+
+    #[test]
+    fn binary_subjects_report_offsets_in_bytes() {
+        let subject = "héllo wörld".as_bytes();
+        let start = "héllo ".len();
+        let match_data = MatchData::new(subject, SubjectEncoding::Binary, start..subject.len(), vec![]);
+        assert_eq!(match_data.begin(0), Some(7));
+        assert_eq!(match_data.end(0), Some(13));
+    }
+
+    #[test]
+    fn invalid_utf8_bytes_count_as_one_character_each() {
+        // An invalid two-byte sequence before the match counts as two
+        // characters.
+        let subject = b"\xFF\xFEabc";
+        let match_data = MatchData::new(&subject[..], SubjectEncoding::Utf8, 2..5, vec![]);
+        assert_eq!(match_data.begin(0), Some(2));
+        assert_eq!(match_data.end(0), Some(5));
+    }
+
+    #[test]
+    fn duplicate_named_groups_resolve_to_the_last_matched_group() {
+        // A match of `/(?<x>h)(?<y>e)?(?<x>l)?/` against `"he"` where the
+        // second `x` group does not participate in the match.
+        let named_captures = [(b"x".to_vec(), vec![1, 3]), (b"y".to_vec(), vec![2])]
+            .into_iter()
+            .collect::<NamedCaptures>();
+        let match_data = MatchData::new(&b"he"[..], SubjectEncoding::Utf8, 0..2, vec![Some(0..1), Some(1..2), None])
+            .with_named_captures(named_captures.clone());
+        assert_eq!(match_data.get_named(b"x"), Some(&b"h"[..]));
+        assert_eq!(match_data.get_named(b"y"), Some(&b"e"[..]));
+        assert_eq!(match_data.get_named(b"z"), None);
+
+        // When both `x` groups participate in the match, the last one wins.
+        let match_data = MatchData::new(
+            &b"hel"[..],
+            SubjectEncoding::Utf8,
+            0..3,
+            vec![Some(0..1), Some(1..2), Some(2..3)],
+        )
+        .with_named_captures(named_captures);
+        assert_eq!(match_data.get_named(b"x"), Some(&b"l"[..]));
+    }
+}
@@ -0,0 +1,283 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::slice;
+
+use scolapasta_string_escape::format_debug_escape_into;
+
+/// Named capture group metadata for a `Regexp`.
+///
+/// Ruby regexp patterns may contain named capture groups and names may be
+/// duplicated, in which case a name refers to multiple group indices. This
+/// type stores the name to group index mapping in pattern definition order
+/// without leaking engine-specific types, which gives all regexp engine
+/// backends a uniform way to implement [`Regexp#names`],
+/// [`Regexp#named_captures`], and named `MatchData` access.
+///
+/// `NamedCaptures` is constructed by collecting an iterator of
+/// `(Vec<u8>, Vec<usize>)` pairs. Entries with duplicate names are merged into
+/// the earliest entry with that name.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_regexp::NamedCaptures;
+///
+/// // Capture group metadata for `/(?<x>a)(?<y>b)(?<x>c)/`.
+/// let captures = [
+///     (b"x".to_vec(), vec![1]),
+///     (b"y".to_vec(), vec![2]),
+///     (b"x".to_vec(), vec![3]),
+/// ]
+/// .into_iter()
+/// .collect::<NamedCaptures>();
+///
+/// let names = captures.names().collect::<Vec<_>>();
+/// assert_eq!(names, [&b"x"[..], &b"y"[..]]);
+/// assert_eq!(captures.indices_for(b"x"), Some(&[1_usize, 3][..]));
+/// assert_eq!(captures.indices_for(b"z"), None);
+/// ```
+///
+/// [`Regexp#names`]: https://ruby-doc.org/core-3.1.2/Regexp.html#method-i-names
+/// [`Regexp#named_captures`]: https://ruby-doc.org/core-3.1.2/Regexp.html#method-i-named_captures
+#[derive(Default, Clone, Hash, PartialEq, Eq)]
+pub struct NamedCaptures {
+    captures: Vec<(Vec<u8>, Vec<usize>)>,
+}
+
+impl NamedCaptures {
+    /// Construct a new, empty `NamedCaptures`.
+    ///
+    /// Patterns without named capture groups have empty metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_regexp::NamedCaptures;
+    ///
+    /// let captures = NamedCaptures::new();
+    /// assert!(captures.is_empty());
+    /// assert_eq!(captures.names().count(), 0);
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { captures: Vec::new() }
+    }
+
+    /// Add a capture group name and the indices it refers to.
+    ///
+    /// If the name was pushed before, the given indices are merged into the
+    /// existing entry and the name keeps its original position.
+    pub fn push(&mut self, name: Vec<u8>, indices: Vec<usize>) {
+        if let Some((_, entry)) = self.captures.iter_mut().find(|(entry, _)| *entry == name) {
+            entry.extend(indices);
+        } else {
+            self.captures.push((name, indices));
+        }
+    }
+
+    /// Whether the pattern contains any named capture groups.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.captures.is_empty()
+    }
+
+    /// Return the number of distinct capture group names.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.captures.len()
+    }
+
+    /// Return an iterator over the capture group names in pattern definition
+    /// order.
+    ///
+    /// Duplicate names are yielded once, suitable for implementing
+    /// [`Regexp#names`].
+    ///
+    /// [`Regexp#names`]: https://ruby-doc.org/core-3.1.2/Regexp.html#method-i-names
+    #[must_use]
+    pub fn names(&self) -> Names<'_> {
+        Names(self.captures.iter())
+    }
+
+    /// Return the capture group indices referred to by the given name.
+    ///
+    /// Returns [`None`] if the pattern has no capture group with this name.
+    #[must_use]
+    pub fn indices_for(&self, name: &[u8]) -> Option<&[usize]> {
+        let (_, indices) = self.captures.iter().find(|(entry, _)| entry == name)?;
+        Some(indices)
+    }
+
+    /// Return an iterator over `(name, indices)` pairs in pattern definition
+    /// order.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self.captures.iter())
+    }
+}
+
+impl FromIterator<(Vec<u8>, Vec<usize>)> for NamedCaptures {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (Vec<u8>, Vec<usize>)>,
+    {
+        let mut captures = Self::new();
+        for (name, indices) in iter {
+            captures.push(name, indices);
+        }
+        captures
+    }
+}
+
+impl<'a> IntoIterator for &'a NamedCaptures {
+    type Item = (&'a [u8], &'a [usize]);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// `NamedCaptures` has a [`fmt::Debug`] implementation which matches the
+/// `Hash` inspect ordering of `Regexp#named_captures`.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_regexp::NamedCaptures;
+///
+/// // Capture group metadata for `/(?<year>\d+)-(?<month>\d+)/`.
+/// let captures = [(b"year".to_vec(), vec![1]), (b"month".to_vec(), vec![2])]
+///     .into_iter()
+///     .collect::<NamedCaptures>();
+/// assert_eq!(format!("{:?}", captures), r#"{"year"=>[1], "month"=>[2]}"#);
+///
+/// let captures = NamedCaptures::new();
+/// assert_eq!(format!("{:?}", captures), "{}");
+/// ```
+impl fmt::Debug for NamedCaptures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        let mut first = true;
+        for (name, indices) in self {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            f.write_str("\"")?;
+            format_debug_escape_into(&mut *f, name)?;
+            f.write_str("\"=>")?;
+            f.debug_list().entries(indices).finish()?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// An iterator over capture group names in pattern definition order.
+///
+/// Duplicate names are yielded once. This struct is created by the [`names`]
+/// method on [`NamedCaptures`]. See its documentation for more.
+///
+/// [`names`]: NamedCaptures::names
+#[derive(Debug, Clone)]
+pub struct Names<'a>(slice::Iter<'a, (Vec<u8>, Vec<usize>)>);
+
+impl<'a> Iterator for Names<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, _) = self.0.next()?;
+        Some(name)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Names<'a> {}
+
+impl<'a> FusedIterator for Names<'a> {}
+
+/// An iterator over `(name, indices)` pairs in pattern definition order.
+///
+/// This struct is created by the [`iter`] method on [`NamedCaptures`]. See its
+/// documentation for more.
+///
+/// [`iter`]: NamedCaptures::iter
+#[derive(Debug, Clone)]
+pub struct Iter<'a>(slice::Iter<'a, (Vec<u8>, Vec<usize>)>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a [u8], &'a [usize]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (name, indices) = self.0.next()?;
+        Some((name, indices))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> FusedIterator for Iter<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::NamedCaptures;
+
+    #[test]
+    fn empty_pattern_has_no_names() {
+        let captures = NamedCaptures::new();
+        assert!(captures.is_empty());
+        assert_eq!(captures.len(), 0);
+        assert_eq!(captures.names().count(), 0);
+        assert_eq!(captures.iter().count(), 0);
+        assert_eq!(captures.indices_for(b"x"), None);
+        assert_eq!(format!("{:?}", captures), "{}");
+    }
+
+    #[test]
+    fn duplicate_names_are_merged_in_definition_order() {
+        // Capture group metadata for `/(?<x>a)(?<y>b)(?<x>c)/`.
+        let captures = [
+            (b"x".to_vec(), vec![1]),
+            (b"y".to_vec(), vec![2]),
+            (b"x".to_vec(), vec![3]),
+        ]
+        .into_iter()
+        .collect::<NamedCaptures>();
+
+        assert_eq!(captures.len(), 2);
+        let names = captures.names().collect::<Vec<_>>();
+        assert_eq!(names, [&b"x"[..], &b"y"[..]]);
+        assert_eq!(captures.indices_for(b"x"), Some(&[1_usize, 3][..]));
+        assert_eq!(captures.indices_for(b"y"), Some(&[2_usize][..]));
+        assert_eq!(format!("{:?}", captures), r#"{"x"=>[1, 3], "y"=>[2]}"#);
+    }
+
+    #[test]
+    fn iter_yields_names_with_indices() {
+        let captures = [(b"year".to_vec(), vec![1]), (b"month".to_vec(), vec![2])]
+            .into_iter()
+            .collect::<NamedCaptures>();
+
+        let pairs = captures.iter().collect::<Vec<_>>();
+        assert_eq!(pairs, [(&b"year"[..], &[1_usize][..]), (&b"month"[..], &[2_usize][..])]);
+    }
+
+    #[test]
+    fn non_ascii_names_are_preserved_and_escaped_in_debug() {
+        let captures = [("résumé".as_bytes().to_vec(), vec![1])]
+            .into_iter()
+            .collect::<NamedCaptures>();
+
+        let names = captures.names().collect::<Vec<_>>();
+        assert_eq!(names, ["résumé".as_bytes()]);
+        assert_eq!(captures.indices_for("résumé".as_bytes()), Some(&[1_usize][..]));
+        assert_eq!(format!("{:?}", captures), r#"{"résumé"=>[1]}"#);
+    }
+}
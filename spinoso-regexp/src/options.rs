@@ -65,6 +65,26 @@ impl From<RegexpOption> for bool {
     }
 }
 
+/// An options argument to [`Regexp::new`]'s optional second parameter.
+///
+/// `Regexp::new` accepts `nil`, `true`, `false`, an `Integer` of or-ed together
+/// [`Flags`] bits, or a modifier `String` like `"im"` as its options argument.
+/// This enum is a Ruby VM-independent representation of that union for
+/// converters in the engine layer.
+///
+/// [`Regexp::new`]: https://ruby-doc.org/core-2.6.3/Regexp.html#method-c-new
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptionsArg<'a> {
+    /// The options argument was `nil` or not given.
+    Nil,
+    /// The options argument was `true` or `false`.
+    Bool(bool),
+    /// The options argument was an `Integer` of or-ed together [`Flags`] bits.
+    Int(i64),
+    /// The options argument was a modifier `String` like `"im"`.
+    Str(&'a [u8]),
+}
+
 /// Configuration options for Ruby Regexps.
 ///
 /// Options can be supplied either as an `Integer` object to `Regexp::new` or
@@ -184,6 +204,43 @@ impl Options {
         }
     }
 
+    /// Parse an `Options` from the union of argument types accepted by
+    /// `Regexp::new`'s second parameter.
+    ///
+    /// `nil` and `false` produce the default, empty `Options`. `true` produces
+    /// a case-insensitive `Options`. An `Integer` is interpreted as or-ed
+    /// together [`Flags`] bits and fails with [`None`] if it does not fit in
+    /// the flag byte. A `String` is scanned for the `m`, `i`, and `x` modifier
+    /// characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_regexp::{Options, OptionsArg};
+    ///
+    /// assert_eq!(
+    ///     Options::try_from_ruby_value_ish(OptionsArg::Nil),
+    ///     Some(Options::new())
+    /// );
+    /// assert_eq!(
+    ///     Options::try_from_ruby_value_ish(OptionsArg::Bool(true)),
+    ///     Some(Options::with_ignore_case())
+    /// );
+    /// assert_eq!(
+    ///     Options::try_from_ruby_value_ish(OptionsArg::Str(b"im")),
+    ///     Options::try_from_ruby_value_ish(OptionsArg::Int(0b101)),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn try_from_ruby_value_ish(options: OptionsArg<'_>) -> Option<Self> {
+        match options {
+            OptionsArg::Nil | OptionsArg::Bool(false) => Some(Self::new()),
+            OptionsArg::Bool(true) => Some(Self::with_ignore_case()),
+            OptionsArg::Int(bits) => Self::try_from_int(bits),
+            OptionsArg::Str(modifiers) => Some(Self::from(modifiers)),
+        }
+    }
+
     /// Try to parse an `Options` from a full-width `i64`.
     ///
     /// If `options` cannot be converted losslessly to a `u8`, this function
@@ -302,9 +359,65 @@ impl Options {
 
 #[cfg(test)]
 mod tests {
-    use super::{Options, RegexpOption};
+    use super::{Options, OptionsArg, RegexpOption};
     use crate::Flags;
 
+    #[test]
+    fn display_modifier_for_every_flag_combination() {
+        let combinations = [
+            (Flags::empty(), ""),
+            (Flags::IGNORECASE, "i"),
+            (Flags::EXTENDED, "x"),
+            (Flags::MULTILINE, "m"),
+            (Flags::IGNORECASE | Flags::EXTENDED, "ix"),
+            (Flags::IGNORECASE | Flags::MULTILINE, "mi"),
+            (Flags::EXTENDED | Flags::MULTILINE, "mx"),
+            (Flags::IGNORECASE | Flags::EXTENDED | Flags::MULTILINE, "mix"),
+        ];
+        for (flags, modifier) in combinations {
+            let opts = Options::from(flags);
+            assert_eq!(
+                opts.as_display_modifier(),
+                modifier,
+                "unexpected display modifier for flags {:?}",
+                flags
+            );
+        }
+    }
+
+    #[test]
+    fn from_modifier_string() {
+        assert_eq!(Options::from("im"), Options::from(Flags::IGNORECASE | Flags::MULTILINE));
+        assert_eq!(Options::from("mi"), Options::from(Flags::IGNORECASE | Flags::MULTILINE));
+        assert_eq!(Options::from("mix"), Options::from(Flags::ALL_REGEXP_OPTS));
+        assert_eq!(Options::from("xyz"), Options::from(Flags::EXTENDED));
+        assert_eq!(Options::from(""), Options::new());
+        assert_eq!(Options::from(&b"im"[..]), Options::from("im"));
+    }
+
+    #[test]
+    fn try_from_ruby_value_ish() {
+        assert_eq!(Options::try_from_ruby_value_ish(OptionsArg::Nil), Some(Options::new()));
+        assert_eq!(
+            Options::try_from_ruby_value_ish(OptionsArg::Bool(false)),
+            Some(Options::new())
+        );
+        assert_eq!(
+            Options::try_from_ruby_value_ish(OptionsArg::Bool(true)),
+            Some(Options::with_ignore_case())
+        );
+        assert_eq!(
+            Options::try_from_ruby_value_ish(OptionsArg::Int(i64::from(Flags::ALL_REGEXP_OPTS.bits()))),
+            Some(Options::from(Flags::ALL_REGEXP_OPTS))
+        );
+        assert_eq!(Options::try_from_ruby_value_ish(OptionsArg::Int(4096)), None);
+        assert_eq!(Options::try_from_ruby_value_ish(OptionsArg::Int(-1)), None);
+        assert_eq!(
+            Options::try_from_ruby_value_ish(OptionsArg::Str(b"im")),
+            Some(Options::from(Flags::IGNORECASE | Flags::MULTILINE))
+        );
+    }
+
     #[test]
     fn new_is_empty_flags() {
         assert_eq!(Options::new(), Options::from(Flags::empty()));
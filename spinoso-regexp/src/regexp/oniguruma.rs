@@ -1,6 +1,6 @@
 use onig::RegexOptions;
 
-use crate::Options;
+use crate::{NamedCaptures, Options};
 
 impl From<Options> for RegexOptions {
     fn from(opts: Options) -> Self {
@@ -13,3 +13,42 @@ impl From<Options> for RegexOptions {
         bits
     }
 }
+
+impl Options {
+    /// Convert an `Options` to an Oniguruma bit flag set.
+    ///
+    /// Alias for the corresponding `Into<RegexOptions>` implementation.
+    #[must_use]
+    pub fn as_onig_flags(self) -> RegexOptions {
+        self.into()
+    }
+}
+
+impl NamedCaptures {
+    /// Collect a named capture group from an [`onig::Regex::foreach_name`]
+    /// callback.
+    ///
+    /// Oniguruma yields `&str` names and `&[u32]` group indices. This helper
+    /// converts them to the engine-neutral byte name and `usize` index
+    /// representation and always returns `true`, so it can be used as the
+    /// callback body to visit every name:
+    ///
+    /// ```
+    /// use onig::Regex;
+    /// use spinoso_regexp::NamedCaptures;
+    ///
+    /// let regex = Regex::new("(?<x>a)(?<y>b)").unwrap();
+    /// let mut named_captures = NamedCaptures::new();
+    /// regex.foreach_name(|name, group_indices| {
+    ///     named_captures.push_onig_capture_group(name, group_indices)
+    /// });
+    ///
+    /// let names = named_captures.names().collect::<Vec<_>>();
+    /// assert_eq!(names, [&b"x"[..], &b"y"[..]]);
+    /// ```
+    pub fn push_onig_capture_group(&mut self, name: &str, group_indices: &[u32]) -> bool {
+        let indices = group_indices.iter().map(|&index| index as usize).collect();
+        self.push(name.as_bytes().to_vec(), indices);
+        true
+    }
+}
@@ -1 +1,42 @@
+use regex::RegexBuilder;
 
+use crate::Options;
+
+impl Options {
+    /// Apply these `Options` to a [`RegexBuilder`] from the [`regex`] crate.
+    ///
+    /// The [`regex`] crate does not expose a bit flag representation of its
+    /// options, so flags are set on the given builder.
+    pub fn as_regex_flags(self, builder: &mut RegexBuilder) -> &mut RegexBuilder {
+        builder
+            .multi_line(self.multiline().is_enabled())
+            .case_insensitive(self.ignore_case().is_enabled())
+            .ignore_whitespace(self.extended().is_enabled())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use regex::RegexBuilder;
+
+    use crate::{Flags, Options};
+
+    #[test]
+    fn regex_flags_applied_to_builder() {
+        let opts = Options::from(Flags::IGNORECASE | Flags::MULTILINE);
+        let regex = opts
+            .as_regex_flags(&mut RegexBuilder::new("^artichoke$"))
+            .build()
+            .unwrap();
+        assert!(regex.is_match("ARTICHOKE"));
+        assert!(regex.is_match("spinoso\nartichoke\nruby"));
+
+        let opts = Options::new();
+        let regex = opts
+            .as_regex_flags(&mut RegexBuilder::new("^artichoke$"))
+            .build()
+            .unwrap();
+        assert!(!regex.is_match("ARTICHOKE"));
+        assert!(!regex.is_match("spinoso\nartichoke\nruby"));
+    }
+}
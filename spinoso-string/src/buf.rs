@@ -0,0 +1,187 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use bstr::ByteSlice;
+
+/// ASCII-ness of the buffer content has not been computed yet.
+const ASCII_UNKNOWN: u8 = 0;
+/// Every byte in the buffer is in the inclusive range `[0, 127]`.
+const ASCII_ONLY: u8 = 1;
+/// At least one byte in the buffer is outside the inclusive range `[0, 127]`.
+const NOT_ASCII: u8 = 2;
+
+/// The backing buffer of a [`String`].
+///
+/// A buffer is either uniquely owned or shared with other `String`s through
+/// an [`Arc`]. Shared buffers make `clone` cheap for dup-heavy workloads and
+/// are copied on write: dereferencing a shared buffer mutably converts it to
+/// the owned representation first, so mutating a `String` never affects
+/// siblings that share its buffer.
+///
+/// All reads go through [`Deref`], which works on either representation
+/// without copying. All writes go through [`DerefMut`] or [`make_unique`],
+/// which ensure the buffer is uniquely owned.
+///
+/// The buffer also carries a tri-state ASCII-ness cache which is lazily
+/// populated by [`is_ascii_only`] and invalidated by [`DerefMut`], the choke
+/// point all mutable accesses to the byte content funnel through.
+///
+/// [`String`]: crate::String
+/// [`make_unique`]: Self::make_unique
+/// [`is_ascii_only`]: Self::is_ascii_only
+#[derive(Debug)]
+pub(crate) struct Buf {
+    repr: Repr,
+    ascii: AtomicU8,
+}
+
+#[derive(Debug, Clone)]
+enum Repr {
+    /// A uniquely owned buffer.
+    Owned(Vec<u8>),
+    /// A buffer possibly shared with other `String`s.
+    Shared(Arc<Vec<u8>>),
+}
+
+impl Clone for Buf {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            repr: self.repr.clone(),
+            // Clones have identical byte content, so the cached ASCII-ness
+            // carries over.
+            ascii: AtomicU8::new(self.ascii.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl Default for Buf {
+    #[inline]
+    fn default() -> Self {
+        Self::owned(Vec::new())
+    }
+}
+
+impl From<Vec<u8>> for Buf {
+    #[inline]
+    fn from(vec: Vec<u8>) -> Self {
+        Self::owned(vec)
+    }
+}
+
+impl From<Arc<Vec<u8>>> for Buf {
+    #[inline]
+    fn from(vec: Arc<Vec<u8>>) -> Self {
+        Self {
+            repr: Repr::Shared(vec),
+            ascii: AtomicU8::new(ASCII_UNKNOWN),
+        }
+    }
+}
+
+impl Buf {
+    /// Construct a uniquely owned buffer from a `Vec` of byte content.
+    #[inline]
+    pub(crate) const fn owned(vec: Vec<u8>) -> Self {
+        Self {
+            repr: Repr::Owned(vec),
+            ascii: AtomicU8::new(ASCII_UNKNOWN),
+        }
+    }
+
+    /// Returns true if every byte in the buffer is in the inclusive range
+    /// `[0, 127]`.
+    ///
+    /// The result is computed on first call and cached; subsequent calls are
+    /// `O(1)` until the buffer is mutated.
+    #[inline]
+    pub(crate) fn is_ascii_only(&self) -> bool {
+        match self.ascii.load(Ordering::Relaxed) {
+            ASCII_ONLY => true,
+            NOT_ASCII => false,
+            _ => {
+                let is_ascii = self.find_non_ascii_byte().is_none();
+                let state = if is_ascii { ASCII_ONLY } else { NOT_ASCII };
+                self.ascii.store(state, Ordering::Relaxed);
+                is_ascii
+            }
+        }
+    }
+
+    /// Ensure the buffer is uniquely owned and return a mutable reference to
+    /// the underlying `Vec`.
+    ///
+    /// If the buffer is shared and this is the last reference, the allocation
+    /// is reclaimed without copying; otherwise the byte content is copied
+    /// into a new uniquely owned buffer.
+    ///
+    /// This method does not invalidate the ASCII-ness cache; it grants no
+    /// access to the byte content and converting representations preserves
+    /// it.
+    #[inline]
+    pub(crate) fn make_unique(&mut self) -> &mut Vec<u8> {
+        if matches!(self.repr, Repr::Shared(_)) {
+            let repr = mem::replace(&mut self.repr, Repr::Owned(Vec::new()));
+            let vec = match repr {
+                Repr::Shared(arc) => Arc::try_unwrap(arc).unwrap_or_else(|arc| (*arc).clone()),
+                Repr::Owned(vec) => vec,
+            };
+            self.repr = Repr::Owned(vec);
+        }
+        match &mut self.repr {
+            Repr::Owned(vec) => vec,
+            Repr::Shared(_) => unreachable!("shared buffer was converted to owned above"),
+        }
+    }
+
+    /// Consume the buffer and return the underlying `Vec`, copying the byte
+    /// content if the buffer is shared with other `String`s.
+    #[inline]
+    pub(crate) fn into_vec(self) -> Vec<u8> {
+        match self.repr {
+            Repr::Owned(vec) => vec,
+            Repr::Shared(arc) => Arc::try_unwrap(arc).unwrap_or_else(|arc| (*arc).clone()),
+        }
+    }
+}
+
+impl Deref for Buf {
+    type Target = Vec<u8>;
+
+    #[inline]
+    fn deref(&self) -> &Vec<u8> {
+        match &self.repr {
+            Repr::Owned(vec) => vec,
+            Repr::Shared(arc) => arc,
+        }
+    }
+}
+
+impl DerefMut for Buf {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        // Every mutable access to the byte content funnels through this
+        // `deref_mut`, so invalidating the ASCII-ness cache here covers all
+        // `&mut self` APIs on `String`, including `DerefMut`, `IndexMut`,
+        // `as_mut_slice`, `iter_mut`, and `Extend`.
+        *self.ascii.get_mut() = ASCII_UNKNOWN;
+        self.make_unique()
+    }
+}
+
+impl AsRef<[u8]> for Buf {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl AsRef<Vec<u8>> for Buf {
+    #[inline]
+    fn as_ref(&self) -> &Vec<u8> {
+        self
+    }
+}
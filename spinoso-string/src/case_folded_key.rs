@@ -0,0 +1,209 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::str;
+
+use crate::{Encoding, String};
+
+/// A case-insensitive wrapper around [`String`] for use as a hash map key.
+///
+/// `CaseFoldedKey` compares and hashes equal for strings which differ only in
+/// case, which makes it suitable for implementing HTTP-header-style lookup
+/// tables and case-insensitive interning.
+///
+/// # Hash and equality consistency
+///
+/// Hash maps require that equal keys have equal hashes. `CaseFoldedKey`
+/// guarantees this by construction: on conversion from [`String`], the key
+/// computes a canonical case-folded byte string and both [`Hash`] and
+/// [`PartialEq`] are defined in terms of that folded form alone.
+///
+/// The folded form is encoding-aware:
+///
+/// - For [ASCII encoded] and [binary encoded] strings, bytes are folded with
+///   ASCII lowercasing.
+/// - For [conventionally UTF-8] strings with valid UTF-8 contents, characters
+///   are folded by uppercasing and then lowercasing, which agrees with
+///   `focaccia`'s full Unicode case folding on case pairs with expansions
+///   like `"ß"` and `"SS"`.
+/// - For conventionally UTF-8 strings with invalid UTF-8 byte content,
+///   folding falls back to a byte comparison and the bytes are taken
+///   verbatim.
+///
+/// Keys with different original strings but identical folded forms are equal
+/// and occupy a single hash map entry.
+///
+/// # Examples
+///
+/// A map keyed by `CaseFoldedKey` can be probed with a pre-folded `&[u8]`
+/// without allocating:
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use spinoso_string::{CaseFoldedKey, String};
+///
+/// let mut headers = HashMap::new();
+/// headers.insert(CaseFoldedKey::from(String::from("Content-Type")), "text/plain");
+///
+/// assert_eq!(headers.get(b"content-type".as_slice()), Some(&"text/plain"));
+/// assert_eq!(
+///     headers.get(&CaseFoldedKey::from(String::from("CONTENT-TYPE"))),
+///     Some(&"text/plain")
+/// );
+/// ```
+///
+/// [ASCII encoded]: crate::Encoding::Ascii
+/// [binary encoded]: crate::Encoding::Binary
+/// [conventionally UTF-8]: crate::Encoding::Utf8
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "casecmp")))]
+pub struct CaseFoldedKey {
+    string: String,
+    folded: Vec<u8>,
+}
+
+impl CaseFoldedKey {
+    /// Construct a new `CaseFoldedKey`, computing the canonical case-folded
+    /// form of the given string.
+    #[inline]
+    #[must_use]
+    pub fn new(string: String) -> Self {
+        let folded = fold(&string);
+        Self { string, folded }
+    }
+
+    /// The original string this key was constructed from.
+    #[inline]
+    #[must_use]
+    pub fn string(&self) -> &String {
+        &self.string
+    }
+
+    /// The canonical case-folded byte content this key hashes and compares
+    /// with.
+    ///
+    /// Probing a map with a `&[u8]` compares against these bytes, so probe
+    /// keys should be pre-folded — for ASCII content, lowercased.
+    #[inline]
+    #[must_use]
+    pub fn folded_bytes(&self) -> &[u8] {
+        &self.folded
+    }
+
+    /// Consume this key and return the original string.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.string
+    }
+}
+
+impl From<String> for CaseFoldedKey {
+    #[inline]
+    fn from(string: String) -> Self {
+        Self::new(string)
+    }
+}
+
+impl Borrow<[u8]> for CaseFoldedKey {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        &self.folded
+    }
+}
+
+impl PartialEq for CaseFoldedKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.folded == other.folded
+    }
+}
+
+impl Eq for CaseFoldedKey {}
+
+impl Hash for CaseFoldedKey {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Delegate to the slice `Hash` impl so that `Borrow<[u8]>` lookups
+        // hash identically.
+        self.folded.hash(state);
+    }
+}
+
+/// Compute the canonical case-folded byte content of a string.
+fn fold(s: &String) -> Vec<u8> {
+    match s.encoding() {
+        Encoding::Ascii | Encoding::Binary => s.as_slice().to_ascii_lowercase(),
+        Encoding::Utf8 => match str::from_utf8(s.as_slice()) {
+            Ok(s) => {
+                let mut folded = Vec::with_capacity(s.len());
+                // Uppercasing and then lowercasing folds case pairs with
+                // expansions to a common form: `ß` uppercases to `SS`, which
+                // lowercases to `ss`, the same fold as `"SS"`.
+                for ch in s.chars().flat_map(char::to_uppercase).flat_map(char::to_lowercase) {
+                    let mut buf = [0; 4];
+                    folded.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+                }
+                folded
+            }
+            // Invalid UTF-8 byte content falls back to byte comparison.
+            Err(_) => s.as_slice().to_vec(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::{Hash, Hasher};
+
+    use super::CaseFoldedKey;
+    use crate::String;
+
+    #[cfg(feature = "std")]
+    fn hash_of(key: &CaseFoldedKey) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        key.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn eszett_collides_with_double_s_under_full_fold() {
+        let eszett = CaseFoldedKey::from(String::utf8("ß".as_bytes().to_vec()));
+        let double_s = CaseFoldedKey::from(String::utf8(b"SS".to_vec()));
+        assert_eq!(eszett, double_s);
+        assert_eq!(hash_of(&eszett), hash_of(&double_s));
+    }
+
+    #[test]
+    fn eszett_does_not_collide_under_ascii_fold() {
+        let eszett = CaseFoldedKey::from(String::binary("ß".as_bytes().to_vec()));
+        let double_s = CaseFoldedKey::from(String::binary(b"SS".to_vec()));
+        assert_ne!(eszett, double_s);
+
+        let upper = CaseFoldedKey::from(String::binary(b"ABC".to_vec()));
+        let lower = CaseFoldedKey::from(String::binary(b"abc".to_vec()));
+        assert_eq!(upper, lower);
+    }
+
+    #[test]
+    fn invalid_utf8_falls_back_to_byte_comparison() {
+        let left = CaseFoldedKey::from(String::utf8(b"ABC\xFF".to_vec()));
+        let right = CaseFoldedKey::from(String::utf8(b"abc\xFF".to_vec()));
+        // Invalid UTF-8 contents are compared by their verbatim bytes.
+        assert_ne!(left, right);
+        assert_eq!(left.folded_bytes(), b"ABC\xFF");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn byte_slice_probes_match_folded_content() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(CaseFoldedKey::from(String::from("Content-Type")), 1);
+        assert_eq!(map.get(b"content-type".as_slice()), Some(&1));
+        assert_eq!(map.get(b"Content-Type".as_slice()), None);
+    }
+}
@@ -207,3 +207,109 @@ impl<'a> Iterator for ConventionallyUtf8<'a> {
 }
 
 impl<'a> FusedIterator for ConventionallyUtf8<'a> {}
+
+/// An iterator over the chars of a [`String`] and their indices.
+///
+/// This struct is created by the [`char_indices`] method on a Spinoso
+/// [`String`]. See its documentation for more.
+///
+/// This iterator yields `(usize, usize, &[u8])` tuples of the char index, the
+/// byte offset, and the byte slice of each char. Chars are segmented like
+/// [`Chars`]: for [conventionally UTF-8] strings, a char is either a valid
+/// UTF-8 byte sequence or a single invalid UTF-8 byte; for ASCII and binary
+/// strings, every byte is a char and the byte offset equals the char index.
+///
+/// [`char_indices`]: crate::String::char_indices
+/// [conventionally UTF-8]: crate::Encoding::Utf8
+#[derive(Debug, Clone)]
+pub struct CharIndices<'a> {
+    bytes: &'a [u8],
+    encoding: Encoding,
+    front_char_index: usize,
+    front_byte_offset: usize,
+    remaining_chars: usize,
+}
+
+impl<'a> Default for CharIndices<'a> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            bytes: &[],
+            encoding: Encoding::Utf8,
+            front_char_index: 0,
+            front_byte_offset: 0,
+            remaining_chars: 0,
+        }
+    }
+}
+
+impl<'a> From<&'a String> for CharIndices<'a> {
+    #[inline]
+    fn from(s: &'a String) -> Self {
+        Self {
+            bytes: s.as_slice(),
+            encoding: s.encoding(),
+            front_char_index: 0,
+            front_byte_offset: 0,
+            remaining_chars: s.char_len(),
+        }
+    }
+}
+
+impl<'a> Iterator for CharIndices<'a> {
+    type Item = (usize, usize, &'a [u8]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_chars == 0 {
+            return None;
+        }
+        let size = match self.encoding {
+            Encoding::Ascii | Encoding::Binary => 1,
+            Encoding::Utf8 => match bstr::decode_utf8(self.bytes) {
+                (Some(_), size) => size,
+                // Invalid UTF-8 bytes are yielded one byte at a time.
+                (None, _) => 1,
+            },
+        };
+        let (ch, remainder) = self.bytes.split_at(size);
+        let item = (self.front_char_index, self.front_byte_offset, ch);
+        self.bytes = remainder;
+        self.front_char_index += 1;
+        self.front_byte_offset += size;
+        self.remaining_chars -= 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining_chars, Some(self.remaining_chars))
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining_chars == 0 {
+            return None;
+        }
+        let size = match self.encoding {
+            Encoding::Ascii | Encoding::Binary => 1,
+            Encoding::Utf8 => match bstr::decode_last_utf8(self.bytes) {
+                (Some(_), size) => size,
+                // Invalid UTF-8 bytes are yielded one byte at a time.
+                (None, _) => 1,
+            },
+        };
+        let (remainder, ch) = self.bytes.split_at(self.bytes.len() - size);
+        let char_index = self.front_char_index + self.remaining_chars - 1;
+        let byte_offset = self.front_byte_offset + remainder.len();
+        self.bytes = remainder;
+        self.remaining_chars -= 1;
+        Some((char_index, byte_offset, ch))
+    }
+}
+
+impl<'a> ExactSizeIterator for CharIndices<'a> {}
+
+impl<'a> FusedIterator for CharIndices<'a> {}
@@ -0,0 +1,435 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use scolapasta_string_escape::{is_ascii_char_with_escape, InvalidUtf8ByteSequence, Literal};
+
+use crate::encoding::Encoding;
+
+/// An iterator that yields an ASCII-safe, round-trippable representation of a
+/// `String` and its byte contents as a sequence of `char`s.
+///
+/// This struct is created by the [`dump`] method on [`String`]. See its
+/// documentation for more.
+///
+/// Unlike [`Inspect`], all non-ASCII characters are escaped: valid UTF-8
+/// multibyte characters are emitted as `\uXXXX` or `\u{XXXXX}` escapes and all
+/// other non-ASCII bytes are emitted as `\xNN` hex escapes. Evaluating the
+/// yielded contents as a Ruby `String` literal produces a byte-for-byte copy
+/// of the source string.
+///
+/// # Examples
+///
+/// To dump a well-formed UTF-8 byte string:
+///
+/// ```
+/// # extern crate alloc;
+/// use alloc::string::String;
+/// # use spinoso_string::Dump;
+/// let dump = Dump::from("spinoso");
+/// assert_eq!(dump.collect::<String>(), "\"spinoso\"");
+///
+/// let dump = Dump::from("Ω☃");
+/// assert_eq!(dump.collect::<String>(), r#""\u03A9\u2603""#);
+/// ```
+///
+/// To dump a byte string with invalid UTF-8 bytes:
+///
+/// ```
+/// # extern crate alloc;
+/// use alloc::string::String;
+/// # use spinoso_string::Dump;
+/// let dump = Dump::from(&b"invalid-\xFF-utf8"[..]);
+/// assert_eq!(dump.collect::<String>(), r#""invalid-\xFF-utf8""#);
+/// ```
+///
+/// [`dump`]: crate::String::dump
+/// [`String`]: crate::String
+/// [`Inspect`]: crate::Inspect
+#[derive(Default, Debug, Clone)]
+#[must_use = "this `Dump` is an `Iterator`, which should be consumed if constructed"]
+pub struct Dump<'a>(State<'a>);
+
+impl<'a> From<&'a str> for Dump<'a> {
+    #[inline]
+    fn from(value: &'a str) -> Self {
+        Self::from(value.as_bytes())
+    }
+}
+
+impl<'a> From<&'a [u8]> for Dump<'a> {
+    #[inline]
+    fn from(value: &'a [u8]) -> Self {
+        Self(State::new(value))
+    }
+}
+
+impl<'a> Iterator for Dump<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a> FusedIterator for Dump<'a> {}
+
+impl<'a> Dump<'a> {
+    /// Construct a `Dump` for a byte string with the given encoding.
+    ///
+    /// [`Utf8`] strings emit valid UTF-8 multibyte characters as `\uXXXX` or
+    /// `\u{XXXXX}` escapes. [`Ascii`] and [`Binary`] strings hex-escape all
+    /// non-ASCII bytes, like `"\xF0\x9F\x92\x8E"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// use alloc::string::String;
+    /// use spinoso_string::{Dump, Encoding};
+    /// let dump = Dump::with_encoding("💎".as_bytes(), Encoding::Utf8);
+    /// assert_eq!(dump.collect::<String>(), r#""\u{1F48E}""#);
+    ///
+    /// let dump = Dump::with_encoding("💎".as_bytes(), Encoding::Binary);
+    /// assert_eq!(dump.collect::<String>(), r#""\xF0\x9F\x92\x8E""#);
+    /// ```
+    ///
+    /// [`Utf8`]: Encoding::Utf8
+    /// [`Ascii`]: Encoding::Ascii
+    /// [`Binary`]: Encoding::Binary
+    #[inline]
+    pub fn with_encoding(bytes: &'a [u8], encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Utf8 => Self(State::new(bytes)),
+            Encoding::Ascii | Encoding::Binary => Self(State::binary(bytes)),
+        }
+    }
+
+    /// Write a `Dump` iterator into the given destination using the dump
+    /// representation of the byte buffer associated with a source `String`.
+    ///
+    /// This formatter writes content like `"spinoso"` and `"Ω"`. To see
+    /// example output of the underlying iterator, see the `Dump`
+    /// documentation.
+    ///
+    /// # Errors
+    ///
+    /// If the given writer returns an error as it is being written to, that
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::fmt::Write;
+    /// # use spinoso_string::Dump;
+    /// let mut buf = String::new();
+    /// let iter = Dump::from("Ω☃");
+    /// iter.format_into(&mut buf);
+    /// assert_eq!(buf, r#""\u03A9\u2603""#);
+    /// ```
+    #[inline]
+    pub fn format_into<W>(self, mut dest: W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        for ch in self {
+            dest.write_char(ch)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Flags {
+    bits: u8,
+}
+
+impl Flags {
+    // Bit flags
+    const EMIT_LEADING_QUOTE: Self = Self { bits: 0b0000_0001 };
+    const EMIT_TRAILING_QUOTE: Self = Self { bits: 0b0000_0010 };
+    const ESCAPE_NON_ASCII: Self = Self { bits: 0b0000_0100 };
+
+    // Initial states
+    const DEFAULT: Self = Self {
+        bits: Self::EMIT_LEADING_QUOTE.bits | Self::EMIT_TRAILING_QUOTE.bits,
+    };
+    const BINARY: Self = Self {
+        bits: Self::DEFAULT.bits | Self::ESCAPE_NON_ASCII.bits,
+    };
+
+    #[inline]
+    fn emit_leading_quote(&mut self) -> Option<char> {
+        if (self.bits & Self::EMIT_LEADING_QUOTE.bits) == Self::EMIT_LEADING_QUOTE.bits {
+            self.bits &= !Self::EMIT_LEADING_QUOTE.bits;
+            Some('"')
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn emit_trailing_quote(&mut self) -> Option<char> {
+        if (self.bits & Self::EMIT_TRAILING_QUOTE.bits) == Self::EMIT_TRAILING_QUOTE.bits {
+            self.bits &= !Self::EMIT_TRAILING_QUOTE.bits;
+            Some('"')
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    const fn escape_non_ascii(self) -> bool {
+        (self.bits & Self::ESCAPE_NON_ASCII.bits) == Self::ESCAPE_NON_ASCII.bits
+    }
+}
+
+/// An iterator over the `\uXXXX` or `\u{XXXXX}` escape of a single character.
+///
+/// Codepoints in the Basic Multilingual Plane use the four digit `\uXXXX`
+/// form; codepoints above `U+FFFF` use the braced `\u{XXXXX}` form, matching
+/// MRI's `String#dump` output.
+#[derive(Default, Debug, Clone)]
+struct UnicodeEscape {
+    // `\u{10FFFF}` is the longest escape sequence at ten bytes.
+    buf: [u8; 10],
+    pos: usize,
+    len: usize,
+}
+
+impl From<char> for UnicodeEscape {
+    fn from(ch: char) -> Self {
+        const UPPER_HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+        let code = ch as u32;
+        let mut buf = [0_u8; 10];
+        buf[0] = b'\\';
+        buf[1] = b'u';
+        let mut len = 2;
+        if code <= 0xFFFF {
+            for &shift in &[12_u32, 8, 4, 0] {
+                buf[len] = UPPER_HEX[((code >> shift) & 0xF) as usize];
+                len += 1;
+            }
+        } else {
+            buf[len] = b'{';
+            len += 1;
+            // Emit only the significant nibbles, most significant first.
+            let mut shift = 4 * ((32 - code.leading_zeros() + 3) / 4);
+            while shift > 0 {
+                shift -= 4;
+                buf[len] = UPPER_HEX[((code >> shift) & 0xF) as usize];
+                len += 1;
+            }
+            buf[len] = b'}';
+            len += 1;
+        }
+        Self { buf, pos: 0, len }
+    }
+}
+
+impl UnicodeEscape {
+    fn next(&mut self) -> Option<char> {
+        if self.pos >= self.len {
+            return None;
+        }
+        let byte = self.buf[self.pos];
+        self.pos += 1;
+        Some(char::from(byte))
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+#[must_use = "this `State` is an `Iterator`, which should be consumed if constructed"]
+struct State<'a> {
+    flags: Flags,
+    literal: Literal,
+    byte_literal: InvalidUtf8ByteSequence,
+    escape: UnicodeEscape,
+    // `true` if a `\#` escape has been emitted and the `#` is still pending.
+    pending_hash: bool,
+    bytes: &'a [u8],
+}
+
+impl Default for Flags {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl<'a> State<'a> {
+    /// Construct a `State` for the given byte slice.
+    ///
+    /// This constructor produces dump contents like `"fred"`.
+    #[inline]
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            flags: Flags::DEFAULT,
+            literal: Literal::empty(),
+            byte_literal: InvalidUtf8ByteSequence::new(),
+            escape: UnicodeEscape::default(),
+            pending_hash: false,
+            bytes,
+        }
+    }
+
+    /// Construct a `State` for the given byte slice which hex-escapes all
+    /// non-ASCII bytes.
+    ///
+    /// This constructor produces dump contents like `"\xF0\x9F\x92\x8E"`.
+    #[inline]
+    fn binary(bytes: &'a [u8]) -> Self {
+        Self {
+            flags: Flags::BINARY,
+            ..Self::new(bytes)
+        }
+    }
+}
+
+impl<'a> Iterator for State<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ch) = self.flags.emit_leading_quote() {
+            return Some(ch);
+        }
+        if self.pending_hash {
+            self.pending_hash = false;
+            return Some('#');
+        }
+        if let Some(ch) = self.literal.next() {
+            return Some(ch);
+        }
+        if let Some(ch) = self.byte_literal.next() {
+            return Some(ch);
+        }
+        if let Some(ch) = self.escape.next() {
+            return Some(ch);
+        }
+        let (ch, size) = if self.flags.escape_non_ascii() {
+            // Binary strings are escaped byte-by-byte: multibyte UTF-8
+            // sequences are never passed through raw.
+            match self.bytes.first() {
+                Some(&byte) if byte.is_ascii() => (Some(char::from(byte)), 1),
+                Some(_) => (None, 1),
+                None => (None, 0),
+            }
+        } else {
+            bstr::decode_utf8(self.bytes)
+        };
+        match ch {
+            // A `#` only requires escaping when it would otherwise begin an
+            // interpolation sequence -- `#{`, `#$`, or `#@`.
+            Some('#') if matches!(self.bytes.get(1), Some(b'{' | b'$' | b'@')) => {
+                self.bytes = &self.bytes[1..];
+                self.pending_hash = true;
+                return Some('\\');
+            }
+            Some(ch) if is_ascii_char_with_escape(ch) => {
+                let (&ascii_byte, remainder) = self.bytes.split_first()?;
+                self.literal = Literal::from(ascii_byte);
+                self.bytes = remainder;
+                return self.literal.next();
+            }
+            Some(ch) if ch.is_ascii() => {
+                self.bytes = &self.bytes[size..];
+                return Some(ch);
+            }
+            Some(ch) => {
+                self.bytes = &self.bytes[size..];
+                self.escape = UnicodeEscape::from(ch);
+                return self.escape.next();
+            }
+            None if size == 0 => {}
+            None => {
+                let (invalid_utf8_bytes, remainder) = self.bytes.split_at(size);
+                // This conversion is safe to unwrap due to the documented
+                // behavior of `bstr::decode_utf8` and `InvalidUtf8ByteSequence`
+                // which indicate that `size` is always in the range of 0..=3.
+                self.byte_literal = InvalidUtf8ByteSequence::try_from(invalid_utf8_bytes).unwrap();
+                self.bytes = remainder;
+                return self.byte_literal.next();
+            }
+        };
+        if let Some(ch) = self.flags.emit_trailing_quote() {
+            return Some(ch);
+        }
+        None
+    }
+}
+
+impl<'a> FusedIterator for State<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::Dump;
+    use crate::Encoding;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Dump::from("").collect::<String>(), r#""""#);
+    }
+
+    #[test]
+    fn ascii_is_not_escaped() {
+        assert_eq!(Dump::from("fred").collect::<String>(), "\"fred\"");
+    }
+
+    #[test]
+    fn ascii_control_and_special_chars_are_escaped() {
+        assert_eq!(Dump::from("\0").collect::<String>(), r#""\x00""#);
+        assert_eq!(Dump::from("\n").collect::<String>(), r#""\n""#);
+        assert_eq!(Dump::from("\x1B").collect::<String>(), r#""\e""#);
+        assert_eq!(Dump::from("\"").collect::<String>(), r#""\"""#);
+        assert_eq!(Dump::from("\\").collect::<String>(), r#""\\""#);
+    }
+
+    #[test]
+    fn utf8_multibyte_chars_are_unicode_escaped() {
+        assert_eq!(Dump::from("Ω☃").collect::<String>(), r#""\u03A9\u2603""#);
+        assert_eq!(Dump::from("é").collect::<String>(), r#""\u00E9""#);
+        assert_eq!(Dump::from("💎").collect::<String>(), r#""\u{1F48E}""#);
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L147-L157
+        assert_eq!(
+            Dump::from("⅛⅜⅝⅞").collect::<String>(),
+            r#""\u215B\u215C\u215D\u215E""#
+        );
+    }
+
+    #[test]
+    fn vmware_super_string_matches_mri_output() {
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L202-L224
+        let dump = Dump::from("表ポあA鷗ŒéＢ逍Üßªąñ丂㐀𠀀").collect::<String>();
+        assert_eq!(
+            dump,
+            "\"\\u8868\\u30DD\\u3042A\\u9DD7\\u0152\\u00E9\\uFF22\\u900D\\u00DC\\u00DF\\u00AA\\u0105\\u00F1\\u4E02\\u3400\\u{20000}\""
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_bytes_are_hex_escaped() {
+        assert_eq!(Dump::from(&b"\xFF"[..]).collect::<String>(), r#""\xFF""#);
+        assert_eq!(
+            Dump::from(&b"\xF0\x9F\xA6\x80abc\xFF"[..]).collect::<String>(),
+            r#""\u{1F980}abc\xFF""#
+        );
+    }
+
+    #[test]
+    fn binary_encoding_hex_escapes_all_non_ascii_bytes() {
+        let dump = Dump::with_encoding("💎".as_bytes(), Encoding::Binary);
+        assert_eq!(dump.collect::<String>(), r#""\xF0\x9F\x92\x8E""#);
+
+        let dump = Dump::with_encoding("abc".as_bytes(), Encoding::Binary);
+        assert_eq!(dump.collect::<String>(), "\"abc\"");
+    }
+
+    #[test]
+    fn interpolation_hash_is_escaped() {
+        assert_eq!(Dump::from("#{foo}").collect::<String>(), r#""\#{foo}""#);
+        assert_eq!(Dump::from("#$foo").collect::<String>(), r#""\#$foo""#);
+        assert_eq!(Dump::from("#@foo").collect::<String>(), r#""\#@foo""#);
+        assert_eq!(Dump::from("#foo#").collect::<String>(), "\"#foo#\"");
+    }
+}
@@ -0,0 +1,840 @@
+//! Byte-oriented `Kernel#sprintf`-style formatting.
+//!
+//! This module implements the format directive mini-language used by
+//! [`Kernel#sprintf`] and [`String#%`] on byte strings. Templates and
+//! formatted results are byte strings, so format strings and `%s` arguments
+//! that contain invalid UTF-8 byte sequences round trip without loss.
+//!
+//! The supported directives are `%d`, `%i`, `%u`, `%f`, `%e`, `%E`, `%g`,
+//! `%G`, `%s`, `%x`, `%X`, `%o`, `%b`, `%c`, and `%%`, with the `-`, `0`, `+`,
+//! space, and `#` flags, width, precision, and `%<name>s` and `%{name}` style
+//! named references.
+//!
+//! [`Kernel#sprintf`]: https://ruby-doc.org/core-2.6.3/Kernel.html#method-i-sprintf
+//! [`String#%`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-25
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::Write as _;
+
+/// An argument to [`format`].
+///
+/// Named arguments are referenced by `%<name>s` and `%{name}` directives and
+/// are skipped when positional directives consume arguments in order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatArg<'a> {
+    /// A signed integer argument, formatted by `%d`, `%i`, `%u`, `%b`, `%o`,
+    /// `%x`, and `%X` directives.
+    Int(i64),
+    /// A float argument, formatted by `%f`, `%e`, `%E`, `%g`, and `%G`
+    /// directives.
+    Float(f64),
+    /// A byte string argument, formatted by `%s` directives.
+    Bytes(&'a [u8]),
+    /// A character argument, formatted by `%c` directives.
+    Char(char),
+    /// A named argument, referenced by `%<name>s` and `%{name}` directives.
+    Named {
+        /// The name referenced by the directive.
+        name: &'a [u8],
+        /// The value formatted by the directive.
+        value: &'a FormatArg<'a>,
+    },
+}
+
+/// Error type for [`format`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FormatError {
+    /// The template contains a `%` directive that could not be parsed or
+    /// names an unsupported conversion.
+    MalformedFormatString,
+    /// The template contains more positional directives than there are
+    /// arguments.
+    TooFewArguments,
+    /// A directive was applied to an argument of an incompatible type, for
+    /// example `%d` applied to a byte string.
+    BadArgumentType,
+    /// A `%<name>s` or `%{name}` directive references a name that does not
+    /// appear in the arguments.
+    KeyNotFound(Vec<u8>),
+}
+
+impl FormatError {
+    /// The exception type this error maps to: `KeyError` for missing named
+    /// references and `ArgumentError` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::FormatError;
+    ///
+    /// assert_eq!(FormatError::TooFewArguments.exception_type(), "ArgumentError");
+    /// assert_eq!(
+    ///     FormatError::KeyNotFound(b"name".to_vec()).exception_type(),
+    ///     "KeyError"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn exception_type(&self) -> &'static str {
+        match self {
+            Self::MalformedFormatString | Self::TooFewArguments | Self::BadArgumentType => "ArgumentError",
+            Self::KeyNotFound(_) => "KeyError",
+        }
+    }
+
+    /// Retrieve the exception message associated with this error.
+    ///
+    /// For [`KeyNotFound`] errors, the missing name can be retrieved with
+    /// [`key`] to build a message like `` key<name> not found ``.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::FormatError;
+    ///
+    /// assert_eq!(FormatError::TooFewArguments.message(), "too few arguments");
+    /// ```
+    ///
+    /// [`KeyNotFound`]: Self::KeyNotFound
+    /// [`key`]: Self::key
+    #[inline]
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::MalformedFormatString => "malformed format string",
+            Self::TooFewArguments => "too few arguments",
+            Self::BadArgumentType => "invalid argument type",
+            Self::KeyNotFound(_) => "key not found",
+        }
+    }
+
+    /// The name referenced by the directive for [`KeyNotFound`] errors.
+    ///
+    /// [`KeyNotFound`]: Self::KeyNotFound
+    #[inline]
+    #[must_use]
+    pub fn key(&self) -> Option<&[u8]> {
+        match self {
+            Self::KeyNotFound(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct Flags {
+    left_justify: bool,
+    zero_pad: bool,
+    plus: bool,
+    space: bool,
+    alternate: bool,
+}
+
+impl Flags {
+    /// The sign prepended to a non-negative number under these flags.
+    const fn sign(self) -> &'static str {
+        if self.plus {
+            "+"
+        } else if self.space {
+            " "
+        } else {
+            ""
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+struct Spec {
+    flags: Flags,
+    width: Option<usize>,
+    precision: Option<usize>,
+}
+
+/// Format a byte string template with the given arguments.
+///
+/// This function can be used to implement the Ruby methods
+/// [`Kernel#sprintf`] and [`String#%`].
+///
+/// # Errors
+///
+/// If the template contains a malformed or unsupported directive, if a
+/// directive is applied to an argument of an incompatible type, if there are
+/// more positional directives than arguments, or if a named reference does
+/// not appear in the arguments, an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_string::{format, FormatArg};
+///
+/// # fn example() -> Result<(), spinoso_string::FormatError> {
+/// let formatted = format(b"%05d: %s", &[FormatArg::Int(123), FormatArg::Bytes(b"printer")])?;
+/// assert_eq!(formatted, b"00123: printer");
+///
+/// let args = [FormatArg::Named {
+///     name: b"id",
+///     value: &FormatArg::Int(42),
+/// }];
+/// let formatted = format(b"%<id>#x", &args)?;
+/// assert_eq!(formatted, b"0x2a");
+/// # Ok(())
+/// # }
+/// # example().unwrap();
+/// ```
+pub fn format(template: &[u8], args: &[FormatArg<'_>]) -> Result<Vec<u8>, FormatError> {
+    let mut out = Vec::with_capacity(template.len());
+    let mut template = template;
+    let mut next_arg = 0;
+    while let Some(start) = template.iter().position(|&byte| byte == b'%') {
+        out.extend_from_slice(&template[..start]);
+        template = &template[start + 1..];
+        match template.first() {
+            None => return Err(FormatError::MalformedFormatString),
+            Some(b'%') => {
+                out.push(b'%');
+                template = &template[1..];
+                continue;
+            }
+            // `%{name}` substitutes the named argument with no flags, width,
+            // or precision, like `%<name>s`.
+            Some(b'{') => {
+                let end = template
+                    .iter()
+                    .position(|&byte| byte == b'}')
+                    .ok_or(FormatError::MalformedFormatString)?;
+                let name = &template[1..end];
+                let arg = named_arg(args, name)?;
+                format_directive(&mut out, b's', arg, Spec::default())?;
+                template = &template[end + 1..];
+                continue;
+            }
+            Some(_) => {}
+        }
+
+        // `%<name>` selects a named argument instead of the next positional
+        // one. Flags, width, and precision follow the reference.
+        let named = if template.first() == Some(&b'<') {
+            let end = template
+                .iter()
+                .position(|&byte| byte == b'>')
+                .ok_or(FormatError::MalformedFormatString)?;
+            let name = &template[1..end];
+            template = &template[end + 1..];
+            Some(named_arg(args, name)?)
+        } else {
+            None
+        };
+
+        let mut spec = Spec::default();
+        loop {
+            match template.first() {
+                Some(b'-') => spec.flags.left_justify = true,
+                Some(b'0') => spec.flags.zero_pad = true,
+                Some(b'+') => spec.flags.plus = true,
+                Some(b' ') => spec.flags.space = true,
+                Some(b'#') => spec.flags.alternate = true,
+                _ => break,
+            }
+            template = &template[1..];
+        }
+        if matches!(template.first(), Some(byte) if byte.is_ascii_digit()) {
+            let (width, remainder) = parse_number(template)?;
+            spec.width = Some(width);
+            template = remainder;
+        }
+        if template.first() == Some(&b'.') {
+            let (precision, remainder) = parse_number(&template[1..])?;
+            spec.precision = Some(precision);
+            template = remainder;
+        }
+
+        let directive = *template.first().ok_or(FormatError::MalformedFormatString)?;
+        template = &template[1..];
+        let arg = if let Some(arg) = named {
+            arg
+        } else {
+            let arg = args.get(next_arg).ok_or(FormatError::TooFewArguments)?;
+            next_arg += 1;
+            arg
+        };
+        format_directive(&mut out, directive, arg, spec)?;
+    }
+    out.extend_from_slice(template);
+    Ok(out)
+}
+
+/// Look up a named argument, resolving the reference to its value.
+fn named_arg<'a>(args: &'a [FormatArg<'a>], name: &[u8]) -> Result<&'a FormatArg<'a>, FormatError> {
+    args.iter()
+        .find_map(|arg| match arg {
+            FormatArg::Named { name: arg_name, value } if *arg_name == name => Some(*value),
+            _ => None,
+        })
+        .ok_or_else(|| FormatError::KeyNotFound(name.to_vec()))
+}
+
+/// Parse a decimal width or precision. An absent precision after `.` is zero.
+fn parse_number(template: &[u8]) -> Result<(usize, &[u8]), FormatError> {
+    let end = template
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(template.len());
+    let mut number = 0_usize;
+    for &byte in &template[..end] {
+        number = number
+            .checked_mul(10)
+            .and_then(|number| number.checked_add(usize::from(byte - b'0')))
+            .ok_or(FormatError::MalformedFormatString)?;
+    }
+    Ok((number, &template[end..]))
+}
+
+fn format_directive(out: &mut Vec<u8>, directive: u8, arg: &FormatArg<'_>, spec: Spec) -> Result<(), FormatError> {
+    match directive {
+        b'd' | b'i' | b'u' => {
+            let value = int_arg(arg)?;
+            let digits = format!("{}", value.unsigned_abs());
+            let sign = if value < 0 { "-" } else { spec.flags.sign() };
+            push_number(out, sign, "", &digits, spec);
+        }
+        b'b' | b'o' | b'x' | b'X' => {
+            let value = int_arg(arg)?;
+            let radix_digit = match directive {
+                b'b' => b'1',
+                b'o' => b'7',
+                _ => if directive == b'X' { b'F' } else { b'f' },
+            };
+            let prefix = if spec.flags.alternate && value != 0 {
+                match directive {
+                    b'b' => "0b",
+                    b'o' => "0",
+                    b'x' => "0x",
+                    _ => "0X",
+                }
+            } else {
+                ""
+            };
+            if value >= 0 || !spec.flags.sign().is_empty() {
+                let magnitude = value.unsigned_abs();
+                let digits = match directive {
+                    b'b' => format!("{magnitude:b}"),
+                    b'o' => format!("{magnitude:o}"),
+                    b'x' => format!("{magnitude:x}"),
+                    _ => format!("{magnitude:X}"),
+                };
+                let sign = if value < 0 { "-" } else { spec.flags.sign() };
+                push_number(out, sign, prefix, &digits, spec);
+            } else {
+                // MRI displays negative numbers in unsigned radix conversions
+                // in two's complement notation with a `..` prefix standing for
+                // an infinite run of leading ones:
+                //
+                // ```
+                // [3.0.1] > sprintf("%b", -5)
+                // => "..1011"
+                // [3.0.1] > sprintf("%x", -1)
+                // => "..f"
+                // ```
+                #[allow(clippy::cast_sign_loss)]
+                let bits = value as u64;
+                let mut digits = match directive {
+                    b'b' => format!("{bits:b}"),
+                    b'o' => format!("{bits:o}"),
+                    b'x' => format!("{bits:x}"),
+                    _ => format!("{bits:X}"),
+                };
+                // Sign extension to 64 bits produces a run of leading
+                // `radix - 1` digits; collapse it to a single digit.
+                let repeated = digits.len() - digits.trim_start_matches(char::from(radix_digit)).len();
+                digits.replace_range(..repeated.saturating_sub(1), "");
+                if spec.flags.zero_pad {
+                    // With zero padding, the `..` is dropped and the field is
+                    // filled with the repeated digit instead of zeros.
+                    let width = spec.width.unwrap_or(0).max(spec.precision.unwrap_or(0));
+                    while digits.len() < width.saturating_sub(prefix.len()) {
+                        digits.insert(0, char::from(radix_digit));
+                    }
+                    out.extend_from_slice(prefix.as_bytes());
+                    out.extend_from_slice(digits.as_bytes());
+                } else {
+                    let digits = format!("..{digits}");
+                    push_number(out, "", prefix, &digits, spec);
+                }
+            }
+        }
+        b'f' => {
+            let value = float_arg(arg)?;
+            let precision = spec.precision.unwrap_or(6);
+            if let Some(special) = non_finite(value) {
+                push_text(out, special.as_bytes(), spec);
+            } else {
+                let digits = format!("{:.*}", precision, value.abs());
+                let sign = if value.is_sign_negative() { "-" } else { spec.flags.sign() };
+                // Precision is already applied to the digits; it must not
+                // disable the `0` flag like it does for integers.
+                push_number(out, sign, "", &digits, Spec { precision: None, ..spec });
+            }
+        }
+        b'e' | b'E' => {
+            let value = float_arg(arg)?;
+            let precision = spec.precision.unwrap_or(6);
+            if let Some(special) = non_finite(value) {
+                push_text(out, special.as_bytes(), spec);
+            } else {
+                let digits = format_exponential(value.abs(), precision, directive == b'E');
+                let sign = if value.is_sign_negative() { "-" } else { spec.flags.sign() };
+                push_number(out, sign, "", &digits, Spec { precision: None, ..spec });
+            }
+        }
+        b'g' | b'G' => {
+            let value = float_arg(arg)?;
+            if let Some(special) = non_finite(value) {
+                push_text(out, special.as_bytes(), spec);
+            } else {
+                let digits = format_general(value.abs(), spec.precision, directive == b'G', spec.flags.alternate);
+                let sign = if value.is_sign_negative() { "-" } else { spec.flags.sign() };
+                push_number(out, sign, "", &digits, Spec { precision: None, ..spec });
+            }
+        }
+        b's' => {
+            let text;
+            let text = match arg {
+                FormatArg::Int(value) => {
+                    text = format!("{value}");
+                    text.as_bytes()
+                }
+                FormatArg::Float(value) => {
+                    text = format!("{value}");
+                    text.as_bytes()
+                }
+                FormatArg::Bytes(bytes) => bytes,
+                FormatArg::Char(ch) => {
+                    text = format!("{ch}");
+                    text.as_bytes()
+                }
+                FormatArg::Named { .. } => return Err(FormatError::BadArgumentType),
+            };
+            // Precision truncates the byte content.
+            let text = match spec.precision {
+                Some(precision) if precision < text.len() => &text[..precision],
+                _ => text,
+            };
+            push_text(out, text, spec);
+        }
+        b'c' => {
+            let mut buf = [0; 4];
+            let text = match arg {
+                FormatArg::Char(ch) => ch.encode_utf8(&mut buf).as_bytes(),
+                FormatArg::Int(value) => {
+                    let codepoint = u32::try_from(*value).map_err(|_| FormatError::BadArgumentType)?;
+                    let ch = char::from_u32(codepoint).ok_or(FormatError::BadArgumentType)?;
+                    ch.encode_utf8(&mut buf).as_bytes()
+                }
+                // A byte string argument contributes its first character.
+                //
+                // ```
+                // [3.0.1] > sprintf("%c", "hello")
+                // => "h"
+                // ```
+                FormatArg::Bytes(bytes) => match bstr::decode_utf8(bytes) {
+                    (_, 0) => return Err(FormatError::BadArgumentType),
+                    (_, size) => &bytes[..size],
+                },
+                FormatArg::Float(_) | FormatArg::Named { .. } => return Err(FormatError::BadArgumentType),
+            };
+            push_text(out, text, spec);
+        }
+        _ => return Err(FormatError::MalformedFormatString),
+    }
+    Ok(())
+}
+
+fn int_arg(arg: &FormatArg<'_>) -> Result<i64, FormatError> {
+    match arg {
+        FormatArg::Int(value) => Ok(*value),
+        // Floats are truncated toward zero like `Float#to_i`.
+        #[allow(clippy::cast_possible_truncation)]
+        FormatArg::Float(value) if value.is_finite() => Ok(*value as i64),
+        _ => Err(FormatError::BadArgumentType),
+    }
+}
+
+fn float_arg(arg: &FormatArg<'_>) -> Result<f64, FormatError> {
+    match arg {
+        FormatArg::Int(value) => {
+            #[allow(clippy::cast_precision_loss)]
+            Ok(*value as f64)
+        }
+        FormatArg::Float(value) => Ok(*value),
+        _ => Err(FormatError::BadArgumentType),
+    }
+}
+
+fn non_finite(value: f64) -> Option<&'static str> {
+    if value.is_nan() {
+        Some("NaN")
+    } else if value == f64::INFINITY {
+        Some("Inf")
+    } else if value == f64::NEG_INFINITY {
+        Some("-Inf")
+    } else {
+        None
+    }
+}
+
+/// Format a non-negative finite float in `%e` notation with a sign and at
+/// least two digits in the exponent.
+fn format_exponential(value: f64, precision: usize, uppercase: bool) -> alloc::string::String {
+    let formatted = format!("{value:.precision$e}");
+    // `LowerExp` formats exponents as a bare integer like `e4` or `e-5`;
+    // C `%e` requires a sign and at least two digits like `e+04`.
+    let (mantissa, exponent) = formatted
+        .split_once('e')
+        .expect("LowerExp formatting always produces an exponent");
+    let exponent = exponent
+        .parse::<i32>()
+        .expect("LowerExp formatting always produces an integer exponent");
+    let e = if uppercase { 'E' } else { 'e' };
+    let mut out = alloc::string::String::with_capacity(formatted.len() + 3);
+    out.push_str(mantissa);
+    out.push(e);
+    let _ = write!(out, "{exponent:+03}");
+    out
+}
+
+/// Format a non-negative finite float in `%g` notation: `%e` when the
+/// exponent is less than -4 or at least the precision, `%f` otherwise, with
+/// trailing zeros removed unless the `#` flag is given.
+fn format_general(value: f64, precision: Option<usize>, uppercase: bool, alternate: bool) -> alloc::string::String {
+    let precision = match precision {
+        None => 6,
+        Some(0) => 1,
+        Some(precision) => precision,
+    };
+    let exponent = if value == 0.0 {
+        0
+    } else {
+        let formatted = format!("{value:e}");
+        let (_, exponent) = formatted
+            .split_once('e')
+            .expect("LowerExp formatting always produces an exponent");
+        exponent
+            .parse::<i32>()
+            .expect("LowerExp formatting always produces an integer exponent")
+    };
+    let mut formatted = if exponent < -4 || exponent >= i32::try_from(precision).unwrap_or(i32::MAX) {
+        format_exponential(value, precision - 1, uppercase)
+    } else {
+        let precision = usize::try_from(i32::try_from(precision).unwrap_or(i32::MAX) - 1 - exponent).unwrap_or(0);
+        format!("{value:.precision$}")
+    };
+    if !alternate {
+        // Strip trailing zeros in the fraction, and the decimal point if the
+        // fraction is removed entirely.
+        let mantissa_len = formatted.find(['e', 'E']).unwrap_or(formatted.len());
+        let (mantissa, exponent) = formatted.split_at(mantissa_len);
+        if mantissa.contains('.') {
+            let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+            formatted = format!("{mantissa}{exponent}");
+        }
+    }
+    formatted
+}
+
+/// Append a formatted number to the output, applying width and justification.
+///
+/// Zero padding inserts zeros between the sign or radix prefix and the
+/// digits, and is ignored when left justifying.
+fn push_number(out: &mut Vec<u8>, sign: &str, prefix: &str, digits: &str, spec: Spec) {
+    let mut digits_len = digits.len();
+    // An explicit precision zero pads integer digits and disables the `0`
+    // flag; float conversions never produce a precision here.
+    let precision_pad = spec.precision.map_or(0, |precision| precision.saturating_sub(digits_len));
+    digits_len += precision_pad;
+    let content_len = sign.len() + prefix.len() + digits_len;
+    let width_pad = spec.width.map_or(0, |width| width.saturating_sub(content_len));
+    if spec.flags.left_justify {
+        out.extend_from_slice(sign.as_bytes());
+        out.extend_from_slice(prefix.as_bytes());
+        extend_repeat(out, b'0', precision_pad);
+        out.extend_from_slice(digits.as_bytes());
+        extend_repeat(out, b' ', width_pad);
+    } else if spec.flags.zero_pad && spec.precision.is_none() {
+        out.extend_from_slice(sign.as_bytes());
+        out.extend_from_slice(prefix.as_bytes());
+        extend_repeat(out, b'0', width_pad);
+        out.extend_from_slice(digits.as_bytes());
+    } else {
+        extend_repeat(out, b' ', width_pad);
+        out.extend_from_slice(sign.as_bytes());
+        out.extend_from_slice(prefix.as_bytes());
+        extend_repeat(out, b'0', precision_pad);
+        out.extend_from_slice(digits.as_bytes());
+    }
+}
+
+/// Append formatted text to the output, applying width and justification.
+fn push_text(out: &mut Vec<u8>, text: &[u8], spec: Spec) {
+    let width_pad = spec.width.map_or(0, |width| width.saturating_sub(text.len()));
+    if spec.flags.left_justify {
+        out.extend_from_slice(text);
+        extend_repeat(out, b' ', width_pad);
+    } else {
+        extend_repeat(out, b' ', width_pad);
+        out.extend_from_slice(text);
+    }
+}
+
+fn extend_repeat(out: &mut Vec<u8>, byte: u8, count: usize) {
+    out.extend(core::iter::repeat(byte).take(count));
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{format, FormatArg, FormatError};
+
+    fn fmt(template: &[u8], args: &[FormatArg<'_>]) -> Vec<u8> {
+        format(template, args).unwrap()
+    }
+
+    #[test]
+    fn decimal() {
+        // ```
+        // [3.0.1] > sprintf("%d", 42)
+        // => "42"
+        // [3.0.1] > sprintf("%d", -42)
+        // => "-42"
+        // ```
+        assert_eq!(fmt(b"%d", &[FormatArg::Int(42)]), b"42");
+        assert_eq!(fmt(b"%i", &[FormatArg::Int(42)]), b"42");
+        assert_eq!(fmt(b"%u", &[FormatArg::Int(42)]), b"42");
+        assert_eq!(fmt(b"%d", &[FormatArg::Int(-42)]), b"-42");
+        // Floats are truncated toward zero.
+        assert_eq!(fmt(b"%d", &[FormatArg::Float(1.9)]), b"1");
+    }
+
+    #[test]
+    fn decimal_flags_width_and_precision() {
+        // ```
+        // [3.0.1] > sprintf("%+d", 42)
+        // => "+42"
+        // [3.0.1] > sprintf("% d", 42)
+        // => " 42"
+        // [3.0.1] > sprintf("%05d", 42)
+        // => "00042"
+        // [3.0.1] > sprintf("%-5d|", 42)
+        // => "42   |"
+        // [3.0.1] > sprintf("%.4d", 42)
+        // => "0042"
+        // [3.0.1] > sprintf("%06.4d", 42)
+        // => "  0042"
+        // ```
+        assert_eq!(fmt(b"%+d", &[FormatArg::Int(42)]), b"+42");
+        assert_eq!(fmt(b"% d", &[FormatArg::Int(42)]), b" 42");
+        assert_eq!(fmt(b"%05d", &[FormatArg::Int(42)]), b"00042");
+        assert_eq!(fmt(b"%05d", &[FormatArg::Int(-42)]), b"-0042");
+        assert_eq!(fmt(b"%-5d|", &[FormatArg::Int(42)]), b"42   |");
+        assert_eq!(fmt(b"%5d", &[FormatArg::Int(42)]), b"   42");
+        assert_eq!(fmt(b"%.4d", &[FormatArg::Int(42)]), b"0042");
+        // An explicit precision disables the `0` flag.
+        assert_eq!(fmt(b"%06.4d", &[FormatArg::Int(42)]), b"  0042");
+    }
+
+    #[test]
+    fn binary_octal_and_hex() {
+        // ```
+        // [3.0.1] > sprintf("%b", 10)
+        // => "1010"
+        // [3.0.1] > sprintf("%#b", 10)
+        // => "0b1010"
+        // [3.0.1] > sprintf("%o", 87)
+        // => "127"
+        // [3.0.1] > sprintf("%#o", 87)
+        // => "0127"
+        // [3.0.1] > sprintf("%x", 255)
+        // => "ff"
+        // [3.0.1] > sprintf("%X", 255)
+        // => "FF"
+        // [3.0.1] > sprintf("%#x", 255)
+        // => "0xff"
+        // ```
+        assert_eq!(fmt(b"%b", &[FormatArg::Int(10)]), b"1010");
+        assert_eq!(fmt(b"%#b", &[FormatArg::Int(10)]), b"0b1010");
+        assert_eq!(fmt(b"%o", &[FormatArg::Int(87)]), b"127");
+        assert_eq!(fmt(b"%#o", &[FormatArg::Int(87)]), b"0127");
+        assert_eq!(fmt(b"%x", &[FormatArg::Int(255)]), b"ff");
+        assert_eq!(fmt(b"%X", &[FormatArg::Int(255)]), b"FF");
+        assert_eq!(fmt(b"%#x", &[FormatArg::Int(255)]), b"0xff");
+        assert_eq!(fmt(b"%08b", &[FormatArg::Int(10)]), b"00001010");
+    }
+
+    #[test]
+    fn negative_radix_conversions_use_twos_complement_notation() {
+        // ```
+        // [3.0.1] > sprintf("%b", -5)
+        // => "..1011"
+        // [3.0.1] > sprintf("%x", -1)
+        // => "..f"
+        // [3.0.1] > sprintf("%x", -26)
+        // => "..fe6"
+        // [3.0.1] > sprintf("%+x", -26)
+        // => "-1a"
+        // ```
+        assert_eq!(fmt(b"%b", &[FormatArg::Int(-5)]), b"..1011");
+        assert_eq!(fmt(b"%x", &[FormatArg::Int(-1)]), b"..f");
+        assert_eq!(fmt(b"%x", &[FormatArg::Int(-26)]), b"..fe6");
+        assert_eq!(fmt(b"%+x", &[FormatArg::Int(-26)]), b"-1a");
+        assert_eq!(fmt(b"% x", &[FormatArg::Int(-26)]), b"-1a");
+    }
+
+    #[test]
+    fn float_fixed() {
+        // ```
+        // [3.0.1] > sprintf("%f", 1.0)
+        // => "1.000000"
+        // [3.0.1] > sprintf("%.2f", 3.14159)
+        // => "3.14"
+        // [3.0.1] > sprintf("%08.2f", 3.14159)
+        // => "00003.14"
+        // ```
+        assert_eq!(fmt(b"%f", &[FormatArg::Float(1.0)]), b"1.000000");
+        assert_eq!(fmt(b"%f", &[FormatArg::Float(-1.5)]), b"-1.500000");
+        assert_eq!(fmt(b"%+f", &[FormatArg::Float(1.5)]), b"+1.500000");
+        assert_eq!(fmt(b"%.2f", &[FormatArg::Float(3.14159)]), b"3.14");
+        assert_eq!(fmt(b"%08.2f", &[FormatArg::Float(3.14159)]), b"00003.14");
+        // Integer arguments are converted to floats.
+        assert_eq!(fmt(b"%.1f", &[FormatArg::Int(3)]), b"3.0");
+    }
+
+    #[test]
+    fn float_exponential() {
+        // ```
+        // [3.0.1] > sprintf("%e", 1.0)
+        // => "1.000000e+00"
+        // [3.0.1] > sprintf("%.2e", 12345.6789)
+        // => "1.23e+04"
+        // [3.0.1] > sprintf("%e", 0.00001)
+        // => "1.000000e-05"
+        // ```
+        assert_eq!(fmt(b"%e", &[FormatArg::Float(1.0)]), b"1.000000e+00");
+        assert_eq!(fmt(b"%.2e", &[FormatArg::Float(12345.6789)]), b"1.23e+04");
+        assert_eq!(fmt(b"%e", &[FormatArg::Float(0.00001)]), b"1.000000e-05");
+        assert_eq!(fmt(b"%.2E", &[FormatArg::Float(12345.6789)]), b"1.23E+04");
+    }
+
+    #[test]
+    fn float_general() {
+        // ```
+        // [3.0.1] > sprintf("%g", 123456789.0)
+        // => "1.23457e+08"
+        // [3.0.1] > sprintf("%g", 0.0001)
+        // => "0.0001"
+        // [3.0.1] > sprintf("%g", 0.00001)
+        // => "1e-05"
+        // [3.0.1] > sprintf("%g", 10.0)
+        // => "10"
+        // ```
+        assert_eq!(fmt(b"%g", &[FormatArg::Float(123_456_789.0)]), b"1.23457e+08");
+        assert_eq!(fmt(b"%g", &[FormatArg::Float(0.0001)]), b"0.0001");
+        assert_eq!(fmt(b"%g", &[FormatArg::Float(0.00001)]), b"1e-05");
+        assert_eq!(fmt(b"%g", &[FormatArg::Float(10.0)]), b"10");
+    }
+
+    #[test]
+    fn string_conversion() {
+        // ```
+        // [3.0.1] > sprintf("%5s", "abc")
+        // => "  abc"
+        // [3.0.1] > sprintf("%-5s|", "abc")
+        // => "abc  |"
+        // [3.0.1] > sprintf("%.2s", "abc")
+        // => "ab"
+        // ```
+        assert_eq!(fmt(b"%s", &[FormatArg::Bytes(b"abc")]), b"abc");
+        assert_eq!(fmt(b"%5s", &[FormatArg::Bytes(b"abc")]), b"  abc");
+        assert_eq!(fmt(b"%-5s|", &[FormatArg::Bytes(b"abc")]), b"abc  |");
+        assert_eq!(fmt(b"%.2s", &[FormatArg::Bytes(b"abc")]), b"ab");
+        assert_eq!(fmt(b"%s", &[FormatArg::Int(42)]), b"42");
+        // Templates and arguments with invalid UTF-8 bytes round trip.
+        assert_eq!(
+            fmt(b"\xFF%s\xFE", &[FormatArg::Bytes(b"\xF0\x9F")]),
+            b"\xFF\xF0\x9F\xFE"
+        );
+    }
+
+    #[test]
+    fn char_conversion() {
+        // ```
+        // [3.0.1] > sprintf("%c", 97)
+        // => "a"
+        // [3.0.1] > sprintf("%c", "hello")
+        // => "h"
+        // ```
+        assert_eq!(fmt(b"%c", &[FormatArg::Int(97)]), b"a");
+        assert_eq!(fmt(b"%c", &[FormatArg::Bytes(b"hello")]), b"h");
+        assert_eq!(fmt(b"%c", &[FormatArg::Char('\u{1F48E}')]), "\u{1F48E}".as_bytes());
+    }
+
+    #[test]
+    fn percent_escape() {
+        // ```
+        // [3.0.1] > sprintf("%d%%", 10)
+        // => "10%"
+        // ```
+        assert_eq!(fmt(b"%d%%", &[FormatArg::Int(10)]), b"10%");
+        assert_eq!(fmt(b"100%%", &[]), b"100%");
+    }
+
+    #[test]
+    fn named_references() {
+        // ```
+        // [3.0.1] > sprintf("%<id>05d", id: 42)
+        // => "00042"
+        // [3.0.1] > sprintf("%{id}", id: 42)
+        // => "42"
+        // ```
+        let args = [FormatArg::Named {
+            name: b"id",
+            value: &FormatArg::Int(42),
+        }];
+        assert_eq!(fmt(b"%<id>05d", &args), b"00042");
+        assert_eq!(fmt(b"%{id}", &args), b"42");
+    }
+
+    #[test]
+    fn missing_named_reference_is_key_not_found() {
+        let args = [FormatArg::Named {
+            name: b"id",
+            value: &FormatArg::Int(42),
+        }];
+        let err = format(b"%<nope>s", &args).unwrap_err();
+        assert_eq!(err, FormatError::KeyNotFound(b"nope".to_vec()));
+        assert_eq!(err.exception_type(), "KeyError");
+        assert_eq!(err.key(), Some(&b"nope"[..]));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            format(b"%d %d", &[FormatArg::Int(1)]),
+            Err(FormatError::TooFewArguments)
+        );
+        assert_eq!(format(b"100%", &[]), Err(FormatError::MalformedFormatString));
+        assert_eq!(
+            format(b"%q", &[FormatArg::Int(1)]),
+            Err(FormatError::MalformedFormatString)
+        );
+        assert_eq!(
+            format(b"%d", &[FormatArg::Bytes(b"abc")]),
+            Err(FormatError::BadArgumentType)
+        );
+        assert_eq!(
+            FormatError::TooFewArguments.exception_type(),
+            "ArgumentError"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use core::iter::FusedIterator;
+use core::str;
+
+use bstr::ByteSlice;
+
+use crate::{Encoding, String};
+
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a>(State<'a>);
+
+impl<'a> Default for Graphemes<'a> {
+    #[inline]
+    fn default() -> Self {
+        const EMPTY: &[u8] = &[];
+        Self(State::Bytes(Bytes::from(EMPTY)))
+    }
+}
+
+impl<'a> From<&'a String> for Graphemes<'a> {
+    #[inline]
+    fn from(s: &'a String) -> Self {
+        let state = match s.encoding() {
+            Encoding::Utf8 => {
+                let iter = ConventionallyUtf8::with_bytes(s.as_slice());
+                State::Utf8(iter)
+            }
+            Encoding::Ascii | Encoding::Binary => {
+                let iter = Bytes::with_bytes(s.as_slice());
+                State::Bytes(iter)
+            }
+        };
+        Self(state)
+    }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<'a> FusedIterator for Graphemes<'a> {}
+
+#[derive(Debug, Clone)]
+enum State<'a> {
+    Utf8(ConventionallyUtf8<'a>),
+    Bytes(Bytes<'a>),
+}
+
+impl<'a> Iterator for State<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Utf8(iter) => iter.next(),
+            Self::Bytes(iter) => iter.next(),
+        }
+    }
+}
+
+impl<'a> FusedIterator for State<'a> {}
+
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Bytes<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Bytes<'a> {
+    #[inline]
+    const fn with_bytes(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<'a> From<&'a [u8]> for Bytes<'a> {
+    #[inline]
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::with_bytes(bytes)
+    }
+}
+
+impl<'a> Iterator for Bytes<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            None
+        } else {
+            // Splitting the byte slice is guaranteed to not panic because the
+            // slice is non-empty.
+            let (next, remainder) = self.bytes.split_at(1);
+            self.bytes = remainder;
+            Some(next)
+        }
+    }
+}
+
+impl<'a> FusedIterator for Bytes<'a> {}
+
+#[derive(Debug, Clone)]
+struct ConventionallyUtf8<'a> {
+    bytes: &'a [u8],
+    graphemes: bstr::GraphemeIndices<'a>,
+    invalid_bytes: Bytes<'a>,
+}
+
+impl<'a> ConventionallyUtf8<'a> {
+    #[inline]
+    fn with_bytes(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            graphemes: bytes.grapheme_indices(),
+            invalid_bytes: Bytes::with_bytes(&[]),
+        }
+    }
+}
+
+impl<'a> Iterator for ConventionallyUtf8<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(slice) = self.invalid_bytes.next() {
+            return Some(slice);
+        }
+        let (start, end, _) = self.graphemes.next()?;
+        let grapheme = &self.bytes[start..end];
+        if str::from_utf8(grapheme).is_ok() {
+            Some(grapheme)
+        } else {
+            // `bstr` lossily decodes invalid UTF-8 byte sequences to
+            // `U+FFFD REPLACEMENT CHARACTER`, which can form grapheme clusters
+            // with neighboring characters. Invalid UTF-8 bytes are yielded as
+            // byte slices one byte at a time.
+            self.invalid_bytes = Bytes::with_bytes(grapheme);
+            self.invalid_bytes.next()
+        }
+    }
+}
+
+impl<'a> FusedIterator for ConventionallyUtf8<'a> {}
@@ -8,7 +8,8 @@ use core::slice::SliceIndex;
 #[cfg(feature = "std")]
 use std::io;
 
-use crate::{Bytes, Center, IntoIter, Iter, IterMut, String};
+use crate::encoding::Encoding;
+use crate::{Bytes, Center, IntoIter, Iter, IterMut, String, Utf8Error};
 
 impl<'a> AsRef<[u8]> for Iter<'a> {
     fn as_ref(&self) -> &[u8] {
@@ -38,6 +39,18 @@ impl fmt::Write for String {
     }
 }
 
+/// Append raw bytes to the `String`.
+///
+/// Writes always succeed and [`flush`] is a no-op. Unlike the [`fmt::Write`]
+/// implementation, writes are not required to be UTF-8 and may leave a
+/// [UTF-8-tagged] `String` with byte content that is not [valid for its
+/// encoding]. This is permitted because encodings are conventional: callers
+/// which require validity should check [`is_valid_encoding`] after writing.
+///
+/// [`flush`]: io::Write::flush
+/// [UTF-8-tagged]: crate::Encoding::Utf8
+/// [valid for its encoding]: String::is_valid_encoding
+/// [`is_valid_encoding`]: String::is_valid_encoding
 #[cfg(feature = "std")]
 impl io::Write for String {
     #[inline]
@@ -289,7 +302,7 @@ impl IntoIterator for String {
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter(self.buf.into_iter())
+        IntoIter(self.buf.into_vec().into_iter())
     }
 }
 
@@ -403,10 +416,83 @@ impl From<&str> for String {
     }
 }
 
+impl<'a> TryFrom<&'a String> for &'a str {
+    type Error = Utf8Error;
+
+    /// Yield the bytes of the `String` as a UTF-8 [`str`].
+    ///
+    /// The conversion fails if the byte content is not valid UTF-8 or if the
+    /// `String` is tagged as [`Encoding::Ascii`] or [`Encoding::Binary`] and
+    /// contains non-ASCII bytes. The returned [`Utf8Error`] carries the byte
+    /// offset at which the first unconvertible byte sequence begins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("artichoke".as_bytes().to_vec());
+    /// assert_eq!(<&str>::try_from(&s), Ok("artichoke"));
+    ///
+    /// let s = String::binary(b"abc\xFF".to_vec());
+    /// let err = <&str>::try_from(&s).unwrap_err();
+    /// assert_eq!(err.offset(), 3);
+    /// ```
+    #[inline]
+    fn try_from(s: &'a String) -> Result<Self, Self::Error> {
+        if let Encoding::Ascii | Encoding::Binary = s.encoding() {
+            // Bytes outside the ASCII range have no character interpretation
+            // in these encodings, even if they form valid UTF-8 sequences.
+            if let Some(offset) = s.as_slice().iter().position(|byte| !byte.is_ascii()) {
+                return Err(Utf8Error::new(offset));
+            }
+        }
+        match simdutf8::compat::from_utf8(s.as_slice()) {
+            Ok(s) => Ok(s),
+            Err(err) => Err(Utf8Error::new(err.valid_up_to())),
+        }
+    }
+}
+
+impl TryFrom<String> for alloc::string::String {
+    type Error = Utf8Error;
+
+    /// Convert the `String` into an owned UTF-8 [`alloc::string::String`],
+    /// reusing the underlying byte buffer.
+    ///
+    /// The conversion fails if the byte content is not valid UTF-8 or if the
+    /// `String` is tagged as [`Encoding::Ascii`] or [`Encoding::Binary`] and
+    /// contains non-ASCII bytes. The returned [`Utf8Error`] carries the byte
+    /// offset at which the first unconvertible byte sequence begins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("artichoke".as_bytes().to_vec());
+    /// let converted = std::string::String::try_from(s).unwrap();
+    /// assert_eq!(converted, "artichoke");
+    ///
+    /// let s = String::utf8(b"abc\xFFxyz".to_vec());
+    /// let err = std::string::String::try_from(s).unwrap_err();
+    /// assert_eq!(err.offset(), 3);
+    /// ```
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        <&str>::try_from(&s)?;
+        // The conversion above ensures the byte content is valid UTF-8.
+        alloc::string::String::from_utf8(s.buf.into_vec())
+            .map_err(|err| Utf8Error::new(err.utf8_error().valid_up_to()))
+    }
+}
+
 impl From<String> for Vec<u8> {
     #[inline]
     fn from(s: String) -> Self {
-        s.buf
+        s.buf.into_vec()
     }
 }
 
@@ -487,13 +573,13 @@ impl<I: SliceIndex<[u8]>> Index<I> for String {
 
     #[inline]
     fn index(&self, index: I) -> &Self::Output {
-        Index::index(&self.buf, index)
+        Index::index(&*self.buf, index)
     }
 }
 
 impl<I: SliceIndex<[u8]>> IndexMut<I> for String {
     #[inline]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
-        IndexMut::index_mut(&mut self.buf, index)
+        IndexMut::index_mut(&mut *self.buf, index)
     }
 }
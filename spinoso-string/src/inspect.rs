@@ -3,6 +3,8 @@ use core::iter::FusedIterator;
 
 use scolapasta_string_escape::{is_ascii_char_with_escape, InvalidUtf8ByteSequence};
 
+use crate::encoding::Encoding;
+
 /// An iterator that yields a debug representation of a `String` and its byte
 /// contents as a sequence of `char`s.
 ///
@@ -87,6 +89,37 @@ impl<'a> DoubleEndedIterator for Inspect<'a> {
 impl<'a> FusedIterator for Inspect<'a> {}
 
 impl<'a> Inspect<'a> {
+    /// Construct an `Inspect` for a byte string with the given encoding.
+    ///
+    /// [`Utf8`] strings emit valid UTF-8 multibyte characters raw, like
+    /// `"💎"`. [`Ascii`] and [`Binary`] strings hex-escape all non-ASCII
+    /// bytes, like `"\xF0\x9F\x92\x8E"`, since such bytes are invalid in
+    /// these encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// use alloc::string::String;
+    /// use spinoso_string::{Encoding, Inspect};
+    /// let inspect = Inspect::with_encoding("💎".as_bytes(), Encoding::Utf8);
+    /// assert_eq!(inspect.collect::<String>(), "\"💎\"");
+    ///
+    /// let inspect = Inspect::with_encoding("💎".as_bytes(), Encoding::Binary);
+    /// assert_eq!(inspect.collect::<String>(), r#""\xF0\x9F\x92\x8E""#);
+    /// ```
+    ///
+    /// [`Utf8`]: Encoding::Utf8
+    /// [`Ascii`]: Encoding::Ascii
+    /// [`Binary`]: Encoding::Binary
+    #[inline]
+    pub fn with_encoding(bytes: &'a [u8], encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Utf8 => Self(State::new(bytes)),
+            Encoding::Ascii | Encoding::Binary => Self(State::binary(bytes)),
+        }
+    }
+
     /// Write an `Inspect` iterator into the given destination using the debug
     /// representation of the byte buffer associated with a source `String`.
     ///
@@ -187,11 +220,15 @@ impl Flags {
     // Bit flags
     const EMIT_LEADING_QUOTE: Self = Self { bits: 0b0000_0001 };
     const EMIT_TRAILING_QUOTE: Self = Self { bits: 0b0000_0010 };
+    const ESCAPE_NON_ASCII: Self = Self { bits: 0b0000_0100 };
 
     // Initial states
     const DEFAULT: Self = Self {
         bits: Self::EMIT_LEADING_QUOTE.bits | Self::EMIT_TRAILING_QUOTE.bits,
     };
+    const BINARY: Self = Self {
+        bits: Self::DEFAULT.bits | Self::ESCAPE_NON_ASCII.bits,
+    };
 
     #[inline]
     fn emit_leading_quote(&mut self) -> Option<char> {
@@ -212,6 +249,51 @@ impl Flags {
             None
         }
     }
+
+    #[inline]
+    const fn escape_non_ascii(self) -> bool {
+        (self.bits & Self::ESCAPE_NON_ASCII.bits) == Self::ESCAPE_NON_ASCII.bits
+    }
+}
+
+/// A double-ended iterator over a short `'static` ASCII escape sequence like
+/// `\#{`.
+#[derive(Debug, Clone)]
+struct EscapeSequence(&'static [u8]);
+
+impl Default for EscapeSequence {
+    #[inline]
+    fn default() -> Self {
+        Self(b"")
+    }
+}
+
+impl EscapeSequence {
+    /// Escape sequence for a `#` which would otherwise begin an interpolation
+    /// sequence, leaving the trigger character in the source byte string.
+    const INTERPOLATION: Self = Self(br"\#");
+
+    /// Escape sequence for a `#` and the given interpolation trigger
+    /// character, one of `{`, `$`, or `@`.
+    fn interpolation_with_trigger(trigger: char) -> Self {
+        match trigger {
+            '{' => Self(br"\#{"),
+            '$' => Self(br"\#$"),
+            _ => Self(br"\#@"),
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let (&byte, remainder) = self.0.split_first()?;
+        self.0 = remainder;
+        Some(char::from(byte))
+    }
+
+    fn next_back(&mut self) -> Option<char> {
+        let (&byte, remainder) = self.0.split_last()?;
+        self.0 = remainder;
+        Some(char::from(byte))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -219,7 +301,9 @@ impl Flags {
 struct State<'a> {
     flags: Flags,
     forward_byte_literal: InvalidUtf8ByteSequence,
+    forward_escape: EscapeSequence,
     bytes: &'a [u8],
+    reverse_escape: EscapeSequence,
     reverse_byte_literal: InvalidUtf8ByteSequence,
 }
 
@@ -232,10 +316,24 @@ impl<'a> State<'a> {
         Self {
             flags: Flags::DEFAULT,
             forward_byte_literal: InvalidUtf8ByteSequence::new(),
+            forward_escape: EscapeSequence::default(),
             bytes,
+            reverse_escape: EscapeSequence::default(),
             reverse_byte_literal: InvalidUtf8ByteSequence::new(),
         }
     }
+
+    /// Construct a `State` for the given byte slice which hex-escapes all
+    /// non-ASCII bytes.
+    ///
+    /// This constructor produces inspect contents like `"\xF0\x9F\x92\x8E"`.
+    #[inline]
+    fn binary(bytes: &'a [u8]) -> Self {
+        Self {
+            flags: Flags::BINARY,
+            ..Self::new(bytes)
+        }
+    }
 }
 
 impl<'a> Default for State<'a> {
@@ -259,8 +357,28 @@ impl<'a> Iterator for State<'a> {
         if let Some(ch) = self.forward_byte_literal.next() {
             return Some(ch);
         }
-        let (ch, size) = bstr::decode_utf8(self.bytes);
+        if let Some(ch) = self.forward_escape.next() {
+            return Some(ch);
+        }
+        let (ch, size) = if self.flags.escape_non_ascii() {
+            // Binary strings are escaped byte-by-byte: multibyte UTF-8
+            // sequences are never passed through raw.
+            match self.bytes.first() {
+                Some(&byte) if byte.is_ascii() => (Some(char::from(byte)), 1),
+                Some(_) => (None, 1),
+                None => (None, 0),
+            }
+        } else {
+            bstr::decode_utf8(self.bytes)
+        };
         match ch {
+            // A `#` only requires escaping when it would otherwise begin an
+            // interpolation sequence -- `#{`, `#$`, or `#@`.
+            Some('#') if matches!(self.bytes.get(1), Some(b'{' | b'$' | b'@')) => {
+                self.bytes = &self.bytes[1..];
+                self.forward_escape = EscapeSequence::INTERPOLATION;
+                return self.forward_escape.next();
+            }
             Some(ch) if is_ascii_char_with_escape(ch) => {
                 let (ascii_byte, remainder) = self.bytes.split_at(size);
                 // This conversion is safe to unwrap due to the documented
@@ -289,6 +407,9 @@ impl<'a> Iterator for State<'a> {
                 return self.forward_byte_literal.next();
             }
         };
+        if let Some(ch) = self.reverse_escape.next() {
+            return Some(ch);
+        }
         if let Some(ch) = self.reverse_byte_literal.next() {
             return Some(ch);
         }
@@ -307,8 +428,30 @@ impl<'a> DoubleEndedIterator for State<'a> {
         if let Some(ch) = self.reverse_byte_literal.next_back() {
             return Some(ch);
         }
-        let (ch, size) = bstr::decode_last_utf8(self.bytes);
+        if let Some(ch) = self.reverse_escape.next_back() {
+            return Some(ch);
+        }
+        let (ch, size) = if self.flags.escape_non_ascii() {
+            // Binary strings are escaped byte-by-byte: multibyte UTF-8
+            // sequences are never passed through raw.
+            match self.bytes.last() {
+                Some(&byte) if byte.is_ascii() => (Some(char::from(byte)), 1),
+                Some(_) => (None, 1),
+                None => (None, 0),
+            }
+        } else {
+            bstr::decode_last_utf8(self.bytes)
+        };
         match ch {
+            // A `#` only requires escaping when it would otherwise begin an
+            // interpolation sequence -- `#{`, `#$`, or `#@`.
+            Some(ch @ ('{' | '$' | '@'))
+                if self.bytes.len() >= 2 && self.bytes[self.bytes.len() - 2] == b'#' =>
+            {
+                self.bytes = &self.bytes[..self.bytes.len() - 2];
+                self.reverse_escape = EscapeSequence::interpolation_with_trigger(ch);
+                return self.reverse_escape.next_back();
+            }
             Some(ch) if is_ascii_char_with_escape(ch) => {
                 let (remainder, ascii_byte) = self.bytes.split_at(self.bytes.len() - size);
                 // This conversion is safe to unwrap due to the documented
@@ -337,6 +480,9 @@ impl<'a> DoubleEndedIterator for State<'a> {
                 return self.reverse_byte_literal.next_back();
             }
         };
+        if let Some(ch) = self.forward_escape.next_back() {
+            return Some(ch);
+        }
         if let Some(ch) = self.forward_byte_literal.next_back() {
             return Some(ch);
         }
@@ -354,6 +500,7 @@ mod tests {
     use alloc::string::String;
 
     use super::Inspect;
+    use crate::Encoding;
 
     #[test]
     fn empty() {
@@ -627,6 +774,89 @@ mod tests {
         assert_eq!(Inspect::from("$-�a").collect::<String>(), r#""$-�a""#);
         assert_eq!(Inspect::from("$-��").collect::<String>(), r#""$-��""#);
     }
+
+    #[test]
+    fn interpolation_hash_is_escaped() {
+        assert_eq!(Inspect::from("#{foo}").collect::<String>(), r#""\#{foo}""#);
+        assert_eq!(Inspect::from("#$foo").collect::<String>(), r#""\#$foo""#);
+        assert_eq!(Inspect::from("#@foo").collect::<String>(), r#""\#@foo""#);
+        assert_eq!(Inspect::from("a#{b}c").collect::<String>(), r#""a\#{b}c""#);
+    }
+
+    #[test]
+    fn bare_hash_is_not_escaped() {
+        assert_eq!(Inspect::from("#").collect::<String>(), "\"#\"");
+        assert_eq!(Inspect::from("#foo").collect::<String>(), "\"#foo\"");
+        assert_eq!(Inspect::from("foo#").collect::<String>(), "\"foo#\"");
+        assert_eq!(Inspect::from("# {").collect::<String>(), "\"# {\"");
+    }
+
+    #[test]
+    fn interpolation_hash_is_escaped_backwards() {
+        let mut inspect = Inspect::from("#{b}");
+        assert_eq!(inspect.next_back(), Some('"'));
+        assert_eq!(inspect.next_back(), Some('}'));
+        assert_eq!(inspect.next_back(), Some('b'));
+        assert_eq!(inspect.next_back(), Some('{'));
+        assert_eq!(inspect.next_back(), Some('#'));
+        assert_eq!(inspect.next_back(), Some('\\'));
+        assert_eq!(inspect.next_back(), Some('"'));
+        assert_eq!(inspect.next_back(), None);
+        assert_eq!(inspect.next(), None);
+    }
+
+    #[test]
+    fn binary_encoding_escapes_multibyte_chars() {
+        let inspect = Inspect::with_encoding("💎".as_bytes(), Encoding::Binary);
+        assert_eq!(inspect.collect::<String>(), r#""\xF0\x9F\x92\x8E""#);
+
+        let inspect = Inspect::with_encoding("abc💎".as_bytes(), Encoding::Binary);
+        assert_eq!(inspect.collect::<String>(), r#""abc\xF0\x9F\x92\x8E""#);
+
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L147-L157
+        let inspect = Inspect::with_encoding("⅛⅜⅝⅞".as_bytes(), Encoding::Binary);
+        assert_eq!(
+            inspect.collect::<String>(),
+            r#""\xE2\x85\x9B\xE2\x85\x9C\xE2\x85\x9D\xE2\x85\x9E""#
+        );
+    }
+
+    #[test]
+    fn binary_encoding_escapes_multibyte_chars_backwards() {
+        let mut inspect = Inspect::with_encoding("a💎".as_bytes(), Encoding::Binary);
+        assert_eq!(inspect.next_back(), Some('"'));
+        assert_eq!(inspect.next_back(), Some('E'));
+        assert_eq!(inspect.next_back(), Some('8'));
+        assert_eq!(inspect.next_back(), Some('x'));
+        assert_eq!(inspect.next_back(), Some('\\'));
+        assert_eq!(inspect.next_back(), Some('2'));
+        assert_eq!(inspect.next_back(), Some('9'));
+        assert_eq!(inspect.next_back(), Some('x'));
+        assert_eq!(inspect.next_back(), Some('\\'));
+        assert_eq!(inspect.next_back(), Some('F'));
+        assert_eq!(inspect.next_back(), Some('9'));
+        assert_eq!(inspect.next_back(), Some('x'));
+        assert_eq!(inspect.next_back(), Some('\\'));
+        assert_eq!(inspect.next_back(), Some('0'));
+        assert_eq!(inspect.next_back(), Some('F'));
+        assert_eq!(inspect.next_back(), Some('x'));
+        assert_eq!(inspect.next_back(), Some('\\'));
+        assert_eq!(inspect.next_back(), Some('a'));
+        assert_eq!(inspect.next_back(), Some('"'));
+        assert_eq!(inspect.next_back(), None);
+        assert_eq!(inspect.next(), None);
+    }
+
+    #[test]
+    fn utf8_encoding_passes_naughty_strings_through() {
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L147-L157
+        let inspect = Inspect::with_encoding("Ω≈ç√∫˜µ≤≥÷".as_bytes(), Encoding::Utf8);
+        assert_eq!(inspect.collect::<String>(), "\"Ω≈ç√∫˜µ≤≥÷\"");
+
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L202-L224
+        let inspect = Inspect::with_encoding("表ポあA鷗ŒéＢ逍Üßªąñ丂㐀𠀀".as_bytes(), Encoding::Utf8);
+        assert_eq!(inspect.collect::<String>(), "\"表ポあA鷗ŒéＢ逍Üßªąñ丂㐀𠀀\"");
+    }
 }
 
 #[cfg(test)]
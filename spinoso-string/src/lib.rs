@@ -34,7 +34,9 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::vec::{self, Vec};
 use core::cmp::Ordering;
 use core::fmt::{self, Write};
@@ -46,22 +48,52 @@ use core::slice::{self, SliceIndex};
 use core::str;
 
 use bstr::{ByteSlice, ByteVec};
+use scolapasta_string_escape::{to_ascii_lower, to_ascii_upper};
 #[doc(inline)]
 #[cfg(feature = "casecmp")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "casecmp")))]
 pub use focaccia::CaseFold;
 
+mod buf;
+#[cfg(feature = "casecmp")]
+mod case_folded_key;
 mod chars;
 mod codepoints;
+mod dump;
 mod encoding;
 mod eq;
+pub mod format;
+mod graphemes;
 mod impls;
 mod inspect;
-
-pub use chars::Chars;
+mod lines;
+mod matches;
+mod parse_float;
+mod parse_int;
+mod split;
+mod tr;
+mod transcode;
+mod upto;
+mod writer;
+
+use buf::Buf;
+#[cfg(feature = "casecmp")]
+pub use case_folded_key::CaseFoldedKey;
+pub use chars::{CharIndices, Chars};
 pub use codepoints::{Codepoints, CodepointsError};
+pub use dump::Dump;
 pub use encoding::{Encoding, InvalidEncodingError};
+pub use format::{format, FormatArg, FormatError};
+pub use graphemes::Graphemes;
 pub use inspect::Inspect;
+pub use lines::Lines;
+pub use matches::Matches;
+pub use parse_float::ParseFloatError;
+pub use parse_int::ParsedInteger;
+pub use split::Split;
+pub use transcode::TranscodeError;
+pub use upto::Upto;
+pub use writer::Writer;
 
 /// Immutable [`String`] byte slice iterator.
 ///
@@ -484,6 +516,161 @@ impl fmt::Display for InvalidCodepointError {
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidCodepointError {}
 
+/// Error returned when a [`String`] cannot be converted to a UTF-8 Rust
+/// string.
+///
+/// This error is returned from the [`TryFrom`] conversions from [`String`] to
+/// [`str`] and [`alloc::string::String`]. It carries the byte offset at which
+/// the first unconvertible byte sequence begins.
+///
+/// A conversion fails when the byte content is not valid UTF-8 or when the
+/// `String` is tagged as [ASCII] or [binary] and contains non-ASCII bytes,
+/// which have no character interpretation.
+///
+/// When the **std** feature of `spinoso-string` is enabled, this struct
+/// implements [`std::error::Error`].
+///
+/// [ASCII]: Encoding::Ascii
+/// [binary]: Encoding::Binary
+/// [`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Utf8Error {
+    offset: usize,
+}
+
+impl Utf8Error {
+    #[inline]
+    #[must_use]
+    const fn new(offset: usize) -> Self {
+        Self { offset }
+    }
+
+    /// Return the byte offset at which the first unconvertible byte sequence
+    /// begins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"abc\xFFxyz".to_vec());
+    /// let err = <&str>::try_from(&s).unwrap_err();
+    /// assert_eq!(err.offset(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for Utf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid UTF-8 byte sequence starting at byte offset {}", self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf8Error {}
+
+/// Error returned when an index is out of range of a [`String`].
+///
+/// This error is returned from [`String::insert_str`] and [`String::setbyte`].
+/// See their documentation for more detail.
+///
+/// This error corresponds to the [Ruby `IndexError` Exception class].
+///
+/// When the **std** feature of `spinoso-string` is enabled, this struct
+/// implements [`std::error::Error`].
+///
+/// [Ruby `IndexError` Exception class]: https://ruby-doc.org/core-2.6.3/IndexError.html
+/// [`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IndexOutOfBoundsError {
+    index: i64,
+    len: usize,
+}
+
+impl IndexOutOfBoundsError {
+    /// `IndexOutOfBoundsError` corresponds to an [`IndexError`] Ruby
+    /// exception.
+    ///
+    /// [`IndexError`]: https://ruby-doc.org/core-2.6.3/IndexError.html
+    pub const EXCEPTION_TYPE: &'static str = "IndexError";
+
+    /// Construct a new `IndexOutOfBoundsError` for the given out of range
+    /// index into a string of the given byte length.
+    ///
+    /// The index is signed because Ruby indexing APIs like [`String#setbyte`]
+    /// accept negative indices, which count backward from the end of the
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::IndexOutOfBoundsError;
+    ///
+    /// const ERR: IndexOutOfBoundsError = IndexOutOfBoundsError::with_index_and_len(-6, 5);
+    /// assert_eq!(ERR.index(), -6);
+    /// assert_eq!(ERR.string_len(), 5);
+    /// ```
+    ///
+    /// [`String#setbyte`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-setbyte
+    #[inline]
+    #[must_use]
+    pub const fn with_index_and_len(index: i64, len: usize) -> Self {
+        Self { index, len }
+    }
+
+    /// Retrieve the out of range index that caused this error.
+    #[inline]
+    #[must_use]
+    pub const fn index(self) -> i64 {
+        self.index
+    }
+
+    /// Retrieve the byte length of the string at the time this error occurred.
+    #[inline]
+    #[must_use]
+    pub const fn string_len(self) -> usize {
+        self.len
+    }
+
+    /// Retrieve the exception message associated with this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_string::IndexOutOfBoundsError;
+    /// let err = IndexOutOfBoundsError::with_index_and_len(5, 3);
+    /// assert_eq!(err.message(), "index 5 out of string");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn message(self) -> alloc::string::String {
+        // The error message is 20 bytes + a base 10 formatted index.
+        const MESSAGE_MAX_LENGTH: usize = 20 + 20;
+        let mut s = alloc::string::String::with_capacity(MESSAGE_MAX_LENGTH);
+        // In practice, the errors from `write!` below are safe to ignore
+        // because the `core::fmt::Write` impl for `String` will never panic
+        // and these `String`s will never approach `isize::MAX` bytes.
+        //
+        // See the `core::fmt::Display` impl for `IndexOutOfBoundsError`.
+        let _ = write!(s, "{}", self);
+        s
+    }
+}
+
+impl fmt::Display for IndexOutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index {} out of string", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOutOfBoundsError {}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum OrdError {
     /// The first character in a [conventionally UTF-8] `String` is an invalid
@@ -577,9 +764,74 @@ impl fmt::Display for OrdError {
 #[cfg(feature = "std")]
 impl std::error::Error for OrdError {}
 
+/// Error returned when failing to repeat a [`String`].
+///
+/// This error is returned from [`String::repeat`]. See its documentation for
+/// more detail.
+///
+/// This error corresponds to the [Ruby `RangeError` Exception class].
+///
+/// When the **std** feature of `spinoso-string` is enabled, this struct
+/// implements [`std::error::Error`].
+///
+/// [Ruby `RangeError` Exception class]: https://ruby-doc.org/core-2.6.3/RangeError.html
+/// [`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RepeatError {
+    /// Error returned when calling [`String::repeat`] with a count whose
+    /// resulting byte length overflows the maximum string capacity.
+    CapacityOverflow,
+}
+
+impl RepeatError {
+    pub const EXCEPTION_TYPE: &'static str = "RangeError";
+
+    /// Create a new capacity overflow `RepeatError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::RepeatError;
+    ///
+    /// const ERR: RepeatError = RepeatError::capacity_overflow();
+    /// assert_eq!(ERR.message(), "argument too big");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn capacity_overflow() -> Self {
+        Self::CapacityOverflow
+    }
+
+    /// Retrieve the exception message associated with this repeat error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_string::RepeatError;
+    /// let err = RepeatError::capacity_overflow();
+    /// assert_eq!(err.message(), "argument too big");
+    /// ```
+    #[inline]
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub const fn message(self) -> &'static str {
+        "argument too big"
+    }
+}
+
+impl fmt::Display for RepeatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let RepeatError::CapacityOverflow = self;
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RepeatError {}
+
 #[derive(Default, Clone)]
 pub struct String {
-    buf: Vec<u8>,
+    buf: Buf,
     encoding: Encoding,
 }
 
@@ -594,7 +846,8 @@ impl fmt::Debug for String {
 
 impl Hash for String {
     fn hash<H: Hasher>(&self, hasher: &mut H) {
-        // A `String`'s hash only depends on its byte contents.
+        // An ASCII-only `String`'s hash only depends on its byte contents
+        // since encodings do not distinguish ASCII-only strings.
         //
         // ```
         // [3.0.2] > s = "abc"
@@ -606,13 +859,21 @@ impl Hash for String {
         // [3.0.2] > t.hash
         // => 3398383793005079442
         // ```
+        //
+        // Strings with non-ASCII byte content additionally hash their
+        // encoding, which keeps the hash consistent with `PartialEq`.
         self.buf.hash(hasher);
+        if !self.buf.is_ascii_only() {
+            self.encoding.hash(hasher);
+        }
     }
 }
 
 impl PartialEq for String {
     fn eq(&self, other: &String) -> bool {
-        // Equality only depends on each `String`'s byte contents.
+        // Equality of ASCII-only `String`s only depends on each `String`'s
+        // byte contents since encodings do not distinguish ASCII-only
+        // strings.
         //
         // ```
         // [3.0.2] > s = "abc"
@@ -622,7 +883,15 @@ impl PartialEq for String {
         // [3.0.2] > s == t
         // => true
         // ```
-        self.buf[..] == other.buf[..]
+        //
+        // Strings with equal non-ASCII byte content are only equal if their
+        // encodings match.
+        //
+        // ```
+        // [3.0.2] > "é" == "é".b
+        // => false
+        // ```
+        self.buf[..] == other.buf[..] && (self.encoding == other.encoding || self.buf.is_ascii_only())
     }
 }
 
@@ -630,13 +899,21 @@ impl Eq for String {}
 
 impl PartialOrd for String {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.buf[..].partial_cmp(&other.buf[..])
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for String {
     fn cmp(&self, other: &String) -> Ordering {
-        self.buf[..].cmp(&other.buf[..])
+        // Strings with equal non-ASCII byte content are ordered by their
+        // encoding, which keeps the ordering consistent with `PartialEq`.
+        self.buf[..].cmp(&other.buf[..]).then_with(|| {
+            if self.buf.is_ascii_only() {
+                Ordering::Equal
+            } else {
+                self.encoding.cmp(&other.encoding)
+            }
+        })
     }
 }
 
@@ -661,7 +938,7 @@ impl String {
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
-        let buf = Vec::new();
+        let buf = Buf::owned(Vec::new());
         let encoding = Encoding::Utf8;
         Self { buf, encoding }
     }
@@ -711,7 +988,7 @@ impl String {
     #[inline]
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
-        let buf = Vec::with_capacity(capacity);
+        let buf = Vec::with_capacity(capacity).into();
         let encoding = Encoding::Utf8;
         Self { buf, encoding }
     }
@@ -760,22 +1037,105 @@ impl String {
     #[inline]
     #[must_use]
     pub fn with_capacity_and_encoding(capacity: usize, encoding: Encoding) -> Self {
-        let buf = Vec::with_capacity(capacity);
+        let buf = Vec::with_capacity(capacity).into();
         Self { buf, encoding }
     }
 
     #[inline]
     #[must_use]
     pub fn with_bytes_and_encoding(buf: Vec<u8>, encoding: Encoding) -> Self {
+        let buf = buf.into();
+        Self { buf, encoding }
+    }
+
+    /// Constructs a new `String` which shares its byte buffer with all clones
+    /// of the given [`Arc`].
+    ///
+    /// A shared `String` supports the same read APIs as an owned one and
+    /// `clone`s of it are cheap — they bump the [`Arc`] reference count
+    /// instead of copying the byte content. The first mutation of a shared
+    /// `String` — including [`as_mut_slice`] and [`as_mut_ptr`] — copies the
+    /// byte content into a uniquely owned buffer, so mutating one `String`
+    /// never modifies the others sharing the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use spinoso_string::{Encoding, String};
+    ///
+    /// let arc = Arc::new(b"abc".to_vec());
+    /// let s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+    /// let mut t = s.clone();
+    ///
+    /// t.push_byte(b'!');
+    /// assert_eq!(t.as_slice(), b"abc!");
+    /// assert_eq!(s.as_slice(), b"abc");
+    /// ```
+    ///
+    /// [`as_mut_slice`]: String::as_mut_slice
+    /// [`as_mut_ptr`]: String::as_mut_ptr
+    #[inline]
+    #[must_use]
+    pub fn shared(buf: Arc<Vec<u8>>, encoding: Encoding) -> Self {
+        let buf = buf.into();
         Self { buf, encoding }
     }
 
+    /// Ensure this `String` uniquely owns its byte buffer.
+    ///
+    /// If this `String` shares its buffer with clones of an [`Arc`] given to
+    /// [`shared`], the byte content is copied into a uniquely owned buffer
+    /// (or the allocation is reclaimed, if this is the last reference).
+    /// Otherwise, this is a no-op.
+    ///
+    /// All mutating APIs call this method implicitly; it is only needed to
+    /// force the copy up front, for example before handing out raw pointers
+    /// with [`as_ptr`].
+    ///
+    /// [`shared`]: String::shared
+    /// [`as_ptr`]: String::as_ptr
+    #[inline]
+    pub fn make_unique(&mut self) {
+        self.buf.make_unique();
+    }
+
     #[inline]
     #[must_use]
     pub fn utf8(buf: Vec<u8>) -> Self {
         Self::with_bytes_and_encoding(buf, Encoding::Utf8)
     }
 
+    /// Construct a new UTF-8 `String` from the given bytes, replacing invalid
+    /// UTF-8 byte sequences with `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// If the bytes are already valid UTF-8, the buffer is reused as-is
+    /// without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8_lossy(b"abc\xFFxyz".to_vec());
+    /// assert_eq!(s, "abc\u{FFFD}xyz");
+    /// assert!(s.is_valid_encoding());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn utf8_lossy(buf: Vec<u8>) -> Self {
+        let scrubbed = match alloc::string::String::from_utf8_lossy(&buf) {
+            Cow::Owned(scrubbed) => Some(scrubbed.into_bytes()),
+            Cow::Borrowed(_) => None,
+        };
+        if let Some(scrubbed) = scrubbed {
+            Self::utf8(scrubbed)
+        } else {
+            Self::utf8(buf)
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn ascii(buf: Vec<u8>) -> Self {
@@ -824,6 +1184,31 @@ impl String {
         self.encoding = encoding;
     }
 
+    /// Retag this `String` with the given [`Encoding`] without validating or
+    /// modifying the byte content.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#force_encoding`]. To convert the byte content to the new
+    /// encoding, use [`transcode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::{Encoding, String};
+    ///
+    /// let mut s = String::binary(b"abc\xFF".to_vec());
+    /// s.force_encoding(Encoding::Utf8);
+    /// assert_eq!(s.encoding(), Encoding::Utf8);
+    /// assert!(!s.is_valid_encoding());
+    /// ```
+    ///
+    /// [`transcode`]: Self::transcode
+    /// [`String#force_encoding`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-force_encoding
+    #[inline]
+    pub fn force_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+    }
+
     /// Shortens the string, keeping the first `len` bytes and dropping the
     /// rest.
     ///
@@ -1025,7 +1410,7 @@ impl String {
         // See: https://doc.rust-lang.org/1.48.0/src/alloc/vec.rs.html#399-402
         //
         // https://github.com/rust-lang/rust/issues/65816
-        let mut me = ManuallyDrop::new(self.buf);
+        let mut me = ManuallyDrop::new(self.buf.into_vec());
         (me.as_mut_ptr(), me.len(), me.capacity())
     }
 
@@ -1049,7 +1434,7 @@ impl String {
     #[inline]
     #[must_use]
     pub fn into_vec(self) -> Vec<u8> {
-        self.buf
+        self.buf.into_vec()
     }
 
     /// Converts the vector into `Box<[u8]>`.
@@ -1082,7 +1467,7 @@ impl String {
     #[inline]
     #[must_use]
     pub fn into_boxed_slice(self) -> Box<[u8]> {
-        self.buf.into_boxed_slice()
+        self.buf.into_vec().into_boxed_slice()
     }
 
     /// Returns the number of bytes the string can hold without reallocating.
@@ -1320,6 +1705,65 @@ impl String {
     pub fn shrink_to(&mut self, min_capacity: usize) {
         self.buf.shrink_to(min_capacity);
     }
+
+    /// Shortens the string, keeping the first `char_count` characters and
+    /// dropping the rest.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// the string is truncated at a character boundary: multi-byte Unicode
+    /// characters are length 1 and invalid UTF-8 bytes are length 1, so a
+    /// multi-byte sequence is never cut in half. For `String`s with
+    /// [ASCII encoding] or [binary encoding], this function is equivalent to
+    /// [`truncate`].
+    ///
+    /// If `char_count` is greater than the string's current character length,
+    /// this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::utf8("a💎b".as_bytes().to_vec());
+    /// s.truncate_chars(2);
+    /// assert_eq!(s, "a💎");
+    /// s.truncate_chars(1);
+    /// assert_eq!(s, "a");
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`truncate`]: Self::truncate
+    #[inline]
+    pub fn truncate_chars(&mut self, char_count: usize) {
+        match self.encoding {
+            Encoding::Ascii | Encoding::Binary => self.buf.truncate(char_count),
+            Encoding::Utf8 => {
+                if let Some(byte_offset) = conventionally_utf8_char_index_to_byte_offset(&self.buf, char_count) {
+                    self.buf.truncate(byte_offset);
+                }
+            }
+        }
+    }
+
+    /// Removes the last byte from the string and returns it, or [`None`] if
+    /// the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::from("ab");
+    /// assert_eq!(s.pop_byte(), Some(b'b'));
+    /// assert_eq!(s.pop_byte(), Some(b'a'));
+    /// assert_eq!(s.pop_byte(), None);
+    /// ```
+    #[inline]
+    pub fn pop_byte(&mut self) -> Option<u8> {
+        self.buf.pop()
+    }
 }
 
 // Indexing
@@ -1352,38 +1796,223 @@ impl String {
         self.buf.get(index)
     }
 
-    /// Returns a mutable reference to a byte or sub-byteslice depending on the
-    /// type of index (see [`get`]) or [`None`] if the index is out of bounds.
+    /// Returns a sub-byteslice with Ruby `String#byteslice` semantics.
+    ///
+    /// A negative `start` counts backward from the end of the string. A
+    /// `start` equal to the byte length selects an empty slice. A `len` of
+    /// [`None`] selects through the end of the string and a `len` past the end
+    /// of the string is clamped. A negative `len` returns [`None`].
+    ///
+    /// This function is a pure byte operation and behaves identically for all
+    /// encodings.
     ///
     /// # Examples
     ///
     /// ```
     /// use spinoso_string::String;
     ///
-    /// let mut s = String::from("abc");
-    ///
-    /// if let Some(byte) = s.get_mut(1) {
-    ///     *byte = b'x';
-    /// }
-    /// assert_eq!(s, "axc");
+    /// let s = String::from("hello");
+    /// assert_eq!(s.byteslice(1, Some(3)), Some(&b"ell"[..]));
+    /// assert_eq!(s.byteslice(-2, Some(10)), Some(&b"lo"[..]));
+    /// assert_eq!(s.byteslice(1, None), Some(&b"ello"[..]));
+    /// assert_eq!(s.byteslice(5, Some(1)), Some(&b""[..]));
+    /// assert_eq!(s.byteslice(6, Some(1)), None);
+    /// assert_eq!(s.byteslice(-6, Some(1)), None);
+    /// assert_eq!(s.byteslice(1, Some(-1)), None);
     /// ```
-    ///
-    /// [`get`]: Self::get
     #[inline]
     #[must_use]
-    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
-    where
-        I: SliceIndex<[u8]>,
-    {
-        self.buf.get_mut(index)
+    pub fn byteslice(&self, start: i64, len: Option<i64>) -> Option<&'_ [u8]> {
+        let bytesize = self.buf.len();
+        let start = if let Ok(start) = usize::try_from(start) {
+            start
+        } else {
+            // A negative start counts backward from the end of the string.
+            let start = start.checked_neg().and_then(|start| usize::try_from(start).ok())?;
+            bytesize.checked_sub(start)?
+        };
+        if start > bytesize {
+            return None;
+        }
+        let len = match len {
+            None => bytesize - start,
+            Some(len) => usize::try_from(len).ok()?,
+        };
+        // A length past the end of the string is clamped.
+        let end = start.saturating_add(len).min(bytesize);
+        self.buf.get(start..end)
     }
 
-    /// Returns a reference to a byte or sub-byteslice, without doing bounds
-    /// checking.
+    /// Returns a sub-byteslice with Ruby `String#byteslice` range semantics.
     ///
-    /// For a safe alternative see [`get`].
+    /// `start` and `end` resolve like range boundaries in Ruby: negative
+    /// boundaries count backward from the end of the string and `exclusive`
+    /// selects whether the byte at `end` is included. Backward ranges with a
+    /// valid `start` select an empty slice:
     ///
-    /// # Safety
+    /// ```ruby
+    /// [3.0.1] > "hello".byteslice(1..3)
+    /// => "ell"
+    /// [3.0.1] > "hello".byteslice(1...3)
+    /// => "el"
+    /// [3.0.1] > "hello".byteslice(3..1)
+    /// => ""
+    /// ```
+    ///
+    /// This function is a pure byte operation and behaves identically for all
+    /// encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from("hello");
+    /// assert_eq!(s.byteslice_range(1, 3, false), Some(&b"ell"[..]));
+    /// assert_eq!(s.byteslice_range(1, 3, true), Some(&b"el"[..]));
+    /// assert_eq!(s.byteslice_range(1, -1, false), Some(&b"ello"[..]));
+    /// assert_eq!(s.byteslice_range(3, 1, false), Some(&b""[..]));
+    /// assert_eq!(s.byteslice_range(6, 7, false), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn byteslice_range(&self, start: i64, end: i64, exclusive: bool) -> Option<&'_ [u8]> {
+        let bytesize = i64::try_from(self.buf.len()).ok()?;
+        // Resolve the range boundaries to a start and length; the resolved
+        // start is validated by `byteslice`.
+        let resolved_start = if start < 0 { start.checked_add(bytesize)? } else { start };
+        let end = if end < 0 { end.checked_add(bytesize)? } else { end };
+        let len = end.checked_sub(resolved_start)?;
+        let len = if exclusive { len } else { len.checked_add(1)? };
+        // Backward ranges with a valid start select an empty slice.
+        self.byteslice(start, Some(len.max(0)))
+    }
+
+    /// Returns the byte at the given index with Ruby `String#getbyte`
+    /// semantics.
+    ///
+    /// A negative index counts backward from the end of the string. [`None`]
+    /// is returned if the index is out of range.
+    ///
+    /// This function is a pure byte operation and behaves identically for all
+    /// encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from("hello");
+    /// assert_eq!(s.getbyte(0), Some(b'h'));
+    /// assert_eq!(s.getbyte(-1), Some(b'o'));
+    /// assert_eq!(s.getbyte(5), None);
+    /// assert_eq!(s.getbyte(-6), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn getbyte(&self, index: i64) -> Option<u8> {
+        let index = self.resolve_byte_index(index)?;
+        self.buf.get(index).copied()
+    }
+
+    /// Stores `value` at the given index with Ruby `String#setbyte` semantics
+    /// and returns the stored byte.
+    ///
+    /// A negative index counts backward from the end of the string. The value
+    /// is reduced modulo 256 before it is stored, like MRI:
+    ///
+    /// ```ruby
+    /// [3.0.1] > s = "hello"
+    /// => "hello"
+    /// [3.0.1] > s.setbyte(0, 0x248)
+    /// => 584
+    /// [3.0.1] > s
+    /// => "Hello"
+    /// ```
+    ///
+    /// This function is a pure byte operation and behaves identically for all
+    /// encodings.
+    ///
+    /// # Errors
+    ///
+    /// If the index is out of range, an [`IndexOutOfBoundsError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// # fn example() -> Result<(), spinoso_string::IndexOutOfBoundsError> {
+    /// let mut s = String::from("hello");
+    /// assert_eq!(s.setbyte(0, 0x248)?, b'H');
+    /// assert_eq!(s, "Hello");
+    /// assert_eq!(s.setbyte(-1, -1)?, 0xFF);
+    /// assert_eq!(s, &b"Hell\xFF"[..]);
+    /// assert!(s.setbyte(5, 0).is_err());
+    /// assert!(s.setbyte(-6, 0).is_err());
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    pub fn setbyte(&mut self, index: i64, value: i64) -> Result<u8, IndexOutOfBoundsError> {
+        let len = self.buf.len();
+        let cell = self
+            .resolve_byte_index(index)
+            .and_then(|index| self.buf.get_mut(index))
+            .ok_or_else(|| IndexOutOfBoundsError::with_index_and_len(index, len))?;
+        // `rem_euclid` with a positive modulus always returns a non-negative
+        // value less than the modulus, so the conversion cannot fail.
+        let byte = u8::try_from(value.rem_euclid(256)).expect("rem_euclid(256) is in range for u8");
+        *cell = byte;
+        Ok(byte)
+    }
+
+    /// Resolve a possibly negative byte index to a forward offset.
+    ///
+    /// A negative index counts backward from the end of the string. Indexes
+    /// past the end of the string resolve to their out of range offset, which
+    /// callers reject with a failed buffer access.
+    fn resolve_byte_index(&self, index: i64) -> Option<usize> {
+        if let Ok(index) = usize::try_from(index) {
+            Some(index)
+        } else {
+            let index = index.checked_neg().and_then(|index| usize::try_from(index).ok())?;
+            self.buf.len().checked_sub(index)
+        }
+    }
+
+    /// Returns a mutable reference to a byte or sub-byteslice depending on the
+    /// type of index (see [`get`]) or [`None`] if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::from("abc");
+    ///
+    /// if let Some(byte) = s.get_mut(1) {
+    ///     *byte = b'x';
+    /// }
+    /// assert_eq!(s, "axc");
+    /// ```
+    ///
+    /// [`get`]: Self::get
+    #[inline]
+    #[must_use]
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[u8]>,
+    {
+        self.buf.get_mut(index)
+    }
+
+    /// Returns a reference to a byte or sub-byteslice, without doing bounds
+    /// checking.
+    ///
+    /// For a safe alternative see [`get`].
+    ///
+    /// # Safety
     ///
     /// Calling this method with an out-of-bounds index is *[undefined
     /// behavior]* even if the resulting reference is not used.
@@ -1665,7 +2294,7 @@ impl String {
     #[inline]
     #[must_use]
     pub fn is_ascii_only(&self) -> bool {
-        self.buf.is_ascii()
+        self.buf.is_ascii_only()
     }
 
     /// Change the [encoding] of this `String` to [`Encoding::Binary`].
@@ -1722,8 +2351,10 @@ impl String {
         match self.encoding {
             Encoding::Ascii | Encoding::Binary => {
                 if let Some((head, tail)) = self.buf.split_first_mut() {
-                    head.make_ascii_uppercase();
-                    tail.make_ascii_lowercase();
+                    *head = to_ascii_upper(*head);
+                    for byte in tail {
+                        *byte = to_ascii_lower(*byte);
+                    }
                 }
             }
             Encoding::Utf8 => {
@@ -1765,7 +2396,7 @@ impl String {
                         bytes = remainder;
                     }
                 }
-                self.buf = replacement;
+                self.buf = replacement.into();
             }
         }
     }
@@ -1775,7 +2406,9 @@ impl String {
     pub fn make_lowercase(&mut self) {
         match self.encoding {
             Encoding::Ascii | Encoding::Binary => {
-                self.buf.make_ascii_lowercase();
+                for byte in self.buf.iter_mut() {
+                    *byte = to_ascii_lower(*byte);
+                }
             }
             Encoding::Utf8 => {
                 // This allocation assumes that in the common case, lower-casing
@@ -1798,7 +2431,7 @@ impl String {
                         bytes = remainder;
                     }
                 }
-                self.buf = replacement;
+                self.buf = replacement.into();
             }
         }
     }
@@ -1808,7 +2441,9 @@ impl String {
     pub fn make_uppercase(&mut self) {
         match self.encoding {
             Encoding::Ascii | Encoding::Binary => {
-                self.buf.make_ascii_uppercase();
+                for byte in self.buf.iter_mut() {
+                    *byte = to_ascii_upper(*byte);
+                }
             }
             Encoding::Utf8 => {
                 // This allocation assumes that in the common case, upper-casing
@@ -1831,7 +2466,7 @@ impl String {
                         bytes = remainder;
                     }
                 }
-                self.buf = replacement;
+                self.buf = replacement.into();
             }
         }
     }
@@ -1870,6 +2505,186 @@ impl String {
         }
     }
 
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "casecmp")]
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "casecmp")))]
+    pub fn unicode_casecmp_ordering(&self, other: &String, options: CaseFold) -> Option<Ordering> {
+        let left = self.buf.as_slice();
+        let right = other;
+        // If both `String`s are conventionally UTF-8, they must be case
+        // compared using the given case folding strategy. This requires the
+        // `String`s be well-formed UTF-8.
+        if let (Encoding::Utf8, Encoding::Utf8) = (self.encoding, other.encoding) {
+            if let (Ok(left), Ok(right)) = (str::from_utf8(left), str::from_utf8(right)) {
+                // Both slices are UTF-8, compare with the given Unicode case
+                // folding scheme.
+                Some(options.casecmp(left, right))
+            } else {
+                // At least one `String` contains invalid UTF-8 bytes.
+                None
+            }
+        } else {
+            // At least one slice is not conventionally UTF-8, so fallback to
+            // ASCII comparator.
+            Some(focaccia::ascii_casecmp(left, right))
+        }
+    }
+
+    /// Parse this `String` as an integer with Ruby's forgiving conversion
+    /// rules.
+    ///
+    /// Leading ASCII whitespace is ignored, an optional leading sign and base
+    /// prefix (`0b`, `0o`, `0d`, or `0x`) are honored, single underscores may
+    /// separate digits, and parsing stops at the first invalid character. A
+    /// `String` with no leading digits parses to zero.
+    ///
+    /// If `base` is given, digits are parsed in that base, which must be in
+    /// the range `2..=36`. If `base` is [`None`], the base is detected from a
+    /// literal prefix or a bare leading zero and defaults to 10, matching
+    /// `String#to_i` with base 0.
+    ///
+    /// Digit sequences that do not fit in an [`i64`] are reported with
+    /// [`ParsedInteger::Overflow`] so callers can fall back to an arbitrary
+    /// precision parse.
+    ///
+    /// # Panics
+    ///
+    /// If `base` is given and outside the range `2..=36`, this function will
+    /// panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::{ParsedInteger, String};
+    ///
+    /// let s = String::utf8(b"1_2_3asdf".to_vec());
+    /// assert_eq!(s.parse_integer(None), ParsedInteger::Value(123));
+    ///
+    /// let s = String::utf8(b"99 red balloons".to_vec());
+    /// assert_eq!(s.parse_integer(None), ParsedInteger::Value(99));
+    ///
+    /// let s = String::utf8(b"-0x10".to_vec());
+    /// assert_eq!(s.parse_integer(None), ParsedInteger::Value(-16));
+    /// assert_eq!(s.parse_integer(Some(16)), ParsedInteger::Value(-16));
+    /// assert_eq!(s.parse_integer(Some(10)), ParsedInteger::Value(0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn parse_integer(&self, base: Option<u32>) -> ParsedInteger {
+        let radix = match base {
+            None => parse_int::Radix::Detect(10),
+            Some(base) => {
+                assert!((2..=36).contains(&base), "invalid radix {}", base);
+                parse_int::Radix::Fixed(base)
+            }
+        };
+        parse_int::parse(self.buf.as_slice(), radix)
+    }
+
+    /// Parse this `String` as a `Float` with Ruby's conversion rules.
+    ///
+    /// Both modes accept an optional sign, single underscores between digits,
+    /// and an optional exponent.
+    ///
+    /// In lenient mode, this function implements `String#to_f`: leading
+    /// whitespace is ignored, parsing stops at the first invalid character,
+    /// and a `String` with no leading digits parses to `0.0`.
+    ///
+    /// In strict mode, this function implements the string conversion in
+    /// `Kernel#Float`: the entire `String` must be a valid decimal or
+    /// hexadecimal float literal with no surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, if this `String` is not a valid float literal, an
+    /// error is returned whose message quotes the original input with
+    /// invalid UTF-8 bytes hex-escaped. Lenient mode never fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    /// # fn example() -> Result<(), spinoso_string::ParseFloatError> {
+    /// let s = String::utf8(b"  3.14 and the rest".to_vec());
+    /// assert_eq!(s.parse_float(false)?, 3.14);
+    ///
+    /// let s = String::utf8(b"1_2.5e-1".to_vec());
+    /// assert_eq!(s.parse_float(true)?, 1.25);
+    ///
+    /// let s = String::utf8(b"0x1p3".to_vec());
+    /// assert_eq!(s.parse_float(true)?, 8.0);
+    ///
+    /// let s = String::utf8(b"razzmatazz".to_vec());
+    /// assert_eq!(s.parse_float(false)?, 0.0);
+    /// assert!(s.parse_float(true).is_err());
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    #[inline]
+    pub fn parse_float(&self, strict: bool) -> Result<f64, ParseFloatError> {
+        if strict {
+            parse_float::parse_strict(self.buf.as_slice())
+        } else {
+            Ok(parse_float::parse_lenient(self.buf.as_slice()))
+        }
+    }
+
+    /// Parse this `String` as a hexadecimal number, ignoring an optional
+    /// leading `0x` or `0X`.
+    ///
+    /// This function implements `String#hex`. Parsing stops at the first
+    /// invalid character and a `String` with no leading hex digits parses to
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::{ParsedInteger, String};
+    ///
+    /// let s = String::utf8(b"0x0a".to_vec());
+    /// assert_eq!(s.hex(), ParsedInteger::Value(10));
+    ///
+    /// let s = String::utf8(b"-1234".to_vec());
+    /// assert_eq!(s.hex(), ParsedInteger::Value(-4660));
+    ///
+    /// let s = String::utf8(b"wombat".to_vec());
+    /// assert_eq!(s.hex(), ParsedInteger::Value(0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn hex(&self) -> ParsedInteger {
+        parse_int::parse(self.buf.as_slice(), parse_int::Radix::Fixed(16))
+    }
+
+    /// Parse this `String` as an octal number, honoring radix prefixes.
+    ///
+    /// This function implements `String#oct`. Digits are parsed as octal
+    /// unless a `0b`, `0o`, `0d`, or `0x` prefix selects another base.
+    /// Parsing stops at the first invalid character and a `String` with no
+    /// leading digits parses to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::{ParsedInteger, String};
+    ///
+    /// let s = String::utf8(b"0755".to_vec());
+    /// assert_eq!(s.oct(), ParsedInteger::Value(493));
+    ///
+    /// let s = String::utf8(b"0b1010".to_vec());
+    /// assert_eq!(s.oct(), ParsedInteger::Value(10));
+    ///
+    /// let s = String::utf8(b"08".to_vec());
+    /// assert_eq!(s.oct(), ParsedInteger::Value(0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn oct(&self) -> ParsedInteger {
+        parse_int::parse(self.buf.as_slice(), parse_int::Radix::Detect(8))
+    }
+
     /// Centers this `String` in width with the given padding.
     ///
     /// This function returns an iterator that yields [`u8`].
@@ -1935,6 +2750,108 @@ impl String {
         ))
     }
 
+    /// Centers this `String` in width with the given padding, returning a new
+    /// `String`.
+    ///
+    /// The returned `String` has the same byte content as the [`Center`]
+    /// iterator returned by [`center`] and the same [encoding] as the
+    /// receiver. The result is built with a single allocation, which makes
+    /// this function a better fit than the iterator when the caller
+    /// immediately collects into an owned byte buffer.
+    ///
+    /// If the given padding is [`None`], the `String` is padded with an ASCII
+    /// space.
+    ///
+    /// # Errors
+    ///
+    /// If given an empty padding byte string, this function returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    /// # fn example() -> Result<(), spinoso_string::CenterError> {
+    /// let s = String::from("hello");
+    ///
+    /// assert_eq!(s.center_owned(4, None)?, "hello");
+    /// assert_eq!(s.center_owned(20, None)?, "       hello        ");
+    /// assert_eq!(s.center_owned(20, Some(&b"123"[..]))?, "1231231hello12312312");
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    ///
+    /// [`center`]: Self::center
+    /// [encoding]: Self::encoding
+    #[inline]
+    pub fn center_owned(&self, width: usize, padding: Option<&[u8]>) -> Result<Self, CenterError> {
+        let padding = match padding {
+            None => &b" "[..],
+            Some(p) if p.is_empty() => return Err(CenterError::ZeroWidthPadding),
+            Some(p) => p,
+        };
+        let padding_width = width.saturating_sub(self.char_len());
+        let pre_pad = padding_width / 2;
+        let post_pad = (padding_width + 1) / 2;
+        let mut buf = Vec::with_capacity(self.buf.len() + padding_width);
+        buf.extend(padding.iter().copied().cycle().take(pre_pad));
+        buf.extend_from_slice(self.buf.as_slice());
+        buf.extend(padding.iter().copied().cycle().take(post_pad));
+        Ok(Self::with_bytes_and_encoding(buf, self.encoding))
+    }
+
+    /// Return a new `String` containing `count` copies of this `String`,
+    /// preserving its encoding.
+    ///
+    /// This method is used to implement `String#*`. The final byte length is
+    /// computed up front with [`checked_mul`] and the backing buffer is
+    /// allocated once; repeating with a huge count fails before any allocation
+    /// is attempted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    /// # fn example() -> Result<(), spinoso_string::RepeatError> {
+    /// let s = String::utf8(b"abc".to_vec());
+    /// assert_eq!(s.repeat(3)?, "abcabcabc");
+    /// assert_eq!(s.repeat(0)?, "");
+    /// assert!(s.repeat(usize::MAX).is_err());
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the resulting byte length overflows the maximum string capacity,
+    /// [`isize::MAX`], a [`RepeatError`] is returned.
+    ///
+    /// [`checked_mul`]: usize::checked_mul
+    #[inline]
+    pub fn repeat(&self, count: usize) -> Result<Self, RepeatError> {
+        let len = self
+            .buf
+            .len()
+            .checked_mul(count)
+            .filter(|&len| isize::try_from(len).is_ok())
+            .ok_or_else(RepeatError::capacity_overflow)?;
+        let mut repeated = Self::with_capacity_and_encoding(len, self.encoding);
+        if len == 0 {
+            return Ok(repeated);
+        }
+        repeated.buf.extend_from_slice(self.buf.as_slice());
+        // Double the copied prefix until one more doubling would exceed the
+        // final length, then top off with the remainder. This performs
+        // `O(log count)` `memcpy` calls instead of `count` appends.
+        while repeated.buf.len() <= len / 2 {
+            repeated.buf.extend_from_within(..);
+        }
+        let remaining = len - repeated.buf.len();
+        repeated.buf.extend_from_within(..remaining);
+        Ok(repeated)
+    }
+
     /// Modifies this `String` in-place with the given record separator removed
     /// from the end of str (if given).
     ///
@@ -1996,6 +2913,41 @@ impl String {
         chomp(self, separator)
     }
 
+    /// Returns the sub-slice of this `String` with the given record separator
+    /// removed from the end, without allocating.
+    ///
+    /// This is the borrowing computation behind [`chomp`]: the returned slice
+    /// is the portion of the buffer that `chomp` would retain, using the same
+    /// separator rules. A [`None`] separator removes one trailing `\n`, `\r`,
+    /// or `\r\n`; an empty separator removes all trailing newlines; an
+    /// explicit separator removes one occurrence.
+    ///
+    /// This method can be used to implement the non-mutating Ruby method
+    /// [`String#chomp`] without cloning the receiver up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"This is a paragraph.\r\n\n\n".to_vec());
+    /// assert_eq!(s.chomped(None), &b"This is a paragraph.\r\n\n"[..]);
+    /// assert_eq!(s.chomped(Some(&b""[..])), &b"This is a paragraph."[..]);
+    ///
+    /// let s = String::utf8(b"This is a paragraph.".to_vec());
+    /// assert_eq!(s.chomped(Some(&b"."[..])), &b"This is a paragraph"[..]);
+    /// assert_eq!(s.chomped(Some(&b"abc"[..])), &b"This is a paragraph."[..]);
+    /// ```
+    ///
+    /// [`chomp`]: Self::chomp
+    /// [`String#chomp`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-chomp
+    #[inline]
+    #[must_use]
+    pub fn chomped(&self, separator: Option<&[u8]>) -> &[u8] {
+        let retain = chomped_len(self.buf.as_slice(), separator);
+        &self.buf.as_slice()[..retain]
+    }
+
     /// Modifies this `String` in-place and removes the last character.
     ///
     /// This method returns a [`bool`] that indicates if this string was modified.
@@ -2034,17 +2986,60 @@ impl String {
     #[inline]
     #[must_use]
     pub fn chop(&mut self) -> bool {
-        if self.buf.is_empty() {
-            return false;
-        }
-        let bytes_to_remove = if self.buf.ends_with(b"\r\n") {
-            2
-        } else if let Encoding::Utf8 = self.encoding {
-            let (ch, size) = bstr::decode_last_utf8(&self.buf);
-            if ch.is_some() {
-                size
-            } else {
-                1
+        let truncate_to = self.chopped_len();
+        let modified = truncate_to != self.buf.len();
+        self.buf.truncate(truncate_to);
+        modified
+    }
+
+    /// Returns the sub-slice of this `String` with the last character removed,
+    /// without allocating.
+    ///
+    /// This is the borrowing computation behind [`chop`]: the returned slice
+    /// is the portion of the buffer that `chop` would retain. If the string
+    /// ends with `\r\n`, both characters are removed. Chopping an empty string
+    /// returns an empty slice.
+    ///
+    /// This method can be used to implement the non-mutating Ruby method
+    /// [`String#chop`] without cloning the receiver up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"This is a paragraph.\r\n".to_vec());
+    /// assert_eq!(s.chopped(), &b"This is a paragraph."[..]);
+    ///
+    /// let s = String::utf8(b"This is a paragraph.".to_vec());
+    /// assert_eq!(s.chopped(), &b"This is a paragraph"[..]);
+    ///
+    /// let s = String::utf8(b"".to_vec());
+    /// assert_eq!(s.chopped(), &b""[..]);
+    /// ```
+    ///
+    /// [`chop`]: Self::chop
+    /// [`String#chop`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-chop
+    #[inline]
+    #[must_use]
+    pub fn chopped(&self) -> &[u8] {
+        &self.buf.as_slice()[..self.chopped_len()]
+    }
+
+    /// Length of the buffer prefix that [`chop`](Self::chop) retains.
+    #[must_use]
+    fn chopped_len(&self) -> usize {
+        if self.buf.is_empty() {
+            return 0;
+        }
+        let bytes_to_remove = if self.buf.ends_with(b"\r\n") {
+            2
+        } else if let Encoding::Utf8 = self.encoding {
+            let (ch, size) = bstr::decode_last_utf8(&self.buf);
+            if ch.is_some() {
+                size
+            } else {
+                1
             }
         } else {
             // `buf` is checked to be non-empty above.
@@ -2052,7 +3047,149 @@ impl String {
         };
         // This subtraction is guaranteed to not panic because we have validated
         // that we're removing a subslice of `buf`.
-        self.buf.truncate(self.buf.len() - bytes_to_remove);
+        self.buf.len() - bytes_to_remove
+    }
+
+    /// Returns true if this `String` begins with any of the given candidate
+    /// prefixes.
+    ///
+    /// Candidates are matched byte-wise, ignoring this `String`'s [encoding].
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#start_with?`] with string arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from("hello");
+    /// assert!(s.starts_with_any([&b"heaven"[..], &b"hell"[..]]));
+    /// assert!(!s.starts_with_any([&b"heaven"[..], &b"paradise"[..]]));
+    /// assert!(!s.starts_with_any([]));
+    /// ```
+    ///
+    /// [encoding]: crate::Encoding
+    /// [`String#start_with?`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-start_with-3F
+    #[inline]
+    #[must_use]
+    pub fn starts_with_any<'a, I>(&self, candidates: I) -> bool
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        let buf = self.buf.as_slice();
+        candidates.into_iter().any(|candidate| buf.starts_with(candidate))
+    }
+
+    /// Returns true if this `String` ends with any of the given candidate
+    /// suffixes.
+    ///
+    /// Candidates are matched byte-wise, ignoring this `String`'s [encoding].
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#end_with?`] with string arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from("hello");
+    /// assert!(s.ends_with_any([&b"punch"[..], &b"llo"[..]]));
+    /// assert!(!s.ends_with_any([&b"punch"[..], &b"judy"[..]]));
+    /// assert!(!s.ends_with_any([]));
+    /// ```
+    ///
+    /// [encoding]: crate::Encoding
+    /// [`String#end_with?`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-end_with-3F
+    #[inline]
+    #[must_use]
+    pub fn ends_with_any<'a, I>(&self, candidates: I) -> bool
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        let buf = self.buf.as_slice();
+        candidates.into_iter().any(|candidate| buf.ends_with(candidate))
+    }
+
+    /// Modifies this `String` in-place and removes the given prefix if
+    /// present.
+    ///
+    /// This function returns `true` if self is modified, `false` otherwise,
+    /// consistent with [`chomp`].
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#delete_prefix!`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::from("hello");
+    /// let modified = s.delete_prefix(b"hel");
+    /// assert!(modified);
+    /// assert_eq!(s, "lo");
+    ///
+    /// let mut s = String::from("hello");
+    /// let modified = s.delete_prefix(b"llo");
+    /// assert!(!modified);
+    /// assert_eq!(s, "hello");
+    /// ```
+    ///
+    /// [`chomp`]: Self::chomp
+    /// [`String#delete_prefix!`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-delete_prefix-21
+    #[inline]
+    #[must_use]
+    pub fn delete_prefix(&mut self, prefix: &[u8]) -> bool {
+        if prefix.is_empty() || !self.buf.starts_with(prefix) {
+            return false;
+        }
+        self.buf.drain(..prefix.len());
+        true
+    }
+
+    /// Modifies this `String` in-place and removes the given suffix if
+    /// present.
+    ///
+    /// This function returns `true` if self is modified, `false` otherwise,
+    /// consistent with [`chomp`].
+    ///
+    /// Suffixes are matched byte-wise, which means multibyte characters in
+    /// [conventionally UTF-8] `String`s are never split: a suffix which ends
+    /// in the middle of a multibyte sequence is a byte mismatch and does not
+    /// match.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#delete_suffix!`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::from("hello");
+    /// let modified = s.delete_suffix(b"llo");
+    /// assert!(modified);
+    /// assert_eq!(s, "he");
+    ///
+    /// let mut s = String::from("hello");
+    /// let modified = s.delete_suffix(b"hel");
+    /// assert!(!modified);
+    /// assert_eq!(s, "hello");
+    /// ```
+    ///
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// [`chomp`]: Self::chomp
+    /// [`String#delete_suffix!`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-delete_suffix-21
+    #[inline]
+    #[must_use]
+    pub fn delete_suffix(&mut self, suffix: &[u8]) -> bool {
+        if suffix.is_empty() || !self.buf.ends_with(suffix) {
+            return false;
+        }
+        let truncate_to = self.buf.len() - suffix.len();
+        self.buf.truncate(truncate_to);
         true
     }
 
@@ -2119,7 +3256,15 @@ impl String {
     /// `String`.
     ///
     /// Returns [`None`] if not found. If the second parameter is present, it
-    /// specifies the position in the string to begin the search.
+    /// specifies the position in the string to begin the search. Negative
+    /// offsets count backward from the end of the string.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// both the offset and the returned position are character indices as
+    /// defined by [`char_len`]: multi-byte Unicode characters are length 1 and
+    /// invalid UTF-8 bytes are length 1. For `String`s with [ASCII encoding]
+    /// or [binary encoding], the offset and returned position are byte
+    /// indices.
     ///
     /// This function can be used to implement [`String#index`].
     ///
@@ -2133,1406 +3278,4566 @@ impl String {
     /// assert_eq!(s.index("lo", None), Some(3));
     /// assert_eq!(s.index("a", None), None);
     /// assert_eq!(s.index("l", Some(3)), Some(3));
+    /// assert_eq!(s.index("l", Some(-2)), Some(3));
+    /// assert_eq!(s.index("l", Some(10)), None);
+    /// ```
+    ///
+    /// Offsets and positions are character indices for [conventionally UTF-8]
+    /// strings:
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("💎abc".as_bytes().to_vec());
+    /// assert_eq!(s.index("abc", None), Some(1));
+    /// assert_eq!(s.index("c", Some(-2)), Some(3));
     /// ```
     ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`char_len`]: Self::char_len
     /// [`String#index`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-index
     #[inline]
     #[must_use]
-    pub fn index<T: AsRef<[u8]>>(&self, needle: T, offset: Option<usize>) -> Option<usize> {
-        fn inner(buf: &[u8], needle: &[u8], offset: Option<usize>) -> Option<usize> {
-            if let Some(offset) = offset {
-                let buf = buf.get(offset..)?;
-                let index = buf.find(needle)?;
-                // This addition is guaranteed not to overflow because the result is
-                // a valid index of the underlying `Vec`.
-                //
-                // `self.buf.len() < isize::MAX` because `self.buf` is a `Vec` and
-                // `Vec` documents `isize::MAX` as its maximum allocation size.
-                Some(index + offset)
-            } else {
-                buf.find(needle)
+    pub fn index<T: AsRef<[u8]>>(&self, needle: T, offset: Option<i64>) -> Option<usize> {
+        fn inner(buf: &[u8], encoding: Encoding, needle: &[u8], offset: Option<i64>) -> Option<usize> {
+            let offset = offset.unwrap_or_default();
+            match encoding {
+                Encoding::Ascii | Encoding::Binary => {
+                    let start = if let Ok(start) = usize::try_from(offset) {
+                        start
+                    } else {
+                        let offset = offset.checked_neg().and_then(|offset| usize::try_from(offset).ok())?;
+                        buf.len().checked_sub(offset)?
+                    };
+                    let index = buf.get(start..)?.find(needle)?;
+                    // This addition is guaranteed not to overflow because the
+                    // result is a valid index of the underlying `Vec`.
+                    //
+                    // `self.buf.len() < isize::MAX` because `self.buf` is a
+                    // `Vec` and `Vec` documents `isize::MAX` as its maximum
+                    // allocation size.
+                    Some(index + start)
+                }
+                Encoding::Utf8 => {
+                    let start = if let Ok(start) = usize::try_from(offset) {
+                        start
+                    } else {
+                        let offset = offset.checked_neg().and_then(|offset| usize::try_from(offset).ok())?;
+                        conventionally_utf8_byte_string_len(buf).checked_sub(offset)?
+                    };
+                    let byte_offset = conventionally_utf8_char_index_to_byte_offset(buf, start)?;
+                    let index = buf.get(byte_offset..)?.find(needle)?;
+                    // Convert the byte index of the match back to a character
+                    // index by counting the characters in the preceding bytes.
+                    Some(conventionally_utf8_byte_string_len(&buf[..byte_offset + index]))
+                }
             }
         }
         // convert to a concrete type and delegate to a single `index` impl
         // to minimize code duplication when monomorphizing.
         let needle = needle.as_ref();
-        inner(&self.buf, needle, offset)
+        inner(&self.buf, self.encoding, needle, offset)
     }
 
+    /// Returns the index of the last occurrence of the given substring in this
+    /// `String`.
+    ///
+    /// Returns [`None`] if not found. If the second parameter is present, it
+    /// specifies the position in the string to end the search — characters
+    /// beyond this point will not be considered. Negative offsets count
+    /// backward from the end of the string.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// both the offset and the returned position are character indices as
+    /// defined by [`char_len`]: multi-byte Unicode characters are length 1 and
+    /// invalid UTF-8 bytes are length 1. For `String`s with [ASCII encoding]
+    /// or [binary encoding], the offset and returned position are byte
+    /// indices.
+    ///
+    /// This function can be used to implement [`String#rindex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from("hello");
+    /// assert_eq!(s.rindex("e", None), Some(1));
+    /// assert_eq!(s.rindex("l", None), Some(3));
+    /// assert_eq!(s.rindex("l", Some(2)), Some(2));
+    /// assert_eq!(s.rindex("l", Some(-2)), Some(3));
+    /// assert_eq!(s.rindex("l", Some(-10)), None);
+    /// assert_eq!(s.rindex("a", None), None);
+    /// ```
+    ///
+    /// Offsets and positions are character indices for [conventionally UTF-8]
+    /// strings:
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("💎abca".as_bytes().to_vec());
+    /// assert_eq!(s.rindex("a", None), Some(4));
+    /// assert_eq!(s.rindex("a", Some(3)), Some(1));
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`char_len`]: Self::char_len
+    /// [`String#rindex`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-rindex
     #[inline]
     #[must_use]
-    pub fn rindex<T: AsRef<[u8]>>(&self, needle: T, offset: Option<usize>) -> Option<usize> {
-        fn inner(buf: &[u8], needle: &[u8], offset: Option<usize>) -> Option<usize> {
-            if let Some(offset) = offset {
-                let end = buf.len().checked_sub(offset).unwrap_or_default();
-                let buf = buf.get(..end)?;
-                buf.rfind(needle)
-            } else {
-                buf.rfind(needle)
+    pub fn rindex<T: AsRef<[u8]>>(&self, needle: T, offset: Option<i64>) -> Option<usize> {
+        fn inner(buf: &[u8], encoding: Encoding, needle: &[u8], offset: Option<i64>) -> Option<usize> {
+            match encoding {
+                Encoding::Ascii | Encoding::Binary => {
+                    let start = match offset {
+                        None => buf.len(),
+                        Some(offset) => {
+                            if let Ok(start) = usize::try_from(offset) {
+                                start.min(buf.len())
+                            } else {
+                                let offset = offset.checked_neg().and_then(|offset| usize::try_from(offset).ok())?;
+                                buf.len().checked_sub(offset)?
+                            }
+                        }
+                    };
+                    // A match may begin at positions up to and including
+                    // `start`, so the search region must extend beyond `start`
+                    // by the length of the needle.
+                    let end = start.saturating_add(needle.len()).min(buf.len());
+                    buf[..end].rfind(needle)
+                }
+                Encoding::Utf8 => {
+                    let byte_offset = match offset {
+                        None => buf.len(),
+                        Some(offset) => {
+                            let start = if let Ok(start) = usize::try_from(offset) {
+                                start
+                            } else {
+                                let offset = offset.checked_neg().and_then(|offset| usize::try_from(offset).ok())?;
+                                conventionally_utf8_byte_string_len(buf).checked_sub(offset)?
+                            };
+                            // Offsets beyond the end of the string saturate to
+                            // the end of the string.
+                            conventionally_utf8_char_index_to_byte_offset(buf, start).unwrap_or(buf.len())
+                        }
+                    };
+                    // A match may begin at positions up to and including the
+                    // offset, so the search region must extend beyond it by
+                    // the length of the needle.
+                    let end = byte_offset.saturating_add(needle.len()).min(buf.len());
+                    let index = buf[..end].rfind(needle)?;
+                    // Convert the byte index of the match back to a character
+                    // index by counting the characters in the preceding bytes.
+                    Some(conventionally_utf8_byte_string_len(&buf[..index]))
+                }
             }
         }
         // convert to a concrete type and delegate to a single `rindex` impl
         // to minimize code duplication when monomorphizing.
         let needle = needle.as_ref();
-        inner(&self.buf, needle, offset)
+        inner(&self.buf, self.encoding, needle, offset)
     }
 
-    /// Returns an iterator that yields a debug representation of the `String`.
+    /// Split this `String` around the first occurrence of the given separator
+    /// into the bytes before the separator, the separator itself, and the
+    /// bytes after it.
     ///
-    /// This iterator produces [`char`] sequences like `"spinoso"` and
-    /// `"invalid-\xFF-utf8"`.
+    /// The split is byte-oriented and the returned slices borrow from this
+    /// `String`. If the separator is not found, the first slice is the whole
+    /// string and the other two slices are empty. An empty separator matches
+    /// at the start of the string.
     ///
-    /// This function can be used to implement the Ruby method
-    /// [`String#inspect`].
+    /// This function can be used to implement [`String#partition`] for literal
+    /// string separators.
     ///
-    /// [`String#inspect`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-inspect:
-    #[inline]
-    pub fn inspect(&self) -> Inspect<'_> {
-        Inspect::from(self.as_slice())
-    }
-
-    /// Returns the Integer ordinal of a one-character string.
+    /// # Examples
     ///
-    /// # Errors
+    /// ```
+    /// use spinoso_string::String;
     ///
-    /// If this `String` is empty, an error is returned.
+    /// let s = String::from("hello, world");
+    /// assert_eq!(s.partition(b"o"), (&b"hell"[..], &b"o"[..], &b", world"[..]));
+    /// assert_eq!(s.partition(b"xyz"), (&b"hello, world"[..], &b""[..], &b""[..]));
+    /// assert_eq!(s.partition(b""), (&b""[..], &b""[..], &b"hello, world"[..]));
+    /// ```
     ///
-    /// If this `String` is [conventionally UTF-8] and the string contents begin
-    /// with an invalid UTF-8 byte sequence, an error is returned.
+    /// Multibyte separators that occur more than once split at the first
+    /// occurrence:
     ///
-    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("a💎b💎c".as_bytes().to_vec());
+    /// assert_eq!(
+    ///     s.partition("💎".as_bytes()),
+    ///     (&b"a"[..], "💎".as_bytes(), "b💎c".as_bytes())
+    /// );
+    /// ```
+    ///
+    /// [`String#partition`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-partition
     #[inline]
-    pub fn ord(&self) -> Result<u32, OrdError> {
-        if let Encoding::Utf8 = self.encoding {
-            let (ch, size) = bstr::decode_utf8(self.buf.as_slice());
-            match ch {
-                // All `char`s are valid `u32`s
-                // https://github.com/rust-lang/rust/blob/1.48.0/library/core/src/char/convert.rs#L12-L20
-                Some(ch) => Ok(u32::from(ch)),
-                None if size == 0 => Err(OrdError::empty_string()),
-                None => Err(OrdError::invalid_utf8_byte_sequence()),
-            }
+    #[must_use]
+    pub fn partition(&self, sep: &[u8]) -> (&[u8], &[u8], &[u8]) {
+        if let Some(start) = self.buf.find(sep) {
+            // This addition is guaranteed not to overflow because the result
+            // is a valid index of the underlying `Vec`.
+            let end = start + sep.len();
+            (&self.buf[..start], &self.buf[start..end], &self.buf[end..])
         } else {
-            let byte = self.buf.get(0).copied().ok_or_else(OrdError::empty_string)?;
-            Ok(u32::from(byte))
+            (&self.buf[..], &[], &[])
         }
     }
-}
 
-// Encoding-aware APIs.
-impl String {
-    /// Returns an iterator over the chars of a `String`.
+    /// Split this `String` around the last occurrence of the given separator
+    /// into the bytes before the separator, the separator itself, and the
+    /// bytes after it.
     ///
-    /// This function is encoding-aware. `String`s with [UTF-8 encoding] are
-    /// only [conventionally UTF-8]. This iterator yields `&[u8]` byte slices
-    /// that correspond to either a valid UTF-8 byte sequence or a single
-    /// invalid UTF-8 byte. For [ASCII encoded] and [binary encoded] strings,
-    /// this iterator yields slices of single bytes.
+    /// The split is byte-oriented and the returned slices borrow from this
+    /// `String`. If the separator is not found, the last slice is the whole
+    /// string and the other two slices are empty. An empty separator matches
+    /// at the end of the string.
     ///
-    /// For UTF-8 encoded strings, the yielded byte slices can be parsed into
-    /// [`char`]s with [`str::from_utf8`] and [`str::chars`].
+    /// This function can be used to implement [`String#rpartition`] for
+    /// literal string separators.
     ///
     /// # Examples
     ///
-    /// Iterating over the characters of a conventionally UTF-8 string:
-    ///
     /// ```
-    /// use core::str;
     /// use spinoso_string::String;
     ///
-    /// let s = String::utf8(b"ab\xF0\x9F\x92\x8E\xFF".to_vec());
-    /// let mut chars = s.chars();
-    /// assert_eq!(chars.next(), Some(&b"a"[..]));
-    /// assert_eq!(chars.next().map(str::from_utf8), Some(Ok("b")));
-    /// assert_eq!(chars.next(), Some(&[0xF0, 0x9F, 0x92, 0x8E][..]));
-    /// assert_eq!(chars.next(), Some(&b"\xFF"[..]));
-    /// assert_eq!(chars.next(), None);
+    /// let s = String::from("hello, world");
+    /// assert_eq!(s.rpartition(b"o"), (&b"hello, w"[..], &b"o"[..], &b"rld"[..]));
+    /// assert_eq!(s.rpartition(b"xyz"), (&b""[..], &b""[..], &b"hello, world"[..]));
+    /// assert_eq!(s.rpartition(b""), (&b"hello, world"[..], &b""[..], &b""[..]));
     /// ```
     ///
-    /// Iterating over the characters of a binary string:
+    /// Multibyte separators that occur more than once split at the last
+    /// occurrence:
     ///
     /// ```
     /// use spinoso_string::String;
     ///
-    /// let s = String::binary("💎".as_bytes().to_vec());
-    /// let mut chars = s.chars();
-    /// assert_eq!(chars.next(), Some(&[0xF0][..]));
-    /// assert_eq!(chars.next(), Some(&[0x9F][..]));
-    /// assert_eq!(chars.next(), Some(&[0x92][..]));
-    /// assert_eq!(chars.next(), Some(&[0x8E][..]));
-    /// assert_eq!(chars.next(), None);
+    /// let s = String::utf8("a💎b💎c".as_bytes().to_vec());
+    /// assert_eq!(
+    ///     s.rpartition("💎".as_bytes()),
+    ///     ("a💎b".as_bytes(), "💎".as_bytes(), &b"c"[..])
+    /// );
     /// ```
     ///
-    /// [UTF-8 encoding]: crate::Encoding::Utf8
-    /// [conventionally UTF-8]: crate::Encoding::Utf8
-    /// [ASCII encoded]: crate::Encoding::Ascii
-    /// [binary encoded]: crate::Encoding::Binary
-    /// [`str::from_utf8`]: core::str::from_utf8
+    /// [`String#rpartition`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-rpartition
     #[inline]
     #[must_use]
-    pub fn chars(&self) -> Chars<'_> {
-        Chars::from(self)
+    pub fn rpartition(&self, sep: &[u8]) -> (&[u8], &[u8], &[u8]) {
+        if let Some(start) = self.buf.rfind(sep) {
+            // This addition is guaranteed not to overflow because the result
+            // is a valid index of the underlying `Vec`.
+            let end = start + sep.len();
+            (&self.buf[..start], &self.buf[start..end], &self.buf[end..])
+        } else {
+            (&[], &[], &self.buf[..])
+        }
     }
 
-    /// Returns an iterator over the `u32` codepoints of a `String`.
+    /// Returns an iterator over the byte offsets of the non-overlapping
+    /// occurrences of the given needle in this `String`.
     ///
-    /// This function is encoding-aware. `String`s with [UTF-8 encoding] are
-    /// only [conventionally UTF-8]. This function only returns `Ok` for
-    /// `String`s with UTF-8 encoding if the underlying bytes in the `String`
-    /// are valid UTF-8. For UTF-8 `String`s, this iterator yields the `u32`
-    /// values of the [`char`]s in the byte string. For [ASCII encoded] and
-    /// [binary encoded] strings, this iterator yields slices of single bytes.
+    /// After each occurrence, the search resumes at the end of the match, so
+    /// occurrences never overlap. An empty needle matches at every character
+    /// boundary, including the end of the string; character boundaries are
+    /// interpreted according to this `String`'s encoding like [`chars`].
     ///
-    /// For UTF-8 encoded strings, the yielded byte slices can be parsed into
-    /// [`char`]s with `.into()`.
+    /// This function can be used to implement the Ruby method [`String#scan`]
+    /// for literal string patterns.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// This function requires the `String` contents to be well-formed with
-    /// respect to its encoding. This function will return an error if the
-    /// `String` has UTF-8 encoding and contains invalid UTF-8 byte sequences.
+    /// ```
+    /// use spinoso_string::String;
     ///
-    /// # Examples
+    /// let s = String::from("mellow yellow");
+    /// assert_eq!(s.matches(b"ello").collect::<Vec<_>>(), [1, 8]);
     ///
-    /// Iterating over the codepoints of a conventionally UTF-8 string:
+    /// // Occurrences do not overlap.
+    /// let s = String::from("aaa");
+    /// assert_eq!(s.matches(b"aa").collect::<Vec<_>>(), [0]);
     ///
+    /// // An empty needle matches at every character boundary.
+    /// let s = String::utf8("a💎".as_bytes().to_vec());
+    /// assert_eq!(s.matches(b"").collect::<Vec<_>>(), [0, 1, 5]);
     /// ```
-    /// use spinoso_string::{CodepointsError, String};
     ///
-    /// # fn example() -> Result<(), spinoso_string::CodepointsError> {
-    /// let s = String::utf8(b"ab\xF0\x9F\x92\x8E\xFF".to_vec());
-    /// assert!(matches!(s.codepoints(), Err(CodepointsError::InvalidUtf8Codepoint)));
+    /// [`chars`]: Self::chars
+    /// [`String#scan`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-scan
+    #[inline]
+    #[must_use]
+    pub fn matches<'a>(&'a self, needle: &'a [u8]) -> Matches<'a> {
+        Matches::new(self, needle)
+    }
+
+    /// Returns the number of non-overlapping occurrences of the given needle
+    /// in this `String`.
+    ///
+    /// This function is equivalent to counting the offsets yielded by
+    /// [`matches`].
+    ///
+    /// # Examples
     ///
-    /// let s = String::utf8("💎".as_bytes().to_vec());
-    /// let mut codepoints = s.codepoints()?;
-    /// assert_eq!(codepoints.next(), Some(u32::from('💎')));
-    /// assert_eq!(codepoints.next(), None);
-    /// # Ok(())
-    /// # }
-    /// # example().unwrap();
     /// ```
+    /// use spinoso_string::String;
     ///
-    /// Iterating over the codepoints of a binary string:
+    /// let s = String::from("mellow yellow");
+    /// assert_eq!(s.count_matches(b"ello"), 2);
+    /// assert_eq!(s.count_matches(b"lemon"), 0);
+    /// ```
+    ///
+    /// [`matches`]: Self::matches
+    #[inline]
+    #[must_use]
+    pub fn count_matches(&self, needle: &[u8]) -> usize {
+        self.matches(needle).count()
+    }
+
+    /// Returns an iterator that yields a debug representation of the `String`.
+    ///
+    /// This iterator produces [`char`] sequences like `"spinoso"` and
+    /// `"invalid-\xFF-utf8"`.
+    ///
+    /// The debug representation is encoding-aware: [conventionally UTF-8]
+    /// strings emit valid multibyte characters raw while [binary] strings
+    /// hex-escape all non-ASCII bytes.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#inspect`].
+    ///
+    /// [conventionally UTF-8]: Encoding::Utf8
+    /// [binary]: Encoding::Binary
+    /// [`String#inspect`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-inspect:
+    #[inline]
+    pub fn inspect(&self) -> Inspect<'_> {
+        Inspect::with_encoding(self.as_slice(), self.encoding)
+    }
+
+    /// Returns an iterator that yields an ASCII-safe, round-trippable
+    /// representation of the `String`.
+    ///
+    /// This iterator produces [`char`] sequences like `"spinoso"` and
+    /// `"\u3042"`. All non-ASCII characters are escaped: valid UTF-8 multibyte
+    /// characters in [conventionally UTF-8] strings are emitted as `\uXXXX` or
+    /// `\u{XXXXX}` escapes and all other non-ASCII bytes are emitted as `\xNN`
+    /// hex escapes. Evaluating the yielded contents as a Ruby `String` literal
+    /// produces a byte-for-byte copy of this `String`.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#dump`].
+    ///
+    /// # Examples
     ///
     /// ```
     /// use spinoso_string::String;
     ///
-    /// # fn example() -> Result<(), spinoso_string::CodepointsError> {
-    /// let s = String::binary("💎".as_bytes().to_vec());
-    /// let mut codepoints = s.codepoints()?;
-    /// assert_eq!(codepoints.next(), Some(0xF0));
-    /// assert_eq!(codepoints.next(), Some(0x9F));
-    /// assert_eq!(codepoints.next(), Some(0x92));
-    /// assert_eq!(codepoints.next(), Some(0x8E));
-    /// assert_eq!(codepoints.next(), None);
-    /// # Ok(())
-    /// # }
-    /// # example().unwrap();
+    /// let s = String::utf8("Ω☃".as_bytes().to_vec());
+    /// let dump = s.dump().collect::<std::string::String>();
+    /// assert_eq!(dump, r#""\u03A9\u2603""#);
     /// ```
     ///
-    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [conventionally UTF-8]: Encoding::Utf8
+    /// [`String#dump`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-dump
+    #[inline]
+    pub fn dump(&self) -> Dump<'_> {
+        Dump::with_encoding(self.as_slice(), self.encoding)
+    }
+
+    /// Returns the Integer ordinal of a one-character string.
+    ///
+    /// # Errors
+    ///
+    /// If this `String` is empty, an error is returned.
+    ///
+    /// If this `String` is [conventionally UTF-8] and the string contents begin
+    /// with an invalid UTF-8 byte sequence, an error is returned.
+    ///
     /// [conventionally UTF-8]: crate::Encoding::Utf8
-    /// [ASCII encoded]: crate::Encoding::Ascii
-    /// [binary encoded]: crate::Encoding::Binary
-    /// [`str::from_utf8`]: core::str::from_utf8
     #[inline]
-    pub fn codepoints(&self) -> Result<Codepoints<'_>, CodepointsError> {
-        Codepoints::try_from(self)
+    pub fn ord(&self) -> Result<u32, OrdError> {
+        if let Encoding::Utf8 = self.encoding {
+            let (ch, size) = bstr::decode_utf8(self.buf.as_slice());
+            match ch {
+                // All `char`s are valid `u32`s
+                // https://github.com/rust-lang/rust/blob/1.48.0/library/core/src/char/convert.rs#L12-L20
+                Some(ch) => Ok(u32::from(ch)),
+                None if size == 0 => Err(OrdError::empty_string()),
+                None => Err(OrdError::invalid_utf8_byte_sequence()),
+            }
+        } else {
+            let byte = self.buf.get(0).copied().ok_or_else(OrdError::empty_string)?;
+            Ok(u32::from(byte))
+        }
     }
 
-    /// Returns the character length of this `String`.
+    /// Returns the successor to this `String` in lexicographic ordering.
     ///
-    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
-    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
-    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
-    /// this function is equivalent to [`len`] and [`bytesize`].
+    /// See [`make_succ`] for a description of the successor algorithm. The
+    /// returned `String` has the same encoding as this `String`.
+    ///
+    /// This function can be used to implement the Ruby methods [`String#succ`]
+    /// and [`String#next`].
     ///
     /// # Examples
     ///
     /// ```
     /// use spinoso_string::String;
     ///
-    /// let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
-    /// assert_eq!(s.char_len(), 5);
-    ///
-    /// let b = String::binary(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
-    /// assert_eq!(b.char_len(), 8);
+    /// assert_eq!(String::from("abcd").succ(), "abce");
+    /// assert_eq!(String::from("THX1138").succ(), "THX1139");
+    /// assert_eq!(String::from("zz").succ(), "aaa");
+    /// assert_eq!(String::from("<<koala>>").succ(), "<<koalb>>");
     /// ```
     ///
-    /// [UTF-8 encoding]: crate::Encoding::Utf8
-    /// [ASCII encoding]: crate::Encoding::Ascii
-    /// [binary encoding]: crate::Encoding::Binary
-    /// [`len`]: Self::len
-    /// [`bytesize`]: Self::bytesize
+    /// [`make_succ`]: Self::make_succ
+    /// [`String#succ`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-succ
+    /// [`String#next`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-next
     #[inline]
     #[must_use]
-    pub fn char_len(&self) -> usize {
-        match self.encoding {
-            Encoding::Ascii | Encoding::Binary => self.buf.len(),
-            Encoding::Utf8 => conventionally_utf8_byte_string_len(self.buf.as_slice()),
-        }
+    pub fn succ(&self) -> Self {
+        let mut succ = self.clone();
+        succ.make_succ();
+        succ
     }
 
-    /// Returns the `index`'th character in the string.
+    /// Modifies this `String` in place to its successor in lexicographic
+    /// ordering.
     ///
-    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
-    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
-    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
-    /// this function is equivalent to [`get`] with a range of length 1.
+    /// The successor is calculated by incrementing characters starting from
+    /// the rightmost ASCII alphanumeric (or the rightmost character if there
+    /// are no alphanumerics) in the string. Incrementing a digit always
+    /// results in another digit, and incrementing a letter results in another
+    /// letter of the same case. Incrementing nonalphanumerics uses the
+    /// underlying character-set's collating sequence.
+    ///
+    /// If the increment generates a "carry", the character to the left of it
+    /// is incremented. This process repeats until there is no carry, adding an
+    /// additional character if necessary.
+    ///
+    /// For [conventionally UTF-8] strings whose rightmost character is a
+    /// multibyte character, the character's codepoint is incremented. The
+    /// successor of [`char::MAX`] is out of range and saturates.
     ///
     /// # Examples
     ///
     /// ```
     /// use spinoso_string::String;
     ///
-    /// let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
-    /// assert_eq!(s.get_char(0), Some(&b"a"[..]));
-    /// assert_eq!(s.get_char(1), Some(&b"b"[..]));
-    /// assert_eq!(s.get_char(2), Some(&b"c"[..]));
-    /// assert_eq!(s.get_char(3), Some("💎".as_bytes()));
-    /// assert_eq!(s.get_char(4), Some(&b"\xFF"[..]));
-    /// assert_eq!(s.get_char(5), None);
+    /// let mut s = String::from("1999zzz");
+    /// s.make_succ();
+    /// assert_eq!(s, "2000aaa");
     ///
-    /// let b = String::binary(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
-    /// assert_eq!(b.get_char(0), Some(&b"a"[..]));
-    /// assert_eq!(b.get_char(1), Some(&b"b"[..]));
-    /// assert_eq!(b.get_char(2), Some(&b"c"[..]));
-    /// assert_eq!(b.get_char(3), Some(&b"\xF0"[..]));
-    /// assert_eq!(b.get_char(4), Some(&b"\x9F"[..]));
-    /// assert_eq!(b.get_char(5), Some(&b"\x92"[..]));
-    /// assert_eq!(b.get_char(6), Some(&b"\x8E"[..]));
-    /// assert_eq!(b.get_char(7), Some(&b"\xFF"[..]));
-    /// assert_eq!(b.get_char(8), None);
+    /// let mut s = String::from("ZZZ9999");
+    /// s.make_succ();
+    /// assert_eq!(s, "AAAA0000");
     /// ```
     ///
-    /// [UTF-8 encoding]: crate::Encoding::Utf8
-    /// [ASCII encoding]: crate::Encoding::Ascii
-    /// [binary encoding]: crate::Encoding::Binary
-    /// [`get`]: Self::get
-    #[inline]
-    #[must_use]
-    pub fn get_char(&self, index: usize) -> Option<&'_ [u8]> {
-        // `Vec` has a max allocation size of `isize::MAX`. For a `Vec<u8>` like
-        // the one in `String` where the `size_of::<u8>() == 1`, the max length
-        // is `isize::MAX`. This checked add short circuits with `None` if we
-        // are given `usize::MAX` as an index, which we could never slice.
-        let end = index.checked_add(1)?;
-        match self.encoding {
-            // For ASCII and binary encodings, all character operations assume
-            // characters are exactly one byte, so we can fallback to byte
-            // slicing.
-            Encoding::Ascii | Encoding::Binary => self.buf.get(index..end),
-            Encoding::Utf8 => {
-                // Fast path rejection for indexes beyond bytesize, which is
-                // cheap to retrieve.
-                if index >= self.len() {
-                    return None;
-                }
-                // Fast path for trying to treat the conventionally UTF-8 string
-                // as entirely ASCII.
-                //
-                // If the string is either all ASCII or all ASCII for a prefix
-                // of the string that contains the range we wish to slice,
-                // fallback to byte slicing as in the ASCII and binary fast path.
-                let consumed = match self.buf.find_non_ascii_byte() {
-                    None => return self.buf.get(index..end),
-                    Some(idx) if idx >= end => return self.buf.get(index..end),
-                    Some(idx) => idx,
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    pub fn make_succ(&mut self) {
+        // The successor to the empty string is the empty string.
+        //
+        // ```
+        // [3.0.1] > "".succ
+        // => ""
+        // ```
+        if self.buf.is_empty() {
+            return;
+        }
+
+        // ASCII alphanumerics are single bytes in every encoding and never
+        // appear within a multibyte UTF-8 sequence, so a byte scan is
+        // sufficient to find them.
+        if let Some(last_alnum) = self.buf.iter().rposition(u8::is_ascii_alphanumeric) {
+            let mut index = last_alnum;
+            loop {
+                let (replacement, carry) = match self.buf[index] {
+                    b'9' => (b'0', true),
+                    b'z' => (b'a', true),
+                    b'Z' => (b'A', true),
+                    byte => (byte + 1, false),
                 };
-                let mut slice = &self.buf[consumed..];
-                // Count of "characters" remaining until the `index`th character.
-                let mut remaining = index - consumed;
-                // This loop will terminate when either:
-                //
-                // - It counts `index` number of characters.
-                // - It consumes the entire slice when scanning for the
-                //   `index`th character.
+                self.buf[index] = replacement;
+                if !carry {
+                    return;
+                }
+                // The carry propagates to the closest alphanumeric to the
+                // left, skipping any nonalphanumerics in between.
                 //
-                // The loop will advance by at least one byte every iteration.
-                loop {
-                    match bstr::decode_utf8(slice) {
-                        // If we've run out of slice while trying to find the
-                        // `index`th character, the lookup fails and we return `nil`.
-                        (_, 0) => return None,
-
-                        // The next two arms mean we've reached the `index`th
-                        // character. Either return the next valid UTF-8
-                        // character byte slice or, if the next bytes are an
-                        // invalid UTF-8 sequence, the next byte.
-                        (Some(_), size) if remaining == 0 => return Some(&slice[..size]),
-                        // Size is guaranteed to be positive per the first arm
-                        // which means this slice operation will not panic.
-                        (None, _) if remaining == 0 => return Some(&slice[..1]),
-
-                        // We found a single UTF-8 encoded characterk keep track
-                        // of the count and advance the substring to continue
-                        // decoding.
-                        (Some(_), size) => {
-                            slice = &slice[size..];
-                            remaining -= 1;
-                        }
+                // ```
+                // [3.0.1] > "1.9".succ
+                // => "2.0"
+                // ```
+                if let Some(prev_alnum) = self.buf[..index].iter().rposition(u8::is_ascii_alphanumeric) {
+                    index = prev_alnum;
+                } else {
+                    // The carry has fallen off the leftmost alphanumeric, so
+                    // an additional character of its class is inserted before
+                    // it.
+                    //
+                    // ```
+                    // [3.0.1] > "99".succ
+                    // => "100"
+                    // [3.0.1] > "zz".succ
+                    // => "aaa"
+                    // [3.0.1] > "Zz".succ
+                    // => "AAa"
+                    // ```
+                    let additional = match self.buf[index] {
+                        b'0' => b'1',
+                        b'a' => b'a',
+                        _ => b'A',
+                    };
+                    self.buf.insert(index, additional);
+                    return;
+                }
+            }
+        }
 
-                        // The next two arms handle the case where we have
-                        // encountered an invalid UTF-8 byte sequence.
-                        //
-                        // In this case, `decode_utf8` will return slices whose
-                        // length is `1..=3`. The length of this slice is the
-                        // number of "characters" we can advance the loop by.
-                        //
-                        // If the invalid UTF-8 sequence contains more bytes
-                        // than we have remaining to get to the `index`th char,
-                        // then the target character is inside the invalid UTF-8
-                        // sequence.
-                        (None, size) if remaining < size => return Some(&slice[remaining..=remaining]),
-                        // If there are more characters remaining than the number
-                        // of bytes yielded in the invalid UTF-8 byte sequence,
-                        // count `size` bytes and advance the slice to continue
-                        // decoding.
-                        (None, size) => {
-                            slice = &slice[size..];
-                            remaining -= size;
-                        }
+        // There are no alphanumerics in the string. For conventionally UTF-8
+        // strings ending in a valid multibyte character, increment the last
+        // character's codepoint.
+        if let Encoding::Utf8 = self.encoding {
+            let last_char_start = self
+                .buf
+                .char_indices()
+                .last()
+                .map(|(start, _, _)| start)
+                .unwrap_or_default();
+            if let (Some(ch), size) = bstr::decode_utf8(&self.buf[last_char_start..]) {
+                if size > 1 {
+                    // Skip over unassigned codepoints like the surrogate
+                    // range. The successor of `char::MAX` is out of range and
+                    // saturates.
+                    let next = (u32::from(ch) + 1..=u32::from(char::MAX)).find_map(char::from_u32);
+                    if let Some(next) = next {
+                        self.buf.truncate(last_char_start);
+                        let mut enc = [0; 4];
+                        self.buf.extend_from_slice(next.encode_utf8(&mut enc).as_bytes());
                     }
+                    return;
                 }
             }
         }
+
+        // Otherwise, increment the rightmost byte. A `0xFF` byte carries into
+        // the byte to its left; a carry off the beginning of the string
+        // prepends a `0x01` byte.
+        //
+        // ```
+        // [3.0.1] > "***".succ
+        // => "**+"
+        // [3.0.1] > "\xFF".succ
+        // => "\x01\x00"
+        // ```
+        let mut index = self.buf.len() - 1;
+        loop {
+            let byte = self.buf[index];
+            if let Some(incremented) = byte.checked_add(1) {
+                self.buf[index] = incremented;
+                return;
+            }
+            self.buf[index] = 0x00;
+            if index == 0 {
+                self.buf.insert(0, 0x01);
+                return;
+            }
+            index -= 1;
+        }
     }
 
-    /// Returns a substring of characters in the string.
+    /// Returns an iterator over the `String`s from this `String` through
+    /// `last` in lexicographic ordering by repeated [successor].
     ///
-    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
-    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
-    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
-    /// this function is equivalent to [`get`] with a range.
+    /// Pairs of all ASCII digit endpoints count up numerically, padding the
+    /// yielded values with leading zeros to the width of this `String`, and
+    /// pairs of single ASCII character endpoints count up bytewise. All other
+    /// endpoints are stepped with [`succ`] until the yielded value compares
+    /// equal to `last` or grows longer than `last`, so the iteration
+    /// terminates even for endpoints which are not reachable by successor.
+    ///
+    /// If `exclusive` is true, `last` itself is not yielded. The yielded
+    /// `String`s have the same encoding as this `String`.
+    ///
+    /// This function can be used to implement the Ruby method [`String#upto`]
+    /// and `Range#each` for `String` ranges.
     ///
     /// # Examples
     ///
     /// ```
-    /// use core::ops::Range;
     /// use spinoso_string::String;
     ///
-    /// let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
-    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  1 }), Some(&b"a"[..]));
-    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  3 }), Some(&b"abc"[..]));
-    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  4 }), Some("abc💎".as_bytes()));
-    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  5 }), Some(&b"abc\xF0\x9F\x92\x8E\xFF"[..]));
-    /// assert_eq!(s.get_char_slice(Range { start:  3, end: 10 }), Some(&b"\xF0\x9F\x92\x8E\xFF"[..]));
-    /// assert_eq!(s.get_char_slice(Range { start:  4, end: 10 }), Some(&b"\xFF"[..]));
-    /// assert_eq!(s.get_char_slice(Range { start: 10, end: 15 }), None);
-    /// assert_eq!(s.get_char_slice(Range { start: 15, end: 10 }), None);
-    /// assert_eq!(s.get_char_slice(Range { start: 15, end:  1 }), None);
-    /// assert_eq!(s.get_char_slice(Range { start:  4, end:  1 }), Some(&b""[..]));
+    /// let s = String::from("a8");
+    /// assert_eq!(s.upto(b"b1", false).collect::<Vec<_>>(), ["a8", "a9", "b0", "b1"]);
+    /// assert_eq!(s.upto(b"a9", true).collect::<Vec<_>>(), ["a8"]);
+    ///
+    /// let s = String::from("9");
+    /// assert_eq!(s.upto(b"11", false).collect::<Vec<_>>(), ["9", "10", "11"]);
+    ///
+    /// let s = String::from("az");
+    /// assert_eq!(s.upto(b"ba", false).collect::<Vec<_>>(), ["az", "ba"]);
+    ///
+    /// let s = String::from("zz");
+    /// assert_eq!(s.upto(b"aa", false).count(), 0);
+    /// ```
+    ///
+    /// [successor]: Self::succ
+    /// [`succ`]: Self::succ
+    /// [`String#upto`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-upto
+    #[inline]
+    #[must_use]
+    pub fn upto<'a>(&'a self, last: &'a [u8], exclusive: bool) -> Upto<'a> {
+        Upto::new(self, last, exclusive)
+    }
+}
+
+// Encoding-aware APIs.
+impl String {
+    /// Returns an iterator over the chars of a `String`.
+    ///
+    /// This function is encoding-aware. `String`s with [UTF-8 encoding] are
+    /// only [conventionally UTF-8]. This iterator yields `&[u8]` byte slices
+    /// that correspond to either a valid UTF-8 byte sequence or a single
+    /// invalid UTF-8 byte. For [ASCII encoded] and [binary encoded] strings,
+    /// this iterator yields slices of single bytes.
+    ///
+    /// For UTF-8 encoded strings, the yielded byte slices can be parsed into
+    /// [`char`]s with [`str::from_utf8`] and [`str::chars`].
+    ///
+    /// # Examples
+    ///
+    /// Iterating over the characters of a conventionally UTF-8 string:
+    ///
+    /// ```
+    /// use core::str;
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"ab\xF0\x9F\x92\x8E\xFF".to_vec());
+    /// let mut chars = s.chars();
+    /// assert_eq!(chars.next(), Some(&b"a"[..]));
+    /// assert_eq!(chars.next().map(str::from_utf8), Some(Ok("b")));
+    /// assert_eq!(chars.next(), Some(&[0xF0, 0x9F, 0x92, 0x8E][..]));
+    /// assert_eq!(chars.next(), Some(&b"\xFF"[..]));
+    /// assert_eq!(chars.next(), None);
+    /// ```
+    ///
+    /// Iterating over the characters of a binary string:
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::binary("💎".as_bytes().to_vec());
+    /// let mut chars = s.chars();
+    /// assert_eq!(chars.next(), Some(&[0xF0][..]));
+    /// assert_eq!(chars.next(), Some(&[0x9F][..]));
+    /// assert_eq!(chars.next(), Some(&[0x92][..]));
+    /// assert_eq!(chars.next(), Some(&[0x8E][..]));
+    /// assert_eq!(chars.next(), None);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// [ASCII encoded]: crate::Encoding::Ascii
+    /// [binary encoded]: crate::Encoding::Binary
+    /// [`str::from_utf8`]: core::str::from_utf8
+    #[inline]
+    #[must_use]
+    pub fn chars(&self) -> Chars<'_> {
+        Chars::from(self)
+    }
+
+    /// Returns an iterator over the chars of a `String` and their indices.
+    ///
+    /// The iterator yields `(usize, usize, &[u8])` tuples of the char index,
+    /// the byte offset, and the byte slice of each char. Chars are segmented
+    /// like [`chars`]: for `String`s with [UTF-8 encoding], a char is either a
+    /// valid UTF-8 byte sequence or a single invalid UTF-8 byte; for
+    /// [ASCII encoded] and [binary encoded] strings, every byte is a char and
+    /// the byte offset equals the char index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"a\xF0\x9F\x92\x8E\xFF".to_vec());
+    /// let mut char_indices = s.char_indices();
+    /// assert_eq!(char_indices.next(), Some((0, 0, &b"a"[..])));
+    /// assert_eq!(char_indices.next(), Some((1, 1, &[0xF0, 0x9F, 0x92, 0x8E][..])));
+    /// assert_eq!(char_indices.next(), Some((2, 5, &b"\xFF"[..])));
+    /// assert_eq!(char_indices.next(), None);
+    /// ```
+    ///
+    /// The iterator is double-ended:
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("ab💎".as_bytes().to_vec());
+    /// let mut char_indices = s.char_indices();
+    /// assert_eq!(char_indices.next_back(), Some((2, 2, "💎".as_bytes())));
+    /// assert_eq!(char_indices.next_back(), Some((1, 1, &b"b"[..])));
+    /// assert_eq!(char_indices.next(), Some((0, 0, &b"a"[..])));
+    /// assert_eq!(char_indices.next(), None);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoded]: crate::Encoding::Ascii
+    /// [binary encoded]: crate::Encoding::Binary
+    /// [`chars`]: Self::chars
+    #[inline]
+    #[must_use]
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        CharIndices::from(self)
+    }
+
+    /// Returns an iterator over the `u32` codepoints of a `String`.
+    ///
+    /// This function is encoding-aware. `String`s with [UTF-8 encoding] are
+    /// only [conventionally UTF-8]. This function only returns `Ok` for
+    /// `String`s with UTF-8 encoding if the underlying bytes in the `String`
+    /// are valid UTF-8. For UTF-8 `String`s, this iterator yields the `u32`
+    /// values of the [`char`]s in the byte string. For [ASCII encoded] and
+    /// [binary encoded] strings, this iterator yields slices of single bytes.
+    ///
+    /// For UTF-8 encoded strings, the yielded byte slices can be parsed into
+    /// [`char`]s with `.into()`.
+    ///
+    /// # Errors
+    ///
+    /// This function requires the `String` contents to be well-formed with
+    /// respect to its encoding. This function will return an error if the
+    /// `String` has UTF-8 encoding and contains invalid UTF-8 byte sequences.
+    ///
+    /// # Examples
+    ///
+    /// Iterating over the codepoints of a conventionally UTF-8 string:
+    ///
+    /// ```
+    /// use spinoso_string::{CodepointsError, String};
+    ///
+    /// # fn example() -> Result<(), spinoso_string::CodepointsError> {
+    /// let s = String::utf8(b"ab\xF0\x9F\x92\x8E\xFF".to_vec());
+    /// assert!(matches!(s.codepoints(), Err(CodepointsError::InvalidUtf8Codepoint)));
+    ///
+    /// let s = String::utf8("💎".as_bytes().to_vec());
+    /// let mut codepoints = s.codepoints()?;
+    /// assert_eq!(codepoints.next(), Some(u32::from('💎')));
+    /// assert_eq!(codepoints.next(), None);
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    ///
+    /// Iterating over the codepoints of a binary string:
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// # fn example() -> Result<(), spinoso_string::CodepointsError> {
+    /// let s = String::binary("💎".as_bytes().to_vec());
+    /// let mut codepoints = s.codepoints()?;
+    /// assert_eq!(codepoints.next(), Some(0xF0));
+    /// assert_eq!(codepoints.next(), Some(0x9F));
+    /// assert_eq!(codepoints.next(), Some(0x92));
+    /// assert_eq!(codepoints.next(), Some(0x8E));
+    /// assert_eq!(codepoints.next(), None);
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// [ASCII encoded]: crate::Encoding::Ascii
+    /// [binary encoded]: crate::Encoding::Binary
+    /// [`str::from_utf8`]: core::str::from_utf8
+    #[inline]
+    pub fn codepoints(&self) -> Result<Codepoints<'_>, CodepointsError> {
+        Codepoints::try_from(self)
+    }
+
+    /// Returns an iterator over the extended grapheme clusters of a `String`.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// this iterator yields `&[u8]` byte slices that correspond to either a
+    /// single [extended grapheme cluster] or a single invalid UTF-8 byte.
+    /// `String`s with UTF-8 encoding are only [conventionally UTF-8] and each
+    /// byte in an invalid UTF-8 byte sequence is yielded on its own. For
+    /// [ASCII encoded] and [binary encoded] strings, this iterator yields
+    /// slices of single bytes.
+    ///
+    /// # Examples
+    ///
+    /// Iterating over the grapheme clusters of a conventionally UTF-8 string:
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("ae\u{301}\r\n".as_bytes().to_vec());
+    /// let mut graphemes = s.graphemes();
+    /// assert_eq!(graphemes.next(), Some(&b"a"[..]));
+    /// assert_eq!(graphemes.next(), Some("e\u{301}".as_bytes()));
+    /// assert_eq!(graphemes.next(), Some(&b"\r\n"[..]));
+    /// assert_eq!(graphemes.next(), None);
+    /// ```
+    ///
+    /// Iterating over the grapheme clusters of a binary string:
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::binary("e\u{301}".as_bytes().to_vec());
+    /// let mut graphemes = s.graphemes();
+    /// assert_eq!(graphemes.next(), Some(&b"e"[..]));
+    /// assert_eq!(graphemes.next(), Some(&b"\xCC"[..]));
+    /// assert_eq!(graphemes.next(), Some(&b"\x81"[..]));
+    /// assert_eq!(graphemes.next(), None);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// [ASCII encoded]: crate::Encoding::Ascii
+    /// [binary encoded]: crate::Encoding::Binary
+    /// [extended grapheme cluster]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+    #[inline]
+    #[must_use]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes::from(self)
+    }
+
+    /// Returns the character length of this `String`.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
+    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
+    /// this function is equivalent to [`len`] and [`bytesize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
+    /// assert_eq!(s.char_len(), 5);
+    ///
+    /// let b = String::binary(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
+    /// assert_eq!(b.char_len(), 8);
     /// ```
     ///
     /// [UTF-8 encoding]: crate::Encoding::Utf8
     /// [ASCII encoding]: crate::Encoding::Ascii
     /// [binary encoding]: crate::Encoding::Binary
-    /// [`get`]: Self::get
+    /// [`len`]: Self::len
+    /// [`bytesize`]: Self::bytesize
     #[inline]
     #[must_use]
-    pub fn get_char_slice(&self, range: Range<usize>) -> Option<&'_ [u8]> {
-        let Range { start, end } = range;
-
-        // Fast path the lookup if the end of the range is before the start.
-        if end < start {
-            // Yes, these types of ranges are allowed and they return `""`.
-            //
-            // ```
-            // [3.0.1] > "aaa"[1..0]
-            // => ""
-            // [3.0.1] > "aaa"[2..0]
-            // => ""
-            // [3.0.1] > "aaa"[2..1]
-            // => ""
-            // [3.0.1] > "aaa"[3..0]
-            // => ""
-            // [3.0.1] > "💎🦀😅"[2..1]
-            // => ""
-            // [3.0.1] > "💎🦀😅"[3..0]
-            // => ""
-            // ```
-            //
-            // but only if `start` is within the string.
-            //
-            // ```
-            // [3.0.1] > "aaa"[10..4]
-            // => nil
-            // [3.0.1] > "aaa"[10..4]
-            // => nil
-            // [3.0.1] > "aaa"[10..0]
-            // => nil
-            // [3.0.1] > "💎🦀😅"[10..4]
-            // => nil
-            // [3.0.1] > "💎🦀😅"[10..0]
-            // => nil
-            // [3.0.1] > "💎🦀😅"[6..0]
-            // => nil
-            // [3.0.1] > "💎🦀😅"[4..0]
-            // => nil
-            // ```
-            //
-            // attempt to short-circuit with a cheap len retrieval
-            if start > self.len() || start > self.char_len() {
-                return None;
-            }
-            return Some(&[]);
+    pub fn char_len(&self) -> usize {
+        match self.encoding {
+            Encoding::Ascii | Encoding::Binary => self.buf.len(),
+            // ASCII-only content has one char per byte, which skips the
+            // UTF-8 scan when the ASCII-ness cache is warm.
+            Encoding::Utf8 if self.buf.is_ascii_only() => self.buf.len(),
+            Encoding::Utf8 => conventionally_utf8_byte_string_len(self.buf.as_slice()),
         }
+    }
+
+    /// Returns the grapheme cluster length of this `String`.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// multi-character [extended grapheme clusters] are length 1 and invalid
+    /// UTF-8 bytes are length 1. For `String`s with [ASCII encoding] or
+    /// [binary encoding], this function is equivalent to [`len`] and
+    /// [`bytesize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("e\u{301}\r\n\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}".as_bytes().to_vec());
+    /// assert_eq!(s.grapheme_len(), 3);
+    ///
+    /// let b = String::binary("e\u{301}".as_bytes().to_vec());
+    /// assert_eq!(b.grapheme_len(), 3);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [extended grapheme clusters]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries
+    /// [`len`]: Self::len
+    /// [`bytesize`]: Self::bytesize
+    #[inline]
+    #[must_use]
+    pub fn grapheme_len(&self) -> usize {
+        match self.encoding {
+            Encoding::Ascii | Encoding::Binary => self.buf.len(),
+            Encoding::Utf8 => {
+                let buf = self.buf.as_slice();
+                buf.grapheme_indices()
+                    .map(|(start, end, _)| {
+                        let grapheme = &buf[start..end];
+                        if str::from_utf8(grapheme).is_ok() {
+                            1
+                        } else {
+                            // Each byte in an invalid UTF-8 byte sequence is
+                            // one grapheme cluster.
+                            end - start
+                        }
+                    })
+                    .sum()
+            }
+        }
+    }
+
+    /// Returns the `index`'th character in the string.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
+    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
+    /// this function is equivalent to [`get`] with a range of length 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
+    /// assert_eq!(s.get_char(0), Some(&b"a"[..]));
+    /// assert_eq!(s.get_char(1), Some(&b"b"[..]));
+    /// assert_eq!(s.get_char(2), Some(&b"c"[..]));
+    /// assert_eq!(s.get_char(3), Some("💎".as_bytes()));
+    /// assert_eq!(s.get_char(4), Some(&b"\xFF"[..]));
+    /// assert_eq!(s.get_char(5), None);
+    ///
+    /// let b = String::binary(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
+    /// assert_eq!(b.get_char(0), Some(&b"a"[..]));
+    /// assert_eq!(b.get_char(1), Some(&b"b"[..]));
+    /// assert_eq!(b.get_char(2), Some(&b"c"[..]));
+    /// assert_eq!(b.get_char(3), Some(&b"\xF0"[..]));
+    /// assert_eq!(b.get_char(4), Some(&b"\x9F"[..]));
+    /// assert_eq!(b.get_char(5), Some(&b"\x92"[..]));
+    /// assert_eq!(b.get_char(6), Some(&b"\x8E"[..]));
+    /// assert_eq!(b.get_char(7), Some(&b"\xFF"[..]));
+    /// assert_eq!(b.get_char(8), None);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`get`]: Self::get
+    #[inline]
+    #[must_use]
+    pub fn get_char(&self, index: usize) -> Option<&'_ [u8]> {
+        // `Vec` has a max allocation size of `isize::MAX`. For a `Vec<u8>` like
+        // the one in `String` where the `size_of::<u8>() == 1`, the max length
+        // is `isize::MAX`. This checked add short circuits with `None` if we
+        // are given `usize::MAX` as an index, which we could never slice.
+        let end = index.checked_add(1)?;
+        match self.encoding {
+            // For ASCII and binary encodings, all character operations assume
+            // characters are exactly one byte, so we can fallback to byte
+            // slicing.
+            Encoding::Ascii | Encoding::Binary => self.buf.get(index..end),
+            Encoding::Utf8 => {
+                // Fast path rejection for indexes beyond bytesize, which is
+                // cheap to retrieve.
+                if index >= self.len() {
+                    return None;
+                }
+                // Fast path for trying to treat the conventionally UTF-8 string
+                // as entirely ASCII.
+                //
+                // If the string is either all ASCII or all ASCII for a prefix
+                // of the string that contains the range we wish to slice,
+                // fallback to byte slicing as in the ASCII and binary fast path.
+                let consumed = match self.buf.find_non_ascii_byte() {
+                    None => return self.buf.get(index..end),
+                    Some(idx) if idx >= end => return self.buf.get(index..end),
+                    Some(idx) => idx,
+                };
+                let mut slice = &self.buf[consumed..];
+                // Count of "characters" remaining until the `index`th character.
+                let mut remaining = index - consumed;
+                // This loop will terminate when either:
+                //
+                // - It counts `index` number of characters.
+                // - It consumes the entire slice when scanning for the
+                //   `index`th character.
+                //
+                // The loop will advance by at least one byte every iteration.
+                loop {
+                    match bstr::decode_utf8(slice) {
+                        // If we've run out of slice while trying to find the
+                        // `index`th character, the lookup fails and we return `nil`.
+                        (_, 0) => return None,
+
+                        // The next two arms mean we've reached the `index`th
+                        // character. Either return the next valid UTF-8
+                        // character byte slice or, if the next bytes are an
+                        // invalid UTF-8 sequence, the next byte.
+                        (Some(_), size) if remaining == 0 => return Some(&slice[..size]),
+                        // Size is guaranteed to be positive per the first arm
+                        // which means this slice operation will not panic.
+                        (None, _) if remaining == 0 => return Some(&slice[..1]),
+
+                        // We found a single UTF-8 encoded characterk keep track
+                        // of the count and advance the substring to continue
+                        // decoding.
+                        (Some(_), size) => {
+                            slice = &slice[size..];
+                            remaining -= 1;
+                        }
+
+                        // The next two arms handle the case where we have
+                        // encountered an invalid UTF-8 byte sequence.
+                        //
+                        // In this case, `decode_utf8` will return slices whose
+                        // length is `1..=3`. The length of this slice is the
+                        // number of "characters" we can advance the loop by.
+                        //
+                        // If the invalid UTF-8 sequence contains more bytes
+                        // than we have remaining to get to the `index`th char,
+                        // then the target character is inside the invalid UTF-8
+                        // sequence.
+                        (None, size) if remaining < size => return Some(&slice[remaining..=remaining]),
+                        // If there are more characters remaining than the number
+                        // of bytes yielded in the invalid UTF-8 byte sequence,
+                        // count `size` bytes and advance the slice to continue
+                        // decoding.
+                        (None, size) => {
+                            slice = &slice[size..];
+                            remaining -= size;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns a substring of characters in the string.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
+    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
+    /// this function is equivalent to [`get`] with a range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::Range;
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
+    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  1 }), Some(&b"a"[..]));
+    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  3 }), Some(&b"abc"[..]));
+    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  4 }), Some("abc💎".as_bytes()));
+    /// assert_eq!(s.get_char_slice(Range { start:  0, end:  5 }), Some(&b"abc\xF0\x9F\x92\x8E\xFF"[..]));
+    /// assert_eq!(s.get_char_slice(Range { start:  3, end: 10 }), Some(&b"\xF0\x9F\x92\x8E\xFF"[..]));
+    /// assert_eq!(s.get_char_slice(Range { start:  4, end: 10 }), Some(&b"\xFF"[..]));
+    /// assert_eq!(s.get_char_slice(Range { start: 10, end: 15 }), None);
+    /// assert_eq!(s.get_char_slice(Range { start: 15, end: 10 }), None);
+    /// assert_eq!(s.get_char_slice(Range { start: 15, end:  1 }), None);
+    /// assert_eq!(s.get_char_slice(Range { start:  4, end:  1 }), Some(&b""[..]));
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`get`]: Self::get
+    #[inline]
+    #[must_use]
+    pub fn get_char_slice(&self, range: Range<usize>) -> Option<&'_ [u8]> {
+        let Range { start, end } = range;
+
+        // Fast path the lookup if the end of the range is before the start.
+        if end < start {
+            // Yes, these types of ranges are allowed and they return `""`.
+            //
+            // ```
+            // [3.0.1] > "aaa"[1..0]
+            // => ""
+            // [3.0.1] > "aaa"[2..0]
+            // => ""
+            // [3.0.1] > "aaa"[2..1]
+            // => ""
+            // [3.0.1] > "aaa"[3..0]
+            // => ""
+            // [3.0.1] > "💎🦀😅"[2..1]
+            // => ""
+            // [3.0.1] > "💎🦀😅"[3..0]
+            // => ""
+            // ```
+            //
+            // but only if `start` is within the string.
+            //
+            // ```
+            // [3.0.1] > "aaa"[10..4]
+            // => nil
+            // [3.0.1] > "aaa"[10..4]
+            // => nil
+            // [3.0.1] > "aaa"[10..0]
+            // => nil
+            // [3.0.1] > "💎🦀😅"[10..4]
+            // => nil
+            // [3.0.1] > "💎🦀😅"[10..0]
+            // => nil
+            // [3.0.1] > "💎🦀😅"[6..0]
+            // => nil
+            // [3.0.1] > "💎🦀😅"[4..0]
+            // => nil
+            // ```
+            //
+            // attempt to short-circuit with a cheap len retrieval
+            if start > self.len() || start > self.char_len() {
+                return None;
+            }
+            return Some(&[]);
+        }
+
+        // If the start of the range is beyond the character count of the
+        // string, the whole lookup must fail.
+        //
+        // Slice lookups where the start is just beyond the last character index
+        // always return an empty slice.
+        //
+        // ```
+        // [3.0.1] > "aaa"[10, 0]
+        // => nil
+        // [3.0.1] > "aaa"[10, 7]
+        // => nil
+        // [3.0.1] > "aaa"[3, 7]
+        // => ""
+        // [3.0.1] > "🦀💎"[2, 0]
+        // => ""
+        // [3.0.1] > "🦀💎"[3, 1]
+        // => nil
+        // [3.0.1] > "🦀💎"[2, 1]
+        // => ""
+        // ```
+        //
+        // Fast path rejection for indexes beyond bytesize, which is cheap to
+        // retrieve.
+        if start > self.len() {
+            return None;
+        }
+        match self.char_len() {
+            char_length if start > char_length => return None,
+            char_length if start == char_length => return Some(&[]),
+            _ => {}
+        }
+
+        // The span is guaranteed to at least partially overlap now.
+        match end - start {
+            // Empty substrings are present in all strings, even empty ones.
+            //
+            // ```
+            // [3.0.1] > "aaa"[""]
+            // => ""
+            // [3.0.1] > ""[""]
+            // => ""
+            // [3.0.1] > ""[0, 0]
+            // => ""
+            // [3.0.1] > "aaa"[0, 0]
+            // => ""
+            // [3.0.1] > "aaa"[2, 0]
+            // => ""
+            // [3.0.1] > "🦀💎"[1, 0]
+            // => ""
+            // [3.0.1] > "🦀💎"[2, 0]
+            // => ""
+            // ```
+            0 => return Some(&[]),
+            // Delegate to the specialized single char lookup, which allows the
+            // remainder of this routine to fall back to the general case of
+            // multi-character spans.
+            //
+            // ```
+            // [3.0.1] > "abc"[2, 1]
+            // => "c"
+            // [3.0.1] > "🦀💎"[1, 1]
+            // => "💎"
+            // ```
+            1 => return self.get_char(start),
+            _ => {}
+        }
+
+        match self.encoding {
+            // Ruby slice lookups saturate to the end of the string even if the
+            // ending index is beyond the string's character count.
+            //
+            // ```
+            // [3.0.1] > "abc"[1, 10]
+            // => "bc"
+            // [3.0.1] > "🦀💎"[0, 10]
+            // => "🦀💎"
+            // ```
+            Encoding::Ascii | Encoding::Binary => self.buf.get(start..end).or_else(|| self.buf.get(start..)),
+            Encoding::Utf8 => {
+                // Fast path for trying to treat the conventionally UTF-8 string
+                // as entirely ASCII.
+                //
+                // If the string is either all ASCII or all ASCII for the subset
+                // of the string we wish to slice, fallback to byte slicing as in
+                // the ASCII and binary fast path.
+                //
+                // Perform the same saturate-to-end slicing mechanism if `end`
+                // is beyond the character length of the string.
+                let consumed = match self.buf.find_non_ascii_byte() {
+                    // The entire string is ASCII, so byte indexing <=> char
+                    // indexing.
+                    None => return self.buf.get(start..end).or_else(|| self.buf.get(start..)),
+                    // The whole substring we are interested in is ASCII, so
+                    // byte indexing is still valid.
+                    Some(non_ascii_byte_offset) if non_ascii_byte_offset > end => return self.get(start..end),
+                    // We turn non-ASCII somewhere inside before the substring
+                    // we're interested in, so consume that much.
+                    Some(non_ascii_byte_offset) if non_ascii_byte_offset <= start => non_ascii_byte_offset,
+                    // This means we turn non-ASCII somewhere inside the substring.
+                    // Consume up to start.
+                    Some(_) => start,
+                };
+                // Scan for the beginning of the slice
+                let mut slice = &self.buf[consumed..];
+                // Count of "characters" remaining until the `start`th character.
+                let mut remaining = start - consumed;
+                if remaining > 0 {
+                    // This loop will terminate when either:
+                    //
+                    // - It counts `start` number of characters.
+                    // - It consumes the entire slice when scanning for the
+                    //   `start`th character.
+                    //
+                    // The loop will advance by at least one byte every iteration.
+                    slice = loop {
+                        match bstr::decode_utf8(slice) {
+                            // If we've run out of slice while trying to find the
+                            // `start`th character, the lookup fails and we return `nil`.
+                            (_, 0) => return None,
+
+                            // We found a single UTF-8 encoded character. keep track
+                            // of the count and advance the substring to continue
+                            // decoding.
+                            //
+                            // If there's only one more to go, advance and stop the
+                            // loop.
+                            (Some(_), size) if remaining == 1 => break &slice[size..],
+                            // Otherwise, keep track of the character we observed and
+                            // advance the slice to continue decoding.
+                            (Some(_), size) => {
+                                slice = &slice[size..];
+                                remaining -= 1;
+                            }
+
+                            // The next two arms handle the case where we have
+                            // encountered an invalid UTF-8 byte sequence.
+                            //
+                            // In this case, `decode_utf8` will return slices whose
+                            // length is `1..=3`. The length of this slice is the
+                            // number of "characters" we can advance the loop by.
+                            //
+                            // If the invalid UTF-8 sequence contains more bytes
+                            // than we have remaining to get to the `start`th char,
+                            // then we can break the loop directly.
+                            (None, size) if remaining <= size => break &slice[remaining..],
+                            // If there are more characters remaining than the number
+                            // of bytes yielded in the invalid UTF-8 byte sequence,
+                            // count `size` bytes and advance the slice to continue
+                            // decoding.
+                            (None, size) => {
+                                slice = &slice[size..];
+                                remaining -= size;
+                            }
+                        }
+                    }
+                };
+
+                // Scan the slice for the span of characters we want to return.
+                remaining = end - start;
+                // We know `remaining` is not zero because we fast-pathed that
+                // case above.
+                debug_assert!(remaining > 0);
+
+                // keep track of the start of the substring from the `start`th
+                // character.
+                let substr = slice;
+
+                // This loop will terminate when either:
+                //
+                // - It counts the next `start - end` number of characters.
+                // - It consumes the entire slice when scanning for the `end`th
+                //   character.
+                //
+                // The loop will advance by at least one byte every iteration.
+                loop {
+                    match bstr::decode_utf8(slice) {
+                        // If we've run out of slice while trying to find the `end`th
+                        // character, saturate the slice to the end of the string.
+                        (_, 0) => return Some(substr),
+
+                        // We found a single UTF-8 encoded character. keep track
+                        // of the count and advance the substring to continue
+                        // decoding.
+                        //
+                        // If there's only one more to go, advance and stop the
+                        // loop.
+                        (Some(_), size) if remaining == 1 => {
+                            // Push `endth` more positive because this match has
+                            // the effect of shrinking `slice`.
+                            let endth = substr.len() - slice.len() + size;
+                            return Some(&substr[..endth]);
+                        }
+                        // Otherwise, keep track of the character we observed and
+                        // advance the slice to continue decoding.
+                        (Some(_), size) => {
+                            slice = &slice[size..];
+                            remaining -= 1;
+                        }
+
+                        // The next two arms handle the case where we have
+                        // encountered an invalid UTF-8 byte sequence.
+                        //
+                        // In this case, `decode_utf8` will return slices whose
+                        // length is `1..=3`. The length of this slice is the
+                        // number of "characters" we can advance the loop by.
+                        //
+                        // If the invalid UTF-8 sequence contains more bytes
+                        // than we have remaining to get to the `end`th char,
+                        // then we can break the loop directly.
+                        (None, size) if remaining <= size => {
+                            // For an explanation of this arithmetic:
+                            // If we're trying to slice:
+                            //
+                            // ```
+                            // s = "a\xF0\x9F\x87"
+                            // s[0, 2]
+                            // ```
+                            //
+                            // By the time we get to this branch in this loop:
+                            //
+                            // ```
+                            // substr = "a\xF0\x9F\x87"
+                            // slice = "\xF0\x9F\x87"
+                            // remaining = 1
+                            // ```
+                            //
+                            // We want to compute `endth == 2`:
+                            //
+                            //    2   =      4       -      3      +     1
+                            let endth = substr.len() - slice.len() + remaining;
+                            return Some(&substr[..endth]);
+                        }
+                        // If there are more characters remaining than the number
+                        // of bytes yielded in the invalid UTF-8 byte sequence,
+                        // count `size` bytes and advance the slice to continue
+                        // decoding.
+                        (None, size) => {
+                            slice = &slice[size..];
+                            remaining -= size;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator over the fields of the string split by the given
+    /// separator.
+    ///
+    /// Fields are yielded as `&[u8]` byte slices of this `String`.
+    ///
+    /// A separator of a single space splits on runs of ASCII whitespace with
+    /// leading whitespace skipped. An empty separator splits the string into
+    /// characters, interpreted according to this string's encoding as in
+    /// [`chars`]. Without a limit, trailing empty fields are suppressed. A
+    /// positive `limit` caps the number of fields; the remainder of the string
+    /// is left unsplit in the last field.
+    ///
+    /// This function can be used to implement the Ruby method [`String#split`]
+    /// with a string separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from(" now's  the time ");
+    /// let fields = s.split(b" ", None).collect::<Vec<_>>();
+    /// assert_eq!(fields, [&b"now's"[..], &b"the"[..], &b"time"[..]]);
+    ///
+    /// let s = String::from("a,b,,");
+    /// let fields = s.split(b",", None).collect::<Vec<_>>();
+    /// assert_eq!(fields, [&b"a"[..], &b"b"[..]]);
+    /// let fields = s.split(b",", Some(3)).collect::<Vec<_>>();
+    /// assert_eq!(fields, [&b"a"[..], &b"b"[..], &b","[..]]);
+    /// ```
+    ///
+    /// [`chars`]: Self::chars
+    /// [`String#split`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-split
+    #[inline]
+    #[must_use]
+    pub fn split(&self, separator: &[u8], limit: Option<usize>) -> Split<'_> {
+        Split::new(self, separator, limit)
+    }
+
+    /// Returns an iterator over the lines of the string split by the given
+    /// separator.
+    ///
+    /// Lines are yielded as `&[u8]` byte slices of this `String`, including
+    /// the separator. The final line is yielded even if it has no trailing
+    /// separator.
+    ///
+    /// A separator of [`None`] splits on newlines. An empty separator selects
+    /// paragraph mode: runs of two or more consecutive newlines separate
+    /// paragraphs and are not included in the yielded slices. If `chomp` is
+    /// true, the separator is stripped from each yielded slice; chomping the
+    /// default newline separator also removes a preceding carriage return.
+    ///
+    /// This function can be used to implement the Ruby methods
+    /// [`String#lines`] and [`String#each_line`] with a string separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from("hello\r\nworld\r\n");
+    /// let lines = s.lines(None, false).collect::<Vec<_>>();
+    /// assert_eq!(lines, [&b"hello\r\n"[..], &b"world\r\n"[..]]);
+    /// let lines = s.lines(None, true).collect::<Vec<_>>();
+    /// assert_eq!(lines, [&b"hello"[..], &b"world"[..]]);
+    ///
+    /// let s = String::from("para one\n\n\n\npara two\nstill two");
+    /// let paragraphs = s.lines(Some(b""), false).collect::<Vec<_>>();
+    /// assert_eq!(paragraphs, [&b"para one"[..], &b"para two\nstill two"[..]]);
+    /// ```
+    ///
+    /// [`String#lines`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-lines
+    /// [`String#each_line`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-each_line
+    #[inline]
+    #[must_use]
+    pub fn lines(&self, separator: Option<&[u8]>, chomp: bool) -> Lines<'_> {
+        Lines::new(self, separator, chomp)
+    }
+
+    /// Returns the `index`'th character in the string, where indices are
+    /// interpreted as characters.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
+    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
+    /// indices are interpreted as byte offsets.
+    ///
+    /// This function is an alias for [`get_char`] and can be used to implement
+    /// the Ruby method [`String#[]`] with an integer index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("💎abc".as_bytes().to_vec());
+    /// assert_eq!(s.char_get(0), Some("💎".as_bytes()));
+    /// assert_eq!(s.char_get(1), Some(&b"a"[..]));
+    /// assert_eq!(s.char_get(4), None);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`get_char`]: Self::get_char
+    /// [`String#[]`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-5B-5D
+    #[inline]
+    #[must_use]
+    pub fn char_get(&self, index: usize) -> Option<&'_ [u8]> {
+        self.get_char(index)
+    }
+
+    /// Returns a substring of `len` characters in the string, starting at the
+    /// `start`'th character.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// multi-byte Unicode characters are length 1 and invalid UTF-8 bytes are
+    /// length 1. For `String`s with [ASCII encoding] or [binary encoding],
+    /// `start` and `len` are interpreted as byte offsets.
+    ///
+    /// A `start` beyond the character length of the string returns [`None`]; a
+    /// `start` exactly equal to [`char_len`] returns an empty slice. A `len`
+    /// which overruns the end of the string is clamped to the end of the
+    /// string:
+    ///
+    /// ```ruby
+    /// [3.0.1] > "aaa"[3, 7]
+    /// => ""
+    /// [3.0.1] > "aaa"[4, 7]
+    /// => nil
+    /// [3.0.1] > "🦀💎"[1, 10]
+    /// => "💎"
+    /// ```
+    ///
+    /// This function can be used to implement the Ruby method [`String#[]`]
+    /// with a start and length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8("💎abc".as_bytes().to_vec());
+    /// assert_eq!(s.char_slice(0, 2), Some("💎a".as_bytes()));
+    /// assert_eq!(s.char_slice(1, 10), Some(&b"abc"[..]));
+    /// assert_eq!(s.char_slice(4, 1), Some(&b""[..]));
+    /// assert_eq!(s.char_slice(5, 1), None);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`char_len`]: Self::char_len
+    /// [`String#[]`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-5B-5D
+    #[inline]
+    #[must_use]
+    pub fn char_slice(&self, start: usize, len: usize) -> Option<&'_ [u8]> {
+        // `get_char_slice` clamps ranges which overrun the end of the string
+        // to the end of the string, so a saturating add matches Ruby slicing
+        // behavior for enormous lengths.
+        let end = start.saturating_add(len);
+        self.get_char_slice(Range { start, end })
+    }
+
+    /// Inserts the given bytes into this `String` at the given character
+    /// index.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// the index is interpreted as a character index as defined by
+    /// [`char_len`]. For `String`s with [ASCII encoding] or [binary encoding],
+    /// the index is interpreted as a byte index. An index exactly equal to the
+    /// character length appends to the string.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#insert`].
+    ///
+    /// # Errors
+    ///
+    /// If the index is beyond the character length of the string, an
+    /// [`IndexOutOfBoundsError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// # fn example() -> Result<(), spinoso_string::IndexOutOfBoundsError> {
+    /// let mut s = String::from("abcd");
+    /// s.insert_str(2, b"X")?;
+    /// assert_eq!(s, "abXcd");
+    ///
+    /// let mut s = String::utf8("💎b".as_bytes().to_vec());
+    /// s.insert_str(1, b"a")?;
+    /// assert_eq!(s, "💎ab");
+    ///
+    /// let mut s = String::from("ab");
+    /// assert!(s.insert_str(3, b"x").is_err());
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`char_len`]: Self::char_len
+    /// [`String#insert`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-insert
+    pub fn insert_str(&mut self, index: usize, other: &[u8]) -> Result<(), IndexOutOfBoundsError> {
+        let byte_offset = match self.encoding {
+            Encoding::Ascii | Encoding::Binary if index <= self.buf.len() => index,
+            Encoding::Ascii | Encoding::Binary => {
+                return Err(IndexOutOfBoundsError::with_index_and_len(
+                    i64::try_from(index).unwrap_or(i64::MAX),
+                    self.buf.len(),
+                ))
+            }
+            Encoding::Utf8 => conventionally_utf8_char_index_to_byte_offset(&self.buf, index).ok_or_else(|| {
+                IndexOutOfBoundsError::with_index_and_len(i64::try_from(index).unwrap_or(i64::MAX), self.buf.len())
+            })?,
+        };
+        // The splice replaces an empty range, so it only inserts and the
+        // returned iterator of removed bytes is empty.
+        drop(self.buf.splice(byte_offset..byte_offset, other.iter().copied()));
+        Ok(())
+    }
+
+    /// Removes a range of characters from this `String` and returns it as a
+    /// new `String` with the same encoding.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// `start` and `len` are interpreted as character indices as defined by
+    /// [`char_len`]. For `String`s with [ASCII encoding] or [binary encoding],
+    /// they are interpreted as byte indices. A `len` which overruns the end of
+    /// the string is clamped to the end of the string.
+    ///
+    /// A `start` beyond the character length of the string returns [`None`]; a
+    /// `start` exactly equal to [`char_len`] removes nothing and returns an
+    /// empty `String`.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#slice!`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::utf8("a💎bc".as_bytes().to_vec());
+    /// let removed = s.drain_range(1, 2).unwrap();
+    /// assert_eq!(removed, "💎b");
+    /// assert_eq!(removed.encoding(), s.encoding());
+    /// assert_eq!(s, "ac");
+    ///
+    /// let mut s = String::from("abc");
+    /// assert_eq!(s.drain_range(3, 10).unwrap(), "");
+    /// assert_eq!(s.drain_range(4, 10), None);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`char_len`]: Self::char_len
+    /// [`String#slice!`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-slice-21
+    #[must_use]
+    pub fn drain_range(&mut self, start: usize, len: usize) -> Option<String> {
+        let end = start.saturating_add(len);
+        let (byte_start, byte_end) = match self.encoding {
+            Encoding::Ascii | Encoding::Binary => {
+                if start > self.buf.len() {
+                    return None;
+                }
+                (start, end.min(self.buf.len()))
+            }
+            Encoding::Utf8 => {
+                let byte_start = conventionally_utf8_char_index_to_byte_offset(&self.buf, start)?;
+                // Ranges which overrun the end of the string are clamped to
+                // the end of the string.
+                let byte_end =
+                    conventionally_utf8_char_index_to_byte_offset(&self.buf, end).unwrap_or_else(|| self.buf.len());
+                (byte_start, byte_end)
+            }
+        };
+        let drained = self.buf.drain(byte_start..byte_end).collect();
+        Some(Self::with_bytes_and_encoding(drained, self.encoding))
+    }
+
+    /// Returns the number of characters in this `String` which belong to the
+    /// intersection of all the given sets.
+    ///
+    /// Each set uses `String#tr` set notation: `a-c` ranges, negation with a
+    /// leading `^`, and `\` escapes. A character is counted when it matches
+    /// every given set. If no sets are given, nothing is counted.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// counting is performed per character; each byte of an invalid UTF-8
+    /// sequence is one character and only matches a set which contains that
+    /// literal byte. For `String`s with [ASCII encoding] or [binary encoding],
+    /// counting is performed per byte.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::from("hello world");
+    /// assert_eq!(s.count(&[b"lo"]), 5);
+    /// assert_eq!(s.count(&[b"lo", b"o"]), 2);
+    /// assert_eq!(s.count(&[b"hello", b"^l"]), 4);
+    /// assert_eq!(s.count(&[b"ej-m"]), 4);
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`String#count`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-count
+    #[must_use]
+    pub fn count(&self, sets: &[&[u8]]) -> usize {
+        if sets.is_empty() {
+            return 0;
+        }
+        let sets = sets
+            .iter()
+            .map(|&spec| tr::CharacterSet::expand(spec, self.encoding))
+            .collect::<Vec<_>>();
+
+        let mut count = 0;
+        let mut rest = self.buf.as_slice();
+        while !rest.is_empty() {
+            let len = tr::first_char_len(rest, self.encoding);
+            let (ch, remainder) = rest.split_at(len);
+            rest = remainder;
+
+            if sets.iter().all(|set| set.matches(ch)) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Translates the characters in this `String` matched by the `from` set to
+    /// the corresponding character in the `to` set, returning the number of
+    /// characters translated.
+    ///
+    /// Both sets use `String#tr` set notation: `a-c` ranges, negation with a
+    /// leading `^`, and `\` escapes. If `to` is shorter than `from`, its last
+    /// character is repeated; if `to` is empty, matched characters are
+    /// deleted. If `from` is negated, characters not in the set are translated
+    /// to the last character of `to`.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// the sets may contain multibyte characters and translation is performed
+    /// per character. For `String`s with [ASCII encoding] or [binary
+    /// encoding], translation is performed per byte.
+    ///
+    /// This function can be used to implement the Ruby method [`String#tr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::from("hello");
+    /// assert_eq!(s.tr(b"el", b"ip"), 3);
+    /// assert_eq!(s, "hippo");
+    ///
+    /// let mut s = String::from("hello");
+    /// s.tr(b"a-y", b"b-z");
+    /// assert_eq!(s, "ifmmp");
+    ///
+    /// let mut s = String::from("hello");
+    /// s.tr(b"^aeiou", b"*");
+    /// assert_eq!(s, "*e**o");
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`String#tr`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-tr
+    pub fn tr(&mut self, from: &[u8], to: &[u8]) -> usize {
+        let from_set = tr::CharacterSet::expand(from, self.encoding);
+        // Negation in the `to` set has no meaning; its members are taken
+        // literally in specification order.
+        let to_set = tr::CharacterSet::expand(to, self.encoding);
+        let to_members = to_set.members();
+
+        let buf = mem::take(&mut self.buf);
+        let mut translated = Vec::with_capacity(buf.len());
+        let mut changed = 0;
+        let mut rest = buf.as_slice();
+        while !rest.is_empty() {
+            let len = tr::first_char_len(rest, self.encoding);
+            let (ch, remainder) = rest.split_at(len);
+            rest = remainder;
+
+            let replacement = if from_set.is_negated() {
+                if from_set.position(ch).is_none() {
+                    // Characters outside a negated set all translate to the
+                    // last character of `to`.
+                    Some(to_members.last())
+                } else {
+                    None
+                }
+            } else if let Some(index) = from_set.position(ch) {
+                // If `to` is shorter than `from`, its last character is
+                // repeated.
+                Some(to_members.get(index).or_else(|| to_members.last()))
+            } else {
+                None
+            };
+            match replacement {
+                // An empty `to` set deletes matched characters.
+                Some(None) => changed += 1,
+                Some(Some(replacement)) if &replacement[..] == ch => translated.extend_from_slice(ch),
+                Some(Some(replacement)) => {
+                    translated.extend_from_slice(replacement);
+                    changed += 1;
+                }
+                None => translated.extend_from_slice(ch),
+            }
+        }
+        self.buf = translated.into();
+        changed
+    }
+
+    /// Removes the characters in this `String` matched by the given set,
+    /// returning the number of characters removed.
+    ///
+    /// The set uses `String#tr` set notation: `a-c` ranges, negation with a
+    /// leading `^`, and `\` escapes.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// the set may contain multibyte characters and deletion is performed per
+    /// character. For `String`s with [ASCII encoding] or [binary encoding],
+    /// deletion is performed per byte.
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#delete`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::from("hello");
+    /// assert_eq!(s.delete(b"l"), 2);
+    /// assert_eq!(s, "heo");
+    ///
+    /// let mut s = String::from("hello");
+    /// s.delete(b"ej-m");
+    /// assert_eq!(s, "ho");
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`String#delete`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-delete
+    pub fn delete(&mut self, set: &[u8]) -> usize {
+        let set = tr::CharacterSet::expand(set, self.encoding);
+
+        let buf = mem::take(&mut self.buf);
+        let mut retained = Vec::with_capacity(buf.len());
+        let mut removed = 0;
+        let mut rest = buf.as_slice();
+        while !rest.is_empty() {
+            let len = tr::first_char_len(rest, self.encoding);
+            let (ch, remainder) = rest.split_at(len);
+            rest = remainder;
+
+            if set.matches(ch) {
+                removed += 1;
+            } else {
+                retained.extend_from_slice(ch);
+            }
+        }
+        self.buf = retained.into();
+        removed
+    }
+
+    /// Squeezes runs of the same character in this `String` down to a single
+    /// character, returning true if the string was modified.
+    ///
+    /// If a set is given, only runs of characters matched by the set are
+    /// squeezed. The set uses `String#tr` set notation: `a-c` ranges, negation
+    /// with a leading `^`, and `\` escapes.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// the set may contain multibyte characters and runs are identified per
+    /// character. For `String`s with [ASCII encoding] or [binary encoding],
+    /// runs are identified per byte.
+    ///
+    /// This function can be used to implement the Ruby methods
+    /// [`String#squeeze`] and [`String#squeeze!`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::from("yellow moon");
+    /// assert!(s.squeeze(None));
+    /// assert_eq!(s, "yelow mon");
+    ///
+    /// let mut s = String::from("mississippi");
+    /// assert!(s.squeeze(Some(b"sp")));
+    /// assert_eq!(s, "misisipi");
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII encoding]: crate::Encoding::Ascii
+    /// [binary encoding]: crate::Encoding::Binary
+    /// [`String#squeeze`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-squeeze
+    /// [`String#squeeze!`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-squeeze-21
+    pub fn squeeze(&mut self, set: Option<&[u8]>) -> bool {
+        let set = set.map(|spec| tr::CharacterSet::expand(spec, self.encoding));
+
+        let buf = mem::take(&mut self.buf);
+        let mut squeezed = Vec::with_capacity(buf.len());
+        let mut modified = false;
+        let mut prev: Option<&[u8]> = None;
+        let mut rest = buf.as_slice();
+        while !rest.is_empty() {
+            let len = tr::first_char_len(rest, self.encoding);
+            let (ch, remainder) = rest.split_at(len);
+            rest = remainder;
+
+            if prev == Some(ch) && set.as_ref().map_or(true, |set| set.matches(ch)) {
+                modified = true;
+            } else {
+                squeezed.extend_from_slice(ch);
+                prev = Some(ch);
+            }
+        }
+        self.buf = squeezed.into();
+        modified
+    }
+
+    /// Returns true for a `String` which is encoded correctly.
+    ///
+    /// For this method to return true, `String`s with [conventionally UTF-8]
+    /// must be well-formed UTF-8; [ASCII]-encoded `String`s must only contain
+    /// bytes in the range `0..=127`; [binary]-encoded `String`s may contain any
+    /// byte sequence.
+    ///
+    /// This method is suitable for implementing the Ruby method
+    /// [`String#valid_encoding?`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::{Encoding, String};
+    ///
+    /// let s = String::utf8(b"xyz".to_vec());
+    /// assert!(s.is_valid_encoding());
+    /// let s = String::utf8("🚀".to_string().into_bytes());
+    /// assert!(s.is_valid_encoding());
+    /// let s = String::utf8(b"abc\xFF\xFExyz".to_vec());
+    /// assert!(!s.is_valid_encoding());
+    ///
+    /// let s = String::ascii(b"xyz".to_vec());
+    /// assert!(s.is_valid_encoding());
+    /// let s = String::ascii("🚀".to_string().into_bytes());
+    /// assert!(!s.is_valid_encoding());
+    /// let s = String::ascii(b"abc\xFF\xFExyz".to_vec());
+    /// assert!(!s.is_valid_encoding());
+    ///
+    /// let s = String::binary(b"xyz".to_vec());
+    /// assert!(s.is_valid_encoding());
+    /// let s = String::binary("🚀".to_string().into_bytes());
+    /// assert!(s.is_valid_encoding());
+    /// let s = String::binary(b"abc\xFF\xFExyz".to_vec());
+    /// assert!(s.is_valid_encoding());
+    /// ```
+    ///
+    /// [conventionally UTF-8]: crate::Encoding::Utf8
+    /// [ASCII]: crate::Encoding::Ascii
+    /// [binary]: crate::Encoding::Binary
+    /// [`String#valid_encoding?`]: https://ruby-doc.org/core-3.0.0/String.html#method-i-valid_encoding-3F
+    #[inline]
+    #[must_use]
+    pub fn is_valid_encoding(&self) -> bool {
+        match self.encoding {
+            Encoding::Utf8 if self.buf.is_ascii_only() => true,
+            Encoding::Utf8 => simdutf8::basic::from_utf8(&self.buf).is_ok(),
+            Encoding::Ascii => self.buf.is_ascii_only(),
+            Encoding::Binary => true,
+        }
+    }
+
+    /// Returns a copy of this `String` with invalid byte sequences replaced
+    /// by the given replacement.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// every maximal invalid UTF-8 byte sequence is replaced by the
+    /// replacement. For [ASCII-encoded] `String`s, every byte outside the
+    /// range `0..=127` is replaced. [Binary-encoded] `String`s contain no
+    /// invalid byte sequences and are always returned unchanged.
+    ///
+    /// If the given replacement is [`None`], invalid byte sequences are
+    /// replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// `String`s which are [valid for their encoding] are returned as
+    /// [`Cow::Borrowed`] without allocating.
+    ///
+    /// This function is suitable for implementing the Ruby method
+    /// [`String#scrub`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"abc\xF0\x9F\x92".to_vec());
+    /// assert_eq!(s.scrub(None), "abc\u{FFFD}".as_bytes());
+    /// assert_eq!(s.scrub(Some(b"?")), &b"abc?"[..]);
+    ///
+    /// let s = String::utf8("💎".as_bytes().to_vec());
+    /// assert!(matches!(s.scrub(None), Cow::Borrowed(_)));
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII-encoded]: crate::Encoding::Ascii
+    /// [Binary-encoded]: crate::Encoding::Binary
+    /// [valid for their encoding]: Self::is_valid_encoding
+    /// [`String#scrub`]: https://ruby-doc.org/core-3.0.0/String.html#method-i-scrub
+    #[inline]
+    #[must_use]
+    pub fn scrub(&self, replacement: Option<&[u8]>) -> Cow<'_, [u8]> {
+        match scrub_into_vec(&self.buf, self.encoding, replacement) {
+            None => Cow::Borrowed(self.buf.as_slice()),
+            Some(scrubbed) => Cow::Owned(scrubbed),
+        }
+    }
+
+    /// Modifies this `String` in-place, replacing invalid byte sequences with
+    /// the given replacement.
+    ///
+    /// Invalid byte sequences are segmented and replaced like [`scrub`].
+    ///
+    /// This function returns `true` if self is modified, `false` otherwise,
+    /// consistent with [`chomp`] and [`chop`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::utf8(b"ab\x80cd".to_vec());
+    /// let modified = s.make_scrub(None);
+    /// assert!(modified);
+    /// assert_eq!(s, "ab\u{FFFD}cd");
+    ///
+    /// let mut s = String::utf8(b"abc".to_vec());
+    /// let modified = s.make_scrub(None);
+    /// assert!(!modified);
+    /// ```
+    ///
+    /// [`scrub`]: Self::scrub
+    /// [`chomp`]: Self::chomp
+    /// [`chop`]: Self::chop
+    #[inline]
+    pub fn make_scrub(&mut self, replacement: Option<&[u8]>) -> bool {
+        if let Some(scrubbed) = scrub_into_vec(&self.buf, self.encoding, replacement) {
+            self.buf = scrubbed.into();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the contents of this `String` as a UTF-8 [`str`], replacing
+    /// byte sequences which have no character interpretation with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// This function is encoding-aware. For `String`s with [UTF-8 encoding],
+    /// every maximal invalid UTF-8 byte sequence is replaced. For [ASCII] and
+    /// [binary] `String`s, every byte outside the range `0..=127` is
+    /// replaced.
+    ///
+    /// `String`s which convert without replacements are returned as
+    /// [`Cow::Borrowed`] without allocating. To fail on unconvertible bytes
+    /// instead of replacing them, use the [`TryFrom`] conversions to [`str`]
+    /// and [`alloc::string::String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::utf8(b"abc\xF0\x9F\x92".to_vec());
+    /// assert_eq!(s.to_string_lossy(), "abc\u{FFFD}");
+    ///
+    /// let s = String::binary(b"abc\xFF".to_vec());
+    /// assert_eq!(s.to_string_lossy(), "abc\u{FFFD}");
+    ///
+    /// let s = String::utf8("💎".as_bytes().to_vec());
+    /// assert!(matches!(s.to_string_lossy(), Cow::Borrowed(_)));
+    /// ```
+    ///
+    /// [UTF-8 encoding]: crate::Encoding::Utf8
+    /// [ASCII]: crate::Encoding::Ascii
+    /// [binary]: crate::Encoding::Binary
+    #[inline]
+    #[must_use]
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        match self.encoding {
+            Encoding::Utf8 => alloc::string::String::from_utf8_lossy(self.buf.as_slice()),
+            // ASCII-only byte content is always valid UTF-8, so
+            // `from_utf8_lossy` borrows. Bytes outside the ASCII range have no
+            // character interpretation in these encodings and are replaced.
+            Encoding::Ascii | Encoding::Binary if self.buf.is_ascii_only() => {
+                alloc::string::String::from_utf8_lossy(self.buf.as_slice())
+            }
+            Encoding::Ascii | Encoding::Binary => {
+                let mut s = alloc::string::String::with_capacity(self.buf.len());
+                for &byte in self.buf.as_slice() {
+                    if byte.is_ascii() {
+                        s.push(char::from(byte));
+                    } else {
+                        s.push('\u{FFFD}');
+                    }
+                }
+                Cow::Owned(s)
+            }
+        }
+    }
+
+    /// Returns a copy of this `String` transcoded to the given [`Encoding`].
+    ///
+    /// This function is encoding-aware. Byte sequences which have no
+    /// representation in the destination encoding — invalid UTF-8 byte
+    /// sequences when transcoding [binary] bytes to [UTF-8], non-ASCII
+    /// characters when transcoding to [ASCII] — are replaced with
+    /// `invalid_replace` if it is given. Transcoding [ASCII]-encoded
+    /// `String`s and transcoding to [binary] only retag the bytes.
+    ///
+    /// To retag this `String` with another encoding without modifying the
+    /// byte content, use [`force_encoding`].
+    ///
+    /// This function can be used to implement the Ruby method
+    /// [`String#encode`].
+    ///
+    /// # Errors
+    ///
+    /// If a byte sequence has no representation in the destination encoding
+    /// and `invalid_replace` is [`None`], a [`TranscodeError`] carrying the
+    /// byte offset and the offending byte sequence is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::{Encoding, String};
+    ///
+    /// let s = String::binary(b"ab\xF0\x9F\x92cd".to_vec());
+    /// let transcoded = s.transcode(Encoding::Utf8, Some(b"?")).unwrap();
+    /// assert_eq!(transcoded, "ab?cd");
+    /// assert_eq!(transcoded.encoding(), Encoding::Utf8);
+    ///
+    /// let s = String::utf8("déjà vu".as_bytes().to_vec());
+    /// let err = s.transcode(Encoding::Ascii, None).unwrap_err();
+    /// assert_eq!(err.bytes(), "é".as_bytes());
+    /// assert_eq!(err.offset(), 1);
+    /// ```
+    ///
+    /// [binary]: crate::Encoding::Binary
+    /// [UTF-8]: crate::Encoding::Utf8
+    /// [ASCII]: crate::Encoding::Ascii
+    /// [`force_encoding`]: Self::force_encoding
+    /// [`String#encode`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-encode
+    #[inline]
+    pub fn transcode(&self, to: Encoding, invalid_replace: Option<&[u8]>) -> Result<Self, TranscodeError> {
+        let transcoded = transcode::transcode(&self.buf, self.encoding, to, invalid_replace)?;
+        Ok(Self::with_bytes_and_encoding(transcoded.into_owned(), to))
+    }
+}
+
+// Fixed-width binary accessors
+//
+// These APIs are bounds-checked primitives for reading and writing fixed-width
+// integers at byte offsets, suitable for implementing binary protocols like
+// `Array#pack` and `String#unpack` on top of `String`.
+impl String {
+    /// Read the byte slice of a fixed-width integer at the given offset.
+    ///
+    /// Returns [`None`] if the integer would straddle the end of the buffer.
+    #[inline]
+    fn get_int_bytes<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        let end = offset.checked_add(N)?;
+        let bytes = self.buf.get(offset..end)?;
+        let mut out = [0; N];
+        out.copy_from_slice(bytes);
+        Some(out)
+    }
+
+    /// Write the byte representation of a fixed-width integer at the given
+    /// offset, extending the buffer and zero-filling any gap when writing
+    /// past the end.
+    #[inline]
+    fn put_int_bytes(&mut self, offset: usize, bytes: &[u8]) -> Result<(), IndexOutOfBoundsError> {
+        let end = offset.checked_add(bytes.len()).ok_or_else(|| {
+            IndexOutOfBoundsError::with_index_and_len(i64::try_from(offset).unwrap_or(i64::MAX), self.buf.len())
+        })?;
+        if self.buf.len() < end {
+            self.buf.resize(end, 0);
+        }
+        self.buf[offset..end].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Read the byte at the given offset.
+    ///
+    /// Returns [`None`] if the offset is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::binary(b"\x01\x02".to_vec());
+    /// assert_eq!(s.get_u8(1), Some(0x02));
+    /// assert_eq!(s.get_u8(2), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_u8(&self, offset: usize) -> Option<u8> {
+        self.buf.get(offset).copied()
+    }
+
+    /// Read a little endian [`u16`] at the given offset.
+    ///
+    /// Returns [`None`] if the integer would straddle the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let s = String::binary(b"\x01\x02".to_vec());
+    /// assert_eq!(s.get_u16_le(0), Some(0x0201));
+    /// assert_eq!(s.get_u16_le(1), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_u16_le(&self, offset: usize) -> Option<u16> {
+        self.get_int_bytes(offset).map(u16::from_le_bytes)
+    }
+
+    /// Read a big endian [`u16`] at the given offset.
+    ///
+    /// Returns [`None`] if the integer would straddle the end of the buffer.
+    #[inline]
+    #[must_use]
+    pub fn get_u16_be(&self, offset: usize) -> Option<u16> {
+        self.get_int_bytes(offset).map(u16::from_be_bytes)
+    }
+
+    /// Read a little endian [`u32`] at the given offset.
+    ///
+    /// Returns [`None`] if the integer would straddle the end of the buffer.
+    #[inline]
+    #[must_use]
+    pub fn get_u32_le(&self, offset: usize) -> Option<u32> {
+        self.get_int_bytes(offset).map(u32::from_le_bytes)
+    }
+
+    /// Read a big endian [`u32`] at the given offset.
+    ///
+    /// Returns [`None`] if the integer would straddle the end of the buffer.
+    #[inline]
+    #[must_use]
+    pub fn get_u32_be(&self, offset: usize) -> Option<u32> {
+        self.get_int_bytes(offset).map(u32::from_be_bytes)
+    }
+
+    /// Read a little endian [`u64`] at the given offset.
+    ///
+    /// Returns [`None`] if the integer would straddle the end of the buffer.
+    #[inline]
+    #[must_use]
+    pub fn get_u64_le(&self, offset: usize) -> Option<u64> {
+        self.get_int_bytes(offset).map(u64::from_le_bytes)
+    }
+
+    /// Read a big endian [`u64`] at the given offset.
+    ///
+    /// Returns [`None`] if the integer would straddle the end of the buffer.
+    #[inline]
+    #[must_use]
+    pub fn get_u64_be(&self, offset: usize) -> Option<u64> {
+        self.get_int_bytes(offset).map(u64::from_be_bytes)
+    }
+
+    /// Write a byte at the given offset.
+    ///
+    /// Writing past the end of the buffer extends it, zero-filling any gap
+    /// between the old end and the offset.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the written integer does not fit in [`usize`], then an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::binary(b"\x01".to_vec());
+    /// s.put_u8(3, 0xFF).unwrap();
+    /// assert_eq!(s, &b"\x01\x00\x00\xFF"[..]);
+    /// ```
+    #[inline]
+    pub fn put_u8(&mut self, offset: usize, value: u8) -> Result<(), IndexOutOfBoundsError> {
+        self.put_int_bytes(offset, &[value])
+    }
+
+    /// Write a little endian [`u16`] at the given offset.
+    ///
+    /// Writing past the end of the buffer extends it, zero-filling any gap
+    /// between the old end and the offset.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the written integer does not fit in [`usize`], then an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::String;
+    ///
+    /// let mut s = String::binary(b"".to_vec());
+    /// s.put_u16_le(1, 0x0201).unwrap();
+    /// assert_eq!(s, &b"\x00\x01\x02"[..]);
+    /// ```
+    #[inline]
+    pub fn put_u16_le(&mut self, offset: usize, value: u16) -> Result<(), IndexOutOfBoundsError> {
+        self.put_int_bytes(offset, &value.to_le_bytes())
+    }
+
+    /// Write a big endian [`u16`] at the given offset.
+    ///
+    /// Writing past the end of the buffer extends it, zero-filling any gap
+    /// between the old end and the offset.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the written integer does not fit in [`usize`], then an
+    /// error is returned.
+    #[inline]
+    pub fn put_u16_be(&mut self, offset: usize, value: u16) -> Result<(), IndexOutOfBoundsError> {
+        self.put_int_bytes(offset, &value.to_be_bytes())
+    }
+
+    /// Write a little endian [`u32`] at the given offset.
+    ///
+    /// Writing past the end of the buffer extends it, zero-filling any gap
+    /// between the old end and the offset.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the written integer does not fit in [`usize`], then an
+    /// error is returned.
+    #[inline]
+    pub fn put_u32_le(&mut self, offset: usize, value: u32) -> Result<(), IndexOutOfBoundsError> {
+        self.put_int_bytes(offset, &value.to_le_bytes())
+    }
+
+    /// Write a big endian [`u32`] at the given offset.
+    ///
+    /// Writing past the end of the buffer extends it, zero-filling any gap
+    /// between the old end and the offset.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the written integer does not fit in [`usize`], then an
+    /// error is returned.
+    #[inline]
+    pub fn put_u32_be(&mut self, offset: usize, value: u32) -> Result<(), IndexOutOfBoundsError> {
+        self.put_int_bytes(offset, &value.to_be_bytes())
+    }
+
+    /// Write a little endian [`u64`] at the given offset.
+    ///
+    /// Writing past the end of the buffer extends it, zero-filling any gap
+    /// between the old end and the offset.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the written integer does not fit in [`usize`], then an
+    /// error is returned.
+    #[inline]
+    pub fn put_u64_le(&mut self, offset: usize, value: u64) -> Result<(), IndexOutOfBoundsError> {
+        self.put_int_bytes(offset, &value.to_le_bytes())
+    }
+
+    /// Write a big endian [`u64`] at the given offset.
+    ///
+    /// Writing past the end of the buffer extends it, zero-filling any gap
+    /// between the old end and the offset.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the written integer does not fit in [`usize`], then an
+    /// error is returned.
+    #[inline]
+    pub fn put_u64_be(&mut self, offset: usize, value: u64) -> Result<(), IndexOutOfBoundsError> {
+        self.put_int_bytes(offset, &value.to_be_bytes())
+    }
+}
+
+/// Replace invalid byte sequences in a byte string, interpreted according to
+/// the given encoding.
+///
+/// Returns [`None`] if the byte string contains no invalid byte sequences.
+fn scrub_into_vec(buf: &[u8], encoding: Encoding, replacement: Option<&[u8]>) -> Option<Vec<u8>> {
+    let replacement = replacement.unwrap_or("\u{FFFD}".as_bytes());
+    match encoding {
+        Encoding::Binary => None,
+        Encoding::Ascii => {
+            buf.find_non_ascii_byte()?;
+            let mut scrubbed = Vec::with_capacity(buf.len());
+            for &byte in buf {
+                if byte.is_ascii() {
+                    scrubbed.push(byte);
+                } else {
+                    scrubbed.extend_from_slice(replacement);
+                }
+            }
+            Some(scrubbed)
+        }
+        Encoding::Utf8 => {
+            if simdutf8::basic::from_utf8(buf).is_ok() {
+                return None;
+            }
+            let mut scrubbed = Vec::with_capacity(buf.len());
+            let mut bytes = buf;
+            while !bytes.is_empty() {
+                match bstr::decode_utf8(bytes) {
+                    (Some(_), size) => {
+                        let (ch, remainder) = bytes.split_at(size);
+                        scrubbed.extend_from_slice(ch);
+                        bytes = remainder;
+                    }
+                    // Each maximal invalid byte sequence is replaced by the
+                    // replacement once.
+                    (None, size) => {
+                        scrubbed.extend_from_slice(replacement);
+                        bytes = &bytes[size..];
+                    }
+                }
+            }
+            Some(scrubbed)
+        }
+    }
+}
+
+#[must_use]
+fn conventionally_utf8_byte_string_len(mut bytes: &[u8]) -> usize {
+    let tail = if let Some(idx) = bytes.find_non_ascii_byte() {
+        idx
+    } else {
+        return bytes.len();
+    };
+    // Safety:
+    //
+    // If `ByteSlice::find_non_ascii_byte` returns `Some(_)`, the index is
+    // guaranteed to be a valid index within `bytes`.
+    bytes = unsafe { bytes.get_unchecked(tail..) };
+    if simdutf8::basic::from_utf8(bytes).is_ok() {
+        return tail + bytecount::num_chars(bytes);
+    }
+    let mut char_len = tail;
+    for chunk in bytes.utf8_chunks() {
+        char_len += bytecount::num_chars(chunk.valid().as_bytes());
+        char_len += chunk.invalid().len();
+    }
+    char_len
+}
+
+/// Convert a character index into a [conventionally UTF-8] byte string to the
+/// byte offset where that character begins.
+///
+/// Character indices are defined as in [`String::char_len`]: each valid UTF-8
+/// byte sequence is one character and each byte in an invalid UTF-8 byte
+/// sequence is one character.
+///
+/// An index exactly equal to the character length of the byte string maps to
+/// the total length of the byte string. Indices beyond the character length
+/// return [`None`].
+///
+/// [conventionally UTF-8]: Encoding::Utf8
+#[must_use]
+fn conventionally_utf8_char_index_to_byte_offset(bytes: &[u8], index: usize) -> Option<usize> {
+    let total = bytes.len();
+    let mut slice = bytes;
+    let mut remaining = index;
+    loop {
+        if remaining == 0 {
+            return Some(total - slice.len());
+        }
+        match bstr::decode_utf8(slice) {
+            // We've run out of bytes while there are still characters to
+            // count, so the index is out of range.
+            (_, 0) => return None,
+            (Some(_), size) => {
+                slice = &slice[size..];
+                remaining -= 1;
+            }
+            // Each byte in an invalid UTF-8 sequence counts as one character.
+            (None, size) if remaining < size => return Some(total - slice.len() + remaining),
+            (None, size) => {
+                slice = &slice[size..];
+                remaining -= size;
+            }
+        }
+    }
+}
+
+#[must_use]
+fn chomp(string: &mut String, separator: Option<&[u8]>) -> bool {
+    let original_len = string.len();
+    let truncate_to = chomped_len(string.buf.as_slice(), separator);
+    string.buf.truncate(truncate_to);
+    truncate_to != original_len
+}
+
+/// Length of the buffer prefix that [`chomp`] retains for the given record
+/// separator.
+#[must_use]
+fn chomped_len(bytes: &[u8], separator: Option<&[u8]>) -> usize {
+    if bytes.is_empty() {
+        return 0;
+    }
+    match separator {
+        Some(separator) if separator.is_empty() => {
+            let mut iter = bytes.iter().copied().rev().peekable();
+            while let Some(&b'\n') = iter.peek() {
+                iter.next();
+                if let Some(&b'\r') = iter.peek() {
+                    iter.next();
+                }
+            }
+            iter.count()
+        }
+        Some(separator) if bytes.ends_with(separator) => {
+            // This subtraction is guaranteed not to panic because
+            // `separator` is a substring of `bytes`.
+            bytes.len() - separator.len()
+        }
+        Some(_) => bytes.len(),
+        None => {
+            let mut iter = bytes.iter().copied().rev().peekable();
+            match iter.peek() {
+                Some(&b'\n') => {
+                    iter.next();
+                    if let Some(&b'\r') = iter.peek() {
+                        iter.next();
+                    }
+                }
+                Some(b'\r') => {
+                    iter.next();
+                }
+                Some(_) | None => {}
+            };
+            iter.count()
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::shadow_unrelated)]
+#[allow(clippy::invisible_characters)]
+mod tests {
+    use alloc::borrow::Cow;
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use core::str;
+
+    use quickcheck::quickcheck;
+
+    use crate::{conventionally_utf8_byte_string_len, CenterError, Encoding, IndexOutOfBoundsError, RepeatError, String};
+
+    const REPLACEMENT_CHARACTER_BYTES: [u8; 3] = [239, 191, 189];
+
+    #[test]
+    fn utf8_char_len_empty() {
+        let s = "".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 0);
+    }
+
+    #[test]
+    fn utf8_char_len_ascii() {
+        let s = "Artichoke Ruby".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 14);
+    }
+
+    #[test]
+    fn utf8_char_len_emoji() {
+        let s = "💎".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 1);
+        let s = "💎🦀🎉".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 3);
+        let s = "a💎b🦀c🎉d".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
+        // with invalid UFF-8 bytes
+        let s = b"a\xF0\x9F\x92\x8E\xFFabc";
+        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 6);
+    }
+
+    #[test]
+    fn utf8_char_len_unicode_replacement_character() {
+        let s = "�".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 1);
+        let s = "���".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 3);
+        let s = "a�b�c�d".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
+        let s = "�💎b🦀c🎉�".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
+        // with invalid UFF-8 bytes
+        let s = b"\xEF\xBF\xBD\xF0\x9F\x92\x8E\xFF\xEF\xBF\xBDab";
+        assert_eq!(conventionally_utf8_byte_string_len(s), 6);
+        assert_eq!(conventionally_utf8_byte_string_len(&REPLACEMENT_CHARACTER_BYTES[..]), 1);
+    }
+
+    #[test]
+    fn utf8_char_len_nul_byte() {
+        let s = b"\x00";
+        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 1);
+        let s = b"abc\x00";
+        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 4);
+        let s = b"abc\x00xyz";
+        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 7);
+    }
+
+    #[test]
+    fn utf8_char_len_invalid_utf8_byte_sequences() {
+        let s = b"\x00\x00\xD8\x00";
+        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 4);
+        let s = b"\xFF\xFE";
+        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 2);
+    }
+
+    #[test]
+    fn utf8_char_len_binary() {
+        let bytes = &[
+            0xB3, 0x7E, 0x39, 0x70, 0x8E, 0xFD, 0xBB, 0x75, 0x62, 0x77, 0xE7, 0xDF, 0x6F, 0xF2, 0x76, 0x27, 0x81,
+            0x9A, 0x3A, 0x9D, 0xED, 0x6B, 0x4F, 0xAE, 0xC4, 0xE7, 0xA1, 0x66, 0x11, 0xF1, 0x08, 0x1C,
+        ];
+        assert_eq!(conventionally_utf8_byte_string_len(&bytes[..]), 32);
+        // Mixed binary and ASCII
+        let bytes = &[
+            b'?', b'!', b'a', b'b', b'c', 0xFD, 0xBB, 0x75, 0x62, 0x77, 0xE7, 0xDF, 0x6F, 0xF2, 0x76, 0x27, 0x81,
+            0x9A, 0x3A, 0x9D, 0xED, 0x6B, 0x4F, 0xAE, 0xC4, 0xE7, 0xA1, 0x66, 0x11, 0xF1, 0x08, 0x1C,
+        ];
+        assert_eq!(conventionally_utf8_byte_string_len(&bytes[..]), 32);
+    }
+
+    #[test]
+    fn utf8_char_len_mixed_ascii_emoji_invalid_bytes() {
+        // ```
+        // [2.6.3] > s = "🦀abc💎\xff"
+        // => "🦀abc💎\xFF"
+        // [2.6.3] > s.length
+        // => 6
+        // [2.6.3] > puts s.bytes.map{|b| "\\x#{b.to_s(16).upcase}"}.join
+        // \xF0\x9F\xA6\x80\x61\x62\x63\xF0\x9F\x92\x8E\xFF
+        // ```
+        let bytes = b"\xF0\x9F\xA6\x80\x61\x62\x63\xF0\x9F\x92\x8E\xFF";
+        assert_eq!(conventionally_utf8_byte_string_len(&bytes[..]), 6);
+    }
+
+    #[test]
+    fn utf8_char_len_utf8() {
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L147-L157
+        let s = "Ω≈ç√∫˜µ≤≥÷".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 10);
+        let s = "åß∂ƒ©˙∆˚¬…æ".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
+        let s = "œ∑´®†¥¨ˆøπ“‘".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
+        let s = "¡™£¢∞§¶•ªº–≠".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
+        let s = "¸˛Ç◊ı˜Â¯˘¿".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 10);
+        let s = "ÅÍÎÏ˝ÓÔÒÚÆ☃".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
+        let s = "Œ„´‰ˇÁ¨ˆØ∏”’".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
+        let s = "`⁄€‹›ﬁﬂ‡°·‚—±".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 13);
+        let s = "⅛⅜⅝⅞".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 4);
+        let s = "ЁЂЃЄЅІЇЈЉЊЋЌЍЎЏАБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯабвгдежзийклмнопрстуфхцчшщъыьэюя".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 79);
+    }
+
+    #[test]
+    fn utf8_char_len_vmware_super_string() {
+        // A super string recommended by VMware Inc. Globalization Team: can
+        // effectively cause rendering issues or character-length issues to
+        // validate product globalization readiness.
+        //
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L202-L224
+        let s = "表ポあA鷗ŒéＢ逍Üßªąñ丂㐀𠀀".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 17);
+    }
+
+    #[test]
+    fn utf8_char_len_two_byte_chars() {
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L188-L196
+        let s = "田中さんにあげて下さい".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
+        let s = "パーティーへ行かないか".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
+        let s = "和製漢語".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 4);
+        let s = "部落格".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 3);
+        let s = "사회과학원 어학연구소".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
+        let s = "찦차를 타고 온 펲시맨과 쑛다리 똠방각하".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 22);
+        let s = "社會科學院語學研究所".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 10);
+        let s = "울란바토르".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 5);
+        let s = "𠜎𠜱𠝹𠱓𠱸𠲖𠳏".as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
+    }
+
+    #[test]
+    fn utf8_char_len_space_chars() {
+        // Whitespace: all the characters with category Zs, Zl, or Zp (in Unicode
+        // version 8.0.0), plus U+0009 (HT), U+000B (VT), U+000C (FF), U+0085 (NEL),
+        // and U+200B (ZERO WIDTH SPACE), which are in the C categories but are often
+        // treated as whitespace in some contexts.
+        // This file unfortunately cannot express strings containing
+        // U+0000, U+000A, or U+000D (NUL, LF, CR).
+        // The next line may appear to be blank or mojibake in some viewers.
+        // The next line may be flagged for "trailing whitespace" in some viewers.
+        //
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L131
+        let s = "	              ​    　
+"
+        .as_bytes();
+        assert_eq!(conventionally_utf8_byte_string_len(s), 24);
+    }
+
+    quickcheck! {
+        #[allow(clippy::needless_pass_by_value)]
+        fn try_from_agrees_with_is_valid_encoding(contents: Vec<u8>) -> bool {
+            let s = String::utf8(contents);
+            <&str>::try_from(&s).is_ok() == s.is_valid_encoding()
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_ascii_cache_agrees_with_rescans(contents: Vec<u8>, ops: Vec<u8>) -> bool {
+            let mut s = String::utf8(contents);
+            for op in ops {
+                // Interleave reads which warm the ASCII-ness cache with
+                // mutations which must invalidate it.
+                match op % 4 {
+                    0 => s.push_byte(op),
+                    1 => s.make_uppercase(),
+                    2 => {
+                        if let Some(last) = s.as_mut_slice().last_mut() {
+                            *last = op;
+                        }
+                    }
+                    _ => {
+                        let _ = s.pop_byte();
+                    }
+                }
+                if s.is_ascii_only() != s.as_slice().is_ascii() {
+                    return false;
+                }
+                if s.char_len() != conventionally_utf8_byte_string_len(s.as_slice()) {
+                    return false;
+                }
+            }
+            true
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_byteslice_agrees_with_byte_slicing(contents: Vec<u8>, start: usize, len: usize) -> bool {
+            let expected = if start > contents.len() {
+                None
+            } else {
+                let end = start.saturating_add(len).min(contents.len());
+                contents.get(start..end)
+            };
+            let s = String::utf8(contents.clone());
+            match (i64::try_from(start), i64::try_from(len)) {
+                (Ok(start), Ok(len)) => s.byteslice(start, Some(len)) == expected,
+                // Inputs beyond `i64` cannot be expressed as byteslice
+                // arguments.
+                _ => true,
+            }
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_byteslice_range_agrees_with_byte_slicing(contents: Vec<u8>, start: usize, end: usize) -> bool {
+            let expected = if start > contents.len() {
+                None
+            } else {
+                let end = end.min(contents.len()).max(start);
+                contents.get(start..end)
+            };
+            let s = String::utf8(contents.clone());
+            match (i64::try_from(start), i64::try_from(end)) {
+                (Ok(start), Ok(end)) => s.byteslice_range(start, end, true) == expected,
+                _ => true,
+            }
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_char_len_utf8_contents_utf8_string(contents: alloc::string::String) -> bool {
+            let expected = contents.chars().count();
+            let s = String::utf8(contents.into_bytes());
+            s.char_len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_len_utf8_contents_utf8_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::utf8(contents.into_bytes());
+            s.len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_bytesize_utf8_contents_utf8_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::utf8(contents.into_bytes());
+            s.bytesize() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_char_len_binary_contents_utf8_string(contents: Vec<u8>) -> bool {
+            if let Ok(utf8_contents) = str::from_utf8(&contents) {
+                let expected = utf8_contents.chars().count();
+                let s = String::utf8(contents);
+                s.char_len() == expected
+            } else {
+                let expected_at_most = contents.len();
+                let s = String::utf8(contents);
+                s.char_len() <= expected_at_most
+            }
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_len_binary_contents_utf8_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::utf8(contents);
+            s.len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_bytesize_binary_contents_utf8_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::utf8(contents);
+            s.bytesize() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_char_len_utf8_contents_ascii_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::ascii(contents.into_bytes());
+            s.char_len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_len_utf8_contents_ascii_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::ascii(contents.into_bytes());
+            s.len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_bytesize_utf8_contents_ascii_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::ascii(contents.into_bytes());
+            s.bytesize() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_char_len_binary_contents_ascii_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::ascii(contents);
+            s.char_len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_len_binary_contents_ascii_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::ascii(contents);
+            s.len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_bytesize_binary_contents_ascii_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::ascii(contents);
+            s.bytesize() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_char_len_utf8_contents_binary_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::binary(contents.into_bytes());
+            s.char_len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_len_utf8_contents_binary_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::binary(contents.into_bytes());
+            s.len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_bytesize_utf8_contents_binary_string(contents: alloc::string::String) -> bool {
+            let expected = contents.len();
+            let s = String::binary(contents.into_bytes());
+            s.bytesize() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_char_len_binary_contents_binary_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::binary(contents);
+            s.char_len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_len_binary_contents_binary_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::binary(contents);
+            s.len() == expected
+        }
+
+        #[allow(clippy::needless_pass_by_value)]
+        fn fuzz_bytesize_binary_contents_binary_string(contents: Vec<u8>) -> bool {
+            let expected = contents.len();
+            let s = String::binary(contents);
+            s.bytesize() == expected
+        }
+    }
+
+    #[test]
+    fn make_capitalized_utf8_string_empty() {
+        let mut s = String::utf8(b"".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn make_capitalized_utf8_string_ascii() {
+        let mut s = String::utf8(b"abc".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::utf8(b"aBC".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::utf8(b"ABC".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::utf8(b"aBC, 123, ABC, baby you and me girl".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc, 123, abc, baby you and me girl");
+    }
+
+    #[test]
+    fn make_capitalized_utf8_string_utf8() {
+        let mut s = String::utf8("ß".to_string().into_bytes());
+        s.make_capitalized();
+        // This differs from MRI:
+        //
+        // ```console
+        // [2.6.3] > "ß".capitalize
+        // => "Ss"
+        // ```
+        assert_eq!(s, "SS");
+
+        let mut s = String::utf8("αύριο".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "Αύριο");
+
+        let mut s = String::utf8("έτος".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "Έτος");
+
+        // two-byte characters
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L198-L200
+        let mut s = String::utf8(
+            "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆"
+                .to_string()
+                .into_bytes(),
+        );
+        s.make_capitalized();
+        assert_eq!(s, "𐐜 𐐼𐐯𐑅𐐨𐑉𐐯𐐻 𐑁𐐲𐑉𐑅𐐻/𐑅𐐯𐐿𐐲𐑌𐐼 𐐺𐐳𐐿 𐐺𐐴 𐑄 𐑉𐐨𐐾𐐯𐑌𐐻𐑅 𐐱𐑂 𐑄 𐐼𐐯𐑅𐐨𐑉𐐯𐐻 𐐷𐐮𐐭𐑌𐐮𐑂𐐲𐑉𐑅𐐮𐐻𐐮");
+
+        // Change length when lower-cased
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L226-L232
+        let mut s = String::utf8("zȺȾ".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "Zⱥⱦ");
+    }
+
+    #[test]
+    fn make_capitalized_utf8_string_invalid_utf8() {
+        let mut s = String::utf8(b"\xFF\xFE".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, &b"\xFF\xFE"[..]);
+    }
+
+    #[test]
+    fn make_capitalized_utf8_string_unicode_replacement_character() {
+        let mut s = String::utf8("�".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "�");
+    }
+
+    #[test]
+    fn make_capitalized_ascii_string_empty() {
+        let mut s = String::ascii(b"".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn make_capitalized_ascii_string_ascii() {
+        let mut s = String::ascii(b"abc".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::ascii(b"aBC".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::ascii(b"ABC".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::ascii(b"aBC, 123, ABC, baby you and me girl".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc, 123, abc, baby you and me girl");
+    }
+
+    #[test]
+    fn make_capitalized_ascii_string_utf8() {
+        let mut s = String::ascii("ß".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "ß");
+
+        let mut s = String::ascii("αύριο".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "αύριο");
+
+        let mut s = String::ascii("έτος".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "έτος");
+
+        // two-byte characters
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L198-L200
+        let mut s = String::ascii(
+            "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆"
+                .to_string()
+                .into_bytes(),
+        );
+        s.make_capitalized();
+        assert_eq!(s, "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆");
+
+        // Change length when lower-cased
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L226-L232
+        let mut s = String::ascii("zȺȾ".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "ZȺȾ");
+    }
+
+    #[test]
+    fn make_capitalized_ascii_string_invalid_utf8() {
+        let mut s = String::ascii(b"\xFF\xFE".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, &b"\xFF\xFE"[..]);
+    }
+
+    #[test]
+    fn make_capitalized_ascii_string_unicode_replacement_character() {
+        let mut s = String::ascii("�".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "�");
+    }
+
+    #[test]
+    fn make_capitalized_binary_string_empty() {
+        let mut s = String::binary(b"".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "");
+    }
+
+    #[test]
+    fn make_capitalized_binary_string_ascii() {
+        let mut s = String::binary(b"abc".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::ascii(b"aBC".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::ascii(b"ABC".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc");
+
+        let mut s = String::ascii(b"aBC, 123, ABC, baby you and me girl".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, "Abc, 123, abc, baby you and me girl");
+    }
+
+    #[test]
+    fn make_capitalized_binary_string_utf8() {
+        let mut s = String::binary("ß".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "ß");
+
+        let mut s = String::binary("αύριο".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "αύριο");
+
+        let mut s = String::binary("έτος".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "έτος");
+
+        // two-byte characters
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L198-L200
+        let mut s = String::binary(
+            "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆"
+                .to_string()
+                .into_bytes(),
+        );
+        s.make_capitalized();
+        assert_eq!(s, "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆");
+
+        // Change length when lower-cased
+        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L226-L232
+        let mut s = String::binary("zȺȾ".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "ZȺȾ");
+    }
+
+    #[test]
+    fn make_capitalized_binary_string_invalid_utf8() {
+        let mut s = String::binary(b"\xFF\xFE".to_vec());
+        s.make_capitalized();
+        assert_eq!(s, &b"\xFF\xFE"[..]);
+    }
+
+    #[test]
+    fn make_capitalized_binary_string_unicode_replacement_character() {
+        let mut s = String::binary("�".to_string().into_bytes());
+        s.make_capitalized();
+        assert_eq!(s, "�");
+    }
+
+    #[test]
+    fn center_returns_error_with_empty_padding() {
+        let s = String::utf8(b"jumbo".to_vec());
+        let center = s.center(10, Some(b""));
+        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+
+        let center = s.center(9, Some(b""));
+        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+
+        let center = s.center(1, Some(b""));
+        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+
+        let center = s.center(5, Some(b""));
+        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+    }
+
+    #[test]
+    fn chr_does_not_return_more_than_one_byte_for_invalid_utf8() {
+        // ```ruby
+        // [3.0.1] > "\xF0\x9F\x87".chr
+        // => "\xF0"
+        // ```
+        //
+        // Per `bstr`:
+        //
+        // The bytes `\xF0\x9F\x87` could lead to a valid UTF-8 sequence, but 3 of them
+        // on their own are invalid. Only one replacement codepoint is substituted,
+        // which demonstrates the "substitution of maximal subparts" strategy.
+        let s = String::utf8(b"\xF0\x9F\x87".to_vec());
+        assert_eq!(s.chr(), b"\xF0");
+    }
+
+    // `String#succ` test cases are ported from ruby/spec:
+    //
+    // https://github.com/ruby/spec/blob/master/core/string/shared/succ.rb
+    #[test]
+    fn succ_returns_the_successor_by_increasing_the_rightmost_alphanumeric() {
+        assert_eq!(String::from("abcd").succ(), "abce");
+        assert_eq!(String::from("THX1138").succ(), "THX1139");
+
+        assert_eq!(String::from("<<koala>>").succ(), "<<koalb>>");
+        assert_eq!(String::from("==A??").succ(), "==B??");
+    }
+
+    #[test]
+    fn succ_increases_nonalphanumerics_if_there_are_no_alphanumerics() {
+        assert_eq!(String::from("***").succ(), "**+");
+        assert_eq!(String::from("**`").succ(), "**a");
+    }
+
+    #[test]
+    fn succ_increases_the_next_best_alphanumeric_on_carry() {
+        assert_eq!(String::from("dz").succ(), "ea");
+        assert_eq!(String::from("HZ").succ(), "IA");
+        assert_eq!(String::from("49").succ(), "50");
+
+        assert_eq!(String::from("izz").succ(), "jaa");
+        assert_eq!(String::from("IZZ").succ(), "JAA");
+        assert_eq!(String::from("699").succ(), "700");
+
+        assert_eq!(String::from("zaz").succ(), "zba");
+        assert_eq!(String::from("AZA").succ(), "AZB");
+        assert_eq!(String::from("909").succ(), "910");
+    }
+
+    #[test]
+    fn succ_carries_over_the_leftmost_alphanumeric_skipping_nonalphanumerics() {
+        assert_eq!(String::from("1.9").succ(), "2.0");
+        assert_eq!(String::from("a.z").succ(), "b.a");
+        assert_eq!(String::from("1999zzz").succ(), "2000aaa");
+        assert_eq!(String::from("NZ/[]ZZZ9999").succ(), "OA/[]AAA0000");
+    }
+
+    #[test]
+    fn succ_adds_an_additional_character_if_there_is_a_carry_on_the_leftmost_alphanumeric() {
+        assert_eq!(String::from("z").succ(), "aa");
+        assert_eq!(String::from("Z").succ(), "AA");
+        assert_eq!(String::from("9").succ(), "10");
+
+        assert_eq!(String::from("zz").succ(), "aaa");
+        assert_eq!(String::from("ZZ").succ(), "AAA");
+        assert_eq!(String::from("99").succ(), "100");
+
+        assert_eq!(String::from("Zz").succ(), "AAa");
+        assert_eq!(String::from("-9").succ(), "-10");
+        assert_eq!(String::from("ZZZ9999").succ(), "AAAA0000");
+    }
+
+    #[test]
+    fn succ_of_empty_string_is_empty() {
+        assert_eq!(String::new().succ(), "");
+    }
+
+    #[test]
+    fn succ_increments_bytes_with_carry_if_there_are_no_alphanumerics() {
+        let s = String::binary(b"\xFF".to_vec());
+        assert_eq!(s.succ(), &b"\x01\x00"[..]);
+        let s = String::binary(b"\xFF\xFF".to_vec());
+        assert_eq!(s.succ(), &b"\x01\x00\x00"[..]);
+        let s = String::binary(b"*\xFF".to_vec());
+        assert_eq!(s.succ(), &b"+\x00"[..]);
+    }
+
+    #[test]
+    fn succ_increments_the_codepoint_of_trailing_multibyte_characters() {
+        // ```
+        // [3.0.1] > "☃".succ
+        // => "☄"
+        // [3.0.1] > "!☃".succ
+        // => "!☄"
+        // ```
+        let s = String::utf8("☃".as_bytes().to_vec());
+        assert_eq!(s.succ(), "☄");
+        let s = String::utf8("!☃".as_bytes().to_vec());
+        assert_eq!(s.succ(), "!☄");
+        // Alphanumerics take precedence over multibyte characters.
+        let s = String::utf8("a☃".as_bytes().to_vec());
+        assert_eq!(s.succ(), "b☃");
+    }
+
+    #[test]
+    fn make_succ_modifies_in_place() {
+        let mut s = String::from("abc");
+        s.make_succ();
+        assert_eq!(s, "abd");
+    }
+
+    #[test]
+    fn upto_steps_by_successor_through_length_changes() {
+        let s = String::from("a8");
+        assert_eq!(s.upto(b"b1", false).collect::<Vec<_>>(), ["a8", "a9", "b0", "b1"]);
+        let s = String::from("az");
+        assert_eq!(s.upto(b"ba", false).collect::<Vec<_>>(), ["az", "ba"]);
+        let s = String::from("aa");
+        assert_eq!(s.upto(b"ac", false).collect::<Vec<_>>(), ["aa", "ab", "ac"]);
+    }
+
+    #[test]
+    fn upto_with_exclusive_end_does_not_yield_the_last_value() {
+        let s = String::from("a8");
+        assert_eq!(s.upto(b"b1", true).collect::<Vec<_>>(), ["a8", "a9", "b0"]);
+        let s = String::from("a");
+        assert_eq!(s.upto(b"a", true).count(), 0);
+    }
+
+    #[test]
+    fn upto_counts_all_ascii_digit_endpoints_numerically() {
+        // ```
+        // [3.0.1] > "9".upto("11").to_a
+        // => ["9", "10", "11"]
+        // ```
+        let s = String::from("9");
+        assert_eq!(s.upto(b"11", false).collect::<Vec<_>>(), ["9", "10", "11"]);
+        // Values shorter than the first endpoint are zero padded.
+        let s = String::from("08");
+        assert_eq!(s.upto(b"10", false).collect::<Vec<_>>(), ["08", "09", "10"]);
+        let s = String::from("9");
+        assert_eq!(s.upto(b"11", true).collect::<Vec<_>>(), ["9", "10"]);
+    }
 
-        // If the start of the range is beyond the character count of the
-        // string, the whole lookup must fail.
-        //
-        // Slice lookups where the start is just beyond the last character index
-        // always return an empty slice.
-        //
+    #[test]
+    fn upto_counts_single_ascii_character_endpoints_bytewise() {
         // ```
-        // [3.0.1] > "aaa"[10, 0]
-        // => nil
-        // [3.0.1] > "aaa"[10, 7]
-        // => nil
-        // [3.0.1] > "aaa"[3, 7]
-        // => ""
-        // [3.0.1] > "🦀💎"[2, 0]
-        // => ""
-        // [3.0.1] > "🦀💎"[3, 1]
-        // => nil
-        // [3.0.1] > "🦀💎"[2, 1]
-        // => ""
+        // [3.0.1] > "Y".upto("b").to_a
+        // => ["Y", "Z", "[", "\\", "]", "^", "_", "`", "a", "b"]
         // ```
-        //
-        // Fast path rejection for indexes beyond bytesize, which is cheap to
-        // retrieve.
-        if start > self.len() {
-            return None;
-        }
-        match self.char_len() {
-            char_length if start > char_length => return None,
-            char_length if start == char_length => return Some(&[]),
-            _ => {}
-        }
+        let s = String::from("Y");
+        assert_eq!(
+            s.upto(b"b", false).collect::<Vec<_>>(),
+            ["Y", "Z", "[", "\\", "]", "^", "_", "`", "a", "b"]
+        );
+    }
 
-        // The span is guaranteed to at least partially overlap now.
-        match end - start {
-            // Empty substrings are present in all strings, even empty ones.
-            //
-            // ```
-            // [3.0.1] > "aaa"[""]
-            // => ""
-            // [3.0.1] > ""[""]
-            // => ""
-            // [3.0.1] > ""[0, 0]
-            // => ""
-            // [3.0.1] > "aaa"[0, 0]
-            // => ""
-            // [3.0.1] > "aaa"[2, 0]
-            // => ""
-            // [3.0.1] > "🦀💎"[1, 0]
-            // => ""
-            // [3.0.1] > "🦀💎"[2, 0]
-            // => ""
-            // ```
-            0 => return Some(&[]),
-            // Delegate to the specialized single char lookup, which allows the
-            // remainder of this routine to fall back to the general case of
-            // multi-character spans.
-            //
-            // ```
-            // [3.0.1] > "abc"[2, 1]
-            // => "c"
-            // [3.0.1] > "🦀💎"[1, 1]
-            // => "💎"
-            // ```
-            1 => return self.get_char(start),
-            _ => {}
-        }
+    #[test]
+    fn upto_yields_nothing_when_the_last_value_is_smaller() {
+        let s = String::from("zz");
+        assert_eq!(s.upto(b"aa", false).count(), 0);
+        let s = String::from("za");
+        assert_eq!(s.upto(b"ab", false).count(), 0);
+        let s = String::from("");
+        assert_eq!(s.upto(b"", false).count(), 0);
+    }
 
-        match self.encoding {
-            // Ruby slice lookups saturate to the end of the string even if the
-            // ending index is beyond the string's character count.
-            //
-            // ```
-            // [3.0.1] > "abc"[1, 10]
-            // => "bc"
-            // [3.0.1] > "🦀💎"[0, 10]
-            // => "🦀💎"
-            // ```
-            Encoding::Ascii | Encoding::Binary => self.buf.get(start..end).or_else(|| self.buf.get(start..)),
-            Encoding::Utf8 => {
-                // Fast path for trying to treat the conventionally UTF-8 string
-                // as entirely ASCII.
-                //
-                // If the string is either all ASCII or all ASCII for the subset
-                // of the string we wish to slice, fallback to byte slicing as in
-                // the ASCII and binary fast path.
-                //
-                // Perform the same saturate-to-end slicing mechanism if `end`
-                // is beyond the character length of the string.
-                let consumed = match self.buf.find_non_ascii_byte() {
-                    // The entire string is ASCII, so byte indexing <=> char
-                    // indexing.
-                    None => return self.buf.get(start..end).or_else(|| self.buf.get(start..)),
-                    // The whole substring we are interested in is ASCII, so
-                    // byte indexing is still valid.
-                    Some(non_ascii_byte_offset) if non_ascii_byte_offset > end => return self.get(start..end),
-                    // We turn non-ASCII somewhere inside before the substring
-                    // we're interested in, so consume that much.
-                    Some(non_ascii_byte_offset) if non_ascii_byte_offset <= start => non_ascii_byte_offset,
-                    // This means we turn non-ASCII somewhere inside the substring.
-                    // Consume up to start.
-                    Some(_) => start,
-                };
-                // Scan for the beginning of the slice
-                let mut slice = &self.buf[consumed..];
-                // Count of "characters" remaining until the `start`th character.
-                let mut remaining = start - consumed;
-                if remaining > 0 {
-                    // This loop will terminate when either:
-                    //
-                    // - It counts `start` number of characters.
-                    // - It consumes the entire slice when scanning for the
-                    //   `start`th character.
-                    //
-                    // The loop will advance by at least one byte every iteration.
-                    slice = loop {
-                        match bstr::decode_utf8(slice) {
-                            // If we've run out of slice while trying to find the
-                            // `start`th character, the lookup fails and we return `nil`.
-                            (_, 0) => return None,
+    #[test]
+    fn upto_terminates_and_fuses_for_incomparable_endpoints() {
+        let s = String::from("a");
+        let mut upto = s.upto(b"%", false);
+        assert_eq!(upto.next(), None);
+        assert_eq!(upto.next(), None);
+        // The endpoint is never reachable by successor, so iteration stops
+        // once the yielded values grow longer than the endpoint: "a".."z",
+        // "aa".."zz", and then "aaa" is longer than "b1".
+        let s = String::from("a");
+        assert_eq!(s.upto(b"b1", false).count(), 26 + 676);
+    }
 
-                            // We found a single UTF-8 encoded character. keep track
-                            // of the count and advance the substring to continue
-                            // decoding.
-                            //
-                            // If there's only one more to go, advance and stop the
-                            // loop.
-                            (Some(_), size) if remaining == 1 => break &slice[size..],
-                            // Otherwise, keep track of the character we observed and
-                            // advance the slice to continue decoding.
-                            (Some(_), size) => {
-                                slice = &slice[size..];
-                                remaining -= 1;
-                            }
+    #[test]
+    fn insert_str_at_char_index() {
+        let mut s = String::utf8("a💎c".as_bytes().to_vec());
+        s.insert_str(2, b"b").unwrap();
+        assert_eq!(s, "a💎bc");
+        // An index exactly equal to the character length appends.
+        s.insert_str(4, b"d").unwrap();
+        assert_eq!(s, "a💎bcd");
+        assert_eq!(
+            s.insert_str(6, b"x"),
+            Err(crate::IndexOutOfBoundsError::with_index_and_len(6, 8))
+        );
+    }
 
-                            // The next two arms handle the case where we have
-                            // encountered an invalid UTF-8 byte sequence.
-                            //
-                            // In this case, `decode_utf8` will return slices whose
-                            // length is `1..=3`. The length of this slice is the
-                            // number of "characters" we can advance the loop by.
-                            //
-                            // If the invalid UTF-8 sequence contains more bytes
-                            // than we have remaining to get to the `start`th char,
-                            // then we can break the loop directly.
-                            (None, size) if remaining <= size => break &slice[remaining..],
-                            // If there are more characters remaining than the number
-                            // of bytes yielded in the invalid UTF-8 byte sequence,
-                            // count `size` bytes and advance the slice to continue
-                            // decoding.
-                            (None, size) => {
-                                slice = &slice[size..];
-                                remaining -= size;
-                            }
-                        }
-                    }
-                };
+    #[test]
+    fn insert_str_binary_is_byte_indexed() {
+        let mut s = String::binary("💎".as_bytes().to_vec());
+        s.insert_str(2, b"!").unwrap();
+        assert_eq!(s, &b"\xF0\x9F!\x92\x8E"[..]);
+        assert!(s.insert_str(6, b"x").is_err());
+    }
 
-                // Scan the slice for the span of characters we want to return.
-                remaining = end - start;
-                // We know `remaining` is not zero because we fast-pathed that
-                // case above.
-                debug_assert!(remaining > 0);
+    #[test]
+    fn drain_range_removes_chars_and_preserves_encoding() {
+        let mut s = String::utf8("a💎bc".as_bytes().to_vec());
+        let removed = s.drain_range(1, 2).unwrap();
+        assert_eq!(removed, "💎b");
+        assert_eq!(removed.encoding(), crate::Encoding::Utf8);
+        assert_eq!(s, "ac");
+
+        // Overlong lengths are clamped to the end of the string.
+        let mut s = String::from("abc");
+        assert_eq!(s.drain_range(1, 10).unwrap(), "bc");
+        assert_eq!(s, "a");
+
+        // A start exactly equal to the character length removes nothing.
+        let mut s = String::from("abc");
+        assert_eq!(s.drain_range(3, 1).unwrap(), "");
+        assert_eq!(s, "abc");
+        assert_eq!(s.drain_range(4, 1), None);
+    }
 
-                // keep track of the start of the substring from the `start`th
-                // character.
-                let substr = slice;
+    #[test]
+    fn count_intersects_multiple_sets() {
+        // ```
+        // [3.0.1] > a = "hello world"
+        // [3.0.1] > a.count("lo")
+        // => 5
+        // [3.0.1] > a.count("lo", "o")
+        // => 2
+        // [3.0.1] > a.count("hello", "^l")
+        // => 4
+        // [3.0.1] > a.count("ej-m")
+        // => 4
+        // ```
+        let s = String::from("hello world");
+        assert_eq!(s.count(&[b"lo"]), 5);
+        assert_eq!(s.count(&[b"lo", b"o"]), 2);
+        assert_eq!(s.count(&[b"hello", b"^l"]), 4);
+        assert_eq!(s.count(&[b"ej-m"]), 4);
+        assert_eq!(s.count(&[]), 0);
+    }
 
-                // This loop will terminate when either:
-                //
-                // - It counts the next `start - end` number of characters.
-                // - It consumes the entire slice when scanning for the `end`th
-                //   character.
-                //
-                // The loop will advance by at least one byte every iteration.
-                loop {
-                    match bstr::decode_utf8(slice) {
-                        // If we've run out of slice while trying to find the `end`th
-                        // character, saturate the slice to the end of the string.
-                        (_, 0) => return Some(substr),
+    #[test]
+    fn count_negated_sets() {
+        let s = String::from("hello");
+        assert_eq!(s.count(&[b"^l"]), 3);
+        assert_eq!(s.count(&[b"^a-y"]), 0);
+    }
 
-                        // We found a single UTF-8 encoded character. keep track
-                        // of the count and advance the substring to continue
-                        // decoding.
-                        //
-                        // If there's only one more to go, advance and stop the
-                        // loop.
-                        (Some(_), size) if remaining == 1 => {
-                            // Push `endth` more positive because this match has
-                            // the effect of shrinking `slice`.
-                            let endth = substr.len() - slice.len() + size;
-                            return Some(&substr[..endth]);
-                        }
-                        // Otherwise, keep track of the character we observed and
-                        // advance the slice to continue decoding.
-                        (Some(_), size) => {
-                            slice = &slice[size..];
-                            remaining -= 1;
-                        }
+    #[test]
+    fn count_multibyte_ranges_and_invalid_bytes() {
+        let s = String::utf8("aαβγz".as_bytes().to_vec());
+        assert_eq!(s.count(&["α-γ".as_bytes()]), 3);
+
+        // Invalid UTF-8 bytes only match sets containing that literal byte.
+        let s = String::utf8(b"a\xFFb".to_vec());
+        assert_eq!(s.count(&[b"\xFF"]), 1);
+        assert_eq!(s.count(&[b"a-z"]), 2);
+
+        // Binary strings count per byte.
+        let s = String::binary("💎".as_bytes().to_vec());
+        assert_eq!(s.count(&[b"\x00-\xFF"]), 4);
+    }
+
+    #[test]
+    fn graphemes_zwj_emoji_is_one_cluster() {
+        // U+1F468 U+200D U+1F469 U+200D U+1F466 is a family emoji joined with
+        // ZERO WIDTH JOINER.
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}";
+        let s = String::utf8(format!("a{family}b").into_bytes());
+        let mut graphemes = s.graphemes();
+        assert_eq!(graphemes.next(), Some(&b"a"[..]));
+        assert_eq!(graphemes.next(), Some(family.as_bytes()));
+        assert_eq!(graphemes.next(), Some(&b"b"[..]));
+        assert_eq!(graphemes.next(), None);
+        assert_eq!(s.grapheme_len(), 3);
+    }
+
+    #[test]
+    fn graphemes_combining_marks_join_their_base() {
+        let s = String::utf8("e\u{301}a\u{300}\u{316}".as_bytes().to_vec());
+        let mut graphemes = s.graphemes();
+        assert_eq!(graphemes.next(), Some("e\u{301}".as_bytes()));
+        assert_eq!(graphemes.next(), Some("a\u{300}\u{316}".as_bytes()));
+        assert_eq!(graphemes.next(), None);
+        assert_eq!(s.grapheme_len(), 2);
+    }
+
+    #[test]
+    fn graphemes_crlf_is_one_cluster() {
+        let s = String::utf8(b"a\r\nb".to_vec());
+        let mut graphemes = s.graphemes();
+        assert_eq!(graphemes.next(), Some(&b"a"[..]));
+        assert_eq!(graphemes.next(), Some(&b"\r\n"[..]));
+        assert_eq!(graphemes.next(), Some(&b"b"[..]));
+        assert_eq!(graphemes.next(), None);
+        assert_eq!(s.grapheme_len(), 3);
+    }
+
+    #[test]
+    fn graphemes_invalid_utf8_bytes_yield_per_byte() {
+        let s = String::utf8(b"a\xF0\x9F\x87b".to_vec());
+        let mut graphemes = s.graphemes();
+        assert_eq!(graphemes.next(), Some(&b"a"[..]));
+        assert_eq!(graphemes.next(), Some(&b"\xF0"[..]));
+        assert_eq!(graphemes.next(), Some(&b"\x9F"[..]));
+        assert_eq!(graphemes.next(), Some(&b"\x87"[..]));
+        assert_eq!(graphemes.next(), Some(&b"b"[..]));
+        assert_eq!(graphemes.next(), None);
+        assert_eq!(s.grapheme_len(), 5);
+    }
+
+    #[test]
+    fn graphemes_ascii_and_binary_strings_yield_per_byte() {
+        let s = String::binary("e\u{301}".as_bytes().to_vec());
+        assert_eq!(s.graphemes().count(), 3);
+        assert_eq!(s.grapheme_len(), 3);
+
+        let s = String::ascii(b"abc".to_vec());
+        let graphemes = s.graphemes().collect::<Vec<_>>();
+        assert_eq!(graphemes, [&b"a"[..], b"b", b"c"]);
+    }
+
+    #[test]
+    #[cfg(feature = "casecmp")]
+    fn unicode_casecmp_ordering_strings_differing_only_in_case_are_equal() {
+        use core::cmp::Ordering;
+
+        use crate::CaseFold;
+
+        let left = String::utf8(b"Abc".to_vec());
+        let right = String::utf8(b"aBC".to_vec());
+        assert_eq!(
+            left.unicode_casecmp_ordering(&right, CaseFold::new()),
+            Some(Ordering::Equal)
+        );
+
+        let left = String::utf8("Straße".as_bytes().to_vec());
+        let right = String::utf8("STRASSE".as_bytes().to_vec());
+        assert_eq!(
+            left.unicode_casecmp_ordering(&right, CaseFold::new()),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "casecmp")]
+    fn unicode_casecmp_ordering_prefixes_order_before_longer_strings() {
+        use core::cmp::Ordering;
+
+        use crate::CaseFold;
+
+        let left = String::utf8(b"abc".to_vec());
+        let right = String::utf8(b"ABCD".to_vec());
+        assert_eq!(
+            left.unicode_casecmp_ordering(&right, CaseFold::new()),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            right.unicode_casecmp_ordering(&left, CaseFold::new()),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "casecmp")]
+    fn unicode_casecmp_ordering_default_fold_is_turkic_insensitive() {
+        use core::cmp::Ordering;
+
+        use crate::CaseFold;
+
+        // The default full Unicode case folding scheme does not map dotted and
+        // dotless I to each other; the Turkic scheme does.
+        let left = String::utf8("İstanbul".as_bytes().to_vec());
+        let right = String::utf8("istanbul".as_bytes().to_vec());
+        assert_ne!(
+            left.unicode_casecmp_ordering(&right, CaseFold::new()),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            left.unicode_casecmp_ordering(&right, CaseFold::Turkic),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "casecmp")]
+    fn unicode_casecmp_ordering_invalid_utf8_is_none() {
+        use crate::CaseFold;
+
+        let left = String::utf8(b"abc\xFF".to_vec());
+        let right = String::utf8(b"ABC\xFF".to_vec());
+        assert_eq!(left.unicode_casecmp_ordering(&right, CaseFold::new()), None);
+    }
 
-                        // The next two arms handle the case where we have
-                        // encountered an invalid UTF-8 byte sequence.
-                        //
-                        // In this case, `decode_utf8` will return slices whose
-                        // length is `1..=3`. The length of this slice is the
-                        // number of "characters" we can advance the loop by.
-                        //
-                        // If the invalid UTF-8 sequence contains more bytes
-                        // than we have remaining to get to the `end`th char,
-                        // then we can break the loop directly.
-                        (None, size) if remaining <= size => {
-                            // For an explanation of this arithmetic:
-                            // If we're trying to slice:
-                            //
-                            // ```
-                            // s = "a\xF0\x9F\x87"
-                            // s[0, 2]
-                            // ```
-                            //
-                            // By the time we get to this branch in this loop:
-                            //
-                            // ```
-                            // substr = "a\xF0\x9F\x87"
-                            // slice = "\xF0\x9F\x87"
-                            // remaining = 1
-                            // ```
-                            //
-                            // We want to compute `endth == 2`:
-                            //
-                            //    2   =      4       -      3      +     1
-                            let endth = substr.len() - slice.len() + remaining;
-                            return Some(&substr[..endth]);
-                        }
-                        // If there are more characters remaining than the number
-                        // of bytes yielded in the invalid UTF-8 byte sequence,
-                        // count `size` bytes and advance the slice to continue
-                        // decoding.
-                        (None, size) => {
-                            slice = &slice[size..];
-                            remaining -= size;
-                        }
-                    }
-                }
-            }
-        }
+    #[test]
+    #[cfg(feature = "casecmp")]
+    fn unicode_casecmp_ordering_binary_strings_use_ascii_fallback() {
+        use core::cmp::Ordering;
+
+        use crate::CaseFold;
+
+        // Invalid UTF-8 bytes are permitted when either string is not
+        // conventionally UTF-8.
+        let left = String::binary(b"abc\xFF".to_vec());
+        let right = String::utf8(b"ABC\xFF".to_vec());
+        assert_eq!(
+            left.unicode_casecmp_ordering(&right, CaseFold::new()),
+            Some(Ordering::Equal)
+        );
     }
 
-    /// Returns true for a `String` which is encoded correctly.
-    ///
-    /// For this method to return true, `String`s with [conventionally UTF-8]
-    /// must be well-formed UTF-8; [ASCII]-encoded `String`s must only contain
-    /// bytes in the range `0..=127`; [binary]-encoded `String`s may contain any
-    /// byte sequence.
-    ///
-    /// This method is suitable for implementing the Ruby method
-    /// [`String#valid_encoding?`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use spinoso_string::{Encoding, String};
-    ///
-    /// let s = String::utf8(b"xyz".to_vec());
-    /// assert!(s.is_valid_encoding());
-    /// let s = String::utf8("🚀".to_string().into_bytes());
-    /// assert!(s.is_valid_encoding());
-    /// let s = String::utf8(b"abc\xFF\xFExyz".to_vec());
-    /// assert!(!s.is_valid_encoding());
-    ///
-    /// let s = String::ascii(b"xyz".to_vec());
-    /// assert!(s.is_valid_encoding());
-    /// let s = String::ascii("🚀".to_string().into_bytes());
-    /// assert!(!s.is_valid_encoding());
-    /// let s = String::ascii(b"abc\xFF\xFExyz".to_vec());
-    /// assert!(!s.is_valid_encoding());
-    ///
-    /// let s = String::binary(b"xyz".to_vec());
-    /// assert!(s.is_valid_encoding());
-    /// let s = String::binary("🚀".to_string().into_bytes());
-    /// assert!(s.is_valid_encoding());
-    /// let s = String::binary(b"abc\xFF\xFExyz".to_vec());
-    /// assert!(s.is_valid_encoding());
-    /// ```
-    ///
-    /// [conventionally UTF-8]: crate::Encoding::Utf8
-    /// [ASCII]: crate::Encoding::Ascii
-    /// [binary]: crate::Encoding::Binary
-    /// [`String#valid_encoding?`]: https://ruby-doc.org/core-3.0.0/String.html#method-i-valid_encoding-3F
-    #[inline]
-    #[must_use]
-    pub fn is_valid_encoding(&self) -> bool {
-        match self.encoding {
-            Encoding::Utf8 if self.buf.is_ascii() => true,
-            Encoding::Utf8 => simdutf8::basic::from_utf8(&self.buf).is_ok(),
-            Encoding::Ascii => self.buf.is_ascii(),
-            Encoding::Binary => true,
-        }
+    #[test]
+    fn matches_are_non_overlapping() {
+        let s = String::from("aaa");
+        assert_eq!(s.matches(b"aa").collect::<Vec<_>>(), [0]);
+        assert_eq!(s.count_matches(b"aa"), 1);
+
+        let s = String::from("aaaa");
+        assert_eq!(s.matches(b"aa").collect::<Vec<_>>(), [0, 2]);
     }
-}
 
-#[must_use]
-fn conventionally_utf8_byte_string_len(mut bytes: &[u8]) -> usize {
-    let tail = if let Some(idx) = bytes.find_non_ascii_byte() {
-        idx
-    } else {
-        return bytes.len();
-    };
-    // Safety:
-    //
-    // If `ByteSlice::find_non_ascii_byte` returns `Some(_)`, the index is
-    // guaranteed to be a valid index within `bytes`.
-    bytes = unsafe { bytes.get_unchecked(tail..) };
-    if simdutf8::basic::from_utf8(bytes).is_ok() {
-        return tail + bytecount::num_chars(bytes);
+    #[test]
+    fn matches_empty_needle_matches_every_char_boundary() {
+        // ```
+        // [3.0.1] > "abc".scan("")
+        // => ["", "", "", ""]
+        // ```
+        let s = String::from("abc");
+        assert_eq!(s.matches(b"").collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+        // Char boundaries are encoding-aware.
+        let s = String::utf8("a💎b".as_bytes().to_vec());
+        assert_eq!(s.matches(b"").collect::<Vec<_>>(), [0, 1, 5, 6]);
+
+        let s = String::binary("💎".as_bytes().to_vec());
+        assert_eq!(s.matches(b"").collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+
+        let s = String::from("");
+        assert_eq!(s.matches(b"").collect::<Vec<_>>(), [0]);
     }
-    let mut char_len = tail;
-    for chunk in bytes.utf8_chunks() {
-        char_len += bytecount::num_chars(chunk.valid().as_bytes());
-        char_len += chunk.invalid().len();
+
+    #[test]
+    fn matches_needle_longer_than_haystack() {
+        let s = String::from("aa");
+        assert_eq!(s.matches(b"aaa").next(), None);
+        assert_eq!(s.count_matches(b"aaa"), 0);
     }
-    char_len
-}
 
-#[must_use]
-fn chomp(string: &mut String, separator: Option<&[u8]>) -> bool {
-    if string.is_empty() {
-        return false;
+    #[test]
+    fn char_indices_emoji_mixed_with_invalid_bytes() {
+        let s = String::utf8(b"a\xF0\x9F\x92\x8E\xFF\xFEb".to_vec());
+        let char_indices = s.char_indices().collect::<Vec<_>>();
+        assert_eq!(
+            char_indices,
+            [
+                (0, 0, &b"a"[..]),
+                (1, 1, &b"\xF0\x9F\x92\x8E"[..]),
+                (2, 5, &b"\xFF"[..]),
+                (3, 6, &b"\xFE"[..]),
+                (4, 7, &b"b"[..]),
+            ]
+        );
+
+        // Reverse iteration yields the same tuples.
+        let mut reversed = s.char_indices().rev().collect::<Vec<_>>();
+        reversed.reverse();
+        assert_eq!(reversed, char_indices);
     }
-    match separator {
-        Some(separator) if separator.is_empty() => {
-            let original_len = string.len();
-            let mut iter = string.bytes().rev().peekable();
-            while let Some(&b'\n') = iter.peek() {
-                iter.next();
-                if let Some(&b'\r') = iter.peek() {
-                    iter.next();
-                }
-            }
-            let truncate_to = iter.count();
-            string.buf.truncate(truncate_to);
-            truncate_to != original_len
-        }
-        Some(separator) if string.buf.ends_with(separator) => {
-            let original_len = string.len();
-            // This subtraction is guaranteed not to panic because
-            // `separator` is a substring of `buf`.
-            let truncate_to_len = original_len - separator.len();
-            string.buf.truncate(truncate_to_len);
-            // Separator is non-empty and we are always truncating, so this
-            // branch always modifies the buffer.
-            true
+
+    #[test]
+    fn char_indices_binary_offset_equals_index() {
+        let s = String::binary("a💎".as_bytes().to_vec());
+        assert_eq!(s.char_indices().len(), 5);
+        for (char_index, byte_offset, ch) in s.char_indices() {
+            assert_eq!(char_index, byte_offset);
+            assert_eq!(ch.len(), 1);
         }
-        Some(_) => false,
-        None => {
-            let original_len = string.len();
-            let mut iter = string.bytes().rev().peekable();
-            match iter.peek() {
-                Some(&b'\n') => {
-                    iter.next();
-                    if let Some(&b'\r') = iter.peek() {
-                        iter.next();
-                    }
-                }
-                Some(b'\r') => {
-                    iter.next();
-                }
-                Some(_) | None => {}
-            };
-            let truncate_to_len = iter.count();
-            string.buf.truncate(truncate_to_len);
-            truncate_to_len != original_len
+    }
+
+    #[test]
+    fn char_indices_empty_string() {
+        let s = String::utf8(Vec::new());
+        assert_eq!(s.char_indices().next(), None);
+        assert_eq!(s.char_indices().next_back(), None);
+        assert_eq!(s.char_indices().len(), 0);
+    }
+
+    #[test]
+    fn truncate_chars_counts_invalid_bytes_as_one_char() {
+        let mut s = String::utf8(b"a\xF0\x9F\x92\x8E\xFF\xFEb".to_vec());
+        s.truncate_chars(4);
+        assert_eq!(s, &b"a\xF0\x9F\x92\x8E\xFF\xFE"[..]);
+        s.truncate_chars(2);
+        assert_eq!(s, &b"a\xF0\x9F\x92\x8E"[..]);
+        // Truncating beyond the character length is a no-op.
+        s.truncate_chars(10);
+        assert_eq!(s, &b"a\xF0\x9F\x92\x8E"[..]);
+
+        let mut s = String::binary("💎".as_bytes().to_vec());
+        s.truncate_chars(2);
+        assert_eq!(s, &b"\xF0\x9F"[..]);
+    }
+
+    #[test]
+    fn center_owned_matches_center_iterator() {
+        let cases: &[(&[u8], usize, Option<&[u8]>)] = &[
+            (b"hello", 4, None),
+            (b"hello", 20, None),
+            (b"hello", 20, Some(b"123")),
+            (b"hello", 6, Some(b"123")),
+            (b"", 5, Some(b"ab")),
+            ("💎".as_bytes(), 5, Some(b"-")),
+        ];
+        for &(contents, width, padding) in cases {
+            let s = String::utf8(contents.to_vec());
+            let expected = s.center(width, padding).unwrap().collect::<Vec<_>>();
+            let centered = s.center_owned(width, padding).unwrap();
+            assert_eq!(centered.as_slice(), expected, "center_owned({width}, {padding:?})");
+            assert_eq!(centered.encoding(), s.encoding());
         }
+
+        let s = String::binary(b"abc".to_vec());
+        let centered = s.center_owned(7, None).unwrap();
+        assert_eq!(centered, "  abc  ");
+        assert_eq!(centered.encoding(), Encoding::Binary);
+
+        let s = String::utf8(b"abc".to_vec());
+        assert_eq!(s.center_owned(10, Some(b"")), Err(CenterError::ZeroWidthPadding));
     }
-}
 
-#[cfg(test)]
-#[allow(clippy::shadow_unrelated)]
-#[allow(clippy::invisible_characters)]
-mod tests {
-    use alloc::string::ToString;
-    use alloc::vec::Vec;
-    use core::str;
+    #[test]
+    fn scrub_lone_continuation_bytes() {
+        let s = String::utf8(b"a\x80b\x80\x80c".to_vec());
+        assert_eq!(s.scrub(None), "a\u{FFFD}b\u{FFFD}\u{FFFD}c".as_bytes());
 
-    use quickcheck::quickcheck;
+        let mut s = String::utf8(b"a\x80b".to_vec());
+        assert!(s.make_scrub(Some(b"?")));
+        assert_eq!(s, "a?b");
+    }
 
-    use crate::{conventionally_utf8_byte_string_len, CenterError, String};
+    #[test]
+    fn scrub_truncated_multibyte_sequence_at_end_of_string() {
+        // A truncated four byte sequence is a single maximal invalid sequence
+        // and is replaced once.
+        let s = String::utf8(b"abc\xF0\x9F\x92".to_vec());
+        assert_eq!(s.scrub(None), "abc\u{FFFD}".as_bytes());
+    }
 
-    const REPLACEMENT_CHARACTER_BYTES: [u8; 3] = [239, 191, 189];
+    #[test]
+    fn scrub_empty_replacement_deletes_invalid_bytes() {
+        let mut s = String::utf8(b"a\xFFb\xF0\x9F\x92".to_vec());
+        assert!(s.make_scrub(Some(b"")));
+        assert_eq!(s, "ab");
+    }
 
     #[test]
-    fn utf8_char_len_empty() {
-        let s = "".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 0);
+    fn scrub_valid_strings_borrow() {
+        let s = String::utf8("a💎b".as_bytes().to_vec());
+        assert!(matches!(s.scrub(None), Cow::Borrowed(_)));
+        let mut s = String::utf8("a💎b".as_bytes().to_vec());
+        assert!(!s.make_scrub(None));
+
+        // Binary strings have no invalid byte sequences.
+        let s = String::binary(b"\xFF\xFE".to_vec());
+        assert!(matches!(s.scrub(None), Cow::Borrowed(_)));
     }
 
     #[test]
-    fn utf8_char_len_ascii() {
-        let s = "Artichoke Ruby".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 14);
+    fn scrub_ascii_replaces_non_ascii_bytes() {
+        let s = String::ascii("aé".as_bytes().to_vec());
+        assert_eq!(s.scrub(Some(b"?")), &b"a??"[..]);
+        let s = String::ascii(b"abc".to_vec());
+        assert!(matches!(s.scrub(None), Cow::Borrowed(_)));
     }
 
     #[test]
-    fn utf8_char_len_emoji() {
-        let s = "💎".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 1);
-        let s = "💎🦀🎉".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 3);
-        let s = "a💎b🦀c🎉d".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
-        // with invalid UFF-8 bytes
-        let s = b"a\xF0\x9F\x92\x8E\xFFabc";
-        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 6);
+    fn tr_pads_short_to_set_with_last_char() {
+        // ```
+        // [3.0.1] > "hello".tr("ello", "ip")
+        // => "hippp"
+        // ```
+        let mut s = String::from("hello");
+        assert_eq!(s.tr(b"ello", b"ip"), 4);
+        assert_eq!(s, "hippp");
     }
 
     #[test]
-    fn utf8_char_len_unicode_replacement_character() {
-        let s = "�".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 1);
-        let s = "���".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 3);
-        let s = "a�b�c�d".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
-        let s = "�💎b🦀c🎉�".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
-        // with invalid UFF-8 bytes
-        let s = b"\xEF\xBF\xBD\xF0\x9F\x92\x8E\xFF\xEF\xBF\xBDab";
-        assert_eq!(conventionally_utf8_byte_string_len(s), 6);
-        assert_eq!(conventionally_utf8_byte_string_len(&REPLACEMENT_CHARACTER_BYTES[..]), 1);
+    fn tr_with_empty_to_set_deletes() {
+        // ```
+        // [3.0.1] > "hello".tr("l", "")
+        // => "heo"
+        // ```
+        let mut s = String::from("hello");
+        assert_eq!(s.tr(b"l", b""), 2);
+        assert_eq!(s, "heo");
     }
 
     #[test]
-    fn utf8_char_len_nul_byte() {
-        let s = b"\x00";
-        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 1);
-        let s = b"abc\x00";
-        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 4);
-        let s = b"abc\x00xyz";
-        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 7);
+    fn tr_multibyte_characters() {
+        // ```
+        // [3.0.1] > "héllo💎".tr("é💎", "ex")
+        // => "hellox"
+        // ```
+        let mut s = String::utf8("héllo💎".as_bytes().to_vec());
+        assert_eq!(s.tr("é💎".as_bytes(), b"ex"), 2);
+        assert_eq!(s, "hellox");
     }
 
     #[test]
-    fn utf8_char_len_invalid_utf8_byte_sequences() {
-        let s = b"\x00\x00\xD8\x00";
-        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 4);
-        let s = b"\xFF\xFE";
-        assert_eq!(conventionally_utf8_byte_string_len(&s[..]), 2);
+    fn tr_does_not_count_unchanged_characters() {
+        let mut s = String::from("hello");
+        assert_eq!(s.tr(b"l", b"l"), 0);
+        assert_eq!(s, "hello");
     }
 
     #[test]
-    fn utf8_char_len_binary() {
-        let bytes = &[
-            0xB3, 0x7E, 0x39, 0x70, 0x8E, 0xFD, 0xBB, 0x75, 0x62, 0x77, 0xE7, 0xDF, 0x6F, 0xF2, 0x76, 0x27, 0x81,
-            0x9A, 0x3A, 0x9D, 0xED, 0x6B, 0x4F, 0xAE, 0xC4, 0xE7, 0xA1, 0x66, 0x11, 0xF1, 0x08, 0x1C,
-        ];
-        assert_eq!(conventionally_utf8_byte_string_len(&bytes[..]), 32);
-        // Mixed binary and ASCII
-        let bytes = &[
-            b'?', b'!', b'a', b'b', b'c', 0xFD, 0xBB, 0x75, 0x62, 0x77, 0xE7, 0xDF, 0x6F, 0xF2, 0x76, 0x27, 0x81,
-            0x9A, 0x3A, 0x9D, 0xED, 0x6B, 0x4F, 0xAE, 0xC4, 0xE7, 0xA1, 0x66, 0x11, 0xF1, 0x08, 0x1C,
-        ];
-        assert_eq!(conventionally_utf8_byte_string_len(&bytes[..]), 32);
+    fn delete_with_range_set() {
+        // ```
+        // [3.0.1] > "hello".delete("ej-m")
+        // => "ho"
+        // ```
+        let mut s = String::from("hello");
+        assert_eq!(s.delete(b"ej-m"), 3);
+        assert_eq!(s, "ho");
+    }
+
+    #[test]
+    fn delete_with_negated_set() {
+        // ```
+        // [3.0.1] > "hello".delete("^l")
+        // => "ll"
+        // ```
+        let mut s = String::from("hello");
+        assert_eq!(s.delete(b"^l"), 3);
+        assert_eq!(s, "ll");
+    }
+
+    #[test]
+    fn squeeze_without_set() {
+        // ```
+        // [3.0.1] > "yellow moon".squeeze
+        // => "yelow mon"
+        // ```
+        let mut s = String::from("yellow moon");
+        assert!(s.squeeze(None));
+        assert_eq!(s, "yelow mon");
+
+        let mut s = String::from("abc");
+        assert!(!s.squeeze(None));
+        assert_eq!(s, "abc");
     }
 
     #[test]
-    fn utf8_char_len_mixed_ascii_emoji_invalid_bytes() {
+    fn squeeze_with_set() {
         // ```
-        // [2.6.3] > s = "🦀abc💎\xff"
-        // => "🦀abc💎\xFF"
-        // [2.6.3] > s.length
-        // => 6
-        // [2.6.3] > puts s.bytes.map{|b| "\\x#{b.to_s(16).upcase}"}.join
-        // \xF0\x9F\xA6\x80\x61\x62\x63\xF0\x9F\x92\x8E\xFF
+        // [3.0.1] > "mississippi".squeeze("sp")
+        // => "misisipi"
         // ```
-        let bytes = b"\xF0\x9F\xA6\x80\x61\x62\x63\xF0\x9F\x92\x8E\xFF";
-        assert_eq!(conventionally_utf8_byte_string_len(&bytes[..]), 6);
+        let mut s = String::from("mississippi");
+        assert!(s.squeeze(Some(b"sp")));
+        assert_eq!(s, "misisipi");
     }
 
     #[test]
-    fn utf8_char_len_utf8() {
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L147-L157
-        let s = "Ω≈ç√∫˜µ≤≥÷".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 10);
-        let s = "åß∂ƒ©˙∆˚¬…æ".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
-        let s = "œ∑´®†¥¨ˆøπ“‘".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
-        let s = "¡™£¢∞§¶•ªº–≠".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
-        let s = "¸˛Ç◊ı˜Â¯˘¿".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 10);
-        let s = "ÅÍÎÏ˝ÓÔÒÚÆ☃".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
-        let s = "Œ„´‰ˇÁ¨ˆØ∏”’".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 12);
-        let s = "`⁄€‹›ﬁﬂ‡°·‚—±".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 13);
-        let s = "⅛⅜⅝⅞".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 4);
-        let s = "ЁЂЃЄЅІЇЈЉЊЋЌЍЎЏАБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯабвгдежзийклмнопрстуфхцчшщъыьэюя".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 79);
+    fn squeeze_multibyte_runs() {
+        let mut s = String::utf8("a💎💎💎b".as_bytes().to_vec());
+        assert!(s.squeeze(None));
+        assert_eq!(s, "a💎b");
+
+        // Binary strings squeeze per byte.
+        let mut s = String::binary(b"\xFF\xFF\x00\x00a".to_vec());
+        assert!(s.squeeze(None));
+        assert_eq!(s, &b"\xFF\x00a"[..]);
     }
 
     #[test]
-    fn utf8_char_len_vmware_super_string() {
-        // A super string recommended by VMware Inc. Globalization Team: can
-        // effectively cause rendering issues or character-length issues to
-        // validate product globalization readiness.
-        //
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L202-L224
-        let s = "表ポあA鷗ŒéＢ逍Üßªąñ丂㐀𠀀".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 17);
+    fn split_on_substring() {
+        // ```
+        // [3.0.1] > "mellow yellow".split("ello")
+        // => ["m", "w y", "w"]
+        // [3.0.1] > "1,2,,3,4,,".split(",")
+        // => ["1", "2", "", "3", "4"]
+        // [3.0.1] > "1,2,,3,4,,".split(",", 4)
+        // => ["1", "2", "", "3,4,,"]
+        // ```
+        let s = String::from("mellow yellow");
+        assert_eq!(s.split(b"ello", None).collect::<Vec<_>>(), [&b"m"[..], b"w y", b"w"]);
+        let s = String::from("1,2,,3,4,,");
+        assert_eq!(
+            s.split(b",", None).collect::<Vec<_>>(),
+            [&b"1"[..], b"2", b"", b"3", b"4"]
+        );
+        assert_eq!(
+            s.split(b",", Some(4)).collect::<Vec<_>>(),
+            [&b"1"[..], b"2", b"", b"3,4,,"]
+        );
     }
 
     #[test]
-    fn utf8_char_len_two_byte_chars() {
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L188-L196
-        let s = "田中さんにあげて下さい".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
-        let s = "パーティーへ行かないか".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
-        let s = "和製漢語".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 4);
-        let s = "部落格".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 3);
-        let s = "사회과학원 어학연구소".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 11);
-        let s = "찦차를 타고 온 펲시맨과 쑛다리 똠방각하".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 22);
-        let s = "社會科學院語學研究所".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 10);
-        let s = "울란바토르".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 5);
-        let s = "𠜎𠜱𠝹𠱓𠱸𠲖𠳏".as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 7);
+    fn split_separator_not_present_yields_whole_string() {
+        let s = String::from("hello");
+        assert_eq!(s.split(b",", None).collect::<Vec<_>>(), [&b"hello"[..]]);
+        assert_eq!(s.split(b",", Some(2)).collect::<Vec<_>>(), [&b"hello"[..]]);
     }
 
     #[test]
-    fn utf8_char_len_space_chars() {
-        // Whitespace: all the characters with category Zs, Zl, or Zp (in Unicode
-        // version 8.0.0), plus U+0009 (HT), U+000B (VT), U+000C (FF), U+0085 (NEL),
-        // and U+200B (ZERO WIDTH SPACE), which are in the C categories but are often
-        // treated as whitespace in some contexts.
-        // This file unfortunately cannot express strings containing
-        // U+0000, U+000A, or U+000D (NUL, LF, CR).
-        // The next line may appear to be blank or mojibake in some viewers.
-        // The next line may be flagged for "trailing whitespace" in some viewers.
-        //
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L131
-        let s = "	              ​    　
-"
-        .as_bytes();
-        assert_eq!(conventionally_utf8_byte_string_len(s), 24);
+    fn split_on_multibyte_separator() {
+        // ```
+        // [3.0.1] > "a💎b💎c".split("💎")
+        // => ["a", "b", "c"]
+        // [3.0.1] > "a💎b💎".split("💎")
+        // => ["a", "b"]
+        // ```
+        let s = String::utf8("a💎b💎c".as_bytes().to_vec());
+        assert_eq!(s.split("💎".as_bytes(), None).collect::<Vec<_>>(), [&b"a"[..], b"b", b"c"]);
+        let s = String::utf8("a💎b💎".as_bytes().to_vec());
+        assert_eq!(s.split("💎".as_bytes(), None).collect::<Vec<_>>(), [&b"a"[..], b"b"]);
     }
 
-    quickcheck! {
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_char_len_utf8_contents_utf8_string(contents: alloc::string::String) -> bool {
-            let expected = contents.chars().count();
-            let s = String::utf8(contents.into_bytes());
-            s.char_len() == expected
-        }
-
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_len_utf8_contents_utf8_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::utf8(contents.into_bytes());
-            s.len() == expected
-        }
-
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_bytesize_utf8_contents_utf8_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::utf8(contents.into_bytes());
-            s.bytesize() == expected
-        }
-
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_char_len_binary_contents_utf8_string(contents: Vec<u8>) -> bool {
-            if let Ok(utf8_contents) = str::from_utf8(&contents) {
-                let expected = utf8_contents.chars().count();
-                let s = String::utf8(contents);
-                s.char_len() == expected
-            } else {
-                let expected_at_most = contents.len();
-                let s = String::utf8(contents);
-                s.char_len() <= expected_at_most
-            }
-        }
-
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_len_binary_contents_utf8_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::utf8(contents);
-            s.len() == expected
-        }
+    #[test]
+    fn split_on_whitespace_runs() {
+        // ```
+        // [3.0.1] > " now's  the time ".split(" ")
+        // => ["now's", "the", "time"]
+        // [3.0.1] > " now's  the time ".split(" ", 2)
+        // => ["now's", "the time "]
+        // ```
+        let s = String::from(" now's  the\t\ntime ");
+        assert_eq!(
+            s.split(b" ", None).collect::<Vec<_>>(),
+            [&b"now's"[..], b"the", b"time"]
+        );
+        assert_eq!(
+            s.split(b" ", Some(2)).collect::<Vec<_>>(),
+            [&b"now's"[..], b"the\t\ntime "]
+        );
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_bytesize_binary_contents_utf8_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::utf8(contents);
-            s.bytesize() == expected
-        }
+    #[test]
+    fn split_empty_separator_yields_chars() {
+        // ```
+        // [3.0.1] > "a💎b".split("")
+        // => ["a", "💎", "b"]
+        // [3.0.1] > "a💎b".split("", 2)
+        // => ["a", "💎b"]
+        // ```
+        let s = String::utf8("a💎b".as_bytes().to_vec());
+        assert_eq!(
+            s.split(b"", None).collect::<Vec<_>>(),
+            [&b"a"[..], "💎".as_bytes(), b"b"]
+        );
+        assert_eq!(
+            s.split(b"", Some(2)).collect::<Vec<_>>(),
+            [&b"a"[..], "💎b".as_bytes()]
+        );
+        // Binary strings split into bytes.
+        let s = String::binary("💎".as_bytes().to_vec());
+        assert_eq!(s.split(b"", None).count(), 4);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_char_len_utf8_contents_ascii_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::ascii(contents.into_bytes());
-            s.char_len() == expected
-        }
+    #[test]
+    fn split_empty_string_has_no_fields() {
+        let s = String::new();
+        assert_eq!(s.split(b",", None).count(), 0);
+        assert_eq!(s.split(b" ", None).count(), 0);
+        assert_eq!(s.split(b"", None).count(), 0);
+        assert_eq!(s.split(b",", Some(5)).count(), 0);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_len_utf8_contents_ascii_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::ascii(contents.into_bytes());
-            s.len() == expected
-        }
+    #[test]
+    fn split_string_ending_with_separator() {
+        // ```
+        // [3.0.1] > "a,b,".split(",")
+        // => ["a", "b"]
+        // [3.0.1] > ",".split(",")
+        // => []
+        // [3.0.1] > "a,b,".split(",", 3)
+        // => ["a", "b", ""]
+        // ```
+        let s = String::from("a,b,");
+        assert_eq!(s.split(b",", None).collect::<Vec<_>>(), [&b"a"[..], b"b"]);
+        assert_eq!(s.split(b",", Some(3)).collect::<Vec<_>>(), [&b"a"[..], b"b", b""]);
+        let s = String::from(",");
+        assert_eq!(s.split(b",", None).count(), 0);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_bytesize_utf8_contents_ascii_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::ascii(contents.into_bytes());
-            s.bytesize() == expected
-        }
+    #[test]
+    fn index_multibyte_haystack_returns_char_index() {
+        // ```
+        // [3.0.1] > "💎abc".index("abc")
+        // => 1
+        // [3.0.1] > "💎abc".index("c", -2)
+        // => 3
+        // [3.0.1] > "💎abc".index("💎", 1)
+        // => nil
+        // [3.0.1] > "💎abc".index("", 4)
+        // => 4
+        // [3.0.1] > "💎abc".index("", 5)
+        // => nil
+        // ```
+        let s = String::utf8("💎abc".as_bytes().to_vec());
+        assert_eq!(s.index("abc", None), Some(1));
+        assert_eq!(s.index("c", Some(-2)), Some(3));
+        assert_eq!(s.index("💎", Some(1)), None);
+        assert_eq!(s.index("", Some(4)), Some(4));
+        assert_eq!(s.index("", Some(5)), None);
+        assert_eq!(s.index("a", Some(-10)), None);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_char_len_binary_contents_ascii_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::ascii(contents);
-            s.char_len() == expected
-        }
+    #[test]
+    fn index_binary_haystack_returns_byte_index() {
+        let s = String::binary("💎abc".as_bytes().to_vec());
+        assert_eq!(s.index("abc", None), Some(4));
+        assert_eq!(s.index("c", Some(-1)), Some(6));
+        assert_eq!(s.index("a", Some(10)), None);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_len_binary_contents_ascii_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::ascii(contents);
-            s.len() == expected
-        }
+    #[test]
+    fn rindex_multibyte_haystack_returns_char_index() {
+        // ```
+        // [3.0.1] > "💎abca".rindex("a")
+        // => 4
+        // [3.0.1] > "💎abca".rindex("a", 3)
+        // => 1
+        // [3.0.1] > "💎abca".rindex("a", -2)
+        // => 1
+        // [3.0.1] > "💎abca".rindex("💎", 0)
+        // => 0
+        // [3.0.1] > "💎abca".rindex("a", -10)
+        // => nil
+        // ```
+        let s = String::utf8("💎abca".as_bytes().to_vec());
+        assert_eq!(s.rindex("a", None), Some(4));
+        assert_eq!(s.rindex("a", Some(3)), Some(1));
+        assert_eq!(s.rindex("a", Some(-2)), Some(1));
+        assert_eq!(s.rindex("💎", Some(0)), Some(0));
+        assert_eq!(s.rindex("a", Some(-10)), None);
+        // Offsets beyond the end of the string saturate to the end.
+        assert_eq!(s.rindex("a", Some(100)), Some(4));
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_bytesize_binary_contents_ascii_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::ascii(contents);
-            s.bytesize() == expected
-        }
+    #[test]
+    fn rindex_binary_haystack_returns_byte_index() {
+        let s = String::binary("💎abca".as_bytes().to_vec());
+        assert_eq!(s.rindex("a", None), Some(7));
+        assert_eq!(s.rindex("a", Some(6)), Some(4));
+        assert_eq!(s.rindex("a", Some(-2)), Some(4));
+        assert_eq!(s.rindex("a", Some(-20)), None);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_char_len_utf8_contents_binary_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::binary(contents.into_bytes());
-            s.char_len() == expected
-        }
+    #[test]
+    fn char_index_to_byte_offset_counts_invalid_bytes() {
+        use crate::conventionally_utf8_char_index_to_byte_offset;
+
+        let s = b"a\xF0\x9F\x92\x8E\xFFbc"; // "a💎\xFFbc"
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 0), Some(0));
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 1), Some(1));
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 2), Some(5));
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 3), Some(6));
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 5), Some(8));
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 6), None);
+
+        // Invalid UTF-8 sequences count one character per byte.
+        let s = b"\xF0\x9F\x87abc";
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 2), Some(2));
+        assert_eq!(conventionally_utf8_char_index_to_byte_offset(s, 3), Some(3));
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_len_utf8_contents_binary_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::binary(contents.into_bytes());
-            s.len() == expected
-        }
+    #[test]
+    fn char_slice_utf8_string() {
+        let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
+        assert_eq!(s.char_slice(0, 3), Some(&b"abc"[..]));
+        assert_eq!(s.char_slice(2, 2), Some(&b"c\xF0\x9F\x92\x8E"[..]));
+        assert_eq!(s.char_slice(3, 1), Some(&b"\xF0\x9F\x92\x8E"[..]));
+        // Lengths which overrun the end of the string are clamped.
+        assert_eq!(s.char_slice(3, 10), Some(&b"\xF0\x9F\x92\x8E\xFF"[..]));
+        // A start exactly equal to the character length returns an empty
+        // slice.
+        assert_eq!(s.char_slice(5, 10), Some(&b""[..]));
+        // Starts beyond the character length return `nil`.
+        assert_eq!(s.char_slice(6, 10), None);
+        assert_eq!(s.char_slice(usize::MAX, 1), None);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_bytesize_utf8_contents_binary_string(contents: alloc::string::String) -> bool {
-            let expected = contents.len();
-            let s = String::binary(contents.into_bytes());
-            s.bytesize() == expected
-        }
+    #[test]
+    fn char_slice_binary_string() {
+        let s = String::binary(b"abc\xF0\x9F\x92\x8E\xFF".to_vec());
+        assert_eq!(s.char_slice(0, 3), Some(&b"abc"[..]));
+        assert_eq!(s.char_slice(3, 1), Some(&b"\xF0"[..]));
+        assert_eq!(s.char_slice(3, 10), Some(&b"\xF0\x9F\x92\x8E\xFF"[..]));
+        assert_eq!(s.char_slice(8, 10), Some(&b""[..]));
+        assert_eq!(s.char_slice(9, 10), None);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_char_len_binary_contents_binary_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::binary(contents);
-            s.char_len() == expected
-        }
+    #[test]
+    fn char_get_utf8_string() {
+        let s = String::utf8(b"abc\xF0\x9F\x92\x8E\xFF".to_vec()); // "abc💎\xFF"
+        assert_eq!(s.char_get(0), Some(&b"a"[..]));
+        assert_eq!(s.char_get(3), Some(&b"\xF0\x9F\x92\x8E"[..]));
+        assert_eq!(s.char_get(4), Some(&b"\xFF"[..]));
+        assert_eq!(s.char_get(5), None);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_len_binary_contents_binary_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::binary(contents);
-            s.len() == expected
-        }
+    #[test]
+    fn strings_compare_equal_only_based_on_byte_content() {
+        let utf8 = String::utf8(b"abc".to_vec());
+        let ascii = String::ascii(b"abc".to_vec());
+        let binary = String::binary(b"abc".to_vec());
+        assert_eq!(utf8, ascii);
+        assert_eq!(utf8, binary);
+        assert_eq!(binary, ascii);
+    }
 
-        #[allow(clippy::needless_pass_by_value)]
-        fn fuzz_bytesize_binary_contents_binary_string(contents: Vec<u8>) -> bool {
-            let expected = contents.len();
-            let s = String::binary(contents);
-            s.bytesize() == expected
-        }
+    #[test]
+    fn non_ascii_strings_compare_equal_only_with_matching_encodings() {
+        let utf8 = String::utf8(b"abc\xFE\xFF".to_vec());
+        let ascii = String::ascii(b"abc\xFE\xFF".to_vec());
+        let binary = String::binary(b"abc\xFE\xFF".to_vec());
+        assert_ne!(utf8, ascii);
+        assert_ne!(utf8, binary);
+        assert_ne!(binary, ascii);
+        assert_eq!(utf8, String::utf8(b"abc\xFE\xFF".to_vec()));
+        assert_eq!(binary, String::binary(b"abc\xFE\xFF".to_vec()));
     }
 
     #[test]
-    fn make_capitalized_utf8_string_empty() {
-        let mut s = String::utf8(b"".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "");
+    fn shared_string_reads_without_copying() {
+        let arc = Arc::new(b"abc\xF0\x9F\x92\x8E".to_vec());
+        let s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+        assert_eq!(s.len(), 7);
+        assert_eq!(s.char_len(), 4);
+        assert_eq!(s.get(0..3), Some(&b"abc"[..]));
+        assert_eq!(s.index(b"\xF0\x9F\x92\x8E", None), Some(3));
+        // Read-only APIs leave the buffer shared with the source `Arc`.
+        assert_eq!(Arc::strong_count(&arc), 2);
     }
 
     #[test]
-    fn make_capitalized_utf8_string_ascii() {
-        let mut s = String::utf8(b"abc".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
+    fn shared_string_clones_bump_the_reference_count() {
+        let arc = Arc::new(b"abc".to_vec());
+        let s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+        let t = s.clone();
+        let u = t.clone();
+        assert_eq!(Arc::strong_count(&arc), 4);
+        drop(t);
+        drop(u);
+        assert_eq!(Arc::strong_count(&arc), 2);
+    }
 
-        let mut s = String::utf8(b"aBC".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
+    #[test]
+    fn mutating_a_shared_string_does_not_modify_siblings() {
+        let arc = Arc::new(b"abc".to_vec());
+        let s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+        let mut t = s.clone();
+
+        t.push_byte(b'!');
+        assert_eq!(t.as_slice(), b"abc!");
+        assert_eq!(s.as_slice(), b"abc");
+        assert_eq!(*arc, b"abc".to_vec());
+        // Mutation detaches the mutated `String` from the shared buffer.
+        assert_eq!(Arc::strong_count(&arc), 2);
+
+        let mut u = s.clone();
+        u.make_uppercase();
+        assert_eq!(u.as_slice(), b"ABC");
+        assert_eq!(s.as_slice(), b"abc");
+    }
 
-        let mut s = String::utf8(b"ABC".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
+    #[test]
+    fn as_mut_apis_on_a_shared_string_trigger_the_copy() {
+        let arc = Arc::new(b"abc".to_vec());
+        let mut s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+        s.as_mut_slice()[0] = b'x';
+        assert_eq!(s.as_slice(), b"xbc");
+        assert_eq!(*arc, b"abc".to_vec());
+        assert_eq!(Arc::strong_count(&arc), 1);
+
+        let mut s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+        let ptr = s.as_mut_ptr();
+        // SAFETY: `s` is non-empty, so writing one byte through the pointer
+        // is in bounds.
+        unsafe {
+            ptr.write(b'y');
+        }
+        assert_eq!(s.as_slice(), b"ybc");
+        assert_eq!(*arc, b"abc".to_vec());
+    }
 
-        let mut s = String::utf8(b"aBC, 123, ABC, baby you and me girl".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc, 123, abc, baby you and me girl");
+    #[test]
+    fn make_unique_detaches_from_the_shared_buffer() {
+        let arc = Arc::new(b"abc".to_vec());
+        let mut s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+        s.make_unique();
+        assert_eq!(Arc::strong_count(&arc), 1);
+        assert_eq!(s.as_slice(), b"abc");
+        // `make_unique` on an owned `String` is a no-op.
+        s.make_unique();
+        assert_eq!(s.as_slice(), b"abc");
     }
 
     #[test]
-    fn make_capitalized_utf8_string_utf8() {
-        let mut s = String::utf8("ß".to_string().into_bytes());
-        s.make_capitalized();
-        // This differs from MRI:
-        //
-        // ```console
-        // [2.6.3] > "ß".capitalize
-        // => "Ss"
-        // ```
-        assert_eq!(s, "SS");
+    fn ascii_cache_is_invalidated_by_deref_mut() {
+        let mut s = String::utf8(b"abc".to_vec());
+        assert!(s.is_ascii_only());
+        assert_eq!(s.char_len(), 3);
+        (*s)[0] = 0xFF;
+        assert!(!s.is_ascii_only());
+        assert_eq!(s.char_len(), 3);
+        assert!(!s.is_valid_encoding());
+    }
 
-        let mut s = String::utf8("αύριο".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "Αύριο");
+    #[test]
+    fn ascii_cache_is_invalidated_by_as_mut_slice() {
+        let mut s = String::utf8("abc\u{6666}".as_bytes().to_vec());
+        assert!(!s.is_ascii_only());
+        assert_eq!(s.char_len(), 4);
+        let slice = s.as_mut_slice();
+        slice[3..].copy_from_slice(b"xyz");
+        assert!(s.is_ascii_only());
+        assert_eq!(s.char_len(), 6);
+    }
 
-        let mut s = String::utf8("έτος".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "Έτος");
+    #[test]
+    fn ascii_cache_is_invalidated_by_index_mut() {
+        let mut s = String::utf8(b"abc".to_vec());
+        assert!(s.is_ascii_only());
+        s[1] = 0xC3;
+        assert!(!s.is_ascii_only());
+        assert!(!s.is_valid_encoding());
+    }
 
-        // two-byte characters
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L198-L200
-        let mut s = String::utf8(
-            "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆"
-                .to_string()
-                .into_bytes(),
-        );
-        s.make_capitalized();
-        assert_eq!(s, "𐐜 𐐼𐐯𐑅𐐨𐑉𐐯𐐻 𐑁𐐲𐑉𐑅𐐻/𐑅𐐯𐐿𐐲𐑌𐐼 𐐺𐐳𐐿 𐐺𐐴 𐑄 𐑉𐐨𐐾𐐯𐑌𐐻𐑅 𐐱𐑂 𐑄 𐐼𐐯𐑅𐐨𐑉𐐯𐐻 𐐷𐐮𐐭𐑌𐐮𐑂𐐲𐑉𐑅𐐮𐐻𐐮");
+    #[test]
+    fn ascii_cache_is_invalidated_by_iter_mut() {
+        let mut s = String::utf8(b"abc".to_vec());
+        assert!(s.is_ascii_only());
+        for byte in s.iter_mut() {
+            *byte |= 0x80;
+        }
+        assert!(!s.is_ascii_only());
+    }
 
-        // Change length when lower-cased
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L226-L232
-        let mut s = String::utf8("zȺȾ".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "Zⱥⱦ");
+    #[test]
+    fn ascii_cache_is_invalidated_by_extend() {
+        let mut s = String::utf8(b"abc".to_vec());
+        assert!(s.is_ascii_only());
+        assert_eq!(s.char_len(), 3);
+        s.extend("\u{1F48E}".as_bytes().iter().copied());
+        assert!(!s.is_ascii_only());
+        assert_eq!(s.char_len(), 4);
+        assert!(s.is_valid_encoding());
     }
 
     #[test]
-    fn make_capitalized_utf8_string_invalid_utf8() {
-        let mut s = String::utf8(b"\xFF\xFE".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, &b"\xFF\xFE"[..]);
+    #[cfg(feature = "std")]
+    fn shared_strings_are_readable_from_concurrent_threads() {
+        let arc = Arc::new(b"concurrent readers".to_vec());
+        let s = String::shared(Arc::clone(&arc), Encoding::Utf8);
+        let handles = (0..4)
+            .map(|_| {
+                let s = s.clone();
+                std::thread::spawn(move || {
+                    assert_eq!(s.as_slice(), b"concurrent readers");
+                    s.char_len()
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 18);
+        }
+        assert_eq!(Arc::strong_count(&arc), 2);
     }
 
     #[test]
-    fn make_capitalized_utf8_string_unicode_replacement_character() {
-        let mut s = String::utf8("�".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "�");
+    fn starts_with_any_matches_any_candidate() {
+        let s = String::utf8(b"hello".to_vec());
+        assert!(s.starts_with_any([&b"heaven"[..], &b"hell"[..], &b"paradise"[..]]));
+        assert!(!s.starts_with_any([&b"heaven"[..], &b"paradise"[..]]));
+        assert!(s.starts_with_any([&b""[..]]));
+        assert!(!s.starts_with_any([]));
     }
 
     #[test]
-    fn make_capitalized_ascii_string_empty() {
-        let mut s = String::ascii(b"".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "");
+    fn ends_with_any_matches_any_candidate() {
+        let s = String::utf8(b"hello".to_vec());
+        assert!(s.ends_with_any([&b"punch"[..], &b"llo"[..]]));
+        assert!(!s.ends_with_any([&b"punch"[..], &b"judy"[..]]));
+        assert!(s.ends_with_any([&b""[..]]));
+        assert!(!s.ends_with_any([]));
     }
 
     #[test]
-    fn make_capitalized_ascii_string_ascii() {
-        let mut s = String::ascii(b"abc".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
+    fn delete_prefix_removes_a_leading_prefix() {
+        let mut s = String::utf8(b"hello".to_vec());
+        assert!(s.delete_prefix(b"hel"));
+        assert_eq!(s, "lo");
+        assert!(!s.delete_prefix(b"hel"));
+        assert_eq!(s, "lo");
+        assert!(!s.delete_prefix(b""));
+        assert_eq!(s, "lo");
+    }
 
-        let mut s = String::ascii(b"aBC".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
+    #[test]
+    fn delete_suffix_removes_a_trailing_suffix() {
+        let mut s = String::utf8(b"hello".to_vec());
+        assert!(s.delete_suffix(b"llo"));
+        assert_eq!(s, "he");
+        assert!(!s.delete_suffix(b"llo"));
+        assert_eq!(s, "he");
+        assert!(!s.delete_suffix(b""));
+        assert_eq!(s, "he");
+    }
 
-        let mut s = String::ascii(b"ABC".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
+    #[cfg(feature = "std")]
+    fn hash_of(s: &String) -> u64 {
+        use core::hash::{Hash as _, Hasher as _};
 
-        let mut s = String::ascii(b"aBC, 123, ABC, baby you and me girl".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc, 123, abc, baby you and me girl");
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
     }
 
     #[test]
-    fn make_capitalized_ascii_string_utf8() {
-        let mut s = String::ascii("ß".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "ß");
-
-        let mut s = String::ascii("αύριο".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "αύριο");
-
-        let mut s = String::ascii("έτος".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "έτος");
+    #[cfg(feature = "std")]
+    fn ascii_only_strings_are_equal_across_encodings() {
+        let utf8 = String::utf8(b"abc".to_vec());
+        let ascii = String::ascii(b"abc".to_vec());
+        let binary = String::binary(b"abc".to_vec());
+        assert_eq!(utf8, ascii);
+        assert_eq!(utf8, binary);
+        assert_eq!(hash_of(&utf8), hash_of(&ascii));
+        assert_eq!(hash_of(&utf8), hash_of(&binary));
+    }
 
-        // two-byte characters
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L198-L200
-        let mut s = String::ascii(
-            "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆"
-                .to_string()
-                .into_bytes(),
-        );
-        s.make_capitalized();
-        assert_eq!(s, "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆");
+    #[test]
+    #[cfg(feature = "std")]
+    fn non_ascii_strings_are_distinguished_by_encoding() {
+        let utf8 = String::utf8("é".as_bytes().to_vec());
+        let binary = String::binary("é".as_bytes().to_vec());
+        assert_ne!(utf8, binary);
+        assert_ne!(hash_of(&utf8), hash_of(&binary));
+    }
 
-        // Change length when lower-cased
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L226-L232
-        let mut s = String::ascii("zȺȾ".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "ZȺȾ");
+    #[cfg(feature = "std")]
+    quickcheck! {
+        fn equal_strings_have_equal_hashes(bytes: Vec<u8>) -> bool {
+            let strings = [
+                String::utf8(bytes.clone()),
+                String::ascii(bytes.clone()),
+                String::binary(bytes),
+            ];
+            strings.iter().all(|a| {
+                strings
+                    .iter()
+                    .all(|b| a != b || hash_of(a) == hash_of(b))
+            })
+        }
     }
 
     #[test]
-    fn make_capitalized_ascii_string_invalid_utf8() {
-        let mut s = String::ascii(b"\xFF\xFE".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, &b"\xFF\xFE"[..]);
+    fn get_fixed_width_integers_little_endian() {
+        let s = String::binary(b"\x01\x02\x03\x04\x05\x06\x07\x08".to_vec());
+        assert_eq!(s.get_u8(0), Some(0x01));
+        assert_eq!(s.get_u8(7), Some(0x08));
+        assert_eq!(s.get_u16_le(0), Some(0x0201));
+        assert_eq!(s.get_u16_le(6), Some(0x0807));
+        assert_eq!(s.get_u32_le(0), Some(0x0403_0201));
+        assert_eq!(s.get_u32_le(4), Some(0x0807_0605));
+        assert_eq!(s.get_u64_le(0), Some(0x0807_0605_0403_0201));
     }
 
     #[test]
-    fn make_capitalized_ascii_string_unicode_replacement_character() {
-        let mut s = String::ascii("�".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "�");
+    fn get_fixed_width_integers_big_endian() {
+        let s = String::binary(b"\x01\x02\x03\x04\x05\x06\x07\x08".to_vec());
+        assert_eq!(s.get_u16_be(0), Some(0x0102));
+        assert_eq!(s.get_u16_be(6), Some(0x0708));
+        assert_eq!(s.get_u32_be(0), Some(0x0102_0304));
+        assert_eq!(s.get_u32_be(4), Some(0x0506_0708));
+        assert_eq!(s.get_u64_be(0), Some(0x0102_0304_0506_0708));
     }
 
     #[test]
-    fn make_capitalized_binary_string_empty() {
-        let mut s = String::binary(b"".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "");
+    fn get_fixed_width_integers_straddling_the_end() {
+        let s = String::binary(b"\x01\x02\x03\x04\x05\x06\x07\x08".to_vec());
+        assert_eq!(s.get_u8(8), None);
+        assert_eq!(s.get_u16_le(7), None);
+        assert_eq!(s.get_u16_be(8), None);
+        assert_eq!(s.get_u32_le(5), None);
+        assert_eq!(s.get_u32_be(6), None);
+        assert_eq!(s.get_u64_le(1), None);
+        assert_eq!(s.get_u64_be(8), None);
+        assert_eq!(s.get_u64_le(usize::MAX), None);
     }
 
     #[test]
-    fn make_capitalized_binary_string_ascii() {
-        let mut s = String::binary(b"abc".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
-
-        let mut s = String::ascii(b"aBC".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
-
-        let mut s = String::ascii(b"ABC".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc");
-
-        let mut s = String::ascii(b"aBC, 123, ABC, baby you and me girl".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, "Abc, 123, abc, baby you and me girl");
+    fn put_fixed_width_integers_in_bounds() {
+        let mut s = String::binary(b"\x00\x00\x00\x00".to_vec());
+        s.put_u16_le(0, 0x0201).unwrap();
+        s.put_u16_be(2, 0x0304).unwrap();
+        assert_eq!(s, &b"\x01\x02\x03\x04"[..]);
+
+        let mut s = String::binary(b"\xFF\xFF\xFF\xFF".to_vec());
+        s.put_u32_le(0, 0x0403_0201).unwrap();
+        assert_eq!(s, &b"\x01\x02\x03\x04"[..]);
+        s.put_u32_be(0, 0x0102_0304).unwrap();
+        assert_eq!(s, &b"\x01\x02\x03\x04"[..]);
+
+        let mut s = String::binary([0xFF; 8].to_vec());
+        s.put_u64_be(0, 0x0102_0304_0506_0708).unwrap();
+        assert_eq!(s, &b"\x01\x02\x03\x04\x05\x06\x07\x08"[..]);
     }
 
     #[test]
-    fn make_capitalized_binary_string_utf8() {
-        let mut s = String::binary("ß".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "ß");
-
-        let mut s = String::binary("αύριο".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "αύριο");
-
-        let mut s = String::binary("έτος".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "έτος");
+    fn put_fixed_width_integers_past_the_end_zero_fill() {
+        let mut s = String::binary(b"\x01".to_vec());
+        s.put_u8(3, 0xFF).unwrap();
+        assert_eq!(s, &b"\x01\x00\x00\xFF"[..]);
+
+        let mut s = String::binary(Vec::new());
+        s.put_u16_le(1, 0x0201).unwrap();
+        assert_eq!(s, &b"\x00\x01\x02"[..]);
+
+        let mut s = String::binary(b"\x01\x02".to_vec());
+        s.put_u32_be(1, 0x0304_0506).unwrap();
+        assert_eq!(s, &b"\x01\x03\x04\x05\x06"[..]);
+
+        let mut s = String::binary(Vec::new());
+        s.put_u64_le(0, 0x0807_0605_0403_0201).unwrap();
+        assert_eq!(s, &b"\x01\x02\x03\x04\x05\x06\x07\x08"[..]);
+    }
 
-        // two-byte characters
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L198-L200
-        let mut s = String::binary(
-            "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆"
-                .to_string()
-                .into_bytes(),
+    #[test]
+    fn put_fixed_width_integers_offset_overflow() {
+        let mut s = String::binary(Vec::new());
+        assert_eq!(
+            s.put_u16_le(usize::MAX, 0x0201),
+            Err(IndexOutOfBoundsError::with_index_and_len(i64::MAX, 0))
         );
-        s.make_capitalized();
-        assert_eq!(s, "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐙𐐊𐐡𐐝𐐓/𐐝𐐇𐐗𐐊𐐤𐐔 𐐒𐐋𐐗 𐐒𐐌 𐐜 𐐡𐐀𐐖𐐇𐐤𐐓𐐝 𐐱𐑂 𐑄 𐐔𐐇𐐝𐐀𐐡𐐇𐐓 𐐏𐐆𐐅𐐤𐐆𐐚𐐊𐐡𐐝𐐆𐐓𐐆");
+        assert_eq!(
+            s.put_u64_be(usize::MAX - 7, 0),
+            Err(IndexOutOfBoundsError::with_index_and_len(i64::MAX, 0))
+        );
+        assert!(s.is_empty());
+    }
 
-        // Change length when lower-cased
-        // https://github.com/minimaxir/big-list-of-naughty-strings/blob/894882e7/blns.txt#L226-L232
-        let mut s = String::binary("zȺȾ".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "ZȺȾ");
+    #[test]
+    fn put_and_get_round_trip() {
+        let mut s = String::binary(Vec::new());
+        s.put_u16_le(0, 0xBEEF).unwrap();
+        s.put_u16_be(2, 0xBEEF).unwrap();
+        s.put_u32_le(4, 0xDEAD_BEEF).unwrap();
+        s.put_u32_be(8, 0xDEAD_BEEF).unwrap();
+        s.put_u64_le(12, 0xDEAD_BEEF_DEAD_BEEF).unwrap();
+        s.put_u64_be(20, 0xDEAD_BEEF_DEAD_BEEF).unwrap();
+        assert_eq!(s.get_u16_le(0), Some(0xBEEF));
+        assert_eq!(s.get_u16_be(2), Some(0xBEEF));
+        assert_eq!(s.get_u32_le(4), Some(0xDEAD_BEEF));
+        assert_eq!(s.get_u32_be(8), Some(0xDEAD_BEEF));
+        assert_eq!(s.get_u64_le(12), Some(0xDEAD_BEEF_DEAD_BEEF));
+        assert_eq!(s.get_u64_be(20), Some(0xDEAD_BEEF_DEAD_BEEF));
     }
 
     #[test]
-    fn make_capitalized_binary_string_invalid_utf8() {
-        let mut s = String::binary(b"\xFF\xFE".to_vec());
-        s.make_capitalized();
-        assert_eq!(s, &b"\xFF\xFE"[..]);
+    fn delete_suffix_does_not_split_multibyte_characters() {
+        // A suffix which is a truncated multibyte sequence is a byte mismatch
+        // and does not match, so multibyte characters are never split.
+        let mut s = String::utf8("abc💎".as_bytes().to_vec());
+        assert!(!s.delete_suffix(b"\xF0\x9F\x92"));
+        assert_eq!(s, "abc💎");
+        assert!(s.delete_suffix("💎".as_bytes()));
+        assert_eq!(s, "abc");
     }
 
     #[test]
-    fn make_capitalized_binary_string_unicode_replacement_character() {
-        let mut s = String::binary("�".to_string().into_bytes());
-        s.make_capitalized();
-        assert_eq!(s, "�");
+    fn repeat_matches_a_straightforward_loop() {
+        let s = String::utf8(b"abc".to_vec());
+        for count in 0..64 {
+            let mut expected = Vec::new();
+            for _ in 0..count {
+                expected.extend_from_slice(b"abc");
+            }
+            let repeated = s.repeat(count).unwrap();
+            assert_eq!(repeated.as_slice(), expected.as_slice());
+            assert_eq!(repeated.encoding(), Encoding::Utf8);
+        }
     }
 
     #[test]
-    fn center_returns_error_with_empty_padding() {
-        let s = String::utf8(b"jumbo".to_vec());
-        let center = s.center(10, Some(b""));
-        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+    fn repeat_zero_preserves_encoding() {
+        let s = String::binary(b"abc".to_vec());
+        let repeated = s.repeat(0).unwrap();
+        assert!(repeated.is_empty());
+        assert_eq!(repeated.encoding(), Encoding::Binary);
+    }
 
-        let center = s.center(9, Some(b""));
-        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+    #[test]
+    fn repeat_with_huge_count_fails_without_allocating() {
+        let s = String::utf8(b"abc".to_vec());
+        assert_eq!(s.repeat(usize::MAX), Err(RepeatError::capacity_overflow()));
+        // A product which fits in a `usize` but exceeds `isize::MAX` is also
+        // rejected before any allocation is attempted.
+        let max_capacity = usize::try_from(isize::MAX).unwrap();
+        assert_eq!(s.repeat(max_capacity / 3 + 1), Err(RepeatError::capacity_overflow()));
+        assert_eq!(RepeatError::capacity_overflow().message(), "argument too big");
+    }
 
-        let center = s.center(1, Some(b""));
-        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+    #[test]
+    fn chomped_agrees_with_chomp() {
+        let cases: &[(&[u8], Option<&[u8]>)] = &[
+            (b"This is a paragraph.\r\n\n\n", None),
+            (b"This is a paragraph.\r\n\n\n", Some(b"")),
+            (b"hello\r\n\r\r\n", None),
+            (b"hello\r\n\r\r\n", Some(b"")),
+            (b"This is a paragraph.", Some(b".")),
+            (b"This is a paragraph.", Some(b"abc")),
+            (b"", None),
+            (b"", Some(b"")),
+        ];
+        for &(bytes, separator) in cases {
+            let s = String::utf8(bytes.to_vec());
+            let chomped = s.chomped(separator).to_vec();
+            let mut mutated = String::utf8(bytes.to_vec());
+            let modified = mutated.chomp(separator);
+            assert_eq!(chomped.as_slice(), mutated.as_slice());
+            assert_eq!(modified, chomped.len() != bytes.len());
+        }
+    }
 
-        let center = s.center(5, Some(b""));
-        assert!(matches!(center, Err(CenterError::ZeroWidthPadding)));
+    #[test]
+    fn chopped_agrees_with_chop() {
+        let cases: &[&[u8]] = &[b"This is a paragraph.\r\n", b"This is a paragraph.", b"", b"x"];
+        for &bytes in cases {
+            let s = String::utf8(bytes.to_vec());
+            let chopped = s.chopped().to_vec();
+            let mut mutated = String::utf8(bytes.to_vec());
+            let modified = mutated.chop();
+            assert_eq!(chopped.as_slice(), mutated.as_slice());
+            assert_eq!(modified, chopped.len() != bytes.len());
+        }
     }
 
     #[test]
-    fn chr_does_not_return_more_than_one_byte_for_invalid_utf8() {
-        // ```ruby
-        // [3.0.1] > "\xF0\x9F\x87".chr
-        // => "\xF0"
-        // ```
-        //
-        // Per `bstr`:
-        //
-        // The bytes `\xF0\x9F\x87` could lead to a valid UTF-8 sequence, but 3 of them
-        // on their own are invalid. Only one replacement codepoint is substituted,
-        // which demonstrates the "substitution of maximal subparts" strategy.
-        let s = String::utf8(b"\xF0\x9F\x87".to_vec());
-        assert_eq!(s.chr(), b"\xF0");
+    fn chomped_borrows_from_the_receiver() {
+        let s = String::utf8(b"This is a paragraph.\r\n\n\n".to_vec());
+        let chomped = s.chomped(None);
+        assert_eq!(chomped.as_ptr(), s.as_slice().as_ptr());
+        assert!(chomped.len() <= s.len());
     }
 
     #[test]
-    fn strings_compare_equal_only_based_on_byte_content() {
-        let utf8 = String::utf8(b"abc".to_vec());
-        let ascii = String::ascii(b"abc".to_vec());
-        let binary = String::binary(b"abc".to_vec());
-        assert_eq!(utf8, ascii);
-        assert_eq!(utf8, binary);
-        assert_eq!(binary, ascii);
+    fn chopped_borrows_from_the_receiver() {
+        let s = String::utf8(b"This is a paragraph.\r\n\n\n".to_vec());
+        let chopped = s.chopped();
+        assert_eq!(chopped.as_ptr(), s.as_slice().as_ptr());
+        assert!(chopped.len() < s.len());
     }
 
     #[test]
-    fn strings_compare_equal_only_based_on_byte_content_without_valid_encoding() {
-        let utf8 = String::utf8(b"abc\xFE\xFF".to_vec());
-        let ascii = String::ascii(b"abc\xFE\xFF".to_vec());
-        let binary = String::binary(b"abc\xFE\xFF".to_vec());
-        assert_eq!(utf8, ascii);
-        assert_eq!(utf8, binary);
-        assert_eq!(binary, ascii);
+    fn setbyte_out_of_range_error_carries_the_given_index_and_len() {
+        let mut s = String::utf8(b"hello".to_vec());
+        let err = s.setbyte(-6, 0).unwrap_err();
+        assert_eq!(err.index(), -6);
+        assert_eq!(err.string_len(), 5);
+        assert_eq!(err.message(), "index -6 out of string");
+        let err = s.setbyte(5, 0).unwrap_err();
+        assert_eq!(err.index(), 5);
+        assert_eq!(err.message(), "index 5 out of string");
+        assert_eq!(s, "hello");
+    }
+
+    quickcheck! {
+        fn getbyte_matches_slice_indexing(bytes: Vec<u8>, index: usize) -> bool {
+            let s = String::binary(bytes.clone());
+            if let Ok(signed) = i64::try_from(index) {
+                s.getbyte(signed) == bytes.get(index).copied()
+            } else {
+                true
+            }
+        }
+
+        fn setbyte_matches_direct_slice_writes(bytes: Vec<u8>, index: usize, value: i64) -> bool {
+            let mut expected = bytes.clone();
+            let mut s = String::binary(bytes);
+            let signed = match i64::try_from(index) {
+                Ok(signed) => signed,
+                Err(_) => return true,
+            };
+            if let Some(cell) = expected.get_mut(index) {
+                let byte = u8::try_from(value.rem_euclid(256)).unwrap();
+                *cell = byte;
+                s.setbyte(signed, value) == Ok(byte) && s == expected
+            } else {
+                s.setbyte(signed, value).is_err()
+            }
+        }
     }
 }
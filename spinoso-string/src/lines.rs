@@ -0,0 +1,224 @@
+use alloc::vec::{self, Vec};
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::String;
+
+/// An iterator over the lines of a [`String`] split by a separator.
+///
+/// This struct is created by the [`lines`] method on a Spinoso [`String`]. See
+/// its documentation for more.
+///
+/// Lines are yielded as `&[u8]` byte slices of the parent string. Callers
+/// that wish to turn a line back into a `String` should construct one with
+/// [`String::with_bytes_and_encoding`] using the parent string's encoding.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_string::String;
+///
+/// let s = String::from("hello\nworld\n");
+/// let lines = s.lines(None, false).collect::<Vec<_>>();
+/// assert_eq!(lines, [&b"hello\n"[..], &b"world\n"[..]]);
+/// ```
+///
+/// [`lines`]: crate::String::lines
+#[derive(Default, Debug, Clone)]
+pub struct Lines<'a> {
+    lines: vec::IntoIter<&'a [u8]>,
+}
+
+impl<'a> Lines<'a> {
+    #[must_use]
+    pub(crate) fn new(s: &'a String, separator: Option<&[u8]>, chomp: bool) -> Self {
+        let buf = s.as_slice();
+        let mut lines = Vec::new();
+
+        match separator {
+            // An empty separator selects paragraph mode: runs of two or more
+            // consecutive newlines separate paragraphs and are collapsed
+            // without being included in the yielded slices.
+            Some(b"") => split_paragraphs(buf, &mut lines),
+            Some(separator) => split_lines(buf, separator, chomp, &mut lines),
+            None => split_lines(buf, b"\n", chomp, &mut lines),
+        }
+
+        Self {
+            lines: lines.into_iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Lines<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.lines.next_back()
+    }
+}
+
+impl<'a> ExactSizeIterator for Lines<'a> {}
+
+impl<'a> FusedIterator for Lines<'a> {}
+
+fn split_lines<'a>(buf: &'a [u8], separator: &[u8], chomp: bool, lines: &mut Vec<&'a [u8]>) {
+    let mut pos = 0;
+    while pos < buf.len() {
+        if let Some(index) = buf[pos..].find(separator) {
+            let mut line = &buf[pos..pos + index + separator.len()];
+            if chomp {
+                line = &line[..index];
+                // Chomping the default newline separator also removes a
+                // preceding carriage return.
+                //
+                // ```
+                // [3.0.1] > "hello\r\nworld\r\n".lines(chomp: true)
+                // => ["hello", "world"]
+                // ```
+                if separator == b"\n" {
+                    if let Some(chomped) = line.strip_suffix(b"\r") {
+                        line = chomped;
+                    }
+                }
+            }
+            lines.push(line);
+            pos += index + separator.len();
+        } else {
+            // The final line without a trailing separator is still yielded.
+            // There is no separator to chomp.
+            //
+            // ```
+            // [3.0.1] > "hello\nworld".lines(chomp: true)
+            // => ["hello", "world"]
+            // ```
+            lines.push(&buf[pos..]);
+            break;
+        }
+    }
+}
+
+fn split_paragraphs<'a>(buf: &'a [u8], lines: &mut Vec<&'a [u8]>) {
+    let mut pos = 0;
+    while pos < buf.len() {
+        // Runs of blank lines between paragraphs are collapsed and are not
+        // included in the yielded slices, so there is never a separator to
+        // chomp in paragraph mode.
+        while buf.get(pos) == Some(&b'\n') {
+            pos += 1;
+        }
+        if pos >= buf.len() {
+            break;
+        }
+        if let Some(index) = buf[pos..].find(b"\n\n") {
+            lines.push(&buf[pos..pos + index]);
+            pos += index;
+        } else {
+            lines.push(&buf[pos..]);
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::String;
+
+    #[test]
+    fn default_separator_includes_the_newline() {
+        let s = String::from("hello\nworld\n");
+        let lines = s.lines(None, false).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"hello\n"[..], &b"world\n"[..]]);
+    }
+
+    #[test]
+    fn final_line_without_separator_is_yielded() {
+        let s = String::from("hello\nworld");
+        let lines = s.lines(None, false).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"hello\n"[..], &b"world"[..]]);
+
+        let lines = s.lines(None, true).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"hello"[..], &b"world"[..]]);
+    }
+
+    #[test]
+    fn empty_string_has_no_lines() {
+        let s = String::from("");
+        assert_eq!(s.lines(None, false).count(), 0);
+    }
+
+    #[test]
+    fn crlf_data_with_newline_separator() {
+        let s = String::from("hello\r\nworld\r\n");
+        let lines = s.lines(None, false).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"hello\r\n"[..], &b"world\r\n"[..]]);
+
+        // Chomping the default separator removes the carriage return too.
+        let lines = s.lines(None, true).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"hello"[..], &b"world"[..]]);
+    }
+
+    #[test]
+    fn multibyte_custom_separator() {
+        let s = String::from("a--b--c");
+        let lines = s.lines(Some(b"--"), false).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"a--"[..], &b"b--"[..], &b"c"[..]]);
+
+        let lines = s.lines(Some(b"--"), true).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"a"[..], &b"b"[..], &b"c"[..]]);
+    }
+
+    #[test]
+    fn chomping_a_custom_separator_preserves_carriage_returns() {
+        let s = String::from("a\r--b");
+        let lines = s.lines(Some(b"--"), true).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"a\r"[..], &b"b"[..]]);
+    }
+
+    #[test]
+    fn paragraph_mode_collapses_blank_line_runs() {
+        let s = String::from("para one\n\n\n\npara two\nstill two\n\npara three");
+        let lines = s.lines(Some(b""), false).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"para one"[..], &b"para two\nstill two"[..], &b"para three"[..]]);
+    }
+
+    #[test]
+    fn paragraph_mode_skips_leading_and_trailing_blank_lines() {
+        let s = String::from("\n\nfirst\n\nsecond\n\n\n");
+        let lines = s.lines(Some(b""), false).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"first"[..], &b"second"[..]]);
+    }
+
+    #[test]
+    fn binary_content_is_preserved_exactly() {
+        let s = String::binary(b"\xFF\xFE\n\x80\n".to_vec());
+        let lines = s.lines(None, false).collect::<Vec<_>>();
+        assert_eq!(lines, [&b"\xFF\xFE\n"[..], &b"\x80\n"[..]]);
+    }
+
+    #[test]
+    fn iterator_is_double_ended_and_clonable() {
+        let s = String::from("a\nb\nc");
+        let mut lines = s.lines(None, true);
+        assert_eq!(lines.next_back(), Some(&b"c"[..]));
+        let rest = lines.clone().collect::<Vec<_>>();
+        assert_eq!(rest, [&b"a"[..], &b"b"[..]]);
+        assert_eq!(lines.next(), Some(&b"a"[..]));
+    }
+}
@@ -0,0 +1,84 @@
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::{Chars, String};
+
+/// An iterator over the non-overlapping occurrences of a needle in a
+/// [`String`].
+///
+/// This struct is created by the [`matches`] method on a Spinoso [`String`].
+/// See its documentation for more.
+///
+/// Occurrences are yielded as byte offsets into the parent string. After each
+/// hit the search resumes at the end of the match, so occurrences never
+/// overlap. An empty needle matches at every character boundary, interpreted
+/// according to the parent string's encoding.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_string::String;
+///
+/// let s = String::from("mellow yellow");
+/// let offsets = s.matches(b"ello").collect::<Vec<_>>();
+/// assert_eq!(offsets, [1, 8]);
+/// ```
+///
+/// [`matches`]: crate::String::matches
+#[derive(Debug)]
+pub struct Matches<'a>(State<'a>);
+
+impl<'a> Matches<'a> {
+    #[must_use]
+    pub(crate) fn new(s: &'a String, needle: &'a [u8]) -> Self {
+        let state = if needle.is_empty() {
+            // An empty needle matches at every character boundary, including
+            // the end of the string.
+            //
+            // ```
+            // [3.0.1] > "abc".scan("")
+            // => ["", "", "", ""]
+            // ```
+            State::CharBoundaries {
+                chars: s.chars(),
+                offset: 0,
+                done: false,
+            }
+        } else {
+            State::Find(s.as_slice().find_iter(needle))
+        };
+        Self(state)
+    }
+}
+
+impl<'a> Iterator for Matches<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            State::Find(iter) => iter.next(),
+            State::CharBoundaries { chars, offset, done } => {
+                if *done {
+                    return None;
+                }
+                let boundary = *offset;
+                if let Some(ch) = chars.next() {
+                    *offset += ch.len();
+                } else {
+                    *done = true;
+                }
+                Some(boundary)
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for Matches<'a> {}
+
+#[derive(Debug)]
+enum State<'a> {
+    Find(bstr::Find<'a>),
+    CharBoundaries { chars: Chars<'a>, offset: usize, done: bool },
+}
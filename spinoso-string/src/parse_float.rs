@@ -0,0 +1,452 @@
+//! Byte-oriented float parsing with Ruby's conversion rules.
+//!
+//! This module backs `String#to_f` and the strict conversion in
+//! `Kernel#Float`. Both modes accept an optional sign, single underscores
+//! between digits, and an optional exponent. The lenient mode ignores leading
+//! whitespace, stops at the first invalid character, and never fails; the
+//! strict mode requires the entire byte string to be a valid float literal and
+//! additionally accepts hexadecimal float literals like `"0x1p3"`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use scolapasta_string_escape::format_debug_escape_into;
+
+/// Error returned when a byte string is not a valid strict `Float` literal.
+///
+/// This error is returned from [`String::parse_float`] in strict mode. See
+/// its documentation for more detail.
+///
+/// This error corresponds to the [Ruby `ArgumentError` Exception class]
+/// raised by `Kernel#Float`.
+///
+/// When the **std** feature of `spinoso-string` is enabled, this struct
+/// implements [`std::error::Error`].
+///
+/// [`String::parse_float`]: crate::String::parse_float
+/// [Ruby `ArgumentError` Exception class]: https://ruby-doc.org/core-2.6.3/ArgumentError.html
+/// [`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
+#[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ParseFloatError {
+    input: Vec<u8>,
+}
+
+impl ParseFloatError {
+    /// `ParseFloatError` corresponds to an [`ArgumentError`] Ruby exception.
+    ///
+    /// [`ArgumentError`]: https://ruby-doc.org/core-2.6.3/ArgumentError.html
+    pub const EXCEPTION_TYPE: &'static str = "ArgumentError";
+
+    /// Construct a new `ParseFloatError` for the given invalid input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use spinoso_string::ParseFloatError;
+    ///
+    /// let err = ParseFloatError::with_input(b"razzmatazz");
+    /// assert_eq!(err.input(), b"razzmatazz");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_input(input: &[u8]) -> Self {
+        Self { input: input.to_vec() }
+    }
+
+    /// Retrieve the invalid input that caused this error.
+    #[inline]
+    #[must_use]
+    pub fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    /// Retrieve the exception message associated with this error.
+    ///
+    /// Invalid UTF-8 bytes in the input are hex-escaped in the message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_string::ParseFloatError;
+    /// let err = ParseFloatError::with_input(b"1.5z");
+    /// assert_eq!(err.message(), r#"invalid value for Float(): "1.5z""#);
+    ///
+    /// let err = ParseFloatError::with_input(b"1.5\xFF");
+    /// assert_eq!(err.message(), r#"invalid value for Float(): "1.5\xFF""#);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> String {
+        let mut s = String::with_capacity(30 + self.input.len());
+        // In practice, the errors from `write!` below are safe to ignore
+        // because the `core::fmt::Write` impl for `String` will never panic
+        // and these `String`s will never approach `isize::MAX` bytes.
+        //
+        // See the `core::fmt::Display` impl for `ParseFloatError`.
+        let _ = write!(s, "{}", self);
+        s
+    }
+}
+
+impl fmt::Display for ParseFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(r#"invalid value for Float(): ""#)?;
+        format_debug_escape_into(&mut *f, &self.input)?;
+        f.write_str("\"")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFloatError {}
+
+/// The result of scanning a run of digits.
+struct DigitRun {
+    /// Number of digits consumed.
+    count: usize,
+    /// Whether the run was terminated by an underscore that was not followed
+    /// by another digit, which ends the whole number in lenient mode and is
+    /// an error in strict mode.
+    dangling_underscore: bool,
+}
+
+/// Scan a run of digits in the given radix, appending the digits to `out`
+/// with underscores removed.
+fn scan_digits(bytes: &[u8], pos: &mut usize, out: &mut String, radix: u32) -> DigitRun {
+    let mut count = 0;
+    while let Some(&byte) = bytes.get(*pos) {
+        match byte {
+            // A single underscore may separate digits. An underscore must be
+            // preceded and followed by a digit.
+            b'_' if count > 0 => match bytes.get(*pos + 1) {
+                Some(&next) if char::from(next).is_digit(radix) => {
+                    out.push(char::from(next));
+                    count += 1;
+                    *pos += 2;
+                }
+                _ => {
+                    return DigitRun {
+                        count,
+                        dangling_underscore: true,
+                    }
+                }
+            },
+            byte if char::from(byte).is_digit(radix) => {
+                out.push(char::from(byte));
+                count += 1;
+                *pos += 1;
+            }
+            _ => break,
+        }
+    }
+    DigitRun {
+        count,
+        dangling_underscore: false,
+    }
+}
+
+/// Parse the longest leading decimal float, returning the parsed value and
+/// whether a dangling underscore or missing digits invalidated the literal.
+///
+/// On success, `pos` points one past the last consumed byte.
+fn parse_decimal(bytes: &[u8], pos: &mut usize, allow_leading_fraction: bool) -> Option<f64> {
+    let mut literal = String::with_capacity(bytes.len());
+
+    let integer = scan_digits(bytes, pos, &mut literal, 10);
+    let mut digits = integer.count;
+    if integer.dangling_underscore {
+        return finish_decimal(&literal, digits);
+    }
+
+    if let (b'.', Some(b'0'..=b'9')) = (
+        bytes.get(*pos).copied().unwrap_or_default(),
+        bytes.get(*pos + 1).copied(),
+    ) {
+        if digits == 0 && !allow_leading_fraction {
+            return None;
+        }
+        if digits == 0 {
+            literal.push('0');
+        }
+        literal.push('.');
+        *pos += 1;
+        let fraction = scan_digits(bytes, pos, &mut literal, 10);
+        digits += fraction.count;
+        if fraction.dangling_underscore {
+            return finish_decimal(&literal, digits);
+        }
+    }
+    if digits == 0 {
+        return None;
+    }
+
+    if let Some(b'e' | b'E') = bytes.get(*pos).copied() {
+        let mut exponent_pos = *pos + 1;
+        let mut exponent = String::from("e");
+        if let Some(sign @ (b'+' | b'-')) = bytes.get(exponent_pos).copied() {
+            exponent.push(char::from(sign));
+            exponent_pos += 1;
+        }
+        let exponent_digits = scan_digits(bytes, &mut exponent_pos, &mut exponent, 10);
+        // An exponent marker with no digits is not part of the number.
+        if exponent_digits.count > 0 {
+            literal.push_str(&exponent);
+            *pos = exponent_pos;
+        }
+    }
+
+    finish_decimal(&literal, digits)
+}
+
+fn finish_decimal(literal: &str, digits: usize) -> Option<f64> {
+    if digits == 0 {
+        return None;
+    }
+    literal.parse().ok()
+}
+
+/// Parse a hexadecimal float literal like `0x1.8p3` following an already
+/// consumed `0x` prefix.
+fn parse_hexadecimal(bytes: &[u8], pos: &mut usize) -> Option<f64> {
+    let mut integer = String::new();
+    let run = scan_digits(bytes, pos, &mut integer, 16);
+    if run.count == 0 || run.dangling_underscore {
+        return None;
+    }
+    let mut value = 0.0_f64;
+    for digit in integer.chars() {
+        value = value * 16.0 + f64::from(digit.to_digit(16)?);
+    }
+
+    if let Some(b'.') = bytes.get(*pos).copied() {
+        let mut fraction = String::new();
+        let mut fraction_pos = *pos + 1;
+        let run = scan_digits(bytes, &mut fraction_pos, &mut fraction, 16);
+        if run.count == 0 || run.dangling_underscore {
+            return None;
+        }
+        *pos = fraction_pos;
+        let mut scale = 1.0_f64 / 16.0;
+        for digit in fraction.chars() {
+            value += f64::from(digit.to_digit(16)?) * scale;
+            scale /= 16.0;
+        }
+    }
+
+    if let Some(b'p' | b'P') = bytes.get(*pos).copied() {
+        *pos += 1;
+        let mut is_negative = false;
+        match bytes.get(*pos).copied() {
+            Some(b'+') => *pos += 1,
+            Some(b'-') => {
+                is_negative = true;
+                *pos += 1;
+            }
+            _ => {}
+        }
+        let mut exponent = String::new();
+        let run = scan_digits(bytes, pos, &mut exponent, 10);
+        if run.count == 0 || run.dangling_underscore {
+            return None;
+        }
+        // Saturate the exponent: `f64` overflows to infinity or rounds to
+        // zero long before the clamp is reached.
+        let mut power = 0_i32;
+        for digit in exponent.chars() {
+            let digit = i32::try_from(digit.to_digit(10)?).ok()?;
+            power = power.saturating_mul(10).saturating_add(digit);
+        }
+        power = power.min(4096);
+        if is_negative {
+            power = -power;
+        }
+        value *= 2.0_f64.powi(power);
+    }
+
+    Some(value)
+}
+
+/// Parse a byte string as a float with `String#to_f`'s lenient rules.
+///
+/// Leading whitespace and an optional sign are consumed, parsing stops at the
+/// first invalid character, and byte strings with no leading digits parse to
+/// `0.0`.
+pub fn parse_lenient(bytes: &[u8]) -> f64 {
+    let mut pos = 0;
+    while let Some(&byte) = bytes.get(pos) {
+        // Match MRI's `ISSPACE`, which unlike `u8::is_ascii_whitespace`
+        // includes vertical tab.
+        if !matches!(byte, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r') {
+            break;
+        }
+        pos += 1;
+    }
+    let mut is_negative = false;
+    match bytes.get(pos).copied() {
+        Some(b'+') => pos += 1,
+        Some(b'-') => {
+            is_negative = true;
+            pos += 1;
+        }
+        _ => {}
+    }
+    let value = parse_decimal(bytes, &mut pos, true).unwrap_or_default();
+    if is_negative {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Parse a byte string as a float with `Kernel#Float`'s strict rules.
+///
+/// The entire byte string must be a valid decimal or hexadecimal float
+/// literal with no surrounding whitespace.
+///
+/// # Errors
+///
+/// If the byte string is not a valid float literal, an error quoting the
+/// input is returned.
+pub fn parse_strict(bytes: &[u8]) -> Result<f64, ParseFloatError> {
+    let err = || ParseFloatError::with_input(bytes);
+
+    let mut pos = 0;
+    let mut is_negative = false;
+    match bytes.get(pos).copied() {
+        Some(b'+') => pos += 1,
+        Some(b'-') => {
+            is_negative = true;
+            pos += 1;
+        }
+        _ => {}
+    }
+    let value = if let (Some(b'0'), Some(b'x' | b'X')) = (bytes.get(pos).copied(), bytes.get(pos + 1).copied()) {
+        pos += 2;
+        parse_hexadecimal(bytes, &mut pos).ok_or_else(err)?
+    } else {
+        parse_decimal(bytes, &mut pos, false).ok_or_else(err)?
+    };
+    if pos != bytes.len() {
+        return Err(err());
+    }
+    if is_negative {
+        Ok(-value)
+    } else {
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use quickcheck::quickcheck;
+
+    use super::{parse_lenient, parse_strict, ParseFloatError};
+
+    #[test]
+    fn lenient_parses_leading_float() {
+        assert_eq!(parse_lenient(b"1.5"), 1.5);
+        assert_eq!(parse_lenient(b"-1.5"), -1.5);
+        assert_eq!(parse_lenient(b"+1.5"), 1.5);
+        assert_eq!(parse_lenient(b"3.14striving"), 3.14);
+        assert_eq!(parse_lenient(b"1e3"), 1000.0);
+        assert_eq!(parse_lenient(b"1.5E-2"), 0.015);
+        assert_eq!(parse_lenient(b".5"), 0.5);
+        assert_eq!(parse_lenient(b"5."), 5.0);
+    }
+
+    #[test]
+    fn lenient_returns_zero_for_garbage() {
+        assert_eq!(parse_lenient(b""), 0.0);
+        assert_eq!(parse_lenient(b"razzmatazz"), 0.0);
+        assert_eq!(parse_lenient(b"+-5"), 0.0);
+        assert_eq!(parse_lenient(b"\xFF1.5"), 0.0);
+        assert_eq!(parse_lenient(b"0x1p3"), 0.0);
+    }
+
+    #[test]
+    fn lenient_ignores_leading_whitespace_but_stops_at_interior_whitespace() {
+        assert_eq!(parse_lenient(b" \t\n\x0B\x0C\r1.5"), 1.5);
+        assert_eq!(parse_lenient(b"1.5 2.0"), 1.5);
+        assert_eq!(parse_lenient(b"1.5\n"), 1.5);
+    }
+
+    #[test]
+    fn lenient_allows_single_underscores_between_digits() {
+        assert_eq!(parse_lenient(b"1_2.3_4"), 12.34);
+        assert_eq!(parse_lenient(b"1e1_0"), 1e10);
+        assert_eq!(parse_lenient(b"1_.5"), 1.0);
+        assert_eq!(parse_lenient(b"1__2"), 1.0);
+        assert_eq!(parse_lenient(b"1.5_"), 1.5);
+        assert_eq!(parse_lenient(b"_1.5"), 0.0);
+    }
+
+    #[test]
+    fn lenient_stops_at_invalid_exponent() {
+        assert_eq!(parse_lenient(b"1.5e"), 1.5);
+        assert_eq!(parse_lenient(b"1.5e+"), 1.5);
+        assert_eq!(parse_lenient(b"1.5ex"), 1.5);
+    }
+
+    #[test]
+    fn strict_parses_whole_float_literals() {
+        assert_eq!(parse_strict(b"1.5"), Ok(1.5));
+        assert_eq!(parse_strict(b"-1.5"), Ok(-1.5));
+        assert_eq!(parse_strict(b"1_2.3_4"), Ok(12.34));
+        assert_eq!(parse_strict(b"1e3"), Ok(1000.0));
+        assert_eq!(parse_strict(b"1.5E-2"), Ok(0.015));
+        assert_eq!(parse_strict(b"10"), Ok(10.0));
+    }
+
+    #[test]
+    fn strict_parses_hexadecimal_float_literals() {
+        assert_eq!(parse_strict(b"0x1p3"), Ok(8.0));
+        assert_eq!(parse_strict(b"0x1.8p1"), Ok(3.0));
+        assert_eq!(parse_strict(b"-0x1p-1"), Ok(-0.5));
+        assert_eq!(parse_strict(b"0xA"), Ok(10.0));
+        assert_eq!(parse_strict(b"0X1P3"), Ok(8.0));
+    }
+
+    #[test]
+    fn strict_rejects_partial_and_malformed_literals() {
+        for input in [
+            &b""[..],
+            b"razzmatazz",
+            b"1.5z",
+            b".5",
+            b"5.",
+            b"1.5e",
+            b"_1",
+            b"1_",
+            b"1__2",
+            b"1.5 ",
+            b" 1.5",
+            b"0x",
+            b"0x1p",
+            b"0x.8p1",
+        ] {
+            assert_eq!(parse_strict(input), Err(ParseFloatError::with_input(input)), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn error_message_quotes_and_escapes_the_input() {
+        let err = parse_strict(b"trailing\xFF").unwrap_err();
+        assert_eq!(err.message(), r#"invalid value for Float(): "trailing\xFF""#);
+    }
+
+    quickcheck! {
+        fn round_trips_formatted_floats(value: f64) -> bool {
+            if !value.is_finite() {
+                return true;
+            }
+            let formatted = format!("{}", value);
+            let expected = formatted.parse::<f64>().unwrap();
+            let strict = match parse_strict(formatted.as_bytes()) {
+                Ok(parsed) => parsed,
+                Err(_) => return false,
+            };
+            strict.to_bits() == expected.to_bits() && parse_lenient(formatted.as_bytes()).to_bits() == expected.to_bits()
+        }
+    }
+}
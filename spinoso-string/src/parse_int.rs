@@ -0,0 +1,286 @@
+//! Byte-oriented integer parsing with Ruby's forgiving conversion rules.
+//!
+//! This module backs `String#hex`, `String#oct`, and `String#to_i`. Unlike
+//! `Kernel#Integer`, these conversions never raise: parsing stops at the first
+//! invalid character and a string with no leading digits converts to zero.
+
+/// The result of parsing a byte string as an integer.
+///
+/// Ruby promotes integer literals that do not fit in a machine word to
+/// `Bignum`. This crate only computes [`i64`] values, so parses whose digits
+/// overflow are reported with [`ParsedInteger::Overflow`] and callers can
+/// fall back to an arbitrary precision parse.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ParsedInteger {
+    /// The parsed value, which fits in an `i64`.
+    Value(i64),
+    /// The digits in the byte string overflow an `i64`.
+    Overflow,
+}
+
+/// The radix to parse digits in.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Radix {
+    /// Parse in the given base, honoring the base's literal prefix (`0b`,
+    /// `0o`, `0d`, or `0x`) if present.
+    Fixed(u32),
+    /// Detect the base from a literal prefix, treating a bare leading zero as
+    /// octal. Digits with no prefix are parsed in the given default base.
+    Detect(u32),
+}
+
+/// Parse a byte string with Ruby's forgiving integer conversion rules.
+///
+/// Leading ASCII whitespace is ignored, an optional leading sign and base
+/// prefix are honored, single underscores may separate digits, and parsing
+/// stops at the first invalid character. Byte strings with no leading digits
+/// parse to zero.
+pub fn parse(bytes: &[u8], radix: Radix) -> ParsedInteger {
+    let mut bytes = bytes;
+    while let [first, rest @ ..] = bytes {
+        // Match MRI's `ISSPACE`, which unlike `u8::is_ascii_whitespace`
+        // includes vertical tab.
+        if !matches!(first, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r') {
+            break;
+        }
+        bytes = rest;
+    }
+    let mut is_negative = false;
+    match bytes {
+        [b'+', rest @ ..] => bytes = rest,
+        [b'-', rest @ ..] => {
+            is_negative = true;
+            bytes = rest;
+        }
+        _ => {}
+    }
+    let base = match radix {
+        Radix::Fixed(base) => {
+            bytes = strip_prefix(bytes, base);
+            base
+        }
+        Radix::Detect(default) => match bytes {
+            [b'0', b'b' | b'B', rest @ ..] if starts_with_digit(rest, 2) => {
+                bytes = rest;
+                2
+            }
+            [b'0', b'o' | b'O', rest @ ..] if starts_with_digit(rest, 8) => {
+                bytes = rest;
+                8
+            }
+            [b'0', b'd' | b'D', rest @ ..] if starts_with_digit(rest, 10) => {
+                bytes = rest;
+                10
+            }
+            [b'0', b'x' | b'X', rest @ ..] if starts_with_digit(rest, 16) => {
+                bytes = rest;
+                16
+            }
+            [b'0', ..] => 8,
+            _ => default,
+        },
+    };
+
+    let mut magnitude = 0_u64;
+    let mut digits = 0_usize;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let digit = match bytes[idx] {
+            // A single underscore may separate digits. A leading, trailing,
+            // or doubled underscore terminates the number, matching MRI.
+            b'_' if digits > 0 => match bytes.get(idx + 1).copied().and_then(|byte| digit_value(byte, base)) {
+                Some(digit) => {
+                    idx += 1;
+                    digit
+                }
+                None => break,
+            },
+            byte => match digit_value(byte, base) {
+                Some(digit) => digit,
+                None => break,
+            },
+        };
+        magnitude = match magnitude
+            .checked_mul(u64::from(base))
+            .and_then(|shifted| shifted.checked_add(u64::from(digit)))
+        {
+            Some(magnitude) => magnitude,
+            None => return ParsedInteger::Overflow,
+        };
+        digits += 1;
+        idx += 1;
+    }
+
+    if digits == 0 {
+        return ParsedInteger::Value(0);
+    }
+    const I64_MIN_MAGNITUDE: u64 = i64::MAX as u64 + 1;
+    match (is_negative, magnitude) {
+        (true, I64_MIN_MAGNITUDE) => ParsedInteger::Value(i64::MIN),
+        (true, magnitude) if magnitude < I64_MIN_MAGNITUDE => {
+            ParsedInteger::Value(-i64::try_from(magnitude).unwrap_or_default())
+        }
+        (false, magnitude) if magnitude <= i64::MAX as u64 => {
+            ParsedInteger::Value(i64::try_from(magnitude).unwrap_or_default())
+        }
+        _ => ParsedInteger::Overflow,
+    }
+}
+
+/// Strip the literal prefix for the given base, if present.
+///
+/// The prefix is only consumed when it is followed by a valid digit so that
+/// byte strings like `"0x_1"` and `"0x"` parse as `0` followed by an invalid
+/// character.
+fn strip_prefix(bytes: &[u8], base: u32) -> &[u8] {
+    let rest = match (base, bytes) {
+        (2, [b'0', b'b' | b'B', rest @ ..])
+        | (8, [b'0', b'o' | b'O', rest @ ..])
+        | (10, [b'0', b'd' | b'D', rest @ ..])
+        | (16, [b'0', b'x' | b'X', rest @ ..]) => rest,
+        _ => return bytes,
+    };
+    if starts_with_digit(rest, base) {
+        rest
+    } else {
+        bytes
+    }
+}
+
+fn starts_with_digit(bytes: &[u8], base: u32) -> bool {
+    matches!(bytes.first(), Some(&byte) if digit_value(byte, base).is_some())
+}
+
+fn digit_value(byte: u8, base: u32) -> Option<u32> {
+    char::from(byte).to_digit(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, ParsedInteger, Radix};
+
+    fn parse_value(bytes: &[u8], radix: Radix) -> i64 {
+        match parse(bytes, radix) {
+            ParsedInteger::Value(value) => value,
+            ParsedInteger::Overflow => panic!("unexpected overflow parsing {:?}", bytes),
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> i64 {
+        parse_value(bytes, Radix::Fixed(16))
+    }
+
+    fn oct(bytes: &[u8]) -> i64 {
+        parse_value(bytes, Radix::Detect(8))
+    }
+
+    fn to_i(bytes: &[u8]) -> i64 {
+        parse_value(bytes, Radix::Fixed(10))
+    }
+
+    #[test]
+    fn hex_parses_hexadecimal_with_optional_prefix() {
+        assert_eq!(hex(b"0x0a"), 10);
+        assert_eq!(hex(b"0X0A"), 10);
+        assert_eq!(hex(b"0a"), 10);
+        assert_eq!(hex(b"-1234"), -4660);
+        assert_eq!(hex(b"0"), 0);
+        assert_eq!(hex(b"ffff"), 65535);
+    }
+
+    #[test]
+    fn hex_returns_zero_when_no_characters_are_a_hex_number() {
+        assert_eq!(hex(b""), 0);
+        assert_eq!(hex(b"+-5"), 0);
+        assert_eq!(hex(b"wombat"), 0);
+        assert_eq!(hex(b"0x"), 0);
+        assert_eq!(hex(b"0x_1"), 0);
+    }
+
+    #[test]
+    fn hex_does_not_accept_a_sequence_of_underscores_as_part_of_a_number() {
+        assert_eq!(hex(b"a_"), 0xA);
+        assert_eq!(hex(b"a__b"), 0xA);
+        assert_eq!(hex(b"a_b"), 0xAB);
+    }
+
+    #[test]
+    fn oct_parses_leading_octal_and_radix_prefixes() {
+        assert_eq!(oct(b"755"), 493);
+        assert_eq!(oct(b"0755"), 493);
+        assert_eq!(oct(b"0o777"), 511);
+        assert_eq!(oct(b"0b1010"), 10);
+        assert_eq!(oct(b"0d500"), 500);
+        assert_eq!(oct(b"0xFF"), 255);
+        assert_eq!(oct(b"-0b1010"), -10);
+        assert_eq!(oct(b"-0xFF"), -255);
+    }
+
+    #[test]
+    fn oct_stops_at_the_first_invalid_octal_digit() {
+        assert_eq!(oct(b"08"), 0);
+        assert_eq!(oct(b"755_333"), 0o755_333);
+        assert_eq!(oct(b"5678"), 0o567);
+        assert_eq!(oct(b""), 0);
+        assert_eq!(oct(b"wombat"), 0);
+    }
+
+    #[test]
+    fn to_i_parses_leading_decimal_digits() {
+        assert_eq!(to_i(b"12345"), 12345);
+        assert_eq!(to_i(b"99 red balloons"), 99);
+        assert_eq!(to_i(b"0a"), 0);
+        assert_eq!(to_i(b"hello"), 0);
+        assert_eq!(to_i(b""), 0);
+        assert_eq!(to_i(b"-1"), -1);
+        assert_eq!(to_i(b"+1"), 1);
+    }
+
+    #[test]
+    fn to_i_ignores_leading_whitespace_and_underscores_between_digits() {
+        assert_eq!(to_i(b" \t\n\x0B\x0C\r123"), 123);
+        assert_eq!(to_i(b"1_2_3"), 123);
+        assert_eq!(to_i(b"1_2_3asdf"), 123);
+        assert_eq!(to_i(b"_123"), 0);
+        assert_eq!(to_i(b"1__2"), 1);
+        assert_eq!(to_i(b"12_"), 12);
+    }
+
+    #[test]
+    fn to_i_with_explicit_base_honors_matching_prefix() {
+        assert_eq!(parse_value(b"0x10", Radix::Fixed(16)), 16);
+        assert_eq!(parse_value(b"0b10", Radix::Fixed(2)), 2);
+        assert_eq!(parse_value(b"0o10", Radix::Fixed(8)), 8);
+        assert_eq!(parse_value(b"0d10", Radix::Fixed(10)), 10);
+        // A prefix for a different base terminates the number.
+        assert_eq!(parse_value(b"0x10", Radix::Fixed(10)), 0);
+        assert_eq!(parse_value(b"z", Radix::Fixed(36)), 35);
+    }
+
+    #[test]
+    fn detected_base_falls_back_to_the_default() {
+        assert_eq!(parse_value(b"10", Radix::Detect(10)), 10);
+        assert_eq!(parse_value(b"0x10", Radix::Detect(10)), 16);
+        assert_eq!(parse_value(b"010", Radix::Detect(10)), 8);
+        assert_eq!(parse_value(b"0", Radix::Detect(10)), 0);
+    }
+
+    #[test]
+    fn invalid_utf8_bytes_terminate_the_number() {
+        assert_eq!(to_i(b"99\xFF"), 99);
+        assert_eq!(to_i(b"\xFF99"), 0);
+        assert_eq!(hex(b"a\xFFb"), 0xA);
+    }
+
+    #[test]
+    fn digits_that_overflow_i64_are_reported() {
+        assert_eq!(parse(b"9223372036854775807", Radix::Fixed(10)), ParsedInteger::Value(i64::MAX));
+        assert_eq!(parse(b"9223372036854775808", Radix::Fixed(10)), ParsedInteger::Overflow);
+        assert_eq!(
+            parse(b"-9223372036854775808", Radix::Fixed(10)),
+            ParsedInteger::Value(i64::MIN)
+        );
+        assert_eq!(parse(b"-9223372036854775809", Radix::Fixed(10)), ParsedInteger::Overflow);
+        assert_eq!(parse(b"ffffffffffffffffff", Radix::Fixed(16)), ParsedInteger::Overflow);
+    }
+}
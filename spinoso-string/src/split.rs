@@ -0,0 +1,216 @@
+use alloc::vec::{self, Vec};
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::String;
+
+/// An iterator over the fields of a [`String`] split by a separator.
+///
+/// This struct is created by the [`split`] method on a Spinoso [`String`]. See
+/// its documentation for more.
+///
+/// Fields are yielded as `&[u8]` byte slices of the parent string. Callers
+/// that wish to turn a field back into a `String` should construct one with
+/// [`String::with_bytes_and_encoding`] using the parent string's encoding.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_string::String;
+///
+/// let s = String::from("mellow yellow");
+/// let fields = s.split(b"ello", None).collect::<Vec<_>>();
+/// assert_eq!(fields, [&b"m"[..], &b"w y"[..], &b"w"[..]]);
+/// ```
+///
+/// [`split`]: crate::String::split
+#[derive(Default, Debug, Clone)]
+pub struct Split<'a> {
+    fields: vec::IntoIter<&'a [u8]>,
+}
+
+impl<'a> Split<'a> {
+    #[must_use]
+    pub(crate) fn new(s: &'a String, separator: &[u8], limit: Option<usize>) -> Self {
+        // A limit of zero behaves identically to no limit at all.
+        //
+        // ```
+        // [3.0.1] > "a,b,,".split(",", 0)
+        // => ["a", "b"]
+        // ```
+        let limit = match limit {
+            None | Some(0) => None,
+            Some(limit) => Some(limit),
+        };
+        let buf = s.as_slice();
+        let mut fields = Vec::new();
+
+        // Empty strings have no fields regardless of separator and limit.
+        //
+        // ```
+        // [3.0.1] > "".split(",")
+        // => []
+        // [3.0.1] > "".split(",", -1)
+        // => []
+        // ```
+        if buf.is_empty() {
+            return Self {
+                fields: fields.into_iter(),
+            };
+        }
+
+        match separator {
+            // A separator of a single space selects awk-style splitting: split
+            // on runs of ASCII whitespace with leading whitespace skipped.
+            //
+            // ```
+            // [3.0.1] > " now's  the time ".split(" ")
+            // => ["now's", "the", "time"]
+            // ```
+            b" " => split_whitespace_runs(buf, limit, &mut fields),
+            // An empty separator splits the string into characters,
+            // interpreted according to the string's encoding.
+            //
+            // ```
+            // [3.0.1] > "hello".split("")
+            // => ["h", "e", "l", "l", "o"]
+            // ```
+            b"" => split_chars(s, limit, &mut fields),
+            separator => split_on_substring(buf, separator, limit, &mut fields),
+        }
+
+        Self {
+            fields: fields.into_iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fields.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.fields.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Split<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.fields.next_back()
+    }
+}
+
+impl<'a> ExactSizeIterator for Split<'a> {}
+
+impl<'a> FusedIterator for Split<'a> {}
+
+/// Whether the given byte is whitespace per POSIX `isspace`.
+///
+/// [`u8::is_ascii_whitespace`] cannot be used here because it does not treat
+/// vertical tab (`\x0B`) as whitespace, which MRI does when splitting.
+#[inline]
+#[must_use]
+const fn is_split_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r')
+}
+
+fn split_whitespace_runs<'a>(buf: &'a [u8], limit: Option<usize>, fields: &mut Vec<&'a [u8]>) {
+    let mut pos = 0;
+    while pos < buf.len() && is_split_whitespace(buf[pos]) {
+        pos += 1;
+    }
+    while pos < buf.len() {
+        // Once the limit is reached, the remainder of the string — including
+        // any trailing whitespace — is left unsplit in the last field.
+        //
+        // ```
+        // [3.0.1] > "  a b c ".split(" ", 2)
+        // => ["a", "b c "]
+        // ```
+        if let Some(limit) = limit {
+            if fields.len() + 1 == limit {
+                fields.push(&buf[pos..]);
+                return;
+            }
+        }
+        let start = pos;
+        while pos < buf.len() && !is_split_whitespace(buf[pos]) {
+            pos += 1;
+        }
+        fields.push(&buf[start..pos]);
+        while pos < buf.len() && is_split_whitespace(buf[pos]) {
+            pos += 1;
+        }
+    }
+}
+
+fn split_chars<'a>(s: &'a String, limit: Option<usize>, fields: &mut Vec<&'a [u8]>) {
+    let buf = s.as_slice();
+    let mut consumed = 0;
+    for ch in s.chars() {
+        // Once the limit is reached, the remainder of the string is left
+        // unsplit in the last field.
+        //
+        // ```
+        // [3.0.1] > "hello".split("", 3)
+        // => ["h", "e", "llo"]
+        // ```
+        if let Some(limit) = limit {
+            if fields.len() + 1 == limit {
+                fields.push(&buf[consumed..]);
+                return;
+            }
+        }
+        fields.push(ch);
+        consumed += ch.len();
+    }
+}
+
+fn split_on_substring<'a>(buf: &'a [u8], separator: &[u8], limit: Option<usize>, fields: &mut Vec<&'a [u8]>) {
+    let mut pos = 0;
+    loop {
+        // Once the limit is reached, the remainder of the string is left
+        // unsplit in the last field.
+        //
+        // ```
+        // [3.0.1] > "a,b,c".split(",", 2)
+        // => ["a", "b,c"]
+        // ```
+        if let Some(limit) = limit {
+            if fields.len() + 1 == limit {
+                fields.push(&buf[pos..]);
+                break;
+            }
+        }
+        if let Some(index) = buf[pos..].find(separator) {
+            fields.push(&buf[pos..pos + index]);
+            pos += index + separator.len();
+        } else {
+            fields.push(&buf[pos..]);
+            break;
+        }
+    }
+    // Without a limit, trailing empty fields are suppressed.
+    //
+    // ```
+    // [3.0.1] > "a,b,,".split(",")
+    // => ["a", "b"]
+    // [3.0.1] > "a,b,,".split(",", -1)
+    // => ["a", "b", "", ""]
+    // ```
+    if limit.is_none() {
+        while let Some(&field) = fields.last() {
+            if !field.is_empty() {
+                break;
+            }
+            fields.pop();
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Character set expansion for `String#tr`-style methods.
+//!
+//! Ruby methods like [`String#tr`], [`String#delete`], and [`String#squeeze`]
+//! describe sets of characters with a shared mini-language: `a-c` expands to
+//! the characters `a`, `b`, and `c`; a leading `^` negates the set; `\`
+//! escapes the following character; and a `-` at the start or end of the set
+//! (or a trailing `\`) is a literal.
+//!
+//! [`String#tr`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-tr
+//! [`String#delete`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-delete
+//! [`String#squeeze`]: https://ruby-doc.org/core-2.6.3/String.html#method-i-squeeze
+
+use alloc::vec::Vec;
+use core::str;
+
+use crate::Encoding;
+
+/// Length in bytes of the first character of a byte string, interpreted
+/// according to the given encoding.
+///
+/// For [conventionally UTF-8] strings, valid UTF-8 byte sequences are one
+/// character and each byte in an invalid UTF-8 byte sequence is one character.
+/// For [ASCII] and [binary] strings, every byte is one character.
+///
+/// Returns zero if and only if the byte string is empty.
+///
+/// [conventionally UTF-8]: Encoding::Utf8
+/// [ASCII]: Encoding::Ascii
+/// [binary]: Encoding::Binary
+#[must_use]
+pub(crate) fn first_char_len(bytes: &[u8], encoding: Encoding) -> usize {
+    match encoding {
+        Encoding::Ascii | Encoding::Binary => {
+            if bytes.is_empty() {
+                0
+            } else {
+                1
+            }
+        }
+        Encoding::Utf8 => match bstr::decode_utf8(bytes) {
+            (Some(_), size) => size,
+            (None, 0) => 0,
+            // Invalid UTF-8 sequences are treated one byte at a time.
+            (None, _) => 1,
+        },
+    }
+}
+
+/// An expanded `String#tr`-style character set.
+///
+/// Members are stored in the order they appear in the set specification so
+/// `String#tr` can map the `i`th member of its `from` set to the `i`th member
+/// of its `to` set.
+#[derive(Default, Debug, Clone)]
+pub(crate) struct CharacterSet {
+    negated: bool,
+    members: Vec<Vec<u8>>,
+}
+
+impl CharacterSet {
+    /// Expand a set specification like `a-c💎` into its member characters.
+    #[must_use]
+    pub(crate) fn expand(spec: &[u8], encoding: Encoding) -> Self {
+        let mut spec = spec;
+        // A leading `^` negates the set, but only if it is followed by other
+        // characters: `"^"` is a set containing a literal caret.
+        let negated = if let [b'^', tail @ ..] = spec {
+            if tail.is_empty() {
+                false
+            } else {
+                spec = tail;
+                true
+            }
+        } else {
+            false
+        };
+
+        // Lex the spec into characters, processing `\` escapes. Escaped
+        // characters never participate in range expansion.
+        let mut chars = Vec::new();
+        while !spec.is_empty() {
+            let len = first_char_len(spec, encoding);
+            let (ch, remainder) = spec.split_at(len);
+            if ch == b"\\" {
+                if remainder.is_empty() {
+                    // A trailing backslash is a literal backslash.
+                    chars.push((&b"\\"[..], true));
+                    break;
+                }
+                let len = first_char_len(remainder, encoding);
+                let (escaped, remainder) = remainder.split_at(len);
+                chars.push((escaped, true));
+                spec = remainder;
+            } else {
+                chars.push((ch, false));
+                spec = remainder;
+            }
+        }
+
+        // Expand `a-c` ranges. A `-` at the start or end of the set is a
+        // literal.
+        let mut members = Vec::<Vec<u8>>::new();
+        let mut idx = 0;
+        while idx < chars.len() {
+            let (ch, escaped) = chars[idx];
+            let next_is_range_hyphen = matches!(chars.get(idx + 1), Some(&(hyphen, false)) if hyphen == b"-");
+            if !escaped && next_is_range_hyphen {
+                if let Some(&(end, _)) = chars.get(idx + 2) {
+                    if let (Some(start), Some(end)) = (single_char(ch), single_char(end)) {
+                        // Empty ranges like `c-a` contribute no members.
+                        match encoding {
+                            // Every member of an ASCII or binary set is a
+                            // single byte, so range endpoints are always in
+                            // `0..=255`.
+                            Encoding::Ascii | Encoding::Binary => {
+                                #[allow(clippy::cast_possible_truncation)]
+                                for byte in start..=end {
+                                    members.push(alloc::vec![byte as u8]);
+                                }
+                            }
+                            Encoding::Utf8 => {
+                                for codepoint in (start..=end).filter_map(char::from_u32) {
+                                    let mut buf = [0; 4];
+                                    members.push(codepoint.encode_utf8(&mut buf).as_bytes().to_vec());
+                                }
+                            }
+                        }
+                        idx += 3;
+                        continue;
+                    }
+                }
+            }
+            members.push(ch.to_vec());
+            idx += 1;
+        }
+
+        Self { negated, members }
+    }
+
+    /// Whether this set was negated with a leading `^`.
+    #[must_use]
+    pub(crate) const fn is_negated(&self) -> bool {
+        self.negated
+    }
+
+    /// The member characters of this set, in specification order.
+    ///
+    /// Negation is not taken into account; callers which care about negated
+    /// sets should combine this with [`is_negated`].
+    ///
+    /// [`is_negated`]: Self::is_negated
+    #[must_use]
+    pub(crate) fn members(&self) -> &[Vec<u8>] {
+        &self.members
+    }
+
+    /// Whether the given character matches this set, honoring negation.
+    #[must_use]
+    pub(crate) fn matches(&self, ch: &[u8]) -> bool {
+        let is_member = self.members.iter().any(|member| &member[..] == ch);
+        is_member != self.negated
+    }
+
+    /// The position of the given character in this set, ignoring negation.
+    #[must_use]
+    pub(crate) fn position(&self, ch: &[u8]) -> Option<usize> {
+        self.members.iter().position(|member| &member[..] == ch)
+    }
+}
+
+/// The codepoint of a character for use as a range endpoint.
+///
+/// Single bytes — which includes every member of an ASCII or binary set and
+/// the bytes of invalid UTF-8 sequences — and valid UTF-8 sequences both have
+/// codepoints. Ranges between byte and non-byte endpoints expand to nothing.
+#[must_use]
+fn single_char(ch: &[u8]) -> Option<u32> {
+    match ch {
+        &[byte] => Some(u32::from(byte)),
+        ch => {
+            let ch = str::from_utf8(ch).ok()?;
+            let mut chars = ch.chars();
+            let ch = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            Some(u32::from(ch))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::CharacterSet;
+    use crate::Encoding;
+
+    fn members(set: &CharacterSet) -> Vec<&[u8]> {
+        set.members().iter().map(|member| &member[..]).collect()
+    }
+
+    #[test]
+    fn expand_literal_characters() {
+        let set = CharacterSet::expand(b"abc", Encoding::Utf8);
+        assert!(!set.is_negated());
+        assert_eq!(members(&set), [&b"a"[..], b"b", b"c"]);
+    }
+
+    #[test]
+    fn expand_ranges() {
+        let set = CharacterSet::expand(b"a-d", Encoding::Utf8);
+        assert_eq!(members(&set), [&b"a"[..], b"b", b"c", b"d"]);
+
+        let set = CharacterSet::expand(b"a-c0-2", Encoding::Utf8);
+        assert_eq!(members(&set), [&b"a"[..], b"b", b"c", b"0", b"1", b"2"]);
+
+        // Reversed ranges contribute no members.
+        let set = CharacterSet::expand(b"c-a", Encoding::Utf8);
+        assert!(members(&set).is_empty());
+    }
+
+    #[test]
+    fn expand_negation() {
+        let set = CharacterSet::expand(b"^a-c", Encoding::Utf8);
+        assert!(set.is_negated());
+        assert!(set.matches(b"z"));
+        assert!(!set.matches(b"a"));
+
+        // A lone caret is a literal.
+        let set = CharacterSet::expand(b"^", Encoding::Utf8);
+        assert!(!set.is_negated());
+        assert!(set.matches(b"^"));
+    }
+
+    #[test]
+    fn expand_literal_hyphens() {
+        let set = CharacterSet::expand(b"-a", Encoding::Utf8);
+        assert_eq!(members(&set), [&b"-"[..], b"a"]);
+
+        let set = CharacterSet::expand(b"a-", Encoding::Utf8);
+        assert_eq!(members(&set), [&b"a"[..], b"-"]);
+    }
+
+    #[test]
+    fn expand_escapes() {
+        let set = CharacterSet::expand(br"a\-c", Encoding::Utf8);
+        assert_eq!(members(&set), [&b"a"[..], b"-", b"c"]);
+
+        let set = CharacterSet::expand(br"\^a", Encoding::Utf8);
+        assert!(!set.is_negated());
+        assert_eq!(members(&set), [&b"^"[..], b"a"]);
+
+        // A trailing backslash is a literal backslash.
+        let set = CharacterSet::expand(b"a\\", Encoding::Utf8);
+        assert_eq!(members(&set), [&b"a"[..], b"\\"]);
+    }
+
+    #[test]
+    fn expand_multibyte_members_and_ranges() {
+        let set = CharacterSet::expand("💎a".as_bytes(), Encoding::Utf8);
+        assert_eq!(members(&set), ["💎".as_bytes(), b"a"]);
+
+        let set = CharacterSet::expand("α-γ".as_bytes(), Encoding::Utf8);
+        assert_eq!(members(&set), ["α".as_bytes(), "β".as_bytes(), "γ".as_bytes()]);
+    }
+
+    #[test]
+    fn expand_binary_spec_is_per_byte() {
+        let set = CharacterSet::expand("💎".as_bytes(), Encoding::Binary);
+        assert_eq!(set.members().len(), 4);
+    }
+}
@@ -0,0 +1,320 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use scolapasta_string_escape::format_debug_escape_into;
+
+use crate::encoding::Encoding;
+
+/// Error returned when a byte sequence cannot be transcoded to the requested
+/// [`Encoding`].
+///
+/// This error is returned from [`transcode`]. See its documentation for more
+/// detail.
+///
+/// This error carries the byte offset and the offending byte sequence, which
+/// can be used to build `Encoding::UndefinedConversionError` messages.
+///
+/// When the **std** feature of `spinoso-string` is enabled, this struct
+/// implements [`std::error::Error`].
+///
+/// [`transcode`]: crate::String::transcode
+/// [`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TranscodeError {
+    bytes: Vec<u8>,
+    offset: usize,
+    from: Encoding,
+    to: Encoding,
+}
+
+impl TranscodeError {
+    /// The exception type raised when a transcode fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_string::TranscodeError;
+    /// assert_eq!(TranscodeError::EXCEPTION_TYPE, "Encoding::UndefinedConversionError");
+    /// ```
+    pub const EXCEPTION_TYPE: &'static str = "Encoding::UndefinedConversionError";
+
+    fn new(bytes: &[u8], offset: usize, from: Encoding, to: Encoding) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+            offset,
+            from,
+            to,
+        }
+    }
+
+    /// Return the byte sequence that could not be transcoded.
+    #[inline]
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Return the byte offset into the source at which the offending byte
+    /// sequence begins.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Return the source [`Encoding`] of the failed transcode.
+    #[inline]
+    #[must_use]
+    pub const fn from(&self) -> Encoding {
+        self.from
+    }
+
+    /// Return the destination [`Encoding`] of the failed transcode.
+    #[inline]
+    #[must_use]
+    pub const fn to(&self) -> Encoding {
+        self.to
+    }
+
+    /// Retrieve the exception message associated with this transcode error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_string::{Encoding, String};
+    /// let s = String::utf8("résumé".as_bytes().to_vec());
+    /// let err = s.transcode(Encoding::Ascii, None).unwrap_err();
+    /// assert_eq!(err.message(), r#""é" from UTF-8 to US-ASCII"#);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn message(&self) -> String {
+        let mut s = String::new();
+        // Suppress error because `fmt::Write` on `String` is infallible.
+        let _ignored = fmt::Write::write_fmt(&mut s, format_args!("{}", self));
+        s
+    }
+}
+
+impl fmt::Display for TranscodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        format_debug_escape_into(&mut *f, &self.bytes)?;
+        f.write_str("\" from ")?;
+        f.write_str(self.from.name())?;
+        f.write_str(" to ")?;
+        f.write_str(self.to.name())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TranscodeError {}
+
+/// Transcode a byte string from one [`Encoding`] to another.
+///
+/// No-op conversions — transcoding to the same encoding, retagging ASCII
+/// bytes, or retagging to [`Encoding::Binary`] — return the source bytes as
+/// [`Cow::Borrowed`] without allocating.
+///
+/// Byte sequences which have no representation in the destination encoding
+/// are replaced with `invalid_replace` if it is given. Otherwise, an error
+/// carrying the byte offset and the offending byte sequence is returned.
+pub fn transcode<'a>(
+    bytes: &'a [u8],
+    from: Encoding,
+    to: Encoding,
+    invalid_replace: Option<&[u8]>,
+) -> Result<Cow<'a, [u8]>, TranscodeError> {
+    match (from, to) {
+        // Transcoding to the source encoding is a no-op.
+        (Encoding::Utf8, Encoding::Utf8) | (Encoding::Ascii, Encoding::Ascii) | (Encoding::Binary, Encoding::Binary) => {
+            Ok(Cow::Borrowed(bytes))
+        }
+        // ASCII bytes are valid in every supported encoding and every byte is
+        // valid in the binary encoding, so these conversions are retags.
+        (Encoding::Ascii, _) | (_, Encoding::Binary) => Ok(Cow::Borrowed(bytes)),
+        (Encoding::Binary, Encoding::Utf8) => binary_to_utf8(bytes, invalid_replace),
+        (Encoding::Utf8, Encoding::Ascii) => utf8_to_ascii(bytes, invalid_replace),
+        (Encoding::Binary, Encoding::Ascii) => binary_to_ascii(bytes, invalid_replace),
+    }
+}
+
+fn binary_to_utf8<'a>(bytes: &'a [u8], invalid_replace: Option<&[u8]>) -> Result<Cow<'a, [u8]>, TranscodeError> {
+    if simdutf8::basic::from_utf8(bytes).is_ok() {
+        return Ok(Cow::Borrowed(bytes));
+    }
+    let mut transcoded = Vec::with_capacity(bytes.len());
+    let mut remainder = bytes;
+    while !remainder.is_empty() {
+        match bstr::decode_utf8(remainder) {
+            (Some(_), size) => {
+                let (ch, rest) = remainder.split_at(size);
+                transcoded.extend_from_slice(ch);
+                remainder = rest;
+            }
+            // Each maximal invalid byte sequence is replaced by the
+            // replacement once.
+            (None, size) => {
+                let (invalid, rest) = remainder.split_at(size);
+                if let Some(replacement) = invalid_replace {
+                    transcoded.extend_from_slice(replacement);
+                } else {
+                    let offset = bytes.len() - remainder.len();
+                    return Err(TranscodeError::new(invalid, offset, Encoding::Binary, Encoding::Utf8));
+                }
+                remainder = rest;
+            }
+        }
+    }
+    Ok(Cow::Owned(transcoded))
+}
+
+fn utf8_to_ascii<'a>(bytes: &'a [u8], invalid_replace: Option<&[u8]>) -> Result<Cow<'a, [u8]>, TranscodeError> {
+    if bytes.is_ascii() {
+        return Ok(Cow::Borrowed(bytes));
+    }
+    let mut transcoded = Vec::with_capacity(bytes.len());
+    let mut remainder = bytes;
+    while !remainder.is_empty() {
+        match bstr::decode_utf8(remainder) {
+            (Some(ch), size) if ch.is_ascii() => {
+                let (ch, rest) = remainder.split_at(size);
+                transcoded.extend_from_slice(ch);
+                remainder = rest;
+            }
+            // Non-ASCII characters and invalid UTF-8 byte sequences have no
+            // representation in US-ASCII.
+            (Some(_) | None, size) => {
+                let (invalid, rest) = remainder.split_at(size);
+                if let Some(replacement) = invalid_replace {
+                    transcoded.extend_from_slice(replacement);
+                } else {
+                    let offset = bytes.len() - remainder.len();
+                    return Err(TranscodeError::new(invalid, offset, Encoding::Utf8, Encoding::Ascii));
+                }
+                remainder = rest;
+            }
+        }
+    }
+    Ok(Cow::Owned(transcoded))
+}
+
+fn binary_to_ascii<'a>(bytes: &'a [u8], invalid_replace: Option<&[u8]>) -> Result<Cow<'a, [u8]>, TranscodeError> {
+    if bytes.is_ascii() {
+        return Ok(Cow::Borrowed(bytes));
+    }
+    let mut transcoded = Vec::with_capacity(bytes.len());
+    for (offset, &byte) in bytes.iter().enumerate() {
+        if byte.is_ascii() {
+            transcoded.push(byte);
+        } else if let Some(replacement) = invalid_replace {
+            transcoded.extend_from_slice(replacement);
+        } else {
+            return Err(TranscodeError::new(
+                &bytes[offset..=offset],
+                offset,
+                Encoding::Binary,
+                Encoding::Ascii,
+            ));
+        }
+    }
+    Ok(Cow::Owned(transcoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::Cow;
+    use alloc::string::ToString;
+
+    use super::{transcode, TranscodeError};
+    use crate::encoding::Encoding;
+
+    #[test]
+    fn transcode_to_same_encoding_borrows() {
+        let bytes = b"abc\xFF";
+        for encoding in [Encoding::Utf8, Encoding::Ascii, Encoding::Binary] {
+            let transcoded = transcode(bytes, encoding, encoding, None).unwrap();
+            assert!(matches!(transcoded, Cow::Borrowed(_)));
+            assert_eq!(&*transcoded, bytes);
+        }
+    }
+
+    #[test]
+    fn transcode_from_ascii_borrows() {
+        let bytes = b"abc";
+        for to in [Encoding::Utf8, Encoding::Binary] {
+            let transcoded = transcode(bytes, Encoding::Ascii, to, None).unwrap();
+            assert!(matches!(transcoded, Cow::Borrowed(_)));
+        }
+    }
+
+    #[test]
+    fn transcode_to_binary_borrows() {
+        let bytes = "résumé".as_bytes();
+        for from in [Encoding::Utf8, Encoding::Ascii] {
+            let transcoded = transcode(bytes, from, Encoding::Binary, None).unwrap();
+            assert!(matches!(transcoded, Cow::Borrowed(_)));
+        }
+    }
+
+    #[test]
+    fn valid_utf8_binary_bytes_borrow() {
+        let bytes = "résumé".as_bytes();
+        let transcoded = transcode(bytes, Encoding::Binary, Encoding::Utf8, None).unwrap();
+        assert!(matches!(transcoded, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn invalid_utf8_binary_bytes_are_replaced() {
+        let transcoded = transcode(b"ab\xF0\x9F\x92cd", Encoding::Binary, Encoding::Utf8, Some(b"?")).unwrap();
+        assert_eq!(&*transcoded, b"ab?cd");
+    }
+
+    #[test]
+    fn invalid_utf8_binary_bytes_error_with_offset() {
+        let err = transcode(b"ab\xF0\x9F\x92cd", Encoding::Binary, Encoding::Utf8, None).unwrap_err();
+        assert_eq!(err.bytes(), b"\xF0\x9F\x92");
+        assert_eq!(err.offset(), 2);
+        assert_eq!(err.from(), Encoding::Binary);
+        assert_eq!(err.to(), Encoding::Utf8);
+    }
+
+    #[test]
+    fn non_ascii_utf8_characters_are_replaced() {
+        let transcoded = transcode("déjà vu".as_bytes(), Encoding::Utf8, Encoding::Ascii, Some(b"?")).unwrap();
+        assert_eq!(&*transcoded, b"d?j? vu");
+    }
+
+    #[test]
+    fn non_ascii_utf8_characters_error_with_offset() {
+        let err = transcode("déjà vu".as_bytes(), Encoding::Utf8, Encoding::Ascii, None).unwrap_err();
+        assert_eq!(err.bytes(), "é".as_bytes());
+        assert_eq!(err.offset(), 1);
+        assert_eq!(err.message(), r#""é" from UTF-8 to US-ASCII"#);
+    }
+
+    #[test]
+    fn invalid_utf8_sequences_cannot_transcode_to_ascii() {
+        let err = transcode(b"abc\xFF", Encoding::Utf8, Encoding::Ascii, None).unwrap_err();
+        assert_eq!(err.bytes(), b"\xFF");
+        assert_eq!(err.offset(), 3);
+    }
+
+    #[test]
+    fn non_ascii_binary_bytes_transcode_bytewise_to_ascii() {
+        let transcoded = transcode(b"ab\xC3\xA9cd", Encoding::Binary, Encoding::Ascii, Some(b"?")).unwrap();
+        assert_eq!(&*transcoded, b"ab??cd");
+
+        let err = transcode(b"ab\xC3\xA9cd", Encoding::Binary, Encoding::Ascii, None).unwrap_err();
+        assert_eq!(err.bytes(), b"\xC3");
+        assert_eq!(err.offset(), 2);
+    }
+
+    #[test]
+    fn error_display_escapes_the_offending_bytes() {
+        let err = TranscodeError::new(b"\xF0\x9F\x92", 2, Encoding::Binary, Encoding::Utf8);
+        assert_eq!(err.to_string(), r#""\xF0\x9F\x92" from ASCII-8BIT to UTF-8"#);
+    }
+}
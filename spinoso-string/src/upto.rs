@@ -0,0 +1,204 @@
+use core::iter::FusedIterator;
+use core::mem;
+
+use crate::{Encoding, String};
+
+/// An iterator over the `String`s between two endpoints in lexicographic
+/// ordering by repeated [successor].
+///
+/// This struct is created by the [`upto`] method on a Spinoso [`String`]. See
+/// its documentation for more.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_string::String;
+///
+/// let s = String::from("a8");
+/// let upto = s.upto(b"b1", false).collect::<Vec<_>>();
+/// assert_eq!(upto, ["a8", "a9", "b0", "b1"]);
+/// ```
+///
+/// [successor]: String::succ
+/// [`upto`]: String::upto
+#[derive(Debug, Clone)]
+pub struct Upto<'a> {
+    state: State<'a>,
+}
+
+impl<'a> Upto<'a> {
+    pub(crate) fn new(begin: &String, last: &'a [u8], exclusive: bool) -> Self {
+        let encoding = begin.encoding();
+        let begin = begin.as_slice();
+        // Pairs of all ASCII digit strings count up numerically, which permits
+        // the yielded values to grow in length.
+        //
+        // ```
+        // [3.0.1] > "9".upto("11").to_a
+        // => ["9", "10", "11"]
+        // ```
+        if is_ascii_digits(begin) && is_ascii_digits(last) {
+            // Endpoints too long to fit in a `u64` fall through to the
+            // successor state, which matches the numeric behavior whenever the
+            // endpoints have the same length.
+            let next = core::str::from_utf8(begin).ok().and_then(|s| s.parse::<u64>().ok());
+            let stop = core::str::from_utf8(last).ok().and_then(|s| s.parse::<u64>().ok());
+            if let (Some(next), Some(last)) = (next, stop) {
+                let state = State::Numeric {
+                    next,
+                    last,
+                    width: begin.len(),
+                    exclusive,
+                    encoding,
+                };
+                return Self { state };
+            }
+        }
+        // Pairs of single ASCII characters count up bytewise through
+        // nonalphanumerics instead of by successor.
+        //
+        // ```
+        // [3.0.1] > "Y".upto("b").to_a
+        // => ["Y", "Z", "[", "\\", "]", "^", "_", "`", "a", "b"]
+        // ```
+        if let (&[first], &[stop]) = (begin, last) {
+            if first.is_ascii() && stop.is_ascii() {
+                let state = State::SingleByte {
+                    next: first,
+                    last: stop,
+                    exclusive,
+                    encoding,
+                };
+                return Self { state };
+            }
+        }
+        // Endpoints are compared bytewise, which matches MRI's `rb_str_cmp`
+        // for strings with the same encoding.
+        if begin > last || (exclusive && begin == last) {
+            return Self { state: State::Done };
+        }
+        let next = String::with_bytes_and_encoding(begin.to_vec(), encoding);
+        // The successor of `last` bounds the iteration so repeated `succ`
+        // cannot step over `last` and loop forever.
+        let after_last = String::with_bytes_and_encoding(last.to_vec(), encoding).succ();
+        let state = State::Succ {
+            next,
+            last,
+            after_last,
+            exclusive,
+        };
+        Self { state }
+    }
+}
+
+impl<'a> Iterator for Upto<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match mem::replace(&mut self.state, State::Done) {
+            State::Done => None,
+            State::Numeric {
+                next,
+                last,
+                width,
+                exclusive,
+                encoding,
+            } => {
+                if next > last || (exclusive && next == last) {
+                    return None;
+                }
+                // Yielded values shorter than the first endpoint are padded
+                // with leading zeros, like `"08".upto("10")`.
+                let item = alloc::format!("{:0width$}", next, width = width);
+                let item = String::with_bytes_and_encoding(item.into_bytes(), encoding);
+                if let Some(incremented) = next.checked_add(1) {
+                    self.state = State::Numeric {
+                        next: incremented,
+                        last,
+                        width,
+                        exclusive,
+                        encoding,
+                    };
+                }
+                Some(item)
+            }
+            State::SingleByte {
+                next,
+                last,
+                exclusive,
+                encoding,
+            } => {
+                if next > last || (exclusive && next == last) {
+                    return None;
+                }
+                let item = String::with_bytes_and_encoding(alloc::vec![next], encoding);
+                if next < last {
+                    self.state = State::SingleByte {
+                        next: next + 1,
+                        last,
+                        exclusive,
+                        encoding,
+                    };
+                }
+                Some(item)
+            }
+            State::Succ {
+                next,
+                last,
+                after_last,
+                exclusive,
+            } => {
+                if next.as_slice() == after_last.as_slice() {
+                    return None;
+                }
+                if !exclusive && next.as_slice() == last {
+                    return Some(next);
+                }
+                let succ = next.succ();
+                // Iteration ends when the successor grows longer than `last`,
+                // which keeps incomparable endpoints like `"a".upto("%")` from
+                // looping forever.
+                let stop = (exclusive && succ.as_slice() == last) || succ.len() > last.len() || succ.is_empty();
+                if !stop {
+                    self.state = State::Succ {
+                        next: succ,
+                        last,
+                        after_last,
+                        exclusive,
+                    };
+                }
+                Some(next)
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for Upto<'a> {}
+
+#[derive(Debug, Clone)]
+enum State<'a> {
+    Done,
+    Numeric {
+        next: u64,
+        last: u64,
+        width: usize,
+        exclusive: bool,
+        encoding: Encoding,
+    },
+    SingleByte {
+        next: u8,
+        last: u8,
+        exclusive: bool,
+        encoding: Encoding,
+    },
+    Succ {
+        next: String,
+        last: &'a [u8],
+        after_last: String,
+        exclusive: bool,
+    },
+}
+
+fn is_ascii_digits(bytes: &[u8]) -> bool {
+    !bytes.is_empty() && bytes.iter().all(u8::is_ascii_digit)
+}
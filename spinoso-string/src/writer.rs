@@ -0,0 +1,217 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::String;
+
+/// An incremental writer over a [`String`] which tracks how many bytes have
+/// been written and can enforce a byte limit.
+///
+/// `Writer` implements [`fmt::Write`] and, when the **std** feature of
+/// `spinoso-string` is enabled, [`io::Write`]. Writes through either trait
+/// count toward [`bytes_written`] and the optional limit.
+///
+/// Writes are all or nothing: a write which would push the total past the
+/// configured limit appends no bytes and fails with
+/// [`io::ErrorKind::WriteZero`] (or [`fmt::Error`] for [`fmt::Write`] writes).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write as _;
+///
+/// use spinoso_string::{String, Writer};
+///
+/// let mut s = String::utf8(Vec::new());
+/// let mut writer = Writer::with_limit(&mut s, 5);
+/// writer.write_all(b"abc").unwrap();
+/// assert_eq!(writer.bytes_written(), 3);
+///
+/// let err = writer.write_all(b"xyz").unwrap_err();
+/// assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+/// assert_eq!(s, "abc");
+/// ```
+///
+/// [`bytes_written`]: Self::bytes_written
+#[derive(Debug)]
+pub struct Writer<'a> {
+    s: &'a mut String,
+    written: usize,
+    limit: Option<usize>,
+}
+
+impl<'a> Writer<'a> {
+    /// Construct a new `Writer` over the given [`String`] with no byte limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Write as _;
+    ///
+    /// use spinoso_string::{String, Writer};
+    ///
+    /// let mut s = String::utf8(Vec::new());
+    /// let mut writer = Writer::new(&mut s);
+    /// write!(writer, "artichoke-{}", 29).unwrap();
+    /// assert_eq!(writer.bytes_written(), 12);
+    /// assert_eq!(s, "artichoke-29");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(s: &'a mut String) -> Self {
+        Self {
+            s,
+            written: 0,
+            limit: None,
+        }
+    }
+
+    /// Construct a new `Writer` over the given [`String`] which fails writes
+    /// that would push the total bytes written past `limit`.
+    #[inline]
+    #[must_use]
+    pub fn with_limit(s: &'a mut String, limit: usize) -> Self {
+        Self {
+            s,
+            written: 0,
+            limit: Some(limit),
+        }
+    }
+
+    /// Return the number of bytes written through this `Writer` since it was
+    /// constructed.
+    ///
+    /// Failed writes do not count toward this total.
+    #[inline]
+    #[must_use]
+    pub const fn bytes_written(&self) -> usize {
+        self.written
+    }
+
+    /// Return the configured byte limit, if any.
+    #[inline]
+    #[must_use]
+    pub const fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Whether appending `len` more bytes would exceed the configured limit.
+    fn would_exceed_limit(&self, len: usize) -> bool {
+        if let Some(limit) = self.limit {
+            match self.written.checked_add(len) {
+                Some(total) => total > limit,
+                None => true,
+            }
+        } else {
+            false
+        }
+    }
+}
+
+impl fmt::Write for Writer<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.would_exceed_limit(s.len()) {
+            return Err(fmt::Error);
+        }
+        self.s.push_str(s);
+        self.written += s.len();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Write for Writer<'_> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.would_exceed_limit(buf.len()) {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "write would exceed configured byte limit",
+            ));
+        }
+        self.s.extend_from_slice(buf);
+        self.written += buf.len();
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::fmt;
+
+    use crate::{String, Writer};
+
+    #[test]
+    fn fmt_writes_are_counted() {
+        use fmt::Write as _;
+
+        let mut s = String::utf8(Vec::new());
+        let mut writer = Writer::new(&mut s);
+        write!(writer, "artichoke-{}", 29).unwrap();
+        assert_eq!(writer.bytes_written(), 12);
+        assert_eq!(s, "artichoke-29");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn interleaved_fmt_and_io_writes() {
+        use std::io::Write as _;
+
+        let mut s = String::utf8(Vec::new());
+        let mut writer = Writer::new(&mut s);
+        fmt::Write::write_str(&mut writer, "abc").unwrap();
+        writer.write_all(b"\xFF\xFE").unwrap();
+        fmt::Write::write_fmt(&mut writer, format_args!("{}", 29)).unwrap();
+        assert_eq!(writer.bytes_written(), 7);
+        assert_eq!(s, &b"abc\xFF\xFE29"[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn io_writes_on_string_may_invalidate_utf8_encoding() {
+        use std::io::Write as _;
+
+        let mut s = String::utf8(Vec::new());
+        s.write_all(b"abc\xFF").unwrap();
+        assert_eq!(s, &b"abc\xFF"[..]);
+        assert!(!s.is_valid_encoding());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn limit_fails_io_writes_with_write_zero() {
+        use std::io::Write as _;
+
+        let mut s = String::utf8(Vec::new());
+        let mut writer = Writer::with_limit(&mut s, 5);
+        writer.write_all(b"abc").unwrap();
+
+        let err = writer.write_all(b"xyz").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+        // Failed writes are all or nothing and do not count toward the total.
+        assert_eq!(writer.bytes_written(), 3);
+
+        writer.write_all(b"de").unwrap();
+        assert_eq!(writer.bytes_written(), 5);
+        assert_eq!(s, "abcde");
+    }
+
+    #[test]
+    fn limit_fails_fmt_writes() {
+        use fmt::Write as _;
+
+        let mut s = String::utf8(Vec::new());
+        let mut writer = Writer::with_limit(&mut s, 5);
+        writer.write_str("abc").unwrap();
+        writer.write_str("xyz").unwrap_err();
+        writer.write_str("de").unwrap();
+        assert_eq!(s, "abcde");
+    }
+}
@@ -52,6 +52,7 @@ use core::fmt;
 use core::str::FromStr;
 
 use bstr::ByteSlice;
+use scolapasta_string_escape::is_ident_continue_ascii;
 
 /// Valid types for Ruby identifiers.
 ///
@@ -276,6 +277,38 @@ impl IdentifierType {
     pub const fn new() -> Self {
         Self::Junk
     }
+
+    /// Parse the given byte string as a Ruby identifier, reporting why the
+    /// input is rejected on failure.
+    ///
+    /// This function classifies the same inputs as the [`FromStr`] and
+    /// [`TryFrom`] implementations, but failures are reported as an
+    /// [`IdentifierError`] which describes the reason for rejection and the
+    /// byte offset of the offending content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_symbol::{IdentifierError, IdentifierType};
+    /// assert_eq!(IdentifierType::try_parse_verbose(b"spinoso"), Ok(IdentifierType::Local));
+    /// assert_eq!(IdentifierType::try_parse_verbose(b""), Err(IdentifierError::Empty));
+    /// assert_eq!(
+    ///     IdentifierType::try_parse_verbose(b"foo bar"),
+    ///     Err(IdentifierError::InvalidContinuation { at: 3 })
+    /// );
+    /// assert_eq!(
+    ///     IdentifierType::try_parse_verbose(b"$foo!"),
+    ///     Err(IdentifierError::TrailingJunk { at: 4 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the given byte string is not a valid identifier, an
+    /// [`IdentifierError`] classifying the reason for rejection is returned.
+    pub fn try_parse_verbose(name: &[u8]) -> Result<Self, IdentifierError> {
+        parse_verbose(name)
+    }
 }
 
 impl Default for IdentifierType {
@@ -361,6 +394,87 @@ impl fmt::Display for ParseIdentifierError {
     }
 }
 
+/// Error type returned from [`IdentifierType::try_parse_verbose`] which
+/// classifies why a byte string is not a valid Ruby identifier.
+///
+/// Unlike [`ParseIdentifierError`], each variant describes the reason the
+/// input was rejected and carries the byte offset of the offending content,
+/// which allows callers to build detailed `NameError` and `SyntaxError`
+/// messages.
+///
+/// # Examples
+///
+/// ```
+/// # use spinoso_symbol::{IdentifierError, IdentifierType};
+/// assert_eq!(IdentifierType::try_parse_verbose(b""), Err(IdentifierError::Empty));
+/// assert_eq!(
+///     IdentifierType::try_parse_verbose(b"foo bar"),
+///     Err(IdentifierError::InvalidContinuation { at: 3 })
+/// );
+/// assert_eq!(
+///     IdentifierType::try_parse_verbose(b"@@$foo"),
+///     Err(IdentifierError::InvalidStart { sigil: Some(b'@') })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IdentifierError {
+    /// The input is empty.
+    ///
+    /// Empty byte strings are never valid identifiers.
+    Empty,
+    /// The input does not begin with a valid identifier start character.
+    ///
+    /// If the input begins with a `$`, `@`, or `@@` sigil, the sigil byte is
+    /// included and the invalid start character is the first byte after the
+    /// sigil.
+    InvalidStart {
+        /// The leading sigil of the rejected input, if it had one.
+        sigil: Option<u8>,
+    },
+    /// The input begins with a valid identifier but contains a character
+    /// which cannot appear in an identifier.
+    InvalidContinuation {
+        /// Byte offset of the first invalid character.
+        at: usize,
+    },
+    /// The input is a valid identifier followed by a `!`, `?`, or `=` suffix
+    /// which is not permitted in this position.
+    ///
+    /// Suffixes are rejected after `$`, `@`, and `@@` sigiled names —
+    /// `$foo!` is not a valid global — and when followed by more junk, as in
+    /// `foo_bar?=`.
+    TrailingJunk {
+        /// Byte offset of the first junk byte.
+        at: usize,
+    },
+    /// The input contains a byte sequence which is not valid UTF-8.
+    InvalidUtf8 {
+        /// Byte offset of the start of the invalid UTF-8 byte sequence.
+        at: usize,
+    },
+}
+
+impl fmt::Display for IdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("Identifiers cannot be empty"),
+            Self::InvalidStart { sigil: Some(_) } => {
+                f.write_str("Identifier does not begin with a valid character after its sigil")
+            }
+            Self::InvalidStart { sigil: None } => f.write_str("Identifier does not begin with a valid character"),
+            Self::InvalidContinuation { at } => {
+                write!(f, "Identifier contains an invalid character at byte offset {}", at)
+            }
+            Self::TrailingJunk { at } => {
+                write!(f, "Identifier contains a trailing junk character at byte offset {}", at)
+            }
+            Self::InvalidUtf8 { at } => {
+                write!(f, "Identifier contains an invalid UTF-8 byte sequence at byte offset {}", at)
+            }
+        }
+    }
+}
+
 #[inline]
 fn parse(name: &[u8]) -> Option<IdentifierType> {
     match name {
@@ -416,6 +530,66 @@ fn parse_ident(name: &[u8], id_type: IdentifierType) -> Option<IdentifierType> {
     }
 }
 
+#[inline]
+fn parse_verbose(name: &[u8]) -> Result<IdentifierType, IdentifierError> {
+    match name {
+        [] => Err(IdentifierError::Empty),
+        [b'\0'] => Err(IdentifierError::InvalidStart { sigil: None }),
+        // special global variable
+        [b'$', name @ ..] if is_special_global_name(name) => Ok(IdentifierType::Global),
+        // global variable
+        [b'$', name @ ..] => parse_ident_verbose(name, Some(b'$'), 1, IdentifierType::Global),
+        // class variable
+        [b'@', b'@', name @ ..] => parse_ident_verbose(name, Some(b'@'), 2, IdentifierType::Class),
+        // instance variable
+        [b'@', name @ ..] => parse_ident_verbose(name, Some(b'@'), 1, IdentifierType::Instance),
+        // Symbolic method names
+        name if is_symbolic_method_name(name) => Ok(IdentifierType::Junk),
+        [b'=' | b'!' | b'[', ..] => Err(IdentifierError::InvalidStart { sigil: None }),
+        [first, ..] if *first != b'_' && first.is_ascii() && !first.is_ascii_alphabetic() => {
+            Err(IdentifierError::InvalidStart { sigil: None })
+        }
+        // Constant name
+        name if is_const_name(name) => parse_ident_verbose(name, None, 0, IdentifierType::Constant),
+        // Local variable
+        name => parse_ident_verbose(name, None, 0, IdentifierType::Local),
+    }
+}
+
+/// Classify why the non-sigil portion of an identifier fails to parse.
+///
+/// `offset` is the length of the sigil prefix stripped from the original
+/// input; reported byte offsets are relative to the original input.
+#[inline]
+fn parse_ident_verbose(
+    name: &[u8],
+    sigil: Option<u8>,
+    offset: usize,
+    id_type: IdentifierType,
+) -> Result<IdentifierType, IdentifierError> {
+    if let Some(id_type) = parse_ident(name, id_type) {
+        return Ok(id_type);
+    }
+    match name {
+        // A bare sigil, like `$`, `@`, or `@@`.
+        [] => Err(IdentifierError::InvalidStart { sigil }),
+        [first, ..] if *first != b'_' && first.is_ascii() && !first.is_ascii_alphabetic() => {
+            Err(IdentifierError::InvalidStart { sigil })
+        }
+        name => {
+            let at = is_ident_until(name)
+                .expect("`parse_ident` rejects a valid start only if the ident scan stops early");
+            match bstr::decode_utf8(&name[at..]) {
+                (None, _) => Err(IdentifierError::InvalidUtf8 { at: offset + at }),
+                // A `!`, `?`, or `=` suffix which is rejected in this
+                // position, like `$foo!` or `foo_bar?=`.
+                (Some('!' | '?' | '='), _) => Err(IdentifierError::TrailingJunk { at: offset + at }),
+                (Some(_), _) => Err(IdentifierError::InvalidContinuation { at: offset + at }),
+            }
+        }
+    }
+}
+
 #[inline]
 fn is_special_global_name(name: &[u8]) -> bool {
     match name {
@@ -495,7 +669,14 @@ fn is_const_name(name: &[u8]) -> bool {
 /// ```
 #[inline]
 fn is_ident_char(ch: char) -> bool {
-    ch.is_alphanumeric() || ch == '_' || !ch.is_ascii()
+    // ASCII bytes are classified through a branch-free lookup table since
+    // ident scanning is a per-character hot path. Non-ASCII characters are
+    // always valid ident chars.
+    if ch.is_ascii() {
+        is_ident_continue_ascii(ch as u8)
+    } else {
+        true
+    }
 }
 
 /// Consume the input until a non-ident character is found.
@@ -631,7 +812,8 @@ fn is_special_global_punct(ch: u8) -> bool {
 #[allow(clippy::shadow_unrelated)]
 mod tests {
     use super::{
-        is_ident_until, is_next_ident_exhausting, is_special_global_name, IdentifierType, ParseIdentifierError,
+        is_ident_until, is_next_ident_exhausting, is_special_global_name, IdentifierError, IdentifierType,
+        ParseIdentifierError,
     };
 
     #[test]
@@ -942,6 +1124,109 @@ mod tests {
         assert_eq!(IdentifierType::try_from("$-�a"), Err(ParseIdentifierError::new()));
         assert_eq!(IdentifierType::try_from("$-��"), Err(ParseIdentifierError::new()));
     }
+
+    #[test]
+    fn verbose_parse_agrees_with_terse_parse_on_valid_idents() {
+        assert_eq!(IdentifierType::try_parse_verbose(b"foobar"), Ok(IdentifierType::Local));
+        assert_eq!(IdentifierType::try_parse_verbose(b"Foobar"), Ok(IdentifierType::Constant));
+        assert_eq!(IdentifierType::try_parse_verbose(b"$foo"), Ok(IdentifierType::Global));
+        assert_eq!(IdentifierType::try_parse_verbose(b"$-w"), Ok(IdentifierType::Global));
+        assert_eq!(IdentifierType::try_parse_verbose(b"@foo"), Ok(IdentifierType::Instance));
+        assert_eq!(IdentifierType::try_parse_verbose(b"@@foo"), Ok(IdentifierType::Class));
+        assert_eq!(IdentifierType::try_parse_verbose(b"foo="), Ok(IdentifierType::AttrSet));
+        assert_eq!(IdentifierType::try_parse_verbose(b"empty?"), Ok(IdentifierType::Junk));
+        assert_eq!(IdentifierType::try_parse_verbose(b"<=>"), Ok(IdentifierType::Junk));
+    }
+
+    #[test]
+    fn verbose_parse_empty() {
+        assert_eq!(IdentifierType::try_parse_verbose(b""), Err(IdentifierError::Empty));
+    }
+
+    #[test]
+    fn verbose_parse_invalid_start() {
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"9foo"),
+            Err(IdentifierError::InvalidStart { sigil: None })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"$"),
+            Err(IdentifierError::InvalidStart { sigil: Some(b'$') })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"@"),
+            Err(IdentifierError::InvalidStart { sigil: Some(b'@') })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"@@"),
+            Err(IdentifierError::InvalidStart { sigil: Some(b'@') })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"@@$foo"),
+            Err(IdentifierError::InvalidStart { sigil: Some(b'@') })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"@0"),
+            Err(IdentifierError::InvalidStart { sigil: Some(b'@') })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"$@foo"),
+            Err(IdentifierError::InvalidStart { sigil: Some(b'$') })
+        );
+    }
+
+    #[test]
+    fn verbose_parse_invalid_continuation_offsets() {
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"foo bar"),
+            Err(IdentifierError::InvalidContinuation { at: 3 })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"invalid-\xFF-utf8"),
+            Err(IdentifierError::InvalidContinuation { at: 7 })
+        );
+        // Offsets include the sigil prefix.
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"@@foo bar"),
+            Err(IdentifierError::InvalidContinuation { at: 5 })
+        );
+    }
+
+    #[test]
+    fn verbose_parse_trailing_junk_offsets() {
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"$foo!"),
+            Err(IdentifierError::TrailingJunk { at: 4 })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"@foo?"),
+            Err(IdentifierError::TrailingJunk { at: 4 })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"@@foo="),
+            Err(IdentifierError::TrailingJunk { at: 5 })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"foo_bar?="),
+            Err(IdentifierError::TrailingJunk { at: 7 })
+        );
+    }
+
+    #[test]
+    fn verbose_parse_invalid_utf8_offsets() {
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"\xFF"),
+            Err(IdentifierError::InvalidUtf8 { at: 0 })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"abc\xFF"),
+            Err(IdentifierError::InvalidUtf8 { at: 3 })
+        );
+        assert_eq!(
+            IdentifierType::try_parse_verbose(b"$abc\xFF\xFExyz"),
+            Err(IdentifierError::InvalidUtf8 { at: 4 })
+        );
+    }
 }
 
 #[cfg(test)]
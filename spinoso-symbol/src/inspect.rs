@@ -969,6 +969,8 @@ mod specs {
 mod functionals {
     use std::string::String;
 
+    use bstr::ByteSlice;
+
     use super::Inspect;
     use crate::fixtures::{IDENTS, IDENT_INSPECTS};
 
@@ -984,4 +986,56 @@ mod functionals {
             );
         }
     }
+
+    #[test]
+    fn mri_symbol_idents_round_trip_without_quoting() {
+        for sym in IDENTS.iter().copied() {
+            let inspect = Inspect::from(sym).collect::<String>();
+            assert!(
+                !inspect.starts_with(":\""),
+                "Expected '{}'.inspect to not be quoted; got '{}'",
+                sym,
+                inspect,
+            );
+            assert_eq!(
+                inspect.strip_prefix(':'),
+                Some(sym),
+                "Expected '{}'.inspect to round trip through debug output; got '{}'",
+                sym,
+                inspect,
+            );
+        }
+    }
+
+    #[test]
+    fn non_idents_are_quoted_with_exact_mri_output() {
+        // ```
+        // [3.0.1] > :"$-ww".inspect
+        // => ":\"$-ww\""
+        // [3.0.1] > :"foo bar".inspect
+        // => ":\"foo bar\""
+        // [3.0.1] > :"9".inspect
+        // => ":\"9\""
+        // ```
+        let cases: &[(&[u8], &str)] = &[
+            (b"$-ww", r#":"$-ww""#),
+            (b"foo bar", r#":"foo bar""#),
+            (b"9", r#":"9""#),
+            (b"@@9", r#":"@@9""#),
+            (b"foo\nbar", r#":"foo\nbar""#),
+            (b"\xFF", r#":"\xFF""#),
+            (b"invalid-\xFF-utf8", r#":"invalid-\xFF-utf8""#),
+        ];
+        for (sym, expected) in cases.iter().copied() {
+            let inspect = Inspect::from(sym).collect::<String>();
+            assert_eq!(
+                inspect,
+                expected,
+                "Expected '{}', to be the result of {:?}.inspect; got '{}'",
+                expected,
+                sym.as_bstr(),
+                inspect,
+            );
+        }
+    }
 }
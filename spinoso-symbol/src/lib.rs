@@ -121,7 +121,7 @@ pub use all_symbols::{AllSymbols, InternerAllSymbols};
 #[cfg(feature = "artichoke")]
 pub use casecmp::{ascii_casecmp, unicode_case_eq};
 #[cfg(feature = "ident-parser")]
-pub use ident::{IdentifierType, ParseIdentifierError};
+pub use ident::{IdentifierError, IdentifierType, ParseIdentifierError};
 #[cfg(feature = "inspect")]
 pub use inspect::Inspect;
 
@@ -53,6 +53,7 @@ use core::fmt;
 use core::time::Duration;
 use std::error::Error;
 
+pub mod strftime;
 mod time;
 
 pub use time::chrono::{Offset, Time, ToA};
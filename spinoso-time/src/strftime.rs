@@ -0,0 +1,519 @@
+//! A byte-oriented [`Time#strftime`] formatter.
+//!
+//! This module formats datetime components with an MRI-compatible directive
+//! set without going through the platform `strftime(3)`, which means the
+//! output does not depend on the OS locale. Formats are bytes, not UTF-8
+//! strings, to match Ruby `String` semantics: bytes outside of directives are
+//! copied to the output verbatim.
+//!
+//! # Examples
+//!
+//! ```
+//! use spinoso_time::strftime::{strftime, TimeParts};
+//!
+//! let parts = TimeParts {
+//!     year: 2001,
+//!     month: 2,
+//!     day: 3,
+//!     hour: 4,
+//!     minute: 5,
+//!     second: 6,
+//!     nanoseconds: 0,
+//!     day_of_week: 6,
+//!     day_of_year: 34,
+//!     seconds_since_epoch: 981_173_106,
+//!     utc_offset: 0,
+//!     time_zone: Some("UTC"),
+//! };
+//! assert_eq!(
+//!     strftime(parts, b"%Y-%m-%d %H:%M:%S %Z"),
+//!     b"2001-02-03 04:05:06 UTC"
+//! );
+//! ```
+//!
+//! [`Time#strftime`]: https://ruby-doc.org/core-2.6.3/Time.html#method-i-strftime
+
+/// Datetime components consumed by [`strftime`].
+///
+/// `TimeParts` decouples the formatter from any particular datetime backend.
+/// All fields are local to the time zone described by [`utc_offset`] and
+/// [`time_zone`].
+///
+/// [`utc_offset`]: Self::utc_offset
+/// [`time_zone`]: Self::time_zone
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct TimeParts<'a> {
+    /// The year, including the century.
+    pub year: i32,
+    /// The month of the year, `1..=12`.
+    pub month: u32,
+    /// The day of the month, `1..=31`.
+    pub day: u32,
+    /// The hour of the day, `0..=23`.
+    pub hour: u32,
+    /// The minute of the hour, `0..=59`.
+    pub minute: u32,
+    /// The second of the minute, `0..=60`.
+    pub second: u32,
+    /// The number of nanoseconds since the last second boundary.
+    pub nanoseconds: u32,
+    /// The day of the week, `0..=6`, with Sunday == 0.
+    pub day_of_week: u32,
+    /// The day of the year, `1..=366`.
+    pub day_of_year: u32,
+    /// The number of seconds since the Unix Epoch.
+    pub seconds_since_epoch: i64,
+    /// The offset from UTC in seconds.
+    pub utc_offset: i32,
+    /// The time zone abbreviation, e.g. `UTC`, if one is known.
+    pub time_zone: Option<&'a str>,
+}
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// How a numeric directive is padded to its field width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Padding {
+    /// Pad with ASCII zeros, e.g. `%m` => `02`.
+    Zero,
+    /// Pad with ASCII spaces, e.g. `%e` => ` 3`.
+    Space,
+    /// Suppress padding, e.g. `%-d` => `3`.
+    None,
+}
+
+/// Format `parts` according to `format`.
+///
+/// The MRI directive set `%Y %m %d %H %I %M %S %L %N %z %:z %Z %a %A %b %B
+/// %j %U %W %e %k %l %p %P %s %w %%` is supported, as are the `-` (no
+/// padding), `_` (space padding), and `0` (zero padding) flags and explicit
+/// field widths like `%03H`. Unknown directives are copied to the output
+/// literally, like MRI.
+///
+/// # Examples
+///
+/// ```
+/// use spinoso_time::strftime::{strftime, TimeParts};
+///
+/// let parts = TimeParts {
+///     year: 2001,
+///     month: 2,
+///     day: 3,
+///     hour: 4,
+///     minute: 5,
+///     second: 6,
+///     nanoseconds: 123_456_789,
+///     day_of_week: 6,
+///     day_of_year: 34,
+///     seconds_since_epoch: 981_173_106,
+///     utc_offset: 0,
+///     time_zone: Some("UTC"),
+/// };
+/// assert_eq!(strftime(parts, b"%-d %B %Y"), b"3 February 2001");
+/// assert_eq!(strftime(parts, b"%H:%M:%S.%3N"), b"04:05:06.123");
+/// assert_eq!(strftime(parts, b"%Q"), b"%Q");
+/// ```
+#[must_use]
+#[allow(clippy::too_many_lines)]
+pub fn strftime(parts: TimeParts<'_>, format: &[u8]) -> Vec<u8> {
+    let mut formatted = Vec::with_capacity(format.len());
+    let mut bytes = format.iter().copied().enumerate();
+    while let Some((start, byte)) = bytes.next() {
+        if byte != b'%' {
+            formatted.push(byte);
+            continue;
+        }
+        // Parse `%<flags><width><colons><conversion>`. If the directive turns
+        // out to be malformed or unknown, emit the consumed bytes verbatim.
+        let mut padding = None;
+        let mut width = None::<usize>;
+        let mut colons = 0_usize;
+        let mut end = start;
+        let directive = loop {
+            let Some((pos, byte)) = bytes.next() else {
+                break None;
+            };
+            end = pos;
+            match byte {
+                b'-' if width.is_none() => padding = Some(Padding::None),
+                b'_' if width.is_none() => padding = Some(Padding::Space),
+                b'0' if width.is_none() => padding = Some(Padding::Zero),
+                b'0'..=b'9' => {
+                    let digit = usize::from(byte - b'0');
+                    width = width
+                        .unwrap_or_default()
+                        .checked_mul(10)
+                        .and_then(|width| width.checked_add(digit));
+                    if width.is_none() {
+                        break None;
+                    }
+                }
+                b':' => colons += 1,
+                _ => break Some(byte),
+            }
+        };
+        // `%:z` is the only directive which takes colon flags.
+        let directive = match directive {
+            Some(b'z') if colons <= 1 => directive,
+            _ if colons > 0 => None,
+            directive => directive,
+        };
+        match directive {
+            Some(b'Y') => push_int(&mut formatted, parts.year.into(), 4, padding.unwrap_or(Padding::Zero), width),
+            Some(b'm') => push_num(&mut formatted, parts.month, 2, padding.unwrap_or(Padding::Zero), width),
+            Some(b'd') => push_num(&mut formatted, parts.day, 2, padding.unwrap_or(Padding::Zero), width),
+            Some(b'e') => push_num(&mut formatted, parts.day, 2, padding.unwrap_or(Padding::Space), width),
+            Some(b'H') => push_num(&mut formatted, parts.hour, 2, padding.unwrap_or(Padding::Zero), width),
+            Some(b'k') => push_num(&mut formatted, parts.hour, 2, padding.unwrap_or(Padding::Space), width),
+            Some(b'I') => {
+                push_num(
+                    &mut formatted,
+                    twelve_hour_clock(parts.hour),
+                    2,
+                    padding.unwrap_or(Padding::Zero),
+                    width,
+                );
+            }
+            Some(b'l') => {
+                push_num(
+                    &mut formatted,
+                    twelve_hour_clock(parts.hour),
+                    2,
+                    padding.unwrap_or(Padding::Space),
+                    width,
+                );
+            }
+            Some(b'M') => push_num(&mut formatted, parts.minute, 2, padding.unwrap_or(Padding::Zero), width),
+            Some(b'S') => push_num(&mut formatted, parts.second, 2, padding.unwrap_or(Padding::Zero), width),
+            Some(b'L') => {
+                push_num(
+                    &mut formatted,
+                    parts.nanoseconds / 1_000_000,
+                    3,
+                    padding.unwrap_or(Padding::Zero),
+                    width,
+                );
+            }
+            Some(b'N') => push_fractional_seconds(&mut formatted, parts.nanoseconds, width.unwrap_or(9)),
+            Some(b'j') => {
+                push_num(
+                    &mut formatted,
+                    parts.day_of_year,
+                    3,
+                    padding.unwrap_or(Padding::Zero),
+                    width,
+                );
+            }
+            Some(b'U') => {
+                // Week of the year where the first Sunday begins week one;
+                // days before it are week zero.
+                let week = (parts.day_of_year + 6 - parts.day_of_week) / 7;
+                push_num(&mut formatted, week, 2, padding.unwrap_or(Padding::Zero), width);
+            }
+            Some(b'W') => {
+                // As `%U`, but weeks begin on Monday.
+                let week = (parts.day_of_year + 6 - (parts.day_of_week + 6) % 7) / 7;
+                push_num(&mut formatted, week, 2, padding.unwrap_or(Padding::Zero), width);
+            }
+            Some(b'w') => push_num(&mut formatted, parts.day_of_week, 1, padding.unwrap_or(Padding::Zero), width),
+            Some(b's') => {
+                push_int(
+                    &mut formatted,
+                    parts.seconds_since_epoch,
+                    1,
+                    padding.unwrap_or(Padding::Zero),
+                    width,
+                );
+            }
+            Some(b'p') => {
+                let meridian = if parts.hour < 12 { "AM" } else { "PM" };
+                formatted.extend_from_slice(meridian.as_bytes());
+            }
+            Some(b'P') => {
+                let meridian = if parts.hour < 12 { "am" } else { "pm" };
+                formatted.extend_from_slice(meridian.as_bytes());
+            }
+            Some(b'a') => {
+                let day_name = DAY_NAMES.get(parts.day_of_week as usize).copied().unwrap_or_default();
+                formatted.extend_from_slice(&day_name.as_bytes()[..3]);
+            }
+            Some(b'A') => {
+                let day_name = DAY_NAMES.get(parts.day_of_week as usize).copied().unwrap_or_default();
+                formatted.extend_from_slice(day_name.as_bytes());
+            }
+            Some(b'b') => {
+                let index = (parts.month as usize).saturating_sub(1);
+                let month_name = MONTH_NAMES.get(index).copied().unwrap_or_default();
+                formatted.extend_from_slice(&month_name.as_bytes()[..3]);
+            }
+            Some(b'B') => {
+                let index = (parts.month as usize).saturating_sub(1);
+                let month_name = MONTH_NAMES.get(index).copied().unwrap_or_default();
+                formatted.extend_from_slice(month_name.as_bytes());
+            }
+            Some(b'z') => push_utc_offset(&mut formatted, parts.utc_offset, colons == 1),
+            Some(b'Z') => {
+                if let Some(time_zone) = parts.time_zone {
+                    formatted.extend_from_slice(time_zone.as_bytes());
+                }
+            }
+            Some(b'%') if padding.is_none() && width.is_none() => formatted.push(b'%'),
+            // Unknown or malformed directives pass through literally.
+            Some(_) => formatted.extend_from_slice(&format[start..=end]),
+            None => formatted.extend_from_slice(&format[start..]),
+        }
+    }
+    formatted
+}
+
+/// Map a 24-hour clock hour to the 12-hour clock.
+fn twelve_hour_clock(hour: u32) -> u32 {
+    match hour % 12 {
+        0 => 12,
+        hour => hour,
+    }
+}
+
+fn push_num(formatted: &mut Vec<u8>, num: u32, default_width: usize, padding: Padding, width: Option<usize>) {
+    push_int(formatted, num.into(), default_width, padding, width);
+}
+
+fn push_int(formatted: &mut Vec<u8>, num: i64, default_width: usize, padding: Padding, width: Option<usize>) {
+    let width = width.unwrap_or(default_width);
+    let digits = num.unsigned_abs().to_string();
+    let sign = if num < 0 { "-" } else { "" };
+    match padding {
+        // Zero padding pads the digits to the field width; the sign does not
+        // count toward the width, e.g. `-0001` for year -1.
+        Padding::Zero => {
+            let pad = width.saturating_sub(digits.len());
+            formatted.extend_from_slice(sign.as_bytes());
+            formatted.resize(formatted.len() + pad, b'0');
+        }
+        Padding::Space => {
+            let pad = width.saturating_sub(digits.len() + sign.len());
+            formatted.resize(formatted.len() + pad, b' ');
+            formatted.extend_from_slice(sign.as_bytes());
+        }
+        Padding::None => formatted.extend_from_slice(sign.as_bytes()),
+    }
+    formatted.extend_from_slice(digits.as_bytes());
+}
+
+/// Emit sub-second digits for `%N` with the given precision.
+///
+/// The field width selects the precision: digits beyond it are truncated, not
+/// rounded, and precision beyond nanoseconds is filled with zeros.
+fn push_fractional_seconds(formatted: &mut Vec<u8>, nanoseconds: u32, precision: usize) {
+    let digits = format!("{nanoseconds:09}");
+    let truncated = digits.len().min(precision);
+    formatted.extend_from_slice(&digits.as_bytes()[..truncated]);
+    formatted.resize(formatted.len() + precision.saturating_sub(truncated), b'0');
+}
+
+/// Emit a `+hhmm` (or `+hh:mm` for `%:z`) UTC offset.
+fn push_utc_offset(formatted: &mut Vec<u8>, utc_offset: i32, colon: bool) {
+    let sign = if utc_offset < 0 { b'-' } else { b'+' };
+    let offset = utc_offset.unsigned_abs();
+    let hours = offset / 3600;
+    let minutes = offset % 3600 / 60;
+    formatted.push(sign);
+    formatted.extend_from_slice(format!("{hours:02}").as_bytes());
+    if colon {
+        formatted.push(b':');
+    }
+    formatted.extend_from_slice(format!("{minutes:02}").as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strftime, TimeParts};
+
+    // `Time.utc(2001, 2, 3, 4, 5, 6, 123_456.789r)`, a Saturday.
+    fn parts() -> TimeParts<'static> {
+        TimeParts {
+            year: 2001,
+            month: 2,
+            day: 3,
+            hour: 4,
+            minute: 5,
+            second: 6,
+            nanoseconds: 123_456_789,
+            day_of_week: 6,
+            day_of_year: 34,
+            seconds_since_epoch: 981_173_106,
+            utc_offset: 0,
+            time_zone: Some("UTC"),
+        }
+    }
+
+    fn format(format: &str) -> Vec<u8> {
+        strftime(parts(), format.as_bytes())
+    }
+
+    #[test]
+    fn literal_bytes_pass_through() {
+        assert_eq!(format(""), b"");
+        assert_eq!(format("abc 123"), b"abc 123");
+        assert_eq!(strftime(parts(), b"\xFF%m\xFE"), b"\xFF02\xFE");
+    }
+
+    #[test]
+    fn numeric_directives() {
+        // Fixtures from `ruby/spec` `core/time/strftime_spec.rb`.
+        assert_eq!(format("%Y"), b"2001");
+        assert_eq!(format("%m"), b"02");
+        assert_eq!(format("%d"), b"03");
+        assert_eq!(format("%e"), b" 3");
+        assert_eq!(format("%H"), b"04");
+        assert_eq!(format("%k"), b" 4");
+        assert_eq!(format("%I"), b"04");
+        assert_eq!(format("%l"), b" 4");
+        assert_eq!(format("%M"), b"05");
+        assert_eq!(format("%S"), b"06");
+        assert_eq!(format("%j"), b"034");
+        assert_eq!(format("%s"), b"981173106");
+        assert_eq!(format("%w"), b"6");
+    }
+
+    #[test]
+    fn name_directives() {
+        assert_eq!(format("%a"), b"Sat");
+        assert_eq!(format("%A"), b"Saturday");
+        assert_eq!(format("%b"), b"Feb");
+        assert_eq!(format("%B"), b"February");
+        assert_eq!(format("%p"), b"AM");
+        assert_eq!(format("%P"), b"am");
+
+        let mut parts = parts();
+        parts.hour = 13;
+        assert_eq!(strftime(parts, b"%p %P %I %l"), b"PM pm 01  1");
+    }
+
+    #[test]
+    fn twelve_hour_clock_wraps_midnight_to_twelve() {
+        let mut parts = parts();
+        parts.hour = 0;
+        assert_eq!(strftime(parts, b"%I %p"), b"12 AM");
+    }
+
+    #[test]
+    fn week_of_year() {
+        assert_eq!(format("%U"), b"04");
+        assert_eq!(format("%W"), b"05");
+
+        // `Time.utc(2000, 1, 1)` is a Saturday in week zero.
+        let parts = TimeParts {
+            year: 2000,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanoseconds: 0,
+            day_of_week: 6,
+            day_of_year: 1,
+            seconds_since_epoch: 946_684_800,
+            utc_offset: 0,
+            time_zone: Some("UTC"),
+        };
+        assert_eq!(strftime(parts, b"%U %W"), b"00 00");
+    }
+
+    #[test]
+    fn padding_flags_and_width() {
+        assert_eq!(format("%-d"), b"3");
+        assert_eq!(format("%_m"), b" 2");
+        assert_eq!(format("%0e"), b"03");
+        assert_eq!(format("%03H"), b"004");
+        assert_eq!(format("%-H"), b"4");
+        assert_eq!(format("%10Y"), b"0000002001");
+        assert_eq!(format("%_10Y"), b"      2001");
+    }
+
+    #[test]
+    fn negative_year_keeps_sign_before_zero_padding() {
+        let mut parts = parts();
+        parts.year = -1;
+        assert_eq!(strftime(parts, b"%Y"), b"-0001");
+        assert_eq!(strftime(parts, b"%_8Y"), b"      -1");
+    }
+
+    #[test]
+    fn fractional_seconds_truncate_to_precision() {
+        assert_eq!(format("%L"), b"123");
+        assert_eq!(format("%N"), b"123456789");
+        assert_eq!(format("%3N"), b"123");
+        assert_eq!(format("%6N"), b"123456");
+        assert_eq!(format("%9N"), b"123456789");
+        assert_eq!(format("%12N"), b"123456789000");
+    }
+
+    #[test]
+    fn utc_offset_directives() {
+        assert_eq!(format("%z"), b"+0000");
+        assert_eq!(format("%:z"), b"+00:00");
+
+        let mut parts = parts();
+        parts.utc_offset = -(4 * 3600 + 30 * 60);
+        parts.time_zone = None;
+        assert_eq!(strftime(parts, b"%z"), b"-0430");
+        assert_eq!(strftime(parts, b"%:z"), b"-04:30");
+        assert_eq!(strftime(parts, b"%Z"), b"");
+
+        parts.utc_offset = 9 * 3600;
+        assert_eq!(strftime(parts, b"%z"), b"+0900");
+    }
+
+    #[test]
+    fn zone_name() {
+        assert_eq!(format("%Z"), b"UTC");
+    }
+
+    #[test]
+    fn percent_literal() {
+        assert_eq!(format("%%"), b"%");
+        assert_eq!(format("%%Y"), b"%Y");
+        assert_eq!(format("100%%"), b"100%");
+    }
+
+    #[test]
+    fn unknown_directives_pass_through_literally() {
+        assert_eq!(format("%Q"), b"%Q");
+        assert_eq!(format("%-4Q"), b"%-4Q");
+        assert_eq!(format("%::z"), b"%::z");
+        assert_eq!(format("%:m"), b"%:m");
+        assert_eq!(format("%"), b"%");
+        assert_eq!(format("%_"), b"%_");
+    }
+
+    #[test]
+    fn compound_formats() {
+        assert_eq!(format("%Y-%m-%dT%H:%M:%S"), b"2001-02-03T04:05:06");
+        assert_eq!(format("%a, %d %b %Y %H:%M:%S %z"), b"Sat, 03 Feb 2001 04:05:06 +0000");
+        assert_eq!(format("%A %B %-d"), b"Saturday February 3");
+    }
+}
@@ -12,6 +12,7 @@ mod math;
 mod offset;
 mod ops;
 mod ordinal;
+mod strftime;
 mod time;
 mod timezone;
 mod weekday;
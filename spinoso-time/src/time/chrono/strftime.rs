@@ -0,0 +1,153 @@
+use chrono::offset::Offset as _;
+use chrono::prelude::*;
+
+use crate::strftime::{strftime, TimeParts};
+use crate::time::chrono::{Offset, Time};
+
+impl Time {
+    /// Formats _time_ according to the directives in the given format string.
+    ///
+    /// Format directives are bytes, so the format string and the returned
+    /// formatted time are byte vecs. See [`strftime`](crate::strftime) for the
+    /// supported directive set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_time::Time;
+    /// let now = Time::now();
+    /// let date = now.strftime(b"%Y-%m-%d");
+    /// assert_eq!(date.len(), 10);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn strftime(self, format: &[u8]) -> Vec<u8> {
+        let parts = TimeParts {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+            second: self.second(),
+            nanoseconds: self.nanosecond(),
+            day_of_week: self.weekday(),
+            day_of_year: self.year_day(),
+            seconds_since_epoch: self.to_int(),
+            utc_offset: self.utc_offset_seconds(),
+            time_zone: self.timezone(),
+        };
+        strftime(parts, format)
+    }
+
+    /// Formats _time_ as an [ISO 8601] combined date and time, like
+    /// `2001-02-03T04:05:06+09:00`.
+    ///
+    /// UTC times use the `Z` zone designator instead of a numeric offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_time::Time;
+    /// let now = Time::now();
+    /// let timestamp = now.iso8601();
+    /// assert_eq!(timestamp[10], b'T');
+    /// ```
+    ///
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    #[inline]
+    #[must_use]
+    pub fn iso8601(self) -> Vec<u8> {
+        if self.is_utc() {
+            self.strftime(b"%Y-%m-%dT%H:%M:%SZ")
+        } else {
+            self.strftime(b"%Y-%m-%dT%H:%M:%S%:z")
+        }
+    }
+
+    /// Formats _time_ as an [RFC 2822] date and time, like
+    /// `Sat, 03 Feb 2001 04:05:06 +0000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_time::Time;
+    /// let now = Time::now();
+    /// let timestamp = now.rfc2822();
+    /// assert_eq!(timestamp[3], b',');
+    /// ```
+    ///
+    /// [RFC 2822]: https://datatracker.ietf.org/doc/html/rfc2822#section-3.3
+    #[inline]
+    #[must_use]
+    pub fn rfc2822(self) -> Vec<u8> {
+        self.strftime(b"%a, %d %b %Y %H:%M:%S %z")
+    }
+
+    /// Returns the offset from UTC in seconds for _time_'s timezone.
+    fn utc_offset_seconds(self) -> i32 {
+        let Self {
+            timestamp,
+            sub_second_nanos,
+            offset,
+        } = self;
+        let naive = NaiveDateTime::from_timestamp(timestamp, sub_second_nanos);
+        match offset {
+            Offset::Utc => 0,
+            Offset::Local => Local.offset_from_utc_datetime(&naive).fix().local_minus_utc(),
+            Offset::Tz(timezone) => timezone.offset_from_utc_datetime(&naive).fix().local_minus_utc(),
+            Offset::Fixed(offset) => offset.local_minus_utc(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::prelude::*;
+
+    use crate::time::chrono::Time;
+
+    fn time() -> Time {
+        // `Time.utc(2001, 2, 3, 4, 5, 6)`, a Saturday.
+        let date = NaiveDate::from_ymd(2001, 2, 3);
+        let time = NaiveTime::from_hms_nano(4, 5, 6, 123_456_789);
+        let naive = NaiveDateTime::new(date, time);
+        Time::from(DateTime::<Utc>::from_utc(naive, Utc))
+    }
+
+    #[test]
+    fn strftime_utc() {
+        let time = time();
+        assert_eq!(time.strftime(b"%Y-%m-%d %H:%M:%S"), b"2001-02-03 04:05:06");
+        assert_eq!(time.strftime(b"%A %-d %B, %U"), b"Saturday 3 February, 04");
+        assert_eq!(time.strftime(b"%s.%6N"), b"981173106.123456");
+        assert_eq!(time.strftime(b"%z %Z"), b"+0000 UTC");
+    }
+
+    #[test]
+    fn strftime_fixed_offset() {
+        let date = NaiveDate::from_ymd(2001, 2, 3);
+        let naive = NaiveDateTime::new(date, NaiveTime::from_hms(4, 5, 6));
+        let offset = FixedOffset::west(7 * 3600);
+        let time = Time::from(DateTime::<FixedOffset>::from_utc(naive, offset));
+        assert_eq!(time.strftime(b"%Y-%m-%d %H:%M:%S %z"), b"2001-02-02 21:05:06 -0700");
+    }
+
+    #[test]
+    fn iso8601_utc_uses_zone_designator() {
+        assert_eq!(time().iso8601(), b"2001-02-03T04:05:06Z");
+    }
+
+    #[test]
+    fn iso8601_fixed_offset_uses_numeric_offset() {
+        let date = NaiveDate::from_ymd(2001, 2, 3);
+        let naive = NaiveDateTime::new(date, NaiveTime::from_hms(4, 5, 6));
+        let offset = FixedOffset::east(9 * 3600);
+        let time = Time::from(DateTime::<FixedOffset>::from_utc(naive, offset));
+        assert_eq!(time.iso8601(), b"2001-02-03T13:05:06+09:00");
+    }
+
+    #[test]
+    fn rfc2822_utc() {
+        assert_eq!(time().rfc2822(), b"Sat, 03 Feb 2001 04:05:06 +0000");
+    }
+}
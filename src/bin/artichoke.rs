@@ -31,10 +31,17 @@
 //! FLAGS:
 //!         --copyright    print the copyright
 //!     -h, --help         Prints help information
+//!     -n                 assume 'while gets(); ... end' loop around your script
+//!     -p                 assume loop like -n but print line also like sed
 //!     -V, --version      Prints version information
+//!     -w                 turn warnings on for your script
 //!
 //! OPTIONS:
+//!         --disable[=FEATURES]...     disable features: gems, did_you_mean (accepted no-ops)
 //!     -e <commands>...                one line of script. Several -e's allowed. Omit [programfile]
+//!         --enable[=FEATURES]...      enable features: gems, did_you_mean (accepted no-ops)
+//!     -r, --require <requires>...     require the library before executing the script. Several -r's allowed
+//!     -W <warning>                    set warning level; 0=silence, 1=medium, 2=verbose (default)
 //!         --with-fixture <fixture>    file whose contents will be read into the `$fixture` global
 //!
 //! ARGS:
@@ -82,10 +89,54 @@ fn main() {
 }
 
 fn parse_args() -> Result<Args> {
-    let matches = clap_matches(env::args_os())?;
+    let mut argv = env::args_os().collect::<Vec<_>>();
+    // `RUBYOPT` contains additional flags which MRI prepends to the command
+    // line arguments, after the executable name.
+    if let Some(rubyopt) = env::var_os("RUBYOPT") {
+        let flags = ruby::rubyopt_flags(&rubyopt)?;
+        argv.splice(1..1, flags);
+    }
+    // Normalize the `--disable-FEATURE` and `--enable-FEATURE` spellings to
+    // the `--disable=FEATURE` and `--enable=FEATURE` forms `clap` can parse.
+    for arg in &mut argv {
+        if let Some(arg_str) = arg.to_str() {
+            if let Some(feature) = arg_str.strip_prefix("--disable-") {
+                *arg = OsString::from(format!("--disable={}", feature));
+            } else if let Some(feature) = arg_str.strip_prefix("--enable-") {
+                *arg = OsString::from(format!("--enable={}", feature));
+            }
+        }
+    }
+    let matches = clap_matches(argv)?;
+
+    let verbosity = if matches.is_present("warning") {
+        let level = matches.value_of("warning").map_or(2, |level| level.parse().unwrap_or(2));
+        Some(level)
+    } else if matches.is_present("w") {
+        Some(2)
+    } else {
+        None
+    };
 
     let mut args = Args::empty()
         .with_copyright(matches.is_present("copyright"))
+        .with_verbosity(verbosity)
+        .with_enabled_features(
+            matches
+                .values_of_os("enable")
+                .into_iter()
+                .flat_map(|v| v.map(OsString::from))
+                .collect(),
+        )
+        .with_disabled_features(
+            matches
+                .values_of_os("disable")
+                .into_iter()
+                .flat_map(|v| v.map(OsString::from))
+                .collect(),
+        )
+        .with_lineloop(matches.is_present("lineloop"))
+        .with_print_lineloop(matches.is_present("print-lineloop"))
         .with_commands(
             matches
                 .values_of_os("commands")
@@ -93,6 +144,13 @@ fn parse_args() -> Result<Args> {
                 .flat_map(|v| v.map(OsString::from))
                 .collect(),
         )
+        .with_requires(
+            matches
+                .values_of_os("requires")
+                .into_iter()
+                .flat_map(|v| v.map(OsString::from))
+                .collect(),
+        )
         .with_fixture(matches.value_of_os("fixture").map(PathBuf::from));
 
     if let Some(mut positional) = matches.values_of_os("programfile") {
@@ -123,6 +181,64 @@ fn app() -> App<'static, 'static> {
             .help(r"one line of script. Several -e's allowed. Omit [programfile]")
             .short("e"),
     );
+    let app = app.arg(
+        Arg::with_name("enable")
+            .takes_value(true)
+            .multiple(true)
+            .min_values(0)
+            .require_equals(true)
+            .use_delimiter(true)
+            .help("enable features: gems, did_you_mean (accepted no-ops)")
+            .long("enable"),
+    );
+    let app = app.arg(
+        Arg::with_name("disable")
+            .takes_value(true)
+            .multiple(true)
+            .min_values(0)
+            .require_equals(true)
+            .use_delimiter(true)
+            .help("disable features: gems, did_you_mean (accepted no-ops)")
+            .long("disable"),
+    );
+    let app = app.arg(
+        Arg::with_name("w")
+            .takes_value(false)
+            .multiple(false)
+            .help("turn warnings on for your script")
+            .short("w"),
+    );
+    let app = app.arg(
+        Arg::with_name("warning")
+            .takes_value(true)
+            .multiple(false)
+            .possible_values(&["0", "1", "2"])
+            .help("set warning level; 0=silence, 1=medium, 2=verbose (default)")
+            .short("W"),
+    );
+    let app = app.arg(
+        Arg::with_name("lineloop")
+            .takes_value(false)
+            .multiple(false)
+            .help("assume 'while gets(); ... end' loop around your script")
+            .short("n"),
+    );
+    let app = app.arg(
+        Arg::with_name("print-lineloop")
+            .takes_value(false)
+            .multiple(false)
+            .help("assume loop like -n but print line also like sed")
+            .short("p"),
+    );
+    let app = app.arg(
+        Arg::with_name("requires")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .help("require the library before executing the script. Several -r's allowed")
+            .short("r")
+            .long("require"),
+    );
     let app = app.arg(
         Arg::with_name("fixture")
             .takes_value(true)
@@ -10,6 +10,17 @@
 /// ```
 pub const INLINE_EVAL_SWITCH: &[u8] = b"-e";
 
+/// Filename for a program read from stdin on the `ruby` frontend, either
+/// because no programfile was given or because the programfile is `-`.
+///
+/// # Examples
+///
+/// ```console
+/// $ echo 'puts __FILE__' | ruby -
+/// -
+/// ```
+pub const STDIN: &[u8] = b"-";
+
 /// Filename for code executed on the REPL frontend with the `irb` command.
 ///
 /// # Examples
@@ -33,6 +44,12 @@ mod tests {
         assert!(!contains_nul_byte);
     }
 
+    #[test]
+    fn stdin_filename_does_not_contain_nul_byte() {
+        let contains_nul_byte = super::STDIN.contains(&b'\0');
+        assert!(!contains_nul_byte);
+    }
+
     #[test]
     fn repl_filename_does_not_contain_nul_byte() {
         let contains_nul_byte = super::REPL.contains(&b'\0');
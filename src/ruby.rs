@@ -11,11 +11,12 @@ use std::path::{Path, PathBuf};
 
 use termcolor::WriteColor;
 
-use crate::backend::platform_string::os_str_to_bytes;
+use crate::backend::platform_string::{bytes_to_os_str, os_str_to_bytes};
 use crate::backend::state::parser::Context;
 use crate::backend::string::format_unicode_debug_into;
+use crate::backend::value::Value;
 use crate::backtrace;
-use crate::filename::INLINE_EVAL_SWITCH;
+use crate::filename::{INLINE_EVAL_SWITCH, STDIN};
 use crate::prelude::*;
 
 /// Command line arguments for Artichoke `ruby` frontend.
@@ -25,6 +26,19 @@ pub struct Args {
     copyright: bool,
     /// one line of script. Several -e's allowed. Omit \[programfile\]
     commands: Vec<OsString>,
+    /// require the library before executing the script. Several -r's allowed.
+    requires: Vec<OsString>,
+    /// set `$VERBOSE`: 0 is `nil`, 1 is `false`, 2 or higher is `true`
+    verbosity: Option<u8>,
+    /// features enabled with `--enable`. Unknown features are accepted no-ops.
+    enabled_features: Vec<OsString>,
+    /// features disabled with `--disable`. Unknown features are accepted
+    /// no-ops.
+    disabled_features: Vec<OsString>,
+    /// assume `while gets(); ... end` loop around the script
+    lineloop: bool,
+    /// assume loop like `-n` but print the value of `$_` after each iteration
+    print_lineloop: bool,
     /// file whose contents will be read into the `$fixture` global
     fixture: Option<PathBuf>,
     programfile: Option<PathBuf>,
@@ -41,6 +55,12 @@ impl Args {
         Self {
             copyright: false,
             commands: Vec::new(),
+            requires: Vec::new(),
+            verbosity: None,
+            enabled_features: Vec::new(),
+            disabled_features: Vec::new(),
+            lineloop: false,
+            print_lineloop: false,
             fixture: None,
             programfile: None,
             argv: Vec::new(),
@@ -61,6 +81,48 @@ impl Args {
         self
     }
 
+    /// Add a parsed set of `-r` require libraries to this `Args`.
+    #[must_use]
+    pub fn with_requires(mut self, requires: Vec<OsString>) -> Self {
+        self.requires = requires;
+        self
+    }
+
+    /// Add a parsed `-w`/`-W[level]` verbosity level to this `Args`.
+    #[must_use]
+    pub fn with_verbosity(mut self, verbosity: Option<u8>) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Add a parsed set of `--enable` features to this `Args`.
+    #[must_use]
+    pub fn with_enabled_features(mut self, enabled_features: Vec<OsString>) -> Self {
+        self.enabled_features = enabled_features;
+        self
+    }
+
+    /// Add a parsed set of `--disable` features to this `Args`.
+    #[must_use]
+    pub fn with_disabled_features(mut self, disabled_features: Vec<OsString>) -> Self {
+        self.disabled_features = disabled_features;
+        self
+    }
+
+    /// Add a parsed `-n` line-processing loop flag to this `Args`.
+    #[must_use]
+    pub fn with_lineloop(mut self, lineloop: bool) -> Self {
+        self.lineloop = lineloop;
+        self
+    }
+
+    /// Add a parsed `-p` printing line-processing loop flag to this `Args`.
+    #[must_use]
+    pub fn with_print_lineloop(mut self, print_lineloop: bool) -> Self {
+        self.print_lineloop = print_lineloop;
+        self
+    }
+
     /// Add a parsed fixture path to this `Args`.
     #[must_use]
     pub fn with_fixture(mut self, fixture: Option<PathBuf>) -> Self {
@@ -83,6 +145,36 @@ impl Args {
     }
 }
 
+/// Parse the contents of the `RUBYOPT` environment variable into CLI flags.
+///
+/// MRI prepends the contents of `RUBYOPT` to the command line arguments before
+/// parsing them. Only flags are permitted in `RUBYOPT`; program files and
+/// positional arguments are rejected.
+///
+/// # Errors
+///
+/// If `rubyopt` contains a token that does not begin with `-`, an error is
+/// returned.
+///
+/// If `rubyopt` contains bytes that cannot round trip to a platform string, an
+/// error is returned.
+pub fn rubyopt_flags(rubyopt: &OsStr) -> Result<Vec<OsString>, Box<dyn error::Error>> {
+    let rubyopt = os_str_to_bytes(rubyopt)?;
+    let mut flags = Vec::new();
+    for token in rubyopt.split(|byte| byte.is_ascii_whitespace()) {
+        if token.is_empty() {
+            continue;
+        }
+        if !token.starts_with(b"-") {
+            let mut message = String::from("illegal switch in RUBYOPT: ");
+            format_unicode_debug_into(&mut message, token)?;
+            return Err(message.into());
+        }
+        flags.push(bytes_to_os_str(token)?.to_os_string());
+    }
+    Ok(flags)
+}
+
 /// Main entry point for Artichoke's version of the `ruby` CLI.
 ///
 /// # Errors
@@ -92,9 +184,32 @@ pub fn run<R, W>(args: Args, input: R, error: W) -> Result<Result<(), ()>, Box<d
 where
     R: io::Read,
     W: io::Write + WriteColor,
+{
+    run_with_output(args, input, io::stdout(), error)
+}
+
+/// Main entry point for Artichoke's version of the `ruby` CLI with an explicit
+/// output stream.
+///
+/// Output written by the `-p` line-processing loop is written to `output`
+/// instead of the process's stdout.
+///
+/// # Errors
+///
+/// If an exception is raised on the interpreter, then an error is returned.
+pub fn run_with_output<R, O, W>(
+    args: Args,
+    input: R,
+    output: O,
+    error: W,
+) -> Result<Result<(), ()>, Box<dyn error::Error>>
+where
+    R: io::Read,
+    O: io::Write,
+    W: io::Write + WriteColor,
 {
     let mut interp = crate::interpreter()?;
-    let result = entrypoint(&mut interp, args, input, error);
+    let result = entrypoint(&mut interp, args, input, output, error);
     interp.close();
     result
 }
@@ -104,16 +219,40 @@ where
 /// # Errors
 ///
 /// If an exception is raised on the interpreter, then an error is returned.
-pub fn entrypoint<R, W>(
+pub fn entrypoint<R, O, W>(
     interp: &mut Artichoke,
     args: Args,
     mut input: R,
-    error: W,
+    output: O,
+    mut error: W,
 ) -> Result<Result<(), ()>, Box<dyn error::Error>>
 where
     R: io::Read,
+    O: io::Write,
     W: io::Write + WriteColor,
 {
+    // Set `$VERBOSE` from the `-w`/`-W[level]` flags.
+    if let Some(level) = args.verbosity {
+        let verbose = match level {
+            0 => Value::nil(),
+            1 => interp.convert(false),
+            _ => interp.convert(true),
+        };
+        interp.set_global_variable(&b"$VERBOSE"[..], &verbose)?;
+    }
+
+    // `--enable` and `--disable` features are accepted no-ops for `ruby` CLI
+    // compatibility, except for `frozen-string-literal`, which overrides
+    // per-file `frozen_string_literal` magic comments. `--enable` wins if both
+    // are given.
+    let frozen_string_literal = OsString::from("frozen-string-literal");
+    if args.disabled_features.contains(&frozen_string_literal) {
+        interp.set_frozen_string_literal(Some(false))?;
+    }
+    if args.enabled_features.contains(&frozen_string_literal) {
+        interp.set_frozen_string_literal(Some(true))?;
+    }
+
     if args.copyright {
         interp.eval(b"puts RUBY_COPYRIGHT")?;
         return Ok(Ok(()));
@@ -130,21 +269,64 @@ where
     let ruby_program_argv = interp.try_convert_mut(ruby_program_argv)?;
     interp.define_global_constant("ARGV", ruby_program_argv)?;
 
-    if !args.commands.is_empty() {
+    // Require `-r` libraries, in order, before evaluating any program.
+    for library in &args.requires {
+        if let Err(ref exc) = interp.require_source(library) {
+            backtrace::format_cli_trace_into(&mut error, interp, exc)?;
+            // short circuit, but don't return an error since we already
+            // printed it
+            return Ok(Err(()));
+        }
+    }
+
+    if args.lineloop || args.print_lineloop {
+        execute_line_loop(interp, error, output, input, args)
+    } else if !args.commands.is_empty() {
         execute_inline_eval(interp, error, args.commands, args.fixture.as_deref())
     } else if let Some(programfile) = args.programfile.filter(|file| file != Path::new("-")) {
         execute_program_file(interp, error, programfile.as_path(), args.fixture.as_deref())
     } else {
+        // A programfile of `-` means the program source is read from stdin.
+        // stdin is consumed by the program source, so it is not available to
+        // the script as an input stream.
         let mut program = vec![];
         input
             .read_to_end(&mut program)
             .map_err(|_| IOError::from("Could not read program from STDIN"))?;
-        if let Err(ref exc) = interp.eval(program.as_slice()) {
-            backtrace::format_cli_trace_into(error, interp, exc)?;
-            return Ok(Err(()));
-        }
-        Ok(Ok(()))
+        execute_stdin_program(interp, error, program, args.fixture.as_deref())
+    }
+}
+
+fn execute_stdin_program<W>(
+    interp: &mut Artichoke,
+    error: W,
+    program: Vec<u8>,
+    fixture: Option<&Path>,
+) -> Result<Result<(), ()>, Box<dyn error::Error>>
+where
+    W: io::Write + WriteColor,
+{
+    if let Some(fixture) = fixture {
+        setup_fixture_hack(interp, fixture)?;
+    }
+    let (code, data) = split_data_section(program.as_slice());
+    if let Some(data) = data {
+        setup_data_constant(interp, data)?;
     }
+    interp.pop_context()?;
+    // Safety:
+    //
+    // - `Context::new_unchecked` requires that its argument has no NUL bytes.
+    // - `STDIN` is controlled by this crate.
+    // - A test asserts that `STDIN` has no NUL bytes.
+    let context = unsafe { Context::new_unchecked(STDIN) };
+    interp.push_context(context)?;
+    if let Err(ref exc) = interp.eval(code) {
+        backtrace::format_cli_trace_into(error, interp, exc)?;
+        // short circuit, but don't return an error since we already printed it
+        return Ok(Err(()));
+    }
+    Ok(Ok(()))
 }
 
 fn execute_inline_eval<W>(
@@ -185,6 +367,124 @@ where
     Ok(Ok(()))
 }
 
+// Implicit `while gets(); ... end` loop around the script for the `-n` and
+// `-p` flags.
+//
+// Each line read from the input sources is bound to the `$_` global before the
+// script is evaluated. In `-p` mode, the value of `$_` is written to `output`
+// at the end of each iteration, like `sed`.
+//
+// When `-e` commands are given, all positional arguments name input files.
+// Otherwise, the programfile is the script and the trailing positional
+// arguments name input files. If no input files are given, lines are read from
+// `input`.
+fn execute_line_loop<R, O, W>(
+    interp: &mut Artichoke,
+    mut error: W,
+    mut output: O,
+    mut input: R,
+    args: Args,
+) -> Result<Result<(), ()>, Box<dyn error::Error>>
+where
+    R: io::Read,
+    O: io::Write,
+    W: io::Write + WriteColor,
+{
+    if let Some(fixture) = args.fixture.as_deref() {
+        setup_fixture_hack(interp, fixture)?;
+    }
+
+    let mut sources = Vec::new();
+    let program = if args.commands.is_empty() {
+        if let Some(programfile) = args.programfile.filter(|file| file != Path::new("-")) {
+            let program = if let Ok(program) = fs::read(&programfile) {
+                program
+            } else {
+                return Err(Error::from(LoadError::from(load_error(&programfile, "No such file or directory")?)).into());
+            };
+            sources.extend(args.argv.iter().map(PathBuf::from));
+            program
+        } else {
+            let mut program = vec![];
+            input
+                .read_to_end(&mut program)
+                .map_err(|_| IOError::from("Could not read program from STDIN"))?;
+            return execute_line_loop_over(interp, error, output, &[], program.as_slice(), args.print_lineloop);
+        }
+    } else {
+        interp.pop_context()?;
+        // Safety:
+        //
+        // - `Context::new_unchecked` requires that its argument has no NUL
+        //   bytes.
+        // - `INLINE_EVAL_SWITCH` is controlled by this crate.
+        // - A test asserts that `INLINE_EVAL_SWITCH` has no NUL bytes.
+        let context = unsafe { Context::new_unchecked(INLINE_EVAL_SWITCH) };
+        interp.push_context(context)?;
+        let mut commands = args.commands.into_iter();
+        let mut buf = if let Some(command) = commands.next() {
+            command
+        } else {
+            return Ok(Ok(()));
+        };
+        for command in commands {
+            buf.push("\n");
+            buf.push(command);
+        }
+        sources.extend(args.programfile);
+        sources.extend(args.argv.iter().map(PathBuf::from));
+        os_str_to_bytes(&buf)?.to_vec()
+    };
+
+    let mut data = Vec::new();
+    if sources.is_empty() {
+        input
+            .read_to_end(&mut data)
+            .map_err(|_| IOError::from("Could not read input from STDIN"))?;
+    } else {
+        for file in &sources {
+            let contents = if let Ok(contents) = fs::read(file) {
+                contents
+            } else {
+                return Err(Error::from(LoadError::from(load_error(file, "No such file or directory")?)).into());
+            };
+            data.extend_from_slice(&contents);
+        }
+    }
+    execute_line_loop_over(interp, &mut error, &mut output, &data, program.as_slice(), args.print_lineloop)
+}
+
+fn execute_line_loop_over<O, W>(
+    interp: &mut Artichoke,
+    mut error: W,
+    mut output: O,
+    data: &[u8],
+    program: &[u8],
+    print: bool,
+) -> Result<Result<(), ()>, Box<dyn error::Error>>
+where
+    O: io::Write,
+    W: io::Write + WriteColor,
+{
+    for line in data.split_inclusive(|&byte| byte == b'\n') {
+        let line = interp.try_convert_mut(line.to_vec())?;
+        interp.set_global_variable(&b"$_"[..], &line)?;
+        if let Err(ref exc) = interp.eval(program) {
+            backtrace::format_cli_trace_into(&mut error, interp, exc)?;
+            // short circuit, but don't return an error since we already
+            // printed it
+            return Ok(Err(()));
+        }
+        if print {
+            if let Some(last_line) = interp.get_global_variable(&b"$_"[..])? {
+                let last_line = last_line.try_convert_into_mut::<Vec<u8>>(interp)?;
+                output.write_all(&last_line)?;
+            }
+        }
+    }
+    Ok(Ok(()))
+}
+
 fn execute_program_file<W>(
     interp: &mut Artichoke,
     error: W,
@@ -197,13 +497,64 @@ where
     if let Some(fixture) = fixture {
         setup_fixture_hack(interp, fixture)?;
     }
-    if let Err(ref exc) = interp.eval_file(programfile) {
+    let program = if let Ok(program) = fs::read(programfile) {
+        program
+    } else {
+        return Err(Error::from(LoadError::from(load_error(programfile, "No such file or directory")?)).into());
+    };
+    let (code, data) = split_data_section(program.as_slice());
+    if let Some(data) = data {
+        setup_data_constant(interp, data)?;
+    }
+    let context = Context::new(os_str_to_bytes(programfile.as_os_str())?.to_vec())
+        .ok_or_else(|| Error::from(ArgumentError::with_message("path name contains null byte")))?;
+    interp.push_context(context)?;
+    let result = interp.eval(code);
+    interp.pop_context()?;
+    if let Err(ref exc) = result {
         backtrace::format_cli_trace_into(error, interp, exc)?;
         return Ok(Err(()));
     }
     Ok(Ok(()))
 }
 
+// Split a program at an `__END__` marker appearing alone on a line.
+//
+// Everything before the marker line is the code handed to the parser and
+// everything after it is the data section exposed to the script as the `DATA`
+// constant. The code is returned as a prefix of the program so line numbers
+// reported in errors are unchanged by the split.
+//
+// If the program has no `__END__` marker, the entire program is code and there
+// is no data section.
+fn split_data_section(program: &[u8]) -> (&[u8], Option<&[u8]>) {
+    let mut offset = 0;
+    for line in program.split_inclusive(|&byte| byte == b'\n') {
+        let marker = line.strip_suffix(b"\n").unwrap_or(line);
+        let marker = marker.strip_suffix(b"\r").unwrap_or(marker);
+        if marker == b"__END__" {
+            let data_start = offset + line.len();
+            return (&program[..offset], Some(&program[data_start..]));
+        }
+        offset += line.len();
+    }
+    (program, None)
+}
+
+// Bind the data section of a program to the `DATA` constant.
+//
+// `DATA` is an `Artichoke::DataFile`, an IO-like object defined in Ruby that
+// reads from an in-memory buffer. MRI backs `DATA` with a `File` handle on the
+// script, which Artichoke cannot rely on being able to reopen.
+fn setup_data_constant(interp: &mut Artichoke, data: &[u8]) -> Result<(), Error> {
+    interp.eval(&include_bytes!("ruby/data.rb")[..])?;
+    let data = interp.try_convert_mut(data.to_vec())?;
+    let data_file = interp.eval(b"Artichoke::DataFile")?;
+    let data_file = data_file.funcall(interp, "new", &[data], None)?;
+    interp.define_global_constant("DATA", data_file)?;
+    Ok(())
+}
+
 fn load_error<P: AsRef<OsStr>>(file: P, message: &str) -> Result<String, Error> {
     let mut buf = String::from(message);
     buf.push_str(" -- ");
@@ -237,7 +588,10 @@ mod tests {
 
     use termcolor::Ansi;
 
-    use super::{run, Args};
+    use std::ffi::OsStr;
+
+    use super::{entrypoint, rubyopt_flags, run, run_with_output, Args};
+    use crate::prelude::*;
 
     #[test]
     fn run_with_copyright() {
@@ -263,6 +617,95 @@ mod tests {
         assert!(matches!(run(args, &input[..], &mut err), Ok(Err(_))));
     }
 
+    #[test]
+    fn run_with_programfile_from_stdin_sets_program_filename() {
+        let args = Args::empty().with_programfile(Some(PathBuf::from("-")));
+        let input = b"raise 'wrong filename' unless __FILE__ == '-'";
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_data_section_reads_data_twice_after_rewind() {
+        let args = Args::empty().with_programfile(Some(PathBuf::from("-")));
+        let input = b"first = DATA.read
+DATA.rewind
+second = DATA.read
+raise 'wrong data' unless first == \"hello\\nworld\\n\"
+raise 'rewind mismatch' unless second == first
+__END__
+hello
+world
+";
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_data_section_iterates_lines() {
+        let args = Args::empty().with_programfile(Some(PathBuf::from("-")));
+        let input = b"lines = []
+DATA.each_line { |line| lines << line }
+raise 'wrong lines' unless lines == [\"hello\\n\", 'world']
+DATA.rewind
+raise 'wrong gets' unless DATA.gets == \"hello\\n\"
+raise 'wrong gets' unless DATA.gets == 'world'
+raise 'gets past EOF' unless DATA.gets.nil?
+__END__
+hello
+world";
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_without_data_section_does_not_define_data() {
+        let args = Args::empty().with_programfile(Some(PathBuf::from("-")));
+        let input = b"raise 'DATA is defined' if Object.const_defined?(:DATA)";
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_data_section_does_not_shift_error_line_numbers() {
+        let args = Args::empty().with_programfile(Some(PathBuf::from("-")));
+        let input = b"x = 1\ny = 2\nraise ArgumentError\n__END__\ndata\n";
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Err(_))));
+        let err = err.into_inner();
+        let err = String::from_utf8(err).unwrap();
+        assert!(err.contains("-:3"));
+    }
+
+    #[test]
+    fn split_data_section_splits_at_end_marker() {
+        let program = &b"puts 1\n__END__\ndata\n"[..];
+        let (code, data) = super::split_data_section(program);
+        assert_eq!(code, b"puts 1\n");
+        assert_eq!(data, Some(&b"data\n"[..]));
+    }
+
+    #[test]
+    fn split_data_section_requires_marker_alone_on_a_line() {
+        let program = &b"puts 1\n__END__ = 7\nx = __END__\n"[..];
+        let (code, data) = super::split_data_section(program);
+        assert_eq!(code, program);
+        assert_eq!(data, None);
+    }
+
+    #[test]
+    fn split_data_section_handles_crlf_and_marker_at_eof() {
+        let program = &b"puts 1\r\n__END__\r\ndata"[..];
+        let (code, data) = super::split_data_section(program);
+        assert_eq!(code, b"puts 1\r\n");
+        assert_eq!(data, Some(&b"data"[..]));
+
+        let program = &b"puts 1\n__END__"[..];
+        let (code, data) = super::split_data_section(program);
+        assert_eq!(code, b"puts 1\n");
+        assert_eq!(data, Some(&b""[..]));
+    }
+
     #[test]
     fn run_with_stdin() {
         let args = Args::empty();
@@ -294,4 +737,151 @@ mod tests {
         let mut err = Ansi::new(Vec::new());
         assert!(matches!(run(args, &input[..], &mut err), Ok(Err(_))));
     }
+
+    #[test]
+    fn run_with_lineloop_processes_each_line() {
+        let mut interp = crate::interpreter().unwrap();
+        let args = Args::empty()
+            .with_lineloop(true)
+            .with_commands(vec![OsString::from("$lines = ($lines || '') + $_.upcase")]);
+        let input = b"foo\nbar\n";
+        let mut out = Vec::new();
+        let mut err = Ansi::new(Vec::new());
+        let result = entrypoint(&mut interp, args, &input[..], &mut out, &mut err);
+        assert!(matches!(result, Ok(Ok(_))));
+        // `-n` does not print unless the program does.
+        assert!(out.is_empty());
+        let lines = interp.get_global_variable(&b"$lines"[..]).unwrap().unwrap();
+        let lines = lines.try_convert_into_mut::<Vec<u8>>(&mut interp).unwrap();
+        assert_eq!(lines, b"FOO\nBAR\n".to_vec());
+        interp.close();
+    }
+
+    #[test]
+    fn run_with_print_lineloop_transforms_output() {
+        let args = Args::empty()
+            .with_print_lineloop(true)
+            .with_commands(vec![OsString::from("$_ = $_.upcase")]);
+        let input = b"foo\nbar\nbaz\n";
+        let mut out = Vec::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run_with_output(args, &input[..], &mut out, &mut err), Ok(Ok(_))));
+        assert_eq!(out, b"FOO\nBAR\nBAZ\n".to_vec());
+    }
+
+    #[test]
+    fn run_with_print_lineloop_raise_exception() {
+        let args = Args::empty()
+            .with_print_lineloop(true)
+            .with_commands(vec![OsString::from("raise ArgumentError")]);
+        let input = b"foo\n";
+        let mut out = Vec::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run_with_output(args, &input[..], &mut out, &mut err), Ok(Err(_))));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn run_with_verbosity_sets_verbose_global() {
+        let args = Args::empty()
+            .with_verbosity(Some(2))
+            .with_commands(vec![OsString::from("raise 'not verbose' unless $VERBOSE == true")]);
+        let input = Vec::<u8>::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_verbosity_zero_sets_nil_verbose_global() {
+        let args = Args::empty()
+            .with_verbosity(Some(0))
+            .with_commands(vec![OsString::from("raise 'not silent' unless $VERBOSE.nil?")]);
+        let input = Vec::<u8>::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_disabled_features_is_accepted_noop() {
+        let args = Args::empty()
+            .with_disabled_features(vec![OsString::from("gems"), OsString::from("did_you_mean")])
+            .with_commands(vec![OsString::from("2 + 7")]);
+        let input = Vec::<u8>::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_enabled_frozen_string_literal_freezes_literals() {
+        let args = Args::empty()
+            .with_enabled_features(vec![OsString::from("frozen-string-literal")])
+            .with_commands(vec![OsString::from("raise 'not frozen' unless 'a'.frozen?")]);
+        let input = Vec::<u8>::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_disabled_frozen_string_literal_overrides_magic_comment() {
+        let args = Args::empty()
+            .with_disabled_features(vec![OsString::from("frozen-string-literal")])
+            .with_programfile(Some(PathBuf::from("-")));
+        let input = b"# frozen_string_literal: true\nraise 'frozen' if 'a'.frozen?";
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn rubyopt_flags_accepts_flags() {
+        let flags = rubyopt_flags(OsStr::new("-w --disable=gems")).unwrap();
+        assert_eq!(flags, vec![OsString::from("-w"), OsString::from("--disable=gems")]);
+    }
+
+    #[test]
+    fn rubyopt_flags_empty() {
+        let flags = rubyopt_flags(OsStr::new("")).unwrap();
+        assert!(flags.is_empty());
+        let flags = rubyopt_flags(OsStr::new("   ")).unwrap();
+        assert!(flags.is_empty());
+    }
+
+    #[test]
+    fn rubyopt_flags_rejects_program_args() {
+        assert!(rubyopt_flags(OsStr::new("program.rb")).is_err());
+        assert!(rubyopt_flags(OsStr::new("-w program.rb")).is_err());
+    }
+
+    #[test]
+    fn run_with_require_and_inline_eval() {
+        let args = Args::empty()
+            .with_requires(vec![OsString::from("forwardable")])
+            .with_commands(vec![OsString::from("Forwardable")]);
+        let input = Vec::<u8>::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_multiple_requires() {
+        let args = Args::empty()
+            .with_requires(vec![OsString::from("forwardable"), OsString::from("set")])
+            .with_commands(vec![OsString::from("Forwardable && Set")]);
+        let input = Vec::<u8>::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Ok(_))));
+    }
+
+    #[test]
+    fn run_with_failing_require_does_not_run_program() {
+        let args = Args::empty()
+            .with_requires(vec![OsString::from("nonexistent-library")])
+            .with_commands(vec![OsString::from("raise 'unreachable'")]);
+        let input = Vec::<u8>::new();
+        let mut err = Ansi::new(Vec::new());
+        assert!(matches!(run(args, &input[..], &mut err), Ok(Err(_))));
+        let err = err.into_inner();
+        let err = String::from_utf8(err).unwrap();
+        assert!(err.contains("LoadError"));
+        assert!(!err.contains("unreachable"));
+    }
 }
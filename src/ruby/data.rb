@@ -0,0 +1,54 @@
+# frozen_string_literal: true
+
+module Artichoke
+  # An in-memory `IO`-like object that backs the `DATA` constant.
+  #
+  # When a program contains an `__END__` marker, the `ruby` frontend exposes
+  # everything after the marker line as `DATA`. MRI backs `DATA` with a `File`
+  # handle on the script itself; Artichoke may not be able to access the file
+  # system, so the data section is captured into memory when the program is
+  # loaded.
+  class DataFile
+    def initialize(bytes)
+      @bytes = bytes
+      @pos = 0
+    end
+
+    def each_line(separator = "\n")
+      return to_enum(:each_line, separator) unless block_given?
+
+      while (line = gets(separator))
+        yield line
+      end
+      self
+    end
+
+    def gets(separator = "\n")
+      return nil if @pos >= @bytes.length
+
+      index = @bytes.index(separator, @pos)
+      line =
+        if index.nil?
+          @bytes[@pos..-1]
+        else
+          @bytes[@pos...index + separator.length]
+        end
+      @pos += line.length
+      line
+    end
+
+    def read(length = nil)
+      remaining = @bytes.length - @pos
+      return nil if length && remaining.zero? && !length.zero?
+
+      chunk = @bytes[@pos, length || remaining]
+      @pos += chunk.length
+      chunk
+    end
+
+    def rewind
+      @pos = 0
+      0
+    end
+  end
+end